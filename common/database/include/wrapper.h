@@ -125,6 +125,21 @@ inline int32_t ExecuteSql(RdbStore &store, const rust::str sql)
     return store.ExecuteSql(std::string(sql));
 }
 
+inline int32_t BeginTransaction(RdbStore &store)
+{
+    return store.BeginTransaction();
+}
+
+inline int32_t Commit(RdbStore &store)
+{
+    return store.Commit();
+}
+
+inline int32_t RollBack(RdbStore &store)
+{
+    return store.RollBack();
+}
+
 inline std::shared_ptr<ResultSet> Query(
     RdbStore &store, const rust::str sql, const std::unique_ptr<std::vector<ValueObject>> args)
 {
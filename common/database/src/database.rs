@@ -19,11 +19,13 @@
 
 use std::pin::Pin;
 
-use cxx::SharedPtr;
+use cxx::{CxxVector, SharedPtr, UniquePtr};
 
 use crate::config::OpenConfig;
 use crate::params::{FromSql, Params};
-use crate::wrapper::ffi::{self, Execute, ExecuteSql, NewRowEntity, Query};
+use crate::statement::Statement;
+use crate::transaction::Transaction;
+use crate::wrapper::ffi::{self, Execute, ExecuteSql, NewRowEntity, Query, ValueObject};
 use crate::wrapper::open_rdb_store;
 
 /// Success error code constant.
@@ -35,7 +37,7 @@ const E_OK: i32 = 0;
 /// database. Wraps the underlying FFI implementation with safe, idiomatic Rust.
 pub struct RdbStore<'a> {
     /// Internal representation of the database store
-    inner: RdbStoreInner<'a>,
+    pub(crate) inner: RdbStoreInner<'a>,
 }
 
 impl<'a> RdbStore<'a> {
@@ -86,10 +88,7 @@ impl<'a> RdbStore<'a> {
     ///
     /// Returns `Ok(())` on success, or `Err` with an error code on failure
     pub fn execute<P: Params>(&self, sql: &str, values: P) -> Result<(), i32> {
-        match Execute(self.inner.pin_mut(), sql, values.into_values_object()) {
-            0 => Ok(()),
-            err => Err(err),
-        }
+        self.execute_values(sql, values.into_values_object())
     }
 
     /// Executes an SQL statement via `ExecuteSql`, which bypasses `Execute`'s column-count
@@ -122,7 +121,116 @@ impl<'a> RdbStore<'a> {
     /// This method uses unsafe code to handle FFI pointers to the underlying
     /// result set.
     pub fn query<T>(&self, sql: &str, values: impl Params) -> Result<QuerySet<T>, i32> {
-        let result = Query(self.inner.pin_mut(), sql, values.into_values_object());
+        self.query_values(sql, values.into_values_object())
+    }
+
+    /// Runs `f` inside a transaction.
+    ///
+    /// The transaction is committed if `f` returns `Ok`, and rolled back if
+    /// `f` returns `Err`, if the commit itself fails, or if `f` panics.
+    /// Transactions must not be nested.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Closure executing statements through the given `Transaction`
+    ///
+    /// # Returns
+    ///
+    /// Returns the value produced by `f` once the transaction is committed,
+    /// or `Err` with the error code from `f` or from the database
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// rdb.transaction(|tx| {
+    ///     tx.execute("UPDATE t SET state = ? WHERE id = ?", (1, 10))?;
+    ///     tx.execute("DELETE FROM t WHERE id = ?", 11)
+    /// })?;
+    /// ```
+    pub fn transaction<T, F>(&self, f: F) -> Result<T, i32>
+    where
+        F: FnOnce(&Transaction<'_, 'a>) -> Result<T, i32>,
+    {
+        let tx = Transaction::begin(self)?;
+        match f(&tx) {
+            Ok(value) => {
+                tx.commit()?;
+                Ok(value)
+            }
+            Err(err) => {
+                tx.rollback();
+                Err(err)
+            }
+        }
+    }
+
+    /// Prepares an SQL statement for repeated execution with bound
+    /// parameters.
+    ///
+    /// Only positional `?` placeholders are supported. The number of
+    /// placeholders is checked against the parameters on every call, so a
+    /// statement can never be run with missing or extra values.
+    ///
+    /// # Arguments
+    ///
+    /// * `sql` - The SQL statement containing `?` placeholders
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok` with a `Statement` handle, or `Err` if `sql` is empty
+    pub fn prepare(&self, sql: &str) -> Result<Statement<'_, 'a>, i32> {
+        Statement::new(self, sql)
+    }
+
+    /// Executes the same SQL statement once for every parameter set, inside a
+    /// single transaction.
+    ///
+    /// Either all rows are applied or none of them are.
+    ///
+    /// # Arguments
+    ///
+    /// * `sql` - The SQL statement containing `?` placeholders
+    /// * `rows` - Parameter sets, one per execution
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok` with the number of executions on success, or `Err` with
+    /// the first error code encountered
+    pub fn execute_batch<P, I>(&self, sql: &str, rows: I) -> Result<usize, i32>
+    where
+        P: Params,
+        I: IntoIterator<Item = P>,
+    {
+        self.transaction(|tx| {
+            let stmt = tx.prepare(sql)?;
+            let mut count = 0;
+            for values in rows {
+                stmt.execute(values)?;
+                count += 1;
+            }
+            Ok(count)
+        })
+    }
+
+    /// Executes an SQL statement with an already built parameter vector.
+    pub(crate) fn execute_values(
+        &self,
+        sql: &str,
+        values: UniquePtr<CxxVector<ValueObject>>,
+    ) -> Result<(), i32> {
+        match Execute(self.inner.pin_mut(), sql, values) {
+            0 => Ok(()),
+            err => Err(err),
+        }
+    }
+
+    /// Runs an SQL query with an already built parameter vector.
+    pub(crate) fn query_values<T>(
+        &self,
+        sql: &str,
+        values: UniquePtr<CxxVector<ValueObject>>,
+    ) -> Result<QuerySet<T>, i32> {
+        let result = Query(self.inner.pin_mut(), sql, values);
         if result.is_null() {
             return Err(-1);
         }
@@ -146,7 +254,7 @@ impl<'a> RdbStore<'a> {
 ///
 /// Provides a unified interface for different ownership models of the
 /// underlying FFI store.
-enum RdbStoreInner<'a> {
+pub(crate) enum RdbStoreInner<'a> {
     /// Shared ownership model using a reference-counted pointer
     Shared(SharedPtr<ffi::RdbStore>),
     /// Borrowed reference model using a pinned mutable reference
@@ -160,7 +268,7 @@ impl RdbStoreInner<'_> {
     ///
     /// This method uses unsafe code to convert between pointer types and create
     /// mutable references.
-    fn pin_mut(&self) -> Pin<&mut ffi::RdbStore> {
+    pub(crate) fn pin_mut(&self) -> Pin<&mut ffi::RdbStore> {
         match self {
             Self::Shared(ffi) => {
                 let ptr = ffi.as_ref().unwrap() as *const ffi::RdbStore as *mut ffi::RdbStore;
@@ -16,8 +16,12 @@
 mod config;
 mod database;
 mod params;
+mod statement;
+mod transaction;
 mod wrapper;
 
 pub use config::OpenConfig;
-pub use database::RdbStore;
+pub use database::{QuerySet, RdbStore};
+pub use statement::Statement;
+pub use transaction::Transaction;
 pub use wrapper::ffi::SecurityLevel;
@@ -253,8 +253,8 @@ impl ParamValues {
 
 /// Trait for types that can be used as SQL query parameters.
 ///
-/// Implementations are provided for common types and tuples of up to 16
-/// elements.
+/// Implementations are provided for common types, vectors and tuples of up
+/// to 16 elements.
 pub trait Params {
     /// Converts the value into a vector of SQL value objects for binding.
     fn into_values_object(self) -> UniquePtr<CxxVector<ValueObject>>;
@@ -276,6 +276,18 @@ impl<T: ToSql> Params for T {
     }
 }
 
+impl<T: ToSql> Params for Vec<T> {
+    /// Converts a list of values, whose length is only known at runtime,
+    /// into a parameter collection.
+    fn into_values_object(self) -> UniquePtr<CxxVector<ValueObject>> {
+        let mut values = ParamValues::new();
+        for value in self {
+            values.push(value);
+        }
+        values.inner
+    }
+}
+
 // Macro for implementing `Params` for tuple types
 // This allows using tuples of up to 16 elements as query parameters
 macro_rules! single_tuple_impl {
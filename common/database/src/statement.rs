@@ -0,0 +1,151 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Prepared SQL statements.
+//!
+//! This module provides a reusable statement handle that binds typed
+//! parameters to `?` placeholders instead of formatting values into SQL text.
+
+use crate::database::{QuerySet, RdbStore};
+use crate::params::Params;
+
+/// Error code returned for an empty statement or mismatched parameters.
+pub(crate) const E_INVALID_ARGS: i32 = -2;
+
+/// A prepared SQL statement bound to an `RdbStore`.
+///
+/// The statement text is validated once at preparation and can then be run
+/// any number of times with different parameters. The statement is not
+/// cached: the store compiles the SQL text again on every execution.
+pub struct Statement<'s, 'a> {
+    /// The store the statement runs on
+    rdb: &'s RdbStore<'a>,
+    /// The SQL text of the statement
+    sql: String,
+    /// Number of `?` placeholders in the statement
+    param_count: usize,
+}
+
+impl<'s, 'a> Statement<'s, 'a> {
+    /// Creates a new statement for the given store.
+    ///
+    /// # Arguments
+    ///
+    /// * `rdb` - The store the statement runs on
+    /// * `sql` - The SQL statement containing `?` placeholders
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok` with the statement, or `Err` if `sql` is empty
+    pub(crate) fn new(rdb: &'s RdbStore<'a>, sql: &str) -> Result<Self, i32> {
+        if sql.trim().is_empty() {
+            return Err(E_INVALID_ARGS);
+        }
+        Ok(Self {
+            rdb,
+            sql: sql.to_string(),
+            param_count: count_placeholders(sql),
+        })
+    }
+
+    /// Gets the number of `?` placeholders in the statement.
+    pub fn param_count(&self) -> usize {
+        self.param_count
+    }
+
+    /// Gets the SQL text of the statement.
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// Executes the statement with the given parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - Parameters to bind, one per placeholder
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or `Err` with an error code on failure or
+    /// if the number of parameters does not match the placeholders
+    pub fn execute<P: Params>(&self, values: P) -> Result<(), i32> {
+        let values = values.into_values_object();
+        if values.len() != self.param_count {
+            return Err(E_INVALID_ARGS);
+        }
+        self.rdb.execute_values(&self.sql, values)
+    }
+
+    /// Runs the statement as a query with the given parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - Parameters to bind, one per placeholder
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok` with a `QuerySet` iterator on success, or `Err` with an
+    /// error code on failure or if the number of parameters does not match
+    /// the placeholders
+    pub fn query<T>(&self, values: impl Params) -> Result<QuerySet<T>, i32> {
+        let values = values.into_values_object();
+        if values.len() != self.param_count {
+            return Err(E_INVALID_ARGS);
+        }
+        self.rdb.query_values(&self.sql, values)
+    }
+}
+
+/// Counts the `?` placeholders in `sql`, ignoring any inside string literals,
+/// quoted identifiers and comments.
+pub(crate) fn count_placeholders(sql: &str) -> usize {
+    let mut count = 0;
+    let mut chars = sql.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' | '"' | '`' => {
+                // Doubled quotes are escapes and simply reopen the literal.
+                for d in chars.by_ref() {
+                    if d == c {
+                        break;
+                    }
+                }
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                for d in chars.by_ref() {
+                    if d == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = ' ';
+                for d in chars.by_ref() {
+                    if prev == '*' && d == '/' {
+                        break;
+                    }
+                    prev = d;
+                }
+            }
+            '?' => count += 1,
+            _ => {}
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod ut_statement {
+    include!("../tests/ut/ut_statement.rs");
+}
@@ -0,0 +1,102 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Transaction support for relational database operations.
+//!
+//! This module provides a guard type that keeps a transaction open on an
+//! `RdbStore` and rolls it back unless it is explicitly committed.
+
+use std::cell::Cell;
+use std::ops::Deref;
+
+use crate::database::RdbStore;
+use crate::wrapper::ffi::{BeginTransaction, Commit, RollBack};
+
+/// An open transaction on an `RdbStore`.
+///
+/// Dereferences to the underlying `RdbStore`, so `execute`, `query` and
+/// `prepare` run inside the transaction. Dropping the transaction without
+/// committing it rolls back every change made through it.
+pub struct Transaction<'s, 'a> {
+    /// The store the transaction was started on
+    rdb: &'s RdbStore<'a>,
+    /// Whether the transaction has already been committed or rolled back
+    finished: Cell<bool>,
+}
+
+impl<'s, 'a> Transaction<'s, 'a> {
+    /// Starts a new transaction on the given store.
+    ///
+    /// # Arguments
+    ///
+    /// * `rdb` - The store to start the transaction on
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok` with the open transaction, or `Err` with an error code
+    /// on failure
+    pub(crate) fn begin(rdb: &'s RdbStore<'a>) -> Result<Self, i32> {
+        match BeginTransaction(rdb.inner.pin_mut()) {
+            0 => Ok(Self {
+                rdb,
+                finished: Cell::new(false),
+            }),
+            err => Err(err),
+        }
+    }
+
+    /// Commits the transaction.
+    ///
+    /// If the commit fails the transaction is rolled back when dropped.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or `Err` with an error code on failure
+    pub(crate) fn commit(self) -> Result<(), i32> {
+        match Commit(self.rdb.inner.pin_mut()) {
+            0 => {
+                self.finished.set(true);
+                Ok(())
+            }
+            err => Err(err),
+        }
+    }
+
+    /// Rolls back the transaction, discarding every change made through it.
+    pub(crate) fn rollback(self) {
+        self.finish_with_rollback();
+    }
+
+    /// Rolls back the transaction if it has not been finished yet.
+    fn finish_with_rollback(&self) {
+        if !self.finished.replace(true) {
+            let _ = RollBack(self.rdb.inner.pin_mut());
+        }
+    }
+}
+
+impl<'a> Deref for Transaction<'_, 'a> {
+    type Target = RdbStore<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        self.rdb
+    }
+}
+
+impl Drop for Transaction<'_, '_> {
+    /// Rolls back the transaction if it was neither committed nor rolled
+    /// back, e.g. when the closure passed to `RdbStore::transaction` panics.
+    fn drop(&mut self) {
+        self.finish_with_rollback();
+    }
+}
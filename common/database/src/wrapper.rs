@@ -285,6 +285,11 @@ pub mod ffi {
             sql: &str,
         ) -> i32;
 
+        // Transaction control on the underlying store connection
+        fn BeginTransaction(rdb: Pin<&mut RdbStore>) -> i32;
+        fn Commit(rdb: Pin<&mut RdbStore>) -> i32;
+        fn RollBack(rdb: Pin<&mut RdbStore>) -> i32;
+
         fn Query(
             rdb: Pin<&mut RdbStore>,
             sql: &str,
@@ -117,3 +117,105 @@ fn ut_database_option() {
         .unwrap();
     assert_eq!(set.next().unwrap(), Some(TEST_STRING.to_string()));
 }
+
+// @tc.name: ut_database_transaction
+// @tc.desc: Test transaction commit and rollback semantics
+// @tc.precon: NA
+// @tc.step: 1. Create test database and table
+//           2. Insert records in a successful transaction
+//           3. Insert records in a failing transaction
+//           4. Verify only the committed records remain
+// @tc.expect: Committed changes persist and failed transactions roll back
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_database_transaction() {
+    let rdb = get_rdb();
+    rdb.execute("DROP TABLE IF EXISTS test_table_003", ())
+        .unwrap();
+    rdb.execute(
+        "CREATE TABLE IF NOT EXISTS test_table_003 (id INTEGER PRIMARY KEY, name TEXT)",
+        (),
+    )
+    .unwrap();
+    rdb.transaction(|tx| {
+        tx.execute("INSERT INTO test_table_003 (id, name) VALUES (?, ?)", (0, "a"))?;
+        tx.execute("INSERT INTO test_table_003 (id, name) VALUES (?, ?)", (1, "b"))
+    })
+    .unwrap();
+
+    let res = rdb.transaction(|tx| {
+        tx.execute("INSERT INTO test_table_003 (id, name) VALUES (?, ?)", (2, "c"))?;
+        tx.execute("INSERT INTO test_table_003 (id, name) VALUES (?, ?)", (0, "d"))
+    });
+    assert!(res.is_err());
+
+    let mut set = rdb
+        .query::<(i32, String)>("SELECT id, name FROM test_table_003 ORDER BY id", ())
+        .unwrap();
+    assert_eq!(set.row_count(), 2);
+    assert_eq!(set.next().unwrap(), (0, "a".to_string()));
+    assert_eq!(set.next().unwrap(), (1, "b".to_string()));
+}
+
+// @tc.name: ut_database_prepare_and_batch
+// @tc.desc: Test prepared statements and batch execution
+// @tc.precon: NA
+// @tc.step: 1. Create test database and table
+//           2. Insert records with execute_batch
+//           3. Query through a prepared statement
+//           4. Execute a prepared statement with wrong parameter count
+//           5. Query with a vector of parameters
+// @tc.expect: Batch rows are inserted, mismatched parameters are rejected and
+//             vectors bind one value per placeholder
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_database_prepare_and_batch() {
+    let rdb = get_rdb();
+    rdb.execute("DROP TABLE IF EXISTS test_table_004", ())
+        .unwrap();
+    rdb.execute(
+        "CREATE TABLE IF NOT EXISTS test_table_004 (id INTEGER PRIMARY KEY, name TEXT)",
+        (),
+    )
+    .unwrap();
+    let count = rdb
+        .execute_batch(
+            "INSERT INTO test_table_004 (id, name) VALUES (?, ?)",
+            (0..10).map(|i| (i, "batch")),
+        )
+        .unwrap();
+    assert_eq!(count, 10);
+
+    let stmt = rdb
+        .prepare("SELECT name FROM test_table_004 WHERE id = ?")
+        .unwrap();
+    assert_eq!(stmt.param_count(), 1);
+    for i in 0..10 {
+        let mut set = stmt.query::<String>(i).unwrap();
+        assert_eq!(set.next().unwrap(), "batch");
+    }
+    assert!(stmt.query::<String>((1, 2)).is_err());
+    assert!(stmt.execute(()).is_err());
+
+    let ids: Vec<i64> = vec![1, 3, 5];
+    let mut set = rdb
+        .query::<i32>(
+            "SELECT COUNT(*) FROM test_table_004 WHERE id IN (?, ?, ?)",
+            ids,
+        )
+        .unwrap();
+    assert_eq!(set.next().unwrap(), 3);
+
+    // A failing row rolls back the whole batch.
+    let res = rdb.execute_batch(
+        "INSERT INTO test_table_004 (id, name) VALUES (?, ?)",
+        vec![(10, "new"), (0, "dup")],
+    );
+    assert!(res.is_err());
+    let mut set = rdb
+        .query::<i32>("SELECT COUNT(*) FROM test_table_004", ())
+        .unwrap();
+    assert_eq!(set.next().unwrap(), 10);
+}
@@ -0,0 +1,42 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+// @tc.name: ut_count_placeholders
+// @tc.desc: Test placeholder counting for prepared statements
+// @tc.precon: NA
+// @tc.step: 1. Count placeholders in plain statements
+//           2. Count placeholders with literals and comments
+// @tc.expect: Only placeholders outside literals and comments are counted
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_count_placeholders() {
+    assert_eq!(count_placeholders("SELECT * FROM t"), 0);
+    assert_eq!(count_placeholders("SELECT * FROM t WHERE id = ?"), 1);
+    assert_eq!(
+        count_placeholders("INSERT INTO t (id, name) VALUES (?, ?)"),
+        2
+    );
+    assert_eq!(
+        count_placeholders("SELECT * FROM t WHERE name = '?' AND id = ?"),
+        1
+    );
+    assert_eq!(
+        count_placeholders("SELECT * FROM t WHERE name = 'it''s ?' AND id = ?"),
+        1
+    );
+    assert_eq!(count_placeholders("SELECT \"a?\" FROM t -- id = ?\n WHERE id = ?"), 1);
+    assert_eq!(count_placeholders("SELECT /* ? */ id FROM t WHERE id = ?"), 1);
+}
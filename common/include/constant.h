@@ -48,6 +48,7 @@ enum ExceptionErrorCode : int32_t {
     E_TASK_NOT_FOUND = 21900006,
     E_TASK_STATE = 21900007,
     E_GROUP_NOT_FOUND = 21900008,
+    E_TASK_QUOTA = 21900009,
 };
 
 struct ExceptionError {
@@ -68,6 +69,7 @@ static constexpr const char *E_TASK_MODE_INFO = "Operation with wrong task mode"
 static constexpr const char *E_TASK_NOT_FOUND_INFO = "Task removed or not found";
 static constexpr const char *E_TASK_STATE_INFO = "Operation with wrong task state";
 static constexpr const char *E_GROUP_NOT_FOUND_INFO = "Group deleted or not found";
+static constexpr const char *E_TASK_QUOTA_INFO = "The application task quota is exceeded";
 
 static constexpr const char *FUNCTION_PAUSE = "pause";
 static constexpr const char *FUNCTION_QUERY = "query";
@@ -307,11 +307,16 @@ impl DownloadInfo {
     /// # Arguments
     ///
     /// * `dns` - A vector of DNS server addresses used during resolution.
-    pub(crate) fn set_network_dns(&mut self, dns: Vec<String>) {
+    pub fn set_network_dns(&mut self, dns: Vec<String>) {
         self.network.set_dns(dns);
     }
 
-    pub(crate) fn set_ip_address(&mut self, addr: String) {
+    /// Sets the address of the server this download connected to.
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - The IP address of the server.
+    pub fn set_ip_address(&mut self, addr: String) {
         self.network.set_ip_address(addr);
     }
 
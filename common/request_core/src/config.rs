@@ -830,6 +830,48 @@ pub struct Notification {
     pub want_agent: Option<String>,
 }
 
+/// Chunked upload configuration of a single-file upload task.
+///
+/// The URL, header values and finalize fields may contain the placeholders
+/// `{index}`, `{number}`, `{offset}`, `{end}`, `{size}`, `{total}` and
+/// `{count}`; `finalize_part` may also contain `{etag}` and is repeated once
+/// per chunk in place of `{parts}` in `finalize_body`.
+#[derive(Clone, Debug, Default)]
+pub struct ChunkedUploadConfig {
+    /// Size of every chunk but the last, in bytes, from 5 MiB to 64 MiB.
+    pub chunk_size: u64,
+    /// Number of chunks uploaded at the same time, from 1 to 4.
+    pub parallelism: u32,
+    /// Number of times a failed chunk is retried before the task fails.
+    pub max_retries: u32,
+    /// URL of each chunk request; the task URL when empty.
+    pub url: String,
+    /// Method of each chunk request, `PUT` or `POST`.
+    pub method: String,
+    /// Headers added to each chunk request.
+    pub headers: HashMap<String, String>,
+    /// URL of the request sent once all chunks are uploaded; none when empty.
+    pub finalize_url: String,
+    /// Method of the finalize request, `PUT` or `POST`.
+    pub finalize_method: String,
+    /// Body of the finalize request.
+    pub finalize_body: String,
+    /// Text listing one chunk in the finalize body.
+    pub finalize_part: String,
+}
+
+/// DNS options of a task, applied on top of the DNS options of the
+/// application.
+#[derive(Clone, Debug, Default)]
+pub struct TaskDnsConfig {
+    /// Addresses pinned to hosts, `host:port:addr[,addr]` each in the style
+    /// of curl `--resolve`, at most 32.
+    pub overrides: Vec<String>,
+    /// `any`, `ipv4`, `ipv6`, `ipv4_first` or `ipv6_first`; the preference of
+    /// the application when empty.
+    pub ip_preference: String,
+}
+
 /// Configuration for a grouped set of tasks.
 #[derive(Clone, Debug)]
 pub struct GroupConfig {
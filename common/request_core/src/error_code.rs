@@ -57,3 +57,6 @@ pub const TASK_STATE_ERR: i32 = 21900007;
 
 /// Requested task group not found.
 pub const GROUP_NOT_FOUND: i32 = 21900008;
+
+/// The application exceeds its quota of queued tasks.
+pub const TASK_QUOTA_ERR: i32 = 21900009;
@@ -380,6 +380,13 @@ pub struct TaskState {
     pub message: String,
 }
 
+impl TaskState {
+    /// Whether the file was transferred successfully.
+    pub fn is_success(&self) -> bool {
+        self.response_code == 0
+    }
+}
+
 /// Progress information for a task.
 ///
 /// Contains current state, processed bytes, and other progress metrics.
@@ -527,6 +534,11 @@ pub struct TaskInfo {
     pub common_data: CommonTaskInfo,
     /// Maximum allowed transfer speed (bytes per second).
     pub max_speed: i64,
+    /// File name suggested by the server, empty if none.
+    pub suggested_name: String,
+    /// Path the file was saved at under the suggested name, empty if it
+    /// stays at its configured path.
+    pub saved_path: String,
 }
 
 impl Deserialize for TaskInfo {
@@ -629,6 +641,10 @@ impl Deserialize for TaskInfo {
             });
         }
 
+        // Read the server-suggested file name and the path it was saved at
+        let suggested_name = parcel.read::<String>().unwrap_or_default();
+        let saved_path = parcel.read::<String>().unwrap_or_default();
+
         // Construct common task information
         let common_data = CommonTaskInfo {
             task_id,
@@ -672,6 +688,54 @@ impl Deserialize for TaskInfo {
             extras, // Extras are not serialized in this context
             common_data,
             max_speed: 0, // Max speed is not serialized in this context
+            suggested_name,
+            saved_path,
+        })
+    }
+}
+
+/// Archived record of a finished task, kept after the live task is purged.
+#[derive(Debug, Clone)]
+pub struct HistoryRecord {
+    /// Unique identifier of the task.
+    pub task_id: String,
+    /// Bundle name of the task owner.
+    pub bundle: String,
+    /// Host of the task URL.
+    pub url_host: String,
+    /// Action performed by the task.
+    pub action: Action,
+    /// Final state of the task.
+    pub state: State,
+    /// Final reason of the task.
+    pub reason: Reason,
+    /// Total bytes transferred.
+    pub total_processed: i64,
+    /// Creation time in milliseconds.
+    pub ctime: u64,
+    /// Last modification time in milliseconds.
+    pub mtime: u64,
+    /// Time spent transferring, in seconds.
+    pub task_time: u64,
+    /// Number of attempts made.
+    pub tries: u32,
+}
+
+impl Deserialize for HistoryRecord {
+    /// Deserializes a `HistoryRecord` from an IPC parcel.
+    fn deserialize(parcel: &mut ipc::parcel::MsgParcel) -> ipc::IpcResult<Self> {
+        Ok(HistoryRecord {
+            task_id: parcel.read::<String>()?,
+            bundle: parcel.read::<String>()?,
+            url_host: parcel.read::<String>()?,
+            action: Action::from(parcel.read::<u32>()?),
+            state: State::from(parcel.read::<u32>()?),
+            reason: Reason::from(parcel.read::<u32>()?),
+            total_processed: parcel.read::<i64>()?,
+            ctime: parcel.read::<u64>()?,
+            mtime: parcel.read::<u64>()?,
+            task_time: parcel.read::<u64>()?,
+            tries: parcel.read::<u32>()?,
         })
     }
 }
@@ -61,6 +61,29 @@ pub const SET_MAX_SPEED: u32 = 21;
 pub const SET_MODE: u32 = 100;
 /// Change task mode.
 pub const DISABLE_TASK_NOTIFICATION: u32 = 101;
+/// Lists the transfer history of finished tasks.
+pub const QUERY_HISTORY: u32 = 102;
+/// Sets the retention policy of the transfer history.
+pub const SET_HISTORY_RETENTION: u32 = 103;
+/// Re-runs only the failed files of a multi-file upload task.
+pub const RETRY_FAILED_FILES: u32 = 104;
+/// Sets whether a multi-file upload task continues after a file fails.
+pub const SET_FILE_ERROR_POLICY: u32 = 105;
+/// Makes a single-file upload task a chunked upload.
+pub const SET_CHUNKED_UPLOAD: u32 = 106;
+/// Saves the file of a download task under the server-suggested name.
+pub const SET_SAVE_AS_SUGGESTED_NAME: u32 = 107;
+/// Changes the priority of a task.
+pub const SET_PRIORITY: u32 = 108;
+/// Moves a task to the front of its application's queue.
+pub const MOVE_TO_FRONT: u32 = 109;
+/// Sets the addresses pinned to hosts and the IP preference of a task.
+pub const SET_TASK_DNS: u32 = 110;
+
+/// Interface version appended to `QUERY`, `QUERY_MIME_TYPE`, `SHOW` and
+/// `TOUCH` requests. Replies carry the server-suggested file name and saved
+/// path from version 1 on; older clients get the replies without them.
+pub const INTERFACE_VERSION: u32 = 1;
 
 #[cfg(test)]
 mod test {
@@ -90,5 +113,14 @@ mod test {
         assert_eq!(20, DELETE_GROUP);
         assert_eq!(100, SET_MODE);
         assert_eq!(101, DISABLE_TASK_NOTIFICATION);
+        assert_eq!(102, QUERY_HISTORY);
+        assert_eq!(103, SET_HISTORY_RETENTION);
+        assert_eq!(104, RETRY_FAILED_FILES);
+        assert_eq!(105, SET_FILE_ERROR_POLICY);
+        assert_eq!(106, SET_CHUNKED_UPLOAD);
+        assert_eq!(107, SET_SAVE_AS_SUGGESTED_NAME);
+        assert_eq!(108, SET_PRIORITY);
+        assert_eq!(109, MOVE_TO_FRONT);
+        assert_eq!(110, SET_TASK_DNS);
     }
 }
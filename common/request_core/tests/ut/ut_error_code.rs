@@ -37,6 +37,7 @@ fn ut_error_code_constants() {
     assert_eq!(TASK_NOT_FOUND, 21900006);
     assert_eq!(TASK_STATE_ERR, 21900007);
     assert_eq!(GROUP_NOT_FOUND, 21900008);
+    assert_eq!(TASK_QUOTA_ERR, 21900009);
 }
 
 // @tc.name: ut_error_code_distinct_values
@@ -64,6 +65,7 @@ fn ut_error_code_distinct_values() {
         TASK_NOT_FOUND,
         TASK_STATE_ERR,
         GROUP_NOT_FOUND,
+        TASK_QUOTA_ERR,
     ];
     
     for i in 0..codes.len() {
@@ -109,7 +111,14 @@ fn ut_error_code_positive_or_zero() {
 // @tc.require: issueNumber
 #[test]
 fn ut_error_code_task_errors_range() {
-    let task_errors = [TASK_ENQUEUE_ERR, TASK_MODE_ERR, TASK_NOT_FOUND, TASK_STATE_ERR, GROUP_NOT_FOUND];
+    let task_errors = [
+        TASK_ENQUEUE_ERR,
+        TASK_MODE_ERR,
+        TASK_NOT_FOUND,
+        TASK_STATE_ERR,
+        GROUP_NOT_FOUND,
+        TASK_QUOTA_ERR,
+    ];
     
     for code in task_errors {
         assert!(code >= 21900000 && code < 22000000, "Task error should be in 219xxxxx range");
@@ -0,0 +1,150 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! DNS messages of RFC 1035, limited to `A` and `AAAA` queries.
+
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// Record type of IPv4 addresses.
+pub(crate) const TYPE_A: u16 = 1;
+/// Record type of IPv6 addresses.
+pub(crate) const TYPE_AAAA: u16 = 28;
+
+const CLASS_IN: u16 = 1;
+const HEADER_LEN: usize = 12;
+const FLAG_RESPONSE: u16 = 0x8000;
+const FLAG_TRUNCATED: u16 = 0x0200;
+const FLAG_RECURSION_DESIRED: u16 = 0x0100;
+const RCODE_NAME_ERROR: u16 = 3;
+
+/// Encodes a recursive query for the records of `qtype` of `name`.
+pub(crate) fn encode_query(id: u16, name: &str, qtype: u16) -> io::Result<Vec<u8>> {
+    let name = name.strip_suffix('.').unwrap_or(name);
+    if name.is_empty() || name.len() > 253 {
+        return Err(invalid_input(name));
+    }
+    let mut message = Vec::with_capacity(HEADER_LEN + name.len() + 6);
+    message.extend_from_slice(&id.to_be_bytes());
+    message.extend_from_slice(&FLAG_RECURSION_DESIRED.to_be_bytes());
+    // One question, no answer, authority or additional records.
+    message.extend_from_slice(&[0, 1, 0, 0, 0, 0, 0, 0]);
+    for label in name.split('.') {
+        if label.is_empty() || label.len() > 63 {
+            return Err(invalid_input(name));
+        }
+        message.push(label.len() as u8);
+        message.extend_from_slice(label.as_bytes());
+    }
+    message.push(0);
+    message.extend_from_slice(&qtype.to_be_bytes());
+    message.extend_from_slice(&CLASS_IN.to_be_bytes());
+    Ok(message)
+}
+
+/// Checks whether a response was truncated and must be asked again over TCP.
+pub(crate) fn is_truncated(message: &[u8]) -> bool {
+    message.len() >= HEADER_LEN && read_u16(message, 2) & FLAG_TRUNCATED != 0
+}
+
+/// Decodes the addresses of `qtype` answering the query `id`.
+///
+/// Records of other types, such as the `CNAME` records leading to the
+/// addresses, are skipped.
+pub(crate) fn decode_response(message: &[u8], id: u16, qtype: u16) -> io::Result<Vec<IpAddr>> {
+    if message.len() < HEADER_LEN || read_u16(message, 0) != id {
+        return Err(invalid_data("unexpected DNS response"));
+    }
+    let flags = read_u16(message, 2);
+    if flags & FLAG_RESPONSE == 0 {
+        return Err(invalid_data("unexpected DNS response"));
+    }
+    match flags & 0x000F {
+        0 => {}
+        RCODE_NAME_ERROR => return Err(io::Error::new(io::ErrorKind::NotFound, "unknown host")),
+        rcode => return Err(invalid_data(&format!("DNS error {}", rcode))),
+    }
+
+    let questions = read_u16(message, 4);
+    let answers = read_u16(message, 6);
+    let mut pos = HEADER_LEN;
+    for _ in 0..questions {
+        pos = skip_name(message, pos)? + 4;
+    }
+
+    let mut addrs = Vec::new();
+    for _ in 0..answers {
+        pos = skip_name(message, pos)?;
+        let fixed = message
+            .get(pos..pos + 10)
+            .ok_or_else(|| invalid_data("truncated DNS record"))?;
+        let rtype = read_u16(fixed, 0);
+        let class = read_u16(fixed, 2);
+        let len = read_u16(fixed, 8) as usize;
+        pos += 10;
+        let data = message
+            .get(pos..pos + len)
+            .ok_or_else(|| invalid_data("truncated DNS record"))?;
+        pos += len;
+        if class != CLASS_IN || rtype != qtype {
+            continue;
+        }
+        match (rtype, data.len()) {
+            (TYPE_A, 4) => {
+                let octets: [u8; 4] = data.try_into().unwrap();
+                addrs.push(IpAddr::V4(Ipv4Addr::from(octets)));
+            }
+            (TYPE_AAAA, 16) => {
+                let octets: [u8; 16] = data.try_into().unwrap();
+                addrs.push(IpAddr::V6(Ipv6Addr::from(octets)));
+            }
+            _ => return Err(invalid_data("invalid DNS address record")),
+        }
+    }
+    Ok(addrs)
+}
+
+/// Skips an encoded name, returning the position after it.
+fn skip_name(message: &[u8], mut pos: usize) -> io::Result<usize> {
+    loop {
+        let len = *message
+            .get(pos)
+            .ok_or_else(|| invalid_data("truncated DNS name"))?;
+        match len {
+            0 => return Ok(pos + 1),
+            // A compression pointer ends the name.
+            len if len & 0xC0 == 0xC0 => return Ok(pos + 2),
+            len => pos += 1 + len as usize,
+        }
+    }
+}
+
+fn read_u16(message: &[u8], pos: usize) -> u16 {
+    u16::from_be_bytes([message[pos], message[pos + 1]])
+}
+
+fn invalid_input(name: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("invalid host name: {}", name),
+    )
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod ut_message {
+    include!("../../tests/ut/dns/ut_message.rs");
+}
@@ -0,0 +1,361 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Custom DNS resolution.
+//!
+//! A `DnsConfig` resolves host names in three steps:
+//!
+//! 1. Static overrides in the style of curl `--resolve`, `host:port:addr[,addr]`,
+//!    pin a host to given addresses without any query.
+//! 2. Otherwise the `DnsServer` is asked: the system resolver, a plain DNS
+//!    server, a DNS over TLS server or a DNS over HTTPS server. The secure
+//!    servers need the `dns_tls` feature.
+//! 3. The addresses are filtered and ordered by the `IpPreference`, then the
+//!    families are interleaved as in happy eyeballs (RFC 8305), so that a
+//!    client trying them in order quickly falls back to the other family.
+//!
+//! Resolution is blocking; asynchronous clients should run it on a blocking
+//! thread.
+
+mod message;
+#[cfg(feature = "dns_tls")]
+mod tls;
+mod transport;
+
+use std::fs::File;
+use std::io::{self, Read};
+use std::net::{IpAddr, SocketAddr, ToSocketAddrs};
+use std::time::Duration;
+
+use message::{decode_response, encode_query, TYPE_A, TYPE_AAAA};
+
+/// Default port of plain DNS servers.
+const DNS_PORT: u16 = 53;
+/// Default port of DNS over TLS servers.
+const DNS_TLS_PORT: u16 = 853;
+
+/// Addresses pinned to a host and port, as `host:port:addr[,addr]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HostOverride {
+    /// Lowercase host name, or `*` for every host.
+    pub host: String,
+    /// Port the override applies to.
+    pub port: u16,
+    /// Addresses of the host, tried in order.
+    pub addrs: Vec<IpAddr>,
+}
+
+impl HostOverride {
+    /// Parses an override in the style of curl `--resolve`, such as
+    /// `cdn.example.com:443:203.0.113.7,[2001:db8::7]`.
+    pub fn parse(value: &str) -> io::Result<Self> {
+        let invalid = || invalid_input("invalid host override", value);
+        let (host, rest) = value.split_once(':').ok_or_else(invalid)?;
+        let (port, addrs) = rest.split_once(':').ok_or_else(invalid)?;
+        let port = port.parse().map_err(|_| invalid())?;
+        let addrs = addrs
+            .split(',')
+            .map(|addr| {
+                let addr = addr.trim();
+                let addr = addr
+                    .strip_prefix('[')
+                    .and_then(|addr| addr.strip_suffix(']'))
+                    .unwrap_or(addr);
+                addr.parse().map_err(|_| invalid())
+            })
+            .collect::<io::Result<Vec<IpAddr>>>()?;
+        if host.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Self {
+            host: host.to_ascii_lowercase(),
+            port,
+            addrs,
+        })
+    }
+
+    fn matches(&self, host: &str, port: u16) -> bool {
+        self.port == port && (self.host == "*" || self.host.eq_ignore_ascii_case(host))
+    }
+}
+
+/// Server answering the queries of a `DnsConfig`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum DnsServer {
+    /// The resolver of the system.
+    #[default]
+    System,
+    /// A plain DNS server, `udp://addr[:port]`.
+    Udp(SocketAddr),
+    /// A DNS over TLS server, `tls://addr[:port][#name]`, verified against
+    /// `name` or else its address.
+    Tls {
+        /// Address of the server.
+        addr: SocketAddr,
+        /// Name of the server certificate.
+        name: String,
+    },
+    /// A DNS over HTTPS server, `https://host[:port]/path`.
+    Https(String),
+}
+
+impl DnsServer {
+    /// Parses a server, `system` or one of the URL forms of the variants.
+    pub fn parse(value: &str) -> io::Result<Self> {
+        let invalid = || invalid_input("invalid DNS server", value);
+        if value == "system" {
+            return Ok(Self::System);
+        }
+        if value.starts_with("https://") {
+            return Ok(Self::Https(value.to_string()));
+        }
+        if let Some(addr) = value.strip_prefix("udp://") {
+            return parse_addr(addr, DNS_PORT)
+                .map(Self::Udp)
+                .ok_or_else(invalid);
+        }
+        if let Some(rest) = value.strip_prefix("tls://") {
+            let (addr, name) = match rest.split_once('#') {
+                Some((addr, name)) if !name.is_empty() => (addr, Some(name)),
+                Some(_) => return Err(invalid()),
+                None => (rest, None),
+            };
+            let addr = parse_addr(addr, DNS_TLS_PORT).ok_or_else(invalid)?;
+            let name = name.map_or_else(|| addr.ip().to_string(), str::to_string);
+            return Ok(Self::Tls { addr, name });
+        }
+        Err(invalid())
+    }
+}
+
+/// Address families to use and which one to try first.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IpPreference {
+    /// Both families, starting with the family of the first address found.
+    #[default]
+    Any,
+    /// IPv4 addresses only.
+    Ipv4,
+    /// IPv6 addresses only.
+    Ipv6,
+    /// Both families, starting with IPv4.
+    Ipv4First,
+    /// Both families, starting with IPv6.
+    Ipv6First,
+}
+
+impl IpPreference {
+    /// Parses a preference: `any`, `ipv4`, `ipv6`, `ipv4_first` or
+    /// `ipv6_first`.
+    pub fn parse(value: &str) -> io::Result<Self> {
+        match value {
+            "any" => Ok(Self::Any),
+            "ipv4" => Ok(Self::Ipv4),
+            "ipv6" => Ok(Self::Ipv6),
+            "ipv4_first" => Ok(Self::Ipv4First),
+            "ipv6_first" => Ok(Self::Ipv6First),
+            _ => Err(invalid_input("invalid IP preference", value)),
+        }
+    }
+}
+
+/// DNS options of a request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DnsConfig {
+    /// Static addresses of hosts, the first match wins.
+    pub overrides: Vec<HostOverride>,
+    /// Server asked for hosts without override.
+    pub server: DnsServer,
+    /// Address families to use and which one to try first.
+    pub preference: IpPreference,
+    /// Number of addresses of the first family tried before the other
+    /// family, the "First Address Family Count" of RFC 8305.
+    pub first_family_count: usize,
+    /// Time allowed to each query.
+    pub timeout: Duration,
+    /// Extra CA certificates in PEM trusted for DNS over TLS and HTTPS.
+    pub root_certificates: Vec<String>,
+}
+
+impl Default for DnsConfig {
+    fn default() -> Self {
+        Self {
+            overrides: Vec::new(),
+            server: DnsServer::System,
+            preference: IpPreference::Any,
+            first_family_count: 1,
+            timeout: Duration::from_secs(5),
+            root_certificates: Vec::new(),
+        }
+    }
+}
+
+impl DnsConfig {
+    /// Checks whether the config resolves as the system does, so that
+    /// clients can keep their own resolver.
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Resolves an authority, `host:port` or `[v6]:port`.
+    pub fn resolve_authority(&self, authority: &str) -> io::Result<Vec<SocketAddr>> {
+        let invalid = || invalid_input("invalid authority", authority);
+        let (host, port) = authority.rsplit_once(':').ok_or_else(invalid)?;
+        let port = port.parse().map_err(|_| invalid())?;
+        let host = host
+            .strip_prefix('[')
+            .and_then(|host| host.strip_suffix(']'))
+            .unwrap_or(host);
+        self.resolve(host, port)
+    }
+
+    /// Resolves a host to the addresses to try, in order.
+    pub fn resolve(&self, host: &str, port: u16) -> io::Result<Vec<SocketAddr>> {
+        let ips = match self.overrides.iter().find(|o| o.matches(host, port)) {
+            Some(o) => o.addrs.clone(),
+            None => match host.parse::<IpAddr>() {
+                Ok(ip) => vec![ip],
+                Err(_) => self.query(host, port)?,
+            },
+        };
+        let addrs: Vec<_> = ips
+            .into_iter()
+            .map(|ip| SocketAddr::new(ip, port))
+            .collect();
+        let addrs = order_addrs(addrs, self.preference, self.first_family_count);
+        if addrs.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no address of {}", host),
+            ));
+        }
+        Ok(addrs)
+    }
+
+    fn query(&self, host: &str, port: u16) -> io::Result<Vec<IpAddr>> {
+        if self.server == DnsServer::System {
+            return Ok((host, port).to_socket_addrs()?.map(|a| a.ip()).collect());
+        }
+        let qtypes: &[u16] = match self.preference {
+            IpPreference::Ipv4 => &[TYPE_A],
+            IpPreference::Ipv6 => &[TYPE_AAAA],
+            IpPreference::Ipv6First => &[TYPE_AAAA, TYPE_A],
+            _ => &[TYPE_A, TYPE_AAAA],
+        };
+        let mut ips = Vec::new();
+        let mut last_err = None;
+        for &qtype in qtypes {
+            match self.exchange(host, qtype) {
+                Ok(found) => ips.extend(found),
+                Err(e) => last_err = Some(e),
+            }
+        }
+        match last_err {
+            Some(e) if ips.is_empty() => Err(e),
+            _ => Ok(ips),
+        }
+    }
+
+    fn exchange(&self, host: &str, qtype: u16) -> io::Result<Vec<IpAddr>> {
+        // DNS over HTTPS uses the id 0 so that responses can be cached.
+        let id = match self.server {
+            DnsServer::Https(_) => 0,
+            _ => random_id()?,
+        };
+        let query = encode_query(id, host, qtype)?;
+        let response = match &self.server {
+            DnsServer::System => unreachable!(),
+            DnsServer::Udp(addr) => transport::query(*addr, &query, self.timeout)?,
+            #[cfg(feature = "dns_tls")]
+            DnsServer::Tls { addr, name } => {
+                tls::tls_query(*addr, name, &query, self.timeout, &self.root_certificates)?
+            }
+            #[cfg(feature = "dns_tls")]
+            DnsServer::Https(url) => {
+                tls::https_query(url, &query, self.timeout, &self.root_certificates)?
+            }
+            #[cfg(not(feature = "dns_tls"))]
+            DnsServer::Tls { .. } | DnsServer::Https(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "secure DNS needs the dns_tls feature",
+                ))
+            }
+        };
+        decode_response(&response, id, qtype)
+    }
+}
+
+/// Draws the ID of a query from the system CSPRNG, so that off-path
+/// attackers cannot guess it to spoof responses.
+fn random_id() -> io::Result<u16> {
+    let mut bytes = [0u8; 2];
+    File::open("/dev/urandom")?.read_exact(&mut bytes)?;
+    Ok(u16::from_ne_bytes(bytes))
+}
+
+/// Filters addresses by preference and interleaves their families.
+///
+/// The first family gets `first_family_count` addresses in a row, then the
+/// families alternate while both have addresses left. Each family keeps its
+/// own order.
+pub fn order_addrs(
+    addrs: Vec<SocketAddr>,
+    preference: IpPreference,
+    first_family_count: usize,
+) -> Vec<SocketAddr> {
+    let v6_first = match preference {
+        IpPreference::Ipv4First => false,
+        IpPreference::Ipv6First => true,
+        _ => addrs.first().is_some_and(SocketAddr::is_ipv6),
+    };
+    let (v4, v6): (Vec<_>, Vec<_>) = addrs.into_iter().partition(SocketAddr::is_ipv4);
+    match preference {
+        IpPreference::Ipv4 => return v4,
+        IpPreference::Ipv6 => return v6,
+        _ => {}
+    }
+    let (first, second) = if v6_first { (v6, v4) } else { (v4, v6) };
+
+    let mut ordered = Vec::with_capacity(first.len() + second.len());
+    let mut first = first.into_iter();
+    let mut second = second.into_iter();
+    ordered.extend(first.by_ref().take(first_family_count.max(1)));
+    loop {
+        match (second.next(), first.next()) {
+            (None, None) => break,
+            (a, b) => ordered.extend(a.into_iter().chain(b)),
+        }
+    }
+    ordered
+}
+
+fn parse_addr(value: &str, default_port: u16) -> Option<SocketAddr> {
+    value
+        .parse::<SocketAddr>()
+        .ok()
+        .or_else(|| Some(SocketAddr::new(value.parse().ok()?, default_port)))
+        .or_else(|| {
+            let ip = value.strip_prefix('[')?.strip_suffix(']')?.parse().ok()?;
+            Some(SocketAddr::new(ip, default_port))
+        })
+}
+
+fn invalid_input(msg: &str, value: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, format!("{}: {}", msg, value))
+}
+
+#[cfg(test)]
+mod ut_mod {
+    include!("../../tests/ut/dns/ut_mod.rs");
+}
@@ -0,0 +1,183 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! DNS over TLS (RFC 7858) and DNS over HTTPS (RFC 8484).
+//!
+//! Servers are verified against the system CA certificates and the extra
+//! certificates of the `DnsConfig`. DNS over HTTPS sends one `POST` per query
+//! on a new connection; the host of its URL, unless an IP address, is
+//! resolved by the system resolver.
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+use openssl::ssl::{SslConnector, SslMethod, SslStream, SslVersion};
+use openssl::x509::X509;
+
+use super::transport::stream_query;
+
+/// Largest DNS over HTTPS response accepted.
+const MAX_HTTPS_RESPONSE: usize = 64 * 1024;
+
+/// Sends a query to a DNS over TLS server.
+pub(crate) fn tls_query(
+    addr: SocketAddr,
+    name: &str,
+    query: &[u8],
+    timeout: Duration,
+    roots: &[String],
+) -> io::Result<Vec<u8>> {
+    let mut stream = connect(&addr, name, timeout, roots)?;
+    stream_query(&mut stream, query)
+}
+
+/// Sends a query to a DNS over HTTPS server.
+pub(crate) fn https_query(
+    url: &str,
+    query: &[u8],
+    timeout: Duration,
+    roots: &[String],
+) -> io::Result<Vec<u8>> {
+    let (authority, path) = url
+        .strip_prefix("https://")
+        .map(|rest| rest.split_at(rest.find('/').unwrap_or(rest.len())))
+        .ok_or_else(|| invalid_input(url))?;
+    let path = if path.is_empty() { "/" } else { path };
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) if !port.contains(']') => {
+            (host, port.parse().map_err(|_| invalid_input(url))?)
+        }
+        _ => (authority, 443),
+    };
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    let addr = (host, port)
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| invalid_input(url))?;
+
+    let mut stream = connect(&addr, host, timeout, roots)?;
+    let head = format!(
+        "POST {} HTTP/1.1\r\nHost: {}\r\nAccept: application/dns-message\r\n\
+         Content-Type: application/dns-message\r\nContent-Length: {}\r\n\
+         Connection: close\r\n\r\n",
+        path,
+        authority,
+        query.len()
+    );
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(query)?;
+    stream.flush()?;
+    read_response(BufReader::new(stream))
+}
+
+fn connect(
+    addr: &SocketAddr,
+    name: &str,
+    timeout: Duration,
+    roots: &[String],
+) -> io::Result<SslStream<TcpStream>> {
+    let mut builder = SslConnector::builder(SslMethod::tls_client()).map_err(io::Error::other)?;
+    builder
+        .set_min_proto_version(Some(SslVersion::TLS1_2))
+        .map_err(io::Error::other)?;
+    for root in roots {
+        let cert = X509::from_pem(root.as_bytes()).map_err(io::Error::other)?;
+        builder
+            .cert_store_mut()
+            .add_cert(cert)
+            .map_err(io::Error::other)?;
+    }
+    let connector = builder.build();
+
+    let stream = TcpStream::connect_timeout(addr, timeout)?;
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))?;
+    connector.connect(name, stream).map_err(io::Error::other)
+}
+
+/// Reads the body of a `200 OK` response, sent with a length, chunked or up
+/// to the end of the connection.
+fn read_response<R: Read>(mut reader: BufReader<R>) -> io::Result<Vec<u8>> {
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    if line.split_whitespace().nth(1) != Some("200") {
+        return Err(io::Error::other(format!(
+            "DNS over HTTPS failed: {}",
+            line.trim_end()
+        )));
+    }
+    let mut length = None;
+    let mut chunked = false;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 || line == "\r\n" || line == "\n" {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            let value = value.trim();
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value.parse::<usize>().ok();
+            } else if name.eq_ignore_ascii_case("transfer-encoding") {
+                chunked = value.eq_ignore_ascii_case("chunked");
+            }
+        }
+    }
+
+    let mut body = Vec::new();
+    if chunked {
+        loop {
+            line.clear();
+            reader.read_line(&mut line)?;
+            let size = line.trim().split(';').next().unwrap_or_default();
+            let size = usize::from_str_radix(size, 16).map_err(|_| invalid_data())?;
+            if size == 0 {
+                break;
+            }
+            let start = body.len();
+            let end = match start.checked_add(size) {
+                Some(end) if end <= MAX_HTTPS_RESPONSE => end,
+                _ => return Err(invalid_data()),
+            };
+            body.resize(end, 0);
+            reader.read_exact(&mut body[start..])?;
+            line.clear();
+            reader.read_line(&mut line)?;
+        }
+    } else if let Some(length) = length {
+        if length > MAX_HTTPS_RESPONSE {
+            return Err(invalid_data());
+        }
+        body.resize(length, 0);
+        reader.read_exact(&mut body)?;
+    } else {
+        reader
+            .take(MAX_HTTPS_RESPONSE as u64)
+            .read_to_end(&mut body)?;
+    }
+    Ok(body)
+}
+
+fn invalid_input(url: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("invalid DNS over HTTPS URL: {}", url),
+    )
+}
+
+fn invalid_data() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "invalid DNS over HTTPS response",
+    )
+}
@@ -0,0 +1,69 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Plain DNS over UDP with a TCP fallback for truncated responses.
+
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, TcpStream, UdpSocket};
+use std::time::Duration;
+
+use super::message::is_truncated;
+
+/// Largest response accepted over UDP.
+const MAX_UDP_RESPONSE: usize = 4096;
+
+/// Sends a query over UDP, asking again over TCP if the response was
+/// truncated.
+pub(crate) fn query(server: SocketAddr, query: &[u8], timeout: Duration) -> io::Result<Vec<u8>> {
+    let local: SocketAddr = match server {
+        SocketAddr::V4(_) => ([0, 0, 0, 0], 0).into(),
+        SocketAddr::V6(_) => ([0u16; 8], 0).into(),
+    };
+    let socket = UdpSocket::bind(local)?;
+    socket.set_read_timeout(Some(timeout))?;
+    socket.connect(server)?;
+    socket.send(query)?;
+
+    let mut response = vec![0; MAX_UDP_RESPONSE];
+    loop {
+        let len = socket.recv(&mut response)?;
+        // Ignores stray datagrams of other queries.
+        if len >= 2 && response[..2] == query[..2] {
+            response.truncate(len);
+            break;
+        }
+    }
+    if !is_truncated(&response) {
+        return Ok(response);
+    }
+    let mut stream = TcpStream::connect_timeout(&server, timeout)?;
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))?;
+    stream_query(&mut stream, query)
+}
+
+/// Sends a query over a stream, prefixed by its length as over TCP.
+pub(crate) fn stream_query<S: Read + Write>(stream: &mut S, query: &[u8]) -> io::Result<Vec<u8>> {
+    let len = u16::try_from(query.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "DNS query too long"))?;
+    let mut message = len.to_be_bytes().to_vec();
+    message.extend_from_slice(query);
+    stream.write_all(&message)?;
+    stream.flush()?;
+
+    let mut len = [0; 2];
+    stream.read_exact(&mut len)?;
+    let mut response = vec![0; u16::from_be_bytes(len) as usize];
+    stream.read_exact(&mut response)?;
+    Ok(response)
+}
@@ -25,6 +25,9 @@
 #[macro_use]
 mod macros;
 
+/// Custom DNS resolution.
+pub mod dns;
+
 /// Fast pseudorandom number generation utilities.
 pub mod fastrand;
 
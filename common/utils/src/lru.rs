@@ -240,6 +240,27 @@ impl<K: Hash + Eq + Clone, V> LRUCache<K, V> {
         None
     }
 
+    /// Returns a reference to the value corresponding to the key if it exists.
+    ///
+    /// Unlike [`LRUCache::get`], the access order is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use request_utils::lru::LRUCache;
+    ///
+    /// let mut cache = LRUCache::new();
+    /// cache.insert(1, "one");
+    /// cache.insert(2, "two");
+    ///
+    /// assert_eq!(cache.peek(&1), Some(&"one"));
+    /// // Key 1 is still the least recently used
+    /// assert_eq!(cache.pop(), Some("one"));
+    /// ```
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.map.get(key).map(|&node| unsafe { &(*node).value })
+    }
+
     /// Inserts a key-value pair into the cache.
     ///
     /// If the key already exists, updates the value and moves the key to the
@@ -0,0 +1,365 @@
+// Copyright (C) 2024 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! HTTP test servers.
+//!
+//! `test_server` spawns a local TCP listener on an OS-assigned port and
+//! dispatches the incoming request lines to a caller-supplied handler, then
+//! replies with a fixed `200 OK` response.
+//!
+//! `MockServer` serves scripted responses by route, so that download and
+//! upload tests can exercise resume, retry and error paths without an
+//! external network: sequences of statuses, redirects, chunked and throttled
+//! bodies, `Range` requests and connections closed in the middle of a body.
+//! Every request is recorded for assertions. With the `test_tls` feature the
+//! server can also speak HTTPS with a certificate signed by a test CA.
+
+mod request;
+mod response;
+#[cfg(feature = "test_tls")]
+mod tls;
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Lines, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+pub use request::RecordedRequest;
+pub use response::MockResponse;
+#[cfg(feature = "test_tls")]
+pub use tls::TEST_CA_PEM;
+
+pub fn test_server<F>(f: F) -> String
+where
+    F: FnOnce(Lines<BufReader<&mut TcpStream>>) + Send + 'static,
+{
+    let server = "127.0.0.1";
+    // let the OS assigns a port to this listener.
+    let port = 0;
+    let listener = TcpListener::bind((server, port)).unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    std::thread::spawn(move || {
+        let stream = listener.incoming().next().unwrap().unwrap();
+        handle_connection(stream, f);
+    });
+    format!("http://{}", addr.as_str())
+}
+
+fn handle_connection<F>(mut stream: TcpStream, task_f: F)
+where
+    F: FnOnce(Lines<BufReader<&mut TcpStream>>),
+{
+    let buf_reader = BufReader::new(&mut stream);
+    let lines = buf_reader.lines();
+    task_f(lines);
+    let response = "HTTP/1.1 200 OK\r\n\r\n";
+    stream.write_all(response.as_bytes()).unwrap();
+}
+
+/// Responses of a route, served in order; the last one is repeated.
+struct Script {
+    method: Option<String>,
+    // Responses served once each, before `last`
+    sequence: Vec<MockResponse>,
+    // Response repeated once the sequence is served
+    last: MockResponse,
+    served: usize,
+}
+
+impl Script {
+    /// Builds the script of the route `path`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `responses` is empty, a route has to answer something.
+    fn new(method: Option<String>, path: &str, mut responses: Vec<MockResponse>) -> Self {
+        let Some(last) = responses.pop() else {
+            panic!("route {} without response", path);
+        };
+        Self {
+            method,
+            sequence: responses,
+            last,
+            served: 0,
+        }
+    }
+
+    /// Gets the response to the next request.
+    fn next(&mut self) -> &MockResponse {
+        let index = self.served;
+        self.served += 1;
+        self.sequence.get(index).unwrap_or(&self.last)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    routes: HashMap<String, Script>,
+    requests: Vec<RecordedRequest>,
+}
+
+#[derive(Default)]
+struct Shared {
+    state: Mutex<State>,
+    received: Condvar,
+    closed: AtomicBool,
+}
+
+impl Shared {
+    fn respond(&self, request: RecordedRequest) -> MockResponse {
+        let mut state = self.state.lock().unwrap();
+        let response = match state.routes.get_mut(request.path()) {
+            Some(script)
+                if script
+                    .method
+                    .as_ref()
+                    .is_none_or(|method| method.eq_ignore_ascii_case(&request.method)) =>
+            {
+                script.next().for_request(&request)
+            }
+            Some(_) => MockResponse::new(405),
+            None => MockResponse::new(404),
+        };
+        state.requests.push(request);
+        self.received.notify_all();
+        response
+    }
+}
+
+/// A local HTTP server serving scripted responses.
+///
+/// Routes match the path of the request, without its query string. Paths
+/// without a route are answered with `404 Not Found`. Every connection
+/// serves one request and is then closed. The server stops when dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use request_utils::test::server::{MockResponse, MockServer};
+///
+/// let server = MockServer::start();
+/// server.route(
+///     "/file",
+///     [MockResponse::new(503), MockResponse::ok("hello").accept_ranges()],
+/// );
+/// let url = server.url("/file");
+/// // The first request to `url` gets 503, the next ones the file.
+/// # drop(url);
+/// server.assert_hits("/file", 0);
+/// ```
+pub struct MockServer {
+    addr: SocketAddr,
+    scheme: &'static str,
+    shared: Arc<Shared>,
+}
+
+impl MockServer {
+    /// Starts a plain HTTP server on an OS-assigned loopback port.
+    pub fn start() -> Self {
+        Self::spawn("http", serve::<TcpStream>)
+    }
+
+    /// Starts an HTTPS server on an OS-assigned loopback port.
+    ///
+    /// Its certificate is valid for `127.0.0.1` and `localhost` and signed by
+    /// the test CA in `TEST_CA_PEM`, which clients must trust.
+    #[cfg(feature = "test_tls")]
+    pub fn start_tls() -> Self {
+        let acceptor = Arc::new(tls::acceptor());
+        Self::spawn("https", move |stream, shared| {
+            if let Ok(stream) = acceptor.accept(stream) {
+                serve(stream, shared);
+            }
+        })
+    }
+
+    fn spawn<F>(scheme: &'static str, handle: F) -> Self
+    where
+        F: Fn(TcpStream, &Shared) + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let addr = listener.local_addr().unwrap();
+        let shared = Arc::new(Shared::default());
+        let handle = Arc::new(handle);
+        let server = shared.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                if server.closed.load(Ordering::Acquire) {
+                    break;
+                }
+                let Ok(stream) = stream else {
+                    continue;
+                };
+                let shared = server.clone();
+                let handle = handle.clone();
+                std::thread::spawn(move || handle(stream, &shared));
+            }
+        });
+        Self {
+            addr,
+            scheme,
+            shared,
+        }
+    }
+
+    /// Gets the URL of `path` on this server, such as
+    /// `http://127.0.0.1:12345/file`.
+    pub fn url(&self, path: &str) -> String {
+        format!("{}://{}{}", self.scheme, self.addr, path)
+    }
+
+    /// Gets the address the server listens on.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Serves `responses` in order to the requests of `path`, whatever their
+    /// method, repeating the last one. Replaces any previous route of `path`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `responses` is empty.
+    pub fn route(&self, path: &str, responses: impl IntoIterator<Item = MockResponse>) {
+        self.add_route(None, path, responses.into_iter().collect());
+    }
+
+    /// Same as `route`, but answers the other methods with
+    /// `405 Method Not Allowed`.
+    pub fn route_method(
+        &self,
+        method: &str,
+        path: &str,
+        responses: impl IntoIterator<Item = MockResponse>,
+    ) {
+        self.add_route(
+            Some(method.to_string()),
+            path,
+            responses.into_iter().collect(),
+        );
+    }
+
+    fn add_route(&self, method: Option<String>, path: &str, responses: Vec<MockResponse>) {
+        let script = Script::new(method, path, responses);
+        let mut state = self.shared.state.lock().unwrap();
+        state.routes.insert(path.to_string(), script);
+    }
+
+    /// Gets the requests received so far, in order.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.shared.state.lock().unwrap().requests.clone()
+    }
+
+    /// Gets the number of requests received for `path`.
+    pub fn hits(&self, path: &str) -> usize {
+        let state = self.shared.state.lock().unwrap();
+        state
+            .requests
+            .iter()
+            .filter(|request| request.path() == path)
+            .count()
+    }
+
+    /// Waits until `count` requests in total have been received.
+    ///
+    /// # Returns
+    ///
+    /// `false` if `timeout` elapsed first.
+    pub fn wait_for_requests(&self, count: usize, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        let mut state = self.shared.state.lock().unwrap();
+        while state.requests.len() < count {
+            let now = Instant::now();
+            if now >= deadline {
+                return false;
+            }
+            state = self
+                .shared
+                .received
+                .wait_timeout(state, deadline - now)
+                .unwrap()
+                .0;
+        }
+        true
+    }
+
+    /// Asserts that `path` was requested exactly `count` times.
+    ///
+    /// # Panics
+    ///
+    /// Panics listing the received requests otherwise.
+    pub fn assert_hits(&self, path: &str, count: usize) {
+        let hits = self.hits(path);
+        assert_eq!(
+            hits,
+            count,
+            "{} requested {} times, requests: {:?}",
+            path,
+            hits,
+            self.request_lines()
+        );
+    }
+
+    /// Asserts that every request of `path` had the header `name` set to
+    /// `value`, and that there was at least one.
+    ///
+    /// # Panics
+    ///
+    /// Panics listing the received requests otherwise.
+    pub fn assert_header(&self, path: &str, name: &str, value: &str) {
+        let requests = self.requests();
+        let mut matching = requests.iter().filter(|request| request.path() == path);
+        let all = matching
+            .clone()
+            .all(|request| request.header(name) == Some(value));
+        assert!(
+            matching.next().is_some() && all,
+            "{} not always requested with {}: {}, requests: {:?}",
+            path,
+            name,
+            value,
+            requests
+        );
+    }
+
+    fn request_lines(&self) -> Vec<String> {
+        self.requests()
+            .iter()
+            .map(|request| format!("{} {}", request.method, request.target))
+            .collect()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.shared.closed.store(true, Ordering::Release);
+        // Wakes the accept loop so that it sees the flag.
+        let _ = TcpStream::connect(self.addr);
+    }
+}
+
+fn serve<S: Read + Write>(stream: S, shared: &Shared) {
+    let mut reader = BufReader::new(stream);
+    let request = match RecordedRequest::read(&mut reader) {
+        Ok(Some(request)) => request,
+        _ => return,
+    };
+    let response = shared.respond(request);
+    let _ = response.write_to(reader.get_mut());
+}
+
+#[cfg(test)]
+mod ut_mock_server {
+    include!("../../../tests/ut/test/server/ut_mock_server.rs");
+}
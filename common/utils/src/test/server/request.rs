@@ -0,0 +1,129 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Requests received by the mock server.
+
+use std::io::{self, BufRead, Read, Write};
+
+/// A request received by the mock server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordedRequest {
+    /// Request method, such as `GET`.
+    pub method: String,
+    /// Request target, including the query string.
+    pub target: String,
+    /// Headers in the order they were received.
+    pub headers: Vec<(String, String)>,
+    /// Request body, after removing the chunked framing if any.
+    pub body: Vec<u8>,
+}
+
+impl RecordedRequest {
+    /// Gets the path of the target, without the query string.
+    pub fn path(&self) -> &str {
+        self.target.split('?').next().unwrap_or_default()
+    }
+
+    /// Gets the first value of a header, matching its name case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Reads a request from a connection.
+    ///
+    /// Answers `Expect: 100-continue` before reading the body.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(None)` if the peer closed the connection before sending a request.
+    pub(crate) fn read<S: Read + Write>(reader: &mut io::BufReader<S>) -> io::Result<Option<Self>> {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let mut parts = line.split_whitespace();
+        let method = parts.next().unwrap_or_default().to_string();
+        let target = parts.next().unwrap_or("/").to_string();
+
+        let mut headers = Vec::new();
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 {
+                break;
+            }
+            let header = line.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if let Some((key, value)) = header.split_once(':') {
+                headers.push((key.trim().to_string(), value.trim().to_string()));
+            }
+        }
+
+        let mut request = Self {
+            method,
+            target,
+            headers,
+            body: Vec::new(),
+        };
+        if request
+            .header("Expect")
+            .is_some_and(|value| value.eq_ignore_ascii_case("100-continue"))
+        {
+            reader
+                .get_mut()
+                .write_all(b"HTTP/1.1 100 Continue\r\n\r\n")?;
+        }
+        let chunked = request
+            .header("Transfer-Encoding")
+            .is_some_and(|value| value.eq_ignore_ascii_case("chunked"));
+        if chunked {
+            request.body = read_chunked(reader)?;
+        } else if let Some(len) = request.header("Content-Length") {
+            let len = len.parse::<usize>().unwrap_or(0);
+            let mut body = vec![0; len];
+            reader.read_exact(&mut body)?;
+            request.body = body;
+        }
+        Ok(Some(request))
+    }
+}
+
+fn read_chunked<R: BufRead>(reader: &mut R) -> io::Result<Vec<u8>> {
+    let mut body = Vec::new();
+    let mut line = String::new();
+    loop {
+        line.clear();
+        reader.read_line(&mut line)?;
+        let size = line.trim().split(';').next().unwrap_or_default();
+        let size = usize::from_str_radix(size, 16)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "bad chunk size"))?;
+        if size == 0 {
+            // Skips the trailers.
+            loop {
+                line.clear();
+                if reader.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+                    return Ok(body);
+                }
+            }
+        }
+        let start = body.len();
+        body.resize(start + size, 0);
+        reader.read_exact(&mut body[start..])?;
+        line.clear();
+        reader.read_line(&mut line)?;
+    }
+}
@@ -0,0 +1,250 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scripted responses of the mock server.
+
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+use super::request::RecordedRequest;
+
+/// A response served by the mock server.
+///
+/// Besides the status, headers and body, a response describes how it is
+/// sent: chunked, throttled, cut after some bytes of body, or answering
+/// `Range` requests with partial content.
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+    delay: Option<Duration>,
+    chunk_size: Option<usize>,
+    throttle: Option<(usize, Duration)>,
+    disconnect_after: Option<usize>,
+    ranges: bool,
+}
+
+impl MockResponse {
+    /// Creates an empty response with the given status code.
+    pub fn new(status: u16) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: Vec::new(),
+            delay: None,
+            chunk_size: None,
+            throttle: None,
+            disconnect_after: None,
+            ranges: false,
+        }
+    }
+
+    /// Creates a `200 OK` response with the given body.
+    pub fn ok(body: impl Into<Vec<u8>>) -> Self {
+        Self::new(200).body(body)
+    }
+
+    /// Creates a redirect to `location` with the given status code, such as
+    /// 301, 302, 307 or 308.
+    pub fn redirect(status: u16, location: &str) -> Self {
+        Self::new(status).header("Location", location)
+    }
+
+    /// Adds a header.
+    pub fn header(mut self, key: &str, value: &str) -> Self {
+        self.headers.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Sets the body.
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Waits before sending the status line, to trigger client timeouts.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Sends the body with `Transfer-Encoding: chunked`, in chunks of
+    /// `size` bytes.
+    pub fn chunked(mut self, size: usize) -> Self {
+        self.chunk_size = Some(size.max(1));
+        self
+    }
+
+    /// Sends the body `bytes` bytes at a time, waiting `interval` after each
+    /// write.
+    pub fn throttle(mut self, bytes: usize, interval: Duration) -> Self {
+        self.throttle = Some((bytes.max(1), interval));
+        self
+    }
+
+    /// Closes the connection after sending `bytes` bytes of body.
+    pub fn disconnect_after(mut self, bytes: usize) -> Self {
+        self.disconnect_after = Some(bytes);
+        self
+    }
+
+    /// Answers single-range `Range` requests with `206 Partial Content`, or
+    /// `416 Range Not Satisfiable`, and advertises `Accept-Ranges: bytes`.
+    pub fn accept_ranges(mut self) -> Self {
+        self.ranges = true;
+        self
+    }
+
+    /// Gets the status code.
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// Applies the `Range` header of the request, if ranges are accepted.
+    pub(crate) fn for_request(&self, request: &RecordedRequest) -> Self {
+        let mut response = self.clone();
+        if !self.ranges || self.status != 200 {
+            return response;
+        }
+        response
+            .headers
+            .push(("Accept-Ranges".into(), "bytes".into()));
+        let Some(range) = request.header("Range") else {
+            return response;
+        };
+        let total = self.body.len();
+        match parse_range(range, total) {
+            Some((start, end)) => {
+                response.status = 206;
+                response.headers.push((
+                    "Content-Range".into(),
+                    format!("bytes {}-{}/{}", start, end, total),
+                ));
+                response.body = self.body[start..=end].to_vec();
+            }
+            None => {
+                response.status = 416;
+                response
+                    .headers
+                    .push(("Content-Range".into(), format!("bytes */{}", total)));
+                response.body.clear();
+            }
+        }
+        response
+    }
+
+    /// Writes the response, then closes the connection by returning.
+    pub(crate) fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        if let Some(delay) = self.delay {
+            thread::sleep(delay);
+        }
+        let mut head = format!("HTTP/1.1 {} {}\r\n", self.status, reason(self.status));
+        for (key, value) in self.headers.iter() {
+            head.push_str(&format!("{}: {}\r\n", key, value));
+        }
+        if self.chunk_size.is_some() {
+            head.push_str("Transfer-Encoding: chunked\r\n");
+        } else {
+            head.push_str(&format!("Content-Length: {}\r\n", self.body.len()));
+        }
+        head.push_str("Connection: close\r\n\r\n");
+        writer.write_all(head.as_bytes())?;
+
+        let segment = match (self.chunk_size, self.throttle) {
+            (Some(chunk), Some((bytes, _))) => chunk.min(bytes),
+            (Some(chunk), None) => chunk,
+            (None, Some((bytes, _))) => bytes,
+            (None, None) => self.body.len().max(1),
+        };
+        let limit = self.disconnect_after.unwrap_or(usize::MAX);
+        let mut sent = 0;
+        for piece in self.body.chunks(segment) {
+            let piece = &piece[..piece.len().min(limit - sent)];
+            if !piece.is_empty() {
+                if self.chunk_size.is_some() {
+                    writer.write_all(format!("{:x}\r\n", piece.len()).as_bytes())?;
+                    writer.write_all(piece)?;
+                    writer.write_all(b"\r\n")?;
+                } else {
+                    writer.write_all(piece)?;
+                }
+                writer.flush()?;
+                sent += piece.len();
+            }
+            if sent == limit {
+                return Ok(());
+            }
+            if let Some((_, interval)) = self.throttle {
+                thread::sleep(interval);
+            }
+        }
+        if self.chunk_size.is_some() {
+            writer.write_all(b"0\r\n\r\n")?;
+        }
+        writer.flush()
+    }
+}
+
+/// Parses a single `bytes=` range against a body of `total` bytes.
+///
+/// # Returns
+///
+/// The inclusive bounds of the range, or `None` if it is not satisfiable.
+fn parse_range(range: &str, total: usize) -> Option<(usize, usize)> {
+    let spec = range.trim().strip_prefix("bytes=")?;
+    if spec.contains(',') || total == 0 {
+        return None;
+    }
+    let (start, end) = spec.split_once('-')?;
+    let (start, end) = match (start.trim(), end.trim()) {
+        ("", suffix) => {
+            let suffix = suffix.parse::<usize>().ok()?;
+            (total.saturating_sub(suffix), total - 1)
+        }
+        (start, "") => (start.parse().ok()?, total - 1),
+        (start, end) => (
+            start.parse().ok()?,
+            end.parse::<usize>().ok()?.min(total - 1),
+        ),
+    };
+    (start <= end && start < total).then_some((start, end))
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        100 => "Continue",
+        200 => "OK",
+        201 => "Created",
+        204 => "No Content",
+        206 => "Partial Content",
+        301 => "Moved Permanently",
+        302 => "Found",
+        303 => "See Other",
+        304 => "Not Modified",
+        307 => "Temporary Redirect",
+        308 => "Permanent Redirect",
+        400 => "Bad Request",
+        403 => "Forbidden",
+        404 => "Not Found",
+        408 => "Request Timeout",
+        416 => "Range Not Satisfiable",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => "Unknown",
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! TLS for the mock server.
+//!
+//! The certificates in `tests/certs` are for tests only: `ca.pem` is a
+//! self-signed CA whose key was discarded, `server.pem` is signed by it for
+//! `127.0.0.1` and `localhost`. To renew them, create a new CA with
+//! `openssl req -x509`, then sign a new server key with `openssl x509 -req`
+//! and a `subjectAltName = IP:127.0.0.1, DNS:localhost` extension.
+
+use openssl::pkey::PKey;
+use openssl::ssl::{SslAcceptor, SslMethod};
+use openssl::x509::X509;
+
+/// Certificate of the test CA, in PEM, to be trusted by the clients of a
+/// `MockServer` started with `start_tls`.
+pub const TEST_CA_PEM: &str = include_str!("../../../tests/certs/ca.pem");
+
+const SERVER_PEM: &str = include_str!("../../../tests/certs/server.pem");
+const SERVER_KEY: &str = include_str!("../../../tests/certs/server.key");
+
+/// Builds the acceptor of the mock server.
+pub(crate) fn acceptor() -> SslAcceptor {
+    let cert = X509::from_pem(SERVER_PEM.as_bytes()).unwrap();
+    let key = PKey::private_key_from_pem(SERVER_KEY.as_bytes()).unwrap();
+    let mut builder = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls_server()).unwrap();
+    builder.set_certificate(&cert).unwrap();
+    builder.set_private_key(&key).unwrap();
+    builder.check_private_key().unwrap();
+    builder.build()
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Builds the response to `query` with a `CNAME` record followed by `addrs`,
+/// their names compressed as pointers to the question.
+pub(crate) fn answer(query: &[u8], flags: u16, addrs: &[IpAddr]) -> Vec<u8> {
+    let mut message = query.to_vec();
+    message[2..4].copy_from_slice(&(0x8180 | flags).to_be_bytes());
+    message[6..8].copy_from_slice(&(addrs.len() as u16 + 1).to_be_bytes());
+    // CNAME of the question to itself, the alias is skipped.
+    message.extend_from_slice(&[0xC0, 12, 0, 5, 0, 1, 0, 0, 0, 60, 0, 2, 0xC0, 12]);
+    for addr in addrs {
+        let (rtype, data) = match addr {
+            IpAddr::V4(ip) => (TYPE_A, ip.octets().to_vec()),
+            IpAddr::V6(ip) => (TYPE_AAAA, ip.octets().to_vec()),
+        };
+        message.extend_from_slice(&[0xC0, 12]);
+        message.extend_from_slice(&rtype.to_be_bytes());
+        message.extend_from_slice(&[0, 1, 0, 0, 0, 60]);
+        message.extend_from_slice(&(data.len() as u16).to_be_bytes());
+        message.extend_from_slice(&data);
+    }
+    message
+}
+
+// @tc.name: ut_dns_message_encode
+// @tc.desc: Test encoding of DNS queries
+// @tc.precon: NA
+// @tc.step: 1. Encode queries of valid and invalid names
+// @tc.expect: Valid names are encoded as labels, invalid names are refused
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_dns_message_encode() {
+    let query = encode_query(0x1234, "cdn.example.com.", TYPE_AAAA).unwrap();
+    let mut expected = vec![0x12, 0x34, 0x01, 0x00, 0, 1, 0, 0, 0, 0, 0, 0];
+    expected.extend_from_slice(b"\x03cdn\x07example\x03com\x00");
+    expected.extend_from_slice(&[0, 28, 0, 1]);
+    assert_eq!(query, expected);
+
+    assert!(encode_query(1, "", TYPE_A).is_err());
+    assert!(encode_query(1, "a..b", TYPE_A).is_err());
+    assert!(encode_query(1, &"a".repeat(64), TYPE_A).is_err());
+}
+
+// @tc.name: ut_dns_message_decode
+// @tc.desc: Test decoding of DNS responses
+// @tc.precon: NA
+// @tc.step: 1. Decode responses with aliases and addresses
+//           2. Decode responses of other queries, errors and truncation
+// @tc.expect: The addresses of the asked type are returned in order, other
+//             responses are refused
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_dns_message_decode() {
+    let query = encode_query(7, "example.com", TYPE_A).unwrap();
+    let addrs: Vec<IpAddr> = vec![
+        "192.0.2.1".parse().unwrap(),
+        "2001:db8::1".parse().unwrap(),
+        "192.0.2.2".parse().unwrap(),
+    ];
+    let response = answer(&query, 0, &addrs);
+    assert!(!is_truncated(&response));
+    assert_eq!(
+        decode_response(&response, 7, TYPE_A).unwrap(),
+        vec![addrs[0], addrs[2]]
+    );
+    assert_eq!(
+        decode_response(&response, 7, TYPE_AAAA).unwrap(),
+        vec![addrs[1]]
+    );
+
+    assert!(decode_response(&response, 8, TYPE_A).is_err());
+    assert!(decode_response(&query, 7, TYPE_A).is_err());
+    assert!(decode_response(&response[..response.len() - 1], 7, TYPE_A).is_err());
+
+    let missing = answer(&query, 3, &[]);
+    assert_eq!(
+        decode_response(&missing, 7, TYPE_A).unwrap_err().kind(),
+        io::ErrorKind::NotFound
+    );
+    assert!(decode_response(&answer(&query, 2, &[]), 7, TYPE_A).is_err());
+    assert!(is_truncated(&answer(&query, 0x0200, &[])));
+}
@@ -0,0 +1,319 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::UdpSocket;
+use std::sync::mpsc;
+use std::thread;
+
+use super::*;
+
+/// Builds the response to `query` with `addrs`.
+fn answer(query: &[u8], addrs: &[IpAddr]) -> Vec<u8> {
+    let qtype = u16::from_be_bytes([query[query.len() - 4], query[query.len() - 3]]);
+    let mut message = query.to_vec();
+    message[2..4].copy_from_slice(&0x8180u16.to_be_bytes());
+    let mut count = 0u16;
+    for addr in addrs {
+        let (rtype, data) = match addr {
+            IpAddr::V4(ip) => (TYPE_A, ip.octets().to_vec()),
+            IpAddr::V6(ip) => (TYPE_AAAA, ip.octets().to_vec()),
+        };
+        if rtype != qtype {
+            continue;
+        }
+        count += 1;
+        message.extend_from_slice(&[0xC0, 12]);
+        message.extend_from_slice(&rtype.to_be_bytes());
+        message.extend_from_slice(&[0, 1, 0, 0, 0, 60]);
+        message.extend_from_slice(&(data.len() as u16).to_be_bytes());
+        message.extend_from_slice(&data);
+    }
+    message[6..8].copy_from_slice(&count.to_be_bytes());
+    message
+}
+
+/// Starts a DNS server answering `addrs` to every query, reporting the
+/// queries it gets.
+fn udp_server(addrs: Vec<IpAddr>) -> (SocketAddr, mpsc::Receiver<Vec<u8>>) {
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let addr = socket.local_addr().unwrap();
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut buf = [0u8; 512];
+        while let Ok((len, peer)) = socket.recv_from(&mut buf) {
+            let query = buf[..len].to_vec();
+            socket.send_to(&answer(&query, &addrs), peer).unwrap();
+            if tx.send(query).is_err() {
+                break;
+            }
+        }
+    });
+    (addr, rx)
+}
+
+fn socket_addrs(addrs: &[&str]) -> Vec<SocketAddr> {
+    addrs.iter().map(|addr| addr.parse().unwrap()).collect()
+}
+
+// @tc.name: ut_dns_parse
+// @tc.desc: Test parsing of host overrides, servers and preferences
+// @tc.precon: NA
+// @tc.step: 1. Parse valid and invalid values of each option
+// @tc.expect: Valid values are parsed with their defaults, invalid values
+//             are refused
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_dns_parse() {
+    let o = HostOverride::parse("CDN.example.com:443:203.0.113.7, [2001:db8::7]").unwrap();
+    assert_eq!(o.host, "cdn.example.com");
+    assert_eq!(o.port, 443);
+    assert_eq!(
+        o.addrs,
+        vec![
+            "203.0.113.7".parse::<IpAddr>().unwrap(),
+            "2001:db8::7".parse().unwrap()
+        ]
+    );
+    for value in [
+        "cdn.example.com:443",
+        ":443:1.2.3.4",
+        "a:b:1.2.3.4",
+        "a:443:cdn",
+    ] {
+        assert!(HostOverride::parse(value).is_err(), "{}", value);
+    }
+
+    assert_eq!(DnsServer::parse("system").unwrap(), DnsServer::System);
+    assert_eq!(
+        DnsServer::parse("udp://8.8.8.8").unwrap(),
+        DnsServer::Udp("8.8.8.8:53".parse().unwrap())
+    );
+    assert_eq!(
+        DnsServer::parse("udp://[2001:db8::53]").unwrap(),
+        DnsServer::Udp("[2001:db8::53]:53".parse().unwrap())
+    );
+    assert_eq!(
+        DnsServer::parse("tls://1.1.1.1#cloudflare-dns.com").unwrap(),
+        DnsServer::Tls {
+            addr: "1.1.1.1:853".parse().unwrap(),
+            name: "cloudflare-dns.com".to_string(),
+        }
+    );
+    assert_eq!(
+        DnsServer::parse("tls://9.9.9.9:8853").unwrap(),
+        DnsServer::Tls {
+            addr: "9.9.9.9:8853".parse().unwrap(),
+            name: "9.9.9.9".to_string(),
+        }
+    );
+    assert_eq!(
+        DnsServer::parse("https://dns.example.com/dns-query").unwrap(),
+        DnsServer::Https("https://dns.example.com/dns-query".to_string())
+    );
+    for value in ["", "udp://dns.example.com", "tls://1.1.1.1#", "http://dns/"] {
+        assert!(DnsServer::parse(value).is_err(), "{}", value);
+    }
+
+    assert_eq!(
+        IpPreference::parse("ipv6_first").unwrap(),
+        IpPreference::Ipv6First
+    );
+    assert!(IpPreference::parse("ipv5").is_err());
+    assert!(DnsConfig::default().is_default());
+}
+
+// @tc.name: ut_dns_order_addrs
+// @tc.desc: Test ordering of addresses by preference
+// @tc.precon: NA
+// @tc.step: 1. Order mixed IPv4 and IPv6 addresses with each preference and
+//              several first family counts
+// @tc.expect: Families are filtered or interleaved as preferred
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_dns_order_addrs() {
+    let addrs = socket_addrs(&[
+        "1.0.0.1:80",
+        "1.0.0.2:80",
+        "[::1]:80",
+        "1.0.0.3:80",
+        "[::2]:80",
+    ]);
+    let order = |preference, count| order_addrs(addrs.clone(), preference, count);
+
+    assert_eq!(
+        order(IpPreference::Any, 1),
+        socket_addrs(&[
+            "1.0.0.1:80",
+            "[::1]:80",
+            "1.0.0.2:80",
+            "[::2]:80",
+            "1.0.0.3:80"
+        ])
+    );
+    assert_eq!(
+        order(IpPreference::Ipv6First, 1),
+        socket_addrs(&[
+            "[::1]:80",
+            "1.0.0.1:80",
+            "[::2]:80",
+            "1.0.0.2:80",
+            "1.0.0.3:80"
+        ])
+    );
+    assert_eq!(
+        order(IpPreference::Ipv4First, 2),
+        socket_addrs(&[
+            "1.0.0.1:80",
+            "1.0.0.2:80",
+            "[::1]:80",
+            "1.0.0.3:80",
+            "[::2]:80"
+        ])
+    );
+    assert_eq!(
+        order(IpPreference::Ipv4, 1),
+        socket_addrs(&["1.0.0.1:80", "1.0.0.2:80", "1.0.0.3:80"])
+    );
+    assert_eq!(
+        order(IpPreference::Ipv6, 1),
+        socket_addrs(&["[::1]:80", "[::2]:80"])
+    );
+    let v6_first = socket_addrs(&["[::1]:80", "1.0.0.1:80"]);
+    assert_eq!(
+        order_addrs(v6_first.clone(), IpPreference::Any, 0),
+        v6_first
+    );
+}
+
+// @tc.name: ut_dns_resolve_override
+// @tc.desc: Test resolution of overridden hosts and address literals
+// @tc.precon: NA
+// @tc.step: 1. Resolve a host with an override for its port, for another
+//              port through a wildcard, and an address literal
+// @tc.expect: The pinned addresses are returned without any query
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_dns_resolve_override() {
+    let config = DnsConfig {
+        overrides: vec![
+            HostOverride::parse("cdn.example.com:443:203.0.113.7,2001:db8::7").unwrap(),
+            HostOverride::parse("*:80:127.0.0.1").unwrap(),
+        ],
+        // Any query would fail.
+        server: DnsServer::Udp("127.0.0.1:9".parse().unwrap()),
+        timeout: Duration::from_millis(100),
+        ..Default::default()
+    };
+    assert_eq!(
+        config.resolve_authority("CDN.example.com:443").unwrap(),
+        socket_addrs(&["203.0.113.7:443", "[2001:db8::7]:443"])
+    );
+    assert_eq!(
+        config.resolve("cdn.example.com", 80).unwrap(),
+        socket_addrs(&["127.0.0.1:80"])
+    );
+    assert_eq!(
+        config.resolve_authority("[::1]:8080").unwrap(),
+        socket_addrs(&["[::1]:8080"])
+    );
+    assert!(config.resolve_authority("cdn.example.com").is_err());
+
+    let config = DnsConfig {
+        preference: IpPreference::Ipv6,
+        ..config
+    };
+    assert_eq!(
+        config.resolve("127.0.0.1", 80).unwrap_err().kind(),
+        io::ErrorKind::NotFound
+    );
+}
+
+// @tc.name: ut_dns_resolve_udp
+// @tc.desc: Test resolution through a plain DNS server
+// @tc.precon: NA
+// @tc.step: 1. Start a DNS server answering IPv4 and IPv6 addresses
+//           2. Resolve a host preferring IPv6, then IPv4 only
+// @tc.expect: The server gets A and AAAA queries as preferred and the
+//             addresses are ordered by preference
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_dns_resolve_udp() {
+    let (addr, queries) = udp_server(vec![
+        "192.0.2.1".parse().unwrap(),
+        "2001:db8::1".parse().unwrap(),
+    ]);
+    let config = DnsConfig {
+        server: DnsServer::Udp(addr),
+        preference: IpPreference::Ipv6First,
+        ..Default::default()
+    };
+    assert_eq!(
+        config.resolve("cdn.example.com", 443).unwrap(),
+        socket_addrs(&["[2001:db8::1]:443", "192.0.2.1:443"])
+    );
+    let qtype =
+        |query: Vec<u8>| u16::from_be_bytes([query[query.len() - 4], query[query.len() - 3]]);
+    assert_eq!(qtype(queries.recv().unwrap()), TYPE_AAAA);
+    assert_eq!(qtype(queries.recv().unwrap()), TYPE_A);
+
+    let config = DnsConfig {
+        preference: IpPreference::Ipv4,
+        ..config
+    };
+    assert_eq!(
+        config.resolve("cdn.example.com", 443).unwrap(),
+        socket_addrs(&["192.0.2.1:443"])
+    );
+    assert_eq!(qtype(queries.recv().unwrap()), TYPE_A);
+    assert!(queries.try_recv().is_err());
+}
+
+// @tc.name: ut_dns_resolve_https
+// @tc.desc: Test resolution through a DNS over HTTPS server
+// @tc.precon: NA
+// @tc.step: 1. Start an HTTPS server answering DNS messages
+//           2. Resolve a host with IPv4 only
+// @tc.expect: The query is posted as a DNS message and the answer is used
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[cfg(all(feature = "dns_tls", feature = "test_tls"))]
+#[test]
+fn ut_dns_resolve_https() {
+    use crate::test::server::{MockResponse, MockServer, TEST_CA_PEM};
+
+    let query = encode_query(0, "cdn.example.com", TYPE_A).unwrap();
+    let server = MockServer::start_tls();
+    server.route(
+        "/dns-query",
+        [
+            MockResponse::ok(answer(&query, &["198.51.100.1".parse().unwrap()]))
+                .header("Content-Type", "application/dns-message"),
+        ],
+    );
+    let config = DnsConfig {
+        server: DnsServer::parse(&server.url("/dns-query")).unwrap(),
+        preference: IpPreference::Ipv4,
+        root_certificates: vec![TEST_CA_PEM.to_string()],
+        ..Default::default()
+    };
+    assert_eq!(
+        config.resolve("cdn.example.com", 443).unwrap(),
+        socket_addrs(&["198.51.100.1:443"])
+    );
+    server.assert_header("/dns-query", "content-type", "application/dns-message");
+    assert_eq!(server.requests()[0].body, query);
+}
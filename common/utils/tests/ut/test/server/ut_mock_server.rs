@@ -0,0 +1,251 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::time::{Duration, Instant};
+
+use super::*;
+
+/// Sends a raw request and reads the raw response until the server closes.
+fn send(server: &MockServer, request: &str) -> String {
+    let mut stream = TcpStream::connect(server.addr()).unwrap();
+    stream.write_all(request.as_bytes()).unwrap();
+    let mut response = Vec::new();
+    let _ = stream.read_to_end(&mut response);
+    String::from_utf8_lossy(&response).into_owned()
+}
+
+fn get(server: &MockServer, path: &str, headers: &str) -> String {
+    send(
+        server,
+        &format!(
+            "GET {} HTTP/1.1\r\nHost: 127.0.0.1\r\n{}\r\n",
+            path, headers
+        ),
+    )
+}
+
+// @tc.name: ut_mock_server_routes
+// @tc.desc: Test route matching of the mock server
+// @tc.precon: NA
+// @tc.step: 1. Route a path for any method and another for POST only
+//           2. Request the routes, a wrong method and an unknown path
+// @tc.expect: Routes are served, ignoring the query string, others get 405
+//             and 404
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_mock_server_routes() {
+    let server = MockServer::start();
+    server.route(
+        "/file",
+        [MockResponse::ok("hello").header("ETag", "\"v1\"")],
+    );
+    server.route_method("POST", "/upload", [MockResponse::new(201)]);
+
+    let response = get(&server, "/file?token=1", "");
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.contains("ETag: \"v1\"\r\n"));
+    assert!(response.contains("Content-Length: 5\r\n"));
+    assert!(response.ends_with("\r\n\r\nhello"));
+
+    assert!(get(&server, "/upload", "").starts_with("HTTP/1.1 405 "));
+    assert!(get(&server, "/missing", "").starts_with("HTTP/1.1 404 Not Found"));
+    let response = send(
+        &server,
+        "POST /upload HTTP/1.1\r\nContent-Length: 4\r\n\r\ndata",
+    );
+    assert!(response.starts_with("HTTP/1.1 201 Created"));
+    server.assert_hits("/file", 1);
+    server.assert_hits("/upload", 2);
+    assert_eq!(server.requests()[3].body, b"data");
+    assert_eq!(server.requests()[0].target, "/file?token=1");
+}
+
+// @tc.name: ut_mock_server_sequence
+// @tc.desc: Test scripted sequences of responses
+// @tc.precon: NA
+// @tc.step: 1. Route a path to 408, 429, 503 and then 200
+//           2. Request the path five times
+// @tc.expect: Responses follow the script and the last one repeats
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_mock_server_sequence() {
+    let server = MockServer::start();
+    server.route(
+        "/retry",
+        [
+            MockResponse::new(408),
+            MockResponse::new(429).header("Retry-After", "1"),
+            MockResponse::new(503),
+            MockResponse::ok("done"),
+        ],
+    );
+    let statuses: Vec<_> = (0..5)
+        .map(|_| get(&server, "/retry", "")[9..12].to_string())
+        .collect();
+    assert_eq!(statuses, ["408", "429", "503", "200", "200"]);
+    server.assert_hits("/retry", 5);
+}
+
+// @tc.name: ut_mock_server_empty_route
+// @tc.desc: Test that a route without response is rejected
+// @tc.precon: NA
+// @tc.step: 1. Route a path to no response
+// @tc.expect: The route is rejected when it is added
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+#[should_panic(expected = "route /empty without response")]
+fn ut_mock_server_empty_route() {
+    let server = MockServer::start();
+    server.route("/empty", []);
+}
+
+// @tc.name: ut_mock_server_redirect
+// @tc.desc: Test redirect responses
+// @tc.precon: NA
+// @tc.step: 1. Route a path to a redirect
+//           2. Request the path
+// @tc.expect: The response has the status and the location of the redirect
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_mock_server_redirect() {
+    let server = MockServer::start();
+    server.route("/old", [MockResponse::redirect(302, "/new")]);
+    let response = get(&server, "/old", "");
+    assert!(response.starts_with("HTTP/1.1 302 Found\r\n"));
+    assert!(response.contains("Location: /new\r\n"));
+}
+
+// @tc.name: ut_mock_server_chunked
+// @tc.desc: Test chunked responses and chunked request bodies
+// @tc.precon: NA
+// @tc.step: 1. Route a path to a chunked body of 10 bytes in chunks of 4
+//           2. Send a chunked request body to the path
+// @tc.expect: The response is framed in chunks and the request body is
+//             recorded without framing
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_mock_server_chunked() {
+    let server = MockServer::start();
+    server.route("/chunked", [MockResponse::ok("0123456789").chunked(4)]);
+    let response = send(
+        &server,
+        "PUT /chunked HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n\
+         3\r\nabc\r\n2\r\nde\r\n0\r\n\r\n",
+    );
+    assert!(response.contains("Transfer-Encoding: chunked\r\n"));
+    assert!(!response.contains("Content-Length"));
+    assert!(response.ends_with("\r\n\r\n4\r\n0123\r\n4\r\n4567\r\n2\r\n89\r\n0\r\n\r\n"));
+    assert_eq!(server.requests()[0].body, b"abcde");
+}
+
+// @tc.name: ut_mock_server_range
+// @tc.desc: Test Range support of the mock server
+// @tc.precon: NA
+// @tc.step: 1. Route a path to a body accepting ranges
+//           2. Request a range, an open range, a suffix and an invalid range
+// @tc.expect: Partial content with Content-Range, or 416 if not satisfiable
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_mock_server_range() {
+    let server = MockServer::start();
+    server.route("/range", [MockResponse::ok("0123456789").accept_ranges()]);
+
+    let response = get(&server, "/range", "");
+    assert!(response.starts_with("HTTP/1.1 200 OK"));
+    assert!(response.contains("Accept-Ranges: bytes\r\n"));
+
+    let response = get(&server, "/range", "Range: bytes=2-5\r\n");
+    assert!(response.starts_with("HTTP/1.1 206 Partial Content"));
+    assert!(response.contains("Content-Range: bytes 2-5/10\r\n"));
+    assert!(response.ends_with("\r\n\r\n2345"));
+
+    let response = get(&server, "/range", "Range: bytes=7-\r\n");
+    assert!(response.ends_with("\r\n\r\n789"));
+    let response = get(&server, "/range", "Range: bytes=-2\r\n");
+    assert!(response.ends_with("\r\n\r\n89"));
+
+    let response = get(&server, "/range", "Range: bytes=10-\r\n");
+    assert!(response.starts_with("HTTP/1.1 416 Range Not Satisfiable"));
+    assert!(response.contains("Content-Range: bytes */10\r\n"));
+    server.assert_header("/range", "Host", "127.0.0.1");
+}
+
+// @tc.name: ut_mock_server_disconnect
+// @tc.desc: Test connections closed in the middle of a body
+// @tc.precon: NA
+// @tc.step: 1. Route a path to a body of 10 bytes cut after 4 bytes
+//           2. Request the path
+// @tc.expect: The response announces 10 bytes but only 4 are received
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_mock_server_disconnect() {
+    let server = MockServer::start();
+    server.route("/cut", [MockResponse::ok("0123456789").disconnect_after(4)]);
+    let response = get(&server, "/cut", "");
+    assert!(response.contains("Content-Length: 10\r\n"));
+    assert!(response.ends_with("\r\n\r\n0123"));
+}
+
+// @tc.name: ut_mock_server_throttle
+// @tc.desc: Test throttled bodies
+// @tc.precon: NA
+// @tc.step: 1. Route a path to a body of 4 bytes sent 1 byte every 50ms
+//           2. Request the path and measure the time
+// @tc.expect: The whole body is received after at least 150ms
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_mock_server_throttle() {
+    let server = MockServer::start();
+    server.route(
+        "/slow",
+        [MockResponse::ok("slow").throttle(1, Duration::from_millis(50))],
+    );
+    let begin = Instant::now();
+    let response = get(&server, "/slow", "");
+    assert!(begin.elapsed() >= Duration::from_millis(150));
+    assert!(response.ends_with("\r\n\r\nslow"));
+}
+
+// @tc.name: ut_mock_server_wait_for_requests
+// @tc.desc: Test waiting for requests received by another thread
+// @tc.precon: NA
+// @tc.step: 1. Send a request from another thread
+//           2. Wait for one request, then for two with a short timeout
+// @tc.expect: The first wait succeeds and the second times out
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_mock_server_wait_for_requests() {
+    let server = MockServer::start();
+    let addr = server.addr();
+    std::thread::spawn(move || {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream
+            .write_all(b"GET /async HTTP/1.1\r\nHost: 127.0.0.1\r\n\r\n")
+            .unwrap();
+        let _ = stream.read_to_end(&mut Vec::new());
+    });
+    assert!(server.wait_for_requests(1, Duration::from_secs(5)));
+    assert!(!server.wait_for_requests(2, Duration::from_millis(100)));
+    assert_eq!(server.requests()[0].header("host"), Some("127.0.0.1"));
+}
@@ -253,3 +253,25 @@ fn ut_test_insert_after_pop() {
     assert!(!cache.is_empty());
     assert_eq!(Some(Cache::from_u(1)), cache.pop());
 }
+
+// @tc.name: ut_lru_cache_peek
+// @tc.desc: Test peeking entries of LRUCache without changing access order
+// @tc.precon: NA
+// @tc.step: 1. Create a new LRUCache instance
+//           2. Insert two entries and peek the oldest one
+//           3. Pop the least recently used entry
+// @tc.expect: Peek returns the entry, and the peeked entry is still popped
+// first
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_test_peek() {
+    let mut cache = LRUCache::new();
+    cache.insert("key0", Cache::from_u(0));
+    cache.insert("key1", Cache::from_u(1));
+    assert_eq!(Some(&Cache::from_u(0)), cache.peek(&"key0"));
+    assert_eq!(None, cache.peek(&"key2"));
+    assert_eq!(Some(Cache::from_u(0)), cache.pop());
+    assert_eq!(Some(&Cache::from_u(1)), cache.peek(&"key1"));
+}
@@ -18,6 +18,7 @@
 //! ETS-facing representations.
 
 use std::collections::HashMap;
+use std::time::UNIX_EPOCH;
 
 use preload_native_rlib::{CacheDownloadError, ErrorKind};
 
@@ -185,3 +186,158 @@ impl DownloadError {
         }
     }
 }
+
+/// Storage tier of a cache entry.
+#[derive(Clone)]
+#[ani_rs::ani(path = "@ohos.request.cacheDownload.cacheDownload.CacheTier")]
+pub enum CacheTier {
+    /// In-memory cache.
+    RAM,
+    /// Cache file on disk.
+    FILE,
+    /// Segments of a resource cached by byte ranges, on disk.
+    PARTIAL,
+}
+
+/// Counters of the cache activity since the service started.
+#[ani_rs::ani(path = "@ohos.request.cacheDownload.cacheDownload.CacheCountersInner")]
+pub struct CacheCounters {
+    /// Lookups served from the memory cache.
+    pub ram_hits: i64,
+    /// Lookups served from cache files or partial entries.
+    pub file_hits: i64,
+    /// Downloads of resources that were not cached.
+    pub misses: i64,
+    /// Bytes served from the memory cache.
+    pub ram_bytes: i64,
+    /// Bytes served from cache files or partial entries.
+    pub file_bytes: i64,
+    /// Bytes received from the network.
+    pub network_bytes: i64,
+    /// Entries evicted from the memory cache.
+    pub ram_evictions: i64,
+    /// Entries evicted from the file cache.
+    pub file_evictions: i64,
+}
+
+impl CacheCounters {
+    /// Converts the native counters into the ETS-facing form.
+    pub fn from_native(counters: &preload_native_rlib::CacheCounters) -> Self {
+        Self {
+            ram_hits: counters.ram_hits as i64,
+            file_hits: counters.file_hits as i64,
+            misses: counters.misses as i64,
+            ram_bytes: counters.ram_bytes as i64,
+            file_bytes: counters.file_bytes as i64,
+            network_bytes: counters.network_bytes as i64,
+            ram_evictions: counters.ram_evictions as i64,
+            file_evictions: counters.file_evictions as i64,
+        }
+    }
+}
+
+/// Occupancy of a cache tier.
+#[ani_rs::ani(path = "@ohos.request.cacheDownload.cacheDownload.TierUsageInner")]
+pub struct TierUsage {
+    /// Bytes used.
+    pub used: i64,
+    /// Bytes available to the tier.
+    pub capacity: i64,
+    /// Number of entries.
+    pub entries: i64,
+}
+
+impl TierUsage {
+    /// Converts the native tier usage into the ETS-facing form.
+    pub fn from_native(usage: &preload_native_rlib::TierUsage) -> Self {
+        Self {
+            used: usage.used as i64,
+            capacity: usage.capacity as i64,
+            entries: usage.entries as i64,
+        }
+    }
+}
+
+/// Statistics of a named cache namespace.
+#[ani_rs::ani(path = "@ohos.request.cacheDownload.cacheDownload.NamespaceStatsInner")]
+pub struct NamespaceStats {
+    /// Name of the namespace.
+    pub name: String,
+    /// Counters of the entries of the namespace.
+    pub counters: CacheCounters,
+    /// Occupancy of the file cache budget of the namespace.
+    pub file: TierUsage,
+}
+
+/// Snapshot of the cache statistics.
+#[ani_rs::ani(path = "@ohos.request.cacheDownload.cacheDownload.CacheStatsInner")]
+pub struct CacheStats {
+    /// Counters of all the entries.
+    pub counters: CacheCounters,
+    /// Occupancy of the memory cache.
+    pub ram: TierUsage,
+    /// Occupancy of the file caches of all the namespaces.
+    pub file: TierUsage,
+    /// Occupancy of the pinned entries.
+    pub pinned: TierUsage,
+    /// Statistics of the named namespaces.
+    pub namespaces: Vec<NamespaceStats>,
+}
+
+impl CacheStats {
+    /// Converts a native statistics snapshot into the ETS-facing form.
+    pub fn from_native(stats: preload_native_rlib::CacheStats) -> Self {
+        Self {
+            counters: CacheCounters::from_native(&stats.counters),
+            ram: TierUsage::from_native(&stats.ram),
+            file: TierUsage::from_native(&stats.file),
+            pinned: TierUsage::from_native(&stats.pinned),
+            namespaces: stats
+                .namespaces
+                .iter()
+                .map(|(name, namespace)| NamespaceStats {
+                    name: name.clone(),
+                    counters: CacheCounters::from_native(&namespace.counters),
+                    file: TierUsage::from_native(&namespace.file),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Cached entry, as listed by `getCacheEntries` or reported on eviction.
+#[derive(Clone)]
+#[ani_rs::ani(path = "@ohos.request.cacheDownload.cacheDownload.CacheEntryInner")]
+pub struct CacheEntry {
+    /// Cache key of the entry.
+    pub key: String,
+    /// Namespace of the entry, if any.
+    pub namespace: Option<String>,
+    /// Tier holding the entry.
+    pub tier: CacheTier,
+    /// Size of the entry in bytes.
+    pub size: i64,
+    /// Time the entry was last stored or served, in milliseconds since the
+    /// Unix epoch.
+    pub last_access: i64,
+}
+
+impl CacheEntry {
+    /// Converts a native cache entry into the ETS-facing form.
+    pub fn from_native(entry: &preload_native_rlib::CacheEntry) -> Self {
+        Self {
+            key: entry.task_id.to_string(),
+            namespace: entry.namespace.clone(),
+            tier: match entry.tier {
+                preload_native_rlib::CacheTier::Ram => CacheTier::RAM,
+                preload_native_rlib::CacheTier::File => CacheTier::FILE,
+                preload_native_rlib::CacheTier::Partial => CacheTier::PARTIAL,
+            },
+            size: entry.size as i64,
+            last_access: entry
+                .last_access
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |time| time.as_millis() as i64),
+        }
+    }
+}
@@ -25,8 +25,10 @@ use ani_rs::AniEnv;
 use preload_native_rlib::{CacheDownloadService, DownloadRequest, Downloader, PreloadCallback};
 use preload_permission_verify::permission_check;
 
-use crate::bridge::{CacheDownloadOptions, CacheStrategy, DownloadInfo, SslType};
-use crate::callback::{self, CallbackManager, CallbackWrapper};
+use crate::bridge::{
+    CacheDownloadOptions, CacheEntry, CacheStats, CacheStrategy, DownloadInfo, SslType,
+};
+use crate::callback::{self, CallbackManager, CallbackWrapper, EvictionCallbacks};
 
 const MAX_FILE_SIZE: i64 = 4294967296;
 const MAX_MEM_SIZE: i64 = 1073741824;
@@ -443,6 +445,48 @@ pub fn off_download_error(
     Ok(())
 }
 
+/// Takes a snapshot of the cache statistics.
+#[ani_rs::native]
+pub fn get_cache_stats() -> Result<CacheStats, BusinessError> {
+    let stats = CacheDownloadService::get_instance().stats();
+    Ok(CacheStats::from_native(stats))
+}
+
+/// Lists the cached entries with their tier, size and last access.
+#[ani_rs::native]
+pub fn get_cache_entries() -> Result<Vec<CacheEntry>, BusinessError> {
+    let entries = CacheDownloadService::get_instance()
+        .cache_entries()
+        .iter()
+        .map(CacheEntry::from_native)
+        .collect();
+    Ok(entries)
+}
+
+/// Registers a callback invoked when a cache entry is evicted to make room
+/// for others.
+#[ani_rs::native]
+pub fn on_cache_eviction(env: &AniEnv, callback: AniFnObject) -> Result<(), BusinessError> {
+    let callback = callback.into_global_callback(env)?;
+    EvictionCallbacks::get_instance().register_callback(callback);
+    Ok(())
+}
+
+/// Unregisters an eviction callback.
+///
+/// When `callback` is `None`, all eviction callbacks are removed.
+#[ani_rs::native]
+pub fn off_cache_eviction(
+    env: &AniEnv,
+    callback: Option<AniFnObject>,
+) -> Result<(), BusinessError> {
+    let callback = callback
+        .map(|cb| cb.into_global_callback(env))
+        .transpose()?;
+    EvictionCallbacks::get_instance().unregister_callback(callback);
+    Ok(())
+}
+
 fn check_url_length(url: &str) -> Result<(), BusinessError> {
     let url_len = url.len();
     if url_len == 0 {
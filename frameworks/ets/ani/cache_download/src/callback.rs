@@ -14,16 +14,19 @@
 //! Callback management for cache download operations.
 //!
 //! This module defines structures and methods to manage success and error
-//! callbacks associated with download URLs.
+//! callbacks associated with download URLs, and the callbacks notified of
+//! evicted cache entries.
 
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex, OnceLock};
 
 use ani_rs::objects::GlobalRefCallback;
 use preload_native_rlib::info::RustDownloadInfo;
-use preload_native_rlib::{CacheDownloadError, PreloadCallback, RamCache};
+use preload_native_rlib::{
+    CacheDownloadError, CacheDownloadService, EvictionListener, PreloadCallback, RamCache,
+};
 
-use crate::bridge::{DownloadError, ErrorCode};
+use crate::bridge::{CacheEntry, DownloadError, ErrorCode};
 
 /// Holds the success and error callbacks registered for a single download URL.
 pub struct CallbackUnit {
@@ -214,3 +217,64 @@ impl CallbackManager {
         }
     }
 }
+
+/// Adapter that forwards native eviction events to the callbacks managed by
+/// `EvictionCallbacks`.
+struct EvictionWrapper;
+
+impl EvictionListener for EvictionWrapper {
+    fn on_evict(&self, entry: &preload_native_rlib::CacheEntry) {
+        EvictionCallbacks::get_instance().call_callbacks(CacheEntry::from_native(entry));
+    }
+}
+
+/// Singleton registry of the callbacks notified of evicted cache entries.
+///
+/// The native eviction listener is only set while callbacks are registered.
+pub struct EvictionCallbacks {
+    callbacks: Mutex<Vec<GlobalRefCallback<(CacheEntry,)>>>,
+}
+
+impl EvictionCallbacks {
+    /// Returns the singleton instance of the eviction callbacks.
+    pub fn get_instance() -> &'static EvictionCallbacks {
+        static INSTANCE: OnceLock<EvictionCallbacks> = OnceLock::new();
+        INSTANCE.get_or_init(|| EvictionCallbacks {
+            callbacks: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// Registers a callback, ignoring duplicates.
+    pub fn register_callback(&self, callback: GlobalRefCallback<(CacheEntry,)>) {
+        let mut cbs = self.callbacks.lock().unwrap();
+        if cbs.iter().any(|cb| callback.eq(cb)) {
+            return;
+        }
+        if cbs.is_empty() {
+            CacheDownloadService::get_instance()
+                .set_eviction_listener(Some(Box::new(EvictionWrapper)));
+        }
+        cbs.push(callback);
+    }
+
+    /// Unregisters a specific callback, or all when `None`.
+    pub fn unregister_callback(&self, callback: Option<GlobalRefCallback<(CacheEntry,)>>) {
+        let mut cbs = self.callbacks.lock().unwrap();
+        if let Some(callback) = callback {
+            cbs.retain(|cb| !cb.eq(&callback));
+        } else {
+            cbs.clear();
+        }
+        if cbs.is_empty() {
+            CacheDownloadService::get_instance().set_eviction_listener(None);
+        }
+    }
+
+    /// Invokes all registered callbacks with the evicted entry.
+    fn call_callbacks(&self, entry: CacheEntry) {
+        let cbs = self.callbacks.lock().unwrap().clone();
+        for cb in cbs.iter() {
+            cb.execute((entry.clone(),));
+        }
+    }
+}
@@ -37,5 +37,9 @@ ani_rs::ani_constructor! {
         "offDownloadError" : cache_download::off_download_error,
         "setGlobalRetryOptions" : cache_download::set_global_retry_options,
         "setGlobalTimeoutOptions" : cache_download::set_global_timeout_options,
+        "getCacheStats" : cache_download::get_cache_stats,
+        "getCacheEntries" : cache_download::get_cache_entries,
+        "onCacheEviction" : cache_download::on_cache_eviction,
+        "offCacheEviction" : cache_download::off_cache_eviction,
     ]
 }
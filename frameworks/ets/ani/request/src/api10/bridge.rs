@@ -627,6 +627,8 @@ impl From<request_core::info::TaskInfo> for TaskInfo {
     fn from(value: request_core::info::TaskInfo) -> Self {
         let saveas = if value.common_data.action == Action::Upload as u8 {
             "".to_string()
+        } else if !value.saved_path.is_empty() {
+            value.saved_path.clone()
         } else {
             value
                 .file_specs
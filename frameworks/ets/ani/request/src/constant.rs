@@ -63,4 +63,6 @@ pub enum ExceptionErrorCode {
     E_TASK_STATE = 21900007,
     /// Task group with the specified ID was not found.
     E_GROUP_NOT_FOUND = 21900008,
+    /// Application task quota is exceeded.
+    E_TASK_QUOTA = 21900009,
 }
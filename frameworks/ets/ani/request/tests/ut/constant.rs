@@ -104,6 +104,7 @@ fn ut_error_code_service() {
 //           3. Verify task not found error code
 //           4. Verify task state error code
 //           5. Verify group not found error code
+//           6. Verify task quota error code
 // @tc.expect: Task error codes should have expected values
 // @tc.type: FUNC
 // @tc.require: issues#ICN16H
@@ -114,6 +115,7 @@ fn ut_error_code_task_errors() {
     assert_eq!(ExceptionErrorCode::E_TASK_NOT_FOUND as i32, 21900006);
     assert_eq!(ExceptionErrorCode::E_TASK_STATE as i32, 21900007);
     assert_eq!(ExceptionErrorCode::E_GROUP_NOT_FOUND as i32, 21900008);
+    assert_eq!(ExceptionErrorCode::E_TASK_QUOTA as i32, 21900009);
 }
 
 // @tc.name: ut_error_code_ipc_errors
@@ -174,6 +176,7 @@ fn ut_error_code_all_variants() {
         ExceptionErrorCode::E_TASK_NOT_FOUND as i32,
         ExceptionErrorCode::E_TASK_STATE as i32,
         ExceptionErrorCode::E_GROUP_NOT_FOUND as i32,
+        ExceptionErrorCode::E_TASK_QUOTA as i32,
     ];
 
     let unique_codes: HashSet<_> = codes.iter().cloned().collect();
@@ -533,9 +533,15 @@ bool JsInitialize::ParseProxy(napi_env env, napi_value jsConfig, std::string &pr
         return false;
     }
 
-    if (!regex_match(proxy, std::regex("^http:\\/\\/.+:\\d{1,5}$"))) {
+    // A single http or socks5 proxy with optional credentials, or a list of
+    // `pattern=target` rules whose targets are proxies or DIRECT.
+    static const std::string PROXY = "(http|socks5):\\/\\/[^;=\\s]+:\\d{1,5}";
+    static const std::string RULE = "\\s*[^;=\\s][^;=]*=\\s*(DIRECT|" + PROXY + ")\\s*";
+    static const std::regex PROXY_REGEX("^(" + PROXY + "|" + RULE + "(;" + RULE + ")*;?\\s*)$");
+    if (!regex_match(proxy, PROXY_REGEX)) {
         REQUEST_HILOGE("ParseProxy error");
-        errInfo = "Parameter verification failed, the format of proxy is http(s)://<address or domain>:port";
+        errInfo = "Parameter verification failed, the format of proxy is http://<address or domain>:port, "
+                  "socks5://<address or domain>:port or <pattern>=<proxy or DIRECT>;...";
         return false;
     }
     return true;
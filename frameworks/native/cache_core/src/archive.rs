@@ -0,0 +1,331 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Archives of cached responses.
+//!
+//! An archive holds prefetched responses, each with its URL, its response
+//! headers and its body. Applications ship archives in their resources and
+//! import them into the file cache on first launch with
+//! [`CacheManager::import_archive`]; [`CacheManager::export_archive`] writes
+//! the entries of the file cache to an archive.
+//!
+//! All integers are little endian:
+//!
+//! ```text
+//! archive   := magic version entry*
+//! magic     := "PLDCACHE"
+//! version   := u32                   currently 1
+//! entry     := key url namespace headers body crc32
+//! key       := string                cache key, empty for the key of the URL
+//! url       := string
+//! namespace := string                empty for the default namespace
+//! headers   := u32 (string string)*  number of headers, then name and value
+//! body      := u64 byte*             size of the body, then the body
+//! crc32     := u32                   CRC-32 of the body
+//! string    := u32 byte*             size of the UTF-8 text, then the text
+//! ```
+//!
+//! The archive ends after its last entry. Entries whose body is larger than
+//! the largest cache entry are skipped when reading, without being buffered.
+//!
+//! [`CacheManager::import_archive`]: crate::CacheManager::import_archive
+//! [`CacheManager::export_archive`]: crate::CacheManager::export_archive
+
+use std::io::{self, Read, Write};
+
+use request_utils::task_id::TaskId;
+
+use crate::data::{ChecksumReader, MAX_CACHE_SIZE};
+
+/// Magic bytes starting an archive.
+const ARCHIVE_MAGIC: &[u8; 8] = b"PLDCACHE";
+
+/// Version of the archive format written by [`ArchiveWriter`].
+pub const ARCHIVE_VERSION: u32 = 1;
+
+/// Cached response stored in an archive.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ArchiveEntry {
+    /// Cache key of the entry, `None` for the key derived from the URL
+    pub key: Option<String>,
+    /// Origin URL of the resource, empty if unknown
+    pub url: String,
+    /// Namespace of the entry, `None` for the default namespace
+    pub namespace: Option<String>,
+    /// Response headers of the resource
+    pub headers: Vec<(String, String)>,
+    /// Body of the response
+    pub body: Vec<u8>,
+}
+
+impl ArchiveEntry {
+    /// Returns the task ID the entry is cached under.
+    pub fn task_id(&self) -> TaskId {
+        match self.key.as_ref() {
+            Some(key) => TaskId::new(key.clone()),
+            None => TaskId::from_url(&self.url),
+        }
+    }
+
+    /// Checks that the entry can be stored in the file cache.
+    ///
+    /// Keys name cache files and must be ASCII alphanumeric, and entries
+    /// without a key need a URL to derive it from; the URL, namespace and
+    /// headers are stored as lines of the cache file metadata and must not
+    /// contain line breaks.
+    pub(crate) fn is_storable(&self) -> bool {
+        let multi_line = |text: &str| text.contains(['\r', '\n']);
+        let valid_key = match self.key.as_ref() {
+            Some(key) => !key.is_empty() && key.bytes().all(|b| b.is_ascii_alphanumeric()),
+            None => !self.url.is_empty(),
+        };
+        valid_key
+            && !multi_line(&self.url)
+            && !self.namespace.as_deref().is_some_and(multi_line)
+            && self
+                .headers
+                .iter()
+                .all(|(name, value)| !name.contains(':') && !multi_line(name) && !multi_line(value))
+    }
+}
+
+/// Reader of the entries of an archive.
+///
+/// Iterating yields the entries in order and stops after the first error.
+pub struct ArchiveReader<R> {
+    inner: R,
+    done: bool,
+}
+
+impl<R: Read> ArchiveReader<R> {
+    /// Creates a reader over `inner`, checking the archive header.
+    ///
+    /// # Errors
+    /// Returns `InvalidData` if `inner` is not an archive or its version is
+    /// unknown, or `io::Error` if reading fails.
+    pub fn new(mut inner: R) -> io::Result<Self> {
+        let mut magic = [0; 8];
+        inner.read_exact(&mut magic)?;
+        if &magic != ARCHIVE_MAGIC {
+            return Err(invalid("not a cache archive".to_string()));
+        }
+        let version = read_u32(&mut inner)?;
+        if version != ARCHIVE_VERSION {
+            return Err(invalid(format!("unknown archive version {}", version)));
+        }
+        Ok(Self { inner, done: false })
+    }
+
+    /// Reads the next entry of the archive, skipping the entries too large to
+    /// be cached.
+    ///
+    /// # Returns
+    /// `None` at the end of the archive.
+    ///
+    /// # Errors
+    /// Returns `InvalidData` or `UnexpectedEof` if the entry is malformed or
+    /// truncated, or `io::Error` if reading fails.
+    pub fn read_entry(&mut self) -> io::Result<Option<ArchiveEntry>> {
+        loop {
+            let mut len = [0; 4];
+            let mut read = 0;
+            while read < len.len() {
+                match self.inner.read(&mut len[read..]) {
+                    Ok(0) if read == 0 => return Ok(None),
+                    Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+                    Ok(n) => read += n,
+                    Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            let key = read_string_of(&mut self.inner, u32::from_le_bytes(len))?;
+            let url = read_string(&mut self.inner)?;
+            let namespace = read_string(&mut self.inner)?;
+            let count = read_u32(&mut self.inner)?;
+            let mut headers = vec![];
+            for _ in 0..count {
+                let name = read_string(&mut self.inner)?;
+                let value = read_string(&mut self.inner)?;
+                headers.push((name, value));
+            }
+            let size = read_u64(&mut self.inner)?;
+            let mut reader = ChecksumReader::new((&mut self.inner).take(size));
+            let body = if size > MAX_CACHE_SIZE {
+                io::copy(&mut reader, &mut io::sink())?;
+                None
+            } else {
+                let mut body = Vec::new();
+                reader.read_to_end(&mut body)?;
+                Some(body)
+            };
+            let crc = reader.checksum();
+            if reader.size() < size {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+            if read_u32(&mut self.inner)? != crc {
+                return Err(invalid(format!("corrupt body of {}", url)));
+            }
+            let Some(body) = body else {
+                info!("archive entry of {} too large, skipped", url);
+                continue;
+            };
+            return Ok(Some(ArchiveEntry {
+                key: (!key.is_empty()).then_some(key),
+                url,
+                namespace: (!namespace.is_empty()).then_some(namespace),
+                headers,
+                body,
+            }));
+        }
+    }
+}
+
+impl<R: Read> Iterator for ArchiveReader<R> {
+    type Item = io::Result<ArchiveEntry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let res = self.read_entry().transpose();
+        if !matches!(res, Some(Ok(_))) {
+            self.done = true;
+        }
+        res
+    }
+}
+
+/// Writer of an archive.
+pub struct ArchiveWriter<W: Write> {
+    inner: W,
+}
+
+impl<W: Write> ArchiveWriter<W> {
+    /// Creates a writer over `inner`, writing the archive header.
+    ///
+    /// # Errors
+    /// Returns `io::Error` if writing fails.
+    pub fn new(mut inner: W) -> io::Result<Self> {
+        inner.write_all(ARCHIVE_MAGIC)?;
+        inner.write_all(&ARCHIVE_VERSION.to_le_bytes())?;
+        Ok(Self { inner })
+    }
+
+    /// Appends an entry to the archive.
+    ///
+    /// # Errors
+    /// Returns `io::Error` if writing fails.
+    pub fn write_entry(&mut self, entry: &ArchiveEntry) -> io::Result<()> {
+        let size = entry.body.len() as u64;
+        self.write_entry_from(entry, size, entry.body.as_slice())
+    }
+
+    /// Appends an entry whose body is copied from `body` as it is read,
+    /// ignoring the body of `entry`.
+    ///
+    /// # Arguments
+    /// * `entry` - Fields of the entry other than its body
+    /// * `size` - Size of the body
+    /// * `body` - Reader of the body
+    ///
+    /// # Errors
+    /// Returns `UnexpectedEof` if `body` ends before `size` bytes, in which
+    /// case the archive is left truncated, or `io::Error` if reading or
+    /// writing fails.
+    pub(crate) fn write_entry_from<R: Read>(
+        &mut self,
+        entry: &ArchiveEntry,
+        size: u64,
+        body: R,
+    ) -> io::Result<()> {
+        write_string(&mut self.inner, entry.key.as_deref().unwrap_or_default())?;
+        write_string(&mut self.inner, &entry.url)?;
+        write_string(
+            &mut self.inner,
+            entry.namespace.as_deref().unwrap_or_default(),
+        )?;
+        write_len(&mut self.inner, entry.headers.len())?;
+        for (name, value) in entry.headers.iter() {
+            write_string(&mut self.inner, name)?;
+            write_string(&mut self.inner, value)?;
+        }
+        self.inner.write_all(&size.to_le_bytes())?;
+        let mut reader = ChecksumReader::new(body.take(size));
+        io::copy(&mut reader, &mut self.inner)?;
+        if reader.size() < size {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        self.inner.write_all(&reader.checksum().to_le_bytes())
+    }
+
+    /// Flushes the archive and returns the underlying writer.
+    ///
+    /// # Errors
+    /// Returns `io::Error` if flushing fails.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> io::Result<u32> {
+    let mut buf = [0; 4];
+    reader.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut buf = [0; 8];
+    reader.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+/// Reads `len` bytes, growing the buffer as they come so that a corrupt size
+/// fails at the end of the archive instead of allocating it upfront.
+fn read_bytes<R: Read>(reader: &mut R, len: u64) -> io::Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    reader.take(len).read_to_end(&mut buf)?;
+    if (buf.len() as u64) < len {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    Ok(buf)
+}
+
+fn read_string<R: Read>(reader: &mut R) -> io::Result<String> {
+    let len = read_u32(reader)?;
+    read_string_of(reader, len)
+}
+
+fn read_string_of<R: Read>(reader: &mut R, len: u32) -> io::Result<String> {
+    let buf = read_bytes(reader, len as u64)?;
+    String::from_utf8(buf).map_err(|_| invalid("string not UTF-8".to_string()))
+}
+
+fn write_len<W: Write>(writer: &mut W, len: usize) -> io::Result<()> {
+    let len = u32::try_from(len).map_err(|_| invalid("length too large".to_string()))?;
+    writer.write_all(&len.to_le_bytes())
+}
+
+fn write_string<W: Write>(writer: &mut W, text: &str) -> io::Result<()> {
+    write_len(writer, text.len())?;
+    writer.write_all(text.as_bytes())
+}
+
+fn invalid(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod ut_archive {
+    include!("../tests/ut/ut_archive.rs");
+}
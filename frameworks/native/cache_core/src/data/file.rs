@@ -26,8 +26,8 @@
 //! provides mechanisms for persisting data across application restarts.
 
 use std::fs::{self, DirEntry, File, OpenOptions};
-use std::io::{self, Seek, Write};
-use std::os::unix::fs::MetadataExt;
+use std::io::{self, Write};
+use std::os::unix::fs::{FileExt, MetadataExt};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::{Arc, Mutex, Once};
@@ -35,6 +35,7 @@ use std::time::SystemTime;
 
 use request_utils::task_id::TaskId;
 
+use super::meta::{ChecksumReader, FileMeta, META_SUFFIX};
 use super::ram::RamCache;
 use crate::manage::CacheManager;
 
@@ -45,6 +46,19 @@ use crate::manage::CacheManager;
 /// and are considered invalid.
 const FINISH_SUFFIX: &str = "_F";
 
+/// Suffix of cache files being written.
+///
+/// Files are written under this suffix and renamed with the finish suffix
+/// once their content and metadata are on disk, so a crash never leaves a
+/// truncated file under the finish suffix.
+const TEMP_SUFFIX: &str = "_T";
+
+/// Suffix of metadata files being written.
+///
+/// Metadata is written under this suffix and renamed over the previous
+/// metadata, so readers see either the old or the new metadata in full.
+const META_TEMP_SUFFIX: &str = "_MT";
+
 /// Global file store directory manager.
 ///
 /// This static variable manages the directories used for storing cache files.
@@ -395,12 +409,28 @@ pub(crate) struct FileCache {
     size: u64,
     /// ID of the task associated with this cache
     task_id: TaskId,
+    /// Time the cache file was last read or written
+    accessed: SystemTime,
 }
 
 impl FileCache {
     /// Creates a new `FileCache` for the given task and size.
     pub(crate) fn new(task_id: TaskId, size: u64) -> Self {
-        Self { size, task_id }
+        Self {
+            size,
+            task_id,
+            accessed: SystemTime::now(),
+        }
+    }
+
+    /// Returns the time the cache file was last read or written.
+    pub(crate) fn accessed(&self) -> SystemTime {
+        self.accessed
+    }
+
+    /// Records the time the cache file was read.
+    pub(crate) fn touch(&mut self, time: SystemTime) {
+        self.accessed = time;
     }
 
     /// Returns the cached file size in bytes.
@@ -415,11 +445,15 @@ impl FileCache {
 
     /// Releases the file cache resource.
     ///
-    /// Removes the cache file from disk and releases the associated memory.
-    ///
-    /// # Returns
-    /// Amount of memory released (in bytes)
+    /// Removes the cache file and its metadata from disk.
     pub(crate) fn remove_file(task_id: &TaskId) {
+        if let Some(path) = Self::meta_path(task_id) {
+            if let Err(e) = fs::remove_file(path) {
+                if e.kind() != io::ErrorKind::NotFound {
+                    error!("{} drop meta file error: {}", task_id.brief(), e);
+                }
+            }
+        }
         if let Some(path) = Self::path(task_id) {
             if let Err(e) = fs::remove_file(path) {
                 // Different logging levels based on error type
@@ -435,9 +469,6 @@ impl FileCache {
 
     /// Creates a cache file and writes the contents of the RAM cache to it.
     ///
-    /// Writes data to a temporary file and then renames it with the finish
-    /// suffix to indicate it's complete.
-    ///
     /// # Arguments
     /// * `task_id` - ID of the task to create the file for
     /// * `cache` - RAM cache to write to disk
@@ -445,22 +476,86 @@ impl FileCache {
     /// # Returns
     /// `Ok(())` if successful, `Err(io::Error)` if any file operation fails
     pub(crate) fn create_file(task_id: &TaskId, cache: Arc<RamCache>) -> Result<(), io::Error> {
-        if let Some(path) = Self::path(task_id) {
-            // Create the file and write cache contents
-            let mut file = OpenOptions::new()
-                .write(true)
-                .create(true)
-                .truncate(true)
-                .open(path.as_path())?;
-            io::copy(&mut cache.cursor(), &mut file)?;
-            file.flush()?;
-            file.rewind()?;
-            return Ok(());
+        let data = cache.cursor().into_inner();
+        let mut meta = FileMeta::new(cache.url().map(str::to_string), data);
+        meta.set_namespace(cache.namespace().map(str::to_string));
+        Self::write_entry(task_id, data, &meta)
+    }
+
+    /// Creates a cache file holding `data`, described by `meta`.
+    ///
+    /// Writes data and its metadata to temporary files flushed to the storage
+    /// device, and then atomically renames the metadata and the data, the
+    /// latter with the finish suffix to indicate it's complete.
+    ///
+    /// # Errors
+    /// Returns `io::Error` if any file operation fails.
+    pub(crate) fn write_entry(task_id: &TaskId, data: &[u8], meta: &FileMeta) -> io::Result<()> {
+        let (Some(path), Some(temp), Some(meta_path), Some(meta_temp)) = (
+            Self::path(task_id),
+            Self::temp_path(task_id),
+            Self::meta_path(task_id),
+            Self::meta_temp_path(task_id),
+        ) else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "cache store dir not created.",
+            ));
+        };
+        let res = Self::write_temp(&temp, data)
+            .and_then(|_| meta.write(&meta_temp))
+            .and_then(|_| fs::rename(&meta_temp, &meta_path))
+            .and_then(|_| fs::rename(&temp, &path))
+            .and_then(|_| sync_parent_dir(&path));
+        if res.is_err() {
+            let _ = fs::remove_file(&temp);
+            let _ = fs::remove_file(&meta_temp);
         }
-        Err(io::Error::new(
-            io::ErrorKind::NotFound,
-            "cache store dir not created.",
-        ))
+        res
+    }
+
+    /// Records in the metadata of a task's cache file whether it is pinned.
+    ///
+    /// The metadata is replaced atomically. Cache files without metadata, as
+    /// written by older versions, get metadata computed from their content.
+    ///
+    /// # Errors
+    /// Returns `io::Error` if the cache file or its metadata cannot be read,
+    /// or the metadata cannot be written.
+    pub(crate) fn set_pinned(task_id: &TaskId, pinned: bool) -> io::Result<()> {
+        let (Some(meta_path), Some(temp)) =
+            (Self::meta_path(task_id), Self::meta_temp_path(task_id))
+        else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "cache store dir not created.",
+            ));
+        };
+        let mut meta = match FileMeta::read(&meta_path)? {
+            Some(meta) if meta.pinned() == pinned => return Ok(()),
+            Some(meta) => meta,
+            None => FileMeta::from_reader(None, Self::open(task_id)?)?,
+        };
+        meta.set_pinned(pinned);
+        let res = meta
+            .write(&temp)
+            .and_then(|_| fs::rename(&temp, &meta_path))
+            .and_then(|_| sync_parent_dir(&meta_path));
+        if res.is_err() {
+            let _ = fs::remove_file(&temp);
+        }
+        res
+    }
+
+    /// Writes data to a temporary file and flushes it to the storage device.
+    fn write_temp(temp: &Path, data: &[u8]) -> io::Result<()> {
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(temp)?;
+        file.write_all(data)?;
+        file.sync_all()
     }
 
     /// Opens the cache file for the given task for reading.
@@ -488,6 +583,8 @@ impl FileCache {
     ///
     /// # Errors
     /// Returns `io::Error` if the file cannot be opened or read.
+    ///
+    /// Returns `InvalidData` if the content does not match its metadata.
     pub(crate) fn read(task_id: &TaskId, handle: &'static CacheManager) -> io::Result<RamCache> {
         let mut file = Self::open(task_id).map_err(|e| {
             error!("{:?} open file failed {:?}", task_id.brief(), e);
@@ -497,6 +594,53 @@ impl FileCache {
         FileCache::copy_file_to_cache(task_id, handle, &mut file, Some(size as usize))
     }
 
+    /// Reads a range of a task's cache file without loading the rest of it.
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task whose cache file should be read.
+    /// * `offset` - Offset of the first byte to read.
+    /// * `len` - Maximum number of bytes to read.
+    ///
+    /// # Returns
+    /// The bytes of the range, fewer than `len` if it runs past the end of
+    /// the file; only the bytes within the file are allocated.
+    ///
+    /// # Errors
+    /// Returns `io::Error` if the file cannot be opened or read, and
+    /// `InvalidData` if its size does not match its metadata.
+    pub(crate) fn read_range(task_id: &TaskId, offset: u64, len: usize) -> io::Result<Vec<u8>> {
+        let file = Self::open(task_id).map_err(|e| {
+            error!("{:?} open file failed {:?}", task_id.brief(), e);
+            e
+        })?;
+        let size = file.metadata()?.size();
+        if let Some(meta) = Self::read_meta(task_id)? {
+            if size != meta.size() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "corrupt cache file, size {} instead of {}",
+                        size,
+                        meta.size()
+                    ),
+                ));
+            }
+        }
+        let len = size.saturating_sub(offset).min(len as u64) as usize;
+        let mut buf = vec![0; len];
+        let mut read = 0;
+        while read < len {
+            match file.read_at(&mut buf[read..], offset + read as u64) {
+                Ok(0) => break,
+                Ok(n) => read += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        buf.truncate(read);
+        Ok(buf)
+    }
+
     /// Reads a task's cache file into a RAM cache without registering it with
     /// the cache manager.
     ///
@@ -505,7 +649,8 @@ impl FileCache {
     /// * `handle` - Cache manager used to allocate the RAM cache.
     ///
     /// # Errors
-    /// Returns `io::Error` if the file cannot be opened or read.
+    /// Returns `io::Error` if the file cannot be opened or read, and
+    /// `InvalidData` if the content does not match its metadata.
     pub(crate) fn read_but_not_cache(
         task_id: &TaskId,
         handle: &'static CacheManager,
@@ -517,7 +662,27 @@ impl FileCache {
         FileCache::copy_file_to_cache(task_id, handle, &mut file, None)
     }
 
-    /// Copies the contents of a file into a new RAM cache.
+    /// Reads a task's cache file through to verify its content against its
+    /// metadata if any, without keeping the content.
+    ///
+    /// # Returns
+    /// The metadata of the file and the size of its content.
+    ///
+    /// # Errors
+    /// Returns `io::Error` if the file cannot be opened or read, and
+    /// `InvalidData` if the content does not match its metadata.
+    pub(crate) fn check_entry(task_id: &TaskId) -> io::Result<(Option<FileMeta>, u64)> {
+        let meta = Self::read_meta(task_id)?;
+        let mut reader = ChecksumReader::new(Self::open(task_id)?);
+        io::copy(&mut reader, &mut io::sink())?;
+        if let Some(meta) = meta.as_ref() {
+            meta.verify(reader.size(), reader.checksum())?;
+        }
+        Ok((meta, reader.size()))
+    }
+
+    /// Copies the contents of a file into a new RAM cache, verifying them
+    /// against the metadata of the file if any.
     ///
     /// # Arguments
     /// * `task_id` - ID of the task the cache belongs to.
@@ -526,21 +691,43 @@ impl FileCache {
     /// * `size` - Expected content size, or `None` if unknown.
     ///
     /// # Errors
-    /// Returns `io::Error` if copying from the file fails.
+    /// Returns `io::Error` if copying from the file fails, and `InvalidData`
+    /// if the content does not match its metadata.
     fn copy_file_to_cache(
         task_id: &TaskId,
         handle: &'static CacheManager,
         file: &mut File,
         size: Option<usize>,
     ) -> io::Result<RamCache> {
+        let meta = Self::read_meta(task_id)?;
         let mut cache = RamCache::new(task_id.clone(), handle, size);
-        io::copy(file, &mut cache).map_err(|e| {
+        let mut reader = ChecksumReader::new(file);
+        io::copy(&mut reader, &mut cache).map_err(|e| {
             error!("{:?} copy file failed {:?}", task_id.brief(), e);
             e
         })?;
+        if let Some(meta) = meta {
+            meta.verify(reader.size(), reader.checksum()).map_err(|e| {
+                error!("{:?} verify file failed {:?}", task_id.brief(), e);
+                e
+            })?;
+            cache.set_url(meta.url().map(str::to_string));
+            cache.set_namespace(meta.namespace().map(str::to_string));
+        }
         Ok(cache)
     }
 
+    /// Reads the metadata of the cache file of a task.
+    ///
+    /// # Returns
+    /// `None` if the file has no metadata, as written by older versions.
+    pub(crate) fn read_meta(task_id: &TaskId) -> io::Result<Option<FileMeta>> {
+        match Self::meta_path(task_id) {
+            Some(path) => FileMeta::read(&path),
+            None => Ok(None),
+        }
+    }
+
     /// Gets the path to the cache file for the given task ID.
     ///
     /// # Arguments
@@ -552,32 +739,67 @@ impl FileCache {
         // SAFETY: This is a read-only operation that joins a path
         unsafe { FILE_STORE_DIR.join(task_id.to_string() + FINISH_SUFFIX) }
     }
+
+    /// Gets the path to the metadata file of the cache file of a task.
+    pub(crate) fn meta_path(task_id: &TaskId) -> Option<PathBuf> {
+        // SAFETY: This is a read-only operation that joins a path
+        unsafe { FILE_STORE_DIR.join(task_id.to_string() + META_SUFFIX) }
+    }
+
+    /// Gets the path to the cache file of a task while it is written.
+    fn temp_path(task_id: &TaskId) -> Option<PathBuf> {
+        // SAFETY: This is a read-only operation that joins a path
+        unsafe { FILE_STORE_DIR.join(task_id.to_string() + TEMP_SUFFIX) }
+    }
+
+    /// Gets the path to the metadata file of a task while it is written.
+    fn meta_temp_path(task_id: &TaskId) -> Option<PathBuf> {
+        // SAFETY: This is a read-only operation that joins a path
+        unsafe { FILE_STORE_DIR.join(task_id.to_string() + META_TEMP_SUFFIX) }
+    }
+}
+
+/// Flushes the entries of the directory containing `path` to the storage
+/// device, making a rename into it durable.
+pub(crate) fn sync_parent_dir(path: &Path) -> io::Result<()> {
+    match path.parent() {
+        Some(dir) => File::open(dir)?.sync_all(),
+        None => Ok(()),
+    }
 }
 
 /// Metadata for a persisted cache file.
 pub(crate) struct FileCacheInfo {
     /// Task ID associated with the cache file.
     task_id: TaskId,
-    /// Modification time of the cache file.
+    /// Creation time of the cache entry, or modification time of the cache
+    /// file if it has no metadata.
     time: SystemTime,
     /// Size of the cache file in bytes.
     size: u64,
+    /// Namespace of the cache entry, `None` for the default namespace.
+    namespace: Option<String>,
+    /// Whether the cache entry is pinned.
+    pinned: bool,
 }
 
 impl FileCacheInfo {
-    /// Creates a new `FileCacheInfo` from the given task ID, time, and size.
+    /// Creates a new `FileCacheInfo` from the given task ID, time, and size,
+    /// unpinned in the default namespace.
     pub(crate) fn new(task_id: TaskId, time: SystemTime, size: u64) -> Self {
         Self {
             task_id,
             time,
             size,
+            namespace: None,
+            pinned: false,
         }
     }
     /// Returns the task ID associated with this cache file.
     pub(crate) fn task_id(&self) -> &TaskId {
         &self.task_id
     }
-    /// Returns the modification time of this cache file.
+    /// Returns the creation time of this cache entry.
     pub(crate) fn time(&self) -> SystemTime {
         self.time
     }
@@ -585,6 +807,14 @@ impl FileCacheInfo {
     pub(crate) fn size(&self) -> u64 {
         self.size
     }
+    /// Returns the namespace of this cache entry.
+    pub(crate) fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+    /// Returns whether this cache entry is pinned.
+    pub(crate) fn pinned(&self) -> bool {
+        self.pinned
+    }
 }
 
 /// Returns an iterator over the metadata of all valid cache files in the
@@ -597,7 +827,7 @@ pub(crate) fn get_cached_files_info() -> Option<impl Iterator<Item = FileCacheIn
 }
 
 /// Scans a directory and returns metadata for each valid cache file, sorted
-/// by modification time.
+/// by creation time.
 ///
 /// # Arguments
 /// * `path` - Directory to scan for cache files.
@@ -615,7 +845,7 @@ pub(crate) fn get_info_from_path(path: &Path) -> impl Iterator<Item = FileCacheI
     let mut v = files
         .into_iter()
         .filter_map(|entry| match get_entry_file_info(entry) {
-            Ok(info) => Some(info),
+            Ok(info) => info,
             Err(e) => {
                 error!("restore file error {}", e);
                 None
@@ -629,15 +859,22 @@ pub(crate) fn get_info_from_path(path: &Path) -> impl Iterator<Item = FileCacheI
 
 /// Extracts cache file metadata from a directory entry.
 ///
-/// Incomplete files (missing the finish suffix) are removed from disk.
+/// Incomplete files (missing the finish suffix), cache files whose size does
+/// not match their metadata and metadata files without cache file are removed
+/// from disk.
 ///
 /// # Arguments
 /// * `entry` - Directory entry to inspect.
 ///
+/// # Returns
+/// `None` for metadata files, which are read with their cache file.
+///
 /// # Errors
 /// Returns `io::Error` if the entry cannot be read, the file name is invalid
-/// UTF-8, the file is incomplete, or metadata cannot be retrieved.
-pub(crate) fn get_entry_file_info(entry: io::Result<DirEntry>) -> Result<FileCacheInfo, io::Error> {
+/// UTF-8, the file is incomplete or corrupt, or metadata cannot be retrieved.
+pub(crate) fn get_entry_file_info(
+    entry: io::Result<DirEntry>,
+) -> Result<Option<FileCacheInfo>, io::Error> {
     let entry = entry?;
     // Get the file name and validate it
     let file_name = entry.file_name();
@@ -646,6 +883,16 @@ pub(crate) fn get_entry_file_info(entry: io::Result<DirEntry>) -> Result<FileCac
         format!("invalid file name {:?}", file_name),
     ))?;
 
+    if let Some(task_id) = file_name.strip_suffix(META_SUFFIX) {
+        let finished = entry
+            .path()
+            .with_file_name(format!("{}{}", task_id, FINISH_SUFFIX));
+        if !finished.exists() {
+            let _ = fs::remove_file(entry.path());
+        }
+        return Ok(None);
+    }
+
     // Check for the finish suffix to ensure the file is complete
     if !file_name.ends_with(FINISH_SUFFIX) {
         // Remove incomplete files
@@ -657,11 +904,35 @@ pub(crate) fn get_entry_file_info(entry: io::Result<DirEntry>) -> Result<FileCac
     }
 
     // Extract the task ID from the file name
-    let task_id = TaskId::new(file_name.trim_end_matches(FINISH_SUFFIX).to_string());
-    // Get the modification time
-    let time = entry.metadata()?.modified()?;
+    let task_id = file_name.trim_end_matches(FINISH_SUFFIX);
+    let meta_path = entry
+        .path()
+        .with_file_name(format!("{}{}", task_id, META_SUFFIX));
     let size = entry.metadata()?.len();
-    Ok(FileCacheInfo::new(task_id, time, size))
+    let (time, namespace, pinned) = match FileMeta::read(&meta_path) {
+        Ok(Some(meta)) if meta.size() == size => (
+            meta.created(),
+            meta.namespace().map(str::to_string),
+            meta.pinned(),
+        ),
+        // Files written before the metadata existed keep their modification
+        // time
+        Ok(None) => (entry.metadata()?.modified()?, None, false),
+        res => {
+            let _ = fs::remove_file(&meta_path);
+            let _ = fs::remove_file(entry.path());
+            return Err(res.err().unwrap_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("corrupt file {}", file_name),
+                )
+            }));
+        }
+    };
+    let mut info = FileCacheInfo::new(TaskId::new(task_id.to_string()), time, size);
+    info.namespace = namespace;
+    info.pinned = pinned;
+    Ok(Some(info))
 }
 
 #[cfg(test)]
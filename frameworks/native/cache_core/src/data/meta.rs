@@ -0,0 +1,347 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integrity metadata of cache files.
+//!
+//! Every cache file is accompanied by a small sidecar file holding the origin
+//! URL of the resource, its response headers if known, the namespace of the
+//! entry, its size, its CRC-32 checksum, the time the entry was created and
+//! whether it is pinned. The
+//! sidecar is written before the cache file is renamed to its final name, so a
+//! finished cache file always has the metadata of its content; entries whose
+//! content does not match are purged.
+//!
+//! Cache files written before the metadata existed have no sidecar and are
+//! trusted as they are.
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Suffix of the metadata sidecar files.
+pub(crate) const META_SUFFIX: &str = "_M";
+
+/// Version of the metadata format.
+const META_VERSION: u32 = 1;
+
+/// Metadata of a cache file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct FileMeta {
+    /// Origin URL of the resource, if known
+    url: Option<String>,
+    /// Namespace of the entry, `None` for the default namespace
+    namespace: Option<String>,
+    /// Size of the content in bytes
+    size: u64,
+    /// CRC-32 checksum of the content
+    checksum: u32,
+    /// Time the entry was created
+    created: SystemTime,
+    /// Whether the entry is pinned
+    pinned: bool,
+    /// Response headers of the resource, if known
+    headers: Vec<(String, String)>,
+}
+
+impl FileMeta {
+    /// Creates the metadata of a content created now.
+    pub(crate) fn new(url: Option<String>, data: &[u8]) -> Self {
+        let mut crc = Crc32::new();
+        crc.update(data);
+        Self {
+            url,
+            namespace: None,
+            size: data.len() as u64,
+            checksum: crc.finish(),
+            created: SystemTime::now(),
+            pinned: false,
+            headers: vec![],
+        }
+    }
+
+    /// Creates the metadata of a content read from `reader`, created now.
+    ///
+    /// # Errors
+    /// Returns `io::Error` if reading fails.
+    pub(crate) fn from_reader<R: Read>(url: Option<String>, reader: R) -> io::Result<Self> {
+        let mut reader = ChecksumReader::new(reader);
+        io::copy(&mut reader, &mut io::sink())?;
+        Ok(Self {
+            url,
+            namespace: None,
+            size: reader.size(),
+            checksum: reader.checksum(),
+            created: SystemTime::now(),
+            pinned: false,
+            headers: vec![],
+        })
+    }
+
+    /// Returns the origin URL of the resource, if known.
+    pub(crate) fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    /// Returns the namespace of the entry, `None` for the default namespace.
+    pub(crate) fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// Records the namespace of the entry.
+    pub(crate) fn set_namespace(&mut self, namespace: Option<String>) {
+        self.namespace = namespace;
+    }
+
+    /// Returns whether the entry is pinned.
+    pub(crate) fn pinned(&self) -> bool {
+        self.pinned
+    }
+
+    /// Records whether the entry is pinned.
+    pub(crate) fn set_pinned(&mut self, pinned: bool) {
+        self.pinned = pinned;
+    }
+
+    /// Returns the response headers of the resource.
+    pub(crate) fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// Records the response headers of the resource.
+    pub(crate) fn set_headers(&mut self, headers: Vec<(String, String)>) {
+        self.headers = headers;
+    }
+
+    /// Returns the size of the content in bytes.
+    pub(crate) fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the time the entry was created.
+    pub(crate) fn created(&self) -> SystemTime {
+        self.created
+    }
+
+    /// Checks that a content matches the metadata.
+    ///
+    /// # Errors
+    /// Returns `InvalidData` if the size or the checksum differ.
+    pub(crate) fn verify(&self, size: u64, checksum: u32) -> io::Result<()> {
+        if size != self.size {
+            return Err(corrupt(format!("size {} instead of {}", size, self.size)));
+        }
+        if checksum != self.checksum {
+            return Err(corrupt(format!(
+                "checksum {:08x} instead of {:08x}",
+                checksum, self.checksum
+            )));
+        }
+        Ok(())
+    }
+
+    /// Reads the metadata stored at `path`.
+    ///
+    /// # Returns
+    /// `None` if there is no metadata file.
+    ///
+    /// # Errors
+    /// Returns `InvalidData` if the file is malformed, or `io::Error` if it
+    /// cannot be read.
+    pub(crate) fn read(path: &Path) -> io::Result<Option<Self>> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::decode(&text)
+                .map(Some)
+                .ok_or_else(|| corrupt("malformed metadata".to_string())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Writes the metadata to `path` and flushes it to the storage device.
+    ///
+    /// # Errors
+    /// Returns `io::Error` if the file cannot be written.
+    pub(crate) fn write(&self, path: &Path) -> io::Result<()> {
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        file.write_all(self.encode().as_bytes())?;
+        file.sync_all()
+    }
+
+    /// Encodes the metadata as `key=value` lines.
+    pub(crate) fn encode(&self) -> String {
+        let created = self
+            .created
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        let mut text = format!(
+            "version={}\nsize={}\ncrc32={:08x}\ncreated={}\n",
+            META_VERSION, self.size, self.checksum, created
+        );
+        if let Some(namespace) = self.namespace.as_ref() {
+            text.push_str(&format!("namespace={}\n", namespace));
+        }
+        if self.pinned {
+            text.push_str("pinned=1\n");
+        }
+        for (name, value) in self.headers.iter() {
+            text.push_str(&format!("header={}:{}\n", name, value));
+        }
+        if let Some(url) = self.url.as_ref() {
+            text.push_str(&format!("url={}\n", url));
+        }
+        text
+    }
+
+    /// Decodes metadata encoded by [`FileMeta::encode`].
+    ///
+    /// # Returns
+    /// `None` if a field is missing or malformed, or the version is unknown.
+    pub(crate) fn decode(text: &str) -> Option<Self> {
+        let mut version = None;
+        let mut size = None;
+        let mut checksum = None;
+        let mut created = None;
+        let mut url = None;
+        let mut namespace = None;
+        let mut pinned = false;
+        let mut headers = vec![];
+        for line in text.lines().filter(|line| !line.is_empty()) {
+            let (key, value) = line.split_once('=')?;
+            match key {
+                "version" => version = value.parse::<u32>().ok(),
+                "size" => size = value.parse().ok(),
+                "crc32" => checksum = u32::from_str_radix(value, 16).ok(),
+                "created" => created = value.parse().ok().map(Duration::from_millis),
+                "namespace" => namespace = Some(value.to_string()),
+                "pinned" => pinned = value == "1",
+                "header" => {
+                    let (name, value) = value.split_once(':')?;
+                    headers.push((name.to_string(), value.to_string()));
+                }
+                "url" => url = Some(value.to_string()),
+                _ => {}
+            }
+        }
+        if version? != META_VERSION {
+            return None;
+        }
+        Some(Self {
+            url,
+            namespace,
+            size: size?,
+            checksum: checksum?,
+            created: UNIX_EPOCH + created?,
+            pinned,
+            headers,
+        })
+    }
+}
+
+/// Reader computing the size and the CRC-32 checksum of the bytes read.
+pub(crate) struct ChecksumReader<R> {
+    inner: R,
+    crc: Crc32,
+    size: u64,
+}
+
+impl<R: Read> ChecksumReader<R> {
+    /// Creates a reader over `inner`.
+    pub(crate) fn new(inner: R) -> Self {
+        Self {
+            inner,
+            crc: Crc32::new(),
+            size: 0,
+        }
+    }
+
+    /// Returns the number of bytes read.
+    pub(crate) fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the checksum of the bytes read.
+    pub(crate) fn checksum(&self) -> u32 {
+        self.crc.finish()
+    }
+}
+
+impl<R: Read> Read for ChecksumReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.crc.update(&buf[..n]);
+        self.size += n as u64;
+        Ok(n)
+    }
+}
+
+/// Incremental CRC-32 (IEEE 802.3) computation.
+#[derive(Clone, Copy)]
+pub(crate) struct Crc32(u32);
+
+impl Crc32 {
+    /// Lookup table of the reflected polynomial.
+    const TABLE: [u32; 256] = {
+        let mut table = [0u32; 256];
+        let mut i = 0;
+        while i < 256 {
+            let mut crc = i as u32;
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if crc & 1 == 1 {
+                    (crc >> 1) ^ 0xEDB8_8320
+                } else {
+                    crc >> 1
+                };
+                bit += 1;
+            }
+            table[i] = crc;
+            i += 1;
+        }
+        table
+    };
+
+    /// Starts a computation.
+    pub(crate) fn new() -> Self {
+        Self(!0)
+    }
+
+    /// Adds bytes to the computation.
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        for byte in data {
+            self.0 = Self::TABLE[((self.0 ^ *byte as u32) & 0xFF) as usize] ^ (self.0 >> 8);
+        }
+    }
+
+    /// Returns the checksum of the bytes added so far.
+    pub(crate) fn finish(&self) -> u32 {
+        !self.0
+    }
+}
+
+fn corrupt(msg: String) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("corrupt cache file, {}", msg),
+    )
+}
+
+#[cfg(test)]
+mod ut_meta {
+    include!("../../tests/ut/data/ut_meta.rs");
+}
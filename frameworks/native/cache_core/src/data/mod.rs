@@ -17,6 +17,8 @@
 //! the file cache, RAM cache, space manager, and store directory helpers.
 
 mod file;
+mod meta;
+mod partial;
 mod ram;
 mod space;
 
@@ -28,6 +30,8 @@ pub use file::{
     get_curr_store_dir, init_curr_store_dir, init_history_store_dir, is_history_init, FileStoreDir,
     HistoryDir,
 };
+pub(crate) use meta::{ChecksumReader, Crc32, FileMeta};
+pub(crate) use partial::{PartialFile, RangeMap};
 /// Re-export of the in-memory RAM cache type.
 pub use ram::RamCache;
 pub(crate) use space::SpaceManager;
@@ -0,0 +1,264 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Partial caching of large resources.
+//!
+//! A partial entry stores the downloaded segments of a resource at their
+//! offsets in a sparse file, and tracks which byte ranges are present in a
+//! `RangeMap`. Once every byte of the resource is present, the partial file is
+//! promoted to a regular cache file.
+//!
+//! The range map is kept in memory only: partial files do not carry the finish
+//! suffix, so the files left by a previous session are removed when the cache
+//! index is restored.
+
+use std::fs::{self, File, OpenOptions};
+use std::io;
+use std::ops::Range;
+use std::os::unix::fs::FileExt;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use request_utils::task_id::TaskId;
+
+use super::file::{sync_parent_dir, FileCache, FILE_STORE_DIR};
+use super::meta::FileMeta;
+
+/// Suffix of the sparse files holding partial entries.
+const PARTIAL_SUFFIX: &str = "_P";
+
+/// Set of byte ranges, kept sorted and merged.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct RangeMap {
+    /// Disjoint, non-adjacent ranges in increasing order
+    ranges: Vec<Range<u64>>,
+}
+
+impl RangeMap {
+    /// Creates an empty range map.
+    pub(crate) fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    /// Adds a range, merging it with the overlapping and adjacent ranges.
+    pub(crate) fn insert(&mut self, range: Range<u64>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let mut merged = range;
+        if first < last {
+            merged.start = merged.start.min(self.ranges[first].start);
+            merged.end = merged.end.max(self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Returns whether every byte of the range is present.
+    pub(crate) fn contains(&self, range: &Range<u64>) -> bool {
+        range.is_empty()
+            || self
+                .ranges
+                .iter()
+                .any(|r| r.start <= range.start && range.end <= r.end)
+    }
+
+    /// Returns the sub-ranges of `range` that are not present, in order.
+    pub(crate) fn missing(&self, range: &Range<u64>) -> Vec<Range<u64>> {
+        let mut missing = Vec::new();
+        let mut start = range.start;
+        for r in self.ranges.iter() {
+            if r.end <= start {
+                continue;
+            }
+            if r.start >= range.end {
+                break;
+            }
+            if r.start > start {
+                missing.push(start..r.start);
+            }
+            start = r.end;
+        }
+        if start < range.end {
+            missing.push(start..range.end);
+        }
+        missing
+    }
+
+    /// Returns the number of bytes present.
+    pub(crate) fn covered(&self) -> u64 {
+        self.ranges.iter().map(|r| r.end - r.start).sum()
+    }
+}
+
+/// Partial entry of a resource, stored in a sparse file.
+pub(crate) struct PartialFile {
+    /// ID of the task associated with this entry
+    task_id: TaskId,
+    /// Total size of the resource, once known
+    total: Option<u64>,
+    /// Byte ranges present in the file
+    ranges: RangeMap,
+    /// Namespace whose budget holds the file, `None` for the default one
+    namespace: Option<String>,
+    /// Time the entry was last written or read
+    accessed: SystemTime,
+}
+
+impl PartialFile {
+    /// Creates an empty partial entry.
+    pub(crate) fn new(task_id: TaskId) -> Self {
+        Self {
+            task_id,
+            total: None,
+            ranges: RangeMap::new(),
+            namespace: None,
+            accessed: SystemTime::now(),
+        }
+    }
+
+    /// Returns the time the entry was last written or read.
+    pub(crate) fn accessed(&self) -> SystemTime {
+        self.accessed
+    }
+
+    /// Marks the entry as accessed now.
+    pub(crate) fn touch(&mut self) {
+        self.accessed = SystemTime::now();
+    }
+
+    /// Returns the namespace whose budget holds the file.
+    pub(crate) fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// Records the namespace whose budget holds the file.
+    pub(crate) fn set_namespace(&mut self, namespace: Option<String>) {
+        self.namespace = namespace;
+    }
+
+    /// Returns the total size of the resource, if known.
+    pub(crate) fn total(&self) -> Option<u64> {
+        self.total
+    }
+
+    /// Records the total size of the resource.
+    pub(crate) fn set_total(&mut self, total: u64) {
+        self.total = Some(total);
+    }
+
+    /// Returns the byte ranges present in the file.
+    pub(crate) fn ranges(&self) -> &RangeMap {
+        &self.ranges
+    }
+
+    /// Returns the number of bytes stored.
+    pub(crate) fn size(&self) -> u64 {
+        self.ranges.covered()
+    }
+
+    /// Returns the number of bytes a segment would add to the file.
+    pub(crate) fn added_size(&self, offset: u64, len: usize) -> u64 {
+        self.ranges
+            .missing(&(offset..offset + len as u64))
+            .iter()
+            .map(|r| r.end - r.start)
+            .sum()
+    }
+
+    /// Returns whether every byte of the resource is present.
+    pub(crate) fn is_complete(&self) -> bool {
+        self.total
+            .is_some_and(|total| self.ranges.contains(&(0..total)))
+    }
+
+    /// Writes a segment at its offset in the file.
+    ///
+    /// # Errors
+    /// Returns `io::Error` if the store directory or the file is unavailable.
+    pub(crate) fn write(&mut self, offset: u64, data: &[u8]) -> io::Result<()> {
+        let path = self.path()?;
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        file.write_all_at(data, offset)?;
+        self.ranges.insert(offset..offset + data.len() as u64);
+        self.touch();
+        Ok(())
+    }
+
+    /// Reads a range of the file.
+    ///
+    /// # Errors
+    /// Returns `NotFound` if some byte of the range is not present.
+    pub(crate) fn read(&self, offset: u64, len: usize) -> io::Result<Vec<u8>> {
+        let end = offset
+            .checked_add(len as u64)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "range overflows"))?;
+        if !self.ranges.contains(&(offset..end)) {
+            return Err(io::Error::new(io::ErrorKind::NotFound, "range not cached"));
+        }
+        let file = OpenOptions::new().read(true).open(self.path()?)?;
+        let mut buf = vec![0; len];
+        file.read_exact_at(&mut buf, offset)?;
+        Ok(buf)
+    }
+
+    /// Turns the complete file into the regular cache file of the task.
+    ///
+    /// The file is flushed to the storage device and its metadata written
+    /// before it is renamed, like the cache files written at once.
+    ///
+    /// # Errors
+    /// Returns `io::Error` if the file cannot be flushed, read or renamed.
+    pub(crate) fn promote(&self) -> io::Result<()> {
+        let not_created =
+            || io::Error::new(io::ErrorKind::NotFound, "cache store dir not created.");
+        let to = FileCache::path(&self.task_id).ok_or_else(not_created)?;
+        let meta_path = FileCache::meta_path(&self.task_id).ok_or_else(not_created)?;
+        let path = self.path()?;
+        let file = File::open(&path)?;
+        file.sync_all()?;
+        let mut meta = FileMeta::from_reader(None, file)?;
+        meta.set_namespace(self.namespace.clone());
+        meta.write(&meta_path)?;
+        fs::rename(path, &to)?;
+        sync_parent_dir(&to)
+    }
+
+    /// Removes the file from disk.
+    pub(crate) fn remove(&self) {
+        if let Ok(path) = self.path() {
+            if let Err(e) = fs::remove_file(path) {
+                if e.kind() != io::ErrorKind::NotFound {
+                    error!("{} remove partial file error: {}", self.task_id.brief(), e);
+                }
+            }
+        }
+    }
+
+    /// Gets the path to the sparse file of the entry.
+    fn path(&self) -> io::Result<PathBuf> {
+        // SAFETY: This is a read-only operation that joins a path
+        unsafe { FILE_STORE_DIR.join(self.task_id.to_string() + PARTIAL_SUFFIX) }
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "cache store dir not created."))
+    }
+}
+
+#[cfg(test)]
+mod ut_partial {
+    include!("../../tests/ut/data/ut_partial.rs");
+}
@@ -20,7 +20,8 @@
 
 use std::cmp::Ordering;
 use std::io::{Cursor, Write};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
 
 use request_utils::task_id::TaskId;
 
@@ -39,8 +40,14 @@ pub struct RamCache {
     pub(super) task_id: TaskId,
     /// Binary data stored in the cache
     data: Vec<u8>,
+    /// Origin URL of the data, if known
+    url: Option<String>,
+    /// Namespace of the entry, `None` for the default namespace
+    namespace: Option<String>,
     /// Amount of memory allocated for this cache (in bytes)
     applied: u64,
+    /// Time the data was last served
+    accessed: Mutex<SystemTime>,
     /// Reference to the cache manager controlling this cache
     handle: &'static CacheManager,
 }
@@ -75,7 +82,7 @@ impl RamCache {
     pub(crate) fn new(task_id: TaskId, handle: &'static CacheManager, size: Option<usize>) -> Self {
         let applied = match size {
             Some(size) => {
-                if CacheManager::apply_cache(&handle.ram_handle, &handle.rams, size) {
+                if handle.apply_cache(size) {
                     info!("apply ram {} for {}", size, task_id.brief());
                     size as u64
                 } else {
@@ -89,7 +96,10 @@ impl RamCache {
         Self {
             task_id,
             data: Vec::with_capacity(size.unwrap_or(DEFAULT_TRUNK_CAPACITY)),
+            url: None,
+            namespace: None,
             applied,
+            accessed: Mutex::new(SystemTime::now()),
             handle,
         }
     }
@@ -131,9 +141,7 @@ impl RamCache {
             Ordering::Equal => true,
             Ordering::Greater => {
                 let diff = self.data.len() - self.applied as usize;
-                if self.data.len() > MAX_CACHE_SIZE as usize
-                    || !CacheManager::apply_cache(&self.handle.ram_handle, &self.handle.rams, diff)
-                {
+                if self.data.len() > MAX_CACHE_SIZE as usize || !self.handle.apply_cache(diff) {
                     // Exceeds maximum allowed size or failed to allocate additional memory
                     info!(
                         "apply extra ram {} cache for {} failed",
@@ -175,6 +183,38 @@ impl RamCache {
         &self.task_id
     }
 
+    /// Records the origin URL of the data, stored in the metadata of the
+    /// cache file.
+    pub(crate) fn set_url(&mut self, url: Option<String>) {
+        self.url = url;
+    }
+
+    /// Returns the origin URL of the data, if known.
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    /// Records the namespace of the entry, whose file cache budget holds the
+    /// cache file.
+    pub(crate) fn set_namespace(&mut self, namespace: Option<String>) {
+        self.namespace = namespace;
+    }
+
+    /// Returns the namespace of the entry, `None` for the default namespace.
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// Returns the time the data was last served.
+    pub fn last_access(&self) -> SystemTime {
+        *self.accessed.lock().unwrap()
+    }
+
+    /// Marks the data as served now.
+    pub(crate) fn touch(&self) {
+        *self.accessed.lock().unwrap() = SystemTime::now();
+    }
+
     /// Returns the current size of the cached data.
     ///
     /// # Returns
@@ -183,6 +223,20 @@ impl RamCache {
         self.data.len()
     }
 
+    /// Copies a range of the cached data.
+    ///
+    /// # Arguments
+    /// * `offset` - Offset of the first byte to copy
+    /// * `len` - Maximum number of bytes to copy
+    ///
+    /// # Returns
+    /// The bytes of the range, fewer than `len` if it runs past the end
+    pub fn read_range(&self, offset: u64, len: usize) -> Vec<u8> {
+        let start = offset.min(self.data.len() as u64) as usize;
+        let end = start.saturating_add(len).min(self.data.len());
+        self.data[start..end].to_vec()
+    }
+
     /// Creates a cursor for reading the cached data.
     ///
     /// # Returns
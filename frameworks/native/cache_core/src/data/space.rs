@@ -72,6 +72,16 @@ impl SpaceManager {
         self.used_capacity -= size;
     }
 
+    /// Returns the used capacity in bytes.
+    pub(crate) fn used(&self) -> u64 {
+        self.used_capacity
+    }
+
+    /// Returns the total capacity in bytes.
+    pub(crate) fn total(&self) -> u64 {
+        self.total_capacity
+    }
+
     /// Updates the total capacity of the resource manager.
     ///
     /// # Arguments
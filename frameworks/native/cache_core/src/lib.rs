@@ -31,16 +31,27 @@
 #[macro_use]
 extern crate request_utils;
 
+mod archive;
 mod data;
 mod manage;
+mod stats;
+mod stream;
 mod update;
 
 pub mod observe;
 
+/// Archives of cached responses to import into and export from the cache.
+pub use archive::{ArchiveEntry, ArchiveReader, ArchiveWriter, ARCHIVE_VERSION};
 /// In-memory cache implementation for task data.
 pub use data::{init_curr_store_dir, RamCache};
 /// Central manager for cache operations and resources.
 pub use manage::CacheManager;
+/// Statistics and entries of the cache, and listener of its evictions.
+pub use stats::{
+    CacheCounters, CacheEntry, CacheStats, CacheTier, EvictionListener, NamespaceStats, TierUsage,
+};
+/// Reader of cache entries that may still be downloading.
+pub use stream::CacheReader;
 /// Handles cache updates and synchronization operations.
 pub use update::Updater;
 
@@ -20,16 +20,24 @@
 
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::io;
+use std::io::{self, Read, Write};
+use std::ops::Range;
 use std::sync::{Arc, Condvar, Mutex, OnceLock, Weak};
+use std::time::SystemTime;
 
 use request_utils::lru::LRUCache;
 use request_utils::task_id::TaskId;
 
 use super::data::{
-    get_cached_files_info, FileCache, FileCacheInfo, RamCache, SpaceManager, MAX_CACHE_SIZE,
+    get_cached_files_info, FileCache, FileCacheInfo, FileMeta, PartialFile, RamCache, SpaceManager,
+    MAX_CACHE_SIZE,
 };
+use crate::archive::{ArchiveEntry, ArchiveReader, ArchiveWriter};
 use crate::spawn;
+use crate::stats::{
+    CacheEntry, CacheStats, CacheTier, EvictionListener, NamespaceStats, StatsRecorder, TierUsage,
+};
+use crate::stream::{CacheReader, CacheStream};
 
 /// Default maximum size for RAM-based cache storage (20MB).
 const DEFAULT_RAM_CACHE_SIZE: u64 = 1024 * 1024 * 20;
@@ -37,8 +45,52 @@ const DEFAULT_RAM_CACHE_SIZE: u64 = 1024 * 1024 * 20;
 /// Default maximum size for file-based cache storage (100MB).
 const DEFAULT_FILE_CACHE_SIZE: u64 = 1024 * 1024 * 100;
 
+/// Default maximum size for pinned file-based cache storage (100MB).
+const DEFAULT_PINNED_CACHE_SIZE: u64 = 1024 * 1024 * 100;
+
+/// File caches and space budget of a named namespace.
+pub(crate) struct Namespace {
+    /// File-based cache storage of the namespace using LRU eviction policy
+    pub(crate) files: LRUCache<TaskId, Arc<Mutex<FileCache>>>,
+
+    /// Manages the file cache capacity of the namespace; only counts the
+    /// space used while the namespace shares the default budget
+    pub(crate) space: SpaceManager,
+
+    /// Whether the namespace has a budget of its own
+    pub(crate) configured: bool,
+}
+
+impl Namespace {
+    /// Creates an empty namespace with the given file cache size budget.
+    fn new(size: u64) -> Self {
+        Self {
+            files: LRUCache::new(),
+            space: SpaceManager::new(size),
+            configured: true,
+        }
+    }
+
+    /// Creates an empty namespace sharing the default file cache budget.
+    fn shared() -> Self {
+        Self {
+            files: LRUCache::new(),
+            space: SpaceManager::new(u64::MAX),
+            configured: false,
+        }
+    }
+}
+
 /// Container holding the file-backed caches, their space budget, and the
 /// per-task queues of pending serialized file operations.
+///
+/// Entries of the default namespace are stored in `files` within the budget of
+/// `file_space`; entries of named namespaces are stored in their namespace.
+/// A named namespace whose size was set is evicted within its own budget
+/// only, the others share the budget of the default namespace, so that
+/// namespaces cannot grow the disk usage by just being named. Pinned entries,
+/// whatever their namespace, are stored in `pinned` within a budget of their
+/// own and are never evicted.
 pub(crate) struct FileCaches {
     /// File-based cache storage using LRU eviction policy
     pub(crate) files: LRUCache<TaskId, Arc<Mutex<FileCache>>>,
@@ -46,22 +98,105 @@ pub(crate) struct FileCaches {
     /// Manages file cache resource allocation and capacity
     pub(crate) file_space: SpaceManager,
 
+    /// Named namespaces, by name
+    pub(crate) namespaces: HashMap<String, Namespace>,
+
+    /// Namespace of the entries stored in a named namespace
+    pub(crate) owners: HashMap<TaskId, String>,
+
+    /// Pinned entries and their space budget
+    pub(crate) pinned: Namespace,
+
+    /// Tasks pinned, whose entries are in `pinned` once cached
+    pub(crate) pins: HashSet<TaskId>,
+
     /// Per-task queues of pending file operations used to serialize access.
     pub(crate) operations: HashMap<TaskId, Arc<Mutex<VecDeque<Arc<NotifyCondition>>>>>,
+
+    /// Recorder of the evictions
+    pub(crate) stats: Arc<StatsRecorder>,
 }
 
 impl FileCaches {
     /// Creates an empty `FileCaches` with the default file cache size budget.
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(stats: Arc<StatsRecorder>) -> Self {
         Self {
             files: LRUCache::new(),
             file_space: SpaceManager::new(DEFAULT_FILE_CACHE_SIZE),
+            namespaces: HashMap::new(),
+            owners: HashMap::new(),
+            pinned: Namespace::new(DEFAULT_PINNED_CACHE_SIZE),
+            pins: HashSet::new(),
             operations: HashMap::new(),
+            stats,
+        }
+    }
+
+    /// Gets the entries of a namespace, creating a named namespace sharing
+    /// the default budget if absent.
+    ///
+    /// # Arguments
+    /// * `namespace` - Name of the namespace, `None` for the default one.
+    fn partition(
+        &mut self,
+        namespace: Option<&str>,
+    ) -> &mut LRUCache<TaskId, Arc<Mutex<FileCache>>> {
+        match namespace {
+            Some(name) => {
+                &mut self
+                    .namespaces
+                    .entry(name.to_string())
+                    .or_insert_with(Namespace::shared)
+                    .files
+            }
+            None => &mut self.files,
+        }
+    }
+
+    /// Gets the entries holding the entry of a task, which are the pinned
+    /// entries if the task is pinned.
+    fn partition_of(&mut self, task_id: &TaskId) -> &mut LRUCache<TaskId, Arc<Mutex<FileCache>>> {
+        if self.pins.contains(task_id) {
+            return &mut self.pinned.files;
+        }
+        let namespace = self.owners.get(task_id).cloned();
+        self.partition(namespace.as_deref())
+    }
+
+    /// Applies for space in a namespace, in the default budget if the
+    /// namespace has no budget of its own.
+    ///
+    /// # Returns
+    /// `true` if the space is reserved, `false` if it does not fit.
+    fn apply(&mut self, namespace: Option<&str>, size: u64) -> bool {
+        let Some(name) = namespace else {
+            return self.file_space.apply_cache_size(size);
+        };
+        let namespace = self
+            .namespaces
+            .entry(name.to_string())
+            .or_insert_with(Namespace::shared);
+        if !namespace.configured && !self.file_space.apply_cache_size(size) {
+            return false;
+        }
+        namespace.space.apply_cache_size(size)
+    }
+
+    /// Releases the space of the entry of a task, in the budget of the
+    /// pinned entries if the task is pinned.
+    fn release_entry(&mut self, task_id: &TaskId, size: u64) {
+        if self.pins.contains(task_id) {
+            self.pinned.space.release(size);
+        } else {
+            let namespace = self.owners.get(task_id).cloned();
+            self.release(namespace.as_deref(), size);
         }
     }
 
     /// Removes a task's file cache entry and releases its occupied space.
     ///
+    /// The task stays pinned, so that a new entry of the task is pinned too.
+    ///
     /// # Arguments
     /// * `task_id` - ID of the task whose file cache should be removed.
     ///
@@ -69,8 +204,12 @@ impl FileCaches {
     /// `Some(OperatingTask)` if an entry was removed, `None` if the task was
     /// not cached.
     pub(crate) fn remove(&mut self, task_id: &TaskId) -> Option<OperatingTask> {
-        if let Some(file_cache) = self.files.remove(task_id) {
-            self.file_space.release(file_cache.lock().unwrap().size());
+        let removed = self.partition_of(task_id).remove(task_id);
+        if let Some(file_cache) = removed.as_ref() {
+            self.release_entry(task_id, file_cache.lock().unwrap().size());
+        }
+        self.owners.remove(task_id);
+        if removed.is_some() {
             let operations = self.get_operations(task_id);
             Some(OperatingTask::new(task_id.clone(), operations))
         } else {
@@ -86,13 +225,124 @@ impl FileCaches {
         self.file_space.change_total_size(size);
     }
 
+    /// Updates the file cache capacity limit of a named namespace, creating
+    /// it if absent.
+    ///
+    /// A namespace sharing the default budget takes the space it uses out of
+    /// the default budget and has a budget of its own from now on.
+    ///
+    /// # Arguments
+    /// * `namespace` - Name of the namespace.
+    /// * `size` - New file cache size limit of the namespace in bytes.
+    pub(crate) fn change_namespace_size(&mut self, namespace: &str, size: u64) {
+        let namespace = self
+            .namespaces
+            .entry(namespace.to_string())
+            .or_insert_with(Namespace::shared);
+        if !namespace.configured {
+            self.file_space.release(namespace.space.used());
+            namespace.configured = true;
+        }
+        namespace.space.change_total_size(size);
+    }
+
+    /// Updates the capacity limit of the pinned entries.
+    ///
+    /// # Arguments
+    /// * `size` - New pinned file cache size limit in bytes.
+    pub(crate) fn change_pinned_size(&mut self, size: u64) {
+        self.pinned.space.change_total_size(size);
+    }
+
     /// Returns whether a file cache entry exists for the given task.
     pub(crate) fn contains(&self, task_id: &TaskId) -> bool {
-        self.files.contains_key(task_id)
+        if self.pinned.files.contains_key(task_id) {
+            return true;
+        }
+        match self.owners.get(task_id) {
+            Some(name) => self
+                .namespaces
+                .get(name)
+                .is_some_and(|namespace| namespace.files.contains_key(task_id)),
+            None => self.files.contains_key(task_id),
+        }
+    }
+
+    /// Marks the file cache entry of a task as recently used.
+    ///
+    /// # Returns
+    /// `true` if the task has a file cache entry, `false` otherwise.
+    pub(crate) fn touch(&mut self, task_id: &TaskId) -> bool {
+        match self.partition_of(task_id).get(task_id) {
+            Some(cache) => {
+                cache.lock().unwrap().touch(SystemTime::now());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the size of the file cache entry of a task, if any.
+    pub(crate) fn size(&mut self, task_id: &TaskId) -> Option<u64> {
+        let files = self.partition_of(task_id);
+        files.get(task_id).map(|cache| cache.lock().unwrap().size())
+    }
+
+    /// Records that the file cache entry of a task was read, without marking
+    /// it as recently used.
+    pub(crate) fn mark_read(&self, task_id: &TaskId) {
+        let files = match self.owners.get(task_id) {
+            _ if self.pins.contains(task_id) => &self.pinned.files,
+            Some(name) => match self.namespaces.get(name) {
+                Some(namespace) => &namespace.files,
+                None => return,
+            },
+            None => &self.files,
+        };
+        if let Some(cache) = files.peek(task_id) {
+            cache.lock().unwrap().touch(SystemTime::now());
+        }
+    }
+
+    /// Inserts the file cache entry of a task whose space was applied for, in
+    /// the pinned entries if the task is pinned.
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task.
+    /// * `namespace` - Namespace of the entry, `None` for the default one.
+    /// * `size` - Size of the cache file in bytes.
+    pub(crate) fn insert(&mut self, task_id: &TaskId, namespace: Option<&str>, size: u64) {
+        let cache = Arc::new(Mutex::new(FileCache::new(task_id.clone(), size)));
+        if let Some(name) = namespace {
+            self.owners.insert(task_id.clone(), name.to_string());
+        }
+        self.partition_of(task_id).insert(task_id.clone(), cache);
+    }
+
+    /// Releases space applied for in a namespace.
+    ///
+    /// # Arguments
+    /// * `namespace` - Namespace of the space, `None` for the default one.
+    /// * `size` - Amount of space to release in bytes.
+    pub(crate) fn release(&mut self, namespace: Option<&str>, size: u64) {
+        let Some(name) = namespace else {
+            return self.file_space.release(size);
+        };
+        let namespace = self
+            .namespaces
+            .entry(name.to_string())
+            .or_insert_with(Namespace::shared);
+        namespace.space.release(size);
+        if !namespace.configured {
+            self.file_space.release(size);
+        }
     }
 
     /// Attempts to restore a file cache entry from persisted info.
     ///
+    /// Pinned entries are restored in the pinned entries if they fit in
+    /// their budget, and as unpinned entries otherwise.
+    ///
     /// # Arguments
     /// * `info` - Metadata of the cache file to restore.
     ///
@@ -100,63 +350,272 @@ impl FileCaches {
     /// `true` if the entry was restored or already present, `false` if there
     /// was insufficient space to restore it.
     pub(crate) fn try_restore_file(&mut self, info: &FileCacheInfo) -> bool {
-        if self.files.contains_key(info.task_id()) {
+        if self.contains(info.task_id()) {
             return true;
         }
         let size = info.size();
-        if self.file_space.apply_cache_size(size) {
-            let cache = Arc::new(Mutex::new(FileCache::new(info.task_id().clone(), size)));
-            self.files.insert(info.task_id().clone(), cache);
+        if info.pinned() && self.pinned.space.apply_cache_size(size) {
+            self.pins.insert(info.task_id().clone());
+        } else if !self.apply(info.namespace(), size) {
+            info!(
+                "restore file for {} failed, size: {}",
+                info.task_id().brief(),
+                size
+            );
+            return false;
+        }
+        self.insert(info.task_id(), info.namespace(), size);
+        if let Some(cache) = self.partition_of(info.task_id()).peek(info.task_id()) {
+            cache.lock().unwrap().touch(info.time());
+        }
+        true
+    }
+
+    /// Pins the cached entry of a task, moving it to the pinned entries.
+    ///
+    /// # Returns
+    /// `true` if the entry is pinned, `false` if the task is not cached or
+    /// the entry does not fit in the budget of the pinned entries.
+    pub(crate) fn pin(&mut self, task_id: &TaskId) -> bool {
+        if self.pinned.files.contains_key(task_id) {
             return true;
         }
-        info!(
-            "restore file for {} failed, size: {}",
-            info.task_id().brief(),
-            size
-        );
-        false
+        let Some(size) = self
+            .partition_of(task_id)
+            .peek(task_id)
+            .map(|cache| cache.lock().unwrap().size())
+        else {
+            return false;
+        };
+        if !self.pinned.space.apply_cache_size(size) {
+            info!("pin {} failed, size: {}", task_id.brief(), size);
+            return false;
+        }
+        if let Some(cache) = self.partition_of(task_id).remove(task_id) {
+            self.release_entry(task_id, size);
+            self.pinned.files.insert(task_id.clone(), cache);
+        }
+        self.pins.insert(task_id.clone());
+        true
     }
 
-    /// Attempts to reserve the requested cache size, evicting LRU entries as
-    /// needed.
+    /// Unpins the entry of a task, moving it back to its namespace and
+    /// evicting LRU entries of the namespace as needed.
+    ///
+    /// # Returns
+    /// `None` if the task has no pinned entry. Otherwise whether the entry is
+    /// still cached, `false` if it was removed for not fitting in its
+    /// namespace, and the list of operating tasks evicted to free space.
+    pub(crate) fn unpin(&mut self, task_id: &TaskId) -> Option<(bool, Vec<OperatingTask>)> {
+        self.pins.remove(task_id);
+        let cache = self.pinned.files.remove(task_id)?;
+        let size = cache.lock().unwrap().size();
+        self.pinned.space.release(size);
+        let namespace = self.owners.get(task_id).cloned();
+        let (success, removed) = self.try_apply_size(namespace.as_deref(), size);
+        if success {
+            self.partition(namespace.as_deref())
+                .insert(task_id.clone(), cache);
+        } else {
+            self.owners.remove(task_id);
+        }
+        Some((success, removed))
+    }
+
+    /// Reserves space for the entry of a task, in the budget of the pinned
+    /// entries if the task is pinned, otherwise in its namespace, evicting
+    /// LRU entries of the namespace as needed.
+    ///
+    /// A pinned task whose entry does not fit in the budget of the pinned
+    /// entries is unpinned and stored in its namespace.
+    ///
+    /// # Returns
+    /// A tuple of whether the allocation succeeded and the list of operating
+    /// tasks evicted to free space.
+    pub(crate) fn try_apply_entry_size(
+        &mut self,
+        task_id: &TaskId,
+        namespace: Option<&str>,
+        apply: u64,
+    ) -> (bool, Vec<OperatingTask>) {
+        if self.pins.contains(task_id) {
+            if apply <= MAX_CACHE_SIZE && self.pinned.space.apply_cache_size(apply) {
+                return (true, vec![]);
+            }
+            info!("pin {} failed, size: {}", task_id.brief(), apply);
+            self.pins.remove(task_id);
+        }
+        self.try_apply_size(namespace, apply)
+    }
+
+    /// Returns whether the entry of a task is pinned, or will be once cached.
+    pub(crate) fn is_pinned(&self, task_id: &TaskId) -> bool {
+        self.pins.contains(task_id)
+    }
+
+    /// Attempts to reserve the requested cache size in a namespace, evicting
+    /// LRU entries of the namespace as needed, then those of the other
+    /// namespaces sharing its budget.
     ///
     /// # Arguments
+    /// * `namespace` - Namespace to reserve space in, `None` for the default
+    ///   one.
     /// * `apply` - Amount of space to reserve in bytes.
     ///
     /// # Returns
     /// A tuple of whether the allocation succeeded and the list of operating
     /// tasks evicted to free space.
-    pub(crate) fn try_apply_size(&mut self, apply: u64) -> (bool, Vec<OperatingTask>) {
+    pub(crate) fn try_apply_size(
+        &mut self,
+        namespace: Option<&str>,
+        apply: u64,
+    ) -> (bool, Vec<OperatingTask>) {
         let mut removed = Vec::new();
         if apply > MAX_CACHE_SIZE {
             return (false, removed);
         }
-        loop {
-            if self.file_space.apply_cache_size(apply) {
-                return (true, removed);
+        let mut evicted = Vec::new();
+        let success = loop {
+            if self.apply(namespace, apply) {
+                break true;
             };
             // No cache in caches - eviction failed
-            match self.files.pop() {
-                Some(cache) => {
+            match self.pop_victim(namespace) {
+                Some((owner, cache)) => {
                     let index = cache.lock().unwrap();
-                    let size = index.size();
-                    let id = index.task_id().clone();
-                    let operations = self.get_operations(&id);
-                    drop(index);
-                    self.file_space.release(size);
-                    removed.push(OperatingTask::new(id, operations));
+                    self.release(owner.as_deref(), index.size());
+                    evicted.push(CacheEntry {
+                        task_id: index.task_id().clone(),
+                        namespace: owner,
+                        tier: CacheTier::File,
+                        size: index.size(),
+                        last_access: index.accessed(),
+                    });
                 }
                 None => {
                     info!("CacheManager apply cache failed");
-                    return (false, removed);
+                    break false;
                 }
             }
+        };
+        for entry in evicted.iter() {
+            let id = entry.task_id.clone();
+            self.owners.remove(&id);
+            let operations = self.get_operations(&id);
+            removed.push(OperatingTask::new(id, operations));
+        }
+        self.stats.evict(evicted);
+        (success, removed)
+    }
+
+    /// Removes the LRU entry of a namespace to free space for it, or that of
+    /// another namespace sharing its budget if it has none.
+    ///
+    /// # Returns
+    /// The namespace of the entry removed and the entry, `None` if there is
+    /// nothing left to evict.
+    fn pop_victim(
+        &mut self,
+        namespace: Option<&str>,
+    ) -> Option<(Option<String>, Arc<Mutex<FileCache>>)> {
+        let owner = namespace.map(str::to_string);
+        if let Some(cache) = self.partition(namespace).pop() {
+            return Some((owner, cache));
+        }
+        if namespace.is_some_and(|name| self.namespaces[name].configured) {
+            return None;
+        }
+        if let Some(cache) = self.files.pop() {
+            return Some((None, cache));
+        }
+        self.namespaces
+            .iter_mut()
+            .filter(|(_, namespace)| !namespace.configured)
+            .find_map(|(name, namespace)| Some((Some(name.clone()), namespace.files.pop()?)))
+    }
+
+    /// Returns the occupancy of all the namespaces and that of each named
+    /// namespace; namespaces sharing the default budget report it as their
+    /// capacity.
+    pub(crate) fn usage(&self) -> (TierUsage, HashMap<String, TierUsage>) {
+        let mut total = TierUsage {
+            used: self.file_space.used(),
+            capacity: self.file_space.total(),
+            entries: self.files.len(),
+        };
+        let mut namespaces = HashMap::new();
+        for (name, namespace) in self.namespaces.iter() {
+            let curr = TierUsage {
+                used: namespace.space.used(),
+                capacity: if namespace.configured {
+                    namespace.space.total()
+                } else {
+                    self.file_space.total()
+                },
+                entries: namespace.files.len(),
+            };
+            // The space of shared namespaces is already in the default budget
+            if namespace.configured {
+                total.used += curr.used;
+                total.capacity += curr.capacity;
+            }
+            total.entries += curr.entries;
+            namespaces.insert(name.clone(), curr);
         }
+        (total, namespaces)
     }
 
-    /// Returns the task IDs of all currently cached file entries.
+    /// Returns the occupancy of the pinned entries.
+    pub(crate) fn pinned_usage(&self) -> TierUsage {
+        TierUsage {
+            used: self.pinned.space.used(),
+            capacity: self.pinned.space.total(),
+            entries: self.pinned.files.len(),
+        }
+    }
+
+    /// Lists the file cache entries of all the namespaces, pinned entries
+    /// included.
+    pub(crate) fn entries(&self) -> Vec<CacheEntry> {
+        let named = self
+            .namespaces
+            .iter()
+            .map(|(name, namespace)| (Some(name.as_str()), &namespace.files));
+        let mut entries = vec![];
+        for (namespace, files) in [(None, &self.files)].into_iter().chain(named) {
+            entries.extend(files.keys().filter_map(|task_id| {
+                let cache = files.peek(task_id)?.lock().unwrap();
+                Some(CacheEntry {
+                    task_id: task_id.clone(),
+                    namespace: namespace.map(str::to_string),
+                    tier: CacheTier::File,
+                    size: cache.size(),
+                    last_access: cache.accessed(),
+                })
+            }));
+        }
+        entries.extend(self.pinned.files.keys().filter_map(|task_id| {
+            let cache = self.pinned.files.peek(task_id)?.lock().unwrap();
+            Some(CacheEntry {
+                task_id: task_id.clone(),
+                namespace: self.owners.get(task_id).cloned(),
+                tier: CacheTier::File,
+                size: cache.size(),
+                last_access: cache.accessed(),
+            })
+        }));
+        entries
+    }
+
+    /// Returns the task IDs of all currently cached file entries that are not
+    /// pinned.
     pub(crate) fn task_ids(&self) -> Vec<TaskId> {
-        self.files.keys().cloned().collect()
+        self.files
+            .keys()
+            .chain(self.owners.keys())
+            .filter(|task_id| !self.pins.contains(task_id))
+            .cloned()
+            .collect()
     }
 
     /// Returns the operation queue for a task, creating one if absent.
@@ -186,15 +645,24 @@ pub struct FileManager {
 
     /// Backup RAM cache storage not subject to LRU eviction
     pub(crate) backup_rams: Mutex<HashMap<TaskId, Arc<RamCache>>>,
+
+    /// Partial entries of resources cached by ranges
+    pub(crate) partials: Mutex<HashMap<TaskId, PartialFile>>,
+
+    /// Recorder of the cache activity, shared with the cache manager
+    pub(crate) stats: Arc<StatsRecorder>,
 }
 
 impl FileManager {
     /// Creates a new, empty file manager.
     pub fn new() -> Self {
+        let stats = Arc::new(StatsRecorder::new());
         Self {
-            caches: Mutex::new(FileCaches::new()),
+            caches: Mutex::new(FileCaches::new(stats.clone())),
             update_from_file_once: Mutex::new(HashMap::new()),
             backup_rams: Mutex::new(HashMap::new()),
+            partials: Mutex::new(HashMap::new()),
+            stats,
         }
     }
 
@@ -206,7 +674,7 @@ impl FileManager {
     pub fn set_file_cache_size(&self, size: u64) {
         let mut caches = self.caches.lock().unwrap();
         caches.change_total_size(size);
-        let (_, removed) = caches.try_apply_size(0);
+        let (_, removed) = caches.try_apply_size(None, 0);
         let mut remove_others = vec![];
         for task in removed.iter() {
             let handle = send_operation_message(task);
@@ -218,8 +686,97 @@ impl FileManager {
         }
     }
 
+    /// Updates the file cache size limit of a namespace, evicting cached
+    /// tasks of the namespace that no longer fit.
+    ///
+    /// # Arguments
+    /// * `namespace` - Name of the namespace.
+    /// * `size` - New file cache size limit of the namespace in bytes.
+    pub fn set_namespace_cache_size(&self, namespace: &str, size: u64) {
+        let mut caches = self.caches.lock().unwrap();
+        caches.change_namespace_size(namespace, size);
+        let (_, removed) = caches.try_apply_size(Some(namespace), 0);
+        let handles = removed
+            .iter()
+            .map(send_operation_message)
+            .collect::<Vec<_>>();
+        drop(caches);
+        for (task, handle) in removed.into_iter().zip(handles.iter()) {
+            execute_file_remove(task, handle);
+        }
+    }
+
+    /// Updates the size limit of the pinned entries.
+    ///
+    /// Pinned entries are never evicted, so a limit below the size of the
+    /// pinned entries only prevents pinning others until some are unpinned.
+    ///
+    /// # Arguments
+    /// * `size` - New pinned file cache size limit in bytes.
+    pub fn set_pinned_cache_size(&self, size: u64) {
+        self.caches.lock().unwrap().change_pinned_size(size);
+    }
+
+    /// Pins the entry of a task so that it is not evicted until unpinned.
+    ///
+    /// An entry whose cache file is being written is pinned once written.
+    ///
+    /// # Returns
+    /// `true` if the entry is pinned, `false` if the task is not cached or
+    /// the entry does not fit in the budget of the pinned entries.
+    pub(crate) fn pin(&self, task_id: &TaskId) -> bool {
+        let mut caches = self.caches.lock().unwrap();
+        if caches.pin(task_id) {
+            let task = OperatingTask::new(task_id.clone(), caches.get_operations(task_id));
+            let handle = send_operation_message(&task);
+            drop(caches);
+            execute_file_pin(task, true, &handle);
+            return true;
+        }
+        if self.backup_rams.lock().unwrap().contains_key(task_id) {
+            caches.pins.insert(task_id.clone());
+            return true;
+        }
+        false
+    }
+
+    /// Unpins the entry of a task, which becomes subject to eviction again.
+    ///
+    /// The entry is removed if it does not fit in the budget of its
+    /// namespace, even after evicting the other entries of the namespace.
+    ///
+    /// # Returns
+    /// `true` if the task was pinned, `false` otherwise.
+    pub(crate) fn unpin(&self, task_id: &TaskId) -> bool {
+        let mut caches = self.caches.lock().unwrap();
+        let pinned = caches.is_pinned(task_id);
+        let Some((kept, removed)) = caches.unpin(task_id) else {
+            return pinned;
+        };
+        let handles = removed
+            .iter()
+            .map(send_operation_message)
+            .collect::<Vec<_>>();
+        let task = OperatingTask::new(task_id.clone(), caches.get_operations(task_id));
+        let handle = send_operation_message(&task);
+        drop(caches);
+        for (task, handle) in removed.into_iter().zip(handles.iter()) {
+            execute_file_remove(task, handle);
+        }
+        if kept {
+            execute_file_pin(task, false, &handle);
+        } else {
+            info!("{} removed when unpinned", task_id.brief());
+            execute_file_remove(task, &handle);
+            self.update_from_file_once.lock().unwrap().remove(task_id);
+        }
+        true
+    }
+
     /// Removes file cache entries for tasks that are not currently running.
     ///
+    /// Pinned entries are kept.
+    ///
     /// # Arguments
     /// * `running_tasks` - Task IDs that are still running and must be kept.
     pub(crate) fn clear_file_cache(&self, running_tasks: &HashSet<TaskId>) {
@@ -237,6 +794,15 @@ impl FileManager {
         for (handle, task) in remove_handles {
             execute_file_remove(task, &handle);
         }
+        let mut partials = self.partials.lock().unwrap();
+        let ids = partials.keys().cloned().collect::<Vec<_>>();
+        for id in ids {
+            if !running_tasks.contains(&id) {
+                if let Some(partial) = partials.remove(&id) {
+                    self.remove_partial(partial);
+                }
+            }
+        }
     }
 
     /// Returns whether the task has a file cache entry or a backup RAM entry.
@@ -317,7 +883,7 @@ impl FileManager {
             debug!("{} ram updated from file", task_id.brief());
 
             let mut caches = self.caches.lock().unwrap();
-            if !caches.files.contains_key(task_id) {
+            if !caches.contains(task_id) {
                 return Err(io::Error::new(io::ErrorKind::NotFound, "not found"));
             }
             let operating_task =
@@ -345,6 +911,12 @@ impl FileManager {
         if ret.is_some() {
             return ret;
         }
+        if let Err(e) = res {
+            if e.kind() == io::ErrorKind::InvalidData {
+                self.purge_corrupt(task_id);
+                return None;
+            }
+        }
         // Try to upgrade the weak reference
         res.as_ref().ok().and_then(|weak| {
             *retry = true;
@@ -352,11 +924,192 @@ impl FileManager {
         })
     }
 
-    /// Removes a task's cache entry and deletes its cached file from disk.
+    /// Reads a range of a task's file cache or partial entry.
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task to read.
+    /// * `offset` - Offset of the first byte to read.
+    /// * `len` - Maximum number of bytes to read.
+    ///
+    /// # Returns
+    /// The bytes of the range, fewer than `len` if it runs past the end of
+    /// the resource.
+    ///
+    /// # Errors
+    /// Returns `NotFound` if the task has no file cache and its partial entry
+    /// misses some byte of the range.
+    pub(crate) fn read_range(
+        &self,
+        task_id: &TaskId,
+        offset: u64,
+        len: usize,
+    ) -> io::Result<Vec<u8>> {
+        let mut caches = self.caches.lock().unwrap();
+        // Getting the entry also marks it as recently used
+        if caches.touch(task_id) {
+            let namespace = caches.owners.get(task_id).cloned();
+            let task = OperatingTask::new(task_id.clone(), caches.get_operations(task_id));
+            let notify = send_operation_message(&task);
+            drop(caches);
+            let res = execute_file_read_range(task, &notify, offset, len);
+            match res.as_ref() {
+                Ok(data) => {
+                    self.stats
+                        .hit(CacheTier::File, namespace.as_deref(), data.len() as u64)
+                }
+                Err(e) if e.kind() == io::ErrorKind::InvalidData => self.purge_corrupt(task_id),
+                Err(_) => {}
+            }
+            return res;
+        }
+        drop(caches);
+
+        match self.partials.lock().unwrap().get_mut(task_id) {
+            Some(partial) => {
+                let len = match partial.total() {
+                    Some(total) => len.min(total.saturating_sub(offset) as usize),
+                    None => len,
+                };
+                let data = partial.read(offset, len)?;
+                partial.touch();
+                self.stats
+                    .hit(CacheTier::Partial, partial.namespace(), data.len() as u64);
+                Ok(data)
+            }
+            None => Err(io::Error::new(io::ErrorKind::NotFound, "not found")),
+        }
+    }
+
+    /// Lists the partial entries.
+    pub(crate) fn partial_entries(&self) -> Vec<CacheEntry> {
+        self.partials
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(task_id, partial)| CacheEntry {
+                task_id: task_id.clone(),
+                namespace: partial.namespace().map(str::to_string),
+                tier: CacheTier::Partial,
+                size: partial.size(),
+                last_access: partial.accessed(),
+            })
+            .collect()
+    }
+
+    /// Stores a segment of a resource in its partial entry.
+    ///
+    /// Space is applied for the new bytes of the segment, evicting file caches
+    /// as needed. Once every byte of the resource is present, the partial
+    /// entry becomes the file cache of the task.
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task the segment belongs to.
+    /// * `namespace` - Namespace of the entry, `None` for the default one.
+    /// * `offset` - Offset of the segment in the resource.
+    /// * `data` - Bytes of the segment.
+    /// * `total` - Total size of the resource, if known.
+    ///
+    /// # Returns
+    /// `true` if the segment is cached, `false` if there is not enough space
+    /// or writing it failed.
+    pub(crate) fn write_range(
+        &self,
+        task_id: &TaskId,
+        namespace: Option<&str>,
+        offset: u64,
+        data: &[u8],
+        total: Option<u64>,
+    ) -> bool {
+        if self.contains(task_id) {
+            return true;
+        }
+        let mut partials = self.partials.lock().unwrap();
+        let partial = partials.entry(task_id.clone()).or_insert_with(|| {
+            let mut partial = PartialFile::new(task_id.clone());
+            partial.set_namespace(namespace.map(str::to_string));
+            partial
+        });
+        if let Some(total) = total {
+            partial.set_total(total);
+        }
+
+        let namespace = partial.namespace().map(str::to_string);
+        let added = partial.added_size(offset, data.len());
+        let mut caches = self.caches.lock().unwrap();
+        let (success, removed) = caches.try_apply_size(namespace.as_deref(), added);
+        let mut remove_others = vec![];
+        for task in removed.iter() {
+            let handle = send_operation_message(task);
+            remove_others.push(handle);
+        }
+        drop(caches);
+        for (idx, task) in removed.into_iter().enumerate() {
+            execute_file_remove(task, &remove_others[idx]);
+        }
+        if !success {
+            info!("apply {} for range of {} failed", added, task_id.brief());
+            return false;
+        }
+
+        if let Err(e) = partial.write(offset, data) {
+            error!("{} write range error: {}", task_id.brief(), e);
+            self.caches
+                .lock()
+                .unwrap()
+                .release(namespace.as_deref(), added);
+            return false;
+        }
+        if partial.is_complete() {
+            let partial = partials.remove(task_id).unwrap();
+            drop(partials);
+            self.promote_partial(task_id, partial);
+        }
+        true
+    }
+
+    /// Turns a complete partial entry into the file cache of its task.
+    fn promote_partial(&self, task_id: &TaskId, partial: PartialFile) {
+        info!("{} partial entry completed", task_id.brief());
+        let mut caches = self.caches.lock().unwrap();
+        caches.insert(task_id, partial.namespace(), partial.size());
+        let task = OperatingTask::new(task_id.clone(), caches.get_operations(task_id));
+        let notify = send_operation_message(&task);
+        drop(caches);
+        self.update_from_file_once.lock().unwrap().remove(task_id);
+
+        if !notify.is_first() {
+            notify.handle().wait();
+        }
+        let res = partial.promote();
+        notify_next_operation(task);
+        if let Err(e) = res {
+            error!("{} promote partial entry error: {}", task_id.brief(), e);
+            partial.remove();
+            self.remove(task_id);
+        }
+    }
+
+    /// Removes a partial entry from disk and releases its space.
+    fn remove_partial(&self, partial: PartialFile) {
+        self.caches
+            .lock()
+            .unwrap()
+            .release(partial.namespace(), partial.size());
+        partial.remove();
+    }
+
+    /// Removes a task's cache entry and deletes its cached file from disk,
+    /// unpinning it.
     pub fn remove(&self, task_id: &TaskId) {
+        let partial = self.partials.lock().unwrap().remove(task_id);
+        if let Some(partial) = partial {
+            self.remove_partial(partial);
+        }
         self.backup_rams.lock().unwrap().remove(task_id);
         let mut caches = self.caches.lock().unwrap();
-        if let Some(task) = caches.remove(task_id) {
+        let removed = caches.remove(task_id);
+        caches.pins.remove(task_id);
+        if let Some(task) = removed {
             let handle = send_operation_message(&task);
             drop(caches);
             execute_file_remove(task, &handle);
@@ -364,6 +1117,13 @@ impl FileManager {
         self.update_from_file_once.lock().unwrap().remove(task_id);
     }
 
+    /// Removes the entry of a task whose cache file does not match its
+    /// metadata, so that the resource is downloaded again.
+    fn purge_corrupt(&self, task_id: &TaskId) {
+        error!("{} cache file corrupt, purged", task_id.brief());
+        self.remove(task_id);
+    }
+
     /// Removes a task's cached file from disk if it is not in the file cache.
     ///
     /// # Arguments
@@ -404,10 +1164,127 @@ impl FileManager {
         success
     }
 
+    /// Stores an archive entry as the file cache entry of its task.
+    ///
+    /// The entry is stored only if the task is not cached yet and the entry
+    /// fits in the free space of its namespace, so that importing never
+    /// evicts other entries.
+    ///
+    /// # Returns
+    /// `true` if the entry was stored, `false` otherwise.
+    pub(crate) fn import_entry(&self, entry: ArchiveEntry) -> bool {
+        if !entry.is_storable() {
+            error!("archive entry of {} not storable", entry.url);
+            return false;
+        }
+        let task_id = entry.task_id();
+        let size = entry.body.len() as u64;
+        let namespace = entry.namespace.as_deref();
+        let partial = self.partials.lock().unwrap().contains_key(&task_id);
+        let mut caches = self.caches.lock().unwrap();
+        if partial
+            || caches.contains(&task_id)
+            || self.backup_rams.lock().unwrap().contains_key(&task_id)
+            || FileCache::path(&task_id).is_some_and(|path| path.exists())
+        {
+            debug!("{} already cached, not imported", task_id.brief());
+            return false;
+        }
+        if size > MAX_CACHE_SIZE || !caches.apply(namespace, size) {
+            info!("{} no space to import", task_id.brief());
+            return false;
+        }
+        caches.insert(&task_id, namespace, size);
+        let task = OperatingTask::new(task_id.clone(), caches.get_operations(&task_id));
+        let notify = send_operation_message(&task);
+        drop(caches);
+        self.update_from_file_once.lock().unwrap().remove(&task_id);
+
+        if !notify.is_first() {
+            notify.handle().wait();
+        }
+        let url = (!entry.url.is_empty()).then_some(entry.url);
+        let mut meta = FileMeta::new(url, &entry.body);
+        meta.set_namespace(entry.namespace);
+        meta.set_headers(entry.headers);
+        let res = FileCache::write_entry(&task_id, &entry.body, &meta);
+        notify_next_operation(task);
+        if let Err(e) = res {
+            error!("{} import entry error: {}", task_id.brief(), e);
+            self.remove(&task_id);
+            return false;
+        }
+        true
+    }
+
+    /// Appends the file cache entry of a task to an archive, copying its
+    /// cache file as it is read.
+    ///
+    /// The cache file is verified first, so that a corrupt one is skipped,
+    /// and purged, before anything is written.
+    ///
+    /// # Returns
+    /// `true` if the entry was written, `false` if the task has no file cache
+    /// entry or it cannot be read.
+    ///
+    /// # Errors
+    /// Returns `io::Error` if writing the entry fails, in which case the
+    /// archive is left truncated.
+    pub(crate) fn export_entry<W: Write>(
+        &self,
+        task_id: &TaskId,
+        writer: &mut ArchiveWriter<W>,
+    ) -> io::Result<bool> {
+        let mut caches = self.caches.lock().unwrap();
+        if !caches.contains(task_id) {
+            return Ok(false);
+        }
+        let namespace = caches.owners.get(task_id).cloned();
+        let task = OperatingTask::new(task_id.clone(), caches.get_operations(task_id));
+        let notify = send_operation_message(&task);
+        drop(caches);
+
+        if !notify.is_first() {
+            notify.handle().wait();
+        }
+        let res = FileCache::check_entry(task_id)
+            .and_then(|(meta, size)| Ok((meta, size, FileCache::open(task_id)?)));
+        let (meta, size, file) = match res {
+            Ok(entry) => entry,
+            Err(e) => {
+                notify_next_operation(task);
+                error!("{} export entry error: {}", task_id.brief(), e);
+                if e.kind() == io::ErrorKind::InvalidData {
+                    self.purge_corrupt(task_id);
+                }
+                return Ok(false);
+            }
+        };
+        let (url, headers) = match meta {
+            Some(meta) => (
+                meta.url().unwrap_or_default().to_string(),
+                meta.headers().to_vec(),
+            ),
+            None => (String::new(), vec![]),
+        };
+        let entry = ArchiveEntry {
+            key: Some(task_id.to_string()),
+            url,
+            namespace,
+            headers,
+            body: vec![],
+        };
+        // The file stays reserved while it is copied
+        let res = writer.write_entry_from(&entry, size, file);
+        notify_next_operation(task);
+        res.map(|_| true)
+    }
+
     /// Persists a RAM cache to disk by spawning a background file write.
     ///
     /// Stores a backup RAM copy and evicts entries as needed to fit the new
-    /// file cache entry.
+    /// file cache entry. The entry of a pinned task is stored in the pinned
+    /// entries instead.
     ///
     /// # Arguments
     /// * `task_id` - ID of the task whose cache should be persisted.
@@ -415,6 +1292,11 @@ impl FileManager {
     pub(super) fn update_file_cache(&'static self, task_id: TaskId, cache: Arc<RamCache>) {
         // Remove any existing update operation for this task
         self.update_from_file_once.lock().unwrap().remove(&task_id);
+        // The whole resource supersedes its partial entry
+        let partial = self.partials.lock().unwrap().remove(&task_id);
+        if let Some(partial) = partial {
+            self.remove_partial(partial);
+        }
         // Store backup of RAM cache
         self.backup_rams
             .lock()
@@ -429,24 +1311,25 @@ impl FileManager {
                 let handle = send_operation_message(&task);
                 remove_curr = Some((task, handle));
             }
-            let (success, removed) = caches.try_apply_size(cache.size() as u64);
+            let (success, removed) =
+                caches.try_apply_entry_size(&task_id, cache.namespace(), cache.size() as u64);
             let mut remove_others = vec![];
             for task in removed.iter() {
                 let handle = send_operation_message(task);
                 remove_others.push(handle);
             }
             let mut insert_curr = None;
+            let mut pin_curr = None;
             if success {
-                caches.files.insert(
-                    task_id.clone(),
-                    Arc::new(Mutex::new(FileCache::new(
-                        task_id.clone(),
-                        cache.size() as u64,
-                    ))),
-                );
+                caches.insert(&task_id, cache.namespace(), cache.size() as u64);
                 let task = OperatingTask::new(task_id.clone(), caches.get_operations(&task_id));
                 let handle = send_operation_message(&task);
                 insert_curr = Some((task, handle));
+                if caches.is_pinned(&task_id) {
+                    let task = OperatingTask::new(task_id.clone(), caches.get_operations(&task_id));
+                    let handle = send_operation_message(&task);
+                    pin_curr = Some((task, handle));
+                }
             }
             drop(caches);
             if let Some((task, handle)) = remove_curr {
@@ -458,6 +1341,9 @@ impl FileManager {
             if let Some((task, handle)) = insert_curr {
                 execute_file_write(task, cache, &handle);
             }
+            if let Some((task, handle)) = pin_curr {
+                execute_file_pin(task, true, &handle);
+            }
             // Clean up backup RAM cache after file update
             self.backup_rams.lock().unwrap().remove(&task_id);
         });
@@ -580,6 +1466,10 @@ impl NotifyCondition {
 /// // Set custom cache sizes
 /// manager.set_ram_cache_size(50 * 1024 * 1024); // 50MB
 /// manager.set_file_cache_size(200 * 1024 * 1024); // 200MB
+///
+/// // Give consumers budgets of their own
+/// manager.set_namespace_cache_size("thumbnails", 50 * 1024 * 1024); // 50MB
+/// manager.set_namespace_cache_size("audio", 500 * 1024 * 1024); // 500MB
 /// ```
 pub struct CacheManager {
     /// Primary RAM cache storage using LRU eviction policy
@@ -590,6 +1480,12 @@ pub struct CacheManager {
 
     /// Manages file cache resource allocation and capacity
     pub(crate) file_manager: FileManager,
+
+    /// Streams of the entries being downloaded
+    pub(crate) streams: Mutex<HashMap<TaskId, Arc<CacheStream>>>,
+
+    /// Recorder of the cache activity
+    pub(crate) stats: Arc<StatsRecorder>,
 }
 
 impl CacheManager {
@@ -601,10 +1497,13 @@ impl CacheManager {
     /// # Returns
     /// A new CacheManager instance ready for use
     pub fn new() -> Self {
+        let file_manager = FileManager::new();
         Self {
             rams: Mutex::new(LRUCache::new()),
             ram_handle: Mutex::new(SpaceManager::new(DEFAULT_RAM_CACHE_SIZE)),
-            file_manager: FileManager::new(),
+            stats: file_manager.stats.clone(),
+            file_manager,
+            streams: Mutex::new(HashMap::new()),
         }
     }
 
@@ -617,7 +1516,7 @@ impl CacheManager {
     /// * `size` - New maximum RAM cache size in bytes
     pub fn set_ram_cache_size(&self, size: u64) {
         self.ram_handle.lock().unwrap().change_total_size(size);
-        CacheManager::apply_cache(&self.ram_handle, &self.rams, 0);
+        self.apply_cache(0);
     }
 
     /// Sets the maximum size for file-based caching.
@@ -631,6 +1530,74 @@ impl CacheManager {
         self.file_manager.set_file_cache_size(size);
     }
 
+    /// Sets the maximum size for file-based caching of a namespace.
+    ///
+    /// Entries stored in a namespace only count against its own budget and
+    /// only evict each other, so that a consumer cannot evict the working set
+    /// of another. Namespaces are created on first use with the default file
+    /// cache size; the RAM cache is shared by all namespaces.
+    ///
+    /// # Arguments
+    /// * `namespace` - Name of the namespace
+    /// * `size` - New maximum file cache size of the namespace in bytes
+    pub fn set_namespace_cache_size(&self, namespace: &str, size: u64) {
+        self.file_manager.set_namespace_cache_size(namespace, size);
+    }
+
+    /// Sets the maximum size of the pinned entries.
+    ///
+    /// Pinned entries count against this budget only, whatever their
+    /// namespace. They are never evicted, so a size below that of the pinned
+    /// entries only prevents pinning others until some are unpinned.
+    ///
+    /// # Arguments
+    /// * `size` - New maximum size of the pinned entries in bytes
+    pub fn set_pinned_cache_size(&self, size: u64) {
+        self.file_manager.set_pinned_cache_size(size);
+    }
+
+    /// Pins a cache entry so that it survives eviction until unpinned.
+    ///
+    /// The entry keeps being served like any other and is still removed by
+    /// [`CacheManager::remove`]; clearing the file cache keeps it. Pins are
+    /// persisted with the cache files.
+    ///
+    /// # Arguments
+    /// * `task_id` - The task ID to pin
+    ///
+    /// # Returns
+    /// `true` if the entry is pinned, `false` if it is not cached on disk or
+    /// does not fit in the budget of the pinned entries
+    pub fn pin(&self, task_id: &TaskId) -> bool {
+        self.file_manager.pin(task_id)
+    }
+
+    /// Unpins a cache entry, which becomes subject to eviction again.
+    ///
+    /// The entry moves back to the file cache of its namespace, evicting
+    /// least recently used entries as needed, and is removed if it does not
+    /// fit.
+    ///
+    /// # Arguments
+    /// * `task_id` - The task ID to unpin
+    ///
+    /// # Returns
+    /// `true` if the entry was pinned, `false` otherwise
+    pub fn unpin(&self, task_id: &TaskId) -> bool {
+        self.file_manager.unpin(task_id)
+    }
+
+    /// Checks if a cache entry is pinned.
+    ///
+    /// # Arguments
+    /// * `task_id` - The task ID to check
+    ///
+    /// # Returns
+    /// `true` if the entry is pinned, `false` otherwise
+    pub fn is_pinned(&self, task_id: &TaskId) -> bool {
+        self.file_manager.caches.lock().unwrap().is_pinned(task_id)
+    }
+
     /// Restores all valid cache files from the given directory.
     ///
     /// Scans the directory for valid cache files, filters out incomplete files,
@@ -677,6 +1644,145 @@ impl CacheManager {
         self.get_cache(task_id)
     }
 
+    /// Opens a reader on a cache entry, which may still be downloading.
+    ///
+    /// Entries being downloaded are read as their bytes are received, entries
+    /// cached in files only are read from their file range by range, without
+    /// loading them into RAM.
+    ///
+    /// # Arguments
+    /// * `task_id` - The task ID to read
+    ///
+    /// # Returns
+    /// `Some(CacheReader)` if the entry is being downloaded or cached, `None`
+    /// otherwise
+    pub fn open_stream(&'static self, task_id: &TaskId) -> Option<CacheReader> {
+        let stream = self.streams.lock().unwrap().get(task_id).cloned();
+        let stream = match stream {
+            Some(stream) => stream,
+            None => match self.find_ram(task_id) {
+                Some(cache) => {
+                    cache.touch();
+                    self.stats
+                        .hit(CacheTier::Ram, cache.namespace(), cache.size() as u64);
+                    Arc::new(CacheStream::finished(cache))
+                }
+                None => {
+                    let size = self.file_manager.caches.lock().unwrap().size(task_id)?;
+                    Arc::new(CacheStream::cached(self, task_id.clone(), size as usize))
+                }
+            },
+        };
+        Some(CacheReader::new(stream))
+    }
+
+    /// Registers the stream of an entry starting to download, replacing the
+    /// stream of a previous download of the entry.
+    pub(crate) fn start_stream(&self, task_id: &TaskId) -> Arc<CacheStream> {
+        let stream = Arc::new(CacheStream::new());
+        self.streams
+            .lock()
+            .unwrap()
+            .insert(task_id.clone(), stream.clone());
+        stream
+    }
+
+    /// Unregisters the stream of a download that ended, unless a later
+    /// download of the entry replaced it.
+    pub(crate) fn end_stream(&self, task_id: &TaskId, stream: &Arc<CacheStream>) {
+        let mut streams = self.streams.lock().unwrap();
+        if streams
+            .get(task_id)
+            .is_some_and(|curr| Arc::ptr_eq(curr, stream))
+        {
+            streams.remove(task_id);
+        }
+    }
+
+    /// Reads a range of a cache entry without loading the whole entry.
+    ///
+    /// The range is served from the RAM cache if present, otherwise directly
+    /// from the cache file or from the partial entry of the task.
+    ///
+    /// # Arguments
+    /// * `task_id` - The task ID to read
+    /// * `offset` - Offset of the first byte to read
+    /// * `len` - Maximum number of bytes to read
+    ///
+    /// # Returns
+    /// The bytes of the range, fewer than `len` if it runs past the end of the
+    /// resource
+    ///
+    /// # Errors
+    /// Returns `NotFound` if some byte of the range is not cached
+    pub fn read_range(&self, task_id: &TaskId, offset: u64, len: usize) -> io::Result<Vec<u8>> {
+        match self.find_ram(task_id) {
+            Some(cache) => {
+                let data = cache.read_range(offset, len);
+                cache.touch();
+                self.stats
+                    .hit(CacheTier::Ram, cache.namespace(), data.len() as u64);
+                Ok(data)
+            }
+            None => self.file_manager.read_range(task_id, offset, len),
+        }
+    }
+
+    /// Stores a segment of a resource too large or too sparse to be cached at
+    /// once.
+    ///
+    /// See [`CacheManager::missing_ranges`] to find the segments to download.
+    ///
+    /// # Arguments
+    /// * `task_id` - The task ID the segment belongs to
+    /// * `namespace` - Namespace of the entry, `None` for the default one
+    /// * `offset` - Offset of the segment in the resource
+    /// * `data` - Bytes of the segment
+    /// * `total` - Total size of the resource, if known
+    ///
+    /// # Returns
+    /// `true` if the segment is cached, `false` otherwise
+    pub fn write_range(
+        &self,
+        task_id: &TaskId,
+        namespace: Option<&str>,
+        offset: u64,
+        data: &[u8],
+        total: Option<u64>,
+    ) -> bool {
+        self.stats.receive(namespace, data.len() as u64);
+        if self.rams.lock().unwrap().contains_key(task_id) {
+            return true;
+        }
+        self.file_manager
+            .write_range(task_id, namespace, offset, data, total)
+    }
+
+    /// Gets the sub-ranges of a range that are not cached.
+    ///
+    /// # Arguments
+    /// * `task_id` - The task ID to check
+    /// * `offset` - Offset of the first byte of the range
+    /// * `len` - Length of the range
+    ///
+    /// # Returns
+    /// The missing sub-ranges in order, empty if the whole range is cached
+    pub fn missing_ranges(&self, task_id: &TaskId, offset: u64, len: usize) -> Vec<Range<u64>> {
+        if self.contains(task_id) {
+            return vec![];
+        }
+        let range = offset..offset.saturating_add(len as u64);
+        match self.file_manager.partials.lock().unwrap().get(task_id) {
+            Some(partial) => {
+                let end = partial
+                    .total()
+                    .map_or(range.end, |total| range.end.min(total));
+                partial.ranges().missing(&(range.start..end))
+            }
+            None => vec![range],
+        }
+    }
+
     /// Removes a cache entry by task ID.
     ///
     /// Removes the entry from all cache storage types (file, backup RAM, and
@@ -704,6 +1810,160 @@ impl CacheManager {
         self.file_manager.contains(task_id) || self.rams.lock().unwrap().contains_key(task_id)
     }
 
+    /// Takes a snapshot of the statistics of the cache.
+    ///
+    /// # Returns
+    /// The counters of the cache activity since the manager was created, with
+    /// the current occupancy of each tier, in total and per named namespace
+    pub fn stats(&self) -> CacheStats {
+        let (counters, namespace_counters) = self.stats.counters();
+        let ram = {
+            // Dropping a RAM cache locks the handle, so `rams` is not locked
+            // while holding it
+            let (used, capacity) = {
+                let handle = self.ram_handle.lock().unwrap();
+                (handle.used(), handle.total())
+            };
+            TierUsage {
+                used,
+                capacity,
+                entries: self.rams.lock().unwrap().len(),
+            }
+        };
+        let (file, usages, pinned) = {
+            let caches = self.file_manager.caches.lock().unwrap();
+            let (file, usages) = caches.usage();
+            (file, usages, caches.pinned_usage())
+        };
+
+        let mut namespaces = HashMap::new();
+        for (name, counters) in namespace_counters {
+            namespaces
+                .entry(name)
+                .or_insert_with(NamespaceStats::default)
+                .counters = counters;
+        }
+        for (name, usage) in usages {
+            namespaces
+                .entry(name)
+                .or_insert_with(NamespaceStats::default)
+                .file = usage;
+        }
+        CacheStats {
+            counters,
+            ram,
+            file,
+            pinned,
+            namespaces,
+        }
+    }
+
+    /// Lists the cached entries.
+    ///
+    /// A resource held both in RAM and in a cache file is listed once per
+    /// tier. Listing the entries does not mark them as recently used.
+    ///
+    /// # Returns
+    /// The entries of the RAM cache, of the file caches and the partial
+    /// entries, in no particular order
+    pub fn entries(&self) -> Vec<CacheEntry> {
+        let mut entries = {
+            let rams = self.rams.lock().unwrap();
+            rams.keys()
+                .filter_map(|task_id| rams.peek(task_id))
+                .map(|cache| CacheEntry {
+                    task_id: cache.task_id().clone(),
+                    namespace: cache.namespace().map(str::to_string),
+                    tier: CacheTier::Ram,
+                    size: cache.size() as u64,
+                    last_access: cache.last_access(),
+                })
+                .collect::<Vec<_>>()
+        };
+        entries.extend(self.file_manager.caches.lock().unwrap().entries());
+        entries.extend(self.file_manager.partial_entries());
+        entries
+    }
+
+    /// Imports the responses of an archive into the file cache.
+    ///
+    /// Entries whose task is already cached, that do not fit in the free
+    /// space of their namespace or that cannot be stored are skipped, so
+    /// importing never replaces nor evicts cached entries.
+    ///
+    /// # Arguments
+    /// * `reader` - Reader of the archive, see [`ArchiveReader`]
+    ///
+    /// # Returns
+    /// The number of entries imported
+    ///
+    /// # Errors
+    /// Returns `InvalidData` if the archive is malformed or its version is
+    /// unknown, or `io::Error` if reading it fails. The entries read before
+    /// the error stay imported.
+    pub fn import_archive<R: Read>(&self, reader: R) -> io::Result<usize> {
+        let mut imported = 0;
+        for entry in ArchiveReader::new(reader)? {
+            let entry = entry?;
+            if self.rams.lock().unwrap().contains_key(&entry.task_id()) {
+                continue;
+            }
+            if self.file_manager.import_entry(entry) {
+                imported += 1;
+            }
+        }
+        info!("{} archive entries imported", imported);
+        Ok(imported)
+    }
+
+    /// Exports the file cache entries to an archive.
+    ///
+    /// Entries whose cache file is being written, entries held in RAM only
+    /// and partial entries are not exported. Entries whose cache file cannot
+    /// be read are skipped. Cache files are copied to the archive as they
+    /// are read, never held in memory whole.
+    ///
+    /// # Arguments
+    /// * `writer` - Writer of the archive, see [`ArchiveWriter`]
+    ///
+    /// # Returns
+    /// The number of entries exported
+    ///
+    /// # Errors
+    /// Returns `io::Error` if writing the archive fails.
+    pub fn export_archive<W: Write>(&self, writer: W) -> io::Result<usize> {
+        let mut writer = ArchiveWriter::new(writer)?;
+        let task_ids = self
+            .file_manager
+            .caches
+            .lock()
+            .unwrap()
+            .entries()
+            .into_iter()
+            .map(|entry| entry.task_id)
+            .collect::<Vec<_>>();
+        let mut exported = 0;
+        for task_id in task_ids {
+            if self.file_manager.export_entry(&task_id, &mut writer)? {
+                exported += 1;
+            }
+        }
+        writer.finish()?;
+        info!("{} archive entries exported", exported);
+        Ok(exported)
+    }
+
+    /// Sets the listener of the entries evicted to make room for others.
+    ///
+    /// Entries removed explicitly, replaced by a new download or dropped for
+    /// being corrupt are not reported.
+    ///
+    /// # Arguments
+    /// * `listener` - The listener, `None` to remove the current one
+    pub fn set_eviction_listener(&self, listener: Option<Box<dyn EvictionListener>>) {
+        self.stats.set_listener(listener.map(Arc::from));
+    }
+
     /// Internal method to get a cache entry with fallback logic.
     ///
     /// First checks the primary RAM cache, then the backup RAM cache, and
@@ -716,8 +1976,24 @@ impl CacheManager {
     /// `Some(Arc<RamCache>)` if found through any cache source, `None`
     /// otherwise
     pub(crate) fn get_cache(&'static self, task_id: &TaskId) -> Option<Arc<RamCache>> {
-        let res = self.rams.lock().unwrap().get(task_id).cloned();
-        res.or_else(|| {
+        if let Some(cache) = self.find_ram(task_id) {
+            cache.touch();
+            self.stats
+                .hit(CacheTier::Ram, cache.namespace(), cache.size() as u64);
+            return Some(cache);
+        }
+        let cache = self.update_ram_from_file(task_id)?;
+        self.file_manager.caches.lock().unwrap().mark_read(task_id);
+        self.stats
+            .hit(CacheTier::File, cache.namespace(), cache.size() as u64);
+        Some(cache)
+    }
+
+    /// Finds the RAM cache of a task, in the RAM caches or the backup RAM
+    /// caches waiting to be written to file.
+    fn find_ram(&self, task_id: &TaskId) -> Option<Arc<RamCache>> {
+        let ram = self.rams.lock().unwrap().get(task_id).cloned();
+        ram.or_else(|| {
             self.file_manager
                 .backup_rams
                 .lock()
@@ -725,7 +2001,6 @@ impl CacheManager {
                 .get(task_id)
                 .cloned()
         })
-        .or_else(|| self.update_ram_from_file(task_id))
     }
 
     /// Clears memory cache entries not associated with running tasks.
@@ -764,8 +2039,21 @@ impl CacheManager {
     ///
     /// # Returns
     /// `Some` with the loaded RAM cache on success, or `None` on read failure.
+    /// A file that does not match its metadata is removed.
     pub fn read_task_local_file(&'static self, task_id: &TaskId) -> Option<RamCache> {
-        FileCache::read_but_not_cache(task_id, self).ok()
+        match FileCache::read_but_not_cache(task_id, self) {
+            Ok(cache) => {
+                self.stats
+                    .hit(CacheTier::File, cache.namespace(), cache.size() as u64);
+                Some(cache)
+            }
+            Err(e) if e.kind() == io::ErrorKind::InvalidData => {
+                self.file_manager.purge_corrupt(task_id);
+                self.file_manager.try_remove_from_disk(task_id);
+                None
+            }
+            Err(_) => None,
+        }
     }
 
     /// Forwards a RAM cache to the file manager for disk persistence.
@@ -798,35 +2086,42 @@ impl CacheManager {
     /// is available, evicts the least recently used entries until enough
     /// space is freed or all entries have been evicted.
     ///
-    /// # Type Parameters
-    /// * `T` - The cache value type, can be either `RamCache` or `FileCache`
-    ///
     /// # Arguments
-    /// * `handle` - Resource manager controlling the cache capacity
-    /// * `caches` - LRU cache to potentially evict entries from
-    /// * `size` - Amount of space to allocate in bytes
+    /// * `size` - Amount of RAM to allocate in bytes
     ///
     /// # Returns
     /// `true` if allocation succeeded, `false` if insufficient space even after
     /// eviction
-    pub(super) fn apply_cache<T>(
-        handle: &Mutex<SpaceManager>,
-        caches: &Mutex<LRUCache<TaskId, T>>,
-        size: usize,
-    ) -> bool {
-        loop {
-            if size > MAX_CACHE_SIZE as usize {
-                return false;
-            }
-            if handle.lock().unwrap().apply_cache_size(size as u64) {
-                return true;
+    pub(super) fn apply_cache(&self, size: usize) -> bool {
+        if size > MAX_CACHE_SIZE as usize {
+            return false;
+        }
+        let mut evicted = Vec::new();
+        let success = loop {
+            if self
+                .ram_handle
+                .lock()
+                .unwrap()
+                .apply_cache_size(size as u64)
+            {
+                break true;
             };
+            let popped = self.rams.lock().unwrap().pop();
             // No cache in caches - eviction failed
-            if caches.lock().unwrap().pop().is_none() {
+            let Some(cache) = popped else {
                 info!("CacheManager release cache failed");
-                return false;
-            }
-        }
+                break false;
+            };
+            evicted.push(CacheEntry {
+                task_id: cache.task_id().clone(),
+                namespace: cache.namespace().map(str::to_string),
+                tier: CacheTier::Ram,
+                size: cache.size() as u64,
+                last_access: cache.last_access(),
+            });
+        };
+        self.stats.evict(evicted);
+        success
     }
 
     /// Inserts or refreshes a RAM cache entry under its task ID.
@@ -901,6 +2196,35 @@ pub fn execute_file_read(
     ram
 }
 
+/// Reads a range of a task's cached file, waiting for prior operations on the
+/// same task to finish first.
+///
+/// # Arguments
+/// * `task` - Operating task identifying the file to read.
+/// * `notify` - Handle controlling turn ordering.
+/// * `offset` - Offset of the first byte to read.
+/// * `len` - Maximum number of bytes to read.
+///
+/// # Returns
+/// The bytes of the range, or an I/O error on failure.
+pub fn execute_file_read_range(
+    task: OperatingTask,
+    notify: &NotifyHandle,
+    offset: u64,
+    len: usize,
+) -> io::Result<Vec<u8>> {
+    let is_first = notify.is_first();
+    let pair = notify.handle();
+    let data = if is_first {
+        FileCache::read_range(task.task_id(), offset, len)
+    } else {
+        pair.wait();
+        FileCache::read_range(task.task_id(), offset, len)
+    };
+    notify_next_operation(task);
+    data
+}
+
 /// Writes a RAM cache to a task's cached file, waiting for prior operations on
 /// the same task to finish first.
 pub fn execute_file_write(task: OperatingTask, ram: Arc<RamCache>, notify: &NotifyHandle) {
@@ -919,6 +2243,18 @@ pub fn execute_file_write(task: OperatingTask, ram: Arc<RamCache>, notify: &Noti
     notify_next_operation(task);
 }
 
+/// Records whether a task's cached file is pinned in its metadata, waiting for
+/// prior operations on the same task to finish first.
+pub fn execute_file_pin(task: OperatingTask, pinned: bool, notify: &NotifyHandle) {
+    if !notify.is_first() {
+        notify.handle().wait();
+    }
+    if let Err(e) = FileCache::set_pinned(task.task_id(), pinned) {
+        error!("{} set pinned error: {}", task.task_id().brief(), e);
+    }
+    notify_next_operation(task);
+}
+
 /// Completes the current operation for a task and wakes the next queued
 /// operation, if any.
 pub fn notify_next_operation(task: OperatingTask) {
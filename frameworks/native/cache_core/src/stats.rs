@@ -0,0 +1,252 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Statistics of the cache.
+//!
+//! The cache manager counts the lookups served by each tier with the bytes
+//! they served, the resources downloaded and the entries evicted, in total and
+//! per namespace. [`CacheManager::stats`] takes a snapshot of the counters
+//! together with the occupancy of each tier, and [`CacheManager::entries`]
+//! lists the cached entries.
+//!
+//! Counters start at zero with the manager and are not persisted.
+//!
+//! [`CacheManager::stats`]: crate::CacheManager::stats
+//! [`CacheManager::entries`]: crate::CacheManager::entries
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use request_utils::task_id::TaskId;
+
+use crate::spawn;
+
+/// Storage tier of a cache entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CacheTier {
+    /// In-memory cache
+    Ram,
+    /// Cache file on disk
+    File,
+    /// Segments of a resource cached by byte ranges, on disk
+    Partial,
+}
+
+/// Counters of the cache activity.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CacheCounters {
+    /// Lookups served from the RAM cache
+    pub ram_hits: u64,
+    /// Lookups served from cache files or partial entries
+    pub file_hits: u64,
+    /// Downloads of resources that were not cached, or forced to update
+    pub misses: u64,
+    /// Bytes served from the RAM cache
+    pub ram_bytes: u64,
+    /// Bytes served from cache files or partial entries
+    pub file_bytes: u64,
+    /// Bytes received from the network
+    pub network_bytes: u64,
+    /// Entries evicted from the RAM cache to make room for others
+    pub ram_evictions: u64,
+    /// Entries evicted from the file cache to make room for others
+    pub file_evictions: u64,
+}
+
+impl CacheCounters {
+    /// Returns the share of the requests served by the cache.
+    ///
+    /// # Returns
+    /// A ratio between 0 and 1, 0 if nothing was requested yet
+    pub fn hit_rate(&self) -> f64 {
+        let hits = self.ram_hits + self.file_hits;
+        match hits + self.misses {
+            0 => 0.0,
+            total => hits as f64 / total as f64,
+        }
+    }
+}
+
+/// Occupancy of a cache tier.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TierUsage {
+    /// Bytes used
+    pub used: u64,
+    /// Bytes available to the tier
+    pub capacity: u64,
+    /// Number of entries
+    pub entries: usize,
+}
+
+/// Statistics of a named namespace.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NamespaceStats {
+    /// Counters of the entries of the namespace
+    pub counters: CacheCounters,
+    /// Occupancy of the file cache budget of the namespace
+    pub file: TierUsage,
+}
+
+/// Snapshot of the statistics of the cache.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Counters of all the entries, whatever their namespace
+    pub counters: CacheCounters,
+    /// Occupancy of the RAM cache
+    pub ram: TierUsage,
+    /// Occupancy of the file caches of all the namespaces, partial entries
+    /// included in the bytes used and pinned entries excluded
+    pub file: TierUsage,
+    /// Occupancy of the pinned entries, whatever their namespace
+    pub pinned: TierUsage,
+    /// Statistics of the named namespaces, by name
+    pub namespaces: HashMap<String, NamespaceStats>,
+}
+
+/// Cache entry, as listed by [`CacheManager::entries`].
+///
+/// [`CacheManager::entries`]: crate::CacheManager::entries
+#[derive(Clone, PartialEq, Eq)]
+pub struct CacheEntry {
+    /// Cache key of the entry
+    pub task_id: TaskId,
+    /// Namespace of the entry, `None` for the default namespace
+    pub namespace: Option<String>,
+    /// Tier holding the entry
+    pub tier: CacheTier,
+    /// Size of the entry in bytes
+    pub size: u64,
+    /// Time the entry was last stored or served
+    pub last_access: SystemTime,
+}
+
+/// Listener of the entries evicted to make room for others.
+///
+/// Listeners are called on a background thread, so that they may use the
+/// cache manager.
+pub trait EvictionListener: Send + Sync {
+    /// Called after an entry was evicted from a tier.
+    ///
+    /// # Arguments
+    /// * `entry` - The evicted entry
+    fn on_evict(&self, entry: &CacheEntry);
+}
+
+/// Counters of the whole cache and of each named namespace.
+#[derive(Default)]
+struct Counters {
+    total: CacheCounters,
+    namespaces: HashMap<String, CacheCounters>,
+}
+
+/// Records the activity of a cache manager.
+pub(crate) struct StatsRecorder {
+    /// Counters of the activity
+    counters: Mutex<Counters>,
+    /// Listener of the evictions, if any
+    listener: Mutex<Option<Arc<dyn EvictionListener>>>,
+}
+
+impl StatsRecorder {
+    /// Creates a recorder with zeroed counters and no listener.
+    pub(crate) fn new() -> Self {
+        Self {
+            counters: Mutex::new(Counters::default()),
+            listener: Mutex::new(None),
+        }
+    }
+
+    /// Updates the total counters and those of the namespace, if named.
+    fn record<F>(&self, namespace: Option<&str>, update: F)
+    where
+        F: Fn(&mut CacheCounters),
+    {
+        let mut counters = self.counters.lock().unwrap();
+        update(&mut counters.total);
+        if let Some(namespace) = namespace {
+            update(
+                counters
+                    .namespaces
+                    .entry(namespace.to_string())
+                    .or_default(),
+            );
+        }
+    }
+
+    /// Records a lookup served by a tier.
+    ///
+    /// # Arguments
+    /// * `tier` - Tier serving the lookup
+    /// * `namespace` - Namespace of the entry, `None` for the default one
+    /// * `bytes` - Number of bytes served
+    pub(crate) fn hit(&self, tier: CacheTier, namespace: Option<&str>, bytes: u64) {
+        self.record(namespace, |counters| match tier {
+            CacheTier::Ram => {
+                counters.ram_hits += 1;
+                counters.ram_bytes += bytes;
+            }
+            CacheTier::File | CacheTier::Partial => {
+                counters.file_hits += 1;
+                counters.file_bytes += bytes;
+            }
+        });
+    }
+
+    /// Records the download of a resource that was not served by the cache.
+    pub(crate) fn miss(&self, namespace: Option<&str>) {
+        self.record(namespace, |counters| counters.misses += 1);
+    }
+
+    /// Records bytes received from the network.
+    pub(crate) fn receive(&self, namespace: Option<&str>, bytes: u64) {
+        self.record(namespace, |counters| counters.network_bytes += bytes);
+    }
+
+    /// Records evictions and notifies the listener of them, if any, in a
+    /// single task.
+    pub(crate) fn evict(&self, entries: Vec<CacheEntry>) {
+        for entry in entries.iter() {
+            self.record(entry.namespace.as_deref(), |counters| match entry.tier {
+                CacheTier::Ram => counters.ram_evictions += 1,
+                CacheTier::File | CacheTier::Partial => counters.file_evictions += 1,
+            });
+        }
+        if entries.is_empty() {
+            return;
+        }
+        if let Some(listener) = self.listener.lock().unwrap().clone() {
+            spawn(move || {
+                for entry in entries.iter() {
+                    listener.on_evict(entry);
+                }
+            });
+        }
+    }
+
+    /// Replaces the listener of the evictions.
+    pub(crate) fn set_listener(&self, listener: Option<Arc<dyn EvictionListener>>) {
+        *self.listener.lock().unwrap() = listener;
+    }
+
+    /// Returns the total counters and those of each named namespace.
+    pub(crate) fn counters(&self) -> (CacheCounters, HashMap<String, CacheCounters>) {
+        let counters = self.counters.lock().unwrap();
+        (counters.total.clone(), counters.namespaces.clone())
+    }
+}
+
+#[cfg(test)]
+mod ut_stats {
+    include!("../tests/ut/ut_stats.rs");
+}
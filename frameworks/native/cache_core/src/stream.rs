@@ -0,0 +1,411 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Streaming access to cache entries while they are downloaded.
+//!
+//! A `CacheStream` is registered for every download in flight. The `Updater`
+//! writes the received bytes into the `RamCache` held by the stream, so
+//! readers consume them from there before the entry is finished, without a
+//! copy of their own. When the download finishes readers continue from the
+//! finished `RamCache`. Entries cached in files only are read from their file
+//! range by range, without loading them.
+//!
+//! Blocking readers wait on their own `NotifyCondition`, asynchronous readers
+//! register a `Waker`; both are signaled whenever bytes are appended or the
+//! download ends.
+
+use std::future::poll_fn;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::sync::{Arc, Mutex, Weak};
+use std::task::{Poll, Waker};
+
+use request_utils::task_id::TaskId;
+
+use crate::data::RamCache;
+use crate::manage::{CacheManager, NotifyCondition};
+
+/// Progress of the download behind a stream.
+enum StreamStatus {
+    /// The download is in flight.
+    Writing,
+    /// The download finished into the given cache.
+    Finished(Arc<RamCache>),
+    /// The entry is cached in a file of the given size.
+    Cached {
+        /// Manager of the file cache
+        manager: &'static CacheManager,
+        /// Task of the entry
+        task_id: TaskId,
+        /// Size of the entry
+        size: usize,
+    },
+    /// The download failed or was canceled.
+    Failed,
+}
+
+/// Mutable state of a stream.
+struct StreamState {
+    /// Cache the download writes into while it is in flight
+    cache: Option<RamCache>,
+    /// Size of the entry announced by the download, if any
+    total_size: Option<usize>,
+    /// Progress of the download
+    status: StreamStatus,
+    /// Conditions of the blocking readers waiting for bytes
+    waiters: Vec<Weak<NotifyCondition>>,
+    /// Wakers of the asynchronous readers waiting for bytes
+    wakers: Vec<Waker>,
+}
+
+/// Bytes of a cache entry shared between its download and its readers.
+pub(crate) struct CacheStream {
+    state: Mutex<StreamState>,
+}
+
+impl CacheStream {
+    /// Creates the stream of a download in flight.
+    pub(crate) fn new() -> Self {
+        Self::with_status(StreamStatus::Writing)
+    }
+
+    /// Creates the stream of an entry that is already cached in RAM.
+    pub(crate) fn finished(cache: Arc<RamCache>) -> Self {
+        Self::with_status(StreamStatus::Finished(cache))
+    }
+
+    /// Creates the stream of an entry that is cached in a file only.
+    pub(crate) fn cached(manager: &'static CacheManager, task_id: TaskId, size: usize) -> Self {
+        Self::with_status(StreamStatus::Cached {
+            manager,
+            task_id,
+            size,
+        })
+    }
+
+    fn with_status(status: StreamStatus) -> Self {
+        Self {
+            state: Mutex::new(StreamState {
+                cache: None,
+                total_size: None,
+                status,
+                waiters: Vec::new(),
+                wakers: Vec::new(),
+            }),
+        }
+    }
+
+    /// Records the size of the entry announced by the download.
+    pub(crate) fn set_total_size(&self, total_size: Option<usize>) {
+        self.state.lock().unwrap().total_size = total_size;
+    }
+
+    /// Writes bytes received by the download into the cache of the stream,
+    /// creating the cache with `init` on the first bytes.
+    pub(crate) fn receive<F>(&self, data: &[u8], init: F) -> io::Result<()>
+    where
+        F: FnOnce() -> RamCache,
+    {
+        let mut state = self.state.lock().unwrap();
+        if !matches!(state.status, StreamStatus::Writing) {
+            return Ok(());
+        }
+        let res = state.cache.get_or_insert_with(init).write_all(data);
+        Self::wake(&mut state);
+        res
+    }
+
+    /// Takes the bytes received so far for a download restarted from the
+    /// beginning; readers wait until the new download catches up with them.
+    ///
+    /// # Returns
+    /// The cache of the bytes received so far, `None` if it is empty.
+    pub(crate) fn reset(&self) -> Option<RamCache> {
+        let mut state = self.state.lock().unwrap();
+        match state.cache.as_ref() {
+            Some(cache) if cache.size() != 0 => state.cache.take(),
+            _ => None,
+        }
+    }
+
+    /// Ends the stream, turning the bytes received into the finished cache.
+    ///
+    /// `finish_write` runs with the stream locked, so readers never see the
+    /// received bytes vanish before the finished cache replaces them.
+    pub(crate) fn finish<F>(&self, finish_write: F) -> Arc<RamCache>
+    where
+        F: FnOnce(Option<RamCache>) -> Arc<RamCache>,
+    {
+        let mut state = self.state.lock().unwrap();
+        let cache = finish_write(state.cache.take());
+        state.status = StreamStatus::Finished(cache.clone());
+        Self::wake(&mut state);
+        cache
+    }
+
+    /// Ends the stream of a download that failed or was canceled.
+    ///
+    /// Readers may still consume the bytes received so far.
+    pub(crate) fn fail(&self) {
+        let mut state = self.state.lock().unwrap();
+        if matches!(state.status, StreamStatus::Writing) {
+            state.status = StreamStatus::Failed;
+            Self::wake(&mut state);
+        }
+    }
+
+    /// Attaches a reader, signaled whenever bytes are received.
+    fn attach(&self, notify: &Arc<NotifyCondition>) {
+        self.state
+            .lock()
+            .unwrap()
+            .waiters
+            .push(Arc::downgrade(notify));
+    }
+
+    /// Signals every waiting reader, dropping the readers that are gone.
+    fn wake(state: &mut StreamState) {
+        state.waiters.retain(|waiter| match waiter.upgrade() {
+            Some(notify) => {
+                notify.notify();
+                true
+            }
+            None => false,
+        });
+        for waker in state.wakers.drain(..) {
+            waker.wake();
+        }
+    }
+
+    /// Copies the bytes at `pos` into `buf`.
+    ///
+    /// # Returns
+    /// `None` if no bytes are available at `pos` yet, after registering
+    /// `waker` if given, otherwise the result of the read.
+    fn read_at(
+        &self,
+        pos: usize,
+        buf: &mut [u8],
+        waker: Option<&Waker>,
+    ) -> Option<io::Result<usize>> {
+        let mut state = self.state.lock().unwrap();
+        if let StreamStatus::Cached {
+            manager,
+            task_id,
+            size,
+        } = &state.status
+        {
+            let (manager, task_id, size) = (*manager, task_id.clone(), *size);
+            drop(state);
+            return Some(Self::read_file(manager, &task_id, size, pos, buf));
+        }
+        let data: &[u8] = match (&state.status, &state.cache) {
+            (StreamStatus::Finished(cache), _) => cache.cursor().into_inner(),
+            (_, Some(cache)) => cache.cursor().into_inner(),
+            _ => &[],
+        };
+        if pos < data.len() {
+            let n = buf.len().min(data.len() - pos);
+            buf[..n].copy_from_slice(&data[pos..pos + n]);
+            return Some(Ok(n));
+        }
+        match state.status {
+            StreamStatus::Finished(_) | StreamStatus::Cached { .. } => Some(Ok(0)),
+            StreamStatus::Failed => Some(Err(io::Error::new(
+                io::ErrorKind::ConnectionAborted,
+                "download of the cache entry failed",
+            ))),
+            StreamStatus::Writing => {
+                if let Some(waker) = waker {
+                    state.wakers.push(waker.clone());
+                }
+                None
+            }
+        }
+    }
+
+    /// Copies the bytes of a file cached entry at `pos` into `buf`, reading
+    /// no more than `buf` from the file.
+    fn read_file(
+        manager: &CacheManager,
+        task_id: &TaskId,
+        size: usize,
+        pos: usize,
+        buf: &mut [u8],
+    ) -> io::Result<usize> {
+        if pos >= size {
+            return Ok(0);
+        }
+        let len = buf.len().min(size - pos);
+        let data = manager.read_range(task_id, pos as u64, len)?;
+        if data.is_empty() && len != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "cache file shorter than the entry",
+            ));
+        }
+        buf[..data.len()].copy_from_slice(&data);
+        Ok(data.len())
+    }
+
+    /// Returns the size of the entry, if known.
+    fn total_size(&self) -> Option<usize> {
+        let state = self.state.lock().unwrap();
+        match &state.status {
+            StreamStatus::Finished(cache) => Some(cache.size()),
+            StreamStatus::Cached { size, .. } => Some(*size),
+            _ => state.total_size,
+        }
+    }
+
+    /// Returns the number of bytes available and whether the stream ended
+    /// successfully.
+    fn progress(&self) -> (usize, bool) {
+        let state = self.state.lock().unwrap();
+        match &state.status {
+            StreamStatus::Finished(cache) => (cache.size(), true),
+            StreamStatus::Cached { size, .. } => (*size, true),
+            _ => (state.cache.as_ref().map_or(0, RamCache::size), false),
+        }
+    }
+}
+
+/// Reader of a cache entry that may still be downloading.
+///
+/// Reads return the bytes as soon as they are received and wait for more at
+/// the end of the downloaded bytes, until the download finishes (end of file)
+/// or fails (`ErrorKind::ConnectionAborted`). Several readers may read the
+/// same entry, each at its own position.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::io::Read;
+///
+/// use cache_core::CacheManager;
+/// use request_utils::task_id::TaskId;
+///
+/// fn play(manager: &'static CacheManager, task_id: &TaskId) {
+///     if let Some(mut reader) = manager.open_stream(task_id) {
+///         let mut buf = [0u8; 4096];
+///         while let Ok(n) = reader.read(&mut buf) {
+///             if n == 0 {
+///                 break;
+///             }
+///             // Decode the n bytes read.
+///         }
+///     }
+/// }
+/// ```
+pub struct CacheReader {
+    /// Stream of the entry
+    stream: Arc<CacheStream>,
+    /// Position of the next byte to read
+    pos: usize,
+    /// Condition signaled when the stream changes
+    notify: Arc<NotifyCondition>,
+}
+
+impl CacheReader {
+    /// Creates a reader at the start of a stream.
+    pub(crate) fn new(stream: Arc<CacheStream>) -> Self {
+        let notify = Arc::new(NotifyCondition::new());
+        stream.attach(&notify);
+        Self {
+            stream,
+            pos: 0,
+            notify,
+        }
+    }
+
+    /// Returns the number of bytes downloaded so far.
+    pub fn available(&self) -> usize {
+        self.stream.progress().0
+    }
+
+    /// Returns the size of the entry, known once the download finished or its
+    /// response announced it.
+    pub fn total_size(&self) -> Option<usize> {
+        self.stream.total_size()
+    }
+
+    /// Returns whether the download of the entry finished successfully.
+    pub fn is_finished(&self) -> bool {
+        self.stream.progress().1
+    }
+
+    /// Reads bytes into `buf`, waiting asynchronously for them to be
+    /// downloaded.
+    ///
+    /// The asynchronous counterpart of `Read::read`.
+    ///
+    /// # Returns
+    /// The number of bytes read, 0 at the end of a finished entry.
+    pub async fn read_async(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = poll_fn(
+            |cx| match self.stream.read_at(self.pos, buf, Some(cx.waker())) {
+                Some(res) => Poll::Ready(res),
+                None => Poll::Pending,
+            },
+        )
+        .await?;
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+impl Read for CacheReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            if let Some(res) = self.stream.read_at(self.pos, buf, None) {
+                let n = res?;
+                self.pos += n;
+                return Ok(n);
+            }
+            self.notify.wait();
+        }
+    }
+}
+
+impl Seek for CacheReader {
+    /// Seeks within the bytes downloaded so far.
+    ///
+    /// Seeking from the end is only supported once the download finished.
+    /// Seeking past the downloaded bytes is an `InvalidInput` error.
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let (available, finished) = self.stream.progress();
+        let target = match pos {
+            SeekFrom::Start(offset) => offset as i128,
+            SeekFrom::Current(offset) => self.pos as i128 + offset as i128,
+            SeekFrom::End(offset) if finished => available as i128 + offset as i128,
+            SeekFrom::End(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "seek from the end of an entry still downloading",
+                ))
+            }
+        };
+        if !(0..=available as i128).contains(&target) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "seek outside the downloaded bytes",
+            ));
+        }
+        self.pos = target as usize;
+        Ok(self.pos as u64)
+    }
+}
+
+#[cfg(test)]
+mod ut_stream {
+    include!("../tests/ut/ut_stream.rs");
+}
@@ -18,13 +18,13 @@
 //! state. It handles the synchronization between incoming data and the caching
 //! system.
 
-use std::io::Write;
 use std::sync::Arc;
 
 use request_utils::task_id::TaskId;
 
 use crate::data::RamCache;
 use crate::manage::CacheManager;
+use crate::stream::CacheStream;
 
 // Previous version of Updater struct (commented out)
 // pub(crate) struct Updater {
@@ -61,18 +61,32 @@ pub struct Updater {
     /// Unique identifier for the task being updated
     task_id: TaskId,
 
-    /// Optional RAM cache for storing received data
-    cache: Option<RamCache>,
-
     /// Reference to the global cache manager
     cache_manager: &'static CacheManager,
+
+    /// Stream of the entry while it is downloading, holding the received data
+    stream: Option<Arc<CacheStream>>,
+
+    /// Origin URL of the data, stored in the metadata of the cache file
+    url: Option<String>,
+
+    /// Namespace of the entry, `None` for the default namespace
+    namespace: Option<String>,
+}
+
+impl Drop for Updater {
+    /// Ends the stream of a download that did not finish.
+    fn drop(&mut self) {
+        self.cache_fail();
+    }
 }
 
 impl Updater {
     /// Creates a new updater for the specified task.
     ///
     /// Initializes an updater with the given task ID and cache manager
-    /// reference, ready to receive data for caching.
+    /// reference, ready to receive data for caching, and registers the stream
+    /// of the entry so that it can be read while downloading.
     ///
     /// # Arguments
     /// * `task_id` - Unique identifier for the task
@@ -81,13 +95,33 @@ impl Updater {
     /// # Returns
     /// A new Updater instance
     pub fn new(task_id: TaskId, cache_manager: &'static CacheManager) -> Self {
+        let stream = cache_manager.start_stream(&task_id);
         Self {
             task_id,
-            cache: None,
             cache_manager,
+            stream: Some(stream),
+            url: None,
+            namespace: None,
         }
     }
 
+    /// Records the origin URL of the data being cached.
+    ///
+    /// # Arguments
+    /// * `url` - URL the data is downloaded from
+    pub fn set_url(&mut self, url: &str) {
+        self.url = Some(url.to_string());
+    }
+
+    /// Stores the entry in a namespace instead of the default one.
+    ///
+    /// # Arguments
+    /// * `namespace` - Name of the namespace, see
+    ///   [`CacheManager::set_namespace_cache_size`]
+    pub fn set_namespace(&mut self, namespace: &str) {
+        self.namespace = Some(namespace.to_string());
+    }
+
     /// Finalizes the cache and returns an Arc-wrapped RamCache.
     ///
     /// Completes the write operation on the cache and returns it wrapped in an
@@ -97,13 +131,35 @@ impl Updater {
     /// # Returns
     /// An Arc-wrapped RamCache instance containing the cached data
     pub fn cache_finish(&mut self) -> Arc<RamCache> {
-        match self.cache.take() {
+        let finish_write = |cache: Option<RamCache>| match cache {
             Some(cache) => cache.finish_write(),
             None => Arc::new(RamCache::new(
                 self.task_id.clone(),
                 self.cache_manager,
                 Some(0),
             )),
+        };
+        match self.stream.take() {
+            Some(stream) => {
+                let cache = stream.finish(finish_write);
+                self.cache_manager.end_stream(&self.task_id, &stream);
+                self.cache_manager.stats.miss(self.namespace.as_deref());
+                cache
+            }
+            None => finish_write(None),
+        }
+    }
+
+    /// Ends the stream of the entry after the download failed or was
+    /// canceled.
+    ///
+    /// Readers of the entry get an error once they consumed the bytes
+    /// received so far.
+    pub fn cache_fail(&mut self) {
+        if let Some(stream) = self.stream.take() {
+            stream.fail();
+            self.cache_manager.end_stream(&self.task_id, &stream);
+            self.cache_manager.stats.miss(self.namespace.as_deref());
         }
     }
 
@@ -124,16 +180,27 @@ impl Updater {
     where
         F: FnOnce() -> Option<usize>,
     {
-        // Initialize cache on first data reception
-        if self.cache.is_none() {
+        // Data received after the download ended starts a new one
+        let stream = self
+            .stream
+            .get_or_insert_with(|| self.cache_manager.start_stream(&self.task_id));
+
+        self.cache_manager
+            .stats
+            .receive(self.namespace.as_deref(), data.len() as u64);
+
+        // Initialize cache on first data reception, then write data to cache
+        // and log errors without panicking
+        let init = || {
             let content_length = content_length();
-            let apply_cache =
+            stream.set_total_size(content_length);
+            let mut apply_cache =
                 RamCache::new(self.task_id.clone(), self.cache_manager, content_length);
-            self.cache = Some(apply_cache)
-        }
-
-        // Write data to cache and log errors without panicking
-        if let Err(e) = self.cache.as_mut().unwrap().write_all(data) {
+            apply_cache.set_url(self.url.clone());
+            apply_cache.set_namespace(self.namespace.clone());
+            apply_cache
+        };
+        if let Err(e) = stream.receive(data, init) {
             error!("{} cache write error: {}", self.task_id.brief(), e);
         };
     }
@@ -143,10 +210,8 @@ impl Updater {
     /// Takes ownership of the current cache if it contains data, effectively
     /// clearing it and releasing associated resources.
     pub fn reset_cache(&mut self) {
-        let size = self.cache.as_ref().map(|a| a.size()).unwrap_or(0);
-        if size != 0 {
-            info!("reset {} cache size {}", self.task_id.brief(), size);
-            self.cache.take();
+        if let Some(cache) = self.stream.as_ref().and_then(|stream| stream.reset()) {
+            info!("reset {} cache size {}", self.task_id.brief(), cache.size());
         }
     }
 }
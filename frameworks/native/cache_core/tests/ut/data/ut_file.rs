@@ -78,6 +78,37 @@ fn ut_cache_file_create() {
     FileCache::remove_file(&task_id);
 }
 
+// @tc.name: ut_cache_file_read_range
+// @tc.desc: Test reading ranges of a cache file
+// @tc.precon: NA
+// @tc.step: 1. Create a file cache with test data
+//           2. Read a range within the file, a range running past its end
+//              with a huge length and a range past its end
+// @tc.expect: Only the bytes within the file are returned
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_cache_file_read_range() {
+    init();
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    CACHE_MANAGER.set_file_cache_size(TEST_SIZE);
+
+    init_curr_store_dir();
+
+    let task_id = TaskId::new(fast_random().to_string());
+    let mut ram_cache = RamCache::new(task_id.clone(), &CACHE_MANAGER, Some(TEST_STRING_SIZE));
+    ram_cache.write_all(TEST_STRING.as_bytes()).unwrap();
+    assert!(FileCache::create_file(&task_id, Arc::new(ram_cache)).is_ok());
+
+    let data = FileCache::read_range(&task_id, 3, 6).unwrap();
+    assert_eq!(data, &TEST_STRING.as_bytes()[3..9]);
+    let data = FileCache::read_range(&task_id, 3, usize::MAX).unwrap();
+    assert_eq!(data, &TEST_STRING.as_bytes()[3..]);
+    let data = FileCache::read_range(&task_id, u64::MAX, usize::MAX).unwrap();
+    assert!(data.is_empty());
+    FileCache::remove_file(&task_id);
+}
+
 // @tc.name: ut_cache_file_try_add_beyond_limit
 // @tc.desc: Test failure to add file cache when size exceeds limit
 // @tc.precon: NA
@@ -197,3 +228,129 @@ fn ut_cache_file_update_ram_from_file() {
     }
     FileCache::remove_file(&task_id);
 }
+
+// @tc.name: ut_cache_file_create_meta
+// @tc.desc: Test the metadata written with a cache file
+// @tc.precon: NA
+// @tc.step: 1. Create a cache file from a RAM cache with an origin URL
+//           2. Read its metadata and the file back
+// @tc.expect: No temporary file remains, the metadata holds the URL and size,
+//             and the URL is restored when the file is read
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_cache_file_create_meta() {
+    init();
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    CACHE_MANAGER.set_file_cache_size(TEST_SIZE);
+
+    init_curr_store_dir();
+    let task_id = TaskId::new(fast_random().to_string());
+    let mut ram_cache = RamCache::new(task_id.clone(), &CACHE_MANAGER, Some(TEST_STRING_SIZE));
+    ram_cache.set_url(Some("https://example.com".to_string()));
+    ram_cache.write_all(TEST_STRING.as_bytes()).unwrap();
+    assert!(FileCache::create_file(&task_id, Arc::new(ram_cache)).is_ok());
+    assert!(!FileCache::temp_path(&task_id).unwrap().exists());
+    assert!(!FileCache::meta_temp_path(&task_id).unwrap().exists());
+
+    let meta = FileCache::read_meta(&task_id).unwrap().unwrap();
+    assert_eq!(meta.size(), TEST_STRING_SIZE as u64);
+    assert_eq!(meta.url(), Some("https://example.com"));
+    let read_cache = FileCache::read(&task_id, &CACHE_MANAGER).unwrap();
+    assert_eq!(read_cache.url(), Some("https://example.com"));
+
+    FileCache::remove_file(&task_id);
+    assert!(!FileCache::meta_path(&task_id).unwrap().exists());
+}
+
+// @tc.name: ut_cache_file_corrupt
+// @tc.desc: Test the detection of cache files not matching their metadata
+// @tc.precon: NA
+// @tc.step: 1. Create a cache file and modify a byte of it
+//           2. Read the file
+//           3. Truncate the file and restore the files of its directory
+// @tc.expect: Reading fails with InvalidData, and the truncated file is not
+//             restored but removed with its metadata
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_cache_file_corrupt() {
+    init();
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    CACHE_MANAGER.set_file_cache_size(TEST_SIZE);
+
+    init_curr_store_dir();
+    let task_id = TaskId::new(fast_random().to_string());
+    let mut ram_cache = RamCache::new(task_id.clone(), &CACHE_MANAGER, Some(TEST_STRING_SIZE));
+    ram_cache.write_all(TEST_STRING.as_bytes()).unwrap();
+    assert!(FileCache::create_file(&task_id, Arc::new(ram_cache)).is_ok());
+
+    let path = FileCache::path(&task_id).unwrap();
+    let mut data = fs::read(&path).unwrap();
+    data[0] ^= 0xFF;
+    fs::write(&path, &data).unwrap();
+    let err = FileCache::read(&task_id, &CACHE_MANAGER).err().unwrap();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    // Move the entry to a directory of its own to restore it alone
+    let dir = unsafe { FILE_STORE_DIR.join(String::from("corrupt_test")).unwrap() };
+    fs::create_dir_all(&dir).unwrap();
+    let moved = dir.join(path.file_name().unwrap());
+    let moved_meta = dir.join(FileCache::meta_path(&task_id).unwrap().file_name().unwrap());
+    fs::write(&moved, &data[..1]).unwrap();
+    fs::rename(FileCache::meta_path(&task_id).unwrap(), &moved_meta).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(get_info_from_path(dir.as_path()).count(), 0);
+    assert!(!moved.exists());
+    assert!(!moved_meta.exists());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+// @tc.name: ut_cache_file_set_pinned
+// @tc.desc: Test recording the pin of a cache file in its metadata
+// @tc.precon: NA
+// @tc.step: 1. Create a cache file and remove its metadata
+//           2. Pin the file, then restore the files of its directory
+//           3. Unpin the file
+// @tc.expect: The metadata is recreated with the pin and the size of the
+//             file, the restored entry is pinned, and unpinning is recorded
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_cache_file_set_pinned() {
+    init();
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    CACHE_MANAGER.set_file_cache_size(TEST_SIZE);
+
+    init_curr_store_dir();
+    let task_id = TaskId::new(fast_random().to_string());
+    let mut ram_cache = RamCache::new(task_id.clone(), &CACHE_MANAGER, Some(TEST_STRING_SIZE));
+    ram_cache.write_all(TEST_STRING.as_bytes()).unwrap();
+    assert!(FileCache::create_file(&task_id, Arc::new(ram_cache)).is_ok());
+    fs::remove_file(FileCache::meta_path(&task_id).unwrap()).unwrap();
+
+    assert!(FileCache::set_pinned(&task_id, true).is_ok());
+    assert!(!FileCache::meta_temp_path(&task_id).unwrap().exists());
+    let meta = FileCache::read_meta(&task_id).unwrap().unwrap();
+    assert!(meta.pinned());
+    assert_eq!(meta.size(), TEST_STRING_SIZE as u64);
+
+    // Move the entry to a directory of its own to restore it alone
+    let dir = unsafe { FILE_STORE_DIR.join(String::from("pinned_test")).unwrap() };
+    fs::create_dir_all(&dir).unwrap();
+    for path in [
+        FileCache::path(&task_id).unwrap(),
+        FileCache::meta_path(&task_id).unwrap(),
+    ] {
+        fs::copy(&path, dir.join(path.file_name().unwrap())).unwrap();
+    }
+    let infos = get_info_from_path(dir.as_path()).collect::<Vec<_>>();
+    assert_eq!(infos.len(), 1);
+    assert!(infos[0].pinned());
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(FileCache::set_pinned(&task_id, false).is_ok());
+    assert!(!FileCache::read_meta(&task_id).unwrap().unwrap().pinned());
+    FileCache::remove_file(&task_id);
+}
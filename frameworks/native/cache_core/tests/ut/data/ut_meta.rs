@@ -0,0 +1,123 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+
+use super::*;
+
+const TEST_DATA: &[u8] = b"123456789";
+const TEST_CRC: u32 = 0xCBF4_3926;
+
+// @tc.name: ut_meta_crc32
+// @tc.desc: Test the CRC-32 computation
+// @tc.precon: NA
+// @tc.step: 1. Compute the checksum of the standard check input at once
+//           2. Compute it again in several updates
+// @tc.expect: Both checksums equal the standard check value
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_meta_crc32() {
+    let mut crc = Crc32::new();
+    crc.update(TEST_DATA);
+    assert_eq!(crc.finish(), TEST_CRC);
+
+    let mut crc = Crc32::new();
+    for chunk in TEST_DATA.chunks(2) {
+        crc.update(chunk);
+    }
+    assert_eq!(crc.finish(), TEST_CRC);
+    assert_eq!(Crc32::new().finish(), 0);
+}
+
+// @tc.name: ut_meta_encode_decode
+// @tc.desc: Test the encoding of the metadata
+// @tc.precon: NA
+// @tc.step: 1. Encode metadata with and without URL, namespace, pin and
+//              headers
+//           2. Decode the encoded texts
+//           3. Decode malformed texts
+// @tc.expect: Encoded metadata decode to the same values, at millisecond
+//             precision, and malformed texts are rejected
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_meta_encode_decode() {
+    let url = Some("https://example.com/a?b=c".to_string());
+    let namespace = Some("thumbnails".to_string());
+    for (url, namespace, pinned) in [(None, None, false), (url, namespace, true)] {
+        let mut meta = FileMeta::new(url, TEST_DATA);
+        meta.set_namespace(namespace);
+        meta.set_pinned(pinned);
+        if pinned {
+            meta.set_headers(vec![
+                ("Content-Type".to_string(), "image/png".to_string()),
+                ("ETag".to_string(), "\"a:b=c\"".to_string()),
+            ]);
+        }
+        meta.created = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+        let decoded = FileMeta::decode(&meta.encode()).unwrap();
+        assert_eq!(decoded, meta);
+    }
+
+    let text = FileMeta::new(None, TEST_DATA).encode();
+    assert!(FileMeta::decode("").is_none());
+    assert!(FileMeta::decode(&text.replace("version=1", "version=2")).is_none());
+    assert!(FileMeta::decode(&text.replace("crc32=", "crc32=x")).is_none());
+    assert!(FileMeta::decode(&text.replace("size=", "length=")).is_none());
+    assert!(FileMeta::decode("garbage").is_none());
+    assert!(FileMeta::decode(&format!("{}header=no-colon\n", text)).is_none());
+}
+
+// @tc.name: ut_meta_verify
+// @tc.desc: Test the verification of a content against its metadata
+// @tc.precon: NA
+// @tc.step: 1. Create the metadata of a content
+//           2. Verify the content, a truncated one and a modified one
+// @tc.expect: Only the original content is accepted, others fail with
+//             InvalidData
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_meta_verify() {
+    let meta = FileMeta::new(None, TEST_DATA);
+    assert_eq!(meta.size(), TEST_DATA.len() as u64);
+    assert!(meta.verify(TEST_DATA.len() as u64, TEST_CRC).is_ok());
+
+    let err = meta.verify(4, TEST_CRC).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    let err = meta.verify(TEST_DATA.len() as u64, !TEST_CRC).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+// @tc.name: ut_meta_checksum_reader
+// @tc.desc: Test the reader computing the checksum of the bytes read
+// @tc.precon: NA
+// @tc.step: 1. Read the content through a checksum reader
+//           2. Create metadata from a reader
+// @tc.expect: The bytes are passed through and the size and checksum match
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_meta_checksum_reader() {
+    let mut reader = ChecksumReader::new(TEST_DATA);
+    let mut out = Vec::new();
+    reader.read_to_end(&mut out).unwrap();
+    assert_eq!(out, TEST_DATA);
+    assert_eq!(reader.size(), TEST_DATA.len() as u64);
+    assert_eq!(reader.checksum(), TEST_CRC);
+
+    let meta = FileMeta::from_reader(Some("url".to_string()), TEST_DATA).unwrap();
+    assert_eq!(meta.checksum, TEST_CRC);
+    assert_eq!(meta.url(), Some("url"));
+}
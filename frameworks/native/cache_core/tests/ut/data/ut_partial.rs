@@ -0,0 +1,97 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+// @tc.name: ut_range_map_insert_merge
+// @tc.desc: Test that inserted ranges are merged with their neighbors
+// @tc.precon: NA
+// @tc.step: 1. Insert disjoint, overlapping and adjacent ranges
+//           2. Check the resulting ranges and covered bytes
+// @tc.expect: Overlapping and adjacent ranges are merged, others kept apart
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_range_map_insert_merge() {
+    let mut map = RangeMap::new();
+    map.insert(10..20);
+    map.insert(30..40);
+    map.insert(0..0);
+    assert_eq!(map.ranges, vec![10..20, 30..40]);
+    assert_eq!(map.covered(), 20);
+
+    map.insert(20..25);
+    assert_eq!(map.ranges, vec![10..25, 30..40]);
+    map.insert(5..12);
+    assert_eq!(map.ranges, vec![5..25, 30..40]);
+    map.insert(24..31);
+    assert_eq!(map.ranges, vec![5..40]);
+    map.insert(50..60);
+    map.insert(0..100);
+    assert_eq!(map.ranges, vec![0..100]);
+    assert_eq!(map.covered(), 100);
+}
+
+// @tc.name: ut_range_map_missing
+// @tc.desc: Test the missing sub-ranges of a range
+// @tc.precon: NA
+// @tc.step: 1. Insert two disjoint ranges
+//           2. Query ranges around, across and inside them
+// @tc.expect: The gaps are returned in order and contains matches them
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_range_map_missing() {
+    let mut map = RangeMap::new();
+    map.insert(10..20);
+    map.insert(30..40);
+
+    assert_eq!(map.missing(&(0..50)), vec![0..10, 20..30, 40..50]);
+    assert_eq!(map.missing(&(15..35)), vec![20..30]);
+    assert_eq!(map.missing(&(12..18)), vec![]);
+    assert_eq!(map.missing(&(40..45)), vec![40..45]);
+    assert_eq!(map.missing(&(0..0)), vec![]);
+
+    assert!(map.contains(&(10..20)));
+    assert!(map.contains(&(35..35)));
+    assert!(!map.contains(&(15..35)));
+    assert!(!map.contains(&(5..15)));
+}
+
+// @tc.name: ut_partial_file_complete
+// @tc.desc: Test the completeness of a partial entry
+// @tc.precon: NA
+// @tc.step: 1. Record ranges of an entry without and with a total size
+//           2. Check the added size of segments and the completeness
+// @tc.expect: The entry is complete only once every byte up to the total
+//             size is present
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_partial_file_complete() {
+    let mut partial = PartialFile::new(TaskId::new("ut_partial".to_string()));
+    partial.ranges.insert(0..10);
+    assert_eq!(partial.added_size(5, 10), 5);
+    assert_eq!(partial.added_size(0, 10), 0);
+    assert!(!partial.is_complete());
+
+    partial.set_total(20);
+    assert_eq!(partial.total(), Some(20));
+    assert!(!partial.is_complete());
+    partial.ranges.insert(10..20);
+    assert_eq!(partial.size(), 20);
+    assert!(partial.is_complete());
+}
@@ -0,0 +1,188 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn entries() -> Vec<ArchiveEntry> {
+    vec![
+        ArchiveEntry {
+            key: None,
+            url: "https://example.com/a.png".to_string(),
+            namespace: None,
+            headers: vec![],
+            body: b"123456789".to_vec(),
+        },
+        ArchiveEntry {
+            key: Some("0123456789abcdef".to_string()),
+            url: "https://example.com/b.json".to_string(),
+            namespace: Some("feed".to_string()),
+            headers: vec![
+                ("Content-Type".to_string(), "application/json".to_string()),
+                ("ETag".to_string(), "\"v1\"".to_string()),
+            ],
+            body: vec![],
+        },
+    ]
+}
+
+fn archive(entries: &[ArchiveEntry]) -> Vec<u8> {
+    let mut writer = ArchiveWriter::new(Vec::new()).unwrap();
+    for entry in entries.iter() {
+        writer.write_entry(entry).unwrap();
+    }
+    writer.finish().unwrap()
+}
+
+// @tc.name: ut_archive_roundtrip
+// @tc.desc: Test writing and reading an archive
+// @tc.precon: NA
+// @tc.step: 1. Write entries with and without key, namespace, headers and
+//              body to an archive
+//           2. Read the archive back
+// @tc.expect: The entries read equal the entries written, and an archive
+//             without entries reads as empty
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_archive_roundtrip() {
+    let entries = entries();
+    let data = archive(&entries);
+    let read = ArchiveReader::new(data.as_slice())
+        .unwrap()
+        .collect::<io::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(read, entries);
+    assert!(entries[0].task_id() == TaskId::from_url("https://example.com/a.png"));
+    assert_eq!(entries[1].task_id().to_string(), "0123456789abcdef");
+
+    let data = archive(&[]);
+    assert_eq!(ArchiveReader::new(data.as_slice()).unwrap().count(), 0);
+}
+
+// @tc.name: ut_archive_invalid
+// @tc.desc: Test reading malformed archives
+// @tc.precon: NA
+// @tc.step: 1. Read data that is not an archive, and an archive of another
+//              version
+//           2. Read an archive with a corrupt body, and a truncated archive
+// @tc.expect: The header is rejected with InvalidData, the entries before a
+//             corrupt or truncated one are read, then reading stops with an
+//             error
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_archive_invalid() {
+    let err = ArchiveReader::new(&b"GARBAGE!\x01\x00\x00\x00"[..])
+        .err()
+        .unwrap();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    let mut data = archive(&[]);
+    data[8] = 2;
+    let err = ArchiveReader::new(data.as_slice()).err().unwrap();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    let entries = entries();
+    let mut data = archive(&entries);
+    let len = data.len();
+    data[len - 1] ^= 0xFF;
+    let mut reader = ArchiveReader::new(data.as_slice()).unwrap();
+    assert_eq!(reader.next().unwrap().unwrap(), entries[0]);
+    let err = reader.next().unwrap().unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert!(reader.next().is_none());
+
+    let data = archive(&entries);
+    let mut reader = ArchiveReader::new(&data[..data.len() - 1]).unwrap();
+    assert_eq!(reader.next().unwrap().unwrap(), entries[0]);
+    let err = reader.next().unwrap().unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
+
+// @tc.name: ut_archive_storable
+// @tc.desc: Test which archive entries can be stored in the file cache
+// @tc.precon: NA
+// @tc.step: 1. Check entries with valid fields
+//           2. Check entries with an unsafe key, without key nor URL, and
+//              with line breaks in their fields
+// @tc.expect: Only the entries with valid fields are storable
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_archive_storable() {
+    let entries = entries();
+    assert!(entries.iter().all(ArchiveEntry::is_storable));
+
+    let mut entry = entries[1].clone();
+    entry.key = Some("../a".to_string());
+    assert!(!entry.is_storable());
+    entry.key = Some(String::new());
+    assert!(!entry.is_storable());
+
+    let mut entry = entries[1].clone();
+    entry.url = String::new();
+    assert!(entry.is_storable());
+    entry.key = None;
+    assert!(!entry.is_storable());
+    entry.url = "https://example.com/\nsize=0".to_string();
+    assert!(!entry.is_storable());
+
+    let mut entry = entries[1].clone();
+    entry
+        .headers
+        .push(("X-A".to_string(), "b\r\nc".to_string()));
+    assert!(!entry.is_storable());
+    let mut entry = entries[1].clone();
+    entry.namespace = Some("a\nb".to_string());
+    assert!(!entry.is_storable());
+}
+
+// @tc.name: ut_archive_stream_body
+// @tc.desc: Test archive bodies copied as they are read
+// @tc.precon: NA
+// @tc.step: 1. Write an entry whose body is larger than the largest cache
+//              entry between two entries, from readers
+//           2. Read the archive back
+//           3. Write an entry from a reader shorter than its size
+// @tc.expect: The large entry is skipped, the others are read, and the short
+//             body fails with UnexpectedEof
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_archive_stream_body() {
+    let entries = entries();
+    let mut writer = ArchiveWriter::new(Vec::new()).unwrap();
+    writer
+        .write_entry_from(&entries[0], 9, entries[0].body.as_slice())
+        .unwrap();
+    let size = MAX_CACHE_SIZE + 1;
+    writer
+        .write_entry_from(&entries[1], size, io::repeat(7).take(size))
+        .unwrap();
+    writer.write_entry(&entries[1]).unwrap();
+    let data = writer.finish().unwrap();
+    let read = ArchiveReader::new(data.as_slice())
+        .unwrap()
+        .collect::<io::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(read, entries);
+
+    let mut writer = ArchiveWriter::new(Vec::new()).unwrap();
+    let err = writer
+        .write_entry_from(&entries[0], 10, entries[0].body.as_slice())
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
@@ -134,6 +134,43 @@ fn ut_cache_manager_cache_from_file() {
     }
 }
 
+// @tc.name: ut_cache_manager_open_stream_from_file
+// @tc.desc: Test streaming an entry cached in a file only
+// @tc.precon: NA
+// @tc.step: 1. Create file cache with test data
+//           2. Remove RAM cache
+//           3. Open a stream and read it with a small buffer
+// @tc.expect: The data is read from the file without loading it into RAM
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_cache_manager_open_stream_from_file() {
+    init();
+    let task_id = TaskId::new(fast_random().to_string());
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    let mut cache = RamCache::new(task_id.clone(), &CACHE_MANAGER, Some(TEST_STRING_SIZE));
+    cache.write_all(TEST_STRING.as_bytes()).unwrap();
+    cache.finish_write();
+
+    thread::sleep(Duration::from_millis(100));
+    CACHE_MANAGER.rams.lock().unwrap().remove(&task_id);
+
+    let mut reader = CACHE_MANAGER.open_stream(&task_id).unwrap();
+    assert_eq!(reader.total_size(), Some(TEST_STRING_SIZE));
+    assert!(reader.is_finished());
+    let mut data = vec![];
+    let mut buf = [0u8; 4];
+    loop {
+        let n = reader.read(&mut buf).unwrap();
+        if n == 0 {
+            break;
+        }
+        data.extend_from_slice(&buf[..n]);
+    }
+    assert_eq!(data, TEST_STRING.as_bytes());
+    assert!(!CACHE_MANAGER.rams.lock().unwrap().contains_key(&task_id));
+}
+
 // @tc.name: ut_cache_manager_cache_from_file_clean
 // @tc.desc: Test cache manager cleans up temporary data after file retrieval
 // @tc.precon: NA
@@ -209,3 +246,329 @@ fn ut_cache_manager_update_same() {
     cache.cursor().read_to_string(&mut buf).unwrap();
     assert_eq!(buf, test_string);
 }
+
+// @tc.name: ut_cache_manager_namespace
+// @tc.desc: Test file cache budgets of namespaces
+// @tc.precon: NA
+// @tc.step: 1. Set a default budget of one entry and a namespace budget of
+//              two entries
+//           2. Cache two entries in the namespace, then two entries in the
+//              default namespace
+//           3. Shrink the namespace budget to one entry
+// @tc.expect: Default entries only evict each other, and shrinking the
+//             namespace evicts its oldest entry
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_cache_manager_namespace() {
+    init();
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    CACHE_MANAGER.set_file_cache_size(TEST_STRING_SIZE as u64);
+    CACHE_MANAGER.set_namespace_cache_size("ut_namespace", TEST_STRING_SIZE as u64 * 2);
+
+    let cache = |namespace: Option<&str>| {
+        let task_id = TaskId::new(fast_random().to_string());
+        let mut cache = RamCache::new(task_id.clone(), &CACHE_MANAGER, Some(TEST_STRING_SIZE));
+        cache.set_namespace(namespace.map(str::to_string));
+        cache.write_all(TEST_STRING.as_bytes()).unwrap();
+        cache.finish_write();
+        thread::sleep(Duration::from_millis(100));
+        task_id
+    };
+    let contains = |task_id: &TaskId| {
+        CACHE_MANAGER
+            .file_manager
+            .caches
+            .lock()
+            .unwrap()
+            .contains(task_id)
+    };
+
+    let first = cache(Some("ut_namespace"));
+    let second = cache(Some("ut_namespace"));
+    let third = cache(None);
+    let fourth = cache(None);
+    assert!(contains(&first));
+    assert!(contains(&second));
+    assert!(!contains(&third));
+    assert!(contains(&fourth));
+
+    CACHE_MANAGER.set_namespace_cache_size("ut_namespace", TEST_STRING_SIZE as u64);
+    assert!(!contains(&first));
+    assert!(contains(&second));
+    assert!(contains(&fourth));
+    assert_eq!(
+        FileCache::read_meta(&second).unwrap().unwrap().namespace(),
+        Some("ut_namespace")
+    );
+    for task_id in [second, fourth] {
+        CACHE_MANAGER.remove(task_id);
+    }
+}
+
+// @tc.name: ut_cache_manager_shared_namespace
+// @tc.desc: Test namespaces without a budget of their own
+// @tc.precon: NA
+// @tc.step: 1. Set a default budget of one entry
+//           2. Cache an entry in each of two namespaces without budget
+//           3. Set the budget of the second namespace and cache a default
+//              entry
+// @tc.expect: The namespaces without budget evict each other, and a budget
+//             takes the entries of its namespace out of the default one
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_cache_manager_shared_namespace() {
+    init();
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    CACHE_MANAGER.set_file_cache_size(TEST_STRING_SIZE as u64);
+
+    let cache = |namespace: Option<&str>| {
+        let task_id = TaskId::new(fast_random().to_string());
+        let mut cache = RamCache::new(task_id.clone(), &CACHE_MANAGER, Some(TEST_STRING_SIZE));
+        cache.set_namespace(namespace.map(str::to_string));
+        cache.write_all(TEST_STRING.as_bytes()).unwrap();
+        cache.finish_write();
+        thread::sleep(Duration::from_millis(100));
+        task_id
+    };
+    let contains = |task_id: &TaskId| {
+        CACHE_MANAGER
+            .file_manager
+            .caches
+            .lock()
+            .unwrap()
+            .contains(task_id)
+    };
+
+    let first = cache(Some("ut_shared_first"));
+    let second = cache(Some("ut_shared_second"));
+    assert!(!contains(&first));
+    assert!(contains(&second));
+
+    CACHE_MANAGER.set_namespace_cache_size("ut_shared_second", TEST_STRING_SIZE as u64);
+    let third = cache(None);
+    assert!(contains(&second));
+    assert!(contains(&third));
+    for task_id in [second, third] {
+        CACHE_MANAGER.remove(task_id);
+    }
+}
+
+// @tc.name: ut_cache_manager_pin
+// @tc.desc: Test pinned entries and their budget
+// @tc.precon: NA
+// @tc.step: 1. Set a default budget and a pinned budget of one entry each
+//           2. Pin an entry, then cache two others
+//           3. Pin a second entry, clear the file cache and unpin the entry
+// @tc.expect: The pinned entry is not evicted nor cleared and its pin is
+//             recorded in its metadata, the second pin does not fit, and the
+//             unpinned entry evicts the last entry of the default namespace
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_cache_manager_pin() {
+    init();
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    CACHE_MANAGER.set_file_cache_size(TEST_STRING_SIZE as u64);
+    CACHE_MANAGER.set_pinned_cache_size(TEST_STRING_SIZE as u64);
+
+    let cache = || {
+        let task_id = TaskId::new(fast_random().to_string());
+        let mut cache = RamCache::new(task_id.clone(), &CACHE_MANAGER, Some(TEST_STRING_SIZE));
+        cache.write_all(TEST_STRING.as_bytes()).unwrap();
+        cache.finish_write();
+        thread::sleep(Duration::from_millis(100));
+        task_id
+    };
+    let contains = |task_id: &TaskId| {
+        CACHE_MANAGER
+            .file_manager
+            .caches
+            .lock()
+            .unwrap()
+            .contains(task_id)
+    };
+    let pinned_meta = |task_id: &TaskId| FileCache::read_meta(task_id).unwrap().unwrap().pinned();
+
+    let first = cache();
+    assert!(!CACHE_MANAGER.pin(&TaskId::new(fast_random().to_string())));
+    assert!(CACHE_MANAGER.pin(&first));
+    assert!(CACHE_MANAGER.is_pinned(&first));
+    assert!(pinned_meta(&first));
+    let second = cache();
+    let third = cache();
+    assert!(contains(&first));
+    assert!(!contains(&second));
+    assert!(contains(&third));
+
+    assert!(!CACHE_MANAGER.pin(&third));
+    let stats = CACHE_MANAGER.stats();
+    assert_eq!(stats.pinned.entries, 1);
+    assert_eq!(stats.pinned.used, TEST_STRING_SIZE as u64);
+    assert_eq!(stats.file.entries, 1);
+
+    CACHE_MANAGER.clear_file_cache(&HashSet::new());
+    assert!(contains(&first));
+    assert!(!contains(&third));
+
+    let fourth = cache();
+    assert!(CACHE_MANAGER.unpin(&first));
+    assert!(!CACHE_MANAGER.unpin(&first));
+    assert!(!CACHE_MANAGER.is_pinned(&first));
+    assert!(!pinned_meta(&first));
+    assert!(contains(&first));
+    assert!(!contains(&fourth));
+    assert_eq!(CACHE_MANAGER.stats().pinned.used, 0);
+    CACHE_MANAGER.remove(first);
+}
+
+// @tc.name: ut_cache_manager_archive
+// @tc.desc: Test importing and exporting archives of cached responses
+// @tc.precon: NA
+// @tc.step: 1. Set a default budget of two entries and cache one entry
+//           2. Import an archive holding another body for the cached entry
+//              and two new entries
+//           3. Export the file cache to an archive
+// @tc.expect: Only the first new entry is imported, the cached entry is kept
+//             and the second new entry does not fit; both cached entries are
+//             exported with their body and the imported one with its URL and
+//             headers
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_cache_manager_archive() {
+    init();
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    CACHE_MANAGER.set_file_cache_size(TEST_STRING_SIZE as u64 * 2);
+
+    let cached = TaskId::new(fast_random().to_string());
+    let mut cache = RamCache::new(cached.clone(), &CACHE_MANAGER, Some(TEST_STRING_SIZE));
+    cache.write_all(TEST_STRING.as_bytes()).unwrap();
+    cache.finish_write();
+    thread::sleep(Duration::from_millis(100));
+
+    let entry = |task_id: &TaskId, body: &[u8]| ArchiveEntry {
+        key: Some(task_id.to_string()),
+        url: format!("https://example.com/{}", task_id),
+        namespace: None,
+        headers: vec![("Content-Type".to_string(), "text/plain".to_string())],
+        body: body.to_vec(),
+    };
+    let imported = TaskId::new(fast_random().to_string());
+    let no_space = TaskId::new(fast_random().to_string());
+    let mut writer = ArchiveWriter::new(Vec::new()).unwrap();
+    writer.write_entry(&entry(&cached, b"other")).unwrap();
+    writer
+        .write_entry(&entry(&imported, TEST_STRING.as_bytes()))
+        .unwrap();
+    writer
+        .write_entry(&entry(&no_space, TEST_STRING.as_bytes()))
+        .unwrap();
+    let archive = writer.finish().unwrap();
+    assert_eq!(CACHE_MANAGER.import_archive(archive.as_slice()).unwrap(), 1);
+    assert!(CACHE_MANAGER.contains(&imported));
+    assert!(!CACHE_MANAGER.contains(&no_space));
+    let mut buf = String::new();
+    FileCache::open(&imported)
+        .unwrap()
+        .read_to_string(&mut buf)
+        .unwrap();
+    assert_eq!(buf, TEST_STRING);
+    assert!(CACHE_MANAGER.import_archive(&b"garbage"[..]).is_err());
+
+    let mut exported = vec![];
+    assert_eq!(CACHE_MANAGER.export_archive(&mut exported).unwrap(), 2);
+    let entries = ArchiveReader::new(exported.as_slice())
+        .unwrap()
+        .collect::<io::Result<Vec<_>>>()
+        .unwrap();
+    let find = |task_id: &TaskId| {
+        entries
+            .iter()
+            .find(|entry| entry.task_id() == *task_id)
+            .unwrap()
+    };
+    assert_eq!(find(&imported), &entry(&imported, TEST_STRING.as_bytes()));
+    assert_eq!(find(&cached).body, TEST_STRING.as_bytes());
+    assert!(find(&cached).url.is_empty());
+
+    CACHE_MANAGER.remove(cached);
+    CACHE_MANAGER.remove(imported);
+}
+
+// @tc.name: ut_cache_manager_stats
+// @tc.desc: Test the statistics and the entries of the cache manager
+// @tc.precon: NA
+// @tc.step: 1. Set a RAM budget of one entry and a listener of evictions
+//           2. Cache two entries in a namespace, then fetch the second one
+//           3. Take the statistics and list the entries
+// @tc.expect: The first entry is evicted from RAM and reported, the fetch is
+//             counted as a RAM hit, and both entries are listed in the file
+//             tier with only the second one in RAM
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_cache_manager_stats() {
+    use std::sync::mpsc::{channel, Sender};
+
+    struct ChannelListener(Mutex<Sender<CacheEntry>>);
+
+    impl EvictionListener for ChannelListener {
+        fn on_evict(&self, entry: &CacheEntry) {
+            let _ = self.0.lock().unwrap().send(entry.clone());
+        }
+    }
+
+    init();
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    CACHE_MANAGER.set_ram_cache_size(TEST_STRING_SIZE as u64);
+    let (tx, rx) = channel();
+    CACHE_MANAGER.set_eviction_listener(Some(Box::new(ChannelListener(Mutex::new(tx)))));
+
+    let cache = || {
+        let task_id = TaskId::new(fast_random().to_string());
+        let mut cache = RamCache::new(task_id.clone(), &CACHE_MANAGER, Some(TEST_STRING_SIZE));
+        cache.set_namespace(Some("ut_stats".to_string()));
+        cache.write_all(TEST_STRING.as_bytes()).unwrap();
+        cache.finish_write();
+        thread::sleep(Duration::from_millis(100));
+        task_id
+    };
+    let first = cache();
+    let second = cache();
+    assert!(CACHE_MANAGER.fetch(&second).is_some());
+
+    let evicted = rx.recv_timeout(Duration::from_secs(1)).unwrap();
+    assert!(evicted.task_id == first);
+    assert_eq!(evicted.tier, CacheTier::Ram);
+    assert_eq!(evicted.namespace.as_deref(), Some("ut_stats"));
+
+    let stats = CACHE_MANAGER.stats();
+    assert_eq!(stats.counters.ram_hits, 1);
+    assert_eq!(stats.counters.ram_bytes, TEST_STRING_SIZE as u64);
+    assert_eq!(stats.counters.ram_evictions, 1);
+    assert_eq!(stats.ram.entries, 1);
+    assert_eq!(stats.ram.used, TEST_STRING_SIZE as u64);
+    assert_eq!(stats.file.entries, 2);
+    let namespace = &stats.namespaces["ut_stats"];
+    assert_eq!(namespace.counters.ram_hits, 1);
+    assert_eq!(namespace.file.used, TEST_STRING_SIZE as u64 * 2);
+
+    let entries = CACHE_MANAGER.entries();
+    let tiers = |task_id: &TaskId| {
+        let mut tiers = entries
+            .iter()
+            .filter(|entry| entry.task_id == *task_id)
+            .map(|entry| entry.tier)
+            .collect::<Vec<_>>();
+        tiers.sort_by_key(|tier| *tier == CacheTier::File);
+        tiers
+    };
+    assert_eq!(tiers(&first), vec![CacheTier::File]);
+    assert_eq!(tiers(&second), vec![CacheTier::Ram, CacheTier::File]);
+    for task_id in [first, second] {
+        CACHE_MANAGER.remove(task_id);
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::mpsc::{channel, Sender};
+use std::time::Duration;
+
+use super::*;
+
+struct ChannelListener(Mutex<Sender<CacheEntry>>);
+
+impl EvictionListener for ChannelListener {
+    fn on_evict(&self, entry: &CacheEntry) {
+        let _ = self.0.lock().unwrap().send(entry.clone());
+    }
+}
+
+fn entry(namespace: Option<&str>, tier: CacheTier) -> CacheEntry {
+    CacheEntry {
+        task_id: TaskId::new("ut_stats".to_string()),
+        namespace: namespace.map(str::to_string),
+        tier,
+        size: 10,
+        last_access: SystemTime::now(),
+    }
+}
+
+// @tc.name: ut_stats_counters
+// @tc.desc: Test the counters of the stats recorder
+// @tc.precon: NA
+// @tc.step: 1. Record hits of each tier, misses and received bytes, in the
+//              default namespace and in a named one
+//           2. Take the counters
+// @tc.expect: The total counters cover both namespaces, and only the named
+//             namespace has counters of its own
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_stats_counters() {
+    let stats = StatsRecorder::new();
+    assert_eq!(stats.counters().0.hit_rate(), 0.0);
+
+    stats.hit(CacheTier::Ram, None, 10);
+    stats.hit(CacheTier::File, Some("audio"), 20);
+    stats.hit(CacheTier::Partial, Some("audio"), 5);
+    stats.miss(Some("audio"));
+    stats.receive(Some("audio"), 30);
+
+    let (total, namespaces) = stats.counters();
+    assert_eq!(
+        total,
+        CacheCounters {
+            ram_hits: 1,
+            file_hits: 2,
+            misses: 1,
+            ram_bytes: 10,
+            file_bytes: 25,
+            network_bytes: 30,
+            ..Default::default()
+        }
+    );
+    assert_eq!(total.hit_rate(), 0.75);
+    assert_eq!(namespaces.len(), 1);
+    let audio = &namespaces["audio"];
+    assert_eq!(audio.ram_hits, 0);
+    assert_eq!(audio.file_hits, 2);
+    assert_eq!(audio.hit_rate(), 2.0 / 3.0);
+}
+
+// @tc.name: ut_stats_eviction_listener
+// @tc.desc: Test counting evictions and notifying the listener
+// @tc.precon: NA
+// @tc.step: 1. Record an eviction without listener
+//           2. Set a listener and record evictions of both tiers
+//           3. Remove the listener and record another eviction
+// @tc.expect: Every eviction is counted, and the listener is notified of the
+//             evictions recorded while it is set
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_stats_eviction_listener() {
+    let stats = StatsRecorder::new();
+    stats.evict(vec![entry(None, CacheTier::Ram)]);
+
+    let (tx, rx) = channel();
+    stats.set_listener(Some(Arc::new(ChannelListener(Mutex::new(tx)))));
+    let ram = entry(Some("audio"), CacheTier::Ram);
+    let file = entry(None, CacheTier::File);
+    stats.evict(vec![ram.clone()]);
+    stats.evict(vec![file.clone()]);
+    let mut received = vec![
+        rx.recv_timeout(Duration::from_secs(1)).unwrap(),
+        rx.recv_timeout(Duration::from_secs(1)).unwrap(),
+    ];
+    received.sort_by_key(|entry| entry.tier == CacheTier::File);
+    assert!(received == vec![ram, file]);
+
+    stats.set_listener(None);
+    stats.evict(vec![entry(None, CacheTier::File)]);
+    assert!(rx.recv_timeout(Duration::from_millis(100)).is_err());
+
+    let (total, namespaces) = stats.counters();
+    assert_eq!(total.ram_evictions, 2);
+    assert_eq!(total.file_evictions, 2);
+    assert_eq!(namespaces["audio"].ram_evictions, 1);
+}
+
+// @tc.name: ut_stats_evict_batch
+// @tc.desc: Test recording a batch of evictions
+// @tc.precon: NA
+// @tc.step: 1. Set a listener and record an empty batch
+//           2. Record a batch of evictions of both tiers
+// @tc.expect: Every eviction of the batch is counted, and the listener is
+//             notified of them in order
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_stats_evict_batch() {
+    let stats = StatsRecorder::new();
+    let (tx, rx) = channel();
+    stats.set_listener(Some(Arc::new(ChannelListener(Mutex::new(tx)))));
+    stats.evict(vec![]);
+    assert!(rx.recv_timeout(Duration::from_millis(100)).is_err());
+
+    let entries = vec![
+        entry(Some("audio"), CacheTier::File),
+        entry(None, CacheTier::Ram),
+        entry(None, CacheTier::File),
+    ];
+    stats.evict(entries.clone());
+    for expected in entries {
+        assert!(rx.recv_timeout(Duration::from_secs(1)).unwrap() == expected);
+    }
+
+    let (total, namespaces) = stats.counters();
+    assert_eq!(total.ram_evictions, 1);
+    assert_eq!(total.file_evictions, 2);
+    assert_eq!(namespaces["audio"].file_evictions, 1);
+}
@@ -0,0 +1,219 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+use std::sync::LazyLock;
+use std::thread;
+use std::time::Duration;
+
+use request_utils::task_id::TaskId;
+use request_utils::test::log::init;
+
+use super::*;
+use crate::manage::CacheManager;
+
+const TEST_STRING: &str = "你这猴子真让我欢喜";
+
+static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+
+fn new_cache() -> RamCache {
+    RamCache::new(TaskId::new("ut_stream".to_string()), &CACHE_MANAGER, None)
+}
+
+fn finished_cache(data: &[u8]) -> Arc<RamCache> {
+    let mut cache = RamCache::new(
+        TaskId::new("ut_stream".to_string()),
+        &CACHE_MANAGER,
+        Some(data.len()),
+    );
+    cache.write_all(data).unwrap();
+    Arc::new(cache)
+}
+
+// @tc.name: ut_stream_read_while_writing
+// @tc.desc: Test reading a stream while its bytes are received
+// @tc.precon: NA
+// @tc.step: 1. Attach a reader to a stream being written
+//           2. Read to the end in another thread
+//           3. Receive the bytes in two steps and finish the stream
+// @tc.expect: The reader gets every byte once and ends at the finish
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_stream_read_while_writing() {
+    init();
+    let bytes = TEST_STRING.as_bytes();
+    let stream = Arc::new(CacheStream::new());
+    let mut reader = CacheReader::new(stream.clone());
+
+    let handle = thread::spawn(move || {
+        let mut buf = vec![];
+        reader.read_to_end(&mut buf).unwrap();
+        buf
+    });
+    stream.receive(&bytes[..6], new_cache).unwrap();
+    thread::sleep(Duration::from_millis(50));
+    stream.receive(&bytes[6..], new_cache).unwrap();
+    let cache = stream.finish(|written| Arc::new(written.unwrap()));
+    assert_eq!(cache.size(), bytes.len());
+    assert_eq!(handle.join().unwrap(), bytes);
+}
+
+// @tc.name: ut_stream_reset
+// @tc.desc: Test reading a stream whose download restarted
+// @tc.precon: NA
+// @tc.step: 1. Receive bytes and read some of them
+//           2. Reset the stream and receive the bytes again with more bytes
+//           3. Read the available bytes
+// @tc.expect: The reader waits for the new download to catch up and then
+//             continues where it stopped
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level2
+#[test]
+fn ut_stream_reset() {
+    init();
+    let stream = Arc::new(CacheStream::new());
+    let mut reader = CacheReader::new(stream.clone());
+    stream.receive(b"abc", new_cache).unwrap();
+    let mut buf = [0u8; 2];
+    reader.read_exact(&mut buf).unwrap();
+
+    assert_eq!(stream.reset().unwrap().size(), 3);
+    assert!(stream.reset().is_none());
+    assert_eq!(reader.available(), 0);
+    stream.receive(b"abcd", new_cache).unwrap();
+    assert_eq!(reader.available(), 4);
+
+    let mut buf = [0u8; 8];
+    assert_eq!(reader.read(&mut buf).unwrap(), 2);
+    assert_eq!(&buf[..2], b"cd");
+    assert!(!reader.is_finished());
+}
+
+// @tc.name: ut_stream_seek
+// @tc.desc: Test seeking within the downloaded bytes of a stream
+// @tc.precon: NA
+// @tc.step: 1. Receive bytes and seek from the start and current position
+//           2. Seek past the bytes and from the end before finishing
+//           3. Finish the stream and seek from the end
+// @tc.expect: Seeks within the bytes succeed, the others fail with
+//             InvalidInput or Unsupported
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_stream_seek() {
+    init();
+    let stream = Arc::new(CacheStream::new());
+    let mut reader = CacheReader::new(stream.clone());
+    stream.receive(b"0123456789", new_cache).unwrap();
+
+    let mut buf = [0u8; 3];
+    assert_eq!(reader.seek(SeekFrom::Start(4)).unwrap(), 4);
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"456");
+    assert_eq!(reader.seek(SeekFrom::Current(-2)).unwrap(), 5);
+    assert_eq!(
+        reader.seek(SeekFrom::Start(11)).unwrap_err().kind(),
+        io::ErrorKind::InvalidInput
+    );
+    assert_eq!(
+        reader.seek(SeekFrom::Current(-6)).unwrap_err().kind(),
+        io::ErrorKind::InvalidInput
+    );
+    assert_eq!(
+        reader.seek(SeekFrom::End(0)).unwrap_err().kind(),
+        io::ErrorKind::Unsupported
+    );
+
+    stream.finish(|_| finished_cache(b"0123456789"));
+    assert_eq!(reader.seek(SeekFrom::End(-1)).unwrap(), 9);
+    assert_eq!(reader.read(&mut buf).unwrap(), 1);
+    assert_eq!(buf[0], b'9');
+    assert!(reader.is_finished());
+}
+
+// @tc.name: ut_stream_fail
+// @tc.desc: Test reading a stream whose download failed
+// @tc.precon: NA
+// @tc.step: 1. Receive bytes and fail the stream
+//           2. Read to the end
+// @tc.expect: The received bytes are read, then ConnectionAborted is
+//             returned
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level2
+#[test]
+fn ut_stream_fail() {
+    init();
+    let stream = Arc::new(CacheStream::new());
+    let mut reader = CacheReader::new(stream.clone());
+    stream.receive(b"abc", new_cache).unwrap();
+    stream.fail();
+
+    let mut buf = vec![];
+    let err = reader.read_to_end(&mut buf).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::ConnectionAborted);
+    assert_eq!(buf, b"abc");
+}
+
+// @tc.name: ut_stream_read_async
+// @tc.desc: Test reading a stream asynchronously with several readers
+// @tc.precon: NA
+// @tc.step: 1. Attach an asynchronous and a blocking reader to a stream
+//           2. Receive bytes from another thread
+// @tc.expect: Both readers are woken and get the same bytes
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_stream_read_async() {
+    init();
+    let stream = Arc::new(CacheStream::new());
+    let mut async_reader = CacheReader::new(stream.clone());
+    let mut reader = CacheReader::new(stream.clone());
+
+    let writer = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        stream.receive(b"hello", new_cache).unwrap();
+    });
+    let mut buf = [0u8; 8];
+    let n = ylong_runtime::block_on(async_reader.read_async(&mut buf)).unwrap();
+    assert_eq!(&buf[..n], b"hello");
+    let n = reader.read(&mut buf).unwrap();
+    assert_eq!(&buf[..n], b"hello");
+    writer.join().unwrap();
+}
+
+// @tc.name: ut_stream_total_size
+// @tc.desc: Test the size of an entry read while downloading
+// @tc.precon: NA
+// @tc.step: 1. Check the size before and after the download announces it
+//           2. Finish the stream with fewer bytes than announced
+// @tc.expect: The announced size is returned, then the finished size
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level2
+#[test]
+fn ut_stream_total_size() {
+    init();
+    let stream = Arc::new(CacheStream::new());
+    let reader = CacheReader::new(stream.clone());
+    assert_eq!(reader.total_size(), None);
+    stream.set_total_size(Some(10));
+    assert_eq!(reader.total_size(), Some(10));
+    stream.finish(|_| finished_cache(b"01234"));
+    assert_eq!(reader.total_size(), Some(5));
+}
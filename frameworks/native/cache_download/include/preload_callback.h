@@ -21,6 +21,7 @@
 #include "request_preload.h"
 
 namespace OHOS::Request {
+struct FfiCacheEntry;
 
 class PreloadCallbackWrapper {
 public:
@@ -32,11 +33,13 @@ public:
     void OnSuccess(const std::shared_ptr<Data> data, rust::str TaskId) const;
     void OnFail(rust::Box<CacheDownloadError> error, rust::Box<RustDownloadInfo> info, rust::str TaskId) const;
     void OnCancel() const;
+    void OnUpdated(const std::shared_ptr<Data> data, rust::str TaskId) const;
 
 private:
     std::function<void(const std::shared_ptr<Data> &&, const std::string &TaskId)> onSuccess_;
     std::function<void(const PreloadError &, const std::string &TaskId)> onFail_;
     std::function<void()> onCancel_;
+    std::function<void(const std::shared_ptr<Data> &&, const std::string &TaskId)> onUpdated_;
 };
 
 class PreloadProgressCallbackWrapper {
@@ -52,10 +55,24 @@ private:
     std::function<void(uint64_t, uint64_t)> onProgress_;
 };
 
+class EvictionListenerWrapper {
+public:
+    EvictionListenerWrapper(EvictionListener listener);
+    ~EvictionListenerWrapper() = default;
+    EvictionListenerWrapper(const EvictionListenerWrapper &) = delete;
+    EvictionListenerWrapper &operator=(const EvictionListenerWrapper &) = delete;
+
+    void OnEvict(const FfiCacheEntry &entry) const;
+
+private:
+    EvictionListener onEvict_;
+};
+
 std::shared_ptr<Data> SharedData(rust::Box<RustData> data);
 std::unique_ptr<Data> UniqueData(rust::Box<RustData> data);
 std::unique_ptr<CppDownloadInfo> UniqueInfo(rust::Box<RustDownloadInfo> info);
 std::shared_ptr<PreloadHandle> ShareTaskHandle(rust::Box<TaskHandle> handle);
+CacheEntry ToCacheEntry(const FfiCacheEntry &entry);
 } // namespace OHOS::Request
 
 #endif // REQUEST_PRE_DOWNLOAD_CALLBACK_H
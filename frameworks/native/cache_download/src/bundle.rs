@@ -0,0 +1,270 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Offline bundles of resources.
+//!
+//! A bundle is a manifest of requests preloaded as a unit. Each resource is
+//! pinned once downloaded, so that the bundle stays available offline whatever
+//! else gets cached, and the progress of the downloads is reported for the
+//! bundle as a whole. See [`CacheDownloadService::preload_bundle`].
+//!
+//! [`CacheDownloadService::preload_bundle`]:
+//! crate::CacheDownloadService::preload_bundle
+
+use std::sync::{Arc, Mutex};
+
+use cache_core::{CacheManager, RamCache};
+use request_utils::task_id::TaskId;
+
+use crate::download::task::TaskHandle;
+use crate::download::CacheDownloadError;
+use crate::info::RustDownloadInfo;
+use crate::services::PreloadCallback;
+
+/// Aggregate progress of a bundle.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BundleProgress {
+    /// Number of resources in the bundle
+    pub total: usize,
+    /// Resources downloaded and pinned
+    pub succeeded: usize,
+    /// Resources that failed, were cancelled or could not be pinned
+    pub failed: usize,
+    /// Bytes downloaded so far, over all the resources
+    pub downloaded: u64,
+    /// Total size in bytes of the resources whose size is known so far
+    pub size: u64,
+}
+
+impl BundleProgress {
+    /// Returns whether every resource of the bundle succeeded or failed.
+    pub fn is_complete(&self) -> bool {
+        self.succeeded + self.failed == self.total
+    }
+}
+
+/// Trait defining callback methods for bundle preloads.
+///
+/// Methods are called one at a time, on the threads of the downloads.
+#[allow(unused_variables)]
+pub trait BundleCallback: Send {
+    /// Called when the progress of a resource of the bundle changes.
+    ///
+    /// # Arguments
+    /// * `progress` - Aggregate progress of the bundle
+    fn on_progress(&mut self, progress: &BundleProgress) {}
+
+    /// Called once, after every resource of the bundle succeeded or failed.
+    ///
+    /// # Arguments
+    /// * `progress` - Final progress of the bundle
+    /// * `failed` - URLs of the resources that are not available offline
+    fn on_complete(&mut self, progress: &BundleProgress, failed: &[String]) {}
+}
+
+/// Handle for controlling the downloads of a bundle.
+pub struct BundleHandle {
+    /// Handles of the downloads of the resources
+    handles: Vec<TaskHandle>,
+}
+
+impl BundleHandle {
+    /// Creates a handle over the downloads of a bundle.
+    pub(crate) fn new(handles: Vec<TaskHandle>) -> Self {
+        Self { handles }
+    }
+
+    /// Cancels the downloads of the bundle that have not finished.
+    ///
+    /// Downloads shared with other preloads go on for them. Resources already
+    /// pinned stay pinned, see [`CacheDownloadService::unpin_bundle`].
+    ///
+    /// [`CacheDownloadService::unpin_bundle`]:
+    /// crate::CacheDownloadService::unpin_bundle
+    pub fn cancel(&mut self) {
+        for handle in self.handles.iter_mut() {
+            if !handle.is_finish() {
+                handle.cancel();
+            }
+        }
+    }
+
+    /// Checks if every download of the bundle has finished.
+    pub fn is_finish(&self) -> bool {
+        self.handles.iter().all(TaskHandle::is_finish)
+    }
+}
+
+/// State of a resource of a bundle.
+struct Item {
+    /// URL of the resource
+    url: String,
+    /// Bytes downloaded so far
+    downloaded: u64,
+    /// Size of the resource, 0 if unknown
+    size: u64,
+    /// Whether the resource succeeded, `None` while downloading
+    result: Option<bool>,
+}
+
+/// Resources of a bundle and the callback reporting their progress.
+struct BundleState {
+    items: Vec<Item>,
+    callback: Box<dyn BundleCallback>,
+}
+
+impl BundleState {
+    fn progress(&self) -> BundleProgress {
+        let mut progress = BundleProgress {
+            total: self.items.len(),
+            ..Default::default()
+        };
+        for item in self.items.iter() {
+            match item.result {
+                Some(true) => progress.succeeded += 1,
+                Some(false) => progress.failed += 1,
+                None => {}
+            }
+            progress.downloaded += item.downloaded;
+            progress.size += item.size;
+        }
+        progress
+    }
+}
+
+/// Bundle being preloaded, shared by the callbacks of its resources.
+pub(crate) struct Bundle {
+    state: Mutex<BundleState>,
+}
+
+impl Bundle {
+    /// Creates a bundle of the resources at `urls`, reporting to `callback`.
+    ///
+    /// The callback is completed at once if there is no resource.
+    pub(crate) fn new(urls: Vec<String>, callback: Box<dyn BundleCallback>) -> Arc<Self> {
+        let items = urls
+            .into_iter()
+            .map(|url| Item {
+                url,
+                downloaded: 0,
+                size: 0,
+                result: None,
+            })
+            .collect::<Vec<_>>();
+        let mut state = BundleState { items, callback };
+        if state.items.is_empty() {
+            let progress = state.progress();
+            state.callback.on_complete(&progress, &[]);
+        }
+        Arc::new(Self {
+            state: Mutex::new(state),
+        })
+    }
+
+    /// Records the download progress of a resource.
+    pub(crate) fn update(&self, index: usize, downloaded: u64, size: u64) {
+        let mut state = self.state.lock().unwrap();
+        let item = &mut state.items[index];
+        if item.result.is_some() {
+            return;
+        }
+        item.downloaded = downloaded;
+        item.size = size;
+        let progress = state.progress();
+        state.callback.on_progress(&progress);
+    }
+
+    /// Records that a resource succeeded or failed, completing the bundle
+    /// with its last resource. Only the first result of a resource counts.
+    ///
+    /// # Arguments
+    /// * `index` - Index of the resource in the bundle
+    /// * `size` - Size of the resource, if it was downloaded
+    /// * `success` - Whether the resource is available offline
+    pub(crate) fn settle(&self, index: usize, size: Option<u64>, success: bool) {
+        let mut state = self.state.lock().unwrap();
+        let item = &mut state.items[index];
+        if item.result.is_some() {
+            return;
+        }
+        if let Some(size) = size {
+            item.downloaded = size;
+            item.size = size;
+        }
+        item.result = Some(success);
+        let progress = state.progress();
+        state.callback.on_progress(&progress);
+        if progress.is_complete() {
+            let failed = state
+                .items
+                .iter()
+                .filter(|item| item.result == Some(false))
+                .map(|item| item.url.clone())
+                .collect::<Vec<_>>();
+            state.callback.on_complete(&progress, &failed);
+        }
+    }
+}
+
+/// Preload callback of a resource of a bundle, pinning it once downloaded.
+pub(crate) struct BundleItem {
+    bundle: Arc<Bundle>,
+    index: usize,
+    task_id: TaskId,
+    cache_manager: &'static CacheManager,
+}
+
+impl BundleItem {
+    /// Creates the callback of the resource at `index` in `bundle`.
+    pub(crate) fn new(
+        bundle: Arc<Bundle>,
+        index: usize,
+        task_id: TaskId,
+        cache_manager: &'static CacheManager,
+    ) -> Self {
+        Self {
+            bundle,
+            index,
+            task_id,
+            cache_manager,
+        }
+    }
+}
+
+impl PreloadCallback for BundleItem {
+    fn on_success(&mut self, data: Arc<RamCache>, _task_id: &str) {
+        let pinned = self.cache_manager.pin(&self.task_id);
+        if !pinned {
+            error!("{} bundle resource not pinned", self.task_id.brief());
+        }
+        self.bundle
+            .settle(self.index, Some(data.size() as u64), pinned);
+    }
+
+    fn on_fail(&mut self, _error: CacheDownloadError, _info: RustDownloadInfo, _task_id: &str) {
+        self.bundle.settle(self.index, None, false);
+    }
+
+    fn on_cancel(&mut self) {
+        self.bundle.settle(self.index, None, false);
+    }
+
+    fn on_progress(&mut self, progress: u64, total: u64) {
+        self.bundle.update(self.index, progress, total);
+    }
+}
+
+#[cfg(test)]
+mod ut_bundle {
+    include!("../tests/ut/ut_bundle.rs");
+}
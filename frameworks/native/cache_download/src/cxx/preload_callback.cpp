@@ -19,6 +19,7 @@
 
 #include "cxx.h"
 #include "request_preload.h"
+#include "wrapper.rs.h"
 namespace OHOS::Request {
 
 PreloadCallbackWrapper::PreloadCallbackWrapper(std::unique_ptr<PreloadCallback> &callback)
@@ -27,6 +28,7 @@ PreloadCallbackWrapper::PreloadCallbackWrapper(std::unique_ptr<PreloadCallback>
         this->onSuccess_ = callback->OnSuccess;
         this->onCancel_ = callback->OnCancel;
         this->onFail_ = callback->OnFail;
+        this->onUpdated_ = callback->OnUpdated;
     }
 }
 
@@ -53,6 +55,13 @@ void PreloadCallbackWrapper::OnCancel() const
     }
 }
 
+void PreloadCallbackWrapper::OnUpdated(const std::shared_ptr<Data> data, rust::str taskId) const
+{
+    if (this->onUpdated_ != nullptr) {
+        this->onUpdated_(std::move(data), std::string(taskId));
+    }
+}
+
 PreloadProgressCallbackWrapper::PreloadProgressCallbackWrapper(std::unique_ptr<PreloadCallback> &callback)
 {
     if (callback != nullptr) {
@@ -67,6 +76,17 @@ void PreloadProgressCallbackWrapper::OnProgress(uint64_t current, uint64_t total
     }
 }
 
+EvictionListenerWrapper::EvictionListenerWrapper(EvictionListener listener) : onEvict_(std::move(listener))
+{
+}
+
+void EvictionListenerWrapper::OnEvict(const FfiCacheEntry &entry) const
+{
+    if (this->onEvict_ != nullptr) {
+        this->onEvict_(ToCacheEntry(entry));
+    }
+}
+
 std::shared_ptr<Data> SharedData(rust::Box<RustData> data)
 {
     return std::make_shared<Data>(std::move(data));
@@ -344,7 +344,7 @@ const std::unordered_map<SslType, std::string> SslTypeName = {
  * @param url URL to preload
  * @param callback Callback for task events
  * @param options Additional options for the request
- * @param update Whether to force update cached resource
+ * @param update Whether to refresh cached resource in the background, serving the cached copy first
  * @return Shared pointer to PreloadHandle
  */
 std::shared_ptr<PreloadHandle> Preload::load(std::string const &url, std::unique_ptr<PreloadCallback> callback,
@@ -364,7 +364,9 @@ std::shared_ptr<PreloadHandle> Preload::load(std::string const &url, std::unique
         .headers = rust::Vec<rust::str>(),
         .max_retry = FFI_MAX_RETRY_DEFAULT,
         .network_check_timeout = FFI_TIMEOUT_DEFAULT,
-        .http_total_timeout = FFI_TIMEOUT_DEFAULT
+        .http_total_timeout = FFI_TIMEOUT_DEFAULT,
+        .priority = 0,
+        .tags = rust::Vec<rust::str>()
     };
     if (options != nullptr) {
         // Validate and set headers
@@ -393,6 +395,14 @@ std::shared_ptr<PreloadHandle> Preload::load(std::string const &url, std::unique
         if (options->timeout.httpTotalTimeout != SENTINEL_NOT_SET) {
             ffiOptions.http_total_timeout = static_cast<uint32_t>(options->timeout.httpTotalTimeout);
         }
+
+        ffiOptions.priority = options->priority;
+        for (const auto &tag : options->tags) {
+            if (!Utf8Utils::RunUtf8Validation(std::vector<uint8_t>(tag.begin(), tag.end()))) {
+                return nullptr;
+            }
+            ffiOptions.tags.push_back(rust::str(tag));
+        }
     }
 
     if (!Utf8Utils::RunUtf8Validation(std::vector<uint8_t>(url.begin(), url.end()))) {
@@ -448,10 +458,18 @@ void Preload::SetFileCacheSize(uint64_t size)
 {
     agent_->set_file_cache_size(size);
 }
+void Preload::SetPinnedCacheSize(uint64_t size)
+{
+    agent_->set_pinned_cache_size(size);
+}
 void Preload::SetDownloadInfoListSize(uint16_t size)
 {
     agent_->set_info_list_size(size);
 }
+void Preload::SetMaxConcurrentDownloads(uint32_t max)
+{
+    agent_->set_max_concurrent_downloads(static_cast<size_t>(max));
+}
 
 /**
  * @brief Cancel a preload task
@@ -465,6 +483,32 @@ void Preload::Cancel(std::string const &url)
     agent_->cancel(rust::str(url));
 }
 
+/**
+ * @brief Cancel the queued and running preload tasks with a tag
+ * @param tag Tag of the tasks to cancel
+ */
+void Preload::CancelTag(std::string const &tag)
+{
+    if (!Utf8Utils::RunUtf8Validation(std::vector<uint8_t>(tag.begin(), tag.end()))) {
+        return;
+    }
+    agent_->cancel_tag(rust::str(tag));
+}
+
+/**
+ * @brief Change the priority of a queued preload task
+ * @param url URL of the task
+ * @param priority New priority of the task, higher first
+ * @return true if the task was waiting in the queue
+ */
+bool Preload::Reprioritize(std::string const &url, int32_t priority)
+{
+    if (!Utf8Utils::RunUtf8Validation(std::vector<uint8_t>(url.begin(), url.end()))) {
+        return false;
+    }
+    return agent_->reprioritize(rust::str(url), priority);
+}
+
 /**
  * @brief Remove cached data for URL
  * @param url URL to remove
@@ -490,6 +534,32 @@ void Preload::SetFileCachePath(const std::string &path)
     set_file_cache_path(rust::String(path));
 }
 
+/**
+ * @brief Pin the cache of a URL so that it is not evicted until unpinned
+ * @param url URL of the cache to pin
+ * @return true if the cache is pinned, false if it is not cached on disk or does not fit
+ */
+bool Preload::Pin(std::string const &url)
+{
+    if (!Utf8Utils::RunUtf8Validation(std::vector<uint8_t>(url.begin(), url.end()))) {
+        return false;
+    }
+    return agent_->pin(rust::str(url));
+}
+
+/**
+ * @brief Unpin the cache of a URL
+ * @param url URL of the cache to unpin
+ * @return true if the cache was pinned
+ */
+bool Preload::Unpin(std::string const &url)
+{
+    if (!Utf8Utils::RunUtf8Validation(std::vector<uint8_t>(url.begin(), url.end()))) {
+        return false;
+    }
+    return agent_->unpin(rust::str(url));
+}
+
 /**
  * @brief Check if URL is in cache
  * @param url URL to check
@@ -513,6 +583,110 @@ void Preload::ClearFileCache()
     agent_->clear_file_cache();
 }
 
+/**
+ * @brief Import an archive of prefetched responses into the file cache
+ * @param path Path of the archive
+ * @return Number of responses imported, -1 if the archive cannot be read
+ */
+int64_t Preload::ImportCache(std::string const &path)
+{
+    if (!Utf8Utils::RunUtf8Validation(std::vector<uint8_t>(path.begin(), path.end()))) {
+        return -1;
+    }
+    return agent_->ffi_import_cache(rust::str(path));
+}
+
+/**
+ * @brief Export the file cache to an archive
+ * @param path Path of the archive to write
+ * @return Number of responses exported, -1 if the archive cannot be written
+ */
+int64_t Preload::ExportCache(std::string const &path)
+{
+    if (!Utf8Utils::RunUtf8Validation(std::vector<uint8_t>(path.begin(), path.end()))) {
+        return -1;
+    }
+    return agent_->ffi_export_cache(rust::str(path));
+}
+
+static CacheCounters ToCacheCounters(const FfiCacheCounters &counters)
+{
+    return CacheCounters{
+        .ramHits = counters.ram_hits,
+        .fileHits = counters.file_hits,
+        .misses = counters.misses,
+        .ramBytes = counters.ram_bytes,
+        .fileBytes = counters.file_bytes,
+        .networkBytes = counters.network_bytes,
+        .ramEvictions = counters.ram_evictions,
+        .fileEvictions = counters.file_evictions,
+    };
+}
+
+static TierUsage ToTierUsage(const FfiTierUsage &usage)
+{
+    return TierUsage{ .used = usage.used, .capacity = usage.capacity, .entries = usage.entries };
+}
+
+CacheEntry ToCacheEntry(const FfiCacheEntry &entry)
+{
+    return CacheEntry{
+        .taskId = std::string(entry.task_id),
+        .nameSpace = std::string(entry.namespace_name),
+        .tier = static_cast<CacheTier>(entry.tier),
+        .size = entry.size,
+        .lastAccess = entry.last_access,
+    };
+}
+
+/**
+ * @brief Get a snapshot of the cache statistics
+ * @return Counters and occupancy of the cache, in total and per namespace
+ */
+CacheStats Preload::GetCacheStats()
+{
+    FfiCacheStats ffiStats = agent_->ffi_stats();
+    CacheStats stats = {
+        .counters = ToCacheCounters(ffiStats.counters),
+        .ram = ToTierUsage(ffiStats.ram),
+        .file = ToTierUsage(ffiStats.file),
+        .pinned = ToTierUsage(ffiStats.pinned),
+    };
+    for (const auto &ns : ffiStats.namespaces) {
+        stats.namespaces[std::string(ns.name)] = NamespaceStats{
+            .counters = ToCacheCounters(ns.counters),
+            .file = ToTierUsage(ns.file),
+        };
+    }
+    return stats;
+}
+
+/**
+ * @brief List the cached entries
+ * @return Entries of the memory cache, the file caches and the partial entries
+ */
+std::vector<CacheEntry> Preload::GetCacheEntries()
+{
+    std::vector<CacheEntry> entries;
+    for (const auto &entry : agent_->ffi_cache_entries()) {
+        entries.push_back(ToCacheEntry(entry));
+    }
+    return entries;
+}
+
+/**
+ * @brief Set the listener of evicted entries
+ * @param listener Listener called on a background thread, empty to remove the current one
+ */
+void Preload::SetEvictionListener(EvictionListener listener)
+{
+    if (listener == nullptr) {
+        agent_->ffi_set_eviction_listener(nullptr);
+        return;
+    }
+    agent_->ffi_set_eviction_listener(std::make_unique<EvictionListenerWrapper>(std::move(listener)));
+}
+
 void Preload::SetGlobalRetryOptions(const RetryOptions &options)
 {
     agent_->set_global_retry_options(static_cast<size_t>(options.maxRetryCount));
@@ -127,6 +127,17 @@ impl PrimeCallback {
         }
     }
 
+    /// Records the URL the resource is downloaded from, stored with its
+    /// cache entry.
+    pub(crate) fn set_url(&mut self, url: &str) {
+        self.cache_handle.set_url(url);
+    }
+
+    /// Stores the resource in a cache namespace instead of the default one.
+    pub(crate) fn set_namespace(&mut self, namespace: &str) {
+        self.cache_handle.set_namespace(namespace);
+    }
+
     /// Marks the task state as running.
     pub(crate) fn set_running(&self) {
         self.state.store(RUNNING, Ordering::Release);
@@ -214,6 +225,8 @@ impl PrimeCallback {
         E: CommonError,
     {
         info!("{} download failed {}", self.task_id.brief(), error.code());
+        // End the stream of the entry for its readers
+        self.cache_handle.cache_fail();
         // Update task state to failed
         self.state.store(FAIL, Ordering::Release);
         self.finish.store(true, Ordering::Release);
@@ -242,6 +255,8 @@ impl PrimeCallback {
     /// callbacks of the cancellation.
     pub(crate) fn common_cancel(&mut self) {
         info!("{} is cancel", self.task_id.brief());
+        // End the stream of the entry for its readers
+        self.cache_handle.cache_fail();
         // Update task state to canceled
         self.state.store(CANCEL, Ordering::Release);
         self.finish.store(true, Ordering::Release);
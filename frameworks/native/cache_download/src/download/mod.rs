@@ -14,7 +14,8 @@
 //! Cache download task orchestration and state management.
 //!
 //! Aggregates the callback, network backend (netstack or ylong), common
-//! utilities, error, and task submodules that together drive a cache download.
+//! utilities, error, queue and task submodules that together drive a cache
+//! download.
 
 pub(crate) const INIT: usize = 0;
 pub(crate) const RUNNING: usize = 1;
@@ -34,7 +35,11 @@ cfg_ylong! {
 
 pub(crate) mod common;
 mod error;
+pub(crate) mod queue;
+pub(crate) mod range;
 
 /// Re-export of the cache download error type and its error kind enum.
 pub use error::{CacheDownloadError, ErrorKind};
+/// Re-export of the handle of byte-range reads.
+pub use range::RangeHandle;
 pub(crate) mod task;
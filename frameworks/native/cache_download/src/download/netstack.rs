@@ -13,6 +13,7 @@
 
 //! Netstack client integration for cache download operations.
 
+use std::ops::Range;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
 
@@ -26,7 +27,8 @@ use request_utils::error;
 
 use super::callback::PrimeCallback;
 use super::common::{CommonError, CommonHandle, CommonResponse};
-use crate::services::DownloadRequest;
+use super::range::{body_position, range_header, RangeFill};
+use crate::services::{CacheDownloadService, DownloadRequest};
 
 impl<'a> CommonResponse for Response<'a> {
     /// Returns the HTTP response status code.
@@ -109,6 +111,43 @@ impl RequestCallback for PrimeCallback {
     }
 }
 
+impl RequestCallback for RangeFill {
+    /// Called when the Range request completes.
+    fn on_success(&mut self, response: Response) {
+        self.common_success(response.code());
+    }
+
+    /// Called when the Range request fails.
+    fn on_fail(&mut self, error: HttpClientError, _info: DownloadInfo) {
+        self.common_fail(error);
+    }
+
+    /// Called when the Range request is canceled.
+    fn on_cancel(&mut self) {
+        self.common_cancel();
+    }
+
+    /// Called when a chunk of the body is received; the position of the body
+    /// is taken from the response headers.
+    fn on_data_receive(&mut self, data: &[u8], mut task: RequestTask) {
+        let f = || {
+            let headers = task.headers();
+            body_position(
+                headers.get("content-range").map(String::as_str),
+                headers
+                    .get("content-length")
+                    .and_then(|s| s.parse::<u64>().ok()),
+            )
+        };
+        self.common_data_receive(data, f)
+    }
+
+    /// Called when the Range request is restarted after a retry.
+    fn on_restart(&mut self) {
+        self.common_restart();
+    }
+}
+
 /// Task handler for netstack-based download operations.
 pub(crate) struct DownloadTask;
 
@@ -173,7 +212,7 @@ impl DownloadTask {
     }
 
     /// Build and start the download task.
-    fn start_task(request: Request<PrimeCallback>) -> Option<Arc<dyn CommonHandle>> {
+    fn start_task<C: RequestCallback>(request: Request<C>) -> Option<Arc<dyn CommonHandle>> {
         // Get task_id before building (for logging)
         let task_id = request.task_id_ref().cloned();
         match request.build() {
@@ -195,6 +234,64 @@ impl DownloadTask {
     }
 }
 
+/// Task handler for netstack-based Range requests.
+pub(crate) struct RangeTask;
+
+impl RangeTask {
+    /// Builds and starts a Range request for a range of the resource.
+    ///
+    /// Retry and timeout options fall back to the global settings of the
+    /// service.
+    ///
+    /// # Arguments
+    /// * `input` - Download request parameters (URL, headers, SSL options).
+    /// * `range` - Bytes of the resource to request.
+    /// * `fill` - Receiver of the response.
+    /// * `info_mgr` - Shared download info manager for task state tracking.
+    ///
+    /// # Returns
+    /// A handle to the started request, or `None` if it could not be started.
+    pub(crate) fn run(
+        input: DownloadRequest,
+        range: Range<u64>,
+        fill: RangeFill,
+        info_mgr: Arc<DownloadInfoMgr>,
+    ) -> Option<Arc<dyn CommonHandle>> {
+        let service = CacheDownloadService::get_instance();
+        let task_id = fill.task_id();
+
+        let mut request: Request<RangeFill> = Request::new();
+        request.url(input.url);
+        if let Some(headers) = input.headers {
+            for (key, value) in headers {
+                request.header(key, value);
+            }
+        }
+        request.header("Range", &range_header(&range));
+        if let Some(ssl_type) = input.ssl_type {
+            request.ssl_type(ssl_type);
+        }
+        if let Some(ca_path) = input.ca_path {
+            request.ca_path(ca_path);
+        }
+        let http_total_timeout = input
+            .http_total_timeout
+            .unwrap_or(service.get_global_http_total_timeout());
+        request.timeout(http_total_timeout * 1000);
+        request.max_retry(input.max_retry.unwrap_or(service.get_global_max_retry()));
+        request.network_check_timeout(
+            input
+                .network_check_timeout
+                .unwrap_or(service.get_global_network_check_timeout()),
+        );
+
+        request.task_id(task_id);
+        request.callback(fill);
+        request.info_mgr(info_mgr);
+        DownloadTask::start_task(request)
+    }
+}
+
 /// Handle for managing and canceling netstack download tasks.
 #[derive(Clone)]
 pub struct CancelHandle {
@@ -0,0 +1,613 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Queue of the preload downloads.
+//!
+//! At most a limited number of preloads download at the same time. The others
+//! wait in a queue ordered by the priority of their request, first come first
+//! served among equal priorities, and start as running downloads finish. A
+//! waiting download can be moved in the queue when its resource becomes more
+//! or less urgent, and downloads sharing a tag can be cancelled together.
+//!
+//! Byte-range reads do not go through the queue.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use netstack_rs::info::DownloadInfoMgr;
+use request_utils::dns::DnsConfig;
+use request_utils::task_id::TaskId;
+
+use super::callback::PrimeCallback;
+use super::common::CommonHandle;
+use super::task::TaskHandle;
+use crate::key::CacheKeyPolicy;
+use crate::services::{CacheDownloadService, DownloadRequest};
+
+/// Default maximum number of preloads downloading at the same time.
+pub(crate) const DEFAULT_MAX_RUNNING: usize = 6;
+
+/// Function starting a download with one of the downloaders.
+pub(crate) type RunFn =
+    fn(DownloadRequest, PrimeCallback, Arc<DownloadInfoMgr>) -> Option<Arc<dyn CommonHandle>>;
+
+/// Identifier of a download in the queue: its task and sequence number, as a
+/// task cancelled while waiting may be preloaded again before its first
+/// download leaves the queue.
+type PendingKey = (TaskId, usize);
+
+/// Items waiting in the queue, by priority then by order of arrival.
+///
+/// Items are looked up by [`PendingKey`]; moving and joining items work by
+/// task, on every item of the task.
+struct Pending<T> {
+    /// Identifiers of the items, highest priority first
+    order: BTreeMap<(Reverse<i32>, u64), PendingKey>,
+    /// Items by identifier
+    entries: HashMap<PendingKey, PendingEntry<T>>,
+    /// Order of the next item pushed
+    next: u64,
+}
+
+/// Item waiting in the queue.
+struct PendingEntry<T> {
+    /// Priority of the item, key of `Pending::order` with `order`
+    priority: i32,
+    /// Order of arrival of the item
+    order: u64,
+    /// Tags of the item and of the requests joined into it
+    tags: Vec<String>,
+    /// The queued item
+    item: T,
+}
+
+impl<T> Pending<T> {
+    fn new() -> Self {
+        Self {
+            order: BTreeMap::new(),
+            entries: HashMap::new(),
+            next: 0,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the identifiers of the items of a task.
+    fn keys_of(&self, task_id: &TaskId) -> Vec<PendingKey> {
+        self.entries
+            .keys()
+            .filter(|(id, _)| id == task_id)
+            .cloned()
+            .collect()
+    }
+
+    /// Adds an item behind the items of the same or higher priority.
+    fn push(&mut self, key: PendingKey, priority: i32, tags: Vec<String>, item: T) {
+        let order = self.next;
+        self.next += 1;
+        if let Some(old) = self.entries.remove(&key) {
+            self.order.remove(&(Reverse(old.priority), old.order));
+        }
+        self.order.insert((Reverse(priority), order), key.clone());
+        self.entries.insert(
+            key,
+            PendingEntry {
+                priority,
+                order,
+                tags,
+                item,
+            },
+        );
+    }
+
+    /// Takes the item of highest priority, with its tags.
+    fn pop(&mut self) -> Option<(Vec<String>, T)> {
+        let (_, key) = self.order.pop_first()?;
+        let entry = self.entries.remove(&key)?;
+        Some((entry.tags, entry.item))
+    }
+
+    fn remove(&mut self, key: &PendingKey) -> Option<T> {
+        let entry = self.entries.remove(key)?;
+        self.order.remove(&(Reverse(entry.priority), entry.order));
+        Some(entry.item)
+    }
+
+    /// Changes the priority of an item.
+    fn set_priority(&mut self, key: &PendingKey, priority: i32) {
+        let Some(entry) = self.entries.get_mut(key) else {
+            return;
+        };
+        if let Some(key) = self.order.remove(&(Reverse(entry.priority), entry.order)) {
+            self.order.insert((Reverse(priority), entry.order), key);
+        }
+        entry.priority = priority;
+    }
+
+    /// Changes the priority of the items of a task, which keep their order
+    /// of arrival.
+    ///
+    /// # Returns
+    /// `false` if the task has no item in the queue
+    fn reprioritize(&mut self, task_id: &TaskId, priority: i32) -> bool {
+        let keys = self.keys_of(task_id);
+        for key in keys.iter() {
+            self.set_priority(key, priority);
+        }
+        !keys.is_empty()
+    }
+
+    /// Merges another request of a task into its items, raising their
+    /// priority to the priority of the request if higher and adding the tags
+    /// of the request.
+    ///
+    /// # Returns
+    /// `false` if the task has no item in the queue
+    fn join(&mut self, task_id: &TaskId, priority: i32, tags: &[&str]) -> bool {
+        let keys = self.keys_of(task_id);
+        for key in keys.iter() {
+            let Some(entry) = self.entries.get_mut(key) else {
+                continue;
+            };
+            merge_tags(&mut entry.tags, tags);
+            if priority > entry.priority {
+                self.set_priority(key, priority);
+            }
+        }
+        !keys.is_empty()
+    }
+
+    /// Returns the items with a tag.
+    fn tagged<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = &'a T> + 'a {
+        self.entries
+            .values()
+            .filter(move |entry| entry.tags.iter().any(|t| t == tag))
+            .map(|entry| &entry.item)
+    }
+}
+
+/// Adds to `tags` those of `others` it does not have yet, in order.
+fn merge_tags(tags: &mut Vec<String>, others: &[&str]) {
+    for tag in others {
+        if !tags.iter().any(|t| t == tag) {
+            tags.push(tag.to_string());
+        }
+    }
+}
+
+/// Download request owned by the queue until the download starts.
+///
+/// Keeps an owned copy of each option of the [`DownloadRequest`], which only
+/// borrows them, and lends them back when the download starts.
+struct QueuedRequest {
+    url: String,
+    headers: Option<Vec<(String, String)>>,
+    ssl_type: Option<String>,
+    ca_path: Option<String>,
+    max_retry: Option<usize>,
+    network_check_timeout: Option<u32>,
+    http_total_timeout: Option<u32>,
+    dns: Option<DnsConfig>,
+    cache_key: Option<CacheKeyPolicy>,
+    namespace: Option<String>,
+}
+
+impl QueuedRequest {
+    /// Copies the options of a request.
+    fn new(request: &DownloadRequest) -> Self {
+        Self {
+            url: request.url.to_string(),
+            headers: request.headers.as_ref().map(|headers| {
+                headers
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), value.to_string()))
+                    .collect()
+            }),
+            ssl_type: request.ssl_type.map(str::to_string),
+            ca_path: request.ca_path.map(str::to_string),
+            max_retry: request.max_retry,
+            network_check_timeout: request.network_check_timeout,
+            http_total_timeout: request.http_total_timeout,
+            dns: request.dns.cloned(),
+            cache_key: request.cache_key.cloned(),
+            namespace: request.namespace.map(str::to_string),
+        }
+    }
+
+    /// Borrows the request back for the downloader.
+    fn request(&self) -> DownloadRequest<'_> {
+        let mut request = DownloadRequest::new(&self.url);
+        request.headers = self.headers.as_ref().map(|headers| {
+            headers
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_str()))
+                .collect()
+        });
+        request.ssl_type = self.ssl_type.as_deref();
+        request.ca_path = self.ca_path.as_deref();
+        request.max_retry = self.max_retry;
+        request.network_check_timeout = self.network_check_timeout;
+        request.http_total_timeout = self.http_total_timeout;
+        request.dns = self.dns.as_ref();
+        request.cache_key = self.cache_key.as_ref();
+        request.namespace = self.namespace.as_deref();
+        request
+    }
+}
+
+/// Download submitted to the queue.
+pub(crate) struct QueuedTask {
+    task_id: TaskId,
+    seq: usize,
+    priority: i32,
+    tags: Vec<String>,
+    request: QueuedRequest,
+    callback: PrimeCallback,
+    run: RunFn,
+    info_mgr: Arc<DownloadInfoMgr>,
+    /// Handle of the task, failed if the download cannot be started later
+    task: TaskHandle,
+    handle: Arc<QueuedHandle>,
+}
+
+impl QueuedTask {
+    /// Creates a download to submit to the queue.
+    ///
+    /// # Arguments
+    /// * `task_id` - Identifier of the download
+    /// * `seq` - Sequence number of the download
+    /// * `request` - Request of the download, giving its priority and tags
+    /// * `callback` - Callback of the download
+    /// * `run` - Function starting the download
+    /// * `info_mgr` - Manager for download information
+    /// * `task` - Handle of the task of the download
+    /// * `handle` - Handle set as the download handle of the task
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        task_id: TaskId,
+        seq: usize,
+        request: &DownloadRequest,
+        callback: PrimeCallback,
+        run: RunFn,
+        info_mgr: Arc<DownloadInfoMgr>,
+        task: TaskHandle,
+        handle: Arc<QueuedHandle>,
+    ) -> Self {
+        Self {
+            task_id,
+            seq,
+            priority: request.priority,
+            tags: request.tags.iter().map(|tag| tag.to_string()).collect(),
+            request: QueuedRequest::new(request),
+            callback,
+            run,
+            info_mgr,
+            task,
+            handle,
+        }
+    }
+
+    /// Starts the download.
+    ///
+    /// # Returns
+    /// `false` if the downloader could not start it
+    fn start(self) -> bool {
+        let QueuedTask {
+            request,
+            callback,
+            run,
+            info_mgr,
+            handle,
+            ..
+        } = self;
+        match run(request.request(), callback, info_mgr) {
+            Some(download) => {
+                handle.start(download);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Download handle of a task going through the queue.
+///
+/// Counts the preloads sharing the task like the downloader handles do, and
+/// forwards to the handle of the download once started.
+pub(crate) struct QueuedHandle {
+    task_id: TaskId,
+    seq: usize,
+    state: Mutex<HandleState>,
+}
+
+struct HandleState {
+    /// Number of preloads sharing the task, 0 once cancelled
+    count: usize,
+    /// Handle of the download, once started
+    download: Option<Arc<dyn CommonHandle>>,
+}
+
+impl QueuedHandle {
+    pub(crate) fn new(task_id: TaskId, seq: usize) -> Self {
+        Self {
+            task_id,
+            seq,
+            state: Mutex::new(HandleState {
+                count: 1,
+                download: None,
+            }),
+        }
+    }
+
+    /// Sets the handle of the started download, cancelling the download if
+    /// the task was cancelled while it was starting.
+    fn start(&self, download: Arc<dyn CommonHandle>) {
+        let mut state = self.state.lock().unwrap();
+        if state.count == 0 {
+            download.cancel();
+        } else {
+            state.download = Some(download);
+        }
+    }
+
+    /// Cancels the task whatever the number of preloads sharing it.
+    fn force_cancel(&self) {
+        let mut state = self.state.lock().unwrap();
+        if state.count == 0 {
+            return;
+        }
+        state.count = 0;
+        self.cancel_download(&state);
+    }
+
+    fn cancel_download(&self, state: &HandleState) -> bool {
+        match state.download.as_ref() {
+            Some(download) => download.cancel(),
+            // If the download is being started, it is cancelled by `start`
+            None => {
+                PreloadQueue::get_instance().cancel_pending(&self.task_id, self.seq);
+                true
+            }
+        }
+    }
+}
+
+impl CommonHandle for QueuedHandle {
+    fn cancel(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if state.count == 0 {
+            return false;
+        }
+        state.count -= 1;
+        if state.count > 0 {
+            return false;
+        }
+        self.cancel_download(&state)
+    }
+
+    fn add_count(&self) {
+        self.state.lock().unwrap().count += 1;
+    }
+
+    #[cfg(feature = "netstack")]
+    fn reset(&self) {
+        if let Some(download) = self.state.lock().unwrap().download.as_ref() {
+            download.reset();
+        }
+    }
+}
+
+/// Running download.
+struct Running {
+    tags: Vec<String>,
+    handle: Arc<QueuedHandle>,
+}
+
+/// Queue of the preload downloads.
+pub(crate) struct PreloadQueue {
+    inner: Mutex<QueueInner>,
+}
+
+struct QueueInner {
+    /// Maximum number of running downloads
+    max_running: usize,
+    /// Running downloads, by task and sequence number
+    running: HashMap<(TaskId, usize), Running>,
+    /// Downloads waiting for a running slot
+    pending: Pending<QueuedTask>,
+}
+
+impl PreloadQueue {
+    fn new() -> Self {
+        Self {
+            inner: Mutex::new(QueueInner {
+                max_running: DEFAULT_MAX_RUNNING,
+                running: HashMap::new(),
+                pending: Pending::new(),
+            }),
+        }
+    }
+
+    /// Gets the queue of the preload downloads.
+    pub(crate) fn get_instance() -> &'static Self {
+        static PRELOAD_QUEUE: OnceLock<PreloadQueue> = OnceLock::new();
+        PRELOAD_QUEUE.get_or_init(PreloadQueue::new)
+    }
+
+    /// Starts a download, or queues it if the maximum number of downloads are
+    /// running.
+    ///
+    /// # Returns
+    /// `false` if the download was started and the downloader could not start
+    /// it, `true` otherwise
+    pub(crate) fn submit(&'static self, mut task: QueuedTask) -> bool {
+        let key = (task.task_id.clone(), task.seq);
+        {
+            let mut inner = self.inner.lock().unwrap();
+            let full = inner.running.len() >= inner.max_running;
+            if full || !inner.pending.is_empty() {
+                info!(
+                    "queue task {} priority {}, {} waiting",
+                    task.task_id.brief(),
+                    task.priority,
+                    inner.pending.len()
+                );
+                let tags = std::mem::take(&mut task.tags);
+                inner.pending.push(key, task.priority, tags, task);
+                if !full {
+                    crate::spawn(move || self.schedule());
+                }
+                return true;
+            }
+            let running = Running {
+                tags: std::mem::take(&mut task.tags),
+                handle: task.handle.clone(),
+            };
+            inner.running.insert(key.clone(), running);
+        }
+        if task.start() {
+            return true;
+        }
+        self.inner.lock().unwrap().running.remove(&key);
+        false
+    }
+
+    /// Starts the queued downloads of highest priority while fewer than the
+    /// maximum number of downloads are running.
+    fn schedule(&'static self) {
+        loop {
+            let task = {
+                let mut inner = self.inner.lock().unwrap();
+                if inner.running.len() >= inner.max_running {
+                    return;
+                }
+                let Some((tags, task)) = inner.pending.pop() else {
+                    return;
+                };
+                let running = Running {
+                    tags,
+                    handle: task.handle.clone(),
+                };
+                inner
+                    .running
+                    .insert((task.task_id.clone(), task.seq), running);
+                task
+            };
+            let task_id = task.task_id.clone();
+            let seq = task.seq;
+            let handle = task.task.clone();
+            info!("start queued task {}", task_id.brief());
+            if !task.start() {
+                error!("queued task {} start failed", task_id.brief());
+                handle.fail_to_start();
+                CacheDownloadService::get_instance().task_finish(&task_id, seq);
+            }
+        }
+    }
+
+    /// Frees the running slot of a finished download.
+    pub(crate) fn finish(&'static self, task_id: &TaskId, seq: usize) {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.running.remove(&(task_id.clone(), seq)).is_some() && !inner.pending.is_empty() {
+            crate::spawn(move || self.schedule());
+        }
+    }
+
+    /// Removes a download from the queue and notifies its cancellation.
+    fn cancel_pending(&self, task_id: &TaskId, seq: usize) {
+        let task = {
+            let mut inner = self.inner.lock().unwrap();
+            inner.pending.remove(&(task_id.clone(), seq))
+        };
+        if let Some(task) = task {
+            info!("cancel queued task {}", task_id.brief());
+            let mut callback = task.callback;
+            crate::spawn(move || callback.common_cancel());
+        }
+    }
+
+    /// Merges another preload of a download into it.
+    ///
+    /// # Arguments
+    /// * `task_id` - Identifier of the download
+    /// * `priority` - Priority of the preload, the download takes it if higher
+    /// * `tags` - Tags of the preload, added to the tags of the download
+    pub(crate) fn join(&self, task_id: &TaskId, priority: i32, tags: &[&str]) {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.pending.join(task_id, priority, tags) {
+            return;
+        }
+        for ((id, _), running) in inner.running.iter_mut() {
+            if id == task_id {
+                merge_tags(&mut running.tags, tags);
+            }
+        }
+    }
+
+    /// Changes the priority of a queued download.
+    ///
+    /// # Returns
+    /// `false` if the download is not queued
+    pub(crate) fn reprioritize(&self, task_id: &TaskId, priority: i32) -> bool {
+        self.inner
+            .lock()
+            .unwrap()
+            .pending
+            .reprioritize(task_id, priority)
+    }
+
+    /// Cancels the queued and running downloads with a tag, whatever the
+    /// number of preloads sharing them.
+    ///
+    /// # Returns
+    /// The number of downloads cancelled
+    pub(crate) fn cancel_tag(&self, tag: &str) -> usize {
+        let handles = {
+            let inner = self.inner.lock().unwrap();
+            inner
+                .pending
+                .tagged(tag)
+                .map(|task| task.handle.clone())
+                .chain(
+                    inner
+                        .running
+                        .values()
+                        .filter(|running| running.tags.iter().any(|t| t == tag))
+                        .map(|running| running.handle.clone()),
+                )
+                .collect::<Vec<_>>()
+        };
+        for handle in handles.iter() {
+            handle.force_cancel();
+        }
+        handles.len()
+    }
+
+    /// Sets the maximum number of running downloads, at least 1.
+    pub(crate) fn set_max_running(&'static self, max: usize) {
+        self.inner.lock().unwrap().max_running = max.max(1);
+        crate::spawn(move || self.schedule());
+    }
+}
+
+#[cfg(test)]
+mod ut_queue {
+    include!("../../tests/ut/download/ut_queue.rs");
+}
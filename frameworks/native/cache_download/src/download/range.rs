@@ -0,0 +1,292 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Byte-range reads filled with HTTP Range requests.
+//!
+//! A range read is served from the cache when every byte is present.
+//! Otherwise one Range request covering the missing bytes is sent; the
+//! received segments are stored in the partial entry of the resource and
+//! copied into the requested window, whose other bytes were read from the
+//! cache beforehand.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use cache_core::CacheManager;
+use netstack_rs::info::DownloadInfoMgr;
+use request_utils::task_id::TaskId;
+
+use super::common::{CommonError, CommonHandle};
+use super::CacheDownloadError;
+use crate::services::{DownloadRequest, RangeCallback};
+use crate::Downloader;
+
+cfg_ylong! {
+    use crate::download::ylong;
+}
+
+cfg_netstack! {
+    use crate::download::netstack;
+}
+
+/// Position of a response body in the resource, from its headers.
+///
+/// # Arguments
+/// * `content_range` - Value of the `Content-Range` header, if any
+/// * `content_length` - Value of the `Content-Length` header, if any
+///
+/// # Returns
+/// The offset of the first byte of the body and the total size of the
+/// resource, if known. Without `Content-Range` the body is the whole resource.
+pub(crate) fn body_position(
+    content_range: Option<&str>,
+    content_length: Option<u64>,
+) -> (u64, Option<u64>) {
+    let Some(content_range) = content_range else {
+        return (0, content_length);
+    };
+    // bytes <first>-<last>/<total or *>
+    let spec = content_range.trim().trim_start_matches("bytes").trim();
+    let (range, total) = spec.split_once('/').unwrap_or((spec, "*"));
+    let first = range
+        .split_once('-')
+        .and_then(|(first, _)| first.trim().parse().ok())
+        .unwrap_or(0);
+    (first, total.trim().parse().ok())
+}
+
+/// Formats the `Range` header value requesting a range of bytes.
+pub(crate) fn range_header(range: &Range<u64>) -> String {
+    format!("bytes={}-{}", range.start, range.end - 1)
+}
+
+/// Sends a Range request with the given backend.
+///
+/// # Arguments
+/// * `request` - Download request of the resource
+/// * `range` - Bytes of the resource to request
+/// * `fill` - Receiver of the response
+/// * `downloader` - Type of download backend to use
+/// * `info_mgr` - Manager for download information
+///
+/// # Returns
+/// A handle to the request, or `None` if it could not be started.
+pub(crate) fn run(
+    request: DownloadRequest,
+    range: Range<u64>,
+    fill: RangeFill,
+    downloader: Downloader,
+    info_mgr: Arc<DownloadInfoMgr>,
+) -> Option<Arc<dyn CommonHandle>> {
+    info!("{} range {:?}", fill.task_id.brief(), range);
+    let mut handle = None;
+    match downloader.for_request(&request) {
+        Downloader::Netstack => {
+            #[cfg(feature = "netstack")]
+            {
+                handle = netstack::RangeTask::run(request, range, fill, info_mgr);
+            }
+        }
+        Downloader::Ylong => {
+            #[cfg(feature = "ylong")]
+            {
+                handle = ylong::RangeTask::run(request, range, fill, info_mgr);
+            }
+        }
+    };
+    handle
+}
+
+/// Receiver of the response to a Range request.
+///
+/// Stores the received segments in the partial entry of the resource and
+/// copies the requested bytes into the window reported to the callback.
+pub(crate) struct RangeFill {
+    /// Unique identifier of the resource
+    task_id: TaskId,
+    /// Manager storing the received segments
+    cache_manager: &'static CacheManager,
+    /// Namespace of the resource, `None` for the default one
+    namespace: Option<String>,
+    /// Offset of the requested bytes in the resource
+    offset: u64,
+    /// Requested bytes
+    window: Vec<u8>,
+    /// Offset in the resource of the next byte received, once the response
+    /// headers are known
+    position: Option<u64>,
+    /// Total size of the resource, once known
+    total: Option<u64>,
+    /// Callback to report the result to
+    callback: Option<Box<dyn RangeCallback>>,
+}
+
+impl RangeFill {
+    /// Creates the receiver of a Range request.
+    ///
+    /// # Arguments
+    /// * `task_id` - Unique identifier of the resource
+    /// * `cache_manager` - Manager storing the received segments
+    /// * `offset` - Offset of the requested bytes in the resource
+    /// * `window` - Requested bytes, with those already cached filled in
+    /// * `callback` - Callback to report the result to
+    pub(crate) fn new(
+        task_id: TaskId,
+        cache_manager: &'static CacheManager,
+        offset: u64,
+        window: Vec<u8>,
+        callback: Box<dyn RangeCallback>,
+    ) -> Self {
+        Self {
+            task_id,
+            cache_manager,
+            namespace: None,
+            offset,
+            window,
+            position: None,
+            total: None,
+            callback: Some(callback),
+        }
+    }
+
+    /// Stores the received segments in a namespace instead of the default
+    /// one.
+    pub(crate) fn set_namespace(&mut self, namespace: &str) {
+        self.namespace = Some(namespace.to_string());
+    }
+
+    /// Returns a clone of the resource identifier.
+    pub(crate) fn task_id(&self) -> TaskId {
+        self.task_id.clone()
+    }
+
+    /// Processes a received segment of the body.
+    ///
+    /// # Arguments
+    /// * `data` - Bytes received
+    /// * `position` - Function returning the position of the body, see
+    ///   [`body_position`], called for the first segment only
+    pub(crate) fn common_data_receive<F>(&mut self, data: &[u8], position: F)
+    where
+        F: FnOnce() -> (u64, Option<u64>),
+    {
+        let start = match self.position {
+            Some(start) => start,
+            None => {
+                let (start, total) = position();
+                self.total = total;
+                start
+            }
+        };
+        self.position = Some(start + data.len() as u64);
+
+        if !self.cache_manager.write_range(
+            &self.task_id,
+            self.namespace.as_deref(),
+            start,
+            data,
+            self.total,
+        ) {
+            debug!("{} range at {} not cached", self.task_id.brief(), start);
+        }
+
+        // Copy the part of the segment inside the window
+        let window_end = self.offset + self.window.len() as u64;
+        let from = start.max(self.offset);
+        let to = (start + data.len() as u64).min(window_end);
+        if from < to {
+            self.window[(from - self.offset) as usize..(to - self.offset) as usize]
+                .copy_from_slice(&data[(from - start) as usize..(to - start) as usize]);
+        }
+    }
+
+    /// Reports the requested bytes once the response is complete.
+    ///
+    /// # Arguments
+    /// * `code` - HTTP status code of the response
+    pub(crate) fn common_success(&mut self, code: u32) {
+        info!("{} range status {}", self.task_id.brief(), code);
+        if !(200..300).contains(&code) {
+            let error = std::io::Error::other(format!("unexpected status {}", code));
+            return self.report(Err(CacheDownloadError::from(error)));
+        }
+        let mut window = std::mem::take(&mut self.window);
+        if let Some(total) = self.total {
+            window.truncate(total.saturating_sub(self.offset) as usize);
+        }
+        self.report(Ok(window));
+    }
+
+    /// Reports the failure of the Range request.
+    pub(crate) fn common_fail<E>(&mut self, error: E)
+    where
+        E: CommonError,
+    {
+        info!("{} range failed {}", self.task_id.brief(), error.code());
+        self.report(Err(CacheDownloadError::from(&error)));
+    }
+
+    /// Reports the cancellation of the Range request.
+    pub(crate) fn common_cancel(&mut self) {
+        info!("{} range is cancel", self.task_id.brief());
+        if let Some(mut callback) = self.callback.take() {
+            crate::spawn(move || callback.on_cancel());
+        }
+    }
+
+    /// Restarts the reception after a retry; the body is sent again from its
+    /// first byte.
+    #[cfg(feature = "netstack")]
+    pub(crate) fn common_restart(&mut self) {
+        self.position = None;
+    }
+
+    fn report(&mut self, result: Result<Vec<u8>, CacheDownloadError>) {
+        if let Some(mut callback) = self.callback.take() {
+            crate::spawn(move || match result {
+                Ok(data) => callback.on_success(data),
+                Err(error) => callback.on_fail(error),
+            });
+        }
+    }
+}
+
+/// Handle for canceling a range read.
+pub struct RangeHandle {
+    /// Handle of the Range request, `None` if the range was cached
+    handle: Option<Arc<dyn CommonHandle>>,
+}
+
+impl RangeHandle {
+    /// Creates a range handle.
+    pub(crate) fn new(handle: Option<Arc<dyn CommonHandle>>) -> Self {
+        Self { handle }
+    }
+
+    /// Cancels the Range request of the read, if any.
+    pub fn cancel(&self) {
+        if let Some(handle) = self.handle.as_ref() {
+            handle.cancel();
+        }
+    }
+
+    /// Returns whether the range was served from the cache without request.
+    pub fn is_cached(&self) -> bool {
+        self.handle.is_none()
+    }
+}
+
+#[cfg(test)]
+mod ut_range {
+    include!("../../tests/ut/download/ut_range.rs");
+}
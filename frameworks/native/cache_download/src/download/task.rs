@@ -18,17 +18,19 @@
 //! handling.
 
 use std::collections::VecDeque;
+use std::io;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 
 use cache_core::CacheManager;
-use netstack_rs::info::DownloadInfoMgr;
+use netstack_rs::info::{DownloadInfo, DownloadInfoMgr};
 use request_utils::info;
 use request_utils::task_id::TaskId;
 
 use super::callback::PrimeCallback;
 use super::common::CommonHandle;
-use super::{INIT, SUCCESS};
+use super::queue::{PreloadQueue, QueuedHandle, QueuedTask, RunFn};
+use super::{CacheDownloadError, FAIL, INIT, SUCCESS};
 
 cfg_ylong! {
     use crate::download::ylong;
@@ -38,12 +40,14 @@ cfg_netstack! {
     use crate::download::netstack;
 }
 
+use crate::info::RustDownloadInfo;
 use crate::services::{CacheDownloadService, DownloadRequest, PreloadCallback};
 
 /// Enum representing available download backends.
 ///
 /// Used to select between different HTTP client implementations for download
 /// operations.
+#[derive(Clone, Copy)]
 pub enum Downloader {
     /// Netstack-based HTTP client implementation.
     Netstack,
@@ -51,6 +55,23 @@ pub enum Downloader {
     Ylong,
 }
 
+impl Downloader {
+    /// Gets the downloader able to send `request`.
+    ///
+    /// Netstack resolves hosts itself, so requests with DNS options are sent
+    /// by Ylong, and fail when Ylong is not built.
+    pub(crate) fn for_request(self, request: &DownloadRequest) -> Self {
+        match self {
+            Downloader::Netstack if request.dns.is_some_and(|dns| !dns.is_default()) => {
+                #[cfg(not(feature = "ylong"))]
+                error!("dns options need the ylong downloader");
+                Downloader::Ylong
+            }
+            downloader => downloader,
+        }
+    }
+}
+
 /// Main download task structure for managing download operations.
 ///
 /// Represents a single download operation with its state and handle.
@@ -88,37 +109,43 @@ impl DownloadTask {
         seq: usize,
     ) -> Option<DownloadTask> {
         info!("new task {} seq {}", task_id.brief(), seq);
-        let mut handle = None;
+        let downloader = downloader.for_request(&request);
+        let mut run: Option<RunFn> = None;
         match downloader {
             Downloader::Netstack => {
                 #[cfg(feature = "netstack")]
                 {
-                    handle = download_inner(
-                        task_id,
-                        cache_manager,
-                        info_mgr,
-                        request,
-                        Some(callback),
-                        netstack::DownloadTask::run,
-                        seq,
-                    );
+                    run = Some(netstack::DownloadTask::run);
                 }
             }
             Downloader::Ylong => {
                 #[cfg(feature = "ylong")]
                 {
-                    handle = Some(download_inner(
-                        task_id,
-                        cache_manager,
-                        request,
-                        Some(callback),
-                        ylong::DownloadTask::run,
-                        seq,
-                    ));
+                    run = Some(ylong::DownloadTask::run);
                 }
             }
         };
-        handle.map(|handle| DownloadTask {
+        let run = run?;
+
+        // The download starts once the queue has a running slot for it
+        let (mut handle, callback) =
+            prepare_task(task_id.clone(), cache_manager, &request, callback, seq);
+        let queued = Arc::new(QueuedHandle::new(task_id.clone(), seq));
+        handle.set_handle(queued.clone());
+        let task = QueuedTask::new(
+            task_id,
+            seq,
+            &request,
+            callback,
+            run,
+            info_mgr,
+            handle.clone(),
+            queued,
+        );
+        if !PreloadQueue::get_instance().submit(task) {
+            return None;
+        }
+        Some(DownloadTask {
             remove_flag: false,
             seq,
             handle,
@@ -249,6 +276,20 @@ impl TaskHandle {
         self.finish.store(true, Ordering::Relaxed);
     }
 
+    /// Marks the task as failed because its download could not be started
+    /// after waiting in the queue, and notifies the callbacks.
+    pub(crate) fn fail_to_start(&self) {
+        self.state.store(FAIL, Ordering::Release);
+        self.finish.store(true, Ordering::Release);
+        let mut callbacks = self.callbacks.lock().unwrap();
+        while let Some(mut callback) = callbacks.pop_front() {
+            let task_id = self.task_id.brief().to_string();
+            let error = CacheDownloadError::from(io::Error::other("download could not be started"));
+            let info = RustDownloadInfo::from_download_info(DownloadInfo::new());
+            crate::spawn(move || callback.on_fail(error, info, &task_id));
+        }
+    }
+
     /// Attempts to add a callback to the task if it hasn't finished.
     ///
     /// # Arguments
@@ -311,42 +352,28 @@ impl TaskHandle {
     }
 }
 
-/// Internal function to create and start a download task using the specified
-/// downloader.
-///
-/// # Type Parameters
-/// * `F` - Type of the downloader function that performs the actual download
-///   operation.
+/// Creates the handle and the download callback of a task.
 ///
 /// # Arguments
 /// * `task_id` - Unique identifier for the download task.
 /// * `cache_manager` - Reference to the cache manager for storing downloaded
 ///   content.
-/// * `info_mgr` - Manager for download information.
 /// * `request` - Download request configuration.
-/// * `callback` - Optional callback for download events.
-/// * `downloader` - Function that performs the actual download operation.
+/// * `callback` - Callback for download events.
 /// * `seq` - Sequence number for task ordering.
 ///
 /// # Returns
-/// A new `TaskHandle` if the download operation was successfully started,
-/// otherwise `None`.
-fn download_inner<F>(
+/// The `TaskHandle` of the task, without download handle, and the callback
+/// to pass to the downloader.
+fn prepare_task(
     task_id: TaskId,
     cache_manager: &'static CacheManager,
-    info_mgr: Arc<DownloadInfoMgr>,
-    request: DownloadRequest,
-    callback: Option<Box<dyn PreloadCallback>>,
-    downloader: F,
+    request: &DownloadRequest,
+    callback: Box<dyn PreloadCallback>,
     seq: usize,
-) -> Option<TaskHandle>
-where
-    F: Fn(DownloadRequest, PrimeCallback, Arc<DownloadInfoMgr>) -> Option<Arc<dyn CommonHandle>>,
-{
-    let mut handle = TaskHandle::new(task_id.clone());
-    if let Some(callback) = callback {
-        handle.callbacks.lock().unwrap().push_back(callback);
-    }
+) -> (TaskHandle, PrimeCallback) {
+    let handle = TaskHandle::new(task_id.clone());
+    handle.callbacks.lock().unwrap().push_back(callback);
 
     // Get config from request or use global defaults
     let service = CacheDownloadService::get_instance();
@@ -358,7 +385,7 @@ where
         .http_total_timeout
         .or(Some(service.get_global_http_total_timeout()));
 
-    let callback = PrimeCallback::new(
+    let mut callback = PrimeCallback::new(
         task_id,
         cache_manager,
         handle.finish_flag(),
@@ -367,10 +394,11 @@ where
         seq,
         super::callback::TaskConfig::new(max_retry, network_check_timeout, http_total_timeout),
     );
-    downloader(request, callback, info_mgr).map(move |command| {
-        handle.set_handle(command);
-        handle
-    })
+    callback.set_url(request.url);
+    if let Some(namespace) = request.namespace {
+        callback.set_namespace(namespace);
+    }
+    (handle, callback)
 }
 
 #[cfg(test)]
@@ -19,8 +19,8 @@
 
 use std::sync::LazyLock;
 
-use ylong_http_client::async_impl::Client;
-use ylong_http_client::{Redirect, Timeout, TlsVersion};
+use ylong_http_client::async_impl::{Client, ClientBuilder, Resolver};
+use ylong_http_client::{HttpClientError, Redirect, Timeout, TlsVersion};
 
 /// Timeout for establishing a connection (in seconds).
 const CONNECT_TIMEOUT: u64 = 60;
@@ -50,19 +50,37 @@ const SECONDS_IN_ONE_WEEK: u64 = 7 * 24 * 60 * 60;
 /// or system limitations.
 pub(crate) fn client() -> &'static Client {
     // Use LazyLock to create the client only once and share it across threads
-    static CLIENT: LazyLock<Client> = LazyLock::new(|| {
-        let client = Client::builder()
-            // Set connection timeout to prevent hanging connections
-            .connect_timeout(Timeout::from_secs(CONNECT_TIMEOUT))
-            // Set very long request timeout to accommodate large file downloads
-            .request_timeout(Timeout::from_secs(SECONDS_IN_ONE_WEEK))
-            // Enforce minimum TLS version for security
-            .min_tls_version(TlsVersion::TLS_1_2)
-            // Allow unlimited redirects for maximum compatibility
-            .redirect(Redirect::limited(usize::MAX))
-            // Use system's built-in root certificates for TLS validation
-            .tls_built_in_root_certs(true);
-        client.build().unwrap()
-    });
+    static CLIENT: LazyLock<Client> = LazyLock::new(|| builder().build().unwrap());
     &CLIENT
 }
+
+/// Creates an HTTP client with the same configuration as [`client`] that
+/// resolves host names with the given resolver.
+///
+/// Used by requests with custom DNS options, which cannot share the
+/// singleton client.
+///
+/// # Arguments
+/// * `resolver` - Resolver used for every connection of the client
+///
+/// # Errors
+///
+/// Returns an error if the client cannot be built.
+pub(crate) fn client_with_resolver<R: Resolver>(resolver: R) -> Result<Client, HttpClientError> {
+    builder().dns_resolver(resolver).build()
+}
+
+/// Returns a client builder with the configuration shared by all clients.
+fn builder() -> ClientBuilder {
+    Client::builder()
+        // Set connection timeout to prevent hanging connections
+        .connect_timeout(Timeout::from_secs(CONNECT_TIMEOUT))
+        // Set very long request timeout to accommodate large file downloads
+        .request_timeout(Timeout::from_secs(SECONDS_IN_ONE_WEEK))
+        // Enforce minimum TLS version for security
+        .min_tls_version(TlsVersion::TLS_1_2)
+        // Allow unlimited redirects for maximum compatibility
+        .redirect(Redirect::limited(usize::MAX))
+        // Use system's built-in root certificates for TLS validation
+        .tls_built_in_root_certs(true)
+}
@@ -22,19 +22,25 @@
 mod client;
 
 use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::ops::Range;
 use std::pin::Pin;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::task::{Context, Poll};
 
-use client::client;
+use client::{client, client_with_resolver};
+use netstack_rs::info::{DownloadInfo, DownloadInfoMgr};
+use request_utils::dns::DnsConfig;
 use ylong_http_client::async_impl::{
-    Body, DownloadOperator, Downloader, PercentEncoder, RequestBuilder,
+    Addrs, Body, Client, DownloadOperator, Downloader, PercentEncoder, RequestBuilder, Resolver,
+    SocketFuture,
 };
 use ylong_http_client::{ErrorKind, HttpClientError, StatusCode};
 
 use super::callback::PrimeCallback;
 use super::common::{CommonHandle, CommonError, CommonResponse};
+use super::range::{body_position, range_header, RangeFill};
 use crate::services::DownloadRequest;
 
 /// Implements the `CommonError` trait for the `HttpClientError` type.
@@ -122,21 +128,26 @@ impl DownloadTask {
     /// the actual download. Returns a handle that can be used to cancel the download.
     ///
     /// # Arguments
-    /// * `request` - Download request containing URL and headers
+    /// * `request` - Download request containing URL, headers and DNS options
     /// * `callback` - Callback to receive download events
+    /// * `info_mgr` - Manager receiving the address resolved with custom DNS
+    ///   options
     ///
     /// # Returns
     /// A handle that implements `CommonHandle` for download cancellation
     pub(super) fn run(
         request: DownloadRequest,
         mut callback: PrimeCallback,
-    ) -> Arc<dyn CommonHandle> {
+        info_mgr: Arc<DownloadInfoMgr>,
+    ) -> Option<Arc<dyn CommonHandle>> {
         // Encode the URL to handle special characters
         let url = match PercentEncoder::encode(request.url) {
             Ok(url) => url,
             Err(e) => {
                 callback.common_fail(e);
-                return Arc::new(CancelHandle::new(Arc::new(AtomicBool::new(false))));
+                return Some(Arc::new(CancelHandle::new(Arc::new(AtomicBool::new(
+                    false,
+                )))));
             }
         };
         
@@ -157,9 +168,19 @@ impl DownloadTask {
             );
         }
         
+        // Custom DNS options need their own client, the shared one uses the
+        // system resolver
+        let dns = request.dns.filter(|dns| !dns.is_default()).cloned();
+
         // Spawn an asynchronous task to perform the download
         ylong_runtime::spawn(async move {
-            if let Err(e) = download(url, headers, &mut callback, flag).await {
+            let result = match dns {
+                Some(dns) => {
+                    download_with_dns(url, headers, &mut callback, flag, dns, info_mgr).await
+                }
+                None => download(url, headers, &mut callback, flag).await,
+            };
+            if let Err(e) = result {
                 // Handle errors based on their type
                 if e.error_kind() == ErrorKind::UserAborted {
                     callback.common_cancel();
@@ -169,8 +190,184 @@ impl DownloadTask {
             }
         });
         
-        handle
+        Some(handle)
+    }
+}
+
+/// Task handler for Range requests with the Ylong HTTP client.
+pub struct RangeTask;
+
+impl RangeTask {
+    /// Runs a Range request for a range of the resource.
+    ///
+    /// # Arguments
+    /// * `request` - Download request containing URL, headers and DNS options
+    /// * `range` - Bytes of the resource to request
+    /// * `fill` - Receiver of the response
+    /// * `_info_mgr` - Download info manager, unused by this backend
+    ///
+    /// # Returns
+    /// A handle that implements `CommonHandle` for request cancellation
+    pub(super) fn run(
+        request: DownloadRequest,
+        range: Range<u64>,
+        mut fill: RangeFill,
+        _info_mgr: Arc<DownloadInfoMgr>,
+    ) -> Option<Arc<dyn CommonHandle>> {
+        let url = match PercentEncoder::encode(request.url) {
+            Ok(url) => url,
+            Err(e) => {
+                fill.common_fail(e);
+                return Some(Arc::new(CancelHandle::new(Arc::new(AtomicBool::new(
+                    false,
+                )))));
+            }
+        };
+        let flag = Arc::new(AtomicBool::new(false));
+        let handle = Arc::new(CancelHandle::new(flag.clone()));
+        let mut headers: Vec<(String, String)> = request
+            .headers
+            .unwrap_or_default()
+            .iter()
+            .map(|a| (a.0.to_string(), a.1.to_string()))
+            .collect();
+        headers.push(("Range".to_string(), range_header(&range)));
+        let dns = request.dns.filter(|dns| !dns.is_default()).cloned();
+
+        ylong_runtime::spawn(async move {
+            let result = match dns {
+                Some(dns) => match client_with_resolver(CacheResolver::new(dns)) {
+                    Ok(client) => download_range(&client, url, headers, &mut fill, flag).await,
+                    Err(e) => Err(e),
+                },
+                None => download_range(client(), url, headers, &mut fill, flag).await,
+            };
+            match result {
+                Ok(code) => fill.common_success(code),
+                Err(e) if e.error_kind() == ErrorKind::UserAborted => fill.common_cancel(),
+                Err(e) => fill.common_fail(e),
+            }
+        });
+        Some(handle)
+    }
+}
+
+/// Sends a Range request and passes the body to the receiver.
+///
+/// # Returns
+/// The status code of the response if the body was received, otherwise an
+/// error
+async fn download_range(
+    client: &Client,
+    url: String,
+    headers: Vec<(String, String)>,
+    fill: &mut RangeFill,
+    abort_flag: Arc<AtomicBool>,
+) -> Result<u32, HttpClientError> {
+    let mut request = RequestBuilder::new().url(url.as_str()).method("GET");
+    for (k, v) in headers {
+        request = request.append_header(k.as_str(), v.as_str());
     }
+    let request = request.body(Body::empty())?;
+    let response = client.request(request).await?;
+    let code = response.status().as_u16() as u32;
+
+    let header = |name: &str| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_string().ok())
+    };
+    let position = body_position(
+        header("content-range").as_deref(),
+        header("content-length").and_then(|len| len.parse().ok()),
+    );
+    let operator = RangeOperator {
+        fill,
+        position,
+        abort_flag,
+    };
+    let mut downloader = Downloader::builder()
+        .body(response)
+        .operator(operator)
+        .build();
+    downloader.download().await?;
+    Ok(code)
+}
+
+/// Download operator passing the body of a Range request to its receiver.
+struct RangeOperator<'a> {
+    /// Receiver of the body
+    fill: &'a mut RangeFill,
+    /// Position of the body in the resource
+    position: (u64, Option<u64>),
+    /// Flag used to signal cancellation
+    abort_flag: Arc<AtomicBool>,
+}
+
+impl<'a> DownloadOperator for RangeOperator<'a> {
+    fn poll_download(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        data: &[u8],
+    ) -> Poll<Result<usize, HttpClientError>> {
+        let me = self.get_mut();
+        let position = me.position;
+        me.fill.common_data_receive(data, || position);
+        Poll::Ready(Ok(data.len()))
+    }
+
+    fn poll_progress(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        _downloaded: u64,
+        _total: Option<u64>,
+    ) -> Poll<Result<(), HttpClientError>> {
+        if self.abort_flag.load(Ordering::Acquire) {
+            Poll::Ready(Err(HttpClientError::user_aborted()))
+        } else {
+            Poll::Ready(Ok(()))
+        }
+    }
+}
+
+/// Performs an asynchronous HTTP download resolving host names with custom
+/// DNS options.
+///
+/// The first resolved address, which is tried first, is recorded as the
+/// server address in the download information of the task.
+///
+/// # Arguments
+/// * `url` - URL to download from
+/// * `headers` - Optional HTTP headers to include in the request
+/// * `callback` - Callback to receive download events
+/// * `abort_flag` - Flag to signal download cancellation
+/// * `dns` - DNS options of the request
+/// * `info_mgr` - Manager receiving the download information
+///
+/// # Returns
+/// `Ok(())` if the download completed successfully, otherwise an error
+async fn download_with_dns(
+    url: String,
+    headers: Option<Vec<(String, String)>>,
+    callback: &mut PrimeCallback,
+    abort_flag: Arc<AtomicBool>,
+    dns: DnsConfig,
+    info_mgr: Arc<DownloadInfoMgr>,
+) -> Result<(), HttpClientError> {
+    let task_id = callback.task_id();
+    let resolver = CacheResolver::new(dns);
+    let resolved = resolver.resolved.clone();
+    let client = client_with_resolver(resolver)?;
+    let result = send(&client, url, headers, callback, abort_flag).await;
+
+    let addrs = resolved.lock().unwrap();
+    if let Some(addr) = addrs.first() {
+        let mut info = DownloadInfo::new();
+        info.set_ip_address(addr.ip().to_string());
+        info_mgr.insert_download_info(task_id, info);
+    }
+    result
 }
 
 /// Performs an asynchronous HTTP download operation.
@@ -192,6 +389,18 @@ pub async fn download(
     headers: Option<Vec<(String, String)>>,
     callback: &mut PrimeCallback,
     abort_flag: Arc<AtomicBool>,
+) -> Result<(), HttpClientError> {
+    send(client(), url, headers, callback, abort_flag).await
+}
+
+/// Sends the download request with the given client and streams the response
+/// through the callback.
+async fn send(
+    client: &Client,
+    url: String,
+    headers: Option<Vec<(String, String)>>,
+    callback: &mut PrimeCallback,
+    abort_flag: Arc<AtomicBool>,
 ) -> Result<(), HttpClientError> {
     // Create a GET request for the specified URL
     let mut request = RequestBuilder::new().url(url.as_str()).method("GET");
@@ -207,7 +416,7 @@ pub async fn download(
     let request = request.body(Body::empty())?;
 
     // Send the request using the configured client
-    let response = client().request(request).await?;
+    let response = client.request(request).await?;
     let status = response.status();
 
     // Create download operator with the callback and headers
@@ -234,6 +443,56 @@ pub async fn download(
     Ok(())
 }
 
+/// Resolver applying the DNS options of a download request.
+///
+/// Resolution blocks on network queries, so it runs on the blocking pool of
+/// the runtime. The addresses of the last resolution are kept in the order
+/// they are tried, for the download information.
+struct CacheResolver {
+    /// DNS options of the request
+    config: Arc<DnsConfig>,
+    /// Addresses returned by the last resolution
+    resolved: Arc<Mutex<Vec<SocketAddr>>>,
+}
+
+impl CacheResolver {
+    /// Creates a resolver with the DNS options of a request.
+    fn new(config: DnsConfig) -> Self {
+        Self {
+            config: Arc::new(config),
+            resolved: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+impl Resolver for CacheResolver {
+    fn resolve(&self, authority: &str) -> SocketFuture {
+        let config = self.config.clone();
+        let resolved = self.resolved.clone();
+        let authority = authority.to_string();
+        Box::pin(async move {
+            let addrs = ylong_runtime::spawn_blocking(move || config.resolve_authority(&authority))
+                .await
+                .map_err(|e| std::io::Error::other(e.to_string()))??;
+            *resolved.lock().unwrap() = addrs.clone();
+            Ok(Box::new(ResolvedAddrs(addrs.into_iter())) as Box<dyn Addrs>)
+        })
+    }
+}
+
+/// Addresses returned by a `CacheResolver`, in the order to try them.
+struct ResolvedAddrs(std::vec::IntoIter<SocketAddr>);
+
+impl Iterator for ResolvedAddrs {
+    type Item = SocketAddr;
+
+    fn next(&mut self) -> Option<SocketAddr> {
+        self.0.next()
+    }
+}
+
+impl Addrs for ResolvedAddrs {}
+
 /// HTTP response wrapper containing status code information.
 ///
 /// Provides a simplified view of the HTTP response for the download system.
@@ -0,0 +1,197 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cache keys of download requests.
+//!
+//! By default a resource is cached under the hash of its full URL. A
+//! [`CacheKeyPolicy`] derives the key differently, so that URLs differing only
+//! by a rotating signature share their entry, or so that responses varying
+//! with a request header get entries of their own. The namespace of the
+//! request is part of the key, separating the entries of different consumers.
+
+use request_utils::task_id::TaskId;
+
+/// Policy deriving the cache key of a request.
+///
+/// # Examples
+///
+/// ```rust
+/// use preload_native_rlib::{CacheKeyPolicy, DownloadRequest};
+///
+/// let policy = CacheKeyPolicy {
+///     ignored_params: vec!["token".to_string(), "expires".to_string()],
+///     normalize: true,
+///     ..Default::default()
+/// };
+/// let mut first = DownloadRequest::new("https://cdn.example.com/a.mp4?token=1");
+/// first.cache_key(&policy);
+/// let mut second = DownloadRequest::new("https://CDN.example.com:443/a.mp4?token=2");
+/// second.cache_key(&policy);
+/// assert!(first.task_id() == second.task_id());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CacheKeyPolicy {
+    /// Explicit key used instead of the URL
+    pub key: Option<String>,
+    /// Query parameters left out of the key, such as signatures and tokens
+    pub ignored_params: Vec<String>,
+    /// Whether the URL is normalized: lowercase scheme and host, default port,
+    /// empty path and fragment removed, query parameters sorted
+    pub normalize: bool,
+    /// Request headers whose values are part of the key, like the fields of a
+    /// `Vary` response header
+    pub vary: Vec<String>,
+}
+
+/// Derives the cache key of a request.
+///
+/// Without policy nor namespace the key is the hash of the URL, the key of
+/// the entries cached by the URL-based methods of the service.
+///
+/// # Arguments
+/// * `url` - URL of the request
+/// * `headers` - Header fields of the request
+/// * `policy` - Policy deriving the key, if any
+/// * `namespace` - Namespace of the request, if any
+pub(crate) fn cache_key(
+    url: &str,
+    headers: &[(&str, &str)],
+    policy: Option<&CacheKeyPolicy>,
+    namespace: Option<&str>,
+) -> TaskId {
+    let mut key = match policy {
+        Some(policy) => match policy.key.as_ref() {
+            Some(key) => key.clone(),
+            None => {
+                let url = strip_params(url, &policy.ignored_params);
+                if policy.normalize {
+                    normalize(&url)
+                } else {
+                    url
+                }
+            }
+        },
+        None => url.to_string(),
+    };
+    // Lines cannot appear in URLs, so they separate the parts of the key
+    if let Some(policy) = policy {
+        for name in policy.vary.iter() {
+            let value = headers
+                .iter()
+                .find(|(field, _)| field.eq_ignore_ascii_case(name))
+                .map_or("", |(_, value)| value.trim());
+            key.push_str(&format!("\n{}: {}", name.to_ascii_lowercase(), value));
+        }
+    }
+    if let Some(namespace) = namespace {
+        key = format!("{}\n{}", namespace, key);
+    }
+    TaskId::from_url(&key)
+}
+
+/// Removes query parameters from a URL.
+///
+/// The URL is returned unchanged if it has none of the parameters.
+fn strip_params(url: &str, ignored: &[String]) -> String {
+    let (rest, fragment) = split_fragment(url);
+    let Some((base, query)) = rest.split_once('?') else {
+        return url.to_string();
+    };
+    let params = query
+        .split('&')
+        .filter(|param| {
+            let name = param.split_once('=').map_or(*param, |(name, _)| name);
+            !ignored.iter().any(|ignored| ignored == name)
+        })
+        .collect::<Vec<_>>();
+    if params.len() == query.split('&').count() {
+        return url.to_string();
+    }
+    let mut stripped = base.to_string();
+    if !params.is_empty() {
+        stripped.push('?');
+        stripped.push_str(&params.join("&"));
+    }
+    if let Some(fragment) = fragment {
+        stripped.push('#');
+        stripped.push_str(fragment);
+    }
+    stripped
+}
+
+/// Normalizes a URL so that equivalent spellings give the same key.
+///
+/// URLs without scheme are only stripped of their fragment and get their
+/// query parameters sorted.
+fn normalize(url: &str) -> String {
+    let (rest, _) = split_fragment(url);
+    let (rest, query) = match rest.split_once('?') {
+        Some((rest, query)) => (rest, Some(query)),
+        None => (rest, None),
+    };
+    let mut normalized = match rest.split_once("://") {
+        Some((scheme, rest)) => {
+            let scheme = scheme.to_ascii_lowercase();
+            let (authority, path) = match rest.find('/') {
+                Some(i) => rest.split_at(i),
+                None => (rest, "/"),
+            };
+            // Keep the case of the user information, if any
+            let (userinfo, host) = match authority.rsplit_once('@') {
+                Some((userinfo, host)) => (Some(userinfo), host),
+                None => (None, authority),
+            };
+            let mut host = host.to_ascii_lowercase();
+            let default_port = match scheme.as_str() {
+                "http" => Some(":80"),
+                "https" => Some(":443"),
+                _ => None,
+            };
+            if let Some(port) = default_port {
+                if host.ends_with(port) {
+                    host.truncate(host.len() - port.len());
+                }
+            }
+            match userinfo {
+                Some(userinfo) => format!("{}://{}@{}{}", scheme, userinfo, host, path),
+                None => format!("{}://{}{}", scheme, host, path),
+            }
+        }
+        None => rest.to_string(),
+    };
+    if let Some(query) = query {
+        let mut params = query
+            .split('&')
+            .filter(|param| !param.is_empty())
+            .collect::<Vec<_>>();
+        params.sort_unstable();
+        if !params.is_empty() {
+            normalized.push('?');
+            normalized.push_str(&params.join("&"));
+        }
+    }
+    normalized
+}
+
+/// Splits the fragment off a URL.
+fn split_fragment(url: &str) -> (&str, Option<&str>) {
+    match url.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (url, None),
+    }
+}
+
+#[cfg(test)]
+mod ut_key {
+    include!("../tests/ut/ut_key.rs");
+}
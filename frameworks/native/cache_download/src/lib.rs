@@ -36,8 +36,14 @@ extern crate request_utils;
 #[macro_use]
 mod macros;
 
+// Offline bundles of pinned resources
+mod bundle;
 // Core download functionality module
 mod download;
+mod key;
+mod proxy;
+// Stale-while-revalidate preloads of cached resources
+mod revalidate;
 
 // Public modules exposing API interfaces
 pub mod info; // Download information and metrics
@@ -45,14 +51,27 @@ pub mod observe; // Observation and monitoring functionality
 pub mod services; // Service interfaces and types
 
 // Re-export downloader enum for public API use
-/// Re-export of the in-memory RAM cache type from `cache_core`.
-pub use cache_core::RamCache;
+/// Re-export of the offline bundle types.
+pub use bundle::{BundleCallback, BundleHandle, BundleProgress};
+/// Re-export of the in-memory RAM cache, streaming reader, cache statistics and
+/// cache archive types from `cache_core`.
+pub use cache_core::{
+    ArchiveEntry, ArchiveReader, ArchiveWriter, CacheCounters, CacheEntry, CacheReader, CacheStats,
+    CacheTier, EvictionListener, NamespaceStats, RamCache, TierUsage, ARCHIVE_VERSION,
+};
 /// Re-export of the downloader implementation.
 pub use download::task::Downloader;
-/// Re-export of the cache download error type and its error kind enum.
-pub use download::{CacheDownloadError, ErrorKind};
-/// Re-export of the cache download service, request, and preload callback types.
-pub use services::{CacheDownloadService, DownloadRequest, PreloadCallback};
+/// Re-export of the cache download error type, its error kind enum and the
+/// handle of byte-range reads.
+pub use download::{CacheDownloadError, ErrorKind, RangeHandle};
+/// Re-export of the cache key policy of download requests.
+pub use key::CacheKeyPolicy;
+/// Re-export of the loopback HTTP proxy serving cached resources.
+pub use proxy::CacheProxy;
+/// Re-export of the DNS options of download requests.
+pub use request_utils::dns::{DnsConfig, DnsServer, HostOverride, IpPreference};
+/// Re-export of the cache download service, request, and callback types.
+pub use services::{CacheDownloadService, DownloadRequest, PreloadCallback, RangeCallback};
 
 // Conditional compilation for OpenHarmony platform
 cfg_ohos! {
@@ -0,0 +1,325 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Minimal HTTP/1.1 support for the loopback proxy.
+//!
+//! Only what players and web views need is implemented: `GET` and `HEAD`
+//! requests, single byte ranges, and responses closing the connection once
+//! their body is sent.
+
+use std::io::{self, BufRead, Read, Seek, SeekFrom, Write};
+use std::ops::Range;
+
+/// Maximum size of the head of a request.
+const MAX_HEAD_SIZE: usize = 8192;
+
+/// Request received by the proxy.
+#[derive(Debug)]
+pub(crate) struct HttpRequest {
+    /// Request method, such as `GET`
+    pub(crate) method: String,
+    /// Request target, the path and query of the URL
+    pub(crate) target: String,
+    /// Header fields, with lowercase names
+    headers: Vec<(String, String)>,
+}
+
+impl HttpRequest {
+    /// Reads the head of a request.
+    ///
+    /// # Errors
+    /// Returns `InvalidData` if the head is malformed or too large, and
+    /// `UnexpectedEof` if the connection closes before its end.
+    pub(crate) fn read_from<R: BufRead>(reader: &mut R) -> io::Result<Self> {
+        let mut lines = Vec::new();
+        let mut size = 0;
+        loop {
+            let mut line = String::new();
+            let limit = (MAX_HEAD_SIZE - size) as u64;
+            let n = reader.by_ref().take(limit).read_line(&mut line)?;
+            if n == 0 {
+                return Err(match size {
+                    MAX_HEAD_SIZE => invalid("request head too large"),
+                    _ => io::Error::from(io::ErrorKind::UnexpectedEof),
+                });
+            }
+            size += n;
+            let line = line.trim_end_matches(['\r', '\n']);
+            if line.is_empty() {
+                break;
+            }
+            lines.push(line.to_string());
+        }
+        Self::parse(&lines).ok_or_else(|| invalid("malformed request head"))
+    }
+
+    /// Parses the lines of the head of a request, without line terminators.
+    fn parse(lines: &[String]) -> Option<Self> {
+        let (request_line, fields) = lines.split_first()?;
+        let mut parts = request_line.split_whitespace();
+        let method = parts.next()?.to_string();
+        let target = parts.next()?.to_string();
+        if !parts.next()?.starts_with("HTTP/1.") || parts.next().is_some() {
+            return None;
+        }
+        let headers = fields
+            .iter()
+            .map(|field| {
+                let (name, value) = field.split_once(':')?;
+                Some((name.trim().to_ascii_lowercase(), value.trim().to_string()))
+            })
+            .collect::<Option<_>>()?;
+        Some(Self {
+            method,
+            target,
+            headers,
+        })
+    }
+
+    /// Gets the value of a header field, by case-insensitive name.
+    pub(crate) fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Gets the path of the target without its leading slash and query.
+    pub(crate) fn path(&self) -> &str {
+        let path = self.target.split(['?', '#']).next().unwrap_or_default();
+        path.trim_start_matches('/')
+    }
+}
+
+/// Byte range requested with a `Range` header.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ByteRange {
+    /// Bytes from the given offset to the end
+    From(u64),
+    /// Bytes between the given offsets, inclusive
+    Between(u64, u64),
+    /// Last bytes, in the given number
+    Suffix(u64),
+}
+
+impl ByteRange {
+    /// Parses the value of a `Range` header.
+    ///
+    /// # Returns
+    /// `None` for malformed values and sets of several ranges, which are
+    /// ignored by serving the whole resource.
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        let spec = value.trim().strip_prefix("bytes=")?;
+        if spec.contains(',') {
+            return None;
+        }
+        let (first, last) = spec.split_once('-')?;
+        match (first.trim(), last.trim()) {
+            ("", "") => None,
+            ("", len) => len.parse().ok().map(Self::Suffix),
+            (first, "") => first.parse().ok().map(Self::From),
+            (first, last) => {
+                let (first, last) = (first.parse().ok()?, last.parse().ok()?);
+                (first <= last).then_some(Self::Between(first, last))
+            }
+        }
+    }
+
+    /// Resolves the range against the size of the resource.
+    ///
+    /// # Returns
+    /// The bytes to serve, `None` if the range is not satisfiable.
+    pub(crate) fn resolve(&self, total: u64) -> Option<Range<u64>> {
+        match *self {
+            Self::From(first) => (first < total).then_some(first..total),
+            Self::Between(first, last) => {
+                (first < total).then(|| first..last.saturating_add(1).min(total))
+            }
+            Self::Suffix(len) => (len > 0 && total > 0).then(|| total - len.min(total)..total),
+        }
+    }
+}
+
+/// Status line and header fields of a response.
+pub(crate) struct ResponseHead {
+    /// Status code
+    status: u16,
+    /// Header fields
+    headers: Vec<(&'static str, String)>,
+}
+
+impl ResponseHead {
+    /// Creates the head of a response without body.
+    pub(crate) fn empty(status: u16) -> Self {
+        Self {
+            status,
+            headers: vec![("Content-Length", "0".to_string())],
+        }
+    }
+
+    /// Creates the head of a response with the whole resource.
+    pub(crate) fn full(total: u64) -> Self {
+        Self {
+            status: 200,
+            headers: vec![
+                ("Accept-Ranges", "bytes".to_string()),
+                ("Content-Length", total.to_string()),
+            ],
+        }
+    }
+
+    /// Creates the head of a response with a range of the resource.
+    pub(crate) fn partial(range: &Range<u64>, total: u64) -> Self {
+        Self {
+            status: 206,
+            headers: vec![
+                ("Accept-Ranges", "bytes".to_string()),
+                ("Content-Length", (range.end - range.start).to_string()),
+                (
+                    "Content-Range",
+                    format!("bytes {}-{}/{}", range.start, range.end - 1, total),
+                ),
+            ],
+        }
+    }
+
+    /// Creates the head of a response to a range that is not satisfiable.
+    pub(crate) fn unsatisfiable(total: u64) -> Self {
+        let mut head = Self::empty(416);
+        head.headers
+            .push(("Content-Range", format!("bytes */{}", total)));
+        head
+    }
+
+    /// Creates the head of a response with the whole resource, whose size is
+    /// not known yet.
+    pub(crate) fn chunked() -> Self {
+        Self {
+            status: 200,
+            headers: vec![("Transfer-Encoding", "chunked".to_string())],
+        }
+    }
+
+    /// Writes the head, announcing that the connection closes after the body.
+    pub(crate) fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write!(
+            writer,
+            "HTTP/1.1 {} {}\r\n",
+            self.status,
+            reason(self.status)
+        )?;
+        for (name, value) in self.headers.iter() {
+            write!(writer, "{}: {}\r\n", name, value)?;
+        }
+        writer.write_all(b"Connection: close\r\n\r\n")
+    }
+}
+
+/// Writer of a body with the chunked transfer coding.
+pub(crate) struct ChunkedWriter<W: Write> {
+    inner: W,
+}
+
+impl<W: Write> ChunkedWriter<W> {
+    /// Creates a writer of chunks into `inner`.
+    pub(crate) fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Writes the last chunk, ending the body.
+    ///
+    /// A body that is not finished lets the client detect that it is
+    /// truncated.
+    pub(crate) fn finish(mut self) -> io::Result<()> {
+        self.inner.write_all(b"0\r\n\r\n")?;
+        self.inner.flush()
+    }
+}
+
+impl<W: Write> Write for ChunkedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        write!(self.inner, "{:x}\r\n", buf.len())?;
+        self.inner.write_all(buf)?;
+        self.inner.write_all(b"\r\n")?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Serves bytes of a resource whose content is known, reading only the
+/// requested range.
+///
+/// # Arguments
+/// * `writer` - Connection to the client
+/// * `reader` - Reader of the whole resource
+/// * `total` - Size of the resource
+/// * `range` - Value of the `Range` header of the request, if any
+/// * `head` - Whether the request is a `HEAD` request, answered without body
+pub(crate) fn serve_bytes<W: Write, R: Read + Seek>(
+    writer: &mut W,
+    mut reader: R,
+    total: u64,
+    range: Option<&str>,
+    head: bool,
+) -> io::Result<()> {
+    let (response, bytes) = match range.and_then(ByteRange::parse) {
+        Some(range) => match range.resolve(total) {
+            Some(bytes) => (ResponseHead::partial(&bytes, total), bytes),
+            None => return ResponseHead::unsatisfiable(total).write_to(writer),
+        },
+        None => (ResponseHead::full(total), 0..total),
+    };
+    response.write_to(writer)?;
+    if head {
+        return Ok(());
+    }
+    reader.seek(SeekFrom::Start(bytes.start))?;
+    let len = bytes.end - bytes.start;
+    if io::copy(&mut reader.take(len), writer)? < len {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "resource shorter than announced",
+        ));
+    }
+    Ok(())
+}
+
+/// Gets the reason phrase of the status codes used by the proxy.
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        206 => "Partial Content",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        416 => "Range Not Satisfiable",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        _ => "",
+    }
+}
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod ut_http {
+    include!("../../tests/ut/proxy/ut_http.rs");
+}
@@ -0,0 +1,503 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Loopback HTTP proxy serving cached resources.
+//!
+//! Players and web views consume URLs rather than caches. The proxy maps
+//! `http://127.0.0.1:<port>/<token>` to a resource of the cache: cached bytes
+//! are served with their size and `Range` support, bytes still downloading are
+//! streamed as they are received, and misses are preloaded from the origin,
+//! which stores them in the cache while they are served. A range starting past
+//! the bytes received so far is fetched from the origin with Range requests
+//! instead of waiting for the download to get there.
+//!
+//! Tokens are random, so that other apps cannot guess the URLs of resources.
+//! Each connection is served by its own thread and closed after one response;
+//! at most `MAX_CONNECTIONS` are served at the same time.
+
+mod http;
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::ops::Range;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use cache_core::CacheReader;
+use request_utils::task_id::TaskId;
+
+use self::http::{serve_bytes, ByteRange, ChunkedWriter, HttpRequest, ResponseHead};
+use crate::download::CacheDownloadError;
+use crate::services::{CacheDownloadService, DownloadRequest, PreloadCallback, RangeCallback};
+use crate::Downloader;
+
+/// Time to wait for the head of a request.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Time to wait for a client to accept more bytes of a response.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Size of the buffer used to stream bytes to the clients.
+const BUFFER_SIZE: usize = 64 * 1024;
+
+/// Maximum number of connections served at the same time.
+const MAX_CONNECTIONS: usize = 32;
+
+/// Size of each Range request sent for a range past the downloaded bytes.
+const RANGE_WINDOW: usize = 1024 * 1024;
+
+/// Number of random bytes of a token.
+const TOKEN_SIZE: usize = 16;
+
+/// Running proxy server.
+struct Server {
+    /// Address the server listens on
+    addr: SocketAddr,
+    /// Flag telling the accept loop to stop
+    stop: Arc<AtomicBool>,
+    /// Thread of the accept loop
+    handle: JoinHandle<()>,
+}
+
+/// Resources served by the proxy.
+#[derive(Default)]
+struct Resources {
+    /// Origin URLs of the resources, by token
+    urls: HashMap<String, String>,
+    /// Tokens of the resources, by origin URL
+    tokens: HashMap<String, String>,
+}
+
+/// Loopback HTTP proxy serving cached resources.
+///
+/// Implements the singleton pattern like [`CacheDownloadService`], whose
+/// cache it serves.
+///
+/// # Examples
+///
+/// ```rust
+/// use preload_native_rlib::{CacheProxy, Downloader};
+///
+/// let proxy = CacheProxy::get_instance();
+/// let port = proxy.start(0, Downloader::Netstack).unwrap();
+/// let url = proxy.proxy_url("https://example.com/video.mp4").unwrap();
+/// assert!(url.starts_with(&format!("http://127.0.0.1:{}/", port)));
+/// // Hand `url` to the player, then stop the proxy when done.
+/// proxy.stop();
+/// ```
+pub struct CacheProxy {
+    /// Running server, if started
+    server: Mutex<Option<Server>>,
+    /// Resources served, by token and origin URL
+    resources: Mutex<Resources>,
+    /// Number of connections being served
+    connections: AtomicUsize,
+}
+
+/// Connection counted in [`CacheProxy::connections`] while it is served.
+struct ConnectionSlot(&'static CacheProxy);
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.connections.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+impl CacheProxy {
+    /// Creates a stopped proxy without resources.
+    fn new() -> Self {
+        Self {
+            server: Mutex::new(None),
+            resources: Mutex::new(Resources::default()),
+            connections: AtomicUsize::new(0),
+        }
+    }
+
+    /// Gets the singleton instance of the proxy.
+    pub fn get_instance() -> &'static Self {
+        static PROXY: OnceLock<CacheProxy> = OnceLock::new();
+        PROXY.get_or_init(CacheProxy::new)
+    }
+
+    /// Starts serving on the loopback interface.
+    ///
+    /// # Arguments
+    /// * `port` - Port to listen on, 0 to let the system choose one
+    /// * `downloader` - Type of downloader used to fetch misses from origin
+    ///
+    /// # Returns
+    /// The port the proxy listens on, the current one if it already runs.
+    ///
+    /// # Errors
+    /// Returns `io::Error` if the port cannot be bound.
+    pub fn start(&'static self, port: u16, downloader: Downloader) -> io::Result<u16> {
+        let mut server = self.server.lock().unwrap();
+        if let Some(server) = server.as_ref() {
+            return Ok(server.addr.port());
+        }
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+        let addr = listener.local_addr()?;
+        let stop = Arc::new(AtomicBool::new(false));
+        let flag = stop.clone();
+        let handle = thread::Builder::new()
+            .name("cache_proxy".to_string())
+            .spawn(move || self.accept_loop(listener, flag, downloader))?;
+        info!("cache proxy listens on {}", addr);
+        *server = Some(Server { addr, stop, handle });
+        Ok(addr.port())
+    }
+
+    /// Stops accepting connections.
+    ///
+    /// Responses being sent are completed. The minted URLs remain valid once
+    /// the proxy is started again on the same port.
+    pub fn stop(&self) {
+        let Some(server) = self.server.lock().unwrap().take() else {
+            return;
+        };
+        server.stop.store(true, Ordering::Release);
+        // Wake the accept loop up with a connection of our own
+        if let Err(e) = TcpStream::connect(server.addr) {
+            error!("cache proxy wake up failed: {}", e);
+        }
+        if server.handle.join().is_err() {
+            error!("cache proxy accept loop panicked");
+        }
+        info!("cache proxy stopped");
+    }
+
+    /// Returns the port the proxy listens on, if started.
+    pub fn port(&self) -> Option<u16> {
+        self.server
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|server| server.addr.port())
+    }
+
+    /// Mints the proxy URL of a resource, the same one for the same
+    /// resource until it is removed.
+    ///
+    /// # Arguments
+    /// * `url` - Origin URL of the resource
+    ///
+    /// # Returns
+    /// The URL serving the resource through the proxy, `None` if the proxy is
+    /// not started or no token could be drawn
+    pub fn proxy_url(&self, url: &str) -> Option<String> {
+        let port = self.port()?;
+        let mut resources = self.resources.lock().unwrap();
+        let token = match resources.tokens.get(url) {
+            Some(token) => token.clone(),
+            None => {
+                let token = random_token()
+                    .inspect_err(|e| error!("cache proxy token failed: {}", e))
+                    .ok()?;
+                resources.tokens.insert(url.to_string(), token.clone());
+                resources.urls.insert(token.clone(), url.to_string());
+                token
+            }
+        };
+        Some(format!("http://{}:{}/{}", Ipv4Addr::LOCALHOST, port, token))
+    }
+
+    /// Forgets the proxy URL of a resource; requests to it are answered with
+    /// `404 Not Found`.
+    ///
+    /// # Arguments
+    /// * `url` - Origin URL of the resource
+    pub fn remove_url(&self, url: &str) {
+        let mut resources = self.resources.lock().unwrap();
+        if let Some(token) = resources.tokens.remove(url) {
+            resources.urls.remove(&token);
+        }
+    }
+
+    /// Accepts connections until stopped.
+    fn accept_loop(
+        &'static self,
+        listener: TcpListener,
+        stop: Arc<AtomicBool>,
+        downloader: Downloader,
+    ) {
+        for stream in listener.incoming() {
+            if stop.load(Ordering::Acquire) {
+                break;
+            }
+            let stream = match stream {
+                Ok(stream) => stream,
+                Err(e) => {
+                    error!("cache proxy accept failed: {}", e);
+                    continue;
+                }
+            };
+            if self.connections.fetch_add(1, Ordering::AcqRel) >= MAX_CONNECTIONS {
+                self.connections.fetch_sub(1, Ordering::AcqRel);
+                error!("cache proxy refuses a connection, too many connections");
+                let _ = ResponseHead::empty(503).write_to(&mut &stream);
+                continue;
+            }
+            let slot = ConnectionSlot(self);
+            let spawned = thread::Builder::new()
+                .name("cache_proxy_conn".to_string())
+                .spawn(move || {
+                    self.serve_connection(stream, downloader);
+                    drop(slot);
+                });
+            if let Err(e) = spawned {
+                error!("cache proxy connection thread failed: {}", e);
+            }
+        }
+    }
+
+    /// Answers the request of a connection.
+    fn serve_connection(&self, stream: TcpStream, downloader: Downloader) {
+        if let Err(e) = stream.set_read_timeout(Some(READ_TIMEOUT)) {
+            error!("cache proxy set timeout failed: {}", e);
+        }
+        if let Err(e) = stream.set_write_timeout(Some(WRITE_TIMEOUT)) {
+            error!("cache proxy set write timeout failed: {}", e);
+        }
+        let mut writer = BufWriter::new(&stream);
+        let result = match HttpRequest::read_from(&mut BufReader::new(&stream)) {
+            Ok(request) => self.respond(&request, &mut writer, downloader),
+            Err(e) => {
+                info!("cache proxy bad request: {}", e);
+                ResponseHead::empty(400).write_to(&mut writer)
+            }
+        };
+        if let Err(e) = result.and_then(|_| writer.flush()) {
+            // Clients close connections as soon as they have enough bytes
+            info!("cache proxy response interrupted: {}", e);
+        }
+    }
+
+    /// Writes the response to a request.
+    fn respond<W: Write>(
+        &self,
+        request: &HttpRequest,
+        writer: &mut W,
+        downloader: Downloader,
+    ) -> io::Result<()> {
+        let head = match request.method.as_str() {
+            "GET" => false,
+            "HEAD" => true,
+            _ => return ResponseHead::empty(405).write_to(writer),
+        };
+        let url = self
+            .resources
+            .lock()
+            .unwrap()
+            .urls
+            .get(request.path())
+            .cloned();
+        let Some(url) = url else {
+            return ResponseHead::empty(404).write_to(writer);
+        };
+        let task_id = TaskId::from_url(&url);
+        let range = request.header("range");
+        info!(
+            "cache proxy {} {} {:?}",
+            request.method,
+            task_id.brief(),
+            range
+        );
+
+        let service = CacheDownloadService::get_instance();
+        let reader = service.stream(&url).or_else(|| {
+            // Fetch the miss from origin, the download stores it in the cache
+            let request = DownloadRequest::new(&url);
+            service.preload(request, Box::new(ProxyCallback), false, downloader)?;
+            service.stream(&url)
+        });
+        match reader {
+            // Hits are read from the cache window by window
+            Some(reader) if reader.is_finished() => {
+                let total = reader.total_size().unwrap_or(0) as u64;
+                let reader = BufReader::with_capacity(BUFFER_SIZE, reader);
+                serve_bytes(writer, reader, total, range, head)
+            }
+            Some(reader) => serve_stream(writer, reader, range, head, &url, downloader),
+            None => ResponseHead::empty(502).write_to(writer),
+        }
+    }
+}
+
+/// Draws a token from the system CSPRNG, as hexadecimal digits.
+fn random_token() -> io::Result<String> {
+    let mut bytes = [0u8; TOKEN_SIZE];
+    File::open("/dev/urandom")?.read_exact(&mut bytes)?;
+    Ok(bytes.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+/// Serves a resource that may still be downloading.
+///
+/// The response starts once the origin answered, so that the size it
+/// announced can be used. Without a size, the whole resource is sent with the
+/// chunked transfer coding, ignoring the requested range. A range starting
+/// past the bytes received so far is fetched from `url` with Range requests.
+fn serve_stream<W: Write>(
+    writer: &mut W,
+    mut reader: CacheReader,
+    range: Option<&str>,
+    head: bool,
+    url: &str,
+    downloader: Downloader,
+) -> io::Result<()> {
+    let mut first = vec![0; BUFFER_SIZE];
+    let n = match reader.read(&mut first) {
+        Ok(n) => n,
+        Err(e) => {
+            info!("cache proxy origin failed: {}", e);
+            return ResponseHead::empty(502).write_to(writer);
+        }
+    };
+    first.truncate(n);
+
+    let Some(total) = reader.total_size().map(|size| size as u64) else {
+        ResponseHead::chunked().write_to(writer)?;
+        if head {
+            return Ok(());
+        }
+        let mut chunked = ChunkedWriter::new(writer);
+        chunked.write_all(&first)?;
+        io::copy(&mut reader, &mut chunked)?;
+        return chunked.finish();
+    };
+    let bytes = match range.and_then(ByteRange::parse) {
+        Some(range) => match range.resolve(total) {
+            Some(bytes) => {
+                ResponseHead::partial(&bytes, total).write_to(writer)?;
+                bytes
+            }
+            None => return ResponseHead::unsatisfiable(total).write_to(writer),
+        },
+        None => {
+            ResponseHead::full(total).write_to(writer)?;
+            0..total
+        }
+    };
+    if head {
+        return Ok(());
+    }
+    if bytes.start > reader.available() as u64 {
+        return copy_origin_range(writer, url, bytes, downloader);
+    }
+    copy_range(writer, &first, &mut reader, bytes)
+}
+
+/// Copies a range of a resource to the client.
+///
+/// # Arguments
+/// * `writer` - Connection to the client
+/// * `first` - First bytes of the resource, already read
+/// * `reader` - Reader positioned after the first bytes
+/// * `range` - Bytes to copy
+fn copy_range<W: Write>(
+    writer: &mut W,
+    first: &[u8],
+    reader: &mut CacheReader,
+    range: Range<u64>,
+) -> io::Result<()> {
+    let read = first.len() as u64;
+    if range.start < read {
+        writer.write_all(&first[range.start as usize..range.end.min(read) as usize])?;
+    }
+    let from = range.start.max(read);
+    if from >= range.end {
+        return Ok(());
+    }
+    // Skip the bytes before the range, waiting for them if needed
+    if reader.available() as u64 >= from {
+        reader.seek(SeekFrom::Start(from))?;
+    } else {
+        io::copy(&mut reader.by_ref().take(from - read), &mut io::sink())?;
+    }
+    let len = range.end - from;
+    if io::copy(&mut reader.by_ref().take(len), writer)? < len {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "resource shorter than announced",
+        ));
+    }
+    Ok(())
+}
+
+/// Copies a range of a resource fetched from its origin to the client, one
+/// `RANGE_WINDOW` at a time.
+fn copy_origin_range<W: Write>(
+    writer: &mut W,
+    url: &str,
+    range: Range<u64>,
+    downloader: Downloader,
+) -> io::Result<()> {
+    let service = CacheDownloadService::get_instance();
+    let mut from = range.start;
+    while from < range.end {
+        let len = (range.end - from).min(RANGE_WINDOW as u64) as usize;
+        let (tx, rx) = mpsc::channel();
+        let request = DownloadRequest::new(url);
+        let callback = Box::new(ProxyRangeCallback(tx));
+        service
+            .fetch_range(request, from, len, callback, downloader)
+            .ok_or_else(|| io::Error::other("range request not started"))?;
+        let data = rx
+            .recv()
+            .map_err(|_| io::Error::other("range request ended"))??;
+        if data.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "resource shorter than announced",
+            ));
+        }
+        writer.write_all(&data)?;
+        from += data.len() as u64;
+    }
+    Ok(())
+}
+
+/// Callback of the preloads started by the proxy; the proxy follows them
+/// through the stream of the resource.
+struct ProxyCallback;
+
+impl PreloadCallback for ProxyCallback {}
+
+/// Callback of the Range requests sent by the proxy, passing their outcome
+/// to the connection waiting for it.
+struct ProxyRangeCallback(Sender<io::Result<Vec<u8>>>);
+
+impl RangeCallback for ProxyRangeCallback {
+    fn on_success(&mut self, data: Vec<u8>) {
+        let _ = self.0.send(Ok(data));
+    }
+
+    fn on_fail(&mut self, error: CacheDownloadError) {
+        let message = format!("range request failed: {}", error.message());
+        let _ = self.0.send(Err(io::Error::other(message)));
+    }
+
+    fn on_cancel(&mut self) {
+        let error = io::Error::other("range request cancelled");
+        let _ = self.0.send(Err(error));
+    }
+}
+
+#[cfg(test)]
+mod ut_proxy {
+    include!("../../tests/ut/proxy/ut_proxy.rs");
+}
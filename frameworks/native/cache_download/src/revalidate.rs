@@ -0,0 +1,101 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Stale-while-revalidate preloads.
+//!
+//! A preload with update of a resource already cached gets the cached copy at
+//! once, while the resource is downloaded again in the background. The
+//! download is shared with the other preloads of the resource like any other;
+//! once it succeeds, the callback is told if the content changed. A failed
+//! download keeps the cached copy, while a cancelled one is reported. See
+//! [`CacheDownloadService::preload`].
+//!
+//! [`CacheDownloadService::preload`]: crate::CacheDownloadService::preload
+
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+
+use cache_core::RamCache;
+use request_utils::task_id::TaskId;
+
+use crate::download::CacheDownloadError;
+use crate::info::RustDownloadInfo;
+use crate::services::PreloadCallback;
+
+/// Callback of the download revalidating a cached copy, taking over the
+/// callback of the preload once given the cached copy.
+pub(crate) struct Revalidation {
+    /// Cached copy given to the callback
+    stale: Arc<RamCache>,
+    /// Receives the callback once it has been given the cached copy
+    callback: Receiver<Box<dyn PreloadCallback>>,
+}
+
+impl Revalidation {
+    /// Gives the cached copy `stale` to `callback` on a separate thread, and
+    /// returns the callback of the download revalidating it.
+    pub(crate) fn start(
+        stale: Arc<RamCache>,
+        mut callback: Box<dyn PreloadCallback>,
+        task_id: &TaskId,
+    ) -> Self {
+        let (tx, rx) = mpsc::channel();
+        let data = stale.clone();
+        let task_id = task_id.clone();
+        crate::spawn(move || {
+            callback.on_success(data, task_id.brief());
+            let _ = tx.send(callback);
+        });
+        Self {
+            stale,
+            callback: rx,
+        }
+    }
+}
+
+impl PreloadCallback for Revalidation {
+    fn on_success(&mut self, data: Arc<RamCache>, task_id: &str) {
+        if Arc::ptr_eq(&data, &self.stale)
+            || data.cursor().get_ref() == self.stale.cursor().get_ref()
+        {
+            info!("{} not modified", task_id);
+            return;
+        }
+        info!("{} updated", task_id);
+        // Waits for the cached copy to be given first
+        if let Ok(mut callback) = self.callback.recv() {
+            callback.on_updated(data, task_id);
+        }
+    }
+
+    fn on_fail(&mut self, error: CacheDownloadError, _info: RustDownloadInfo, task_id: &str) {
+        info!(
+            "{} revalidation failed, cached copy kept: {}",
+            task_id,
+            error.message()
+        );
+    }
+
+    fn on_cancel(&mut self) {
+        info!("revalidation cancelled");
+        // The preload was cancelled by the caller, who expects to be told
+        if let Ok(mut callback) = self.callback.recv() {
+            callback.on_cancel();
+        }
+    }
+}
+
+#[cfg(test)]
+mod ut_revalidate {
+    include!("../tests/ut/ut_revalidate.rs");
+}
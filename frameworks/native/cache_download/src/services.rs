@@ -20,20 +20,32 @@
 // Standard library imports for thread safety and collections
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex, Once, OnceLock};
 
 // External dependencies
-use cache_core::{CacheManager, RamCache};
+use cache_core::{CacheEntry, CacheManager, CacheReader, CacheStats, EvictionListener, RamCache};
 use netstack_rs::info::{DownloadInfo, DownloadInfoMgr};
+use request_utils::dns::DnsConfig;
 use request_utils::observe::network::NetRegistrar;
 use request_utils::task_id::TaskId;
 
 // Internal dependencies
+use crate::bundle::{Bundle, BundleCallback, BundleHandle, BundleItem};
+use crate::download::queue::PreloadQueue;
+use crate::download::range::{self, RangeFill};
 use crate::download::task::{DownloadTask, Downloader, TaskHandle};
-use crate::download::CacheDownloadError;
+use crate::download::{CacheDownloadError, RangeHandle};
 use crate::info::RustDownloadInfo;
+use crate::key::{self, CacheKeyPolicy};
 use crate::observe::NetObserver;
+use crate::revalidate::Revalidation;
+
+/// Largest number of bytes read by a single
+/// [`CacheDownloadService::fetch_range`] call, whose bytes are buffered.
+pub const MAX_RANGE_LEN: usize = 16 * 1024 * 1024;
 
 /// Trait defining callback methods for preload operations.
 ///
@@ -65,6 +77,38 @@ pub trait PreloadCallback: Send {
     /// * `progress` - Number of bytes downloaded so far
     /// * `total` - Total number of bytes to download
     fn on_progress(&mut self, progress: u64, total: u64) {}
+
+    /// Called after [`PreloadCallback::on_success`] when a preload with
+    /// update returned the cached copy and the resource downloaded again
+    /// differs from it.
+    ///
+    /// # Arguments
+    /// * `data` - The refreshed content in RAM cache
+    /// * `task_id` - Brief identifier for the task
+    fn on_updated(&mut self, data: Arc<RamCache>, task_id: &str) {}
+}
+
+/// Trait defining callback methods for byte-range reads.
+///
+/// Implementations of this trait receive the result of a
+/// [`CacheDownloadService::fetch_range`] call.
+#[allow(unused_variables)]
+pub trait RangeCallback: Send {
+    /// Called with the requested bytes.
+    ///
+    /// # Arguments
+    /// * `data` - The requested bytes, fewer than requested if the resource
+    ///   ends within the range
+    fn on_success(&mut self, data: Vec<u8>) {}
+
+    /// Called when the bytes could not be downloaded.
+    ///
+    /// # Arguments
+    /// * `error` - The error that caused the failure
+    fn on_fail(&mut self, error: CacheDownloadError) {}
+
+    /// Called when the read is cancelled.
+    fn on_cancel(&mut self) {}
 }
 
 /// Main service for managing cache downloads.
@@ -108,6 +152,16 @@ pub struct DownloadRequest<'a> {
     pub network_check_timeout: Option<u32>,
     /// Optional HTTP total timeout in seconds (overrides global setting)
     pub http_total_timeout: Option<u32>,
+    /// Optional DNS options, applied by the Ylong downloader
+    pub dns: Option<&'a DnsConfig>,
+    /// Optional policy deriving the cache key from the URL and headers
+    pub cache_key: Option<&'a CacheKeyPolicy>,
+    /// Optional cache namespace, the default namespace if `None`
+    pub namespace: Option<&'a str>,
+    /// Priority of the download in the preload queue, higher first (default: 0)
+    pub priority: i32,
+    /// Tags of the download, for cancelling downloads by group
+    pub tags: Vec<&'a str>,
 }
 
 impl<'a> DownloadRequest<'a> {
@@ -130,6 +184,11 @@ impl<'a> DownloadRequest<'a> {
             max_retry: None,
             network_check_timeout: None,
             http_total_timeout: None,
+            dns: None,
+            cache_key: None,
+            namespace: None,
+            priority: 0,
+            tags: Vec::new(),
         }
     }
 
@@ -219,6 +278,113 @@ impl<'a> DownloadRequest<'a> {
         self.http_total_timeout = Some(timeout);
         self
     }
+
+    /// Sets the DNS options for the download request.
+    ///
+    /// Static host overrides, the DNS server and the IP preference apply to
+    /// this request only. The resolved addresses are reported as the server
+    /// address of the download info. Only the Ylong downloader supports DNS
+    /// options, so a request with options other than the defaults is always
+    /// downloaded by it, and is refused when it is not built.
+    ///
+    /// # Arguments
+    /// * `dns` - DNS options of the request
+    ///
+    /// # Returns
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use request_utils::dns::{DnsConfig, HostOverride};
+    ///
+    /// let dns = DnsConfig {
+    ///     overrides: vec![HostOverride::parse("example.com:443:203.0.113.7").unwrap()],
+    ///     ..Default::default()
+    /// };
+    /// let mut request = DownloadRequest::new("https://example.com/file.txt");
+    /// request.dns(&dns);
+    /// ```
+    pub fn dns(&mut self, dns: &'a DnsConfig) -> &mut Self {
+        self.dns = Some(dns);
+        self
+    }
+
+    /// Sets the policy deriving the cache key of the request.
+    ///
+    /// Requests with the same key share their cache entry and their download,
+    /// whatever their URL.
+    ///
+    /// # Arguments
+    /// * `policy` - Cache key policy of the request
+    ///
+    /// # Returns
+    /// A mutable reference to self for method chaining
+    pub fn cache_key(&mut self, policy: &'a CacheKeyPolicy) -> &mut Self {
+        self.cache_key = Some(policy);
+        self
+    }
+
+    /// Sets the cache namespace of the request.
+    ///
+    /// Entries of a namespace are kept apart from the entries of other
+    /// namespaces, and only count against the file cache budget of their
+    /// namespace, see [`CacheDownloadService::set_namespace_cache_size`].
+    ///
+    /// # Arguments
+    /// * `namespace` - Name of the namespace
+    ///
+    /// # Returns
+    /// A mutable reference to self for method chaining
+    pub fn namespace(&mut self, namespace: &'a str) -> &mut Self {
+        self.namespace = Some(namespace);
+        self
+    }
+
+    /// Sets the priority of the request in the preload queue.
+    ///
+    /// When the maximum number of downloads are running, the preloads wait in
+    /// a queue and those of highest priority start first, in order of arrival
+    /// among equal priorities. Preloads joining a download already queued
+    /// raise its priority to theirs if higher.
+    ///
+    /// # Arguments
+    /// * `priority` - Priority of the request, 0 by default
+    ///
+    /// # Returns
+    /// A mutable reference to self for method chaining
+    pub fn priority(&mut self, priority: i32) -> &mut Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Adds a tag to the request.
+    ///
+    /// The downloads of all the requests with a tag can be cancelled
+    /// together, see [`CacheDownloadService::cancel_tag`].
+    ///
+    /// # Arguments
+    /// * `tag` - Tag of the request, such as the page showing the resource
+    ///
+    /// # Returns
+    /// A mutable reference to self for method chaining
+    pub fn tag(&mut self, tag: &'a str) -> &mut Self {
+        self.tags.push(tag);
+        self
+    }
+
+    /// Gets the cache key of the request.
+    ///
+    /// # Returns
+    /// The identifier of the cache entry and download of the request
+    pub fn task_id(&self) -> TaskId {
+        key::cache_key(
+            self.url,
+            self.headers.as_deref().unwrap_or_default(),
+            self.cache_key,
+            self.namespace,
+        )
+    }
 }
 
 impl CacheDownloadService {
@@ -283,12 +449,89 @@ impl CacheDownloadService {
     /// # Arguments
     /// * `url` - URL of the download task to cancel
     pub fn cancel(&self, url: &str) {
-        let task_id = TaskId::from_url(url);
-        if let Some(updater) = self.running_tasks.lock().unwrap().get(&task_id).cloned() {
+        self.cancel_task(&TaskId::from_url(url));
+    }
+
+    /// Cancels the download task of a request, identified by its cache key.
+    ///
+    /// # Arguments
+    /// * `request` - Request of the download task to cancel
+    pub fn cancel_request(&self, request: &DownloadRequest) {
+        self.cancel_task(&request.task_id());
+    }
+
+    fn cancel_task(&self, task_id: &TaskId) {
+        if let Some(updater) = self.running_tasks.lock().unwrap().get(task_id).cloned() {
             updater.lock().unwrap().cancel();
         }
     }
 
+    /// Cancels the queued and running downloads of the requests with a tag.
+    ///
+    /// Unlike [`CacheDownloadService::cancel`], the downloads are cancelled
+    /// even if preloads without the tag share them.
+    ///
+    /// # Arguments
+    /// * `tag` - Tag of the requests, see [`DownloadRequest::tag`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use preload_native_rlib::{CacheDownloadService, DownloadRequest};
+    ///
+    /// let service = CacheDownloadService::get_instance();
+    /// let mut request = DownloadRequest::new("https://example.com/feed/1.jpg");
+    /// request.tag("feed-page-1");
+    /// // ...
+    /// // The user left the page
+    /// service.cancel_tag("feed-page-1");
+    /// ```
+    pub fn cancel_tag(&self, tag: &str) {
+        let count = PreloadQueue::get_instance().cancel_tag(tag);
+        info!("cancel {} tasks tagged {}", count, tag);
+    }
+
+    /// Changes the priority of a queued download identified by URL.
+    ///
+    /// # Arguments
+    /// * `url` - URL of the download
+    /// * `priority` - New priority of the download, see
+    ///   [`DownloadRequest::priority`]
+    ///
+    /// # Returns
+    /// `true` if the download was waiting in the queue, `false` if it is
+    /// already running or does not exist
+    pub fn reprioritize(&self, url: &str, priority: i32) -> bool {
+        PreloadQueue::get_instance().reprioritize(&TaskId::from_url(url), priority)
+    }
+
+    /// Changes the priority of the queued download of a request, identified
+    /// by its cache key.
+    ///
+    /// See [`CacheDownloadService::reprioritize`].
+    ///
+    /// # Arguments
+    /// * `request` - Request of the download
+    /// * `priority` - New priority of the download
+    ///
+    /// # Returns
+    /// `true` if the download was waiting in the queue, `false` otherwise
+    pub fn reprioritize_request(&self, request: &DownloadRequest, priority: i32) -> bool {
+        PreloadQueue::get_instance().reprioritize(&request.task_id(), priority)
+    }
+
+    /// Sets the maximum number of preloads downloading at the same time.
+    ///
+    /// Other preloads wait in a queue, see [`DownloadRequest::priority`].
+    /// Lowering the maximum does not interrupt running downloads.
+    ///
+    /// # Arguments
+    /// * `max` - Maximum number of running downloads, at least 1 (default: 6)
+    pub fn set_max_concurrent_downloads(&self, max: usize) {
+        info!("set max concurrent downloads to {}", max);
+        PreloadQueue::get_instance().set_max_running(max);
+    }
+
     /// Resets all currently running download tasks.
     ///
     /// Called when network connectivity is restored to resume paused downloads.
@@ -308,6 +551,66 @@ impl CacheDownloadService {
         self.cache_manager.remove(task_id);
     }
 
+    /// Removes the cached item of a request, identified by its cache key.
+    ///
+    /// # Arguments
+    /// * `request` - Request of the cached item to remove
+    pub fn remove_request(&self, request: &DownloadRequest) {
+        self.cache_manager.remove(request.task_id());
+    }
+
+    /// Pins the cached item of a URL so that it is not evicted until
+    /// unpinned.
+    ///
+    /// Pinned items count against their own budget, see
+    /// [`CacheDownloadService::set_pinned_cache_size`].
+    ///
+    /// # Arguments
+    /// * `url` - URL of the cached item to pin
+    ///
+    /// # Returns
+    /// `true` if the item is pinned, `false` if it is not cached on disk or
+    /// does not fit in the budget of the pinned items
+    pub fn pin(&self, url: &str) -> bool {
+        self.cache_manager.pin(&TaskId::from_url(url))
+    }
+
+    /// Pins the cached item of a request, identified by its cache key.
+    ///
+    /// See [`CacheDownloadService::pin`].
+    ///
+    /// # Arguments
+    /// * `request` - Request of the cached item to pin
+    ///
+    /// # Returns
+    /// `true` if the item is pinned, `false` otherwise
+    pub fn pin_request(&self, request: &DownloadRequest) -> bool {
+        self.cache_manager.pin(&request.task_id())
+    }
+
+    /// Unpins the cached item of a URL, which becomes subject to eviction
+    /// again.
+    ///
+    /// # Arguments
+    /// * `url` - URL of the cached item to unpin
+    ///
+    /// # Returns
+    /// `true` if the item was pinned, `false` otherwise
+    pub fn unpin(&self, url: &str) -> bool {
+        self.cache_manager.unpin(&TaskId::from_url(url))
+    }
+
+    /// Unpins the cached item of a request, identified by its cache key.
+    ///
+    /// # Arguments
+    /// * `request` - Request of the cached item to unpin
+    ///
+    /// # Returns
+    /// `true` if the item was pinned, `false` otherwise
+    pub fn unpin_request(&self, request: &DownloadRequest) -> bool {
+        self.cache_manager.unpin(&request.task_id())
+    }
+
     /// Checks if a URL is already cached.
     ///
     /// # Arguments
@@ -320,11 +623,33 @@ impl CacheDownloadService {
         self.cache_manager.contains(&task_id)
     }
 
+    /// Checks if the content of a request is already cached, identified by
+    /// its cache key.
+    ///
+    /// # Arguments
+    /// * `request` - Request to check in the cache
+    ///
+    /// # Returns
+    /// `true` if the content is in the cache, `false` otherwise
+    pub fn contains_request(&self, request: &DownloadRequest) -> bool {
+        self.cache_manager.contains(&request.task_id())
+    }
+
     /// Preloads content from a URL into the cache.
     ///
     /// Initiates a download operation for the specified URL, optionally
     /// updating existing cached content, and using the provided callback
-    /// for progress notifications.
+    /// for progress notifications. The download waits in the preload queue
+    /// while the maximum number of downloads are running, see
+    /// [`CacheDownloadService::set_max_concurrent_downloads`]. Preloads of a
+    /// resource being downloaded share its download.
+    ///
+    /// With update, a resource already cached is served stale while
+    /// revalidated: the callback gets the cached copy at once, the resource is
+    /// downloaded again in the background, and the callback is notified with
+    /// [`PreloadCallback::on_updated`] if the content changed. The cached copy
+    /// is kept if the download fails, which is not reported to the callback;
+    /// cancelling the download is reported with [`PreloadCallback::on_cancel`].
     ///
     /// # Arguments
     /// * `request` - Download request with URL and optional configuration
@@ -334,7 +659,7 @@ impl CacheDownloadService {
     ///
     /// # Returns
     /// An optional task handle for controlling the download if it was
-    /// successfully started
+    /// successfully started, the background download when revalidating
     pub fn preload(
         &'static self,
         request: DownloadRequest,
@@ -342,8 +667,7 @@ impl CacheDownloadService {
         update: bool,
         downloader: Downloader,
     ) -> Option<TaskHandle> {
-        let url = request.url;
-        let task_id = TaskId::from_url(url);
+        let task_id = request.task_id();
         info!("preload {}", task_id.brief());
 
         // Try to fetch from cache first if not updating
@@ -354,6 +678,9 @@ impl CacheDownloadService {
                     callback = cb;
                 }
             }
+        } else if let Some(stale) = self.fetch_cached(&task_id) {
+            info!("{} revalidate cached copy", task_id.brief());
+            callback = Box::new(Revalidation::start(stale, callback, &task_id));
         }
 
         // Main loop to manage task creation and callback handling
@@ -385,7 +712,10 @@ impl CacheDownloadService {
 
             let mut updater = updater.lock().unwrap();
             match updater.try_add_callback(callback) {
-                Ok(()) => return Some(updater.task_handle()),
+                Ok(()) => {
+                    PreloadQueue::get_instance().join(&task_id, request.priority, &request.tags);
+                    return Some(updater.task_handle());
+                }
                 Err(mut cb) => {
                     if update {
                         info!("add callback failed, update task {}", task_id.brief());
@@ -426,6 +756,97 @@ impl CacheDownloadService {
         }
     }
 
+    /// Preloads the resources of a manifest as an offline bundle.
+    ///
+    /// Each resource is preloaded like with [`CacheDownloadService::preload`]
+    /// without update, and pinned once downloaded, so that the bundle stays
+    /// available offline until unpinned or removed. The callback receives the
+    /// progress of the bundle as a whole; resources that fail or do not fit
+    /// in the budget of the pinned items are reported as failed.
+    ///
+    /// # Arguments
+    /// * `manifest` - Requests of the resources of the bundle
+    /// * `callback` - Callback to receive the progress of the bundle
+    /// * `downloader` - Type of downloader to use for the operations
+    ///
+    /// # Returns
+    /// A handle for controlling the downloads of the bundle
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use preload_native_rlib::{
+    ///     BundleCallback, BundleProgress, CacheDownloadService, DownloadRequest, Downloader,
+    /// };
+    ///
+    /// struct Offline;
+    ///
+    /// impl BundleCallback for Offline {
+    ///     fn on_complete(&mut self, progress: &BundleProgress, _failed: &[String]) {
+    ///         println!("{}/{} available offline", progress.succeeded, progress.total);
+    ///     }
+    /// }
+    ///
+    /// let service = CacheDownloadService::get_instance();
+    /// let urls = ["https://example.com/article/1.html", "https://example.com/article/1.jpg"];
+    /// let manifest = urls.iter().map(|url| DownloadRequest::new(url)).collect();
+    /// let handle = service.preload_bundle(manifest, Box::new(Offline), Downloader::Netstack);
+    /// // ...
+    /// // The user no longer keeps the article
+    /// let manifest = urls.iter().map(|url| DownloadRequest::new(url)).collect::<Vec<_>>();
+    /// service.remove_bundle(&manifest);
+    /// ```
+    pub fn preload_bundle(
+        &'static self,
+        manifest: Vec<DownloadRequest>,
+        callback: Box<dyn BundleCallback>,
+        downloader: Downloader,
+    ) -> BundleHandle {
+        info!("preload bundle of {} resources", manifest.len());
+        let urls = manifest
+            .iter()
+            .map(|request| request.url.to_string())
+            .collect::<Vec<_>>();
+        let bundle = Bundle::new(urls, callback);
+        let mut handles = Vec::with_capacity(manifest.len());
+        for (index, request) in manifest.into_iter().enumerate() {
+            let item = BundleItem::new(
+                bundle.clone(),
+                index,
+                request.task_id(),
+                &self.cache_manager,
+            );
+            match self.preload(request, Box::new(item), false, downloader) {
+                Some(handle) => handles.push(handle),
+                None => bundle.settle(index, None, false),
+            }
+        }
+        BundleHandle::new(handles)
+    }
+
+    /// Unpins the resources of a bundle, which become subject to eviction
+    /// again.
+    ///
+    /// # Arguments
+    /// * `manifest` - Requests of the resources of the bundle
+    pub fn unpin_bundle(&self, manifest: &[DownloadRequest]) {
+        info!("unpin bundle of {} resources", manifest.len());
+        for request in manifest {
+            self.cache_manager.unpin(&request.task_id());
+        }
+    }
+
+    /// Removes the cached resources of a bundle.
+    ///
+    /// # Arguments
+    /// * `manifest` - Requests of the resources of the bundle
+    pub fn remove_bundle(&self, manifest: &[DownloadRequest]) {
+        info!("remove bundle of {} resources", manifest.len());
+        for request in manifest {
+            self.cache_manager.remove(request.task_id());
+        }
+    }
+
     /// Fetches cached content for a URL.
     ///
     /// # Arguments
@@ -438,15 +859,166 @@ impl CacheDownloadService {
         self.cache_manager.fetch(&task_id)
     }
 
+    /// Fetches the cached content of a request, identified by its cache key.
+    ///
+    /// # Arguments
+    /// * `request` - Request of the content to fetch
+    ///
+    /// # Returns
+    /// An optional Arc to the cached content if found
+    pub fn fetch_request(&'static self, request: &DownloadRequest) -> Option<Arc<RamCache>> {
+        self.cache_manager.fetch(&request.task_id())
+    }
+
+    /// Opens a streaming reader on the content of a URL.
+    ///
+    /// Unlike [`CacheDownloadService::fetch`], content still being preloaded
+    /// can be read as soon as its bytes are received; reads wait for the next
+    /// bytes until the download finishes. All the preloads of the URL share
+    /// the same download, so the reader keeps working whichever caller
+    /// started it.
+    ///
+    /// # Arguments
+    /// * `url` - URL of the content to read
+    ///
+    /// # Returns
+    /// A reader if the content is being preloaded or cached, `None` otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Read;
+    ///
+    /// use preload_native_rlib::CacheDownloadService;
+    ///
+    /// let service = CacheDownloadService::get_instance();
+    /// if let Some(mut reader) = service.stream("https://example.com/video.mp4") {
+    ///     let mut head = [0u8; 1024];
+    ///     let n = reader.read(&mut head).unwrap();
+    ///     println!("read {} bytes", n);
+    /// }
+    /// ```
+    pub fn stream(&'static self, url: &str) -> Option<CacheReader> {
+        let task_id = TaskId::from_url(url);
+        self.cache_manager.open_stream(&task_id)
+    }
+
+    /// Opens a streaming reader on the content of a request, identified by
+    /// its cache key.
+    ///
+    /// See [`CacheDownloadService::stream`].
+    ///
+    /// # Arguments
+    /// * `request` - Request of the content to read
+    ///
+    /// # Returns
+    /// A reader if the content is being preloaded or cached, `None` otherwise
+    pub fn stream_request(&'static self, request: &DownloadRequest) -> Option<CacheReader> {
+        self.cache_manager.open_stream(&request.task_id())
+    }
+
+    /// Reads a byte range of the content of a URL.
+    ///
+    /// The bytes present in the cache, whether the full content or segments
+    /// of earlier range reads, are served without network access. Otherwise a
+    /// single Range request covering the missing bytes is sent; the received
+    /// segments are kept in the partial cache of the URL, which becomes a
+    /// regular cache entry once every byte of the content is present.
+    ///
+    /// # Arguments
+    /// * `request` - Download request of the content
+    /// * `offset` - Offset of the first byte to read
+    /// * `len` - Number of bytes to read, at most [`MAX_RANGE_LEN`]
+    /// * `callback` - Callback to receive the bytes
+    /// * `downloader` - Type of downloader to use for the Range request
+    ///
+    /// # Returns
+    /// A handle for cancelling the read if it was started, `None` if the range
+    /// is too long or the Range request could not be started
+    pub fn fetch_range(
+        &'static self,
+        request: DownloadRequest,
+        offset: u64,
+        len: usize,
+        mut callback: Box<dyn RangeCallback>,
+        downloader: Downloader,
+    ) -> Option<RangeHandle> {
+        let task_id = request.task_id();
+        info!("fetch range {} {}+{}", task_id.brief(), offset, len);
+        let end = match offset.checked_add(len as u64) {
+            Some(end) if len <= MAX_RANGE_LEN => end,
+            _ => {
+                error!("{} range {}+{} too long", task_id.brief(), offset, len);
+                return None;
+            }
+        };
+
+        let missing = self.cache_manager.missing_ranges(&task_id, offset, len);
+        if missing.is_empty() {
+            if let Ok(data) = self.cache_manager.read_range(&task_id, offset, len) {
+                crate::spawn(move || callback.on_success(data));
+                return Some(RangeHandle::new(None));
+            }
+        }
+
+        // Request the bytes from the first gap to the last one, the cached
+        // bytes around them are read beforehand
+        let mut window = vec![0; len];
+        let span = match (missing.first(), missing.last()) {
+            (Some(first), Some(last)) => {
+                let head = (first.start - offset) as usize;
+                let tail = (end - last.end) as usize;
+                if self.read_cached(&task_id, offset, &mut window[..head])
+                    && self.read_cached(&task_id, last.end, &mut window[len - tail..])
+                {
+                    first.start..last.end
+                } else {
+                    offset..end
+                }
+            }
+            _ => offset..end,
+        };
+
+        let mut fill = RangeFill::new(task_id, &self.cache_manager, offset, window, callback);
+        if let Some(namespace) = request.namespace {
+            fill.set_namespace(namespace);
+        }
+        range::run(request, span, fill, downloader, self.info_mgr.clone())
+            .map(|handle| RangeHandle::new(Some(handle)))
+    }
+
+    /// Copies cached bytes into a buffer.
+    ///
+    /// # Returns
+    /// `true` if the bytes were read, the buffer may be only partly filled if
+    /// the content ends within it.
+    fn read_cached(&self, task_id: &TaskId, offset: u64, buf: &mut [u8]) -> bool {
+        if buf.is_empty() {
+            return true;
+        }
+        match self.cache_manager.read_range(task_id, offset, buf.len()) {
+            Ok(data) => {
+                let n = data.len().min(buf.len());
+                buf[..n].copy_from_slice(&data[..n]);
+                true
+            }
+            Err(e) => {
+                info!("{} range not read from cache, {}", task_id.brief(), e);
+                false
+            }
+        }
+    }
+
     /// Handles task completion notification.
     ///
-    /// Removes the task from tracking if the sequence number matches the
-    /// current task.
+    /// Frees the running slot of the task in the preload queue, and removes
+    /// the task from tracking if the sequence number matches the current task.
     ///
     /// # Arguments
     /// * `task_id` - ID of the completed task
     /// * `seq` - Sequence number of the task completion
     pub(crate) fn task_finish(&self, task_id: &TaskId, seq: usize) {
+        PreloadQueue::get_instance().finish(task_id, seq);
         let Some(updater) = self.running_tasks.lock().unwrap().get(task_id).cloned() else {
             return;
         };
@@ -466,6 +1038,43 @@ impl CacheDownloadService {
         self.cache_manager.set_file_cache_size(size);
     }
 
+    /// Sets the maximum file cache size of a namespace.
+    ///
+    /// Entries of a namespace only evict each other, so that a consumer
+    /// cannot evict the working set of another. Namespaces without size use
+    /// the default file cache size.
+    ///
+    /// # Arguments
+    /// * `namespace` - Name of the namespace, see
+    ///   [`DownloadRequest::namespace`]
+    /// * `size` - Maximum size in bytes for the file cache of the namespace
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use preload_native_rlib::CacheDownloadService;
+    ///
+    /// let service = CacheDownloadService::get_instance();
+    /// service.set_namespace_cache_size("thumbnails", 50 * 1024 * 1024);
+    /// service.set_namespace_cache_size("audio", 500 * 1024 * 1024);
+    /// ```
+    pub fn set_namespace_cache_size(&self, namespace: &str, size: u64) {
+        info!("set file cache size of {} to {}", namespace, size);
+        self.cache_manager.set_namespace_cache_size(namespace, size);
+    }
+
+    /// Sets the maximum size of the pinned items, whatever their namespace.
+    ///
+    /// Pinned items are never evicted, so a size below that of the pinned
+    /// items only prevents pinning others until some are unpinned.
+    ///
+    /// # Arguments
+    /// * `size` - Maximum size in bytes for pinned items (default: 100MB)
+    pub fn set_pinned_cache_size(&self, size: u64) {
+        info!("set pinned cache size to {}", size);
+        self.cache_manager.set_pinned_cache_size(size);
+    }
+
     /// Sets the maximum RAM cache size.
     ///
     /// # Arguments
@@ -521,6 +1130,97 @@ impl CacheDownloadService {
         info!("clear file cache");
     }
 
+    /// Takes a snapshot of the cache statistics.
+    ///
+    /// # Returns
+    /// Hit, miss, byte and eviction counters since the service started, with
+    /// the occupancy of each cache tier, in total and per namespace
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use preload_native_rlib::CacheDownloadService;
+    ///
+    /// let stats = CacheDownloadService::get_instance().stats();
+    /// println!(
+    ///     "hit rate {:.2}, ram {}/{} bytes",
+    ///     stats.counters.hit_rate(),
+    ///     stats.ram.used,
+    ///     stats.ram.capacity
+    /// );
+    /// ```
+    pub fn stats(&self) -> CacheStats {
+        self.cache_manager.stats()
+    }
+
+    /// Lists the cached entries with their tier, size and last access.
+    pub fn cache_entries(&self) -> Vec<CacheEntry> {
+        self.cache_manager.entries()
+    }
+
+    /// Imports an archive of prefetched responses into the file cache.
+    ///
+    /// Items already cached are kept, and items that do not fit in the free
+    /// space of their namespace are skipped, so importing never evicts cached
+    /// items. See [`ArchiveWriter`] for building archives.
+    ///
+    /// # Arguments
+    /// * `path` - Path of the archive, e.g. shipped in the app resources
+    ///
+    /// # Returns
+    /// The number of items imported
+    ///
+    /// # Errors
+    /// Returns `io::Error` if the archive cannot be read or is malformed.
+    ///
+    /// [`ArchiveWriter`]: crate::ArchiveWriter
+    pub fn import_cache(&self, path: &str) -> io::Result<usize> {
+        let file = File::open(path)?;
+        let imported = self.cache_manager.import_archive(BufReader::new(file))?;
+        info!("import {} cached items from {}", imported, path);
+        Ok(imported)
+    }
+
+    /// Exports the items of the file cache to an archive.
+    ///
+    /// The archive is written to a temporary file renamed to `path` once
+    /// complete, so an existing archive at `path` is replaced only by a
+    /// complete one.
+    ///
+    /// # Arguments
+    /// * `path` - Path of the archive to write
+    ///
+    /// # Returns
+    /// The number of items exported
+    ///
+    /// # Errors
+    /// Returns `io::Error` if the archive cannot be written.
+    pub fn export_cache(&self, path: &str) -> io::Result<usize> {
+        let temp = format!("{}.tmp", path);
+        let res = File::create(&temp).and_then(|file| {
+            let mut writer = BufWriter::new(file);
+            let exported = self.cache_manager.export_archive(&mut writer)?;
+            writer.into_inner()?.sync_all()?;
+            fs::rename(&temp, path)?;
+            Ok(exported)
+        });
+        match res {
+            Ok(exported) => info!("export {} cached items to {}", exported, path),
+            Err(_) => {
+                let _ = fs::remove_file(&temp);
+            }
+        }
+        res
+    }
+
+    /// Sets the listener of the entries evicted to make room for others.
+    ///
+    /// # Arguments
+    /// * `listener` - The listener, `None` to remove the current one
+    pub fn set_eviction_listener(&self, listener: Option<Box<dyn EvictionListener>>) {
+        self.cache_manager.set_eviction_listener(listener);
+    }
+
     /// Sets global retry options for all tasks.
     ///
     /// # Arguments
@@ -603,6 +1303,19 @@ impl CacheDownloadService {
         }
     }
 
+    /// Gets the cached copy of a task, reading its cache file if the cache
+    /// files are still being indexed.
+    fn fetch_cached(&'static self, task_id: &TaskId) -> Option<Arc<RamCache>> {
+        self.cache_manager.fetch(task_id).or_else(|| {
+            if self.restore_finished.load(Ordering::SeqCst) {
+                return None;
+            }
+            self.cache_manager
+                .read_task_local_file(task_id)
+                .map(Arc::new)
+        })
+    }
+
     fn callback_from_local_file(
         &'static self,
         task_id: &TaskId,
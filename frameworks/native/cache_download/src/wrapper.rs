@@ -19,12 +19,16 @@
 
 // Standard library imports for thread synchronization and communication
 use std::sync::{mpsc, Arc, Mutex};
+use std::time::UNIX_EPOCH;
 
 // External dependencies for cache core and FFI bridge
 use cache_core::observe::observe_image_file_delete;
-use cache_core::RamCache;
+use cache_core::{CacheCounters, CacheEntry, CacheTier, EvictionListener, RamCache, TierUsage};
 use cxx::{SharedPtr, UniquePtr};
-use ffi::{FfiPredownloadOptions, PreloadCallbackWrapper, PreloadProgressCallbackWrapper};
+use ffi::{
+    EvictionListenerWrapper, FfiCacheCounters, FfiCacheEntry, FfiCacheStats, FfiNamespaceStats,
+    FfiPredownloadOptions, FfiTierUsage, PreloadCallbackWrapper, PreloadProgressCallbackWrapper,
+};
 
 // Internal dependencies from cache_download
 use crate::download::task::{Downloader, TaskHandle};
@@ -145,6 +149,23 @@ impl PreloadCallback for FfiCallback {
         self.callback.OnCancel();
     }
 
+    /// Notifies C++ of the refreshed content of a revalidated cached copy.
+    ///
+    /// Converts the Rust cached data into a C++ compatible format and invokes
+    /// the C++ OnUpdated callback.
+    ///
+    /// # Arguments
+    /// * `data` - The refreshed content in RAM cache
+    /// * `task_id` - Identifier for the task
+    fn on_updated(&mut self, data: Arc<RamCache>, task_id: &str) {
+        if self.callback.is_null() {
+            return;
+        }
+        let rust_data = RustData::new(data);
+        let shared_data = ffi::SharedData(Box::new(rust_data));
+        self.callback.OnUpdated(shared_data, task_id);
+    }
+
     /// Handles and buffers progress updates for C++.
     ///
     /// Manages progress reporting with a dedicated thread for C++ callbacks,
@@ -204,6 +225,67 @@ impl PreloadCallback for FfiCallback {
     }
 }
 
+/// FFI implementation of the EvictionListener trait for C++ interoperability.
+struct FfiEvictionListener {
+    /// C++ listener wrapper for eviction events
+    listener: UniquePtr<EvictionListenerWrapper>,
+}
+
+// Safety: EvictionListenerWrapper only calls a std::function that the caller
+// makes thread-safe
+unsafe impl Send for FfiEvictionListener {}
+unsafe impl Sync for FfiEvictionListener {}
+
+impl EvictionListener for FfiEvictionListener {
+    fn on_evict(&self, entry: &CacheEntry) {
+        self.listener.OnEvict(&FfiCacheEntry::from(entry));
+    }
+}
+
+impl From<&CacheCounters> for FfiCacheCounters {
+    fn from(counters: &CacheCounters) -> Self {
+        Self {
+            ram_hits: counters.ram_hits,
+            file_hits: counters.file_hits,
+            misses: counters.misses,
+            ram_bytes: counters.ram_bytes,
+            file_bytes: counters.file_bytes,
+            network_bytes: counters.network_bytes,
+            ram_evictions: counters.ram_evictions,
+            file_evictions: counters.file_evictions,
+        }
+    }
+}
+
+impl From<&TierUsage> for FfiTierUsage {
+    fn from(usage: &TierUsage) -> Self {
+        Self {
+            used: usage.used,
+            capacity: usage.capacity,
+            entries: usage.entries as u64,
+        }
+    }
+}
+
+impl From<&CacheEntry> for FfiCacheEntry {
+    fn from(entry: &CacheEntry) -> Self {
+        Self {
+            task_id: entry.task_id.to_string(),
+            namespace_name: entry.namespace.clone().unwrap_or_default(),
+            tier: match entry.tier {
+                CacheTier::Ram => 0,
+                CacheTier::File => 1,
+                CacheTier::Partial => 2,
+            },
+            size: entry.size,
+            last_access: entry
+                .last_access
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |time| time.as_millis() as u64),
+        }
+    }
+}
+
 impl CacheDownloadService {
     /// FFI-compatible preload method for C++.
     ///
@@ -260,6 +342,12 @@ impl CacheDownloadService {
             request.http_total_timeout(options.http_total_timeout);
         }
 
+        // Position in the preload queue and cancellation groups
+        request.priority(options.priority);
+        for tag in options.tags.iter().copied() {
+            request.tag(tag);
+        }
+
         // Perform preload and convert the result to C++ format
         match self.preload(request, Box::new(callback), update, Downloader::Netstack) {
             Some(handle) => ffi::ShareTaskHandle(Box::new(handle)),
@@ -280,6 +368,66 @@ impl CacheDownloadService {
             None => UniquePtr::null(),
         }
     }
+
+    fn ffi_stats(&self) -> FfiCacheStats {
+        let stats = self.stats();
+        FfiCacheStats {
+            counters: FfiCacheCounters::from(&stats.counters),
+            ram: FfiTierUsage::from(&stats.ram),
+            file: FfiTierUsage::from(&stats.file),
+            pinned: FfiTierUsage::from(&stats.pinned),
+            namespaces: stats
+                .namespaces
+                .iter()
+                .map(|(name, namespace)| FfiNamespaceStats {
+                    name: name.clone(),
+                    counters: FfiCacheCounters::from(&namespace.counters),
+                    file: FfiTierUsage::from(&namespace.file),
+                })
+                .collect(),
+        }
+    }
+
+    fn ffi_cache_entries(&self) -> Vec<FfiCacheEntry> {
+        self.cache_entries()
+            .iter()
+            .map(FfiCacheEntry::from)
+            .collect()
+    }
+
+    /// Sets the C++ listener of the evicted entries, removing the current one
+    /// if the pointer is null.
+    fn ffi_set_eviction_listener(&self, listener: UniquePtr<EvictionListenerWrapper>) {
+        if listener.is_null() {
+            self.set_eviction_listener(None);
+        } else {
+            self.set_eviction_listener(Some(Box::new(FfiEvictionListener { listener })));
+        }
+    }
+
+    /// Imports a cache archive, returning the number of items imported or -1
+    /// on error.
+    fn ffi_import_cache(&self, path: &str) -> i64 {
+        match self.import_cache(path) {
+            Ok(imported) => imported as i64,
+            Err(e) => {
+                error!("import cache from {} error: {}", path, e);
+                -1
+            }
+        }
+    }
+
+    /// Exports the file cache to an archive, returning the number of items
+    /// exported or -1 on error.
+    fn ffi_export_cache(&self, path: &str) -> i64 {
+        match self.export_cache(path) {
+            Ok(exported) => exported as i64,
+            Err(e) => {
+                error!("export cache to {} error: {}", path, e);
+                -1
+            }
+        }
+    }
 }
 
 /// Gets a raw pointer to the cache download service singleton for C++.
@@ -322,6 +470,58 @@ pub(crate) mod ffi {
         network_check_timeout: u32,
         /// HTTP total timeout in seconds. u32::MAX means use global default.
         http_total_timeout: u32,
+        /// Priority in the preload queue, higher first.
+        priority: i32,
+        /// Tags of the task, for cancelling tasks by group.
+        tags: Vec<&'a str>,
+    }
+
+    /// Counters of the cache activity
+    struct FfiCacheCounters {
+        ram_hits: u64,
+        file_hits: u64,
+        misses: u64,
+        ram_bytes: u64,
+        file_bytes: u64,
+        network_bytes: u64,
+        ram_evictions: u64,
+        file_evictions: u64,
+    }
+
+    /// Occupancy of a cache tier
+    struct FfiTierUsage {
+        used: u64,
+        capacity: u64,
+        entries: u64,
+    }
+
+    /// Statistics of a named namespace
+    struct FfiNamespaceStats {
+        name: String,
+        counters: FfiCacheCounters,
+        file: FfiTierUsage,
+    }
+
+    /// Snapshot of the cache statistics
+    struct FfiCacheStats {
+        counters: FfiCacheCounters,
+        ram: FfiTierUsage,
+        file: FfiTierUsage,
+        pinned: FfiTierUsage,
+        namespaces: Vec<FfiNamespaceStats>,
+    }
+
+    /// Cached entry
+    ///
+    /// * `namespace_name` - Empty for the default namespace
+    /// * `tier` - 0 for RAM, 1 for file, 2 for partial
+    /// * `last_access` - Milliseconds since the Unix epoch
+    struct FfiCacheEntry {
+        task_id: String,
+        namespace_name: String,
+        tier: i32,
+        size: u64,
+        last_access: u64,
     }
 
     // Rust functions and types exposed to C++
@@ -348,6 +548,7 @@ pub(crate) mod ffi {
 
         fn set_file_cache_size(self: &CacheDownloadService, size: u64);
         fn set_ram_cache_size(self: &CacheDownloadService, size: u64);
+        fn set_pinned_cache_size(self: &CacheDownloadService, size: u64);
         fn set_info_list_size(self: &CacheDownloadService, size: u16);
 
         fn dns_time(self: &RustDownloadInfo) -> f64;
@@ -369,10 +570,25 @@ pub(crate) mod ffi {
         fn cache_download_service() -> *const CacheDownloadService;
         fn set_file_cache_path(path: String);
         fn cancel(self: &CacheDownloadService, url: &str);
+        fn cancel_tag(self: &CacheDownloadService, tag: &str);
+        fn reprioritize(self: &CacheDownloadService, url: &str, priority: i32) -> bool;
+        fn set_max_concurrent_downloads(self: &CacheDownloadService, max: usize);
         fn remove(self: &CacheDownloadService, url: &str);
+        fn pin(self: &CacheDownloadService, url: &str) -> bool;
+        fn unpin(self: &CacheDownloadService, url: &str) -> bool;
         fn contains(self: &CacheDownloadService, url: &str) -> bool;
         fn clear_memory_cache(self: &CacheDownloadService);
         fn clear_file_cache(self: &CacheDownloadService);
+        fn ffi_import_cache(self: &CacheDownloadService, path: &str) -> i64;
+        fn ffi_export_cache(self: &CacheDownloadService, path: &str) -> i64;
+
+        // Cache statistics methods
+        fn ffi_stats(self: &CacheDownloadService) -> FfiCacheStats;
+        fn ffi_cache_entries(self: &CacheDownloadService) -> Vec<FfiCacheEntry>;
+        fn ffi_set_eviction_listener(
+            self: &CacheDownloadService,
+            listener: UniquePtr<EvictionListenerWrapper>,
+        );
 
         // Global configuration methods
         fn set_global_retry_options(self: &CacheDownloadService, max_retry: usize);
@@ -403,6 +619,7 @@ pub(crate) mod ffi {
         // C++ types used in the bridge
         type PreloadCallbackWrapper;
         type PreloadProgressCallbackWrapper;
+        type EvictionListenerWrapper;
         type Data;
         type CppDownloadInfo;
         type PreloadHandle;
@@ -422,6 +639,8 @@ pub(crate) mod ffi {
             task_id: &str,
         );
         fn OnCancel(self: &PreloadCallbackWrapper);
+        fn OnUpdated(self: &PreloadCallbackWrapper, data: SharedPtr<Data>, task_id: &str);
         fn OnProgress(self: &PreloadProgressCallbackWrapper, progress: u64, total: u64);
+        fn OnEvict(self: &EvictionListenerWrapper, entry: &FfiCacheEntry);
     }
 }
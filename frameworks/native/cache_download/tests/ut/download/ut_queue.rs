@@ -0,0 +1,142 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn id(name: &str) -> TaskId {
+    TaskId::from_url(name)
+}
+
+fn key(name: &str) -> PendingKey {
+    (id(name), 0)
+}
+
+fn pop_all(pending: &mut Pending<&'static str>) -> Vec<&'static str> {
+    let mut items = vec![];
+    while let Some((_, item)) = pending.pop() {
+        items.push(item);
+    }
+    items
+}
+
+// @tc.name: ut_pending_order
+// @tc.desc: Test the order in which queued downloads start
+// @tc.precon: NA
+// @tc.step: 1. Push items of different priorities
+//           2. Pop all the items
+// @tc.expect: Items come out by decreasing priority, in order of arrival
+//             among equal priorities
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_pending_order() {
+    let mut pending = Pending::new();
+    pending.push(key("a"), 0, vec![], "a");
+    pending.push(key("b"), 5, vec![], "b");
+    pending.push(key("c"), 0, vec![], "c");
+    pending.push(key("d"), -1, vec![], "d");
+    pending.push(key("e"), 5, vec![], "e");
+    assert_eq!(pending.len(), 5);
+    assert_eq!(pop_all(&mut pending), vec!["b", "e", "a", "c", "d"]);
+    assert!(pending.is_empty());
+}
+
+// @tc.name: ut_pending_reprioritize
+// @tc.desc: Test moving queued downloads and removing them
+// @tc.precon: NA
+// @tc.step: 1. Raise the priority of the last item and lower another one
+//           2. Join a request of higher priority, then one of lower priority
+//           3. Remove an item
+// @tc.expect: Items move to their new priority, joins only raise it, and
+//             removed or unknown items are not found
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_pending_reprioritize() {
+    let mut pending = Pending::new();
+    for name in ["a", "b", "c", "d"] {
+        pending.push(key(name), 0, vec![], name);
+    }
+    assert!(pending.reprioritize(&id("d"), 10));
+    assert!(pending.reprioritize(&id("a"), -10));
+    assert!(!pending.reprioritize(&id("x"), 10));
+
+    assert!(pending.join(&id("c"), 3, &[]));
+    assert!(pending.join(&id("c"), 1, &[]));
+    assert!(!pending.join(&id("x"), 1, &[]));
+
+    assert_eq!(pending.remove(&key("b")), Some("b"));
+    assert_eq!(pending.remove(&key("b")), None);
+    assert!(pending.keys_of(&id("b")).is_empty());
+    assert_eq!(pop_all(&mut pending), vec!["d", "c", "a"]);
+}
+
+// @tc.name: ut_pending_tags
+// @tc.desc: Test finding queued downloads by tag
+// @tc.precon: NA
+// @tc.step: 1. Push items with tags
+//           2. Join a request adding a tag to an item
+//           3. List the items with each tag
+// @tc.expect: Items are listed under their own and joined tags, without
+//             duplicated tags
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_pending_tags() {
+    let mut pending = Pending::new();
+    pending.push(key("a"), 0, vec!["page1".to_string()], "a");
+    pending.push(key("b"), 0, vec!["page1".to_string()], "b");
+    pending.push(key("c"), 0, vec!["page2".to_string()], "c");
+    assert!(pending.join(&id("c"), 0, &["page1", "page2"]));
+
+    let mut page1 = pending.tagged("page1").copied().collect::<Vec<_>>();
+    page1.sort_unstable();
+    assert_eq!(page1, vec!["a", "b", "c"]);
+    assert_eq!(pending.tagged("page2").count(), 1);
+    assert_eq!(pending.tagged("page3").count(), 0);
+
+    assert_eq!(pending.remove(&key("c")), Some("c"));
+    let (tags, item) = pending.pop().unwrap();
+    assert_eq!(item, "a");
+    assert_eq!(tags, vec!["page1".to_string()]);
+}
+
+// @tc.name: ut_pending_same_task
+// @tc.desc: Test queued downloads of the same task with different sequence
+//           numbers
+// @tc.precon: NA
+// @tc.step: 1. Push two items of a task with different sequence numbers and
+//              an item of another task
+//           2. Raise the priority of the task
+//           3. Remove the first item of the task, then pop all the items
+// @tc.expect: Both items of the task are kept and moved, and removing one
+//             keeps the other
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_pending_same_task() {
+    let mut pending = Pending::new();
+    pending.push((id("a"), 0), 0, vec![], "a0");
+    pending.push(key("b"), 0, vec![], "b");
+    pending.push((id("a"), 1), 0, vec![], "a1");
+    assert_eq!(pending.len(), 3);
+
+    assert!(pending.reprioritize(&id("a"), 5));
+    assert_eq!(pending.keys_of(&id("a")).len(), 2);
+    assert_eq!(pending.remove(&(id("a"), 0)), Some("a0"));
+    assert_eq!(pop_all(&mut pending), vec!["a1", "b"]);
+}
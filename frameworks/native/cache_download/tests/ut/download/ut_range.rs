@@ -0,0 +1,60 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+// @tc.name: ut_body_position_content_range
+// @tc.desc: Test the position of a partial response body
+// @tc.precon: NA
+// @tc.step: 1. Parse Content-Range values with known and unknown totals
+// @tc.expect: The first byte and the total size are returned
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_body_position_content_range() {
+    assert_eq!(
+        body_position(Some("bytes 100-199/1000"), Some(100)),
+        (100, Some(1000))
+    );
+    assert_eq!(body_position(Some("bytes 0-9/*"), Some(10)), (0, None));
+    assert_eq!(body_position(Some(" bytes 5-9/10 "), None), (5, Some(10)));
+}
+
+// @tc.name: ut_body_position_full
+// @tc.desc: Test the position of a full response body
+// @tc.precon: NA
+// @tc.step: 1. Get the position without Content-Range
+// @tc.expect: The body starts at 0 and its length is the total size
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_body_position_full() {
+    assert_eq!(body_position(None, Some(1000)), (0, Some(1000)));
+    assert_eq!(body_position(None, None), (0, None));
+}
+
+// @tc.name: ut_range_header
+// @tc.desc: Test the Range header of a range of bytes
+// @tc.precon: NA
+// @tc.step: 1. Format the header of ranges
+// @tc.expect: The last byte of the header is inclusive
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_range_header() {
+    assert_eq!(range_header(&(0..10)), "bytes=0-9");
+    assert_eq!(range_header(&(100..101)), "bytes=100-100");
+}
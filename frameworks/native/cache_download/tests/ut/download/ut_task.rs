@@ -14,7 +14,7 @@
 use std::collections::HashSet;
 use std::io::{BufReader, Lines};
 use std::net::TcpStream;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, LazyLock};
 use std::thread;
 use std::time::Duration;
@@ -22,9 +22,11 @@ use std::time::Duration;
 use cache_core::{CacheManager, RamCache};
 use netstack_rs::info::DownloadInfoMgr;
 use request_utils::test::log::init;
-use request_utils::test::server::test_server;
+use request_utils::test::server::{test_server, MockResponse, MockServer};
 
 use super::*;
+use crate::download::CacheDownloadError;
+use crate::info::RustDownloadInfo;
 use crate::services::PreloadCallback;
 
 const TEST_URL: &str = "https://www.baidu.com";
@@ -42,24 +44,17 @@ impl PreloadCallback for TestCallback {
 }
 
 #[cfg(feature = "ohos")]
-const DOWNLOADER: for<'a> fn(
-    DownloadRequest<'a>,
-    PrimeCallback,
-    Arc<DownloadInfoMgr>,
-) -> Option<Arc<(dyn CommonHandle + 'static)>> = netstack::DownloadTask::run;
+const DOWNLOADER: Downloader = Downloader::Netstack;
 
 #[cfg(not(feature = "ohos"))]
-const DOWNLOADER: for<'a> fn(
-    DownloadRequest<'a>,
-    PrimeCallback,
-) -> Arc<(dyn CommonHandle + 'static)> = ylong::DownloadTask::run;
+const DOWNLOADER: Downloader = Downloader::Ylong;
 
 // @tc.name: ut_preload
 // @tc.desc: Test preload functionality
 // @tc.precon: NA
 // @tc.step: 1. Initialize CacheManager
 //           2. Create download request with test URL
-//           3. Call download_inner function
+//           3. Start a download task through the queue
 //           4. Wait for task completion
 // @tc.expect: success_flag is set to true
 // @tc.type: FUNC
@@ -71,19 +66,19 @@ fn ut_preload() {
     let success_flag = Arc::new(AtomicBool::new(false));
     let request = DownloadRequest::new(TEST_URL);
     let info_mgr = Arc::new(DownloadInfoMgr::new());
-    let handle = download_inner(
+    let task = DownloadTask::new(
         TaskId::from_url(TEST_URL),
         &CACHE_MANAGER,
         info_mgr,
         request,
-        Some(Box::new(TestCallback {
+        Box::new(TestCallback {
             flag: success_flag.clone(),
-        })),
+        }),
         DOWNLOADER,
         0,
     );
-    assert!(handle.is_some());
-    let handle = handle.unwrap();
+    assert!(task.is_some());
+    let handle = task.unwrap().task_handle();
     while !handle.is_finish() {
         thread::sleep(Duration::from_millis(500));
     }
@@ -96,7 +91,7 @@ fn ut_preload() {
 // @tc.step: 1. Initialize CacheManager
 //           2. Create test server to verify headers
 //           3. Create download request with custom headers
-//           4. Call download_inner function
+//           4. Start a download task through the queue
 //           5. Wait for task completion
 // @tc.expect: All custom headers are received by server
 // @tc.type: FUNC
@@ -136,19 +131,106 @@ fn ut_download_headers() {
     let mut request = DownloadRequest::new(&server);
     request.headers(headers);
     let info_mgr = Arc::new(DownloadInfoMgr::new());
-    let handle = download_inner(
+    let task = DownloadTask::new(
         TaskId::from_url(&server),
         &CACHE_MANAGER,
         info_mgr,
         request,
-        None,
+        Box::new(TestCallback {
+            flag: Arc::new(AtomicBool::new(false)),
+        }),
         DOWNLOADER,
         0,
     );
-    assert!(handle.is_some());
-    let handle = handle.unwrap();
+    assert!(task.is_some());
+    let handle = task.unwrap().task_handle();
     while !handle.is_finish() {
         thread::sleep(Duration::from_millis(500));
     }
     assert!(flag.load(Ordering::SeqCst));
 }
+
+struct ResultCallback {
+    size: Arc<AtomicUsize>,
+    failed: Arc<AtomicBool>,
+}
+
+impl PreloadCallback for ResultCallback {
+    fn on_success(&mut self, data: Arc<RamCache>, _task_id: &str) {
+        self.size.store(data.size(), Ordering::Release);
+    }
+
+    fn on_fail(&mut self, _error: CacheDownloadError, _info: RustDownloadInfo, _task_id: &str) {
+        self.failed.store(true, Ordering::Release);
+    }
+}
+
+fn download_from(
+    cache_manager: &'static CacheManager,
+    url: &str,
+) -> (Arc<AtomicUsize>, Arc<AtomicBool>) {
+    let size = Arc::new(AtomicUsize::new(0));
+    let failed = Arc::new(AtomicBool::new(false));
+    let handle = DownloadTask::new(
+        TaskId::from_url(url),
+        cache_manager,
+        Arc::new(DownloadInfoMgr::new()),
+        DownloadRequest::new(url),
+        Box::new(ResultCallback {
+            size: size.clone(),
+            failed: failed.clone(),
+        }),
+        DOWNLOADER,
+        0,
+    )
+    .unwrap()
+    .task_handle();
+    while !handle.is_finish() {
+        thread::sleep(Duration::from_millis(100));
+    }
+    (size, failed)
+}
+
+// @tc.name: ut_download_mock_server
+// @tc.desc: Test a download served by the mock server
+// @tc.precon: NA
+// @tc.step: 1. Serve a chunked body on the mock server
+//           2. Download it and wait for task completion
+// @tc.expect: The whole body is cached after exactly one request
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_download_mock_server() {
+    init();
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    let server = MockServer::start();
+    server.route("/data", [MockResponse::ok(vec![7u8; 10_000]).chunked(1024)]);
+
+    let (size, failed) = download_from(&CACHE_MANAGER, &server.url("/data"));
+    assert!(!failed.load(Ordering::Acquire));
+    assert_eq!(size.load(Ordering::Acquire), 10_000);
+    server.assert_hits("/data", 1);
+}
+
+// @tc.name: ut_download_mock_server_disconnect
+// @tc.desc: Test a download whose connection closes in the middle of the body
+// @tc.precon: NA
+// @tc.step: 1. Serve a body cut after half of its length on the mock server
+//           2. Download it and wait for task completion
+// @tc.expect: The task fails instead of caching a truncated body
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_download_mock_server_disconnect() {
+    init();
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    let server = MockServer::start();
+    server.route(
+        "/cut",
+        [MockResponse::ok(vec![7u8; 10_000]).disconnect_after(5_000)],
+    );
+
+    let (size, failed) = download_from(&CACHE_MANAGER, &server.url("/cut"));
+    assert!(failed.load(Ordering::Acquire));
+    assert_eq!(size.load(Ordering::Acquire), 0);
+}
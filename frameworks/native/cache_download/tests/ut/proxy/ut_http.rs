@@ -0,0 +1,161 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+
+use super::*;
+
+fn response(f: impl FnOnce(&mut Vec<u8>) -> io::Result<()>) -> String {
+    let mut buf = vec![];
+    f(&mut buf).unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+// @tc.name: ut_byte_range_parse
+// @tc.desc: Test parsing the value of Range headers
+// @tc.precon: NA
+// @tc.step: 1. Parse open, closed and suffix ranges
+//           2. Parse malformed values and sets of ranges
+// @tc.expect: Single ranges are parsed, the other values are ignored
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_byte_range_parse() {
+    assert_eq!(ByteRange::parse("bytes=0-"), Some(ByteRange::From(0)));
+    assert_eq!(
+        ByteRange::parse("bytes=10-19"),
+        Some(ByteRange::Between(10, 19))
+    );
+    assert_eq!(ByteRange::parse(" bytes=-5 "), Some(ByteRange::Suffix(5)));
+    assert_eq!(ByteRange::parse("bytes=20-10"), None);
+    assert_eq!(ByteRange::parse("bytes=-"), None);
+    assert_eq!(ByteRange::parse("bytes=0-1,5-6"), None);
+    assert_eq!(ByteRange::parse("items=0-1"), None);
+    assert_eq!(ByteRange::parse("bytes=a-1"), None);
+}
+
+// @tc.name: ut_byte_range_resolve
+// @tc.desc: Test resolving ranges against the size of a resource
+// @tc.precon: NA
+// @tc.step: 1. Resolve ranges inside, across and past the end of a resource
+// @tc.expect: The bytes are clamped to the resource, ranges past its end are
+//             not satisfiable
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_byte_range_resolve() {
+    assert_eq!(ByteRange::From(10).resolve(100), Some(10..100));
+    assert_eq!(ByteRange::From(100).resolve(100), None);
+    assert_eq!(ByteRange::Between(10, 19).resolve(100), Some(10..20));
+    assert_eq!(ByteRange::Between(90, 200).resolve(100), Some(90..100));
+    assert_eq!(ByteRange::Between(0, u64::MAX).resolve(100), Some(0..100));
+    assert_eq!(ByteRange::Suffix(10).resolve(100), Some(90..100));
+    assert_eq!(ByteRange::Suffix(200).resolve(100), Some(0..100));
+    assert_eq!(ByteRange::Suffix(0).resolve(100), None);
+    assert_eq!(ByteRange::From(0).resolve(0), None);
+}
+
+// @tc.name: ut_http_request_read
+// @tc.desc: Test reading the head of a request
+// @tc.precon: NA
+// @tc.step: 1. Read a well-formed request with headers and a query
+//           2. Read malformed and truncated requests
+// @tc.expect: The method, path and headers are parsed, the malformed
+//             requests fail
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_http_request_read() {
+    let raw = "GET /abc?x=1 HTTP/1.1\r\nHost: 127.0.0.1\r\nRange: bytes=0-\r\n\r\nrest";
+    let request = HttpRequest::read_from(&mut Cursor::new(raw)).unwrap();
+    assert_eq!(request.method, "GET");
+    assert_eq!(request.path(), "abc");
+    assert_eq!(request.header("range"), Some("bytes=0-"));
+    assert_eq!(request.header("HOST"), Some("127.0.0.1"));
+    assert_eq!(request.header("accept"), None);
+
+    let malformed = [
+        "GET /\r\n\r\n",
+        "GET / SPDY/3\r\n\r\n",
+        "GET / HTTP/1.1\r\nHost\r\n\r\n",
+    ];
+    for raw in malformed {
+        let err = HttpRequest::read_from(&mut Cursor::new(raw)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+    let err = HttpRequest::read_from(&mut Cursor::new("GET / HTTP/1.1\r\n")).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    let large = format!("GET / HTTP/1.1\r\nX: {}\r\n\r\n", "a".repeat(MAX_HEAD_SIZE));
+    let err = HttpRequest::read_from(&mut Cursor::new(large)).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+// @tc.name: ut_serve_bytes
+// @tc.desc: Test serving a cached resource
+// @tc.precon: NA
+// @tc.step: 1. Serve the resource without range, with a range, with a range
+//              past its end and to a HEAD request
+// @tc.expect: The status, headers and body match each request
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_serve_bytes() {
+    let data = b"0123456789";
+    let full = response(|w| serve_bytes(w, Cursor::new(data), 10, None, false));
+    assert!(full.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(full.contains("Content-Length: 10\r\n"));
+    assert!(full.ends_with("Connection: close\r\n\r\n0123456789"));
+
+    let partial = response(|w| serve_bytes(w, Cursor::new(data), 10, Some("bytes=2-4"), false));
+    assert!(partial.starts_with("HTTP/1.1 206 Partial Content\r\n"));
+    assert!(partial.contains("Content-Length: 3\r\n"));
+    assert!(partial.contains("Content-Range: bytes 2-4/10\r\n"));
+    assert!(partial.ends_with("\r\n\r\n234"));
+
+    let unsatisfiable =
+        response(|w| serve_bytes(w, Cursor::new(data), 10, Some("bytes=10-"), false));
+    assert!(unsatisfiable.starts_with("HTTP/1.1 416 Range Not Satisfiable\r\n"));
+    assert!(unsatisfiable.contains("Content-Range: bytes */10\r\n"));
+    assert!(unsatisfiable.ends_with("\r\n\r\n"));
+
+    let head = response(|w| serve_bytes(w, Cursor::new(data), 10, None, true));
+    assert!(head.contains("Content-Length: 10\r\n"));
+    assert!(head.ends_with("\r\n\r\n"));
+}
+
+// @tc.name: ut_chunked_writer
+// @tc.desc: Test writing a body with the chunked transfer coding
+// @tc.precon: NA
+// @tc.step: 1. Write two chunks and an empty buffer, then finish
+// @tc.expect: Each buffer is a chunk and the body ends with the last chunk
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level2
+#[test]
+fn ut_chunked_writer() {
+    let body = response(|w| {
+        let mut chunked = ChunkedWriter::new(w);
+        chunked.write_all(b"hello")?;
+        chunked.write_all(b"")?;
+        chunked.write_all(&[b'a'; 16])?;
+        chunked.finish()
+    });
+    assert_eq!(
+        body,
+        format!("5\r\nhello\r\n10\r\n{}\r\n0\r\n\r\n", "a".repeat(16))
+    );
+}
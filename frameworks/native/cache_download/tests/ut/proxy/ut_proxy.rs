@@ -0,0 +1,93 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::LazyLock;
+
+use super::*;
+
+fn request(port: u16, raw: &str) -> String {
+    let mut stream = TcpStream::connect((Ipv4Addr::LOCALHOST, port)).unwrap();
+    stream.write_all(raw.as_bytes()).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+// @tc.name: ut_proxy_lifecycle
+// @tc.desc: Test starting and stopping the proxy and minting URLs
+// @tc.precon: NA
+// @tc.step: 1. Mint a URL before starting the proxy
+//           2. Start the proxy twice and mint URLs of two resources, one of
+//              them twice
+//           3. Stop the proxy
+// @tc.expect: URLs are minted only while the proxy runs, on its port, with a
+//             random token kept per resource
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_proxy_lifecycle() {
+    static PROXY: LazyLock<CacheProxy> = LazyLock::new(CacheProxy::new);
+    let url = "https://example.com/video.mp4";
+    assert_eq!(PROXY.proxy_url(url), None);
+
+    let port = PROXY.start(0, Downloader::Netstack).unwrap();
+    assert_eq!(PROXY.start(0, Downloader::Netstack).unwrap(), port);
+    assert_eq!(PROXY.port(), Some(port));
+    let proxied = PROXY.proxy_url(url).unwrap();
+    let prefix = format!("http://127.0.0.1:{}/", port);
+    let token = proxied.strip_prefix(&prefix).unwrap();
+    assert_eq!(token.len(), TOKEN_SIZE * 2);
+    assert_ne!(token, TaskId::from_url(url).to_string());
+    assert_eq!(PROXY.proxy_url(url).unwrap(), proxied);
+    let other = PROXY.proxy_url("https://example.com/audio.mp3").unwrap();
+    assert!(other.starts_with(&prefix));
+    assert_ne!(other, proxied);
+
+    PROXY.stop();
+    assert_eq!(PROXY.port(), None);
+    assert_eq!(PROXY.proxy_url(url), None);
+    PROXY.stop();
+}
+
+// @tc.name: ut_proxy_error_responses
+// @tc.desc: Test the responses to requests the proxy cannot serve
+// @tc.precon: NA
+// @tc.step: 1. Request an unknown token, a removed URL, with another method
+//              and a malformed request
+// @tc.expect: 404, 405 and 400 responses are returned
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_proxy_error_responses() {
+    static PROXY: LazyLock<CacheProxy> = LazyLock::new(CacheProxy::new);
+    let port = PROXY.start(0, Downloader::Netstack).unwrap();
+
+    let response = request(port, "GET /unknown HTTP/1.1\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+
+    let url = "https://example.com/removed.mp4";
+    let proxied = PROXY.proxy_url(url).unwrap();
+    PROXY.remove_url(url);
+    let path = proxied.rsplit('/').next().unwrap();
+    let response = request(port, &format!("GET /{} HTTP/1.1\r\n\r\n", path));
+    assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+
+    let response = request(port, "POST /unknown HTTP/1.1\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 405 Method Not Allowed\r\n"));
+
+    let response = request(port, "hello\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 400 Bad Request\r\n"));
+    PROXY.stop();
+}
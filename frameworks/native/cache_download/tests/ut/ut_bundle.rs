@@ -0,0 +1,106 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[derive(Default)]
+struct Events {
+    progress: Vec<BundleProgress>,
+    complete: Vec<(BundleProgress, Vec<String>)>,
+}
+
+struct TestCallback(Arc<Mutex<Events>>);
+
+impl BundleCallback for TestCallback {
+    fn on_progress(&mut self, progress: &BundleProgress) {
+        self.0.lock().unwrap().progress.push(progress.clone());
+    }
+
+    fn on_complete(&mut self, progress: &BundleProgress, failed: &[String]) {
+        self.0
+            .lock()
+            .unwrap()
+            .complete
+            .push((progress.clone(), failed.to_vec()));
+    }
+}
+
+fn bundle(urls: &[&str]) -> (Arc<Bundle>, Arc<Mutex<Events>>) {
+    let events = Arc::new(Mutex::new(Events::default()));
+    let urls = urls.iter().map(|url| url.to_string()).collect();
+    let bundle = Bundle::new(urls, Box::new(TestCallback(events.clone())));
+    (bundle, events)
+}
+
+// @tc.name: ut_bundle_progress
+// @tc.desc: Test the aggregate progress of a bundle
+// @tc.precon: NA
+// @tc.step: 1. Report the progress of two resources of a bundle
+//           2. Settle the first resource as succeeded, then again as failed
+//           3. Settle the second resource as failed
+// @tc.expect: Bytes and results are summed over the resources, only the
+//             first result of a resource counts, and the bundle completes
+//             once with the URL of the failed resource
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_bundle_progress() {
+    let (bundle, events) = bundle(&["a", "b"]);
+    bundle.update(0, 10, 100);
+    bundle.update(1, 5, 0);
+    assert_eq!(
+        events.lock().unwrap().progress.last().unwrap(),
+        &BundleProgress {
+            total: 2,
+            succeeded: 0,
+            failed: 0,
+            downloaded: 15,
+            size: 100,
+        }
+    );
+
+    bundle.settle(0, Some(100), true);
+    bundle.settle(0, None, false);
+    bundle.update(0, 0, 0);
+    assert!(events.lock().unwrap().complete.is_empty());
+    bundle.settle(1, None, false);
+
+    let events = events.lock().unwrap();
+    assert_eq!(events.progress.len(), 4);
+    assert_eq!(events.complete.len(), 1);
+    let (progress, failed) = &events.complete[0];
+    assert!(progress.is_complete());
+    assert_eq!(progress.succeeded, 1);
+    assert_eq!(progress.failed, 1);
+    assert_eq!(progress.downloaded, 105);
+    assert_eq!(failed, &vec!["b".to_string()]);
+}
+
+// @tc.name: ut_bundle_empty
+// @tc.desc: Test a bundle without resources
+// @tc.precon: NA
+// @tc.step: 1. Create a bundle from an empty manifest
+// @tc.expect: The bundle completes at once without failure
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_bundle_empty() {
+    let (_bundle, events) = bundle(&[]);
+    let events = events.lock().unwrap();
+    assert!(events.progress.is_empty());
+    assert_eq!(events.complete.len(), 1);
+    assert_eq!(events.complete[0].0, BundleProgress::default());
+    assert!(events.complete[0].1.is_empty());
+}
@@ -0,0 +1,139 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+const TEST_URL: &str = "https://cdn.example.com/video.mp4?id=7&token=abc";
+
+fn key(url: &str, policy: &CacheKeyPolicy) -> TaskId {
+    cache_key(url, &[], Some(policy), None)
+}
+
+// @tc.name: ut_cache_key_default
+// @tc.desc: Test the cache key of requests without policy nor namespace
+// @tc.precon: NA
+// @tc.step: 1. Derive the key of a URL without policy
+//           2. Derive it with the default policy
+// @tc.expect: Both keys are the hash of the URL
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_cache_key_default() {
+    let expected = TaskId::from_url(TEST_URL);
+    assert!(cache_key(TEST_URL, &[], None, None) == expected);
+    assert!(key(TEST_URL, &CacheKeyPolicy::default()) == expected);
+}
+
+// @tc.name: ut_cache_key_ignored_params
+// @tc.desc: Test stripping query parameters from the cache key
+// @tc.precon: NA
+// @tc.step: 1. Strip a parameter from URLs with different values of it
+//           2. Strip parameters a URL does not have
+// @tc.expect: The URLs share their key, and URLs without the parameters keep
+//             their key
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_cache_key_ignored_params() {
+    assert_eq!(
+        strip_params(TEST_URL, &["token".to_string()]),
+        "https://cdn.example.com/video.mp4?id=7"
+    );
+    assert_eq!(
+        strip_params("https://a.com/?token=1#top", &["token".to_string()]),
+        "https://a.com/#top"
+    );
+    assert_eq!(
+        strip_params("https://a.com/?a&b=", &[]),
+        "https://a.com/?a&b="
+    );
+
+    let policy = CacheKeyPolicy {
+        ignored_params: vec!["token".to_string()],
+        ..Default::default()
+    };
+    let other = "https://cdn.example.com/video.mp4?id=7&token=xyz";
+    assert!(key(TEST_URL, &policy) == key(other, &policy));
+    let without = "https://cdn.example.com/video.mp4?id=8";
+    assert!(key(without, &policy) == TaskId::from_url(without));
+}
+
+// @tc.name: ut_cache_key_normalize
+// @tc.desc: Test normalizing URLs in the cache key
+// @tc.precon: NA
+// @tc.step: 1. Normalize URLs differing by case, default port, empty path,
+//              fragment and order of parameters
+// @tc.expect: Equivalent URLs are normalized to the same URL, and the case
+//             of paths is kept
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_cache_key_normalize() {
+    assert_eq!(
+        normalize("HTTPS://CDN.Example.com:443/Video.mp4?b=2&a=1#t=10"),
+        "https://cdn.example.com/Video.mp4?a=1&b=2"
+    );
+    assert_eq!(normalize("http://Example.com:80"), "http://example.com/");
+    assert_eq!(
+        normalize("http://User@Example.com:8080/"),
+        "http://User@example.com:8080/"
+    );
+    assert_eq!(normalize("https://a.com/?"), "https://a.com/");
+    assert_eq!(normalize("relative/Path?b&a"), "relative/Path?a&b");
+
+    let policy = CacheKeyPolicy {
+        normalize: true,
+        ..Default::default()
+    };
+    let first = key("https://A.com:443/x?b=2&a=1", &policy);
+    assert!(first == key("https://a.com/x?a=1&b=2", &policy));
+}
+
+// @tc.name: ut_cache_key_vary_namespace
+// @tc.desc: Test request headers, explicit keys and namespaces in the key
+// @tc.precon: NA
+// @tc.step: 1. Derive keys varying with a header of the request
+//           2. Derive keys with an explicit key
+//           3. Derive keys of the same URL in different namespaces
+// @tc.expect: Keys differ with the header value but not with its name case,
+//             explicit keys ignore the URL, and namespaces separate keys
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_cache_key_vary_namespace() {
+    let policy = CacheKeyPolicy {
+        vary: vec!["Accept-Language".to_string()],
+        ..Default::default()
+    };
+    let en = cache_key(TEST_URL, &[("accept-language", "en")], Some(&policy), None);
+    let fr = cache_key(TEST_URL, &[("Accept-Language", "fr")], Some(&policy), None);
+    let en_again = cache_key(TEST_URL, &[("ACCEPT-LANGUAGE", " en")], Some(&policy), None);
+    assert!(en != fr);
+    assert!(en == en_again);
+    assert!(en != TaskId::from_url(TEST_URL));
+
+    let policy = CacheKeyPolicy {
+        key: Some("video-7".to_string()),
+        ..Default::default()
+    };
+    assert!(key(TEST_URL, &policy) == key("https://other.com/", &policy));
+
+    let audio = cache_key(TEST_URL, &[], None, Some("audio"));
+    let thumbnails = cache_key(TEST_URL, &[], None, Some("thumbnails"));
+    assert!(audio != thumbnails);
+    assert!(audio != TaskId::from_url(TEST_URL));
+}
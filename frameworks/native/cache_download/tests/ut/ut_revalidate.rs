@@ -0,0 +1,108 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io;
+use std::sync::mpsc::{channel, Sender};
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use cache_core::{CacheManager, Updater};
+use netstack_rs::info::DownloadInfo;
+
+use super::*;
+
+static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+
+fn cache(data: &[u8]) -> Arc<RamCache> {
+    let task_id = TaskId::new("ut_revalidate".to_string());
+    let mut updater = Updater::new(task_id, &CACHE_MANAGER);
+    updater.cache_receive(data, || Some(data.len()));
+    updater.cache_finish()
+}
+
+struct TestCallback(Sender<(&'static str, Vec<u8>)>);
+
+impl PreloadCallback for TestCallback {
+    fn on_success(&mut self, data: Arc<RamCache>, _task_id: &str) {
+        let _ = self.0.send(("success", data.cursor().get_ref().to_vec()));
+    }
+
+    fn on_updated(&mut self, data: Arc<RamCache>, _task_id: &str) {
+        let _ = self.0.send(("updated", data.cursor().get_ref().to_vec()));
+    }
+
+    fn on_cancel(&mut self) {
+        let _ = self.0.send(("cancel", vec![]));
+    }
+}
+
+// @tc.name: ut_revalidation
+// @tc.desc: Test the callbacks of a stale-while-revalidate preload
+// @tc.precon: NA
+// @tc.step: 1. Start revalidations of a cached copy
+//           2. Complete them with the same content, with another content,
+//              with a failure and with a cancellation
+// @tc.expect: The cached copy is always given first, the refreshed content is
+//             given after it only when it differs, and a cancellation is
+//             reported after it
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level1
+#[test]
+fn ut_revalidation() {
+    let timeout = Duration::from_secs(1);
+    let task_id = TaskId::new("ut_revalidate".to_string());
+    let start = || {
+        let (tx, rx) = channel();
+        let revalidation =
+            Revalidation::start(cache(b"stale"), Box::new(TestCallback(tx)), &task_id);
+        (revalidation, rx)
+    };
+
+    let (mut revalidation, rx) = start();
+    revalidation.on_success(cache(b"stale"), "ut");
+    assert_eq!(
+        rx.recv_timeout(timeout).unwrap(),
+        ("success", b"stale".to_vec())
+    );
+    assert!(rx.recv_timeout(timeout).is_err());
+
+    let (mut revalidation, rx) = start();
+    revalidation.on_success(cache(b"fresh"), "ut");
+    assert_eq!(
+        rx.recv_timeout(timeout).unwrap(),
+        ("success", b"stale".to_vec())
+    );
+    assert_eq!(
+        rx.recv_timeout(timeout).unwrap(),
+        ("updated", b"fresh".to_vec())
+    );
+
+    let (mut revalidation, rx) = start();
+    let error = CacheDownloadError::from(io::Error::other("revalidation failed"));
+    let info = RustDownloadInfo::from_download_info(DownloadInfo::new());
+    revalidation.on_fail(error, info, "ut");
+    assert_eq!(
+        rx.recv_timeout(timeout).unwrap(),
+        ("success", b"stale".to_vec())
+    );
+    assert!(rx.recv_timeout(timeout).is_err());
+
+    let (mut revalidation, rx) = start();
+    revalidation.on_cancel();
+    assert_eq!(
+        rx.recv_timeout(timeout).unwrap(),
+        ("success", b"stale".to_vec())
+    );
+    assert_eq!(rx.recv_timeout(timeout).unwrap(), ("cancel", vec![]));
+}
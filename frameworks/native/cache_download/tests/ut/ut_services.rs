@@ -824,3 +824,39 @@ pub fn get_curr_store_dir() -> PathBuf {
     path.push("preload_caches");
     path
 }
+
+struct TestRangeCallback;
+impl RangeCallback for TestRangeCallback {}
+
+// @tc.name: ut_fetch_range_too_long
+// @tc.desc: Test that overlong byte ranges are refused
+// @tc.precon: NA
+// @tc.step: 1. Fetch a range longer than MAX_RANGE_LEN
+//           2. Fetch a range whose end overflows
+// @tc.expect: Neither read is started
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level2
+#[test]
+fn ut_fetch_range_too_long() {
+    init();
+    static SERVICE: LazyLock<CacheDownloadService> = LazyLock::new(CacheDownloadService::new);
+    assert!(SERVICE
+        .fetch_range(
+            DownloadRequest::new(TEST_URL),
+            0,
+            MAX_RANGE_LEN + 1,
+            Box::new(TestRangeCallback),
+            DOWNLOADER,
+        )
+        .is_none());
+    assert!(SERVICE
+        .fetch_range(
+            DownloadRequest::new(TEST_URL),
+            u64::MAX,
+            1,
+            Box::new(TestRangeCallback),
+            DOWNLOADER,
+        )
+        .is_none());
+}
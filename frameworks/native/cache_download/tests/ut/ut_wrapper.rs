@@ -157,6 +157,8 @@ mod ut_wrapper {
             max_retry: 5,
             network_check_timeout: 10,
             http_total_timeout: 30,
+            priority: 3,
+            tags: vec!["page1"],
         };
 
         assert_eq!(options.headers.len(), 2);
@@ -165,6 +167,8 @@ mod ut_wrapper {
         assert_eq!(options.max_retry, 5);
         assert_eq!(options.network_check_timeout, 10);
         assert_eq!(options.http_total_timeout, 30);
+        assert_eq!(options.priority, 3);
+        assert_eq!(options.tags, vec!["page1"]);
     }
 
     // @tc.name: ut_ffi_predownload_options_empty
@@ -185,6 +189,8 @@ mod ut_wrapper {
             max_retry: usize::MAX,
             network_check_timeout: u32::MAX,
             http_total_timeout: u32::MAX,
+            priority: 0,
+            tags: vec![],
         };
 
         assert!(options.headers.is_empty());
@@ -219,6 +225,8 @@ mod ut_wrapper {
             max_retry: usize::MAX,  // Use global default
             network_check_timeout: u32::MAX,  // Use global default
             http_total_timeout: u32::MAX,  // Use global default
+            priority: 0,
+            tags: vec![],
         };
 
         assert_eq!(options.headers.len(), 6);
@@ -244,6 +252,8 @@ mod ut_wrapper {
             max_retry: 7,  // Override global default (3)
             network_check_timeout: 15,  // Override global default (20)
             http_total_timeout: 90,  // Override global default (60)
+            priority: 0,
+            tags: vec![],
         };
 
         assert_eq!(options.max_retry, 7);
@@ -45,6 +45,15 @@ enum class RequestInterfaceCode {
     CMD_SHOW_PROGRESS,
     CMD_SET_MODE = 100,
     CMD_DISABLE_TASK_NOTIFICATIONS,
+    CMD_QUERY_HISTORY,
+    CMD_SET_HISTORY_RETENTION,
+    CMD_RETRY_FAILED_FILES,
+    CMD_SET_FILE_ERROR_POLICY,
+    CMD_SET_CHUNKED_UPLOAD,
+    CMD_SET_SAVE_AS_SUGGESTED_NAME,
+    CMD_SET_PRIORITY,
+    CMD_MOVE_TO_FRONT,
+    CMD_SET_TASK_DNS,
 };
 
 enum class RequestNotifyInterfaceCode {
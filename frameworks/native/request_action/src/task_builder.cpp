@@ -326,7 +326,12 @@ bool TaskBuilder::checkProxy()
         REQUEST_HILOGE("The proxy exceeds the maximum length of 512");
         return false;
     }
-    if (!regex_match(this->config.proxy, std::regex("^http:\\/\\/.+:\\d{1,5}$"))) {
+    // A single http or socks5 proxy with optional credentials, or a list of
+    // `pattern=target` rules whose targets are proxies or DIRECT.
+    static const std::string PROXY = "(http|socks5):\\/\\/[^;=\\s]+:\\d{1,5}";
+    static const std::string RULE = "\\s*[^;=\\s][^;=]*=\\s*(DIRECT|" + PROXY + ")\\s*";
+    static const std::regex PROXY_REGEX("^(" + PROXY + "|" + RULE + "(;" + RULE + ")*;?\\s*)$");
+    if (!regex_match(this->config.proxy, PROXY_REGEX)) {
         REQUEST_HILOGE("ParseProxy error");
         return false;
     }
@@ -111,11 +111,13 @@ use std::path::PathBuf;
 use std::sync::{Arc, OnceLock};
 
 // External dependencies
-use request_core::config::{Action, Notification, TaskConfig, Version};
+use request_core::config::{
+    Action, ChunkedUploadConfig, Notification, TaskConfig, TaskDnsConfig, Version,
+};
 use request_core::error_code::{CHANNEL_NOT_OPEN, OTHER};
 use request_core::file::FileSpec;
 use request_core::filter::SearchFilter;
-use request_core::info::TaskInfo;
+use request_core::info::{HistoryRecord, TaskInfo};
 use request_utils::context::Context;
 
 // Internal dependencies
@@ -406,6 +408,73 @@ impl<'a> RequestClient<'a> {
         self.proxy.set_max_speed(task_id, speed)
     }
 
+    /// Re-runs only the failed files of a failed multi-file upload task.
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the upload task to retry
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or an error code on failure
+    pub fn retry_failed_files(&self, task_id: i64) -> Result<(), i32> {
+        self.proxy.retry_failed_files(task_id)
+    }
+
+    /// Sets whether a multi-file upload task continues with the remaining
+    /// files after one of them fails. By default it aborts on the first error.
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the upload task to configure
+    /// * `continue_on_error` - `true` to continue with the remaining files
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or an error code on failure
+    pub fn set_file_error_policy(&self, task_id: i64, continue_on_error: bool) -> Result<(), i32> {
+        self.proxy.set_file_error_policy(task_id, continue_on_error)
+    }
+
+    /// Changes the priority of a task. Lower values run first; a waiting
+    /// task takes its new place in the queue right away.
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task to change
+    /// * `priority` - The new priority of the task
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or an error code on failure
+    pub fn set_priority(&self, task_id: i64, priority: u32) -> Result<(), i32> {
+        self.proxy.set_priority(task_id, priority)
+    }
+
+    /// Moves a waiting task to the front of the application's queue, so that
+    /// it runs before the other tasks of the same mode.
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task to move
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or an error code on failure
+    pub fn move_to_front(&self, task_id: i64) -> Result<(), i32> {
+        self.proxy.move_to_front(task_id)
+    }
+
+    /// Makes a single-file upload task a chunked upload, applied the next
+    /// time the task runs. Chunks already uploaded with a previous
+    /// configuration are sent again.
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the upload task to configure
+    /// * `config` - How the file is split and where each chunk goes
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or an error code on failure
+    pub fn set_chunked_upload(
+        &self,
+        task_id: i64,
+        config: &ChunkedUploadConfig,
+    ) -> Result<(), i32> {
+        self.proxy.set_chunked_upload(task_id, config)
+    }
+
     /// Queries the MIME type of a task's downloaded content.
     ///
     /// # Arguments
@@ -417,6 +486,47 @@ impl<'a> RequestClient<'a> {
         self.proxy.query_mime_type(task_id)
     }
 
+    /// Queries the file name suggested by the server for a task's download,
+    /// from its `Content-Disposition` header.
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task to query.
+    ///
+    /// # Returns
+    /// The suggested file name, empty if none, or an error code on failure.
+    pub fn query_suggested_name(&self, task_id: i64) -> Result<String, i32> {
+        self.proxy.query_suggested_name(task_id)
+    }
+
+    /// Makes a download task save its file, once downloaded, into `dir`
+    /// under the name suggested by the server. The name is sanitized and
+    /// numbered if already taken in `dir`.
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the download task to configure
+    /// * `dir` - Sandbox directory to save the file to, empty to keep the
+    ///   file at its configured path
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or an error code on failure
+    pub fn set_save_as_suggested_name(&self, task_id: i64, dir: &str) -> Result<(), i32> {
+        self.proxy.set_save_as_suggested_name(task_id, dir)
+    }
+
+    /// Pins addresses to the hosts of a task and sets the address families
+    /// it uses, applied the next time the task runs. The pinned addresses
+    /// of the task are tried before those of the application.
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task to configure
+    /// * `config` - DNS options of the task, empty to remove them
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or an error code on failure
+    pub fn set_task_dns(&self, task_id: i64, config: &TaskDnsConfig) -> Result<(), i32> {
+        self.proxy.set_task_dns(task_id, config)
+    }
+
     /// Registers a callback for task status updates.
     ///
     /// # Arguments
@@ -461,6 +571,25 @@ impl<'a> RequestClient<'a> {
         self.proxy.search(keyword)
     }
 
+    /// Lists the transfer history of finished tasks, newest first.
+    ///
+    /// # Arguments
+    /// * `bundle_name` - Bundle to list (system callers only); `None` lists
+    ///   every bundle, or the caller's own records for normal apps.
+    /// * `after` - Only records modified after this timestamp (milliseconds).
+    /// * `limit` - Maximum number of records to return.
+    ///
+    /// # Returns
+    /// The archived records on success, or an error code on failure.
+    pub fn query_history(
+        &self,
+        bundle_name: Option<String>,
+        after: i64,
+        limit: u32,
+    ) -> Result<Vec<HistoryRecord>, i32> {
+        self.proxy.query_history(bundle_name, after, limit)
+    }
+
     /// Touches a task to keep it alive, returning its current information.
     ///
     /// # Arguments
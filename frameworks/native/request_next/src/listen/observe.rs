@@ -150,12 +150,17 @@ pub trait Callback {
     /// Called when an upload completes for a batch of task states.
     ///
     /// # Parameters
-    /// * `task_states` - Final states of the uploaded tasks.
+    /// * `task_states` - Final state of each uploaded file.
     fn on_complete_upload(&self, task_states: Vec<TaskState>) {}
     /// Called when an upload fails for a batch of task states.
     ///
+    /// For multi-file uploads each state carries the result of its own file,
+    /// so [`TaskState::is_success`] separates the files that were uploaded
+    /// from the ones that failed and can be re-run with
+    /// `retry_failed_files`.
+    ///
     /// # Parameters
-    /// * `task_states` - States of the tasks at the time of failure.
+    /// * `task_states` - State of each file at the time of failure.
     fn on_fail_upload(&self, task_states: Vec<TaskState>) {}
     /// Called when a task transitions to a waiting state.
     ///
@@ -25,7 +25,7 @@ use ipc::remote;
 // Download core dependencies
 use request_core::config::{Action, TaskConfig};
 use request_core::filter::SearchFilter;
-use request_core::info::{State, TaskInfo};
+use request_core::info::{HistoryRecord, State, TaskInfo};
 use request_core::interface;
 
 // Local dependencies
@@ -52,6 +52,7 @@ impl RequestProxy {
 
         data.write(&1u32).unwrap();
         data.write(&task_id.to_string()).unwrap();
+        data.write(&interface::INTERFACE_VERSION).unwrap();
 
         let mut reply = remote
             .send_request(interface::QUERY, &mut data)
@@ -101,6 +102,7 @@ impl RequestProxy {
         data.write_interface_token(SERVICE_TOKEN).unwrap();
 
         data.write(&task_id.to_string()).unwrap();
+        data.write(&interface::INTERFACE_VERSION).unwrap();
 
         let mut reply = remote
             .send_request(interface::QUERY_MIME_TYPE, &mut data)
@@ -115,6 +117,41 @@ impl RequestProxy {
         Ok(mime_type)
     }
 
+    /// Queries the file name the server suggested for a task's download.
+    ///
+    /// # Arguments
+    /// * `task_id` - Unique identifier of the task to query
+    ///
+    /// # Returns
+    /// A `Result` containing either:
+    /// - `Ok(String)` with the suggested file name, empty if none
+    /// - `Err(i32)` with an error code if the task doesn't exist or cannot be
+    ///   accessed
+    ///
+    /// # Panics
+    /// - Panics if parcel operations fail due to IPC errors
+    pub(crate) fn query_suggested_name(&self, task_id: i64) -> Result<String, i32> {
+        let remote = self.remote()?;
+
+        let mut data = MsgParcel::new();
+        data.write_interface_token(SERVICE_TOKEN).unwrap();
+
+        data.write(&task_id.to_string()).unwrap();
+        data.write(&interface::INTERFACE_VERSION).unwrap();
+
+        let mut reply = remote
+            .send_request(interface::QUERY_MIME_TYPE, &mut data)
+            .map_err(|_| 13400003)?;
+
+        let code = reply.read::<i32>().unwrap(); // error code
+        if code != 0 {
+            return Err(code);
+        }
+
+        let _mime_type = reply.read::<String>().unwrap();
+        Ok(reply.read::<String>().unwrap_or_default())
+    }
+
     /// Retrieves detailed information about a specific download task.
     ///
     /// # Arguments
@@ -151,6 +188,7 @@ impl RequestProxy {
 
         data.write(&1u32).unwrap();
         data.write(&task_id.to_string()).unwrap();
+        data.write(&interface::INTERFACE_VERSION).unwrap();
 
         let mut reply = remote
             .send_request(interface::SHOW, &mut data)
@@ -191,6 +229,7 @@ impl RequestProxy {
         data.write(&1u32).unwrap();
         data.write(&task_id.to_string()).unwrap();
         data.write(&token).unwrap(); // authentication token
+        data.write(&interface::INTERFACE_VERSION).unwrap();
 
         let mut reply = remote
             .send_request(interface::TOUCH, &mut data)
@@ -347,4 +386,50 @@ impl RequestProxy {
         let task_config = reply.read::<TaskConfig>().unwrap();
         Ok(task_config)
     }
+
+    /// Lists the transfer history of finished tasks, newest first.
+    ///
+    /// # Arguments
+    /// * `bundle_name` - Bundle to list, only honored for system callers with
+    ///   manager permission; `None` lists every bundle
+    /// * `after` - Only records modified after this timestamp (milliseconds)
+    /// * `limit` - Maximum number of records to return
+    ///
+    /// # Returns
+    /// - `Ok(Vec<HistoryRecord>)` with the archived records on success
+    /// - `Err(i32)` with an error code on failure
+    pub(crate) fn query_history(
+        &self,
+        bundle_name: Option<String>,
+        after: i64,
+        limit: u32,
+    ) -> Result<Vec<HistoryRecord>, i32> {
+        let remote = self.remote()?;
+
+        let mut data = MsgParcel::new();
+        data.write_interface_token(SERVICE_TOKEN).unwrap();
+
+        match bundle_name {
+            Some(ref bundle) => data.write(bundle).unwrap(),
+            None => data.write(&"*".to_string()).unwrap(),
+        }
+        data.write(&after).unwrap();
+        data.write(&limit).unwrap();
+
+        let mut reply = remote
+            .send_request(interface::QUERY_HISTORY, &mut data)
+            .map_err(|_| 13400003)?;
+
+        let code = reply.read::<i32>().unwrap(); // error code
+        if code != 0 {
+            return Err(code);
+        }
+
+        let len = reply.read::<u32>().unwrap();
+        let mut records = Vec::with_capacity(len as usize);
+        for _ in 0..len {
+            records.push(reply.read::<HistoryRecord>().map_err(|_| 13400003)?);
+        }
+        Ok(records)
+    }
 }
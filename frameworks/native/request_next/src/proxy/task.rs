@@ -20,7 +20,7 @@
 // IPC and parcel dependencies
 use ipc::parcel::MsgParcel;
 // Download core dependencies
-use request_core::config::TaskConfig;
+use request_core::config::{ChunkedUploadConfig, TaskConfig, TaskDnsConfig};
 use request_core::interface;
 
 // Local dependencies
@@ -430,4 +430,258 @@ impl RequestProxy {
         }
         Ok(())
     }
+
+    /// Re-runs only the files of a failed upload task that failed; files that
+    /// already succeeded are not uploaded again.
+    ///
+    /// # Arguments
+    /// * `task_id` - The unique identifier of the upload task
+    ///
+    /// # Returns
+    /// - `Ok(())` if the task was re-queued
+    /// - `Err(i32)` with the error code if the task could not be retried
+    ///
+    /// # Panics
+    /// - Panics if parcel operations fail due to IPC errors
+    pub(crate) fn retry_failed_files(&self, task_id: i64) -> Result<(), i32> {
+        let remote = self.remote()?;
+
+        let mut data = MsgParcel::new();
+        data.write_interface_token(SERVICE_TOKEN).unwrap();
+
+        data.write(&1u32).unwrap(); // task count
+        data.write(&task_id.to_string()).unwrap();
+
+        let mut reply = remote
+            .send_request(interface::RETRY_FAILED_FILES, &mut data)
+            .map_err(|_| 13400003)?;
+
+        let code = reply.read::<i32>().unwrap(); // error code
+        if code != 0 {
+            return Err(code);
+        }
+        let code = reply.read::<i32>().unwrap(); // error code
+        if code != 0 {
+            return Err(code);
+        }
+        Ok(())
+    }
+
+    /// Sets whether an upload task continues with its remaining files after
+    /// one of them fails, instead of aborting on the first error.
+    ///
+    /// # Arguments
+    /// * `task_id` - The unique identifier of the upload task
+    /// * `continue_on_error` - `true` to continue with the remaining files
+    ///
+    /// # Returns
+    /// - `Ok(())` if the policy was set
+    /// - `Err(i32)` with the error code if setting the policy failed
+    ///
+    /// # Panics
+    /// - Panics if parcel operations fail due to IPC errors
+    pub(crate) fn set_file_error_policy(
+        &self,
+        task_id: i64,
+        continue_on_error: bool,
+    ) -> Result<(), i32> {
+        let remote = self.remote()?;
+
+        let mut data = MsgParcel::new();
+        data.write_interface_token(SERVICE_TOKEN).unwrap();
+
+        data.write(&task_id.to_string()).unwrap();
+        data.write(&continue_on_error).unwrap();
+
+        let mut reply = remote
+            .send_request(interface::SET_FILE_ERROR_POLICY, &mut data)
+            .map_err(|_| 13400003)?;
+
+        let code = reply.read::<i32>().unwrap(); // error code
+        if code != 0 {
+            return Err(code);
+        }
+        Ok(())
+    }
+
+    /// Changes the priority of a task; lower values run first.
+    ///
+    /// # Arguments
+    /// * `task_id` - The unique identifier of the task
+    /// * `priority` - The new priority of the task
+    ///
+    /// # Returns
+    /// - `Ok(())` if the priority was changed
+    /// - `Err(i32)` with the error code if the task could not be changed
+    ///
+    /// # Panics
+    /// - Panics if parcel operations fail due to IPC errors
+    pub(crate) fn set_priority(&self, task_id: i64, priority: u32) -> Result<(), i32> {
+        let remote = self.remote()?;
+
+        let mut data = MsgParcel::new();
+        data.write_interface_token(SERVICE_TOKEN).unwrap();
+
+        data.write(&task_id.to_string()).unwrap();
+        data.write(&priority).unwrap();
+
+        let mut reply = remote
+            .send_request(interface::SET_PRIORITY, &mut data)
+            .map_err(|_| 13400003)?;
+
+        let code = reply.read::<i32>().unwrap(); // error code
+        if code != 0 {
+            return Err(code);
+        }
+        Ok(())
+    }
+
+    /// Moves a waiting task in front of the other tasks of its application.
+    ///
+    /// # Arguments
+    /// * `task_id` - The unique identifier of the task
+    ///
+    /// # Returns
+    /// - `Ok(())` if the task was moved
+    /// - `Err(i32)` with the error code if the task could not be moved
+    ///
+    /// # Panics
+    /// - Panics if parcel operations fail due to IPC errors
+    pub(crate) fn move_to_front(&self, task_id: i64) -> Result<(), i32> {
+        let remote = self.remote()?;
+
+        let mut data = MsgParcel::new();
+        data.write_interface_token(SERVICE_TOKEN).unwrap();
+
+        data.write(&task_id.to_string()).unwrap();
+
+        let mut reply = remote
+            .send_request(interface::MOVE_TO_FRONT, &mut data)
+            .map_err(|_| 13400003)?;
+
+        let code = reply.read::<i32>().unwrap(); // error code
+        if code != 0 {
+            return Err(code);
+        }
+        Ok(())
+    }
+
+    /// Makes a single-file upload task a chunked upload.
+    ///
+    /// # Arguments
+    /// * `task_id` - The unique identifier of the upload task
+    /// * `config` - How the file is split and where each chunk goes
+    ///
+    /// # Returns
+    /// - `Ok(())` if the configuration was set
+    /// - `Err(i32)` with the error code if setting the configuration failed
+    ///
+    /// # Panics
+    /// - Panics if parcel operations fail due to IPC errors
+    pub(crate) fn set_chunked_upload(
+        &self,
+        task_id: i64,
+        config: &ChunkedUploadConfig,
+    ) -> Result<(), i32> {
+        let remote = self.remote()?;
+
+        let mut data = MsgParcel::new();
+        data.write_interface_token(SERVICE_TOKEN).unwrap();
+
+        data.write(&task_id.to_string()).unwrap();
+        data.write(&config.chunk_size).unwrap();
+        data.write(&config.parallelism).unwrap();
+        data.write(&config.max_retries).unwrap();
+        data.write(&config.url).unwrap();
+        data.write(&config.method).unwrap();
+        data.write(&(config.headers.len() as u32)).unwrap();
+        for (key, value) in config.headers.iter() {
+            data.write(key).unwrap();
+            data.write(value).unwrap();
+        }
+        data.write(&config.finalize_url).unwrap();
+        data.write(&config.finalize_method).unwrap();
+        data.write(&config.finalize_body).unwrap();
+        data.write(&config.finalize_part).unwrap();
+
+        let mut reply = remote
+            .send_request(interface::SET_CHUNKED_UPLOAD, &mut data)
+            .map_err(|_| 13400003)?;
+
+        let code = reply.read::<i32>().unwrap(); // error code
+        if code != 0 {
+            return Err(code);
+        }
+        Ok(())
+    }
+
+    /// Makes a download task save its file, once downloaded, into `dir` under
+    /// the name suggested by the server.
+    ///
+    /// # Arguments
+    /// * `task_id` - The unique identifier of the download task
+    /// * `dir` - Sandbox directory to save the file to, empty to keep the file
+    ///   at its configured path
+    ///
+    /// # Returns
+    /// - `Ok(())` if the directory was set
+    /// - `Err(i32)` with the error code if setting the directory failed
+    ///
+    /// # Panics
+    /// - Panics if parcel operations fail due to IPC errors
+    pub(crate) fn set_save_as_suggested_name(&self, task_id: i64, dir: &str) -> Result<(), i32> {
+        let remote = self.remote()?;
+
+        let mut data = MsgParcel::new();
+        data.write_interface_token(SERVICE_TOKEN).unwrap();
+
+        data.write(&task_id.to_string()).unwrap();
+        data.write(&dir.to_string()).unwrap();
+
+        let mut reply = remote
+            .send_request(interface::SET_SAVE_AS_SUGGESTED_NAME, &mut data)
+            .map_err(|_| 13400003)?;
+
+        let code = reply.read::<i32>().unwrap(); // error code
+        if code != 0 {
+            return Err(code);
+        }
+        Ok(())
+    }
+
+    /// Sets the addresses pinned to hosts and the IP preference of a task.
+    ///
+    /// # Arguments
+    /// * `task_id` - The unique identifier of the task
+    /// * `config` - DNS options of the task, empty to remove them
+    ///
+    /// # Returns
+    /// - `Ok(())` if the options were set
+    /// - `Err(i32)` with the error code if setting the options failed
+    ///
+    /// # Panics
+    /// - Panics if parcel operations fail due to IPC errors
+    pub(crate) fn set_task_dns(&self, task_id: i64, config: &TaskDnsConfig) -> Result<(), i32> {
+        let remote = self.remote()?;
+
+        let mut data = MsgParcel::new();
+        data.write_interface_token(SERVICE_TOKEN).unwrap();
+
+        data.write(&task_id.to_string()).unwrap();
+        data.write(&(config.overrides.len() as u32)).unwrap();
+        for value in config.overrides.iter() {
+            data.write(value).unwrap();
+        }
+        data.write(&config.ip_preference).unwrap();
+
+        let mut reply = remote
+            .send_request(interface::SET_TASK_DNS, &mut data)
+            .map_err(|_| 13400003)?;
+
+        let code = reply.read::<i32>().unwrap(); // error code
+        if code != 0 {
+            return Err(code);
+        }
+        Ok(())
+    }
 }
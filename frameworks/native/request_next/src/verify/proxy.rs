@@ -13,7 +13,10 @@
 
 //! Proxy configuration validation for task configuration.
 //!
-//! Validates the proxy URL length, `http://` scheme, and numeric port on API10.
+//! Validates the proxy length and syntax on API10. A proxy is either a single
+//! `http://` or `socks5://` proxy with optional `user:password@` credentials
+//! and a numeric port, or a list of `pattern=target` rules separated by `;`
+//! whose targets are proxies or `DIRECT`.
 
 use request_core::config::{Action, TaskConfig, Version};
 
@@ -21,7 +24,8 @@ use crate::verify::ConfigVerifier;
 
 /// Verifier for the proxy configuration of a task config.
 ///
-/// Validates the proxy URL length, scheme, and port on API10.
+/// Validates the proxy length and the syntax of its proxies or rules on
+/// API10.
 pub struct ProxyVerifier {}
 
 impl ConfigVerifier for ProxyVerifier {
@@ -37,23 +41,44 @@ impl ConfigVerifier for ProxyVerifier {
             error!("proxy length must be less than 512");
             return Err(401);
         }
-        if !config.proxy.starts_with("http://") {
-            error!("ParseProxy error");
-            return Err(401);
-        }
-        let pos = config.proxy.rfind(':').ok_or({
-            error!("ParseProxy error");
-            401
-        })?;
-        let port_str = &config.proxy[pos + 1..];
-        if port_str.len() > 5 || port_str.is_empty() {
-            error!("ParseProxy error");
-            return Err(401);
-        }
-        if !port_str.chars().all(|c| c.is_ascii_digit()) {
+        let valid = if config.proxy.contains('=') {
+            config
+                .proxy
+                .split(';')
+                .filter(|rule| !rule.trim().is_empty())
+                .all(|rule| match rule.split_once('=') {
+                    Some((pattern, target)) => {
+                        let target = target.trim();
+                        !pattern.trim().is_empty()
+                            && (target.eq_ignore_ascii_case("DIRECT") || is_valid_proxy(target))
+                    }
+                    None => false,
+                })
+        } else {
+            is_valid_proxy(&config.proxy)
+        };
+        if !valid {
             error!("ParseProxy error");
             return Err(401);
         }
         Ok(())
     }
 }
+
+/// Checks a single proxy: an `http://` or `socks5://` scheme, optional
+/// credentials, a host and a nonzero numeric port.
+fn is_valid_proxy(proxy: &str) -> bool {
+    let Some(rest) = proxy
+        .strip_prefix("http://")
+        .or_else(|| proxy.strip_prefix("socks5://"))
+    else {
+        return false;
+    };
+    let authority = rest.rsplit_once('@').map_or(rest, |(_, authority)| authority);
+    let Some((host, port)) = authority.rsplit_once(':') else {
+        return false;
+    };
+    !host.is_empty()
+        && port.chars().all(|c| c.is_ascii_digit())
+        && port.parse::<u16>().is_ok_and(|port| port != 0)
+}
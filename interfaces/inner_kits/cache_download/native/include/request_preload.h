@@ -22,6 +22,7 @@
 #include <optional>
 #include <string>
 #include <tuple>
+#include <unordered_map>
 #include <vector>
 
 namespace rust {
@@ -255,8 +256,105 @@ struct PreloadCallback {
     std::function<void()> OnCancel;
     /// Download progress callback; current is downloaded bytes, total is total resource bytes.
     std::function<void(uint64_t current, uint64_t total)> OnProgress;
+    /// Revalidation callback of an update of cached content: after OnSuccess returned the cached
+    /// copy, returns the downloaded data if it differs from the cached copy.
+    std::function<void(const std::shared_ptr<Data> &&, const std::string &TaskId)> OnUpdated;
 };
 
+/**
+ * @brief Storage tier of a cache entry.
+ */
+enum class CacheTier : int32_t {
+    /// In-memory cache
+    RAM = 0,
+    /// Cache file on disk
+    FILE = 1,
+    /// Segments of a resource cached by byte ranges, on disk
+    PARTIAL = 2,
+};
+
+/**
+ * @brief Counters of the cache activity since the process started.
+ */
+struct CacheCounters {
+    /// Lookups served from the memory cache
+    uint64_t ramHits;
+    /// Lookups served from cache files or partial entries
+    uint64_t fileHits;
+    /// Downloads of resources that were not cached, or forced to update
+    uint64_t misses;
+    /// Bytes served from the memory cache
+    uint64_t ramBytes;
+    /// Bytes served from cache files or partial entries
+    uint64_t fileBytes;
+    /// Bytes received from the network
+    uint64_t networkBytes;
+    /// Entries evicted from the memory cache to make room for others
+    uint64_t ramEvictions;
+    /// Entries evicted from the file cache to make room for others
+    uint64_t fileEvictions;
+};
+
+/**
+ * @brief Occupancy of a cache tier.
+ */
+struct TierUsage {
+    /// Bytes used
+    uint64_t used;
+    /// Bytes available to the tier
+    uint64_t capacity;
+    /// Number of entries
+    uint64_t entries;
+};
+
+/**
+ * @brief Statistics of a named cache namespace.
+ */
+struct NamespaceStats {
+    /// Counters of the entries of the namespace
+    CacheCounters counters;
+    /// Occupancy of the file cache budget of the namespace
+    TierUsage file;
+};
+
+/**
+ * @brief Snapshot of the cache statistics.
+ */
+struct CacheStats {
+    /// Counters of all the entries, whatever their namespace
+    CacheCounters counters;
+    /// Occupancy of the memory cache
+    TierUsage ram;
+    /// Occupancy of the file caches of all the namespaces, pinned entries excluded
+    TierUsage file;
+    /// Occupancy of the pinned entries
+    TierUsage pinned;
+    /// Statistics of the named namespaces, by name
+    std::unordered_map<std::string, NamespaceStats> namespaces;
+};
+
+/**
+ * @brief Cached entry, as listed by Preload::GetCacheEntries().
+ */
+struct CacheEntry {
+    /// Cache key of the entry
+    std::string taskId;
+    /// Namespace of the entry, empty for the default namespace
+    std::string nameSpace;
+    /// Tier holding the entry
+    CacheTier tier;
+    /// Size of the entry in bytes
+    uint64_t size;
+    /// Time the entry was last stored or served, in milliseconds since the Unix epoch
+    uint64_t lastAccess;
+};
+
+/**
+ * @brief Listener of the entries evicted to make room for others, called on a
+ * background thread.
+ */
+using EvictionListener = std::function<void(const CacheEntry &entry)>;
+
 /**
  * @brief Preload task handle (move-only), used to query state or actively
  * cancel after the task completes.
@@ -295,6 +393,10 @@ struct PreloadOptions {
     RetryOptions retry;
     /// Timeout configuration
     TimeoutOptions timeout;
+    /// Priority in the preload queue, higher first; 0 by default
+    int32_t priority;
+    /// Tags of the preload, for cancelling preloads by group
+    std::vector<std::string> tags;
 };
 
 /**
@@ -316,15 +418,25 @@ public:
     virtual ~Preload() = default;
     /// Cancel the preload task for the specified URL
     void Cancel(std::string const &url);
+    /// Cancel the queued and running preload tasks with the specified tag
+    void CancelTag(std::string const &tag);
+    /// Change the priority of the queued preload task for the specified URL; false if it is not queued
+    bool Reprioritize(std::string const &url, int32_t priority);
     /// Remove the preload task and its cache for the specified URL
     void Remove(std::string const &url);
     /// Whether a preload task or cache exists for the specified URL
     bool Contains(std::string const &url);
+    /// Pin the cache for the specified URL so that it is not evicted until unpinned; false if it cannot be pinned
+    bool Pin(std::string const &url);
+    /// Unpin the cache for the specified URL; false if it was not pinned
+    bool Unpin(std::string const &url);
 
     /// Set the memory cache capacity upper limit (bytes)
     void SetRamCacheSize(uint64_t size);
     /// Set the file cache capacity upper limit (bytes)
     void SetFileCacheSize(uint64_t size);
+    /// Set the capacity upper limit of the pinned caches (bytes)
+    void SetPinnedCacheSize(uint64_t size);
     /// Set the maximum number of entries in the download info list
     void SetDownloadInfoListSize(uint16_t size);
     /// Set the file cache directory (static, takes effect globally)
@@ -334,6 +446,20 @@ public:
     void ClearMemoryCache();
     /// Clear the file cache
     void ClearFileCache();
+    /// Import an archive of prefetched responses into the file cache; returns the number imported, -1 on error
+    int64_t ImportCache(std::string const &path);
+    /// Export the file cache to an archive; returns the number exported, -1 on error
+    int64_t ExportCache(std::string const &path);
+
+    /// Set the maximum number of preload tasks downloading at the same time (default 6)
+    void SetMaxConcurrentDownloads(uint32_t max);
+
+    /// Get a snapshot of the cache statistics
+    CacheStats GetCacheStats();
+    /// List the cached entries; an entry held in memory and on disk is listed once per tier
+    std::vector<CacheEntry> GetCacheEntries();
+    /// Set the listener of evicted entries; an empty listener removes the current one
+    void SetEvictionListener(EvictionListener listener);
 
     /// Set the global retry policy
     void SetGlobalRetryOptions(const RetryOptions &options);
@@ -345,7 +471,8 @@ public:
      * @param url Resource address to be preloaded.
      * @param callback Task event callback, must not be null.
      * @param options Optional configuration for this request; when null, global config is used.
-     * @param update Whether to force-refresh cached content (true means re-download even if cached).
+     * @param update Whether to refresh cached content; cached content is returned at once and
+     *        downloaded again in the background, with OnUpdated called if it changed.
      * @return Task handle, which can be used to cancel or query state later.
      */
     std::shared_ptr<PreloadHandle> load(std::string const &url, std::unique_ptr<PreloadCallback>,
@@ -124,6 +124,8 @@ constexpr const char *REQUEST_TASK_TABLE_ADD_TOTAL_TIMEOUT = "ALTER TABLE reques
                                                              "INTEGER";
 constexpr const char *REQUEST_TASK_TABLE_ADD_TASK_TIME = "ALTER TABLE request_task ADD COLUMN task_time "
                                                          "INTEGER";
+constexpr const char *REQUEST_TASK_TABLE_ADD_CONTINUE_ON_FILE_ERROR = "ALTER TABLE request_task ADD COLUMN "
+                                                                      "continue_on_file_error INTEGER";
 
 constexpr const char *REQUEST_TASK_TABLE_COL_PROXY = "proxy";
 constexpr const char *REQUEST_TASK_TABLE_COL_CERTIFICATE_PINS = "certificate_pins";
@@ -136,6 +138,7 @@ constexpr const char *REQUEST_TASK_TABLE_COL_MIN_SPEED_DURATION = "min_speed_dur
 constexpr const char *REQUEST_TASK_TABLE_COL_CONNECTION_TIMEOUT = "connection_timeout";
 constexpr const char *REQUEST_TASK_TABLE_COL_TOTAL_TIMEOUT = "total_timeout";
 constexpr const char *REQUEST_TASK_TABLE_COL_TASK_TIME = "task_time";
+constexpr const char *REQUEST_TASK_TABLE_COL_CONTINUE_ON_FILE_ERROR = "continue_on_file_error";
 
 struct TaskFilter;
 struct NetworkInfo;
@@ -191,6 +194,7 @@ struct CVectorWrapper {
 bool RecordRequestTask(CTaskInfo *taskInfo, CTaskConfig *taskConfig);
 bool UpdateRequestTask(uint32_t taskId, CUpdateInfo *updateInfo);
 bool UpdateRequestTaskTime(uint32_t taskId, uint64_t taskTime);
+bool UpdateRequestTaskFileSpecs(uint32_t taskId, const CFileSpec *fileSpecsPtr, uint32_t fileSpecsLen);
 bool UpdateRequestTaskState(uint32_t taskId, CUpdateStateInfo *updateStateInfo);
 void RequestDBRemoveRecordsFromTime(uint64_t time);
 CTaskInfo *GetTaskInfo(uint32_t taskId);
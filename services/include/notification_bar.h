@@ -27,8 +27,10 @@ namespace OHOS::Request {
 struct TaskManagerWrapper;
 struct NotifyContent;
 struct ProgressCircle;
+struct OpenTarget;
 
 rust::string GetSystemResourceString(const rust::str);
+rust::string GetSystemResourcePluralString(const rust::str, int32_t quantity);
 rust::string GetSystemLanguage();
 int PublishNotification(const NotifyContent &content);
 
@@ -48,10 +50,7 @@ private:
 
 void SubscribeNotification(rust::Box<TaskManagerWrapper> taskManager);
 
-inline int32_t CancelNotification(uint32_t notificationId)
-{
-    return Notification::NotificationHelper::CancelNotification(notificationId);
-}
+int32_t CancelNotification(uint32_t notificationId);
 
 std::string GetCurrentSystemColorMode();
 
@@ -59,6 +58,10 @@ std::shared_ptr<Media::PixelMap> CreatePixelMapByColorMode(const std::string& co
 
 const char* GetIconPathByColorMode(const std::string& colorMode);
 
+std::shared_ptr<Media::PixelMap> CreateButtonPixelMap(const std::string &buttonName);
+
+int32_t OpenFile(const OpenTarget &target);
+
 void RepublishProgressNotify();
 
 } // namespace OHOS::Request
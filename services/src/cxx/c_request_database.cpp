@@ -590,6 +590,9 @@ void RequestDBUpgradeFrom60(OHOS::NativeRdb::RdbStore &store)
     if (!ColumnExists(store, REQUEST_TASK_TABLE_COL_TASK_TIME)) {
         store.ExecuteSql(REQUEST_TASK_TABLE_ADD_TASK_TIME);
     }
+    if (!ColumnExists(store, REQUEST_TASK_TABLE_COL_CONTINUE_ON_FILE_ERROR)) {
+        store.ExecuteSql(REQUEST_TASK_TABLE_ADD_CONTINUE_ON_FILE_ERROR);
+    }
 }
 
 int RequestDBUpgrade(OHOS::NativeRdb::RdbStore &store)
@@ -1171,6 +1174,21 @@ bool UpdateRequestTaskTime(uint32_t taskId, uint64_t taskTime)
     return true;
 }
 
+bool UpdateRequestTaskFileSpecs(uint32_t taskId, const CFileSpec *fileSpecsPtr, uint32_t fileSpecsLen)
+{
+    REQUEST_HILOGD("update request task file specs");
+    OHOS::NativeRdb::ValuesBucket values;
+    values.PutBlob("file_specs", CFileSpecToBlob(fileSpecsPtr, fileSpecsLen));
+
+    OHOS::NativeRdb::RdbPredicates rdbPredicates("request_task");
+    rdbPredicates.EqualTo("task_id", std::to_string(taskId));
+    if (!OHOS::Request::RequestDataBase::GetInstance(OHOS::Request::DB_NAME, true).Update(values, rdbPredicates)) {
+        REQUEST_HILOGE("update request task file specs failed, task_id: %{public}d", taskId);
+        return false;
+    }
+    return true;
+}
+
 bool UpdateRequestTaskState(uint32_t taskId, CUpdateStateInfo *updateStateInfo)
 {
     REQUEST_HILOGD("Change task state, tid: %{public}d, state is %{public}d", taskId, updateStateInfo->state);
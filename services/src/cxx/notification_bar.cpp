@@ -17,7 +17,10 @@
 
 #include <cstddef>
 #include <cstdint>
+#include <map>
+#include <mutex>
 #include <string>
+#include <unordered_set>
 #include <dlfcn.h>
 
 #include "cxx.h"
@@ -32,6 +35,8 @@
 #include "service/notification_bar/mod.rs.h"
 #include "task/config.rs.h"
 
+#include "ability_manager_client.h"
+#include "want.h"
 #include "want_agent_helper.h"
 #include "sys_event.h"
 #include "system_ability_definition.h"
@@ -56,9 +61,42 @@ static constexpr int32_t REQUEST_STYLE_SIMPLE = 8;
 
 static const std::string CLOSE_ICON_PATH = "/etc/request/xmark.svg";
 static const std::string CLOSE_ICON_PATH_DARK = "/etc/request/xmark_dark.svg";
+static const std::string RETRY_ICON_PATH = "/etc/request/retry.svg";
+static const std::string RETRY_ICON_PATH_DARK = "/etc/request/retry_dark.svg";
+static const std::string OPEN_ICON_PATH = "/etc/request/open.svg";
+static const std::string OPEN_ICON_PATH_DARK = "/etc/request/open_dark.svg";
+
+static const std::string BUTTON_CANCEL = "cancel";
+static const std::string BUTTON_RETRY = "retry";
+static const std::string BUTTON_RETRY_ALL = "retry_all";
+static const std::string BUTTON_OPEN = "open";
+static const std::string BUTTON_DISMISS = "dismiss";
+
+static constexpr const char *ACTION_VIEW_DATA = "ohos.want.action.viewData";
+static constexpr int32_t UID_TRANSFORM_DIVISOR = 200000;
 
 constexpr const char* SYSTEM_COLORMODE = "persist.ace.darkmode";
 
+// IDs of the progress notifications currently shown, the only ones re-published when the color mode changes
+static std::mutex g_progressIdsMutex;
+static std::unordered_set<int32_t> g_progressIds;
+
+void TrackProgressNotification(int32_t notificationId, bool inProgress)
+{
+    std::lock_guard<std::mutex> lock(g_progressIdsMutex);
+    if (inProgress) {
+        g_progressIds.insert(notificationId);
+    } else {
+        g_progressIds.erase(notificationId);
+    }
+}
+
+bool IsProgressNotification(int32_t notificationId)
+{
+    std::lock_guard<std::mutex> lock(g_progressIdsMutex);
+    return g_progressIds.count(notificationId) != 0;
+}
+
 const std::vector<std::string> RESOURCE_STRING_KEYS = {
     "request_agent_download_file",
     "request_agent_download_success",
@@ -67,11 +105,24 @@ const std::vector<std::string> RESOURCE_STRING_KEYS = {
     "request_agent_upload_success",
     "request_agent_upload_fail",
     "request_agent_task_count",
-    "request_agent_download_complete"
+    "request_agent_download_complete",
+    "request_agent_upload_complete",
+    "request_agent_task_summary",
+    "request_agent_fail_text",
+    "request_agent_progress_detail",
+    "request_agent_reason_network",
+    "request_agent_reason_connection",
+    "request_agent_reason_timeout",
+    "request_agent_reason_server",
+    "request_agent_reason_storage",
+    "request_agent_reason_file",
+    "request_agent_reason_interrupted",
+    "request_agent_reason_unknown"
 };
 
 struct RequestSystemResourceStringInfo {
     std::unordered_map<std::string, std::string> stringMap;
+    std::map<std::pair<std::string, int32_t>, std::string> pluralStringMap;
     std::string curSystemLanguage;
     std::mutex mtx;
 };
@@ -84,9 +135,23 @@ bool IsSystemLanguageConsistent(const std::string &curLanguage)
     return g_resourceStringInfo.curSystemLanguage == curLanguage;
 }
 
-/* Updating the System Resource String Cache */
-int UpdateSystemResourceStringMap(std::string &curLanguage)
+/* Clearing the caches when the system language changes */
+void RefreshSystemLanguage(const std::string &curLanguage)
 {
+    if (IsSystemLanguageConsistent(curLanguage)) {
+        return;
+    }
+    g_resourceStringInfo.curSystemLanguage = curLanguage;
+    g_resourceStringInfo.stringMap.clear();
+    g_resourceStringInfo.pluralStringMap.clear();
+}
+
+/* Loading the resource strings missing from the cache, a string failing to load is retried on next use */
+int UpdateSystemResourceStringMap(const std::string &curLanguage)
+{
+    if (g_resourceStringInfo.stringMap.size() == RESOURCE_STRING_KEYS.size()) {
+        return 0;
+    }
     auto resourceMgr = Resource::GetSystemResourceManagerNoSandBox();
     if (resourceMgr == nullptr) {
         REQUEST_HILOGE("GetSystemResourceManagerNoSandBox failed");
@@ -95,6 +160,7 @@ int UpdateSystemResourceStringMap(std::string &curLanguage)
     std::unique_ptr<Resource::ResConfig> config(Resource::CreateResConfig());
     if (config == nullptr) {
         REQUEST_HILOGE("Create ResConfig failed");
+        Resource::ReleaseSystemResourceManager();
         return -1;
     }
     UErrorCode status = U_ZERO_ERROR;
@@ -102,19 +168,17 @@ int UpdateSystemResourceStringMap(std::string &curLanguage)
     config->SetLocaleInfo(locale);
     resourceMgr->UpdateResConfig(*config);
 
-    g_resourceStringInfo.curSystemLanguage = curLanguage.c_str();
-
-    std::string outValue;
-    for (size_t i = 0; i < RESOURCE_STRING_KEYS.size(); ++i) {
-        auto ret = resourceMgr->GetStringByName(RESOURCE_STRING_KEYS[i].c_str(), outValue);
+    for (const auto &key : RESOURCE_STRING_KEYS) {
+        if (g_resourceStringInfo.stringMap.find(key) != g_resourceStringInfo.stringMap.end()) {
+            continue;
+        }
+        std::string outValue;
+        auto ret = resourceMgr->GetStringByName(key.c_str(), outValue);
         if (ret != Resource::RState::SUCCESS) {
-            REQUEST_HILOGE("Get system resource string %{public}s failed: %{public}d",
-                RESOURCE_STRING_KEYS[i].c_str(), ret);
-
-            /* If an item fails to be obtained, it is marked as the initial state and will be retried next time */
-            g_resourceStringInfo.curSystemLanguage.clear();
+            REQUEST_HILOGE("Get system resource string %{public}s failed: %{public}d", key.c_str(), ret);
+            continue;
         }
-        g_resourceStringInfo.stringMap[RESOURCE_STRING_KEYS[i]] = outValue;
+        g_resourceStringInfo.stringMap[key] = outValue;
     }
 
     Resource::ReleaseSystemResourceManager();
@@ -163,16 +227,59 @@ rust::string GetSystemResourceString(const rust::str name)
     char curLanguage[256] = "zh-Hans";
 
     DynamicGetSystemLanguage(curLanguage, sizeof(curLanguage));
-    if (IsSystemLanguageConsistent(curLanguage) != true) {
-        /* Language change or initialization */
-        std::string strCurLanguage(curLanguage);
-        int ret = UpdateSystemResourceStringMap(strCurLanguage);
-        if (ret != 0) {
-            return "";
-        }
+    std::string strCurLanguage(curLanguage);
+    RefreshSystemLanguage(strCurLanguage);
+    if (UpdateSystemResourceStringMap(strCurLanguage) != 0) {
+        return "";
     }
 
-    return rust::string(g_resourceStringInfo.stringMap[name.data()]);
+    /* A string that failed to load is returned empty, the caller falls back on its default text */
+    auto it = g_resourceStringInfo.stringMap.find(std::string(name.data(), name.size()));
+    if (it == g_resourceStringInfo.stringMap.end()) {
+        return "";
+    }
+    return rust::string(it->second);
+}
+
+rust::string GetSystemResourcePluralString(const rust::str name, int32_t quantity)
+{
+    std::lock_guard<std::mutex> lock(g_resourceStringInfo.mtx);
+    char curLanguage[256] = "zh-Hans";
+
+    DynamicGetSystemLanguage(curLanguage, sizeof(curLanguage));
+    RefreshSystemLanguage(std::string(curLanguage));
+
+    auto key = std::make_pair(std::string(name.data(), name.size()), quantity);
+    auto it = g_resourceStringInfo.pluralStringMap.find(key);
+    if (it != g_resourceStringInfo.pluralStringMap.end()) {
+        return rust::string(it->second);
+    }
+
+    auto resourceMgr = Resource::GetSystemResourceManagerNoSandBox();
+    if (resourceMgr == nullptr) {
+        REQUEST_HILOGE("GetSystemResourceManagerNoSandBox failed");
+        return "";
+    }
+    std::unique_ptr<Resource::ResConfig> config(Resource::CreateResConfig());
+    if (config == nullptr) {
+        REQUEST_HILOGE("Create ResConfig failed");
+        Resource::ReleaseSystemResourceManager();
+        return "";
+    }
+    UErrorCode status = U_ZERO_ERROR;
+    icu::Locale locale = icu::Locale::forLanguageTag(curLanguage, status);
+    config->SetLocaleInfo(locale);
+    resourceMgr->UpdateResConfig(*config);
+
+    std::string outValue;
+    auto ret = resourceMgr->GetPluralStringByName(key.first.c_str(), quantity, outValue);
+    Resource::ReleaseSystemResourceManager();
+    if (ret != Resource::RState::SUCCESS) {
+        REQUEST_HILOGE("Get system resource plural string %{public}s failed: %{public}d", key.first.c_str(), ret);
+        return "";
+    }
+    g_resourceStringInfo.pluralStringMap[key] = outValue;
+    return rust::string(outValue);
 }
 
 rust::string GetSystemLanguage()
@@ -267,6 +374,79 @@ const char* GetIconPathByColorMode(const std::string& colorMode)
            : CLOSE_ICON_PATH.c_str();
 }
 
+const char *GetButtonIconPath(const std::string &buttonName, const std::string &colorMode)
+{
+    bool dark = colorMode == AppExecFwk::ConfigurationInner::COLOR_MODE_DARK;
+    if (buttonName == BUTTON_RETRY || buttonName == BUTTON_RETRY_ALL) {
+        return dark ? RETRY_ICON_PATH_DARK.c_str() : RETRY_ICON_PATH.c_str();
+    }
+    if (buttonName == BUTTON_OPEN) {
+        return dark ? OPEN_ICON_PATH_DARK.c_str() : OPEN_ICON_PATH.c_str();
+    }
+    return GetIconPathByColorMode(colorMode);
+}
+
+std::shared_ptr<Media::PixelMap> CreateButtonPixelMap(const std::string &buttonName)
+{
+    if (buttonName == BUTTON_CANCEL) {
+        return CreatePixelMap();
+    }
+    static std::unordered_map<std::string, std::shared_ptr<Media::PixelMap>> cachedPixelMaps;
+    static std::mutex updateMutex;
+    static std::string cachedColorMode;
+
+    std::unique_lock<std::mutex> lock(updateMutex);
+
+    auto currentColorMode = GetCurrentSystemColorMode();
+    if (cachedColorMode != currentColorMode) {
+        cachedPixelMaps.clear();
+        cachedColorMode = currentColorMode;
+    }
+    auto it = cachedPixelMaps.find(buttonName);
+    if (it != cachedPixelMaps.end()) {
+        return it->second;
+    }
+
+    const char *iconPath = GetButtonIconPath(buttonName, currentColorMode);
+    Media::SourceOptions opts;
+    uint32_t errorCode = 0;
+    auto source = Media::ImageSource::CreateImageSource(iconPath, opts, errorCode);
+    if (source == nullptr) {
+        REQUEST_HILOGE("create image source failed for path: %{public}s", iconPath);
+        return nullptr;
+    }
+    Media::DecodeOptions decodeOpts;
+    std::shared_ptr<Media::PixelMap> pixel = source->CreatePixelMap(decodeOpts, errorCode);
+    if (pixel == nullptr) {
+        REQUEST_HILOGE("create pixel map failed, error: %{public}u", errorCode);
+        return nullptr;
+    }
+    cachedPixelMaps[buttonName] = pixel;
+    return pixel;
+}
+
+int32_t OpenFile(const OpenTarget &target)
+{
+    if (target.uri.empty()) {
+        REQUEST_HILOGE("no file to open");
+        return -1;
+    }
+    AAFwk::Want want;
+    want.SetAction(ACTION_VIEW_DATA);
+    if (target.mime_type.empty()) {
+        want.SetUri(std::string(target.uri));
+    } else {
+        want.SetUriAndType(std::string(target.uri), std::string(target.mime_type));
+    }
+    want.AddFlags(AAFwk::Want::FLAG_AUTH_READ_URI_PERMISSION);
+    int32_t userId = static_cast<int32_t>(target.uid / UID_TRANSFORM_DIVISOR);
+    ErrCode ret = AAFwk::AbilityManagerClient::GetInstance()->StartAbility(want, userId);
+    if (ret != ERR_OK) {
+        REQUEST_HILOGE("open file failed, ret: %{public}d", ret);
+    }
+    return ret;
+}
+
 void BasicRequestSettings(Notification::NotificationRequest &request, int32_t uid)
 {
     request.SetCreatorUid(REQUEST_SERVICE_ID);
@@ -292,10 +472,23 @@ std::shared_ptr<OHOS::Notification::NotificationContent> LiveViewContent(const N
     liveViewContent->SetText(std::string(content.text));
     liveViewContent->SetTitle(std::string(content.title));
 
-    if (content.x_mark || content.progress_circle.open) {
+    if (content.x_mark || content.progress_circle.open || !content.buttons.empty()) {
         liveViewContent->addFlag(Notification::NotificationLocalLiveViewContent::LiveViewContentInner::BUTTON);
     }
 
+    if (!content.buttons.empty()) {
+        auto button = liveViewContent->GetButton();
+        for (const auto &name : content.buttons) {
+            auto icon = CreateButtonPixelMap(std::string(name));
+            if (icon == nullptr) {
+                continue;
+            }
+            button.addSingleButtonName(std::string(name));
+            button.addSingleButtonIcon(icon);
+        }
+        liveViewContent->SetButton(button);
+    }
+
     if (content.x_mark) {
         auto button = liveViewContent->GetButton();
         auto icon = CreatePixelMap();
@@ -323,7 +516,7 @@ int PublishNotification(const NotifyContent &content)
     Notification::NotificationRequest request(content.request_id);
     BasicRequestSettings(request, content.uid);
     request.SetInProgress(content.progress_circle.open);
-    if (content.live_view) {
+    if (content.live_view || !content.buttons.empty()) {
         request.SetSlotType(Notification::NotificationConstant::SlotType::LIVE_VIEW);
         request.SetContent(LiveViewContent(content));
     } else {
@@ -333,9 +526,16 @@ int PublishNotification(const NotifyContent &content)
         request.SetWantAgent(
             OHOS::AbilityRuntime::WantAgent::WantAgentHelper::FromString(std::string(content.want_agent)));
     }
+    TrackProgressNotification(static_cast<int32_t>(content.request_id), content.progress_circle.open);
     return Notification::NotificationHelper::PublishNotification(request);
 }
 
+int32_t CancelNotification(uint32_t notificationId)
+{
+    TrackProgressNotification(static_cast<int32_t>(notificationId), false);
+    return Notification::NotificationHelper::CancelNotification(notificationId);
+}
+
 NotificationSubscriber::NotificationSubscriber(rust::Box<TaskManagerWrapper> taskManager)
     : _taskManager(std::move(taskManager)){};
 
@@ -356,9 +556,20 @@ void NotificationSubscriber::OnResponse(
         this->_taskManager->pause_task(static_cast<uint32_t>(notificationId));
     } else if (buttonOption->GetButtonName() == "start") {
         this->_taskManager->resume_task(static_cast<uint32_t>(notificationId));
-    } else if (buttonOption->GetButtonName() == "cancel") {
+    } else if (buttonOption->GetButtonName() == BUTTON_CANCEL) {
         this->_taskManager->stop_task(static_cast<uint32_t>(notificationId));
-        Notification::NotificationHelper::CancelNotification(notificationId);
+        CancelNotification(static_cast<uint32_t>(notificationId));
+    } else if (buttonOption->GetButtonName() == BUTTON_RETRY) {
+        this->_taskManager->retry_task(static_cast<uint32_t>(notificationId));
+    } else if (buttonOption->GetButtonName() == BUTTON_RETRY_ALL) {
+        this->_taskManager->retry_group(static_cast<uint32_t>(notificationId));
+    } else if (buttonOption->GetButtonName() == BUTTON_OPEN) {
+        auto target = this->_taskManager->open_target(static_cast<uint32_t>(notificationId));
+        if (OpenFile(target) == ERR_OK) {
+            CancelNotification(static_cast<uint32_t>(notificationId));
+        }
+    } else if (buttonOption->GetButtonName() == BUTTON_DISMISS) {
+        CancelNotification(static_cast<uint32_t>(notificationId));
     }
 };
 
@@ -398,7 +609,8 @@ void RepublishProgressNotify()
         int32_t notificationId = notificationRequest->GetNotificationId();
 
         if (notificationRequest->GetCreatorUid() != REQUEST_SERVICE_ID ||
-            notificationRequest->GetSlotType() != Notification::NotificationConstant::SlotType::LIVE_VIEW) {
+            notificationRequest->GetSlotType() != Notification::NotificationConstant::SlotType::LIVE_VIEW ||
+            !IsProgressNotification(notificationId)) {
             continue;
         }
         
@@ -416,18 +628,22 @@ void RepublishProgressNotify()
             continue;
         }
         
-        auto icon = CreatePixelMap();
-        if (icon != nullptr) {
-            auto button = liveViewContent->GetButton();
-            if (button.GetAllButtonNames().empty() || button.GetAllButtonIcons().empty()) {
+        auto names = liveViewContent->GetButton().GetAllButtonNames();
+        if (names.empty()) {
+            continue;
+        }
+        REQUEST_HILOGI("Re-publishing notification for id: %{public}d", notificationId);
+        // Names and icons are rebuilt together, a button whose icon fails to load is dropped as a whole
+        Notification::NotificationLocalLiveViewButton button;
+        for (const auto &name : names) {
+            auto icon = CreateButtonPixelMap(name);
+            if (icon == nullptr) {
                 continue;
             }
-            REQUEST_HILOGI("Re-publishing notification for id: %{public}d", notificationId);
-            button.ClearButtonIcons();
-            button.addSingleButtonName("cancel");
+            button.addSingleButtonName(name);
             button.addSingleButtonIcon(icon);
-            liveViewContent->SetButton(button);
         }
+        liveViewContent->SetButton(button);
         
         notificationRequest->SetContent(std::make_shared<Notification::NotificationContent>(liveViewContent));
         Notification::NotificationHelper::PublishContinuousTaskNotification(*notificationRequest);
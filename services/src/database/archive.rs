@@ -0,0 +1,329 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Transfer history archive for finished tasks.
+//!
+//! Before a task is purged from `request_task`, a compact record of it is
+//! copied into `request_task_archive`. The archive is trimmed by a
+//! configurable retention policy and can be queried by apps and system tools
+//! after the live task record is gone.
+//!
+//! Removed and expired tasks are archived when the database maintenance purges
+//! them. The tasks of an uninstalled app or a removed account are deleted
+//! without being archived, and their existing records are deleted from the
+//! archive as well, see [`delete_uid_history`] and [`delete_account_history`].
+
+use std::sync::LazyLock;
+
+use rdb::RdbStore;
+
+use super::REQUEST_DB;
+use crate::manage::query::SearchMethod;
+
+const CREATE_ARCHIVE_TABLE: &str = "CREATE TABLE IF NOT EXISTS request_task_archive (task_id INTEGER PRIMARY KEY, uid INTEGER, bundle TEXT, url_host TEXT, action INTEGER, state INTEGER, reason INTEGER, total_processed INTEGER, ctime INTEGER, mtime INTEGER, task_time INTEGER, tries INTEGER)";
+
+const CREATE_ARCHIVE_INDEX: &str = "CREATE INDEX IF NOT EXISTS request_task_archive_uid_mtime ON request_task_archive (uid, mtime)";
+
+const CREATE_RETENTION_TABLE: &str = "CREATE TABLE IF NOT EXISTS request_task_archive_retention (id INTEGER PRIMARY KEY CHECK (id = 0), max_age INTEGER, max_rows_per_app INTEGER)";
+
+/// Default maximum age of an archive record, 30 days in milliseconds.
+const DEFAULT_MAX_AGE: u64 = 30 * 24 * 60 * 60 * 1000;
+
+/// Default maximum number of archive records kept per app.
+const DEFAULT_MAX_ROWS_PER_APP: u64 = 500;
+
+/// Row layout of `request_task_archive` as read by `query_history`.
+type ArchiveRow = (u32, u64, String, String, u32, u32, u32, i64, u64, u64, u64, u32);
+
+/// Upper bound of records returned by a single history query.
+pub(crate) const MAX_HISTORY_QUERY: u32 = 1000;
+
+static ARCHIVE_INIT: LazyLock<bool> = LazyLock::new(|| {
+    let res = REQUEST_DB.transaction(|tx| {
+        tx.execute(CREATE_ARCHIVE_TABLE, ())?;
+        tx.execute(CREATE_ARCHIVE_INDEX, ())?;
+        tx.execute(CREATE_RETENTION_TABLE, ())
+    });
+    if let Err(e) = res {
+        error!("Failed to create archive tables: {}", e);
+        return false;
+    }
+    true
+});
+
+/// Compact record of a task that has left `request_task`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ArchiveRecord {
+    /// Id of the archived task.
+    pub(crate) task_id: u32,
+    /// Uid of the app that created the task.
+    pub(crate) uid: u64,
+    /// Bundle name of the app that created the task.
+    pub(crate) bundle: String,
+    /// Host of the task url, without scheme, credentials, path or query.
+    pub(crate) url_host: String,
+    /// `Action` repr of the task.
+    pub(crate) action: u8,
+    /// Final `State` repr of the task.
+    pub(crate) state: u8,
+    /// Final `Reason` repr of the task.
+    pub(crate) reason: u8,
+    /// Total bytes transferred.
+    pub(crate) total_processed: i64,
+    /// Creation time in milliseconds.
+    pub(crate) ctime: u64,
+    /// Last modification time in milliseconds.
+    pub(crate) mtime: u64,
+    /// Time spent transferring, in seconds.
+    pub(crate) task_time: u64,
+    /// Number of attempts made.
+    pub(crate) tries: u32,
+}
+
+impl ArchiveRecord {
+    /// Wall-clock time between task creation and its final state change, in
+    /// milliseconds.
+    pub(crate) fn elapsed(&self) -> u64 {
+        self.mtime.saturating_sub(self.ctime)
+    }
+}
+
+/// Retention policy applied to the archive during database maintenance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RetentionPolicy {
+    /// Records older than this, in milliseconds, are deleted.
+    pub(crate) max_age: u64,
+    /// Only the newest records up to this count are kept per app.
+    pub(crate) max_rows_per_app: u64,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            max_age: DEFAULT_MAX_AGE,
+            max_rows_per_app: DEFAULT_MAX_ROWS_PER_APP,
+        }
+    }
+}
+
+/// Copies a compact record of `task_id` from `request_task` into the archive.
+///
+/// Must run on the same store (usually inside the same transaction) as the
+/// statement deleting the live record.
+///
+/// # Errors
+///
+/// Returns the database error code if the task could not be read or the
+/// record could not be written.
+pub(crate) fn archive_task(rdb: &RdbStore, task_id: u32) -> Result<(), i32> {
+    if !*ARCHIVE_INIT {
+        return Err(-1);
+    }
+    let mut rows = rdb.query::<(u64, String, String, u32, u32, u32, i64, u64, u64, u64, u32)>(
+        "SELECT uid, bundle, url, action, state, reason, total_processed, ctime, mtime, task_time, tries FROM request_task WHERE task_id = ?",
+        task_id,
+    )?;
+    let Some((uid, bundle, url, action, state, reason, total_processed, ctime, mtime, task_time, tries)) =
+        rows.next()
+    else {
+        return Ok(());
+    };
+    rdb.execute(
+        "INSERT OR REPLACE INTO request_task_archive (task_id, uid, bundle, url_host, action, state, reason, total_processed, ctime, mtime, task_time, tries) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        (
+            task_id,
+            uid,
+            bundle,
+            url_host(&url),
+            action,
+            state,
+            reason,
+            total_processed,
+            ctime,
+            mtime,
+            task_time,
+            tries,
+        ),
+    )
+}
+
+/// Gets the retention policy currently in effect.
+pub(crate) fn retention_policy() -> RetentionPolicy {
+    if !*ARCHIVE_INIT {
+        return RetentionPolicy::default();
+    }
+    let mut rows = match REQUEST_DB.query::<(u64, u64)>(
+        "SELECT max_age, max_rows_per_app FROM request_task_archive_retention WHERE id = 0",
+        (),
+    ) {
+        Ok(rows) => rows,
+        Err(e) => {
+            error!("Failed to query archive retention policy: {}", e);
+            return RetentionPolicy::default();
+        }
+    };
+    match rows.next() {
+        Some((max_age, max_rows_per_app)) => RetentionPolicy {
+            max_age,
+            max_rows_per_app,
+        },
+        None => RetentionPolicy::default(),
+    }
+}
+
+/// Persists a new retention policy; it applies from the next maintenance
+/// cycle.
+pub(crate) fn set_retention_policy(policy: RetentionPolicy) -> Result<(), i32> {
+    if !*ARCHIVE_INIT {
+        return Err(-1);
+    }
+    REQUEST_DB.execute(
+        "INSERT OR REPLACE INTO request_task_archive_retention (id, max_age, max_rows_per_app) VALUES (0, ?, ?)",
+        (policy.max_age, policy.max_rows_per_app),
+    )
+}
+
+/// Deletes archive records that fall outside `policy`.
+///
+/// # Arguments
+///
+/// * `policy` - The retention policy to enforce.
+/// * `now` - Current time in milliseconds.
+pub(crate) fn apply_retention(policy: RetentionPolicy, now: u64) -> Result<(), i32> {
+    if !*ARCHIVE_INIT {
+        return Err(-1);
+    }
+    REQUEST_DB.transaction(|tx| {
+        tx.execute(
+            "DELETE FROM request_task_archive WHERE mtime < ?",
+            now.saturating_sub(policy.max_age),
+        )?;
+        // Ranks the records of each app from the newest in a single pass.
+        tx.execute(
+            "DELETE FROM request_task_archive WHERE task_id IN (SELECT task_id FROM (SELECT task_id, ROW_NUMBER() OVER (PARTITION BY uid ORDER BY mtime DESC, task_id DESC) AS row_num FROM request_task_archive) WHERE row_num > ?)",
+            policy.max_rows_per_app,
+        )
+    })
+}
+
+/// Deletes the archive records of `uid`, once its app is uninstalled.
+pub(crate) fn delete_uid_history(uid: u64) -> Result<(), i32> {
+    if !*ARCHIVE_INIT {
+        return Err(-1);
+    }
+    REQUEST_DB.execute("DELETE FROM request_task_archive WHERE uid = ?", uid)
+}
+
+/// Deletes the archive records of the user account `user_id`, once it is
+/// removed.
+pub(crate) fn delete_account_history(user_id: i32) -> Result<(), i32> {
+    if !*ARCHIVE_INIT {
+        return Err(-1);
+    }
+    REQUEST_DB.execute(
+        "DELETE FROM request_task_archive WHERE uid/200000 = ?",
+        user_id,
+    )
+}
+
+/// Lists archive records modified after `after`, newest first.
+///
+/// # Arguments
+///
+/// * `method` - Restricts the records to a uid, or to a bundle for system
+///   callers (`"*"` matches every bundle).
+/// * `after` - Only records with a later `mtime` are returned.
+/// * `limit` - Maximum number of records, capped at `MAX_HISTORY_QUERY`.
+pub(crate) fn query_history(method: SearchMethod, after: u64, limit: u32) -> Vec<ArchiveRecord> {
+    if !*ARCHIVE_INIT {
+        return Vec::new();
+    }
+    const COLUMNS: &str = "task_id, uid, bundle, url_host, action, state, reason, total_processed, ctime, mtime, task_time, tries";
+    let limit = limit.min(MAX_HISTORY_QUERY);
+    let rows = match method {
+        SearchMethod::User(uid) => REQUEST_DB.query::<ArchiveRow>(
+            &format!("SELECT {COLUMNS} FROM request_task_archive WHERE uid = ? AND mtime > ? ORDER BY mtime DESC LIMIT ?"),
+            (uid, after, limit),
+        ),
+        SearchMethod::System(bundle) if bundle == "*" => REQUEST_DB.query::<ArchiveRow>(
+            &format!("SELECT {COLUMNS} FROM request_task_archive WHERE mtime > ? ORDER BY mtime DESC LIMIT ?"),
+            (after, limit),
+        ),
+        SearchMethod::System(bundle) => REQUEST_DB.query::<ArchiveRow>(
+            &format!("SELECT {COLUMNS} FROM request_task_archive WHERE bundle = ? AND mtime > ? ORDER BY mtime DESC LIMIT ?"),
+            (bundle, after, limit),
+        ),
+    };
+    let rows = match rows {
+        Ok(rows) => rows,
+        Err(e) => {
+            error!("Failed to query task history: {}", e);
+            return Vec::new();
+        }
+    };
+    rows.map(
+        |(task_id, uid, bundle, url_host, action, state, reason, total_processed, ctime, mtime, task_time, tries)| {
+            ArchiveRecord {
+                task_id,
+                uid,
+                bundle,
+                url_host,
+                action: action as u8,
+                state: state as u8,
+                reason: reason as u8,
+                total_processed,
+                ctime,
+                mtime,
+                task_time,
+                tries,
+            }
+        },
+    )
+    .collect()
+}
+
+/// Gets the number of records in the archive.
+pub(crate) fn archive_record_count() -> Option<u64> {
+    if !*ARCHIVE_INIT {
+        return None;
+    }
+    match REQUEST_DB.query::<u64>("SELECT COUNT(*) FROM request_task_archive", ()) {
+        Ok(mut rows) => rows.next(),
+        Err(e) => {
+            error!("Failed to query archive record count: {}", e);
+            None
+        }
+    }
+}
+
+/// Extracts the host (and port) of `url`, dropping scheme, credentials,
+/// path, query and fragment so no tokens end up in the archive.
+pub(crate) fn url_host(url: &str) -> String {
+    let rest = match url.find("://") {
+        Some(idx) => &url[idx + 3..],
+        None => url,
+    };
+    let authority = rest
+        .split(|c| c == '/' || c == '?' || c == '#')
+        .next()
+        .unwrap_or_default();
+    match authority.rfind('@') {
+        Some(idx) => authority[idx + 1..].to_string(),
+        None => authority.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod ut_archive {
+    include!("../../tests/ut/archive.rs");
+}
@@ -0,0 +1,222 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Persistence of chunked upload tasks.
+//!
+//! A task configured for chunked upload has a row in `request_task_chunk_config`
+//! describing how its file is split and where each chunk goes. Every chunk the
+//! server accepted is recorded in `request_task_chunk` together with its
+//! `ETag`, so an interrupted upload resumes with the missing chunks only and
+//! the finalize request can list all parts.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use rdb::RdbStore;
+
+use super::REQUEST_DB;
+use crate::utils::{hashmap_to_string, string_to_hashmap};
+
+const CREATE_CHUNK_CONFIG_TABLE: &str = "CREATE TABLE IF NOT EXISTS request_task_chunk_config (task_id INTEGER PRIMARY KEY, chunk_size INTEGER, parallelism INTEGER, max_retries INTEGER, url TEXT, method TEXT, headers TEXT, finalize_url TEXT, finalize_method TEXT, finalize_body TEXT, finalize_part TEXT)";
+
+const CREATE_CHUNK_TABLE: &str = "CREATE TABLE IF NOT EXISTS request_task_chunk (task_id INTEGER, chunk_index INTEGER, etag TEXT, PRIMARY KEY (task_id, chunk_index))";
+
+/// Smallest accepted chunk size, S3 rejects smaller parts except the last.
+pub(crate) const MIN_CHUNK_SIZE: u64 = 5 * 1024 * 1024;
+
+/// Largest accepted chunk size; chunks are streamed from the file.
+pub(crate) const MAX_CHUNK_SIZE: u64 = 64 * 1024 * 1024;
+
+/// Largest number of chunks uploaded at the same time.
+pub(crate) const MAX_CHUNK_PARALLELISM: u32 = 4;
+
+/// Row layout of `request_task_chunk_config`.
+type ChunkConfigRow = (
+    u64,
+    u32,
+    u32,
+    String,
+    String,
+    String,
+    String,
+    String,
+    String,
+    String,
+);
+
+static CHUNK_INIT: LazyLock<bool> = LazyLock::new(|| {
+    let res = REQUEST_DB.transaction(|tx| {
+        tx.execute(CREATE_CHUNK_CONFIG_TABLE, ())?;
+        tx.execute(CREATE_CHUNK_TABLE, ())
+    });
+    if let Err(e) = res {
+        error!("Failed to create chunk tables: {}", e);
+        return false;
+    }
+    true
+});
+
+/// How the file of a chunked upload task is split and uploaded.
+///
+/// `url`, the header values and the finalize fields are templates, see
+/// `task::chunked::render` for the supported placeholders.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ChunkConfig {
+    /// Size of every chunk but the last, in bytes.
+    pub(crate) chunk_size: u64,
+    /// Number of chunks uploaded at the same time.
+    pub(crate) parallelism: u32,
+    /// Number of times a failed chunk is retried before the task fails.
+    pub(crate) max_retries: u32,
+    /// URL of each chunk request; the task URL when empty.
+    pub(crate) url: String,
+    /// Method of each chunk request.
+    pub(crate) method: String,
+    /// Headers of each chunk request, added to the task headers.
+    pub(crate) headers: HashMap<String, String>,
+    /// URL of the request sent once all chunks are uploaded; none when empty.
+    pub(crate) finalize_url: String,
+    /// Method of the finalize request.
+    pub(crate) finalize_method: String,
+    /// Body of the finalize request.
+    pub(crate) finalize_body: String,
+    /// Text repeated once per chunk in place of `{parts}` in the finalize
+    /// body.
+    pub(crate) finalize_part: String,
+}
+
+impl ChunkConfig {
+    /// Whether the configuration can be used for a chunked upload.
+    pub(crate) fn is_valid(&self) -> bool {
+        (MIN_CHUNK_SIZE..=MAX_CHUNK_SIZE).contains(&self.chunk_size)
+            && (1..=MAX_CHUNK_PARALLELISM).contains(&self.parallelism)
+            && matches!(self.method.to_uppercase().as_str(), "PUT" | "POST")
+            && (self.finalize_url.is_empty()
+                || matches!(self.finalize_method.to_uppercase().as_str(), "PUT" | "POST"))
+    }
+}
+
+/// Gets the chunk configuration of a task, `None` if it is not a chunked
+/// upload.
+pub(crate) fn chunk_config(task_id: u32) -> Option<ChunkConfig> {
+    if !*CHUNK_INIT {
+        return None;
+    }
+    let mut rows = match REQUEST_DB.query::<ChunkConfigRow>(
+        "SELECT chunk_size, parallelism, max_retries, url, method, headers, finalize_url, finalize_method, finalize_body, finalize_part FROM request_task_chunk_config WHERE task_id = ?",
+        task_id,
+    ) {
+        Ok(rows) => rows,
+        Err(e) => {
+            error!("Failed to query chunk config of task {}: {}", task_id, e);
+            return None;
+        }
+    };
+    rows.next().map(
+        |(
+            chunk_size,
+            parallelism,
+            max_retries,
+            url,
+            method,
+            mut headers,
+            finalize_url,
+            finalize_method,
+            finalize_body,
+            finalize_part,
+        )| ChunkConfig {
+            chunk_size,
+            parallelism,
+            max_retries,
+            url,
+            method,
+            headers: string_to_hashmap(&mut headers),
+            finalize_url,
+            finalize_method,
+            finalize_body,
+            finalize_part,
+        },
+    )
+}
+
+/// Makes a task a chunked upload, forgetting the chunks uploaded with a
+/// previous configuration.
+pub(crate) fn set_chunk_config(task_id: u32, config: &ChunkConfig) -> Result<(), i32> {
+    if !*CHUNK_INIT {
+        return Err(-1);
+    }
+    REQUEST_DB.transaction(|tx| {
+        tx.execute(
+            "INSERT OR REPLACE INTO request_task_chunk_config (task_id, chunk_size, parallelism, max_retries, url, method, headers, finalize_url, finalize_method, finalize_body, finalize_part) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            (
+                task_id,
+                config.chunk_size,
+                config.parallelism,
+                config.max_retries,
+                config.url.as_str(),
+                config.method.as_str(),
+                hashmap_to_string(&config.headers),
+                config.finalize_url.as_str(),
+                config.finalize_method.as_str(),
+                config.finalize_body.as_str(),
+                config.finalize_part.as_str(),
+            ),
+        )?;
+        tx.execute("DELETE FROM request_task_chunk WHERE task_id = ?", task_id)
+    })
+}
+
+/// Gets the uploaded chunks of a task as a map from chunk index to `ETag`.
+pub(crate) fn uploaded_chunks(task_id: u32) -> HashMap<u32, String> {
+    if !*CHUNK_INIT {
+        return HashMap::new();
+    }
+    match REQUEST_DB.query::<(u32, String)>(
+        "SELECT chunk_index, etag FROM request_task_chunk WHERE task_id = ?",
+        task_id,
+    ) {
+        Ok(rows) => rows.collect(),
+        Err(e) => {
+            error!("Failed to query uploaded chunks of task {}: {}", task_id, e);
+            HashMap::new()
+        }
+    }
+}
+
+/// Records that a chunk of a task has been uploaded.
+pub(crate) fn record_chunk(task_id: u32, chunk_index: u32, etag: &str) -> Result<(), i32> {
+    if !*CHUNK_INIT {
+        return Err(-1);
+    }
+    REQUEST_DB.execute(
+        "INSERT OR REPLACE INTO request_task_chunk (task_id, chunk_index, etag) VALUES (?, ?, ?)",
+        (task_id, chunk_index, etag),
+    )
+}
+
+/// Deletes the chunk configuration and records of a task.
+pub(crate) fn delete_chunks(rdb: &RdbStore, task_id: u32) -> Result<(), i32> {
+    if !*CHUNK_INIT {
+        return Ok(());
+    }
+    rdb.execute(
+        "DELETE FROM request_task_chunk_config WHERE task_id = ?",
+        task_id,
+    )?;
+    rdb.execute("DELETE FROM request_task_chunk WHERE task_id = ?", task_id)
+}
+
+#[cfg(test)]
+mod ut_chunk {
+    include!("../../tests/ut/chunk.rs");
+}
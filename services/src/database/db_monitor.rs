@@ -16,7 +16,7 @@
 //! This module provides functionality to monitor database size and record counts,
 //! and report metrics via system events when thresholds are exceeded.
 
-use super::REQUEST_DB;
+use super::{archive, REQUEST_DB};
 use crate::sys_event::{isys_fault, DfxCode};
 use crate::task::info::State;
 
@@ -57,6 +57,8 @@ pub(crate) struct DbMonitorResult {
     pub(crate) top_bundles: Vec<(String, u64)>,
     /// Maximum headers length in the task table.
     pub(crate) max_headers_len: u64,
+    /// Total number of records in the task history archive.
+    pub(crate) archive_records: u64,
     /// Whether the database size exceeds baseline.
     pub(crate) size_exceeded: bool,
 }
@@ -67,6 +69,7 @@ pub(crate) struct DbMonitorResult {
 /// 1. Database file size against `DB_SIZE_BASELINE`
 /// 2. State distribution when size exceeds baseline
 /// 3. Top bundles by task count when size exceeds baseline
+/// 4. Task history archive size when size exceeds baseline
 ///
 /// Reports are sent via HiSysEvent for analytics.
 pub(crate) fn monitor_database() {
@@ -98,7 +101,7 @@ pub(crate) fn monitor_database() {
 
     // Write the system event
     let extra_info = format!(
-        "main_size={},wal_size={},shm_size={},total_size={},records={},size_exceeded={},state_dist={},top_bundles={},max_headers_len={}",
+        "main_size={},wal_size={},shm_size={},total_size={},records={},size_exceeded={},state_dist={},top_bundles={},max_headers_len={},archive_records={}",
         result.db_file_size.main_size,
         result.db_file_size.wal_size,
         result.db_file_size.shm_size,
@@ -107,7 +110,8 @@ pub(crate) fn monitor_database() {
         result.size_exceeded,
         format_state_distribution(&result.state_distribution),
         format_top_bundles(&result.top_bundles),
-        result.max_headers_len
+        result.max_headers_len,
+        result.archive_records
     );
     isys_fault(DfxCode::SA_FAULT_02, extra_info.as_str());
 }
@@ -136,6 +140,7 @@ fn collect_db_metrics() -> Option<DbMonitorResult> {
             state_distribution: Vec::new(),
             top_bundles: Vec::new(),
             max_headers_len: 0,
+            archive_records: 0,
             size_exceeded,
         });
     }
@@ -158,12 +163,15 @@ fn collect_db_metrics() -> Option<DbMonitorResult> {
         }
     };
 
+    let archive_records = archive::archive_record_count().unwrap_or_default();
+
     Some(DbMonitorResult {
         db_file_size,
         total_records,
         state_distribution,
         top_bundles,
         max_headers_len,
+        archive_records,
         size_exceeded,
     })
 }
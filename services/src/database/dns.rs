@@ -0,0 +1,127 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! DNS options of tasks.
+//!
+//! `request_task_dns` keeps, per task, the addresses the app pinned to hosts
+//! and its IP preference. They apply on top of the DNS options of the
+//! application, see `task::dns`, from the next time the task runs.
+
+use std::sync::LazyLock;
+
+use rdb::RdbStore;
+use request_utils::dns::{DnsConfig, HostOverride, IpPreference};
+
+use super::REQUEST_DB;
+
+const CREATE_DNS_TABLE: &str = "CREATE TABLE IF NOT EXISTS request_task_dns (task_id INTEGER PRIMARY KEY, overrides TEXT, ip_preference TEXT)";
+
+/// Largest number of hosts a task pins addresses to.
+pub(crate) const MAX_DNS_OVERRIDES: usize = 32;
+
+static DNS_INIT: LazyLock<bool> = LazyLock::new(|| {
+    if let Err(e) = REQUEST_DB.execute(CREATE_DNS_TABLE, ()) {
+        error!("Failed to create task dns table: {}", e);
+        return false;
+    }
+    true
+});
+
+/// DNS options of a task.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct TaskDns {
+    /// Addresses pinned to hosts, `host:port:addr[,addr]` each, in the style
+    /// of curl `--resolve`.
+    pub(crate) overrides: Vec<String>,
+    /// `any`, `ipv4`, `ipv6`, `ipv4_first` or `ipv6_first`; the preference of
+    /// the application when empty.
+    pub(crate) ip_preference: String,
+}
+
+impl TaskDns {
+    /// Whether the options can be applied.
+    pub(crate) fn is_valid(&self) -> bool {
+        self.overrides.len() <= MAX_DNS_OVERRIDES && self.apply(&mut DnsConfig::default()).is_some()
+    }
+
+    /// Applies the options to the DNS options of the application of the
+    /// task; the pinned addresses of the task win over those of the
+    /// application. Returns `None` if an option is invalid.
+    pub(crate) fn apply(&self, config: &mut DnsConfig) -> Option<()> {
+        let overrides = self
+            .overrides
+            .iter()
+            .map(|value| HostOverride::parse(value).ok())
+            .collect::<Option<Vec<_>>>()?;
+        if !self.ip_preference.is_empty() {
+            config.preference = IpPreference::parse(&self.ip_preference).ok()?;
+        }
+        config.overrides.splice(0..0, overrides);
+        Some(())
+    }
+}
+
+/// Gets the DNS options of a task, `None` if it has none.
+pub(crate) fn task_dns(task_id: u32) -> Option<TaskDns> {
+    if !*DNS_INIT {
+        return None;
+    }
+    match REQUEST_DB.query::<(String, String)>(
+        "SELECT overrides, ip_preference FROM request_task_dns WHERE task_id = ?",
+        task_id,
+    ) {
+        Ok(mut rows) => rows.next().map(|(overrides, ip_preference)| TaskDns {
+            overrides: overrides
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(str::to_string)
+                .collect(),
+            ip_preference,
+        }),
+        Err(e) => {
+            error!("Failed to query dns options of task {}: {}", task_id, e);
+            None
+        }
+    }
+}
+
+/// Sets the DNS options of a task, empty options remove them.
+pub(crate) fn set_task_dns(task_id: u32, dns: &TaskDns) -> Result<(), i32> {
+    if !*DNS_INIT {
+        return Err(-1);
+    }
+    if *dns == TaskDns::default() {
+        return delete_task_dns(&REQUEST_DB, task_id);
+    }
+    REQUEST_DB.execute(
+        "INSERT OR REPLACE INTO request_task_dns (task_id, overrides, ip_preference) VALUES (?, ?, ?)",
+        (
+            task_id,
+            dns.overrides.join("\n"),
+            dns.ip_preference.as_str(),
+        ),
+    )
+}
+
+/// Deletes the DNS options of a task.
+pub(crate) fn delete_task_dns(rdb: &RdbStore, task_id: u32) -> Result<(), i32> {
+    if !*DNS_INIT {
+        return Ok(());
+    }
+    rdb.execute("DELETE FROM request_task_dns WHERE task_id = ?", task_id)
+}
+
+#[cfg(test)]
+mod ut_dns {
+    include!("../../tests/ut/dns.rs");
+}
@@ -0,0 +1,123 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-file results of multi-file upload tasks.
+//!
+//! The outcome of every uploaded file is kept in the `each_file_status`
+//! column of `request_task`, one byte per file: the `Reason` the file ended
+//! with (`Reason::Default` on success), or `FILE_PENDING` if it has not been
+//! attempted yet. The results survive the end of the task, so a later run can
+//! skip the files that already succeeded.
+//!
+//! The `continue_on_file_error` column holds the per-task policy deciding
+//! whether an upload carries on with the remaining files after one fails.
+
+use super::REQUEST_DB;
+use crate::task::reason::Reason;
+
+/// Marker of a file that has not been attempted yet.
+const FILE_PENDING: u8 = u8::MAX;
+
+/// Decodes the `each_file_status` column into one entry per file, `None` for
+/// files not attempted yet.
+pub(crate) fn decode_file_results(blob: &[u8], count: usize) -> Vec<Option<Reason>> {
+    (0..count)
+        .map(|i| match blob.get(i) {
+            Some(&FILE_PENDING) | None => None,
+            Some(&reason) => Some(Reason::from(reason)),
+        })
+        .collect()
+}
+
+/// Encodes per-file results into the `each_file_status` column format.
+pub(crate) fn encode_file_results(results: &[Option<Reason>]) -> Vec<u8> {
+    results
+        .iter()
+        .map(|r| match r {
+            Some(reason) => reason.repr,
+            None => FILE_PENDING,
+        })
+        .collect()
+}
+
+/// Gets the recorded result of each of the `count` files of a task.
+pub(crate) fn file_results(task_id: u32, count: usize) -> Vec<Option<Reason>> {
+    let mut rows = match REQUEST_DB.query::<Option<Vec<u8>>>(
+        "SELECT each_file_status FROM request_task WHERE task_id = ?",
+        task_id,
+    ) {
+        Ok(rows) => rows,
+        Err(e) => {
+            error!("Failed to query file results of task {}: {}", task_id, e);
+            return vec![None; count];
+        }
+    };
+    let blob = rows.next().flatten().unwrap_or_default();
+    decode_file_results(&blob, count)
+}
+
+/// Records the result of the file at `index` of a task with `count` files.
+pub(crate) fn record_file_result(
+    task_id: u32,
+    index: usize,
+    count: usize,
+    reason: Reason,
+) -> Result<(), i32> {
+    let mut results = file_results(task_id, count);
+    if let Some(result) = results.get_mut(index) {
+        *result = Some(reason);
+    }
+    REQUEST_DB.execute(
+        "UPDATE request_task SET each_file_status = ? WHERE task_id = ?",
+        (encode_file_results(&results), task_id),
+    )
+}
+
+/// Gets the indexes of the files of a task that were attempted and failed.
+pub(crate) fn failed_files(task_id: u32, count: usize) -> Vec<usize> {
+    file_results(task_id, count)
+        .iter()
+        .enumerate()
+        .filter(|(_, r)| matches!(r, Some(reason) if *reason != Reason::Default))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Whether an upload task carries on with the remaining files after one of
+/// them fails. Defaults to aborting on the first error.
+pub(crate) fn continue_on_file_error(task_id: u32) -> bool {
+    match REQUEST_DB.query::<Option<bool>>(
+        "SELECT continue_on_file_error FROM request_task WHERE task_id = ?",
+        task_id,
+    ) {
+        Ok(mut rows) => rows.next().flatten().unwrap_or(false),
+        Err(e) => {
+            error!("Failed to query file error policy of task {}: {}", task_id, e);
+            false
+        }
+    }
+}
+
+/// Sets the file error policy of a task; it applies from the next file
+/// that fails.
+pub(crate) fn set_continue_on_file_error(task_id: u32, value: bool) -> Result<(), i32> {
+    REQUEST_DB.execute(
+        "UPDATE request_task SET continue_on_file_error = ? WHERE task_id = ?",
+        (value, task_id),
+    )
+}
+
+#[cfg(test)]
+mod ut_file_status {
+    include!("../../tests/ut/file_status.rs");
+}
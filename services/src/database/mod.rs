@@ -15,7 +15,12 @@
 //!
 //! This module provides database operations and monitoring functionality.
 
+pub(crate) mod archive;
+pub(crate) mod chunk;
 mod db_monitor;
+pub(crate) mod dns;
+pub(crate) mod file_status;
+pub(crate) mod suggested_name;
 
 pub(crate) use db_monitor::monitor_database;
 
@@ -79,7 +84,7 @@ pub(crate) fn clear_database_by_state(pre_count: usize) -> Result<bool, ()> {
 
     for task_id in removed_ids {
         debug!("clear removed task {} info for have been in removed state.", task_id);
-        if let Err(e) = REQUEST_DB.execute("DELETE from request_task WHERE task_id = ?", task_id) {
+        if let Err(e) = purge_task(task_id) {
             error!("Failed to clear removed task {} info: {}", task_id, e);
         }
         NotificationDispatcher::get_instance().clear_task_info(task_id);
@@ -103,7 +108,7 @@ pub(crate) fn clear_database_by_state(pre_count: usize) -> Result<bool, ()> {
 
     for task_id in completed_ids {
         debug!("clear completed task {} info for have been overdue for more than a day.", task_id);
-        if let Err(e) = REQUEST_DB.execute("DELETE from request_task WHERE task_id = ?", task_id) {
+        if let Err(e) = purge_task(task_id) {
             error!("Failed to clear completed task {} info: {}", task_id, e);
         }
         NotificationDispatcher::get_instance().clear_task_info(task_id);
@@ -127,7 +132,7 @@ pub(crate) fn clear_database_by_state(pre_count: usize) -> Result<bool, ()> {
 
     for task_id in other_ids {
         debug!("clear other task {} info for have been overdue for more than a week.", task_id);
-        if let Err(e) = REQUEST_DB.execute("DELETE from request_task WHERE task_id = ?", task_id) {
+        if let Err(e) = purge_task(task_id) {
             error!("Failed to clear other task {} info: {}", task_id, e);
         }
         NotificationDispatcher::get_instance().clear_task_info(task_id);
@@ -136,6 +141,24 @@ pub(crate) fn clear_database_by_state(pre_count: usize) -> Result<bool, ()> {
     Ok(any_remain)
 }
 
+/// Archives a task and deletes its live record, together with its chunked
+/// upload state, DNS options and suggested file name, in a single
+/// transaction.
+///
+/// A failure to archive is logged but does not prevent the deletion, so a
+/// broken archive can never make `request_task` grow unbounded.
+fn purge_task(task_id: u32) -> Result<(), i32> {
+    REQUEST_DB.transaction(|tx| {
+        if let Err(e) = archive::archive_task(tx, task_id) {
+            error!("Failed to archive task {}: {}", task_id, e);
+        }
+        chunk::delete_chunks(tx, task_id)?;
+        dns::delete_task_dns(tx, task_id)?;
+        suggested_name::delete_suggested_name(tx, task_id)?;
+        tx.execute("DELETE from request_task WHERE task_id = ?", task_id)
+    })
+}
+
 /// Checkpoints the WAL back into the main database file.
 ///
 /// Runs `PRAGMA wal_checkpoint(RESTART)`: waits for in-flight readers to finish, merges as
@@ -0,0 +1,112 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Server-suggested file names of download tasks.
+//!
+//! `request_task_suggested_name` keeps, per download task, the file name
+//! suggested by the `Content-Disposition` header of the response, the
+//! directory the file is moved to under that name once downloaded, if the app
+//! asked for it, and the path the file was finally saved at.
+
+use std::sync::LazyLock;
+
+use rdb::RdbStore;
+
+use super::REQUEST_DB;
+
+const CREATE_SUGGESTED_NAME_TABLE: &str = "CREATE TABLE IF NOT EXISTS request_task_suggested_name (task_id INTEGER PRIMARY KEY, suggested_name TEXT, save_dir TEXT, saved_path TEXT)";
+
+static SUGGESTED_NAME_INIT: LazyLock<bool> = LazyLock::new(|| {
+    if let Err(e) = REQUEST_DB.execute(CREATE_SUGGESTED_NAME_TABLE, ()) {
+        error!("Failed to create suggested name table: {}", e);
+        return false;
+    }
+    true
+});
+
+/// File name information of a download task.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct SuggestedName {
+    /// File name suggested by the server, not sanitized; empty if none.
+    pub(crate) name: String,
+    /// Directory the file is saved to under the suggested name; empty if the
+    /// file stays at its configured path.
+    pub(crate) save_dir: String,
+    /// Path the file was saved at; empty until it is saved.
+    pub(crate) saved_path: String,
+}
+
+/// Gets the file name information of a task.
+pub(crate) fn suggested_name(task_id: u32) -> SuggestedName {
+    if !*SUGGESTED_NAME_INIT {
+        return SuggestedName::default();
+    }
+    match REQUEST_DB.query::<(Option<String>, Option<String>, Option<String>)>(
+        "SELECT suggested_name, save_dir, saved_path FROM request_task_suggested_name WHERE task_id = ?",
+        task_id,
+    ) {
+        Ok(mut rows) => rows
+            .next()
+            .map(|(name, save_dir, saved_path)| SuggestedName {
+                name: name.unwrap_or_default(),
+                save_dir: save_dir.unwrap_or_default(),
+                saved_path: saved_path.unwrap_or_default(),
+            })
+            .unwrap_or_default(),
+        Err(e) => {
+            error!("Failed to query suggested name of task {}: {}", task_id, e);
+            SuggestedName::default()
+        }
+    }
+}
+
+/// Sets one column of the row of a task, creating the row if needed.
+fn set_column(task_id: u32, column: &str, value: &str) -> Result<(), i32> {
+    if !*SUGGESTED_NAME_INIT {
+        return Err(-1);
+    }
+    REQUEST_DB.execute(
+        &format!(
+            "INSERT INTO request_task_suggested_name (task_id, {0}) VALUES (?, ?) ON CONFLICT(task_id) DO UPDATE SET {0} = excluded.{0}",
+            column
+        ),
+        (task_id, value),
+    )
+}
+
+/// Records the file name suggested by the server for a task.
+pub(crate) fn set_name(task_id: u32, name: &str) -> Result<(), i32> {
+    set_column(task_id, "suggested_name", name)
+}
+
+/// Sets the directory a task saves its file to under the suggested name, an
+/// empty directory keeps the file at its configured path.
+pub(crate) fn set_save_dir(task_id: u32, dir: &str) -> Result<(), i32> {
+    set_column(task_id, "save_dir", dir)
+}
+
+/// Records the path a task saved its file at.
+pub(crate) fn set_saved_path(task_id: u32, path: &str) -> Result<(), i32> {
+    set_column(task_id, "saved_path", path)
+}
+
+/// Deletes the file name information of a task.
+pub(crate) fn delete_suggested_name(rdb: &RdbStore, task_id: u32) -> Result<(), i32> {
+    if !*SUGGESTED_NAME_INIT {
+        return Ok(());
+    }
+    rdb.execute(
+        "DELETE FROM request_task_suggested_name WHERE task_id = ?",
+        task_id,
+    )
+}
@@ -25,6 +25,7 @@ use std::sync::{LazyLock, Mutex, Once};
 pub(crate) use ffi::*;
 
 use super::database::RequestDb;
+use crate::database::archive;
 use crate::manage::events::TaskManagerEvent;
 use crate::manage::task_manager::TaskManagerTx;
 use crate::utils::{call_once, runtime_spawn};
@@ -77,11 +78,15 @@ static mut TASK_MANAGER_TX: Option<TaskManagerTx> = None;
 ///
 /// This function is typically called when a user account is removed from the
 /// system to ensure proper cleanup of associated resources. It deletes all
-/// tasks in the database belonging to the specified user account.
+/// tasks in the database belonging to the specified user account, and their
+/// transfer history.
 pub(crate) fn remove_account_tasks(user_id: i32) {
     info!("delete database task, uid {}", user_id);
     let request_db = RequestDb::get_instance();
     request_db.delete_all_account_tasks(user_id);
+    if let Err(e) = archive::delete_account_history(user_id) {
+        error!("delete history of account {} failed: {}", user_id, e);
+    }
 }
 
 /// Initiates an asynchronous update of account information.
@@ -131,6 +136,17 @@ pub(crate) fn query_active_accounts() -> (HashSet<u64>, HashSet<u64>) {
     (foreground_accounts_u64, active_accounts)
 }
 
+/// Replaces the active accounts without querying the account service.
+///
+/// Used by the simulation harness, which then sends `AccountEvent::Changed`
+/// to the task manager as the account subscriber would.
+#[cfg(test)]
+pub(crate) fn set_active_accounts(foreground: &[i32], background: &[i32]) {
+    let mut accounts = ACTIVE_ACCOUNTS.lock().unwrap();
+    accounts.foreground = foreground.iter().copied().collect();
+    accounts.background = background.iter().copied().collect();
+}
+
 /// Internal utility for updating account information asynchronously.
 struct AccountUpdater {
     /// Flag indicating if any account information has changed during the
@@ -28,11 +28,12 @@ pub(crate) use ffi::*;
 
 cfg_oh! {
     use crate::manage::SystemConfig;
+    use crate::utils::c_wrapper::CFileSpec;
 }
 
 cfg_not_oh! {
     use rusqlite::Connection;
-    const CREATE_TABLE: &'static str = "CREATE TABLE IF NOT EXISTS request_task (task_id INTEGER PRIMARY KEY, uid INTEGER, token_id INTEGER, action INTEGER, mode INTEGER, cover INTEGER, network INTEGER, metered INTEGER, roaming INTEGER, ctime INTEGER, mtime INTEGER, reason INTEGER, gauge INTEGER, retry INTEGER, redirect INTEGER, tries INTEGER, version INTEGER, config_idx INTEGER, begins INTEGER, ends INTEGER, precise INTEGER, priority INTEGER, background INTEGER, bundle TEXT, url TEXT, data TEXT, token TEXT, title TEXT, description TEXT, method TEXT, headers TEXT, config_extras TEXT, mime_type TEXT, state INTEGER, idx INTEGER, total_processed INTEGER, sizes TEXT, processed TEXT, extras TEXT, form_items BLOB, file_specs BLOB, each_file_status BLOB, body_file_names BLOB, certs_paths BLOB)";
+    const CREATE_TABLE: &'static str = "CREATE TABLE IF NOT EXISTS request_task (task_id INTEGER PRIMARY KEY, uid INTEGER, token_id INTEGER, action INTEGER, mode INTEGER, cover INTEGER, network INTEGER, metered INTEGER, roaming INTEGER, ctime INTEGER, mtime INTEGER, reason INTEGER, gauge INTEGER, retry INTEGER, redirect INTEGER, tries INTEGER, version INTEGER, config_idx INTEGER, begins INTEGER, ends INTEGER, precise INTEGER, priority INTEGER, background INTEGER, bundle TEXT, url TEXT, data TEXT, token TEXT, title TEXT, description TEXT, method TEXT, headers TEXT, config_extras TEXT, mime_type TEXT, state INTEGER, idx INTEGER, total_processed INTEGER, sizes TEXT, processed TEXT, extras TEXT, form_items BLOB, file_specs BLOB, each_file_status BLOB, body_file_names BLOB, certs_paths BLOB, continue_on_file_error INTEGER)";
 }
 use crate::config::Action;
 use crate::error::ErrorCode;
@@ -42,8 +43,37 @@ use crate::task::ffi::{CTaskConfig, CTaskInfo, CUpdateInfo};
 use crate::task::info::{State, TaskInfo, UpdateInfo};
 use crate::task::reason::Reason;
 use crate::task::request_task::RequestTask;
+use crate::utils::form_item::FileSpec;
 use crate::utils::{call_once, get_current_timestamp, hashmap_to_string};
 
+/// SQL statement with the values bound to its `?` placeholders.
+///
+/// Values that come from outside the service, such as UIDs or account IDs,
+/// are bound instead of being written into the SQL text.
+pub(crate) struct BoundSql {
+    /// The SQL text containing `?` placeholders.
+    pub(crate) sql: String,
+    /// The values bound to the placeholders, in order.
+    pub(crate) params: Vec<i64>,
+}
+
+impl BoundSql {
+    /// Creates a statement binding `params` to the placeholders of `sql`.
+    pub(crate) fn new(sql: impl Into<String>, params: Vec<i64>) -> Self {
+        Self {
+            sql: sql.into(),
+            params,
+        }
+    }
+}
+
+impl From<String> for BoundSql {
+    /// Wraps a statement without placeholders.
+    fn from(sql: String) -> Self {
+        Self::new(sql, vec![])
+    }
+}
+
 /// Persistent store backing request tasks.
 ///
 /// Wraps the on-device relational database (C++ `RequestDataBase`) or an
@@ -169,6 +199,99 @@ impl RequestDb {
         })
     }
 
+    /// Executes a SQL statement with bound values and no return rows.
+    ///
+    /// # Arguments
+    /// * `sql` - The SQL statement and the values bound to its placeholders.
+    ///
+    /// # Errors
+    /// Returns `Err(ret)` carrying the error code returned by the database
+    /// engine when the statement fails.
+    #[cfg(feature = "oh")]
+    pub(crate) fn execute_bound(&self, sql: &BoundSql) -> Result<(), i32> {
+        crate::database::REQUEST_DB
+            .execute(&sql.sql, sql.params.clone())
+            .map_err(|ret| {
+                error!("execute sql failed: {}", ret);
+                sys_event!(
+                    ExecFault,
+                    DfxCode::RDB_FAULT_04,
+                    &format!("execute sql failed: {}", ret)
+                );
+                ret
+            })
+    }
+
+    /// Off-device (rusqlite) counterpart of
+    /// [`execute_bound`](Self::execute_bound).
+    #[cfg(not(feature = "oh"))]
+    pub(crate) fn execute_bound(&self, sql: &BoundSql) -> Result<(), i32> {
+        self.inner
+            .execute(&sql.sql, rusqlite::params_from_iter(sql.params.iter()))
+            .map(|_| ())
+            .map_err(|e| {
+                error!("execute sql failed: {}", e);
+                e.sqlite_error_code().unwrap() as u32 as i32
+            })
+    }
+
+    /// Executes a list of SQL statements atomically.
+    ///
+    /// Runs every statement inside a single transaction on the shared
+    /// `REQUEST_DB` store, so a multi-statement state change (e.g. network
+    /// offline or account stopped) is either fully applied or not at all.
+    ///
+    /// # Arguments
+    /// * `sqls` - The SQL statements to execute, in order, with the values
+    ///   bound to their placeholders.
+    ///
+    /// # Errors
+    /// Returns `Err(ret)` carrying the error code of the first failing
+    /// statement or of the commit; nothing is applied in that case.
+    #[cfg(feature = "oh")]
+    pub(crate) fn execute_in_transaction(
+        &self,
+        sqls: impl IntoIterator<Item = impl Into<BoundSql>>,
+    ) -> Result<(), i32> {
+        crate::database::REQUEST_DB
+            .transaction(|tx| {
+                for sql in sqls {
+                    let sql = sql.into();
+                    tx.execute(&sql.sql, sql.params)?;
+                }
+                Ok(())
+            })
+            .map_err(|ret| {
+                error!("execute sql in transaction failed: {}", ret);
+                sys_event!(
+                    ExecFault,
+                    DfxCode::RDB_FAULT_04,
+                    &format!("execute sql in transaction failed: {}", ret)
+                );
+                ret
+            })
+    }
+
+    /// Off-device (rusqlite) counterpart of
+    /// [`execute_in_transaction`](Self::execute_in_transaction).
+    #[cfg(not(feature = "oh"))]
+    pub(crate) fn execute_in_transaction(
+        &self,
+        sqls: impl IntoIterator<Item = impl Into<BoundSql>>,
+    ) -> Result<(), i32> {
+        let code = |e: rusqlite::Error| {
+            error!("execute sql in transaction failed: {}", e);
+            e.sqlite_error_code().unwrap() as u32 as i32
+        };
+        let tx = self.inner.unchecked_transaction().map_err(code)?;
+        for sql in sqls {
+            let sql = sql.into();
+            tx.execute(&sql.sql, rusqlite::params_from_iter(sql.params.iter()))
+                .map_err(code)?;
+        }
+        tx.commit().map_err(code)
+    }
+
     /// Runs a SQL query whose result column is a single integer and returns
     /// the values as a vector.
     ///
@@ -451,6 +574,28 @@ impl RequestDb {
         debug!("Update task time in database, ret is {}", ret);
     }
 
+    /// Updates the file specifications of a task, after its file was moved.
+    ///
+    /// # Arguments
+    /// * `task_id` - The task to update.
+    /// * `file_specs` - The new file specifications.
+    #[cfg(feature = "oh")]
+    pub(crate) fn update_task_file_specs(&self, task_id: u32, file_specs: &[FileSpec]) {
+        let c_file_specs: Vec<_> = file_specs.iter().map(|x| x.to_c_struct()).collect();
+        let ret = unsafe {
+            UpdateRequestTaskFileSpecs(task_id, c_file_specs.as_ptr(), c_file_specs.len() as u32)
+        };
+        debug!("Update task file specs in database, ret is {}", ret);
+    }
+
+    /// Off-device counterpart of
+    /// [`update_task_file_specs`](Self::update_task_file_specs), a no-op as
+    /// the host-side schema stores no file specifications.
+    #[cfg(not(feature = "oh"))]
+    pub(crate) fn update_task_file_specs(&self, task_id: u32, _file_specs: &[FileSpec]) {
+        debug!("Update task file specs in database, task_id: {}", task_id);
+    }
+
     /// Marks stale waiting records as failed.
     ///
     /// Transitions tasks still in the `Waiting` state with the `Default`
@@ -973,6 +1118,8 @@ extern "C" {
     fn UpdateRequestTask(id: u32, info: *const CUpdateInfo) -> bool;
     // Updates only the modification timestamp of a task.
     fn UpdateRequestTaskTime(task_id: u32, taskTime: u64) -> bool;
+    // Replaces the file specifications of a task.
+    fn UpdateRequestTaskFileSpecs(task_id: u32, ptr: *const CFileSpec, len: u32) -> bool;
 }
 
 #[cxx::bridge(namespace = "OHOS::Request")]
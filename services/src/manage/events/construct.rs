@@ -15,10 +15,12 @@
 //!
 //! This module implements the task construction logic for the `TaskManager`,
 //! handling task creation, validation, and initialization. It enforces task
-//! limits based on task type and manages system configuration integration.
+//! limits based on task type and the queued quota of the application, and
+//! manages system configuration integration.
 
 cfg_oh! {
     use crate::ability::SYSTEM_CONFIG_MANAGER;
+    use crate::utils::is_system_token;
 }
 
 use crate::config::Mode;
@@ -59,9 +61,9 @@ impl TaskManager {
     ///
     /// # Notes
     ///
-    /// This method enforces task limits based on mode and user ID, generates a
-    /// unique task ID, validates the configuration, and initializes a new
-    /// task.
+    /// This method enforces task limits based on mode and user ID and the
+    /// queued quota of the application, generates a unique task ID, validates
+    /// the configuration, and initializes a new task.
     pub(crate) fn create(&mut self, mut config: TaskConfig) -> Result<u32, ErrorCode> {
         // Generate a unique task ID and assign it to the configuration
         let task_id = TaskIdGenerator::generate();
@@ -76,12 +78,30 @@ impl TaskManager {
             uid, task_id, version
         );
 
+        // Get the quota of this user ID, elevated for system apps
+        #[cfg(feature = "oh")]
+        let is_system = Some(is_system_token(config.common_data.token_id));
+        #[cfg(not(feature = "oh"))]
+        let is_system = None;
+        let queued = self.scheduler.app_quota(uid, is_system).queued;
+
         // Get or initialize task counters for this user ID
         let (frontend, background) = self
             .task_count
             .entry(config.common_data.uid)
             .or_insert((0, 0));
 
+        // Check the queued quota of the app over both modes
+        if *frontend + *background >= queued {
+            error!(
+                "{} task count {} exceeds the queued quota {}",
+                uid,
+                *frontend + *background,
+                queued
+            );
+            return Err(ErrorCode::TaskQuotaErr);
+        }
+
         // Determine which counter and limit to use based on task mode
         let (task_count, limit) = match config.common_data.mode {
             Mode::FrontEnd => (frontend, MAX_FRONTEND_TASK),
@@ -37,8 +37,10 @@ mod dump;
 mod pause;
 mod remove;
 mod resume;
+mod retry_failed_files;
 mod set_max_speed;
 mod set_mode;
+mod set_priority;
 mod start;
 mod stop;
 
@@ -225,6 +227,44 @@ impl TaskManagerEvent {
         )
     }
 
+    /// Creates a new event to set the priority of a specific task.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID that owns the task.
+    /// * `task_id` - The ID of the task to modify.
+    /// * `priority` - The new priority of the task, lower values first.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the event and a receiver for the operation result.
+    pub(crate) fn set_priority(uid: u64, task_id: u32, priority: u32) -> (Self, Recv<ErrorCode>) {
+        let (tx, rx) = channel::<ErrorCode>();
+        (
+            Self::Service(ServiceEvent::SetPriority(uid, task_id, priority, tx)),
+            Recv::new(rx),
+        )
+    }
+
+    /// Creates a new event to move a task to the front of its application's
+    /// queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID that owns the task.
+    /// * `task_id` - The ID of the task to move.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the event and a receiver for the operation result.
+    pub(crate) fn move_to_front(uid: u64, task_id: u32) -> (Self, Recv<ErrorCode>) {
+        let (tx, rx) = channel::<ErrorCode>();
+        (
+            Self::Service(ServiceEvent::MoveToFront(uid, task_id, tx)),
+            Recv::new(rx),
+        )
+    }
+
     /// Creates a new event to notify about network state changes.
     ///
     /// # Returns
@@ -275,6 +315,25 @@ impl TaskManagerEvent {
         )
     }
 
+    /// Creates a new event to re-run only the failed files of an upload
+    /// task.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID that owns the task.
+    /// * `task_id` - The ID of the task to retry.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the event and a receiver for the operation result.
+    pub(crate) fn retry_failed_files(uid: u64, task_id: u32) -> (Self, Recv<ErrorCode>) {
+        let (tx, rx) = channel::<ErrorCode>();
+        (
+            Self::Service(ServiceEvent::RetryFailedFiles(uid, task_id, tx)),
+            Recv::new(rx),
+        )
+    }
+
     /// Creates a new event to set the maximum speed for a specific task.
     ///
     /// # Arguments
@@ -321,6 +380,8 @@ pub(crate) enum ServiceEvent {
     Remove(u64, u32, Sender<ErrorCode>),
     /// Resume a specific task.
     Resume(u64, u32, Sender<ErrorCode>),
+    /// Re-run only the failed files of an upload task.
+    RetryFailedFiles(u64, u32, Sender<ErrorCode>),
     /// Dump information for a specific task.
     DumpOne(u32, Sender<Option<DumpOneInfo>>),
     /// Dump information for all tasks.
@@ -331,6 +392,10 @@ pub(crate) enum ServiceEvent {
     SetMaxSpeed(u64, u32, i64, Sender<ErrorCode>),
     /// Set the execution mode for a specific task.
     SetMode(u64, u32, Mode, Sender<ErrorCode>),
+    /// Set the priority of a specific task.
+    SetPriority(u64, u32, u32, Sender<ErrorCode>),
+    /// Move a specific task to the front of its application's queue.
+    MoveToFront(u64, u32, Sender<ErrorCode>),
 }
 
 /// Task state and lifecycle events.
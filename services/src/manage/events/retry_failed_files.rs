@@ -0,0 +1,45 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Failed file retry implementation for the task manager.
+//!
+//! This module provides the implementation for re-running only the failed
+//! files of a multi-file upload task. It delegates to the scheduler component.
+
+use crate::error::ErrorCode;
+use crate::manage::TaskManager;
+
+impl TaskManager {
+    /// Re-queues an upload task so that only its failed files are uploaded
+    /// again.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID that owns the task.
+    /// * `task_id` - The ID of the task to retry.
+    ///
+    /// # Returns
+    ///
+    /// * `ErrorCode::ErrOk` - If the task was re-queued.
+    /// * `ErrorCode::TaskStateErr` - If the task is not a failed upload task
+    ///   with failed files.
+    /// * Other `ErrorCode` values - If there was an error starting the task.
+    pub(crate) fn retry_failed_files(&mut self, uid: u64, task_id: u32) -> ErrorCode {
+        debug!("TaskManager retry_failed_files, tid{}", task_id);
+
+        match self.scheduler.retry_failed_files(uid, task_id) {
+            Ok(_) => ErrorCode::ErrOk,
+            Err(e) => e,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Task priority configuration implementation for the task manager.
+//!
+//! This module provides the implementation for reordering the queued tasks of
+//! an application within the `TaskManager`. It delegates both operations to
+//! the scheduler component.
+
+use crate::error::ErrorCode;
+use crate::manage::TaskManager;
+
+impl TaskManager {
+    /// Sets the priority of a task with the specified user ID and task ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID that owns the task.
+    /// * `task_id` - The ID of the task to set priority for.
+    /// * `priority` - The new priority of the task, lower values first.
+    ///
+    /// # Returns
+    ///
+    /// * `ErrorCode::ErrOk` - If the priority was successfully set.
+    /// * Other `ErrorCode` values - If there was an error setting the priority.
+    pub(crate) fn set_priority(&mut self, uid: u64, task_id: u32, priority: u32) -> ErrorCode {
+        debug!(
+            "TaskManager set_priority, tid{} priority{}",
+            task_id, priority
+        );
+
+        match self.scheduler.task_set_priority(uid, task_id, priority) {
+            Ok(_) => ErrorCode::ErrOk,
+            Err(e) => e,
+        }
+    }
+
+    /// Moves a task to the front of its application's queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID that owns the task.
+    /// * `task_id` - The ID of the task to move.
+    ///
+    /// # Returns
+    ///
+    /// * `ErrorCode::ErrOk` - If the task was moved.
+    /// * `ErrorCode::TaskStateErr` - If the task is not waiting or running.
+    /// * Other `ErrorCode` values - If there was an error moving the task.
+    pub(crate) fn move_to_front(&mut self, uid: u64, task_id: u32) -> ErrorCode {
+        debug!("TaskManager move_to_front, tid{}", task_id);
+
+        match self.scheduler.task_move_to_front(uid, task_id) {
+            Ok(_) => ErrorCode::ErrOk,
+            Err(e) => e,
+        }
+    }
+}
@@ -15,7 +15,8 @@
 //!
 //! Aggregates the core management modules: account, app state, database,
 //! events, query, network, scheduler, task manager, notifier, and system
-//! configuration, re-exporting `TaskManager` and `SystemConfigManager`.
+//! configuration, re-exporting `TaskManager` and `SystemConfigManager`. Test
+//! builds add the `simulation` harness driving the task manager.
 
 cfg_oh! {
     pub(crate) mod config;
@@ -32,6 +33,8 @@ pub(crate) mod network;
 pub(crate) mod network_manager;
 pub(crate) mod notifier;
 pub(crate) mod scheduler;
+#[cfg(all(test, feature = "oh"))]
+pub(crate) mod simulation;
 pub(crate) mod task_manager;
 
 #[cfg(test)]
@@ -27,12 +27,14 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 mod sql;
+pub(crate) use qos::Quota;
 use qos::Qos;
 use queue::RunningQueue;
 use state::sql::SqlList;
 
 use super::events::TaskManagerEvent;
 use crate::config::Mode;
+use crate::database::file_status;
 use crate::error::ErrorCode;
 use crate::info::TaskInfo;
 use crate::manage::database::RequestDb;
@@ -108,11 +110,8 @@ impl Scheduler {
         let mut state_handler = state::Handler::new(tx.clone());
         // Initialize state and update database with initial state
         let sql_list = state_handler.init();
-        let db = RequestDb::get_instance();
-        for sql in sql_list {
-            if let Err(e) = db.execute(&sql) {
-                error!("TaskManager update network failed {:?}", e);
-            };
+        if let Err(e) = RequestDb::get_instance().execute_in_transaction(sql_list) {
+            error!("TaskManager update network failed {:?}", e);
         }
 
         Self {
@@ -203,6 +202,34 @@ impl Scheduler {
         self.start_inner(uid, task_id, true)
     }
 
+    /// Re-queues a failed multi-file upload task to upload only the files
+    /// that failed; files that already succeeded are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID of the application that owns the task.
+    /// * `task_id` - The unique identifier of the task.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the task was re-queued, or `ErrorCode::TaskStateErr` if it
+    /// is not a failed upload task with failed files.
+    pub(crate) fn retry_failed_files(&mut self, uid: u64, task_id: u32) -> Result<(), ErrorCode> {
+        let info = RequestDb::get_instance()
+            .get_task_info(task_id)
+            .ok_or(ErrorCode::TaskNotFound)?;
+        if info.progress.common_data.state != State::Failed.repr
+            || Action::from(info.common_data.action) != Action::Upload
+            || file_status::failed_files(task_id, info.file_specs.len()).is_empty()
+        {
+            return Err(ErrorCode::TaskStateErr);
+        }
+        // Failed upload tasks cannot be started again, only their failed
+        // files retried
+        RequestDb::get_instance().requeue_failed_upload(task_id)?;
+        self.queue_waiting_task(uid, task_id, false)
+    }
+
     /// Internal implementation for starting or resuming a task.
     ///
     /// # Arguments
@@ -229,7 +256,29 @@ impl Scheduler {
         }
         // Change to Waiting state so the task can be scheduled
         database.change_status(task_id, State::Waiting)?;
+        self.queue_waiting_task(uid, task_id, is_resume)
+    }
 
+    /// Queues a task that was just changed to the Waiting state for
+    /// scheduling.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID of the application that owns the task.
+    /// * `task_id` - The unique identifier of the task.
+    /// * `is_resume` - Boolean indicating whether this is a resume operation.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the task was queued, or an error if the task could not be
+    /// found.
+    fn queue_waiting_task(
+        &mut self,
+        uid: u64,
+        task_id: u32,
+        is_resume: bool,
+    ) -> Result<(), ErrorCode> {
+        let database = RequestDb::get_instance();
         let info = RequestDb::get_instance()
             .get_task_info(task_id)
             .ok_or(ErrorCode::TaskNotFound)?;
@@ -421,6 +470,89 @@ impl Scheduler {
         Ok(())
     }
 
+    /// Changes the priority of a task.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID of the application that owns the task.
+    /// * `task_id` - The unique identifier of the task.
+    /// * `priority` - The new priority of the task, lower values first.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the priority was successfully changed, or an error if the
+    /// task could not be found or the database update failed.
+    pub(crate) fn task_set_priority(
+        &mut self,
+        uid: u64,
+        task_id: u32,
+        priority: u32,
+    ) -> Result<(), ErrorCode> {
+        let database = RequestDb::get_instance();
+        if database.query_task_uid(task_id) != Some(uid) {
+            return Err(ErrorCode::TaskNotFound);
+        }
+        database
+            .execute_bound(&sql::task_set_priority(task_id, priority))
+            .map_err(|_| ErrorCode::SystemApi)?;
+
+        // Queued tasks take their new place at the next reschedule
+        if self.qos.task_set_priority(uid, task_id, priority) {
+            self.schedule_if_not_scheduled();
+        }
+        Ok(())
+    }
+
+    /// Moves a queued task in front of the other tasks of the same mode of its
+    /// application.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID of the application that owns the task.
+    /// * `task_id` - The unique identifier of the task.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the task was moved, `Err(ErrorCode::TaskStateErr)` if the
+    /// task is not waiting or running, or an error if the database update
+    /// failed.
+    pub(crate) fn task_move_to_front(&mut self, uid: u64, task_id: u32) -> Result<(), ErrorCode> {
+        let changed = self
+            .qos
+            .task_move_to_front(uid, task_id)
+            .ok_or(ErrorCode::TaskStateErr)?;
+        if changed.is_empty() {
+            return Ok(());
+        }
+
+        // Persist every shifted priority at once so the queue order survives
+        // a restart
+        let sqls = changed
+            .iter()
+            .map(|(task_id, priority)| sql::task_set_priority(*task_id, *priority));
+        if RequestDb::get_instance()
+            .execute_in_transaction(sqls)
+            .is_err()
+        {
+            // Restore the queue from the database, which was not changed
+            self.qos.reload_all_tasks();
+            return Err(ErrorCode::SystemApi);
+        }
+        self.schedule_if_not_scheduled();
+        Ok(())
+    }
+
+    /// Gets the quota of an application.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID of the application.
+    /// * `is_system` - Whether the application is a system application, if
+    ///   known by the caller.
+    pub(crate) fn app_quota(&mut self, uid: u64, is_system: Option<bool>) -> Quota {
+        self.qos.app_quota(uid, is_system)
+    }
+
     /// Handles task completion.
     ///
     /// # Arguments
@@ -686,12 +818,9 @@ impl Scheduler {
             return;
         };
 
-        // Execute SQL statements to update database
-        let db = RequestDb::get_instance();
-        for sql in sql_list {
-            if let Err(e) = db.execute(&sql) {
-                error!("TaskManager update network failed {:?}", e);
-            };
+        // Apply all SQL statements of this state change atomically
+        if let Err(e) = RequestDb::get_instance().execute_in_transaction(sql_list) {
+            error!("TaskManager update network failed {:?}", e);
         }
 
         // Reload and reschedule all tasks based on new state
@@ -749,9 +878,23 @@ impl Scheduler {
         // Clear the reschedule flag
         self.resort_scheduled = false;
 
+        // Record the remaining size of running tasks for size-aware policies
+        for task in self.running_queue.tasks() {
+            self.qos
+                .set_remaining(task.uid(), task.task_id(), task.remaining_bytes());
+        }
+
         // Get QoS changes based on current system state
         let changes = self.qos.reschedule(&self.state_handler);
 
+        // Report queued tasks newly held back by their app's quota; running
+        // ones are reported when they are canceled below
+        for (uid, task_id) in self.qos.take_newly_held() {
+            if self.running_queue.get_task_clone(uid, task_id).is_none() {
+                Notifier::waiting(&self.client_manager, task_id, WaitingCause::TaskQueue);
+            }
+        }
+
         // Apply changes to running queue and collect tasks to remove
         let mut qos_remove_queue = vec![];
         self.running_queue
@@ -868,6 +1011,28 @@ impl Scheduler {
 }
 
 impl RequestDb {
+    /// Changes a failed upload task back to the Waiting state so that its
+    /// failed files can be retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The unique identifier of the task.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the task is now waiting, `Err(ErrorCode::TaskStateErr)`
+    /// if it is not a failed upload task, or `Err(ErrorCode::SystemApi)` if
+    /// the database update failed.
+    fn requeue_failed_upload(&self, task_id: u32) -> Result<(), ErrorCode> {
+        self.execute(&sql::retry_failed_files(task_id))
+            .map_err(|_| ErrorCode::SystemApi)?;
+        let info = self.get_task_info(task_id).ok_or(ErrorCode::SystemApi)?;
+        if info.progress.common_data.state != State::Waiting.repr {
+            return Err(ErrorCode::TaskStateErr);
+        }
+        Ok(())
+    }
+
     /// Changes the status of a task in the database.
     ///
     /// # Arguments
@@ -970,3 +1135,9 @@ impl RequestDb {
         Ok(())
     }
 }
+
+#[cfg(feature = "oh")]
+#[cfg(test)]
+mod ut_mod {
+    include!("../../../tests/ut/manage/scheduler/ut_mod.rs");
+}
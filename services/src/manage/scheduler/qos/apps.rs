@@ -48,6 +48,12 @@ impl SortedApps {
         }
     }
 
+    /// Creates an empty `SortedApps` without touching the database.
+    #[cfg(test)]
+    pub(crate) fn new() -> Self {
+        Self { inner: Vec::new() }
+    }
+
     /// Sorts applications based on user focus and foreground status.
     ///
     /// # Arguments
@@ -107,6 +113,7 @@ impl SortedApps {
             mode: Mode::from(task.mode),
             action: Action::from(task.action),
             priority: task.priority,
+            remaining: None,
         };
 
         // Check if the app already exists and add the task
@@ -171,6 +178,57 @@ impl SortedApps {
             None => false,
         }
     }
+
+    /// Changes the priority of a task.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID of the application.
+    /// * `task_id` - The ID of the task to modify.
+    /// * `priority` - The new priority of the task, lower values first.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the task's priority was successfully changed, `false` if
+    /// either the application or task wasn't found.
+    pub(crate) fn task_set_priority(&mut self, uid: u64, task_id: u32, priority: u32) -> bool {
+        match self.get_app_mut(uid) {
+            Some(app) => app.task_set_priority(task_id, priority),
+            None => false,
+        }
+    }
+
+    /// Moves a task in front of the other tasks of the same mode of its
+    /// application.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID of the application.
+    /// * `task_id` - The ID of the task to move.
+    ///
+    /// # Returns
+    ///
+    /// The tasks whose priority changed with their new priority, or `None` if
+    /// either the application or task wasn't found.
+    pub(crate) fn task_move_to_front(&mut self, uid: u64, task_id: u32) -> Option<Vec<(u32, u32)>> {
+        self.get_app_mut(uid)?.move_to_front(task_id)
+    }
+
+    /// Records the bytes a task still has to transfer.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID of the application.
+    /// * `task_id` - The ID of the task.
+    /// * `remaining` - Bytes left to transfer, `None` if the size is unknown.
+    pub(crate) fn set_remaining(&mut self, uid: u64, task_id: u32, remaining: Option<u64>) {
+        if let Some((_index, task)) = self
+            .get_app_mut(uid)
+            .and_then(|app| app.get_task_mut(task_id))
+        {
+            task.remaining = remaining;
+        }
+    }
 }
 
 impl Deref for SortedApps {
@@ -188,6 +246,7 @@ impl Deref for SortedApps {
 ///
 /// This struct manages a collection of tasks belonging to a specific
 /// application (identified by UID).
+#[derive(Clone)]
 pub(crate) struct App {
     /// The application's user ID.
     pub(crate) uid: u64,
@@ -300,11 +359,89 @@ impl App {
             None => false,
         }
     }
+
+    /// Changes the priority of a task and re-sorts the task list.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The ID of the task to modify.
+    /// * `priority` - The new priority of the task.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the task's priority was successfully changed, `false` if the
+    /// task wasn't found.
+    fn task_set_priority(&mut self, task_id: u32, priority: u32) -> bool {
+        match self.get_task_mut(task_id) {
+            Some((_index, task)) => {
+                task.priority = priority;
+                self.resort_tasks();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Gives a task a lower priority value than every other task of the same
+    /// mode and re-sorts the task list.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The ID of the task to move.
+    ///
+    /// # Returns
+    ///
+    /// The tasks whose priority changed with their new priority, or `None` if
+    /// the task wasn't found.
+    ///
+    /// # Notes
+    ///
+    /// When no lower priority value is free, the moved task takes priority 0
+    /// and the other tasks of the same mode are shifted back by one, which
+    /// keeps their relative order.
+    fn move_to_front(&mut self, task_id: u32) -> Option<Vec<(u32, u32)>> {
+        let (_index, task) = self.get_task_mut(task_id)?;
+        let mode = task.mode;
+        let front = self
+            .tasks
+            .iter()
+            .filter(|task| task.mode == mode && task.task_id != task_id)
+            .map(|task| task.priority)
+            .min();
+
+        let mut changed = Vec::new();
+        match front {
+            None => {}
+            Some(front) if front > 0 => {
+                let (_index, task) = self.get_task_mut(task_id)?;
+                if task.priority >= front {
+                    task.priority = front - 1;
+                    changed.push((task_id, task.priority));
+                }
+            }
+            Some(_) => {
+                for task in self.tasks.iter_mut().filter(|task| task.mode == mode) {
+                    let priority = if task.task_id == task_id {
+                        0
+                    } else {
+                        task.priority.saturating_add(1)
+                    };
+                    if priority != task.priority {
+                        task.priority = priority;
+                        changed.push((task.task_id, priority));
+                    }
+                }
+            }
+        }
+        self.resort_tasks();
+        Some(changed)
+    }
 }
 
 /// Represents a task with its scheduling parameters.
 ///
 /// Tasks are sorted by mode and priority within their parent application.
+#[derive(Clone)]
 pub(crate) struct Task {
     /// The user ID of the application that owns this task.
     uid: u64,
@@ -316,6 +453,8 @@ pub(crate) struct Task {
     action: Action,
     /// The priority level of the task within its mode.
     priority: u32,
+    /// Bytes left to transfer, `None` until known.
+    remaining: Option<u64>,
 }
 
 impl Task {
@@ -334,6 +473,21 @@ impl Task {
         self.action
     }
 
+    /// Returns the task's execution mode.
+    pub(crate) fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Returns the task's priority within its mode, lower values first.
+    pub(crate) fn priority(&self) -> u32 {
+        self.priority
+    }
+
+    /// Returns the bytes the task still has to transfer, if known.
+    pub(crate) fn remaining(&self) -> Option<u64> {
+        self.remaining
+    }
+
     /// Updates the task's execution mode.
     ///
     /// # Arguments
@@ -444,6 +598,7 @@ fn reload_tasks_of_app_from_database(uid: u64) -> Vec<Task> {
             mode: Mode::from(info.mode),
             action: Action::from(info.action),
             priority: info.priority,
+            remaining: None,
         })
         .collect()
 }
@@ -0,0 +1,291 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! QoS configuration loaded at service start.
+//!
+//! The configuration selects the active `SchedulingPolicy`, the `RssCapacity`
+//! of each RSS level and the `Quota` of each application. It is read from
+//! `QOS_CONFIG_PATH`, a text file of `key = value` lines where `#` starts a
+//! comment:
+//!
+//! ```text
+//! policy = weighted_fair
+//! level3 = 8 16 4 high middle middle
+//! default_weight = 1
+//! weight.20010042 = 4
+//! quota = 8 500 unlimited
+//! system_quota = 16 2000 unlimited
+//! quota.20010042 = 2 100 1073741824
+//! ```
+//!
+//! Missing keys and invalid lines keep their built-in defaults, so a missing
+//! file yields the historical behavior.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use super::policy::{
+    SchedulingPolicy, ShortestRemainingPolicy, StrictPriorityPolicy, TieredPolicy,
+    WeightedFairPolicy,
+};
+use super::quota::Quota;
+use super::{QosLevel, RssCapacity};
+
+/// Path of the QoS configuration file.
+const QOS_CONFIG_PATH: &str = "/system/etc/request/qos_policy.conf";
+
+/// Number of RSS levels, see `RssCapacity::new`.
+const RSS_LEVELS: usize = 8;
+
+/// Scheduling policies selectable in the configuration.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum PolicyKind {
+    /// `TieredPolicy`, the default.
+    Tiered,
+    /// `WeightedFairPolicy`.
+    WeightedFair,
+    /// `StrictPriorityPolicy`.
+    StrictPriority,
+    /// `ShortestRemainingPolicy`.
+    ShortestRemaining,
+}
+
+impl PolicyKind {
+    /// Parses a policy name of the configuration file.
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "tiered" => Some(Self::Tiered),
+            "weighted_fair" => Some(Self::WeightedFair),
+            "strict_priority" => Some(Self::StrictPriority),
+            "shortest_remaining" => Some(Self::ShortestRemaining),
+            _ => None,
+        }
+    }
+}
+
+/// QoS scheduling configuration.
+pub(crate) struct QosConfig {
+    /// The active scheduling policy.
+    policy: PolicyKind,
+    /// Capacity of each RSS level.
+    capacities: [RssCapacity; RSS_LEVELS],
+    /// Weights of specific applications by uid, used by `WeightedFair`.
+    weights: HashMap<u64, u32>,
+    /// Weight of all other applications, used by `WeightedFair`.
+    default_weight: u32,
+    /// Quota of applications without a specific quota.
+    default_quota: Quota,
+    /// Quota of system applications without a specific quota.
+    system_quota: Quota,
+    /// Quotas of specific applications by uid.
+    quotas: HashMap<u64, Quota>,
+}
+
+impl Default for QosConfig {
+    fn default() -> Self {
+        Self {
+            policy: PolicyKind::Tiered,
+            capacities: [
+                RssCapacity::LEVEL0,
+                RssCapacity::LEVEL1,
+                RssCapacity::LEVEL2,
+                RssCapacity::LEVEL3,
+                RssCapacity::LEVEL4,
+                RssCapacity::LEVEL5,
+                RssCapacity::LEVEL6,
+                RssCapacity::LEVEL7,
+            ],
+            weights: HashMap::new(),
+            default_weight: 1,
+            default_quota: Quota::UNLIMITED,
+            system_quota: Quota::UNLIMITED,
+            quotas: HashMap::new(),
+        }
+    }
+}
+
+impl QosConfig {
+    /// Gets the configuration loaded from `QOS_CONFIG_PATH` on first use.
+    pub(crate) fn get_instance() -> &'static Self {
+        static INSTANCE: LazyLock<QosConfig> =
+            LazyLock::new(|| match std::fs::read_to_string(QOS_CONFIG_PATH) {
+                Ok(text) => QosConfig::parse(&text),
+                Err(e) => {
+                    info!("QoS config not loaded, use defaults: {}", e);
+                    QosConfig::default()
+                }
+            });
+        &INSTANCE
+    }
+
+    /// Parses a configuration file, keeping the defaults of missing keys and
+    /// invalid lines.
+    pub(crate) fn parse(text: &str) -> Self {
+        let mut config = Self::default();
+        for (n, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            let applied = line
+                .split_once('=')
+                .is_some_and(|(key, value)| config.apply(key.trim(), value.trim()));
+            if !applied {
+                error!("QoS config line {} invalid: {}", n + 1, line);
+            }
+        }
+        config
+    }
+
+    /// Applies one `key = value` line, returning whether it was valid.
+    fn apply(&mut self, key: &str, value: &str) -> bool {
+        match key {
+            "policy" => match PolicyKind::from_name(value) {
+                Some(policy) => self.policy = policy,
+                None => return false,
+            },
+            "default_weight" => match value.parse() {
+                Ok(weight) => self.default_weight = weight,
+                Err(_) => return false,
+            },
+            "quota" => match parse_quota(value) {
+                Some(quota) => self.default_quota = quota,
+                None => return false,
+            },
+            "system_quota" => match parse_quota(value) {
+                Some(quota) => self.system_quota = quota,
+                None => return false,
+            },
+            _ => {
+                if let Some(uid) = key.strip_prefix("weight.") {
+                    let (Ok(uid), Ok(weight)) = (uid.parse(), value.parse()) else {
+                        return false;
+                    };
+                    self.weights.insert(uid, weight);
+                } else if let Some(uid) = key.strip_prefix("quota.") {
+                    let (Ok(uid), Some(quota)) = (uid.parse(), parse_quota(value)) else {
+                        return false;
+                    };
+                    self.quotas.insert(uid, quota);
+                } else if let Some(level) = key.strip_prefix("level") {
+                    let (Ok(level), Some(capacity)) =
+                        (level.parse::<usize>(), parse_capacity(value))
+                    else {
+                        return false;
+                    };
+                    if level >= RSS_LEVELS {
+                        return false;
+                    }
+                    self.capacities[level] = capacity;
+                } else {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Gets the capacity of an RSS level, levels out of range meaning the
+    /// highest memory pressure.
+    pub(crate) fn capacity(&self, level: i32) -> RssCapacity {
+        match level {
+            0..=6 => self.capacities[level as usize],
+            _ => self.capacities[RSS_LEVELS - 1],
+        }
+    }
+
+    /// Gets the quota of an application.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID of the application.
+    /// * `is_system` - Whether the application is a system application.
+    ///
+    /// # Returns
+    ///
+    /// The quota configured for the uid if any, otherwise the system or
+    /// default quota.
+    pub(crate) fn quota(&self, uid: u64, is_system: bool) -> Quota {
+        match self.quotas.get(&uid) {
+            Some(quota) => *quota,
+            None if is_system => self.system_quota,
+            None => self.default_quota,
+        }
+    }
+
+    /// Creates the configured scheduling policy.
+    pub(crate) fn create_policy(&self) -> Box<dyn SchedulingPolicy> {
+        match self.policy {
+            PolicyKind::Tiered => Box::new(TieredPolicy),
+            PolicyKind::WeightedFair => Box::new(WeightedFairPolicy::new(
+                self.weights.clone(),
+                self.default_weight,
+            )),
+            PolicyKind::StrictPriority => Box::new(StrictPriorityPolicy),
+            PolicyKind::ShortestRemaining => Box::new(ShortestRemainingPolicy),
+        }
+    }
+}
+
+/// Parses a capacity `m1 m2 m3 m1_speed m2_speed m3_speed`.
+fn parse_capacity(value: &str) -> Option<RssCapacity> {
+    let fields: Vec<_> = value.split_whitespace().collect();
+    let [m1, m2, m3, s1, s2, s3] = fields[..] else {
+        return None;
+    };
+    Some(RssCapacity(
+        m1.parse().ok()?,
+        m2.parse().ok()?,
+        m3.parse().ok()?,
+        parse_level(s1)?,
+        parse_level(s2)?,
+        parse_level(s3)?,
+    ))
+}
+
+/// Parses a quota `running queued bytes`, where each limit is a number or
+/// `unlimited`.
+fn parse_quota(value: &str) -> Option<Quota> {
+    let fields: Vec<_> = value.split_whitespace().collect();
+    let [running, queued, bytes] = fields[..] else {
+        return None;
+    };
+    Some(Quota {
+        running: parse_limit(running, usize::MAX)?,
+        queued: parse_limit(queued, usize::MAX)?,
+        bytes: parse_limit(bytes, u64::MAX)?,
+    })
+}
+
+/// Parses a limit of a quota.
+fn parse_limit<T: std::str::FromStr>(value: &str, unlimited: T) -> Option<T> {
+    match value {
+        "unlimited" => Some(unlimited),
+        value => value.parse().ok(),
+    }
+}
+
+/// Parses a QoS level name.
+fn parse_level(name: &str) -> Option<QosLevel> {
+    match name {
+        "high" => Some(QosLevel::High),
+        "middle" => Some(QosLevel::Middle),
+        "low" => Some(QosLevel::Low),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod ut_config {
+    include!("../../../../tests/ut/manage/scheduler/qos/ut_config.rs");
+}
@@ -23,11 +23,20 @@
 //! overall system performance.
 
 mod apps;
+mod config;
 mod direction;
+mod policy;
+mod quota;
 mod rss;
+#[cfg(test)]
+mod simulator;
 
 use apps::SortedApps;
+use config::QosConfig;
 pub(crate) use direction::{QosChanges, QosDirection, QosLevel};
+use policy::SchedulingPolicy;
+pub(crate) use quota::Quota;
+use quota::QuotaGuard;
 pub(crate) use rss::RssCapacity;
 
 use super::state;
@@ -46,17 +55,30 @@ pub(crate) struct Qos {
     /// Current RSS memory capacity level that determines task allocation
     /// limits.
     capacity: RssCapacity,
+    /// Policy assigning QoS levels to tasks, selected by the QoS
+    /// configuration.
+    policy: Box<dyn SchedulingPolicy>,
+    /// Per-application quotas applied before the policy.
+    quota: QuotaGuard,
+    /// Tasks held back by their application's quota at the last reschedule
+    /// and not held before, to be reported as waiting.
+    newly_held: Vec<(u64, u32)>,
 }
 
 impl Qos {
     /// Creates a new QoS scheduler with default initial state.
     ///
-    /// Returns a `Qos` instance with an empty application collection and
-    /// initial memory capacity set to `RssCapacity::LEVEL0`.
+    /// Returns a `Qos` instance with the applications loaded from the
+    /// database, the initial memory capacity of RSS level 0 and the
+    /// configured scheduling policy.
     pub(crate) fn new() -> Self {
+        let config = QosConfig::get_instance();
         Self {
             apps: SortedApps::init(),
-            capacity: RssCapacity::LEVEL0,
+            capacity: config.capacity(0),
+            policy: config.create_policy(),
+            quota: QuotaGuard::new(),
+            newly_held: Vec::new(),
         }
     }
 
@@ -108,6 +130,18 @@ impl Qos {
         self.capacity = rss;
     }
 
+    /// Records the bytes a task still has to transfer, used by policies that
+    /// favor short tasks.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID of the application that owns the task.
+    /// * `task_id` - The ID of the task.
+    /// * `remaining` - Bytes left to transfer, `None` if the size is unknown.
+    pub(crate) fn set_remaining(&mut self, uid: u64, task_id: u32, remaining: Option<u64>) {
+        self.apps.set_remaining(uid, task_id, remaining);
+    }
+
     /// Changes the execution mode of a specific task.
     ///
     /// # Arguments
@@ -124,6 +158,37 @@ impl Qos {
         self.apps.task_set_mode(uid, task_id, mode)
     }
 
+    /// Changes the priority of a specific task.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID of the application that owns the task.
+    /// * `task_id` - The ID of the task to modify.
+    /// * `priority` - The new priority of the task, lower values first.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the task was found and its priority was changed, `false`
+    /// otherwise.
+    pub(crate) fn task_set_priority(&mut self, uid: u64, task_id: u32, priority: u32) -> bool {
+        self.apps.task_set_priority(uid, task_id, priority)
+    }
+
+    /// Moves a task to the front of its application's queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID of the application that owns the task.
+    /// * `task_id` - The ID of the task to move.
+    ///
+    /// # Returns
+    ///
+    /// The tasks whose priority changed with their new priority, or `None` if
+    /// the task is not queued.
+    pub(crate) fn task_move_to_front(&mut self, uid: u64, task_id: u32) -> Option<Vec<(u32, u32)>> {
+        self.apps.task_move_to_front(uid, task_id)
+    }
+
     /// Reschedules all tasks and generates QoS direction changes.
     ///
     /// # Arguments
@@ -139,142 +204,49 @@ impl Qos {
         // Only sort apps before assigning priorities
         self.apps
             .sort(state.foreground_abilities(), state.foreground_users());
+        // Quotas apply to both actions together, so admit once
+        let (apps, newly_held) = self.quota.admit(&self.apps);
+        self.newly_held = newly_held;
         let mut changes = QosChanges::new();
         // Generate QoS directions for both download and upload tasks separately
-        changes.download = Some(self.reschedule_inner(Action::Download));
-        changes.upload = Some(self.reschedule_inner(Action::Upload));
+        changes.download = Some(self.reschedule_inner(&apps, Action::Download));
+        changes.upload = Some(self.reschedule_inner(&apps, Action::Upload));
         changes
     }
 
-    /// Inner method that handles the core scheduling algorithm for a specific
-    /// action type.
+    /// Takes the tasks newly held back by their application's quota at the
+    /// last reschedule.
+    pub(crate) fn take_newly_held(&mut self) -> Vec<(u64, u32)> {
+        std::mem::take(&mut self.newly_held)
+    }
+
+    /// Gets the quota of an application.
     ///
     /// # Arguments
     ///
+    /// * `uid` - The user ID of the application.
+    /// * `is_system` - Whether the application is a system application, if
+    ///   known by the caller.
+    pub(crate) fn app_quota(&mut self, uid: u64, is_system: Option<bool>) -> Quota {
+        if let Some(is_system) = is_system {
+            self.quota.set_system_app(uid, is_system);
+        }
+        self.quota.quota(uid)
+    }
+
+    /// Inner method that runs the scheduling policy for a specific action
+    /// type.
+    ///
+    /// # Arguments
+    ///
+    /// * `apps` - The applications with the tasks admitted by their quota.
     /// * `action` - The action type (Download or Upload) to schedule.
     ///
     /// # Returns
     ///
     /// A vector of `QosDirection` objects specifying the new QoS levels for
     /// tasks.
-    ///
-    /// # Notes
-    ///
-    /// This method implements a three-tier priority system (M1, M2, M3) with
-    /// different speed limits. Tasks are assigned to tiers based on their
-    /// application's priority and position in the sorted list.
-    fn reschedule_inner(&mut self, action: Action) -> Vec<QosDirection> {
-        // Get capacity limits and corresponding speed levels for each priority tier
-        let m1 = self.capacity.m1();
-        let m1_speed = self.capacity.m1_speed();
-        let m2 = self.capacity.m2();
-        let m2_speed = self.capacity.m2_speed();
-        let m3 = self.capacity.m3();
-        let m3_speed = self.capacity.m3_speed();
-
-        // Track current task count and positions for fair distribution
-        let mut count = 0;
-        let mut app_i = 0;
-        let mut task_i = 0;
-
-        let mut qos_vec = Vec::new();
-
-        // First pass: Assign highest priority (M1) and second priority (M2) tasks
-        // Iterate through all tasks in sorted order by application
-        for (i, task) in self.apps.iter().enumerate().flat_map(|(i, app)| {
-            // Track the last non-empty application index
-            if !app.tasks.is_empty() {
-                app_i = i;
-            }
-            app.tasks.iter().enumerate()
-        }) {
-            // Skip tasks that don't match the current action type
-            if task.action() != action {
-                continue;
-            }
-
-            // Assign tasks to M1 (highest priority) or M2 (medium priority) based on count
-            if count < m1 {
-                qos_vec.push(QosDirection::new(task.uid(), task.task_id(), m1_speed));
-            } else if count < m1 + m2 {
-                qos_vec.push(QosDirection::new(task.uid(), task.task_id(), m2_speed));
-            }
-            count += 1;
-
-            // Stop once we've filled all M1 and M2 slots
-            if count == m1 + m2 {
-                task_i = i;
-                break;
-            }
-        }
-
-        // If we didn't fill all M1 and M2 slots, we're done
-        if count < m1 + m2 {
-            return qos_vec;
-        }
-
-        // Second pass: Implement fair distribution algorithm for M3 (lowest priority)
-        // tasks Each application gets one task in turn to ensure fair resource
-        // distribution
-        let mut i = 0;
-
-        loop {
-            let mut no_tasks_left = true;
-
-            // Iterate through remaining applications (after the last non-empty app)
-            for tasks in self.apps.iter().skip(app_i + 1).map(|app| &app.tasks[..]) {
-                let task = match tasks.get(i) {
-                    Some(task) => {
-                        no_tasks_left = false;
-                        task
-                    }
-                    None => continue,
-                };
-
-                // Skip tasks that don't match the current action type
-                if task.action() != action {
-                    continue;
-                }
-
-                // Assign M3 priority if we haven't filled all slots
-                if count < m1 + m2 + m3 {
-                    qos_vec.push(QosDirection::new(task.uid(), task.task_id(), m3_speed));
-                } else {
-                    return qos_vec;
-                }
-
-                count += 1;
-            }
-
-            // Exit loop when there are no more tasks to process
-            if no_tasks_left {
-                break;
-            }
-            i += 1;
-        }
-
-        // Third pass: Fill any remaining M3 slots with tasks from the last non-empty
-        // application This ensures we utilize all available capacity
-        for task in self
-            .apps
-            .iter()
-            .skip(app_i)
-            .take(1)
-            .flat_map(|app| app.tasks.iter().skip(task_i + 1))
-        {
-            // Skip tasks that don't match the current action type
-            if task.action() != action {
-                continue;
-            }
-
-            // Assign M3 priority if we haven't filled all slots
-            if count < m1 + m2 + m3 {
-                qos_vec.push(QosDirection::new(task.uid(), task.task_id(), m3_speed));
-            } else {
-                return qos_vec;
-            }
-            count += 1;
-        }
-        qos_vec
+    fn reschedule_inner(&self, apps: &[apps::App], action: Action) -> Vec<QosDirection> {
+        self.policy.schedule(apps, &self.capacity, action)
     }
 }
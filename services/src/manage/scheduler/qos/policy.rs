@@ -0,0 +1,297 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scheduling policies deciding which tasks run and at which QoS level.
+//!
+//! A policy receives the applications in their sorted order together with the
+//! current `RssCapacity`, and returns the QoS directions of the tasks allowed
+//! to run. `TieredPolicy` is the historical algorithm and the default; the
+//! other policies order all tasks in one sequence and fill the M1, M2 and M3
+//! zones of the capacity in that order.
+
+use std::collections::HashMap;
+
+use super::apps::{App, Task};
+use super::{QosDirection, RssCapacity};
+use crate::task::config::Action;
+
+/// Algorithm assigning QoS levels to the tasks of all applications.
+pub(crate) trait SchedulingPolicy: Send {
+    /// Assigns QoS levels to the tasks of one action type.
+    ///
+    /// # Arguments
+    ///
+    /// * `apps` - The applications in sorted order, their tasks sorted by mode
+    ///   and priority.
+    /// * `capacity` - The current zone sizes and their QoS levels.
+    /// * `action` - The action type (Download or Upload) to schedule.
+    ///
+    /// # Returns
+    ///
+    /// The QoS directions of the tasks allowed to run, in scheduling order.
+    fn schedule(&self, apps: &[App], capacity: &RssCapacity, action: Action) -> Vec<QosDirection>;
+}
+
+/// Fills the zones of a capacity with tasks in the given order.
+fn fill_zones<'a>(
+    tasks: impl Iterator<Item = &'a Task>,
+    capacity: &RssCapacity,
+) -> Vec<QosDirection> {
+    let m1 = capacity.m1();
+    let m2 = m1 + capacity.m2();
+    let m3 = m2 + capacity.m3();
+    tasks
+        .take(m3)
+        .enumerate()
+        .map(|(i, task)| {
+            let level = if i < m1 {
+                capacity.m1_speed()
+            } else if i < m2 {
+                capacity.m2_speed()
+            } else {
+                capacity.m3_speed()
+            };
+            QosDirection::new(task.uid(), task.task_id(), level)
+        })
+        .collect()
+}
+
+/// Historical policy: M1 and M2 in sorted application order, then M3 round
+/// robin across the remaining applications.
+pub(crate) struct TieredPolicy;
+
+impl SchedulingPolicy for TieredPolicy {
+    /// Implements a three-tier priority system (M1, M2, M3) with different
+    /// speed limits. Tasks are assigned to tiers based on their application's
+    /// priority and position in the sorted list.
+    fn schedule(&self, apps: &[App], capacity: &RssCapacity, action: Action) -> Vec<QosDirection> {
+        // Get capacity limits and corresponding speed levels for each priority tier
+        let m1 = capacity.m1();
+        let m1_speed = capacity.m1_speed();
+        let m2 = capacity.m2();
+        let m2_speed = capacity.m2_speed();
+        let m3 = capacity.m3();
+        let m3_speed = capacity.m3_speed();
+
+        // Track current task count and positions for fair distribution
+        let mut count = 0;
+        let mut app_i = 0;
+        let mut task_i = 0;
+
+        let mut qos_vec = Vec::new();
+
+        // First pass: Assign highest priority (M1) and second priority (M2) tasks
+        // Iterate through all tasks in sorted order by application
+        for (i, task) in apps.iter().enumerate().flat_map(|(i, app)| {
+            // Track the last non-empty application index
+            if !app.tasks.is_empty() {
+                app_i = i;
+            }
+            app.tasks.iter().enumerate()
+        }) {
+            // Skip tasks that don't match the current action type
+            if task.action() != action {
+                continue;
+            }
+
+            // Assign tasks to M1 (highest priority) or M2 (medium priority) based on count
+            if count < m1 {
+                qos_vec.push(QosDirection::new(task.uid(), task.task_id(), m1_speed));
+            } else if count < m1 + m2 {
+                qos_vec.push(QosDirection::new(task.uid(), task.task_id(), m2_speed));
+            }
+            count += 1;
+
+            // Stop once we've filled all M1 and M2 slots
+            if count == m1 + m2 {
+                task_i = i;
+                break;
+            }
+        }
+
+        // If we didn't fill all M1 and M2 slots, we're done
+        if count < m1 + m2 {
+            return qos_vec;
+        }
+
+        // Second pass: Implement fair distribution algorithm for M3 (lowest priority)
+        // tasks Each application gets one task in turn to ensure fair resource
+        // distribution
+        let mut i = 0;
+
+        loop {
+            let mut no_tasks_left = true;
+
+            // Iterate through remaining applications (after the last non-empty app)
+            for tasks in apps.iter().skip(app_i + 1).map(|app| &app.tasks[..]) {
+                let task = match tasks.get(i) {
+                    Some(task) => {
+                        no_tasks_left = false;
+                        task
+                    }
+                    None => continue,
+                };
+
+                // Skip tasks that don't match the current action type
+                if task.action() != action {
+                    continue;
+                }
+
+                // Assign M3 priority if we haven't filled all slots
+                if count < m1 + m2 + m3 {
+                    qos_vec.push(QosDirection::new(task.uid(), task.task_id(), m3_speed));
+                } else {
+                    return qos_vec;
+                }
+
+                count += 1;
+            }
+
+            // Exit loop when there are no more tasks to process
+            if no_tasks_left {
+                break;
+            }
+            i += 1;
+        }
+
+        // Third pass: Fill any remaining M3 slots with tasks from the last non-empty
+        // application This ensures we utilize all available capacity
+        for task in apps
+            .iter()
+            .skip(app_i)
+            .take(1)
+            .flat_map(|app| app.tasks.iter().skip(task_i + 1))
+        {
+            // Skip tasks that don't match the current action type
+            if task.action() != action {
+                continue;
+            }
+
+            // Assign M3 priority if we haven't filled all slots
+            if count < m1 + m2 + m3 {
+                qos_vec.push(QosDirection::new(task.uid(), task.task_id(), m3_speed));
+            } else {
+                return qos_vec;
+            }
+            count += 1;
+        }
+        qos_vec
+    }
+}
+
+/// Weighted fair queuing by uid.
+///
+/// Applications take turns in sorted order, each taking as many tasks per
+/// turn as its weight, so that the running slots are shared in proportion to
+/// the weights.
+pub(crate) struct WeightedFairPolicy {
+    /// Weights of specific applications by uid.
+    weights: HashMap<u64, u32>,
+    /// Weight of applications without a specific weight.
+    default_weight: u32,
+}
+
+impl WeightedFairPolicy {
+    /// Creates a weighted fair policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - Weights of specific applications by uid.
+    /// * `default_weight` - Weight of all other applications.
+    pub(crate) fn new(weights: HashMap<u64, u32>, default_weight: u32) -> Self {
+        Self {
+            weights,
+            default_weight,
+        }
+    }
+
+    /// Returns the weight of an application, at least 1.
+    fn weight(&self, uid: u64) -> usize {
+        self.weights
+            .get(&uid)
+            .copied()
+            .unwrap_or(self.default_weight)
+            .max(1) as usize
+    }
+}
+
+impl SchedulingPolicy for WeightedFairPolicy {
+    fn schedule(&self, apps: &[App], capacity: &RssCapacity, action: Action) -> Vec<QosDirection> {
+        let limit = capacity.m1() + capacity.m2() + capacity.m3();
+        let mut queues: Vec<_> = apps
+            .iter()
+            .map(|app| {
+                let weight = self.weight(app.uid);
+                let tasks = app.tasks.iter().filter(|task| task.action() == action);
+                (weight, tasks.peekable())
+            })
+            .collect();
+
+        let mut order = Vec::new();
+        while order.len() < limit {
+            let mut no_tasks_left = true;
+            for (weight, tasks) in queues.iter_mut() {
+                order.extend(tasks.by_ref().take(*weight));
+                if tasks.peek().is_some() {
+                    no_tasks_left = false;
+                }
+            }
+            if no_tasks_left {
+                break;
+            }
+        }
+        fill_zones(order.into_iter(), capacity)
+    }
+}
+
+/// Strict priority by task priority across all applications.
+///
+/// Tasks with a lower priority value always run first; ties are broken by
+/// mode and then by the sorted application order.
+pub(crate) struct StrictPriorityPolicy;
+
+impl SchedulingPolicy for StrictPriorityPolicy {
+    fn schedule(&self, apps: &[App], capacity: &RssCapacity, action: Action) -> Vec<QosDirection> {
+        let mut tasks: Vec<_> = apps
+            .iter()
+            .flat_map(|app| app.tasks.iter())
+            .filter(|task| task.action() == action)
+            .collect();
+        // Stable sort keeps the application order among equal tasks.
+        tasks.sort_by_key(|task| (task.priority(), task.mode()));
+        fill_zones(tasks.into_iter(), capacity)
+    }
+}
+
+/// Shortest remaining bytes first across all applications.
+///
+/// Tasks whose remaining size is unknown, typically tasks that have not run
+/// yet, come after all tasks of known size in sorted application order.
+pub(crate) struct ShortestRemainingPolicy;
+
+impl SchedulingPolicy for ShortestRemainingPolicy {
+    fn schedule(&self, apps: &[App], capacity: &RssCapacity, action: Action) -> Vec<QosDirection> {
+        let mut tasks: Vec<_> = apps
+            .iter()
+            .flat_map(|app| app.tasks.iter())
+            .filter(|task| task.action() == action)
+            .collect();
+        tasks.sort_by_key(|task| task.remaining().unwrap_or(u64::MAX));
+        fill_zones(tasks.into_iter(), capacity)
+    }
+}
+
+#[cfg(test)]
+mod ut_policy {
+    include!("../../../../tests/ut/manage/scheduler/qos/ut_policy.rs");
+}
@@ -0,0 +1,181 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-application quotas on running, queued and in-flight tasks.
+//!
+//! The `RssCapacity` bounds the running tasks of all applications together, so
+//! without quotas a single application may take every running slot. A `Quota`
+//! bounds one application: the queued quota is enforced when a task is
+//! constructed, which fails with `ErrorCode::TaskQuotaErr`, the running and
+//! bytes quotas when tasks are scheduled. Tasks over quota stay waiting and
+//! are not seen by the `SchedulingPolicy`.
+
+use std::collections::{HashMap, HashSet};
+
+use super::apps::App;
+use super::config::QosConfig;
+
+/// Limits on the tasks of one application.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) struct Quota {
+    /// Maximum number of tasks running at the same time.
+    pub(crate) running: usize,
+    /// Maximum number of unfinished tasks.
+    pub(crate) queued: usize,
+    /// Maximum bytes left to transfer by the running tasks. The first task of
+    /// an application is always admitted, whatever its size.
+    pub(crate) bytes: u64,
+}
+
+impl Quota {
+    /// Quota without any limit, the historical behavior.
+    pub(crate) const UNLIMITED: Self = Self {
+        running: usize::MAX,
+        queued: usize::MAX,
+        bytes: u64::MAX,
+    };
+}
+
+/// Enforces the quotas of all applications at schedule time.
+pub(crate) struct QuotaGuard {
+    /// Whether each known application is a system application.
+    system_apps: HashMap<u64, bool>,
+    /// Tasks held back by the quota of their application.
+    held: HashSet<(u64, u32)>,
+}
+
+impl QuotaGuard {
+    /// Creates a guard without known applications or held tasks.
+    pub(crate) fn new() -> Self {
+        Self {
+            system_apps: HashMap::new(),
+            held: HashSet::new(),
+        }
+    }
+
+    /// Records whether an application is a system application, as known when
+    /// it constructs a task.
+    pub(crate) fn set_system_app(&mut self, uid: u64, is_system: bool) {
+        self.system_apps.insert(uid, is_system);
+    }
+
+    /// Gets the quota of an application.
+    ///
+    /// Applications not seen since the service started are looked up from
+    /// the token of their tasks in the database.
+    pub(crate) fn quota(&mut self, uid: u64) -> Quota {
+        let is_system = *self
+            .system_apps
+            .entry(uid)
+            .or_insert_with(|| is_system_app(uid));
+        QosConfig::get_instance().quota(uid, is_system)
+    }
+
+    /// Removes the tasks over quota from the applications.
+    ///
+    /// # Returns
+    ///
+    /// The applications with only their admitted tasks, in the same order,
+    /// and the tasks held back that were not held at the previous call.
+    pub(crate) fn admit(&mut self, apps: &[App]) -> (Vec<App>, Vec<(u64, u32)>) {
+        let quotas: HashMap<_, _> = apps
+            .iter()
+            .map(|app| (app.uid, self.quota(app.uid)))
+            .collect();
+        admit(apps, |uid| quotas[&uid], &mut self.held)
+    }
+}
+
+/// Removes the tasks over quota from the applications.
+///
+/// Tasks are admitted in the order of their application, so that a quota
+/// keeps the most urgent tasks. A task whose remaining size would exceed the
+/// bytes quota is skipped in favor of the next tasks; unknown sizes count as
+/// zero.
+///
+/// # Arguments
+///
+/// * `apps` - The applications in sorted order.
+/// * `quota_of` - The quota of an application by uid.
+/// * `held` - The tasks held back at the previous call, replaced by the tasks
+///   held back now.
+///
+/// # Returns
+///
+/// The applications with only their admitted tasks and the newly held tasks.
+pub(crate) fn admit(
+    apps: &[App],
+    quota_of: impl Fn(u64) -> Quota,
+    held: &mut HashSet<(u64, u32)>,
+) -> (Vec<App>, Vec<(u64, u32)>) {
+    let mut now_held = HashSet::new();
+    let admitted = apps
+        .iter()
+        .map(|app| {
+            let quota = quota_of(app.uid);
+            let mut app = app.clone();
+            let mut running = 0;
+            let mut bytes = 0u64;
+            app.tasks.retain(|task| {
+                let size = task.remaining().unwrap_or(0);
+                let fits = running == 0 || bytes.saturating_add(size) <= quota.bytes;
+                if running < quota.running && fits {
+                    running += 1;
+                    bytes = bytes.saturating_add(size);
+                    true
+                } else {
+                    now_held.insert((task.uid(), task.task_id()));
+                    false
+                }
+            });
+            app
+        })
+        .collect();
+
+    let mut newly_held: Vec<_> = now_held.difference(held).copied().collect();
+    newly_held.sort_unstable();
+    *held = now_held;
+    (admitted, newly_held)
+}
+
+/// Checks whether an application is a system application from the token of
+/// one of its tasks.
+#[cfg(feature = "oh")]
+fn is_system_app(uid: u64) -> bool {
+    use crate::database::REQUEST_DB;
+
+    let mut rows = match REQUEST_DB.query::<i64>(
+        "SELECT token_id FROM request_task WHERE uid = ? LIMIT 1",
+        uid,
+    ) {
+        Ok(rows) => rows,
+        Err(e) => {
+            error!("Failed to query token of uid {}: {}", uid, e);
+            return false;
+        }
+    };
+    rows.next()
+        .is_some_and(|token_id| crate::utils::is_system_token(token_id as u64))
+}
+
+/// Checks whether an application is a system application; without the token
+/// service no application is.
+#[cfg(not(feature = "oh"))]
+fn is_system_app(_uid: u64) -> bool {
+    false
+}
+
+#[cfg(test)]
+mod ut_quota {
+    include!("../../../../tests/ut/manage/scheduler/qos/ut_quota.rs");
+}
@@ -18,6 +18,7 @@
 //! adjust the number of tasks that can run at various priority levels, ensuring
 //! that network operations adapt to changing system conditions.
 
+use super::config::QosConfig;
 use super::QosLevel;
 
 /// Memory capacity configuration for QoS scheduling based on RSS levels.
@@ -36,8 +37,15 @@ use super::QosLevel;
 /// 4. QoS level for m1 tasks
 /// 5. QoS level for m2 tasks
 /// 6. QoS level for m3 tasks
-#[derive(PartialEq, Eq)]
-pub(crate) struct RssCapacity(usize, usize, usize, QosLevel, QosLevel, QosLevel);
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) struct RssCapacity(
+    pub(super) usize,
+    pub(super) usize,
+    pub(super) usize,
+    pub(super) QosLevel,
+    pub(super) QosLevel,
+    pub(super) QosLevel,
+);
 
 impl RssCapacity {
     pub(crate) const LEVEL0: Self =
@@ -70,22 +78,11 @@ impl RssCapacity {
     ///
     /// # Returns
     ///
-    /// A `RssCapacity` instance configured for the specified pressure level.
-    ///
-    /// # Panics
-    ///
-    /// Panics if `level` is outside the valid range of 0-7.
+    /// The `RssCapacity` configured for the specified pressure level, the
+    /// `LEVEL0` to `LEVEL7` constants unless overridden by the QoS
+    /// configuration. Levels outside 0-6 map to level 7.
     pub(crate) fn new(level: i32) -> Self {
-        match level {
-            0 => Self::LEVEL0,
-            1 => Self::LEVEL1,
-            2 => Self::LEVEL2,
-            3 => Self::LEVEL3,
-            4 => Self::LEVEL4,
-            5 => Self::LEVEL5,
-            6 => Self::LEVEL6,
-            _ => Self::LEVEL7,
-        }
+        QosConfig::get_instance().capacity(level)
     }
 
     /// Returns the maximum number of tasks allowed in the full-speed (highest
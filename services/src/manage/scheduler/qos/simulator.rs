@@ -0,0 +1,252 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deterministic host-side simulator of the QoS scheduling policies.
+//!
+//! Replays a trace of task arrivals on a virtual clock. At every tick the
+//! policy under test assigns QoS levels, the scheduled tasks share a link of
+//! fixed bandwidth within the speed limits of their levels, and completed
+//! tasks leave the queue. The report gives the throughput and starvation of
+//! each application, so that policies can be compared on the same trace.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use super::apps::SortedApps;
+use super::policy::SchedulingPolicy;
+use super::RssCapacity;
+use crate::manage::database::TaskQosInfo;
+use crate::task::config::{Action, Mode};
+
+/// Virtual time between two scheduling rounds, in milliseconds.
+const TICK: u64 = 100;
+
+/// Arrival of a task in a trace.
+#[derive(Clone)]
+pub(crate) struct Arrival {
+    /// Arrival time in milliseconds.
+    time: u64,
+    uid: u64,
+    task_id: u32,
+    action: Action,
+    mode: Mode,
+    priority: u32,
+    /// Size of the task in bytes.
+    size: u64,
+}
+
+impl Arrival {
+    /// Creates the arrival of a background download with priority 0.
+    pub(crate) fn download(time: u64, uid: u64, task_id: u32, size: u64) -> Self {
+        Self {
+            time,
+            uid,
+            task_id,
+            action: Action::Download,
+            mode: Mode::BackGround,
+            priority: 0,
+            size,
+        }
+    }
+
+    /// Makes the task an upload.
+    pub(crate) fn upload(mut self) -> Self {
+        self.action = Action::Upload;
+        self
+    }
+
+    /// Sets the mode of the task.
+    pub(crate) fn mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the priority of the task.
+    pub(crate) fn priority(mut self, priority: u32) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+/// Simulation results of one application.
+#[derive(Default, Debug, PartialEq, Eq)]
+pub(crate) struct AppReport {
+    /// Bytes transferred.
+    pub(crate) bytes: u64,
+    /// Average throughput over the simulation, in bytes per second.
+    pub(crate) throughput: u64,
+    /// Tasks completed.
+    pub(crate) completed: usize,
+    /// Longest time a task waited before it was first scheduled, including
+    /// tasks still waiting at the end, in milliseconds.
+    pub(crate) max_wait: u64,
+    /// Tasks never scheduled before the end of the simulation.
+    pub(crate) starved: usize,
+}
+
+/// Simulation results of all applications of a trace, by uid.
+pub(crate) type SimReport = BTreeMap<u64, AppReport>;
+
+/// State of a task that has arrived and not completed.
+struct SimTask {
+    uid: u64,
+    arrival: u64,
+    remaining: u64,
+    first_run: Option<u64>,
+}
+
+/// Simulator replaying traces against one scheduling policy.
+pub(crate) struct Simulator {
+    policy: Box<dyn SchedulingPolicy>,
+    capacity: RssCapacity,
+    /// Bandwidth of the shared link, in bytes per second.
+    bandwidth: u64,
+}
+
+impl Simulator {
+    /// Creates a simulator.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The scheduling policy under test.
+    /// * `capacity` - The zone sizes and QoS levels to schedule with.
+    /// * `bandwidth` - Bandwidth of the shared link, in bytes per second.
+    pub(crate) fn new(
+        policy: Box<dyn SchedulingPolicy>,
+        capacity: RssCapacity,
+        bandwidth: u64,
+    ) -> Self {
+        Self {
+            policy,
+            capacity,
+            bandwidth,
+        }
+    }
+
+    /// Replays a trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `trace` - The task arrivals, in any order.
+    /// * `duration` - Simulated time in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// The results of every application of the trace.
+    pub(crate) fn run(&self, trace: &[Arrival], duration: u64) -> SimReport {
+        let mut arrivals: Vec<_> = trace.iter().collect();
+        arrivals.sort_by_key(|arrival| arrival.time);
+        let mut arrivals = arrivals.into_iter().peekable();
+
+        let mut report: SimReport = trace
+            .iter()
+            .map(|arrival| (arrival.uid, AppReport::default()))
+            .collect();
+        // All applications stay in the background, in order of arrival.
+        let background = HashSet::new();
+        let mut apps = SortedApps::new();
+        let mut tasks = HashMap::new();
+
+        let mut time = 0;
+        while time < duration {
+            while let Some(arrival) = arrivals.next_if(|arrival| arrival.time <= time) {
+                apps.insert_task(
+                    arrival.uid,
+                    TaskQosInfo {
+                        task_id: arrival.task_id,
+                        action: arrival.action.repr,
+                        mode: arrival.mode.repr,
+                        state: 0,
+                        priority: arrival.priority,
+                    },
+                );
+                tasks.insert(
+                    arrival.task_id,
+                    SimTask {
+                        uid: arrival.uid,
+                        arrival: arrival.time,
+                        remaining: arrival.size,
+                        first_run: None,
+                    },
+                );
+            }
+
+            apps.sort(&background, &background);
+            let mut directions = self
+                .policy
+                .schedule(&apps, &self.capacity, Action::Download);
+            directions.extend(self.policy.schedule(&apps, &self.capacity, Action::Upload));
+
+            let caps: Vec<_> = directions
+                .iter()
+                .map(|direction| {
+                    let remaining = tasks[&direction.task_id()].remaining;
+                    match direction.direction() as u64 {
+                        0 => remaining,
+                        speed => remaining.min(speed * TICK / 1000),
+                    }
+                })
+                .collect();
+            let shares = share(self.bandwidth * TICK / 1000, &caps);
+
+            for (direction, bytes) in directions.iter().zip(shares) {
+                let task = tasks.get_mut(&direction.task_id()).unwrap();
+                task.first_run.get_or_insert(time);
+                task.remaining -= bytes;
+                let app = report.get_mut(&task.uid).unwrap();
+                app.bytes += bytes;
+                app.max_wait = app.max_wait.max(task.first_run.unwrap() - task.arrival);
+                if task.remaining == 0 {
+                    app.completed += 1;
+                    apps.remove_task(direction.uid(), direction.task_id());
+                    tasks.remove(&direction.task_id());
+                } else {
+                    // Like the service, sizes are known once a task has run.
+                    apps.set_remaining(direction.uid(), direction.task_id(), Some(task.remaining));
+                }
+            }
+            time += TICK;
+        }
+
+        for task in tasks.values().filter(|task| task.first_run.is_none()) {
+            let app = report.get_mut(&task.uid).unwrap();
+            app.starved += 1;
+            app.max_wait = app.max_wait.max(duration.saturating_sub(task.arrival));
+        }
+        for app in report.values_mut() {
+            app.throughput = app.bytes * 1000 / duration.max(1);
+        }
+        report
+    }
+}
+
+/// Shares a byte budget equally among tasks, within the cap of each task.
+fn share(budget: u64, caps: &[u64]) -> Vec<u64> {
+    let mut shares = vec![0; caps.len()];
+    let mut left = budget;
+    let mut open: Vec<_> = (0..caps.len()).filter(|&i| caps[i] > 0).collect();
+    while left > 0 && !open.is_empty() {
+        let each = (left / open.len() as u64).max(1);
+        open.retain(|&i| {
+            let give = each.min(caps[i] - shares[i]).min(left);
+            shares[i] += give;
+            left -= give;
+            shares[i] < caps[i]
+        });
+    }
+    shares
+}
+
+#[cfg(test)]
+mod ut_simulator {
+    include!("../../../../tests/ut/manage/scheduler/qos/ut_simulator.rs");
+}
@@ -18,6 +18,7 @@
 
 use crate::config::{Action, Mode};
 use crate::info::State;
+use crate::manage::database::BoundSql;
 use crate::task::reason::Reason;
 
 /// Generates SQL to start a task and transition it to the Waiting state.
@@ -53,6 +54,29 @@ pub(super) fn start_task(task_id: u32) -> String {
     )
 }
 
+/// Generates SQL to re-queue a failed upload task whose failed files are
+/// retried, transitioning it to the Waiting state.
+///
+/// # Arguments
+///
+/// * `task_id` - The unique identifier of the task to retry.
+///
+/// # Returns
+///
+/// A SQL UPDATE statement that changes the task state to `Waiting`, but only
+/// if the task is a failed upload task. Unlike [`start_task`], it does not
+/// allow starting failed upload tasks in general.
+pub(super) fn retry_failed_files(task_id: u32) -> String {
+    format!(
+        "UPDATE request_task SET state = {}, reason = {} where task_id = {} AND action = {} AND state = {}",
+        State::Waiting.repr,
+        Reason::RunningTaskMeetLimits.repr,
+        task_id,
+        Action::Upload.repr,
+        State::Failed.repr,
+    )
+}
+
 /// Generates SQL to pause a task and transition it to the Paused state.
 ///
 /// # Arguments
@@ -165,6 +189,25 @@ pub(super) fn task_set_mode(task_id: u32, mode: Mode) -> String {
     )
 }
 
+/// Generates SQL to change the priority of a task.
+///
+/// # Arguments
+///
+/// * `task_id` - The unique identifier of the task to update.
+/// * `priority` - The new priority of the task, lower values first.
+///
+/// # Returns
+///
+/// A SQL UPDATE statement, with the priority and the task ID bound to its
+/// placeholders, that changes the task's priority without affecting its
+/// current state or reason.
+pub(super) fn task_set_priority(task_id: u32, priority: u32) -> BoundSql {
+    BoundSql::new(
+        "UPDATE request_task SET priority = ? WHERE task_id = ?",
+        vec![priority as i64, task_id as i64],
+    )
+}
+
 // Test module included conditionally for unit testing
 #[cfg(all(not(feature = "oh"), test))]
 mod ut_sql {
@@ -36,6 +36,10 @@ use crate::utils::GetForegroundAbilities;
 mod recorder;
 pub(crate) mod sql;
 
+/// Time an application stays in the background before its tasks are
+/// treated as background tasks.
+pub(crate) const BACKGROUND_TIMEOUT: Duration = Duration::from_secs(60);
+
 /// Handler for managing and responding to system state changes.
 ///
 /// This struct coordinates system state information and triggers appropriate
@@ -200,16 +204,23 @@ impl Handler {
         if !self.foreground_abilities().contains(&uid) {
             return None;
         }
-        // Spawn a timer to handle background timeout after 60 seconds
-        let task_manager = self.task_manager.clone();
-        self.background_timeout.insert(
-            uid,
-            runtime_spawn(async move {
-                // Wait 60 seconds before triggering timeout
-                ylong_runtime::time::sleep(Duration::from_secs(60)).await;
-                task_manager.trigger_background_timeout(uid);
-            }),
-        );
+        // Under virtual time the simulation harness delivers the timeout
+        #[cfg(test)]
+        let spawn_timer = crate::utils::virtual_clock::now().is_none();
+        #[cfg(not(test))]
+        let spawn_timer = true;
+        if spawn_timer {
+            // Spawn a timer to handle background timeout after 60 seconds
+            let task_manager = self.task_manager.clone();
+            self.background_timeout.insert(
+                uid,
+                runtime_spawn(async move {
+                    // Wait 60 seconds before triggering timeout
+                    ylong_runtime::time::sleep(BACKGROUND_TIMEOUT).await;
+                    task_manager.trigger_background_timeout(uid);
+                }),
+            );
+        }
         // Update background state in recorder
         self.recorder.update_background(uid);
         None
@@ -16,11 +16,16 @@
 //! This module provides functionality to generate SQL statements that update
 //! task states in the database based on system state changes, including network
 //! status, account activity, and application foreground/background transitions.
+//!
+//! Only the state, reason and other constants of the service are written into
+//! the SQL text; values that come from the system, such as UIDs, account IDs
+//! and network types, are bound to `?` placeholders.
 
 use std::collections::HashSet;
 
 use crate::config::{Action, Mode, Version};
 use crate::info::State;
+use crate::manage::database::BoundSql;
 use crate::manage::network::{NetworkInfo, NetworkState, NetworkType};
 use crate::task::reason::Reason;
 
@@ -62,7 +67,7 @@ const API10: u8 = Version::API10 as u8;
 /// update task states based on system state changes.
 pub(crate) struct SqlList {
     /// Internal storage for SQL statements.
-    sqls: Vec<String>,
+    sqls: Vec<BoundSql>,
 }
 
 impl SqlList {
@@ -150,7 +155,7 @@ impl SqlList {
 }
 
 impl Iterator for SqlList {
-    type Item = String;
+    type Item = BoundSql;
 
     /// Returns the next SQL statement in the collection.
     ///
@@ -175,8 +180,8 @@ impl Iterator for SqlList {
 /// # Returns
 ///
 /// SQL statement to delete all tasks belonging to the uninstalled application.
-pub(crate) fn app_uninstall(uid: u64) -> String {
-    format!("DELETE FROM request_task WHERE uid = {}", uid)
+pub(crate) fn app_uninstall(uid: u64) -> BoundSql {
+    BoundSql::new("DELETE FROM request_task WHERE uid = ?", vec![uid as i64])
 }
 
 /// Generates SQL to update task states when an application becomes unavailable.
@@ -193,8 +198,8 @@ pub(crate) fn app_uninstall(uid: u64) -> String {
 /// - Uploads are set to failed state
 /// - Existing waiting tasks have their reasons combined with app background
 ///   reason
-pub(crate) fn app_state_unavailable(uid: u64) -> String {
-    format!(
+pub(crate) fn app_state_unavailable(uid: u64) -> BoundSql {
+    let sql = format!(
         "UPDATE request_task SET 
             state = CASE
                 WHEN (state = {RUNNING} OR state = {RETRYING}) AND action = {DOWNLOAD} THEN {WAITING}
@@ -215,8 +220,9 @@ pub(crate) fn app_state_unavailable(uid: u64) -> String {
                 ELSE reason 
             END
         WHERE 
-            uid = {uid} AND mode = {FRONTEND}",
-    )
+            uid = ? AND mode = {FRONTEND}",
+    );
+    BoundSql::new(sql, vec![uid as i64])
 }
 
 /// Generates SQL to update task states when an application becomes available.
@@ -229,8 +235,8 @@ pub(crate) fn app_state_unavailable(uid: u64) -> String {
 ///
 /// SQL statement to restore original task reasons when an application becomes
 /// available again.
-pub(crate) fn app_state_available(uid: u64) -> String {
-    format!(
+pub(crate) fn app_state_available(uid: u64) -> BoundSql {
+    let sql = format!(
         "UPDATE request_task SET 
             reason = CASE
                 WHEN reason = {APP_BACKGROUND_OR_TERMINATE} THEN {RUNNING_TASK_MEET_LIMITS}
@@ -240,8 +246,9 @@ pub(crate) fn app_state_available(uid: u64) -> String {
                 ELSE reason
             END
         WHERE 
-            state = {WAITING} AND uid = {uid}",
-    )
+            state = {WAITING} AND uid = ?",
+    );
+    BoundSql::new(sql, vec![uid as i64])
 }
 
 /// Generates SQL to update task states for inactive accounts.
@@ -254,7 +261,7 @@ pub(crate) fn app_state_available(uid: u64) -> String {
 ///
 /// SQL statement to update task states and reasons for tasks belonging to
 /// inactive accounts.
-pub(super) fn account_unavailable(active_accounts: &HashSet<u64>) -> String {
+pub(super) fn account_unavailable(active_accounts: &HashSet<u64>) -> BoundSql {
     let mut sql = format!(
         "UPDATE request_task SET 
             state = CASE
@@ -278,17 +285,10 @@ pub(super) fn account_unavailable(active_accounts: &HashSet<u64>) -> String {
             uid/200000 NOT IN (",
     );
 
-    // Add all active account IDs to the NOT IN clause
-    for active_account in active_accounts {
-        sql.push_str(&format!("{},", active_account));
-    }
-    // Remove trailing comma if accounts were added
-    if !active_accounts.is_empty() {
-        sql.pop();
-    }
-
+    // Add a placeholder for each active account ID to the NOT IN clause
+    sql.push_str(&account_placeholders(active_accounts));
     sql.push(')');
-    sql
+    BoundSql::new(sql, account_params(active_accounts))
 }
 
 /// Generates SQL to update task states for active accounts.
@@ -301,7 +301,7 @@ pub(super) fn account_unavailable(active_accounts: &HashSet<u64>) -> String {
 ///
 /// SQL statement to restore original task reasons for tasks belonging to active
 /// accounts.
-pub(super) fn account_available(active_accounts: &HashSet<u64>) -> String {
+pub(super) fn account_available(active_accounts: &HashSet<u64>) -> BoundSql {
     let mut sql = format!(
         "UPDATE request_task SET 
             reason = CASE
@@ -315,16 +315,21 @@ pub(super) fn account_available(active_accounts: &HashSet<u64>) -> String {
             state = {WAITING} AND uid/200000 IN (",
     );
 
-    // Add all active account IDs to the IN clause
-    for active_account in active_accounts {
-        sql.push_str(&format!("{},", active_account));
-    }
-    // Remove trailing comma if accounts were added
-    if !active_accounts.is_empty() {
-        sql.pop();
-    }
+    // Add a placeholder for each active account ID to the IN clause
+    sql.push_str(&account_placeholders(active_accounts));
     sql.push(')');
-    sql
+    BoundSql::new(sql, account_params(active_accounts))
+}
+
+/// Generates the comma-separated placeholders of a list of accounts.
+fn account_placeholders(accounts: &HashSet<u64>) -> String {
+    vec!["?"; accounts.len()].join(",")
+}
+
+/// Collects the account IDs bound to the placeholders of
+/// [`account_placeholders`], in the same order.
+fn account_params(accounts: &HashSet<u64>) -> Vec<i64> {
+    accounts.iter().map(|account| *account as i64).collect()
 }
 
 /// Generates SQL to update task states when network goes offline.
@@ -337,8 +342,8 @@ pub(super) fn account_available(active_accounts: &HashSet<u64>) -> String {
 /// - API10 background downloads with retry (wait)
 /// - API9 uploads (fail)
 /// - API10 foreground downloads or no retry (fail)
-pub(super) fn network_offline() -> String {
-    format!(
+pub(super) fn network_offline() -> BoundSql {
+    let sql = format!(
         "UPDATE request_task SET 
             state = CASE 
                 WHEN (state = {RUNNING} OR state = {RETRYING}) AND ((version = {API9} AND action = {DOWNLOAD}) OR (version = {API10} AND mode = {BACKGROUND} AND retry = 1)) THEN {WAITING}
@@ -357,7 +362,8 @@ pub(super) fn network_offline() -> String {
                     END
                 ELSE reason
             END"
-    )
+    );
+    BoundSql::new(sql, vec![])
 }
 
 /// Generates SQL to update task states for unsupported network conditions.
@@ -370,14 +376,14 @@ pub(super) fn network_offline() -> String {
 ///
 /// SQL statement to update task states and reasons for tasks that cannot run on
 /// the current network, or `None` if network type is Other.
-pub(super) fn network_unavailable(info: &NetworkInfo) -> Option<String> {
+pub(super) fn network_unavailable(info: &NetworkInfo) -> Option<BoundSql> {
     // Skip if network type is Other
     if info.network_type == NetworkType::Other {
         return None;
     }
 
     // Build condition for tasks that can't run on this network
-    let mut unsupported_condition = format!("network != {}", info.network_type.repr);
+    let mut unsupported_condition = String::from("network != ?");
 
     // Add metered condition if current network is metered
     if info.is_metered {
@@ -389,7 +395,7 @@ pub(super) fn network_unavailable(info: &NetworkInfo) -> Option<String> {
         unsupported_condition.push_str(" OR roaming = 0");
     }
 
-    Some(format!(
+    let sql = format!(
         "UPDATE request_task SET 
             state = CASE 
                 WHEN (state = {RUNNING} OR state = {RETRYING}) AND ((version = {API9} AND action = {DOWNLOAD}) OR (version = {API10} AND mode = {BACKGROUND} AND retry = 1)) THEN {WAITING}
@@ -410,7 +416,8 @@ pub(super) fn network_unavailable(info: &NetworkInfo) -> Option<String> {
             END
         WHERE 
             {unsupported_condition}"
-    ))
+    );
+    Some(BoundSql::new(sql, vec![info.network_type.repr as i64]))
 }
 
 /// Generates SQL to update task states when network becomes available.
@@ -423,7 +430,7 @@ pub(super) fn network_unavailable(info: &NetworkInfo) -> Option<String> {
 ///
 /// SQL statement to restore original task reasons for tasks that can run on the
 /// current network.
-pub(super) fn network_available(info: &NetworkInfo) -> String {
+pub(super) fn network_available(info: &NetworkInfo) -> BoundSql {
     let mut sql = format!(
         "UPDATE request_task SET 
             reason = CASE 
@@ -440,14 +447,11 @@ pub(super) fn network_available(info: &NetworkInfo) -> String {
 
     // Skip network-specific conditions if network type is Other
     if info.network_type == NetworkType::Other {
-        return sql;
+        return BoundSql::new(sql, vec![]);
     }
 
     // Add conditions for network type matching
-    sql.push_str(&format!(
-        " AND (network = 0 OR network = {}",
-        info.network_type.repr
-    ));
+    sql.push_str(" AND (network = 0 OR network = ?");
 
     // Add metered condition if current network is metered
     if info.is_metered {
@@ -460,7 +464,7 @@ pub(super) fn network_available(info: &NetworkInfo) -> String {
     }
 
     sql.push(')');
-    sql
+    BoundSql::new(sql, vec![info.network_type.repr as i64])
 }
 
 /// Generates SQL to update task states when a special process terminates.
@@ -472,14 +476,14 @@ pub(super) fn network_available(info: &NetworkInfo) -> String {
 /// # Returns
 ///
 /// SQL statement to set all tasks for the terminated process to failed state.
-pub(crate) fn special_process_terminate(uid: u64) -> String {
-    format!(
+pub(crate) fn special_process_terminate(uid: u64) -> BoundSql {
+    let sql = format!(
         "UPDATE request_task
         SET
             state = {FAILED},
             reason = {APP_BACKGROUND_OR_TERMINATE}
         WHERE
-            uid = {uid}
+            uid = ?
             AND (
                 state = {INITIALIZED}
                 OR state = {RUNNING}
@@ -488,7 +492,8 @@ pub(crate) fn special_process_terminate(uid: u64) -> String {
                 OR state = {PAUSED}
                 OR state = {STOPPED}
             );",
-    )
+    );
+    BoundSql::new(sql, vec![uid as i64])
 }
 
 #[cfg(feature = "oh")]
@@ -0,0 +1,409 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deterministic simulation of the task manager event loop.
+//!
+//! A `Simulation` owns a `TaskManager` without running its loop: events are
+//! handled one by one with `TaskManager::handle_event`, in the order they are
+//! sent. Network state, foreground applications, RSS level and accounts are
+//! injected as the system subscribers would inject them, downloads are served
+//! by the in-process `MockServer` of `request_utils` and time is virtual, so timers such as the
+//! background timeout fire only when the simulation advances the clock.
+//!
+//! Only the transfers themselves run in real time; `Simulation::settle` waits
+//! for them. After every handled event the states of the tasks are read from
+//! the database, so scenarios can assert on the transitions they went
+//! through and on the notifications sent to clients.
+
+mod scenario;
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::time::{Duration, Instant};
+
+pub(crate) use request_utils::test::server::{MockResponse, MockServer};
+pub(crate) use scenario::{Scenario, Step, TaskSpec};
+use ylong_runtime::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+
+use super::account::{set_active_accounts, AccountEvent};
+use super::database::RequestDb;
+use super::events::{ScheduleEvent, StateEvent, TaskManagerEvent};
+use super::network::NetworkInfo;
+use super::network_manager::NetworkManager;
+use super::scheduler::state::BACKGROUND_TIMEOUT;
+use super::task_manager::{
+    TaskManagerRx, TaskManagerTx, CLEAR_INTERVAL, RESTORE_ALL_TASKS_INTERVAL,
+};
+use super::TaskManager;
+use crate::config::{Action, ConfigBuilder};
+use crate::error::ErrorCode;
+use crate::info::State;
+use crate::service::active_counter::ActiveCounter;
+use crate::service::client::{ClientEvent, ClientManagerEntry};
+use crate::service::run_count::RunCountManagerEntry;
+use crate::task::notify::{SubscribeType, WaitingCause};
+use crate::tests::{lock_database, test_init, DatabaseLock};
+use crate::utils::virtual_clock;
+
+/// Virtual time at which every simulation starts, in milliseconds.
+const EPOCH: u64 = 1_700_000_000_000;
+
+/// Real time a simulation waits for its transfers before failing.
+const SETTLE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A task manager driven step by step.
+pub(crate) struct Simulation {
+    manager: TaskManager,
+    client_rx: UnboundedReceiver<ClientEvent>,
+    server: MockServer,
+    now: u64,
+    next_clear: u64,
+    restored: bool,
+    background_since: HashMap<u64, u64>,
+    tasks: HashMap<String, (u64, u32)>,
+    states: HashMap<u32, Vec<State>>,
+    notified: HashMap<u32, Vec<SubscribeType>>,
+    waiting: HashMap<u32, Vec<WaitingCause>>,
+    trace: Vec<String>,
+    _lock: DatabaseLock<'static>,
+}
+
+impl Simulation {
+    /// Creates a simulation with a Wi-Fi network online, at virtual time
+    /// `EPOCH`.
+    pub(crate) fn new() -> Self {
+        test_init();
+        let lock = lock_database();
+        virtual_clock::set(Some(EPOCH));
+
+        let (tx, rx) = unbounded_channel();
+        let task_manager_tx = TaskManagerTx::new(tx);
+        let rx = TaskManagerRx::new(rx);
+        let (tx, _rx) = unbounded_channel();
+        let run_count = RunCountManagerEntry::new(tx);
+        let (tx, client_rx) = unbounded_channel();
+        let client = ClientManagerEntry::new(tx);
+        let manager =
+            TaskManager::new(task_manager_tx, rx, run_count, client, ActiveCounter::new());
+
+        let mut simulation = Self {
+            manager,
+            client_rx,
+            server: MockServer::start(),
+            now: EPOCH,
+            next_clear: EPOCH + CLEAR_INTERVAL * 1000,
+            restored: false,
+            background_since: HashMap::new(),
+            tasks: HashMap::new(),
+            states: HashMap::new(),
+            notified: HashMap::new(),
+            waiting: HashMap::new(),
+            trace: Vec::new(),
+            _lock: lock,
+        };
+        simulation.online(NetworkInfo {
+            network_type: super::network::NetworkType::Wifi,
+            is_metered: false,
+            is_roaming: false,
+        });
+        simulation
+    }
+
+    /// Brings the network online.
+    pub(crate) fn online(&mut self, info: NetworkInfo) {
+        NetworkManager::get_instance()
+            .lock()
+            .unwrap()
+            .network
+            .inner
+            .notify_online(info);
+        self.dispatch(TaskManagerEvent::State(StateEvent::Network));
+    }
+
+    /// Takes the network offline.
+    pub(crate) fn offline(&mut self) {
+        NetworkManager::get_instance()
+            .lock()
+            .unwrap()
+            .network
+            .inner
+            .notify_offline();
+        self.dispatch(TaskManagerEvent::State(StateEvent::Network));
+    }
+
+    /// Brings an application to the foreground.
+    pub(crate) fn foreground(&mut self, uid: u64) {
+        self.background_since.remove(&uid);
+        self.dispatch(TaskManagerEvent::State(StateEvent::ForegroundApp(uid)));
+    }
+
+    /// Moves an application to the background, starting its background
+    /// timeout.
+    pub(crate) fn background(&mut self, uid: u64) {
+        self.background_since.insert(uid, self.now);
+        self.dispatch(TaskManagerEvent::State(StateEvent::Background(uid)));
+    }
+
+    /// Changes the RSS level of the device.
+    pub(crate) fn rss(&mut self, level: i32) {
+        self.dispatch(TaskManagerEvent::Device(level));
+    }
+
+    /// Replaces the active accounts.
+    pub(crate) fn accounts(&mut self, foreground: &[i32], background: &[i32]) {
+        set_active_accounts(foreground, background);
+        self.dispatch(TaskManagerEvent::Account(AccountEvent::Changed));
+    }
+
+    /// Gets the mock server of the simulation.
+    pub(crate) fn server(&self) -> &MockServer {
+        &self.server
+    }
+
+    /// Constructs a download task, known by its label from now on.
+    pub(crate) fn download(&mut self, spec: &TaskSpec) -> u32 {
+        let file_path = format!("test_files/simulation_{}", spec.label);
+        let file = File::create(file_path).unwrap();
+        let mut builder = ConfigBuilder::new();
+        builder
+            .action(Action::Download)
+            .mode(spec.mode)
+            .network(spec.network)
+            .file_spec(file)
+            .url(&self.server.url(&spec.path))
+            .redirect(true);
+        if let Some(uid) = spec.uid {
+            builder.uid(uid);
+        }
+        let config = builder.build();
+        let uid = config.common_data.uid;
+        let task_id = match self.manager.create(config) {
+            Ok(task_id) => task_id,
+            Err(e) => self.fail(&format!("constructing {} returns {:?}", spec.label, e)),
+        };
+        self.trace
+            .push(format!("construct {} as {}", spec.label, task_id));
+        self.tasks.insert(spec.label.clone(), (uid, task_id));
+        self.poll();
+        task_id
+    }
+
+    /// Starts a task.
+    pub(crate) fn start(&mut self, label: &str) -> ErrorCode {
+        let (uid, task_id) = self.task(label);
+        let ret = self.manager.start(uid, task_id);
+        self.after_call("start", label, ret)
+    }
+
+    /// Pauses a task.
+    pub(crate) fn pause(&mut self, label: &str) -> ErrorCode {
+        let (uid, task_id) = self.task(label);
+        let ret = self.manager.pause(uid, task_id);
+        self.after_call("pause", label, ret)
+    }
+
+    /// Resumes a task.
+    pub(crate) fn resume(&mut self, label: &str) -> ErrorCode {
+        let (uid, task_id) = self.task(label);
+        let ret = self.manager.resume(uid, task_id);
+        self.after_call("resume", label, ret)
+    }
+
+    /// Stops a task.
+    pub(crate) fn stop(&mut self, label: &str) -> ErrorCode {
+        let (uid, task_id) = self.task(label);
+        let ret = self.manager.stop(uid, task_id);
+        self.after_call("stop", label, ret)
+    }
+
+    /// Removes a task.
+    pub(crate) fn remove(&mut self, label: &str) -> ErrorCode {
+        let (uid, task_id) = self.task(label);
+        let ret = self.manager.remove(uid, task_id);
+        self.after_call("remove", label, ret)
+    }
+
+    /// Advances the virtual clock, firing the timers that expire meanwhile.
+    ///
+    /// The events of the timers are handled immediately; the events they
+    /// cause are handled by the next `settle`.
+    pub(crate) fn advance(&mut self, duration: Duration) {
+        self.now += duration.as_millis() as u64;
+        virtual_clock::set(Some(self.now));
+        self.trace
+            .push(format!("advance to +{}ms", self.now - EPOCH));
+
+        let timeout = BACKGROUND_TIMEOUT.as_millis() as u64;
+        let mut expired: Vec<u64> = self
+            .background_since
+            .iter()
+            .filter(|(_, since)| self.now - **since >= timeout)
+            .map(|(uid, _)| *uid)
+            .collect();
+        expired.sort_unstable();
+        for uid in expired {
+            self.background_since.remove(&uid);
+            self.dispatch(TaskManagerEvent::State(StateEvent::BackgroundTimeout(uid)));
+        }
+        if !self.restored && self.now >= EPOCH + RESTORE_ALL_TASKS_INTERVAL * 1000 {
+            self.restored = true;
+            self.dispatch(TaskManagerEvent::Schedule(ScheduleEvent::RestoreAllTasks));
+        }
+        while self.next_clear <= self.now {
+            self.next_clear += CLEAR_INTERVAL * 1000;
+            self.dispatch(TaskManagerEvent::Schedule(ScheduleEvent::ClearTimeoutTasks));
+        }
+    }
+
+    /// Handles the pending events until no task is running and no event is
+    /// pending.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the trace of the simulation if the tasks still run after
+    /// `SETTLE_TIMEOUT` of real time.
+    pub(crate) fn settle(&mut self) {
+        let begin = Instant::now();
+        loop {
+            while let Ok(event) = self.manager.rx.try_recv() {
+                self.dispatch(event);
+            }
+            self.drain_client();
+            if self.manager.scheduler.running_tasks() == 0 {
+                return;
+            }
+            if begin.elapsed() > SETTLE_TIMEOUT {
+                self.fail("tasks still running after the settle timeout");
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    /// Gets the current state of a task.
+    pub(crate) fn state(&self, label: &str) -> State {
+        let (_, task_id) = self.task(label);
+        match RequestDb::get_instance().get_task_info(task_id) {
+            Some(info) => State::from(info.progress.common_data.state),
+            None => State::Any,
+        }
+    }
+
+    /// Gets the states a task went through, without repetitions.
+    pub(crate) fn states(&self, label: &str) -> &[State] {
+        let (_, task_id) = self.task(label);
+        self.states.get(&task_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Gets the notifications sent for a task, in order.
+    pub(crate) fn notified(&self, label: &str) -> &[SubscribeType] {
+        let (_, task_id) = self.task(label);
+        self.notified.get(&task_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Gets the waiting causes notified for a task, in order.
+    pub(crate) fn waiting(&self, label: &str) -> &[WaitingCause] {
+        let (_, task_id) = self.task(label);
+        self.waiting.get(&task_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Panics with `message` and the trace of the simulation.
+    pub(crate) fn fail(&self, message: &str) -> ! {
+        panic!("{}\ntrace:\n  {}", message, self.trace.join("\n  "));
+    }
+
+    fn task(&self, label: &str) -> (u64, u32) {
+        match self.tasks.get(label) {
+            Some(task) => *task,
+            None => self.fail(&format!("unknown task {}", label)),
+        }
+    }
+
+    fn after_call(&mut self, call: &str, label: &str, ret: ErrorCode) -> ErrorCode {
+        self.trace
+            .push(format!("{} {} returns {:?}", call, label, ret));
+        self.poll();
+        ret
+    }
+
+    fn dispatch(&mut self, event: TaskManagerEvent) {
+        let name = format!("{:?}", event);
+        // The service is never unloaded in a simulation.
+        if matches!(
+            event,
+            TaskManagerEvent::Schedule(ScheduleEvent::Unload | ScheduleEvent::Shutdown)
+        ) {
+            self.trace.push(format!("ignore {}", name));
+            return;
+        }
+        self.trace.push(format!("handle {}", name));
+        self.manager.handle_event(event);
+        self.poll();
+    }
+
+    fn drain_client(&mut self) {
+        while let Ok(event) = self.client_rx.try_recv() {
+            match event {
+                ClientEvent::SendNotifyData(subscribe_type, data) => {
+                    self.notify(data.task_id, subscribe_type)
+                }
+                ClientEvent::SendFaults(task_id, subscribe_type, _) => {
+                    self.notify(task_id, subscribe_type)
+                }
+                ClientEvent::SendWaitNotify(task_id, cause) => {
+                    self.trace
+                        .push(format!("task {} waits for {:?}", task_id, cause));
+                    self.waiting.entry(task_id).or_default().push(cause);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn notify(&mut self, task_id: u32, subscribe_type: SubscribeType) {
+        let notified = self.notified.entry(task_id).or_default();
+        // Progress notifications repeat while transferring, keeps only one.
+        if subscribe_type == SubscribeType::Progress && notified.last() == Some(&subscribe_type) {
+            return;
+        }
+        self.trace
+            .push(format!("notify {:?} of task {}", subscribe_type, task_id));
+        notified.push(subscribe_type);
+    }
+
+    fn poll(&mut self) {
+        self.drain_client();
+        let db = RequestDb::get_instance();
+        for (label, (_, task_id)) in self.tasks.iter() {
+            let Some(info) = db.get_task_info(*task_id) else {
+                continue;
+            };
+            let state = State::from(info.progress.common_data.state);
+            let states = self.states.entry(*task_id).or_default();
+            if states.last() != Some(&state) {
+                self.trace.push(format!("{} becomes {:?}", label, state));
+                states.push(state);
+            }
+        }
+    }
+}
+
+impl Drop for Simulation {
+    fn drop(&mut self) {
+        virtual_clock::set(None);
+    }
+}
+
+#[cfg(test)]
+mod ut_scenario {
+    include!("../../../tests/ut/manage/simulation/ut_scenario.rs");
+}
@@ -0,0 +1,247 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scenarios: a list of steps run against a fresh `Simulation`.
+
+use std::time::Duration;
+
+use super::{MockResponse, Simulation};
+use crate::config::{Mode, NetworkConfig};
+use crate::error::ErrorCode;
+use crate::info::State;
+use crate::manage::network::NetworkInfo;
+use crate::task::notify::{SubscribeType, WaitingCause};
+
+/// Download task constructed by a scenario.
+#[derive(Clone, Debug)]
+pub(crate) struct TaskSpec {
+    /// Label naming the task in the following steps.
+    pub(crate) label: String,
+    /// Path of the download on the mock server.
+    pub(crate) path: String,
+    /// Owner of the task, the builder default if `None`.
+    pub(crate) uid: Option<u64>,
+    /// Mode of the task.
+    pub(crate) mode: Mode,
+    /// Network required by the task.
+    pub(crate) network: NetworkConfig,
+}
+
+impl TaskSpec {
+    /// Creates a background task downloading `path` on any network.
+    pub(crate) fn new(label: &str, path: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            path: path.to_string(),
+            uid: None,
+            mode: Mode::BackGround,
+            network: NetworkConfig::Any,
+        }
+    }
+
+    /// Sets the owner of the task.
+    pub(crate) fn uid(mut self, uid: u64) -> Self {
+        self.uid = Some(uid);
+        self
+    }
+
+    /// Sets the mode of the task.
+    pub(crate) fn mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the network required by the task.
+    pub(crate) fn network(mut self, network: NetworkConfig) -> Self {
+        self.network = network;
+        self
+    }
+}
+
+/// One step of a scenario.
+#[derive(Clone, Debug)]
+pub(crate) enum Step {
+    /// Brings the network online.
+    Online(NetworkInfo),
+    /// Takes the network offline.
+    Offline,
+    /// Brings an application to the foreground.
+    Foreground(u64),
+    /// Moves an application to the background.
+    Background(u64),
+    /// Changes the RSS level.
+    Rss(i32),
+    /// Replaces the foreground and background accounts.
+    Accounts(Vec<i32>, Vec<i32>),
+    /// Serves responses for a path of the mock server, in order, repeating
+    /// the last one.
+    Serve(&'static str, Vec<MockResponse>),
+    /// Constructs a download task.
+    Download(TaskSpec),
+    /// Starts a task, which must succeed.
+    Start(&'static str),
+    /// Pauses a task, which must succeed.
+    Pause(&'static str),
+    /// Resumes a task, which must succeed.
+    Resume(&'static str),
+    /// Stops a task, which must succeed.
+    Stop(&'static str),
+    /// Removes a task, which must succeed.
+    Remove(&'static str),
+    /// Advances the virtual clock.
+    Advance(Duration),
+    /// Handles the pending events until no task runs.
+    Settle,
+    /// Checks the current state of a task.
+    ExpectState(&'static str, State),
+    /// Checks that a task went through the states in this order, possibly
+    /// with other states between them.
+    ExpectPassed(&'static str, Vec<State>),
+    /// Checks that a task was notified of the subscribe type.
+    ExpectNotified(&'static str, SubscribeType),
+    /// Checks that a task was notified to wait for the cause.
+    ExpectWaiting(&'static str, WaitingCause),
+    /// Checks the paths requested from the mock server, in order.
+    ExpectRequests(Vec<&'static str>),
+}
+
+/// A named list of steps.
+pub(crate) struct Scenario {
+    name: &'static str,
+    steps: Vec<Step>,
+}
+
+impl Scenario {
+    /// Creates an empty scenario.
+    pub(crate) fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Appends a step.
+    pub(crate) fn step(mut self, step: Step) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Runs the steps against a new simulation.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the failing step and the trace of the simulation if a
+    /// step fails.
+    pub(crate) fn run(self) -> Simulation {
+        let mut simulation = Simulation::new();
+        for (index, step) in self.steps.into_iter().enumerate() {
+            let context = format!("scenario {} step {} {:?}", self.name, index, step);
+            run_step(&mut simulation, step, &context);
+        }
+        simulation
+    }
+}
+
+fn run_step(simulation: &mut Simulation, step: Step, context: &str) {
+    let ret = match step {
+        Step::Online(info) => {
+            simulation.online(info);
+            ErrorCode::ErrOk
+        }
+        Step::Offline => {
+            simulation.offline();
+            ErrorCode::ErrOk
+        }
+        Step::Foreground(uid) => {
+            simulation.foreground(uid);
+            ErrorCode::ErrOk
+        }
+        Step::Background(uid) => {
+            simulation.background(uid);
+            ErrorCode::ErrOk
+        }
+        Step::Rss(level) => {
+            simulation.rss(level);
+            ErrorCode::ErrOk
+        }
+        Step::Accounts(foreground, background) => {
+            simulation.accounts(&foreground, &background);
+            ErrorCode::ErrOk
+        }
+        Step::Serve(path, responses) => {
+            simulation.server().route(path, responses);
+            ErrorCode::ErrOk
+        }
+        Step::Download(spec) => {
+            simulation.download(&spec);
+            ErrorCode::ErrOk
+        }
+        Step::Start(label) => simulation.start(label),
+        Step::Pause(label) => simulation.pause(label),
+        Step::Resume(label) => simulation.resume(label),
+        Step::Stop(label) => simulation.stop(label),
+        Step::Remove(label) => simulation.remove(label),
+        Step::Advance(duration) => {
+            simulation.advance(duration);
+            ErrorCode::ErrOk
+        }
+        Step::Settle => {
+            simulation.settle();
+            ErrorCode::ErrOk
+        }
+        Step::ExpectState(label, state) => {
+            let current = simulation.state(label);
+            if current != state {
+                simulation.fail(&format!("{}: state is {:?}", context, current));
+            }
+            ErrorCode::ErrOk
+        }
+        Step::ExpectPassed(label, expected) => {
+            let states = simulation.states(label);
+            let mut passed = states.iter();
+            if !expected.iter().all(|state| passed.any(|s| s == state)) {
+                simulation.fail(&format!("{}: went through {:?}", context, states));
+            }
+            ErrorCode::ErrOk
+        }
+        Step::ExpectNotified(label, subscribe_type) => {
+            let notified = simulation.notified(label);
+            if !notified.contains(&subscribe_type) {
+                simulation.fail(&format!("{}: notified {:?}", context, notified));
+            }
+            ErrorCode::ErrOk
+        }
+        Step::ExpectWaiting(label, cause) => {
+            let waiting = simulation.waiting(label);
+            if !waiting.contains(&cause) {
+                simulation.fail(&format!("{}: waiting for {:?}", context, waiting));
+            }
+            ErrorCode::ErrOk
+        }
+        Step::ExpectRequests(paths) => {
+            let requests: Vec<_> = simulation
+                .server()
+                .requests()
+                .iter()
+                .map(|request| request.path().to_string())
+                .collect();
+            if requests != paths {
+                simulation.fail(&format!("{}: requested {:?}", context, requests));
+            }
+            ErrorCode::ErrOk
+        }
+    };
+    if ret != ErrorCode::ErrOk {
+        simulation.fail(&format!("{}: returns {:?}", context, ret));
+    }
+}
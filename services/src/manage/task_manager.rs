@@ -38,8 +38,8 @@ use super::events::{
     QueryEvent, ScheduleEvent, ServiceEvent, StateEvent, TaskEvent, TaskManagerEvent,
 };
 use crate::config::{Action, Mode};
-use crate::database::clear_database_by_state;
 use crate::database::monitor_database;
+use crate::database::{archive, clear_database_by_state};
 use crate::error::ErrorCode;
 use crate::info::{State, TaskInfo};
 use crate::manage::app_state::AppUninstallSubscriber;
@@ -56,11 +56,11 @@ use crate::utils::task_event_count::{task_complete_add, task_fail_add, task_unlo
 use crate::utils::{get_current_timestamp, runtime_spawn, subscribe_common_event, update_policy};
 
 /// Interval (in seconds) for clearing timeout tasks.
-const CLEAR_INTERVAL: u64 = 30 * 60;
+pub(crate) const CLEAR_INTERVAL: u64 = 30 * 60;
 
 /// Interval (in seconds) before restoring all tasks after service
 /// initialization.
-const RESTORE_ALL_TASKS_INTERVAL: u64 = 10;
+pub(crate) const RESTORE_ALL_TASKS_INTERVAL: u64 = 10;
 
 // TaskManager initialization logic:
 //
@@ -231,27 +231,39 @@ impl TaskManager {
                     continue;
                 }
             };
+            self.handle_event(event);
+        }
+    }
 
-            match event {
-                TaskManagerEvent::Service(event) => self.handle_service_event(event),
-                TaskManagerEvent::State(event) => self.handle_state_event(event),
-                TaskManagerEvent::Task(event) => self.handle_task_event(event),
-                TaskManagerEvent::Schedule(event) => {
-                    if self.handle_schedule_event(event) {
-                        info!("TaskManager unload ok");
-                        // If unload_sa success, can not breaks this loop.
-                    }
-                }
-                TaskManagerEvent::Device(level) => {
-                    self.scheduler.on_rss_change(level);
+    /// Handles one event of the task manager.
+    ///
+    /// Delegates to the specialized handler of the event type. The main loop
+    /// calls it for every received event; the simulation harness calls it
+    /// directly to process events in a deterministic order.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The event to handle
+    pub(crate) fn handle_event(&mut self, event: TaskManagerEvent) {
+        match event {
+            TaskManagerEvent::Service(event) => self.handle_service_event(event),
+            TaskManagerEvent::State(event) => self.handle_state_event(event),
+            TaskManagerEvent::Task(event) => self.handle_task_event(event),
+            TaskManagerEvent::Schedule(event) => {
+                if self.handle_schedule_event(event) {
+                    info!("TaskManager unload ok");
+                    // If unload_sa success, can not breaks this loop.
                 }
-                TaskManagerEvent::Account(event) => self.handle_account_event(event),
-                TaskManagerEvent::Query(query) => self.handle_query_event(query),
-                TaskManagerEvent::Reschedule => self.scheduler.reschedule(),
             }
-
-            debug!("TaskManager handles events finished");
+            TaskManagerEvent::Device(level) => {
+                self.scheduler.on_rss_change(level);
+            }
+            TaskManagerEvent::Account(event) => self.handle_account_event(event),
+            TaskManagerEvent::Query(query) => self.handle_query_event(query),
+            TaskManagerEvent::Reschedule => self.scheduler.reschedule(),
         }
+
+        debug!("TaskManager handles events finished");
     }
 
     /// Handles account-related events.
@@ -297,6 +309,9 @@ impl TaskManager {
             ServiceEvent::Resume(uid, task_id, tx) => {
                 let _ = tx.send(self.resume(uid, task_id));
             }
+            ServiceEvent::RetryFailedFiles(uid, task_id, tx) => {
+                let _ = tx.send(self.retry_failed_files(uid, task_id));
+            }
             ServiceEvent::Remove(uid, task_id, tx) => {
                 let _ = tx.send(self.remove(uid, task_id));
             }
@@ -315,6 +330,12 @@ impl TaskManager {
             ServiceEvent::SetMode(uid, task_id, mode, tx) => {
                 let _ = tx.send(self.set_mode(uid, task_id, mode));
             }
+            ServiceEvent::SetPriority(uid, task_id, priority, tx) => {
+                let _ = tx.send(self.set_priority(uid, task_id, priority));
+            }
+            ServiceEvent::MoveToFront(uid, task_id, tx) => {
+                let _ = tx.send(self.move_to_front(uid, task_id));
+            }
         }
     }
 
@@ -347,6 +368,9 @@ impl TaskManager {
                 .on_state_change(Handler::update_background_timeout, uid),
             StateEvent::AppUninstall(uid) => {
                 self.scheduler.on_state_change(Handler::app_uninstall, uid);
+                if let Err(e) = archive::delete_uid_history(uid) {
+                    error!("delete history of uninstalled uid {} failed: {}", uid, e);
+                }
             }
             StateEvent::SpecialTerminate(uid) => {
                 self.scheduler
@@ -491,10 +515,17 @@ impl TaskManager {
             }
         }
 
-        // Step 3: Clear group notification info
+        // Step 3: Trim the task history archive by its retention policy
+        if let Err(e) =
+            archive::apply_retention(archive::retention_policy(), get_current_timestamp())
+        {
+            error!("Archive retention failed: {}", e);
+        }
+
+        // Step 4: Clear group notification info
         NotificationDispatcher::get_instance().clear_group_info();
 
-        // Step 4: Checkpoint the WAL after all cleanup so deleted pages are merged back into
+        // Step 5: Checkpoint the WAL after all cleanup so deleted pages are merged back into
         // the main DB and the -wal file is reset, rather than accumulating across cycles.
         crate::database::checkpoint_wal();
         true
@@ -0,0 +1,162 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Chunked upload configuration of upload tasks.
+//!
+//! This module lets apps turn a single-file upload task into a chunked upload,
+//! where the file is sent in several requests followed by an optional
+//! finalize request.
+
+use std::collections::HashMap;
+
+use ipc::parcel::MsgParcel;
+use ipc::{IpcResult, IpcStatusCode};
+
+use crate::database::chunk::{self, ChunkConfig};
+use crate::error::ErrorCode;
+use crate::manage::database::RequestDb;
+use crate::service::permission::PermissionChecker;
+use crate::service::RequestServiceStub;
+use crate::task::chunked::is_same_origin;
+use crate::task::config::Action;
+use crate::task::info::State;
+
+/// Maximum number of chunk headers accepted.
+const CHUNK_HEADERS_MAX: u32 = 100;
+
+impl RequestServiceStub {
+    /// Makes an upload task a chunked upload.
+    ///
+    /// The configuration is used from the next time the task runs, and the
+    /// chunks uploaded with a previous configuration are forgotten.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Message parcel containing the task ID, chunk size,
+    ///   parallelism, retry count, chunk URL, method and headers, and the
+    ///   finalize URL, method, body and part templates
+    /// * `reply` - Message parcel to write the error code to
+    ///
+    /// # Errors
+    ///
+    /// Returns error codes in the reply parcel:
+    /// * `ErrOk` - Configuration set successfully
+    /// * `ParameterCheck` - The configuration is out of the supported limits,
+    ///   or a chunk or finalize URL does not have the scheme and host of the
+    ///   task URL
+    /// * `TaskNotFound` - Invalid task ID, task does not exist, or permission denied
+    /// * `TaskStateErr` - Task is not a single-file upload task or is running
+    /// * `Other` - The configuration could not be stored
+    pub(crate) fn set_chunked_upload(
+        &self,
+        data: &mut MsgParcel,
+        reply: &mut MsgParcel,
+    ) -> IpcResult<()> {
+        let task_id: String = data.read()?;
+        let chunk_size: u64 = data.read()?;
+        let parallelism: u32 = data.read()?;
+        let max_retries: u32 = data.read()?;
+        let url: String = data.read()?;
+        let method: String = data.read()?;
+        let len: u32 = data.read()?;
+        if len > CHUNK_HEADERS_MAX {
+            error!("Service set_chunked_upload: out of size: {}", len);
+            reply.write(&(ErrorCode::ParameterCheck as i32))?;
+            return Err(IpcStatusCode::Failed);
+        }
+        let mut headers = HashMap::new();
+        for _ in 0..len {
+            let key: String = data.read()?;
+            let value: String = data.read()?;
+            headers.insert(key, value);
+        }
+        let config = ChunkConfig {
+            chunk_size,
+            parallelism,
+            max_retries,
+            url,
+            method,
+            headers,
+            finalize_url: data.read()?,
+            finalize_method: data.read()?,
+            finalize_body: data.read()?,
+            finalize_part: data.read()?,
+        };
+        info!(
+            "Service set_chunked_upload {}, chunk size {}, parallelism {}",
+            task_id, chunk_size, parallelism
+        );
+
+        let Ok(task_id) = task_id.parse::<u32>() else {
+            error!(
+                "Service set_chunked_upload, failed: tid not valid: {}",
+                task_id
+            );
+            reply.write(&(ErrorCode::TaskNotFound as i32))?;
+            return Err(IpcStatusCode::Failed);
+        };
+
+        let uid = ipc::Skeleton::calling_uid();
+        if !PermissionChecker::check_down_permission() && !self.check_task_uid(task_id, uid) {
+            error!(
+                "Service set_chunked_upload, failed: check task uid. tid: {}, uid: {}",
+                task_id, uid
+            );
+            reply.write(&(ErrorCode::TaskNotFound as i32))?;
+            return Err(IpcStatusCode::Failed);
+        }
+
+        if !config.is_valid() {
+            error!(
+                "Service set_chunked_upload, tid: {}, invalid config",
+                task_id
+            );
+            reply.write(&(ErrorCode::ParameterCheck as i32))?;
+            return Err(IpcStatusCode::Failed);
+        }
+
+        let db = RequestDb::get_instance();
+        let code = match (db.get_task_config(task_id), db.query_task_state(task_id)) {
+            (Some(conf), Some(state)) => {
+                if conf.common_data.action != Action::Upload || conf.file_specs.len() != 1 {
+                    ErrorCode::TaskStateErr
+                } else if state == State::Running.repr {
+                    ErrorCode::TaskStateErr
+                } else if !(config.url.is_empty() || is_same_origin(&config.url, &conf.url))
+                    || !(config.finalize_url.is_empty()
+                        || is_same_origin(&config.finalize_url, &conf.url))
+                {
+                    error!(
+                        "Service set_chunked_upload, tid: {}, url not on the task url origin",
+                        task_id
+                    );
+                    ErrorCode::ParameterCheck
+                } else {
+                    match chunk::set_chunk_config(task_id, &config) {
+                        Ok(()) => ErrorCode::ErrOk,
+                        Err(e) => {
+                            error!(
+                                "Service set_chunked_upload, tid: {}, failed: {}",
+                                task_id, e
+                            );
+                            ErrorCode::Other
+                        }
+                    }
+                }
+            }
+            _ => ErrorCode::TaskNotFound,
+        };
+        reply.write(&(code as i32))?;
+        Ok(())
+    }
+}
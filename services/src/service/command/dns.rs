@@ -0,0 +1,106 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! DNS options of tasks.
+//!
+//! This module lets apps pin addresses to the hosts of a task and choose the
+//! address families it uses, on top of the DNS options of the app.
+
+use ipc::parcel::MsgParcel;
+use ipc::{IpcResult, IpcStatusCode};
+
+use crate::database::dns::{self, TaskDns, MAX_DNS_OVERRIDES};
+use crate::error::ErrorCode;
+use crate::manage::database::RequestDb;
+use crate::service::permission::PermissionChecker;
+use crate::service::RequestServiceStub;
+use crate::task::info::State;
+
+impl RequestServiceStub {
+    /// Sets the DNS options of a task, empty options remove them.
+    ///
+    /// The options are used from the next time the task runs.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Message parcel containing the task ID, the pinned addresses
+    ///   as `host:port:addr[,addr]` and the IP preference
+    /// * `reply` - Message parcel to write the error code to
+    ///
+    /// # Errors
+    ///
+    /// Returns error codes in the reply parcel:
+    /// * `ErrOk` - Options set successfully
+    /// * `ParameterCheck` - Too many pinned addresses, or an option is invalid
+    /// * `TaskNotFound` - Invalid task ID, task does not exist, or permission denied
+    /// * `TaskStateErr` - Task is running
+    /// * `Other` - The options could not be stored
+    pub(crate) fn set_task_dns(
+        &self,
+        data: &mut MsgParcel,
+        reply: &mut MsgParcel,
+    ) -> IpcResult<()> {
+        let task_id: String = data.read()?;
+        let len: u32 = data.read()?;
+        if len as usize > MAX_DNS_OVERRIDES {
+            error!("Service set_task_dns: out of size: {}", len);
+            reply.write(&(ErrorCode::ParameterCheck as i32))?;
+            return Err(IpcStatusCode::Failed);
+        }
+        let mut overrides = Vec::with_capacity(len as usize);
+        for _ in 0..len {
+            overrides.push(data.read::<String>()?);
+        }
+        let options = TaskDns {
+            overrides,
+            ip_preference: data.read()?,
+        };
+        info!("Service set_task_dns {}, {} overrides", task_id, len);
+
+        let Ok(task_id) = task_id.parse::<u32>() else {
+            error!("Service set_task_dns, failed: tid not valid: {}", task_id);
+            reply.write(&(ErrorCode::TaskNotFound as i32))?;
+            return Err(IpcStatusCode::Failed);
+        };
+
+        let uid = ipc::Skeleton::calling_uid();
+        if !PermissionChecker::check_down_permission() && !self.check_task_uid(task_id, uid) {
+            error!(
+                "Service set_task_dns, failed: check task uid. tid: {}, uid: {}",
+                task_id, uid
+            );
+            reply.write(&(ErrorCode::TaskNotFound as i32))?;
+            return Err(IpcStatusCode::Failed);
+        }
+
+        if !options.is_valid() {
+            error!("Service set_task_dns, tid: {}, invalid options", task_id);
+            reply.write(&(ErrorCode::ParameterCheck as i32))?;
+            return Err(IpcStatusCode::Failed);
+        }
+
+        let code = match RequestDb::get_instance().query_task_state(task_id) {
+            Some(state) if state == State::Running.repr => ErrorCode::TaskStateErr,
+            Some(_) => match dns::set_task_dns(task_id, &options) {
+                Ok(()) => ErrorCode::ErrOk,
+                Err(e) => {
+                    error!("Service set_task_dns, tid: {}, failed: {}", task_id, e);
+                    ErrorCode::Other
+                }
+            },
+            None => ErrorCode::TaskNotFound,
+        };
+        reply.write(&(code as i32))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Transfer history commands.
+//!
+//! This module lets apps list the archived records of their finished tasks,
+//! lets system tools list the records of any bundle, and lets system tools
+//! configure how long records are retained.
+
+use ipc::parcel::MsgParcel;
+use ipc::{IpcResult, IpcStatusCode};
+
+use crate::database::archive::{self, RetentionPolicy};
+use crate::error::ErrorCode;
+use crate::manage::query::SearchMethod;
+use crate::service::permission::PermissionChecker;
+use crate::service::RequestServiceStub;
+use crate::utils::{is_system_api, is_valid_bundle_name};
+
+impl RequestServiceStub {
+    /// Lists archived records of finished tasks, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Message parcel containing the bundle name (used only by
+    ///   system callers with manager permission, `"*"` for all bundles), the
+    ///   lower `mtime` bound and the maximum number of records
+    /// * `reply` - Message parcel to write the error code and records to
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the records were written to the reply
+    /// * `Err(IpcStatusCode::Failed)` - If the bundle name is invalid
+    ///
+    /// # Notes
+    ///
+    /// Callers without manager permission only see records of their own uid.
+    pub(crate) fn query_history(
+        &self,
+        data: &mut MsgParcel,
+        reply: &mut MsgParcel,
+    ) -> IpcResult<()> {
+        let bundle: String = data.read()?;
+        let after: i64 = data.read()?;
+        let limit: u32 = data.read()?;
+
+        let method = if is_system_api() && PermissionChecker::check_manager().get_action().is_some()
+        {
+            if bundle != "*" && !is_valid_bundle_name(&bundle) {
+                error!("Service query_history: invalid bundle name {}", bundle);
+                reply.write(&(ErrorCode::ParameterCheck as i32))?;
+                return Err(IpcStatusCode::Failed);
+            }
+            SearchMethod::System(bundle)
+        } else {
+            SearchMethod::User(ipc::Skeleton::calling_uid())
+        };
+
+        let records = archive::query_history(method, after.max(0) as u64, limit);
+        debug!("Service query_history: {} records", records.len());
+
+        reply.write(&(ErrorCode::ErrOk as i32))?;
+        reply.write(&(records.len() as u32))?;
+        for record in records.iter() {
+            reply.write(&record.task_id.to_string())?;
+            reply.write(&record.bundle)?;
+            reply.write(&record.url_host)?;
+            reply.write(&(record.action as u32))?;
+            reply.write(&(record.state as u32))?;
+            reply.write(&(record.reason as u32))?;
+            reply.write(&record.total_processed)?;
+            reply.write(&record.ctime)?;
+            reply.write(&record.mtime)?;
+            reply.write(&record.task_time)?;
+            reply.write(&record.tries)?;
+        }
+        Ok(())
+    }
+
+    /// Sets the retention policy of the transfer history.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Message parcel containing the maximum record age in
+    ///   milliseconds and the maximum number of records kept per app
+    /// * `reply` - Message parcel to write the operation result to
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the policy was stored
+    /// * `Err(IpcStatusCode::Failed)` - If the caller is not allowed to change
+    ///   the policy or the policy could not be stored
+    ///
+    /// # Notes
+    ///
+    /// Requires a system API caller with `DOWNLOAD_SESSION_MANAGER`
+    /// permission. The policy is enforced during the next database
+    /// maintenance.
+    pub(crate) fn set_history_retention(
+        &self,
+        data: &mut MsgParcel,
+        reply: &mut MsgParcel,
+    ) -> IpcResult<()> {
+        if !is_system_api() {
+            error!("Service set_history_retention: not system api");
+            reply.write(&(ErrorCode::SystemApi as i32))?;
+            return Err(IpcStatusCode::Failed);
+        }
+        if !PermissionChecker::check_down_permission() {
+            error!("Service set_history_retention: no DOWNLOAD_SESSION_MANAGER permission.");
+            reply.write(&(ErrorCode::Permission as i32))?;
+            return Err(IpcStatusCode::Failed);
+        }
+
+        let max_age: u64 = data.read()?;
+        let max_rows_per_app: u32 = data.read()?;
+        let policy = RetentionPolicy {
+            max_age,
+            max_rows_per_app: max_rows_per_app as u64,
+        };
+        info!("Service set_history_retention {:?}", policy);
+
+        if let Err(e) = archive::set_retention_policy(policy) {
+            error!("Service set_history_retention failed: {}", e);
+            reply.write(&(ErrorCode::Other as i32))?;
+            return Err(IpcStatusCode::Failed);
+        }
+        reply.write(&(ErrorCode::ErrOk as i32))?;
+        Ok(())
+    }
+}
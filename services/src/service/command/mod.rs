@@ -19,9 +19,12 @@
 
 use crate::error::ErrorCode;
 
+mod chunked_upload; // Chunked upload configuration of upload tasks
 mod construct;      // Task creation and configuration
+mod dns;            // DNS options of tasks
 mod dump;           // Task information dumping utilities
 mod get_task;       // Task configuration retrieval
+mod history;        // Transfer history of finished tasks
 mod notification_bar; // Notification system integration
 mod open_channel;   // Channel establishment for data transfer
 mod pause;          // Task pause operations
@@ -29,14 +32,17 @@ mod query;          // Task state and information queries
 mod query_mime_type; // MIME type detection for resources
 mod remove;         // Task deletion operations
 mod resume;         // Task resumption operations
+mod retry_files;    // Failed file retry and file error policy of uploads
 mod search;         // Task searching functionality
 mod set_max_speed;  // Bandwidth control for tasks
 mod set_mode;       // Task execution mode configuration
+mod set_priority;   // Task priority and queue order changes
 mod show;           // Task visibility management
 mod start;          // Task start operations
 mod stop;           // Task termination operations
 mod sub_runcount;   // Running count subscription
 mod subscribe;      // Task event subscription
+mod suggested_name; // Saving downloads under the server-suggested name
 mod touch;          // Task metadata updates
 mod unsub_runcount; // Running count unsubscription
 mod unsubscribe;    // Task event unsubscription
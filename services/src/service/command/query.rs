@@ -33,7 +33,8 @@ impl RequestServiceStub {
     ///
     /// # Arguments
     ///
-    /// * `data` - Message parcel containing the task IDs to query
+    /// * `data` - Message parcel containing the task IDs to query, optionally
+    ///   followed by the client interface version
     /// * `reply` - Message parcel to write the results to
     ///
     /// # Returns
@@ -147,6 +148,8 @@ impl RequestServiceStub {
                 }
             };
         }
+        // Clients older than the interface version field do not send it
+        let version: u32 = data.read().unwrap_or(0);
         
         // Send successful operation status
         reply.write(&(ErrorCode::ErrOk as i32))?;
@@ -155,7 +158,7 @@ impl RequestServiceStub {
         for (c, info) in vec {
             reply.write(&(c as i32))?;
             // TODO: Sends info only when ErrOk.
-            serialize_task_info(info, reply)?;
+            serialize_task_info(info, version, reply)?;
         }
         Ok(())
     }
@@ -19,17 +19,24 @@
 use ipc::parcel::MsgParcel;
 use ipc::{IpcResult, IpcStatusCode};
 
+use crate::database::suggested_name;
 use crate::error::ErrorCode;
 use crate::manage::query;
 use crate::service::permission::PermissionChecker;
-use crate::service::RequestServiceStub;
+use crate::service::{interface, RequestServiceStub};
 
 impl RequestServiceStub {
     /// Queries the MIME type of a specified task.
     ///
+    /// The MIME type is sniffed from the content when the server sent none or
+    /// a generic one. For clients of `SUGGESTED_NAME_VERSION` or later, it is
+    /// followed in the reply by the file name suggested by the server, empty
+    /// if none.
+    ///
     /// # Arguments
     ///
-    /// * `data` - Message parcel containing the task ID to query
+    /// * `data` - Message parcel containing the task ID to query, optionally
+    ///   followed by the client interface version
     /// * `reply` - Message parcel to write the result to
     ///
     /// # Returns
@@ -66,6 +73,8 @@ impl RequestServiceStub {
         
         // Read and log the task ID from the incoming parcel
         let task_id: String = data.read()?;
+        // Clients older than the interface version field do not send it
+        let version: u32 = data.read().unwrap_or(0);
         info!("Service query mime type tid {}", task_id);
 
         // Validate and convert task ID to integer format
@@ -91,9 +100,12 @@ impl RequestServiceStub {
         // Retrieve MIME type from the query module
         let mime = query::query_mime_type(uid, task_id);
 
-        // Send successful response with MIME type
+        // Send successful response with MIME type and suggested file name
         reply.write(&(ErrorCode::ErrOk as i32))?;
         reply.write(&mime)?;
+        if version >= interface::SUGGESTED_NAME_VERSION {
+            reply.write(&suggested_name::suggested_name(task_id).name)?;
+        }
         Ok(())
     }
 }
@@ -0,0 +1,187 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Failed file retry and file error policy of multi-file upload tasks.
+//!
+//! This module lets apps re-run only the files of an upload task that failed,
+//! and choose whether an upload continues with the remaining files or aborts
+//! when one of them fails.
+
+use ipc::parcel::MsgParcel;
+use ipc::{IpcResult, IpcStatusCode};
+
+use crate::database::file_status;
+use crate::error::ErrorCode;
+use crate::manage::database::RequestDb;
+use crate::manage::events::TaskManagerEvent;
+use crate::service::command::{set_code_with_index, CONTROL_MAX};
+use crate::service::permission::PermissionChecker;
+use crate::service::RequestServiceStub;
+use crate::task::config::Action;
+use crate::task::files::check_current_account;
+
+impl RequestServiceStub {
+    /// Re-runs only the failed files of multiple upload tasks.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Message parcel containing count and task IDs to retry
+    /// * `reply` - Message parcel to write operation results to
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the retry operation completed
+    /// * `Err(IpcStatusCode::Failed)` - If input validation failed or permission denied
+    ///
+    /// # Errors
+    ///
+    /// Returns error codes in the reply parcel:
+    /// * `ErrOk` - Task re-queued successfully
+    /// * `Permission` - Caller lacks required internet or download permission
+    /// * `TaskNotFound` - Invalid task ID, task does not exist, or permission denied
+    /// * `TaskStateErr` - Task is not a failed upload task with failed files
+    /// * `Other` - Input size exceeds maximum allowed or other system error
+    pub(crate) fn retry_failed_files(
+        &self,
+        data: &mut MsgParcel,
+        reply: &mut MsgParcel,
+    ) -> IpcResult<()> {
+        debug!("Service retry_failed_files");
+        let permission = PermissionChecker::check_down_permission();
+        if !PermissionChecker::check_internet() && !permission {
+            error!("Service retry_failed_files: no INTERNET permission.");
+            reply.write(&(ErrorCode::Permission as i32))?;
+            return Err(IpcStatusCode::Failed);
+        }
+
+        let len: u32 = data.read()?;
+        let len = len as usize;
+        if len > CONTROL_MAX {
+            info!("Service retry_failed_files: out of size: {}", len);
+            reply.write(&(ErrorCode::Other as i32))?;
+            return Err(IpcStatusCode::Failed);
+        }
+
+        let mut vec = vec![ErrorCode::Other; len];
+        let ipc_uid = ipc::Skeleton::calling_uid();
+
+        for i in 0..len {
+            let task_id: String = data.read()?;
+            info!("Service retry_failed_files {}", task_id);
+            let Ok(task_id) = task_id.parse::<u32>() else {
+                error!("Service retry_failed_files, failed: tid not valid: {}", task_id);
+                set_code_with_index(&mut vec, i, ErrorCode::TaskNotFound);
+                continue;
+            };
+
+            let Some(task_uid) = RequestDb::get_instance().query_task_uid(task_id) else {
+                set_code_with_index(&mut vec, i, ErrorCode::TaskNotFound);
+                continue;
+            };
+            if !check_current_account(task_uid) || (task_uid != ipc_uid && !permission) {
+                error!(
+                    "Service retry_failed_files, failed: check task uid. tid: {}, uid: {}",
+                    task_id, ipc_uid
+                );
+                set_code_with_index(&mut vec, i, ErrorCode::TaskNotFound);
+                continue;
+            }
+
+            let (event, rx) = TaskManagerEvent::retry_failed_files(task_uid, task_id);
+            if !self.task_manager.lock().unwrap().send_event(event) {
+                error!("Service retry_failed_files, failed: task_manager err: {}", task_id);
+                set_code_with_index(&mut vec, i, ErrorCode::Other);
+                continue;
+            }
+            let Some(ret) = rx.get() else {
+                error!(
+                    "Service retry_failed_files, tid: {}, failed: receives ret failed",
+                    task_id
+                );
+                set_code_with_index(&mut vec, i, ErrorCode::Other);
+                continue;
+            };
+            set_code_with_index(&mut vec, i, ret);
+            if ret != ErrorCode::ErrOk {
+                error!(
+                    "Service retry_failed_files, tid: {}, failed: {}",
+                    task_id, ret as i32
+                );
+            }
+        }
+
+        reply.write(&(ErrorCode::ErrOk as i32))?;
+        for ret in vec {
+            reply.write(&(ret as i32))?;
+        }
+        Ok(())
+    }
+
+    /// Sets whether an upload task continues with its remaining files after
+    /// one of them fails, instead of aborting on the first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Message parcel containing the task ID and the policy
+    /// * `reply` - Message parcel to write the error code to
+    ///
+    /// # Errors
+    ///
+    /// Returns error codes in the reply parcel:
+    /// * `ErrOk` - Policy set successfully
+    /// * `TaskNotFound` - Invalid task ID, task does not exist, or permission denied
+    /// * `TaskStateErr` - Task is not an upload task
+    /// * `Other` - The policy could not be stored
+    pub(crate) fn set_file_error_policy(
+        &self,
+        data: &mut MsgParcel,
+        reply: &mut MsgParcel,
+    ) -> IpcResult<()> {
+        let task_id: String = data.read()?;
+        let continue_on_error: bool = data.read()?;
+        info!(
+            "Service set_file_error_policy {}, continue {}",
+            task_id, continue_on_error
+        );
+
+        let Ok(task_id) = task_id.parse::<u32>() else {
+            error!("Service set_file_error_policy, failed: tid not valid: {}", task_id);
+            reply.write(&(ErrorCode::TaskNotFound as i32))?;
+            return Err(IpcStatusCode::Failed);
+        };
+
+        let uid = ipc::Skeleton::calling_uid();
+        if !PermissionChecker::check_down_permission() && !self.check_task_uid(task_id, uid) {
+            error!(
+                "Service set_file_error_policy, failed: check task uid. tid: {}, uid: {}",
+                task_id, uid
+            );
+            reply.write(&(ErrorCode::TaskNotFound as i32))?;
+            return Err(IpcStatusCode::Failed);
+        }
+
+        let code = match RequestDb::get_instance().query_task_action(task_id) {
+            None => ErrorCode::TaskNotFound,
+            Some(action) if action != Action::Upload => ErrorCode::TaskStateErr,
+            Some(_) => match file_status::set_continue_on_file_error(task_id, continue_on_error) {
+                Ok(()) => ErrorCode::ErrOk,
+                Err(e) => {
+                    error!("Service set_file_error_policy, tid: {}, failed: {}", task_id, e);
+                    ErrorCode::Other
+                }
+            },
+        };
+        reply.write(&(code as i32))?;
+        Ok(())
+    }
+}
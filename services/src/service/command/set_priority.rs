@@ -0,0 +1,211 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Task queue ordering functionality.
+//!
+//! This module provides methods to change the priority of a task and to move
+//! a task to the front of its application's queue, letting download managers
+//! reorder queued tasks at runtime.
+
+use ipc::parcel::MsgParcel;
+use ipc::{IpcResult, IpcStatusCode};
+
+use crate::error::ErrorCode;
+use crate::manage::database::RequestDb;
+use crate::manage::events::TaskManagerEvent;
+use crate::service::permission::PermissionChecker;
+use crate::service::RequestServiceStub;
+use crate::utils::Recv;
+
+impl RequestServiceStub {
+    /// Changes the priority of a task.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Message parcel containing the task ID and the new priority
+    /// * `reply` - Message parcel to write the operation result to
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the request was processed
+    /// * `Err(IpcStatusCode::Failed)` - If the task ID is invalid or the
+    ///   caller may not access the task
+    ///
+    /// # Errors
+    ///
+    /// Returns error codes in the reply parcel:
+    /// * `ErrOk` - Priority changed successfully
+    /// * `TaskNotFound` - Invalid task ID or task not accessible by the caller
+    /// * `Other` - General failure in task manager or result retrieval
+    ///
+    /// # Notes
+    ///
+    /// Lower priority values run first. A waiting task takes its new place in
+    /// the queue at the next reschedule.
+    pub(crate) fn set_priority(
+        &self,
+        data: &mut MsgParcel,
+        reply: &mut MsgParcel,
+    ) -> IpcResult<()> {
+        let task_id: String = data.read()?;
+        let priority: u32 = data.read()?;
+        info!("Service set_priority tid {} priority {}", task_id, priority);
+
+        let Some((uid, task_id)) = self.check_priority_task("set_priority", &task_id, reply)?
+        else {
+            return Err(IpcStatusCode::Failed);
+        };
+        let (event, rx) = TaskManagerEvent::set_priority(uid, task_id, priority);
+        self.send_priority_event("set_priority", event, rx, task_id, reply)
+    }
+
+    /// Moves a task to the front of its application's queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Message parcel containing the task ID
+    /// * `reply` - Message parcel to write the operation result to
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the request was processed
+    /// * `Err(IpcStatusCode::Failed)` - If the task ID is invalid or the
+    ///   caller may not access the task
+    ///
+    /// # Errors
+    ///
+    /// Returns error codes in the reply parcel:
+    /// * `ErrOk` - Task moved successfully
+    /// * `TaskNotFound` - Invalid task ID or task not accessible by the caller
+    /// * `TaskStateErr` - The task is not waiting or running
+    /// * `Other` - General failure in task manager or result retrieval
+    ///
+    /// # Notes
+    ///
+    /// The task runs before every other task of the same mode of its
+    /// application; tasks of other applications are not affected.
+    pub(crate) fn move_to_front(
+        &self,
+        data: &mut MsgParcel,
+        reply: &mut MsgParcel,
+    ) -> IpcResult<()> {
+        let task_id: String = data.read()?;
+        info!("Service move_to_front tid {}", task_id);
+
+        let Some((uid, task_id)) = self.check_priority_task("move_to_front", &task_id, reply)?
+        else {
+            return Err(IpcStatusCode::Failed);
+        };
+        let (event, rx) = TaskManagerEvent::move_to_front(uid, task_id);
+        self.send_priority_event("move_to_front", event, rx, task_id, reply)
+    }
+
+    /// Parses a task ID and checks that the caller may reorder the task.
+    ///
+    /// `op` names the operation in the logs and fault events. Returns the
+    /// owner UID and task ID, or writes `TaskNotFound` to the reply and
+    /// returns `None`.
+    fn check_priority_task(
+        &self,
+        op: &str,
+        task_id: &str,
+        reply: &mut MsgParcel,
+    ) -> IpcResult<Option<(u64, u32)>> {
+        let Ok(task_id) = task_id.parse::<u32>() else {
+            error!("Service {}, failed: tid not valid: {}", op, task_id);
+            sys_event!(
+                ExecError,
+                DfxCode::INVALID_IPC_MESSAGE_A47,
+                &format!("Service {}, failed: tid not valid: {}", op, task_id)
+            );
+            reply.write(&(ErrorCode::TaskNotFound as i32))?;
+            return Ok(None);
+        };
+
+        let calling_uid = ipc::Skeleton::calling_uid();
+        if !PermissionChecker::check_down_permission() && !self.check_task_uid(task_id, calling_uid)
+        {
+            error!(
+                "Service {}, failed: check task uid. tid: {}, uid: {}",
+                op, task_id, calling_uid
+            );
+            sys_event!(
+                ExecError,
+                DfxCode::INVALID_IPC_MESSAGE_A47,
+                &format!(
+                    "Service {}, failed: check task uid. tid: {}, uid: {}",
+                    op, task_id, calling_uid
+                )
+            );
+            reply.write(&(ErrorCode::TaskNotFound as i32))?;
+            return Ok(None);
+        }
+
+        match RequestDb::get_instance().query_task_uid(task_id) {
+            Some(uid) => Ok(Some((uid, task_id))),
+            None => {
+                error!("Service {}, failed: task not found: {}", op, task_id);
+                sys_event!(
+                    ExecError,
+                    DfxCode::INVALID_IPC_MESSAGE_A47,
+                    &format!("Service {}, failed: task not found: {}", op, task_id)
+                );
+                reply.write(&(ErrorCode::TaskNotFound as i32))?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Sends a priority event to the task manager and writes its result to
+    /// the reply.
+    fn send_priority_event(
+        &self,
+        op: &str,
+        event: TaskManagerEvent,
+        rx: Recv<ErrorCode>,
+        task_id: u32,
+        reply: &mut MsgParcel,
+    ) -> IpcResult<()> {
+        if !self.task_manager.lock().unwrap().send_event(event) {
+            error!("Service {}, failed: task_manager err: {}", op, task_id);
+            sys_event!(
+                ExecError,
+                DfxCode::INVALID_IPC_MESSAGE_A48,
+                &format!("Service {}, failed: task_manager err: {}", op, task_id)
+            );
+            reply.write(&(ErrorCode::Other as i32))?;
+            return Err(IpcStatusCode::Failed);
+        }
+        let Some(ret) = rx.get() else {
+            error!(
+                "Service {}, tid: {}, failed: receives ret failed",
+                op, task_id
+            );
+            sys_event!(
+                ExecError,
+                DfxCode::INVALID_IPC_MESSAGE_A48,
+                &format!(
+                    "Service {}, tid: {}, failed: receives ret failed",
+                    op, task_id
+                )
+            );
+            reply.write(&(ErrorCode::Other as i32))?;
+            return Err(IpcStatusCode::Failed);
+        };
+        if ret != ErrorCode::ErrOk {
+            error!("Service {}, tid: {}, failed: {}", op, task_id, ret as i32);
+        }
+        reply.write(&(ret as i32))?;
+        Ok(())
+    }
+}
@@ -32,7 +32,8 @@ impl RequestServiceStub {
     ///
     /// # Arguments
     ///
-    /// * `data` - Message parcel containing count and task IDs to query
+    /// * `data` - Message parcel containing count and task IDs to query,
+    ///   optionally followed by the client interface version
     /// * `reply` - Message parcel to write operation results to
     ///
     /// # Returns
@@ -143,6 +144,8 @@ impl RequestServiceStub {
                 }
             };
         }
+        // Clients older than the interface version field do not send it
+        let version: u32 = data.read().unwrap_or(0);
         
         // Write overall operation success code
         reply.write(&(ErrorCode::ErrOk as i32))?;
@@ -151,7 +154,7 @@ impl RequestServiceStub {
         for (c, info) in vec {
             reply.write(&(c as i32))?;
             // TODO: Sends info only when ErrOk.
-            serialize_task_info(info, reply)?;
+            serialize_task_info(info, version, reply)?;
         }
         Ok(())
     }
@@ -0,0 +1,116 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Saving of downloads under the server-suggested file name.
+//!
+//! This module lets apps have a download task move its file, once
+//! downloaded, into a directory under the name suggested by the server.
+
+use ipc::parcel::MsgParcel;
+use ipc::{IpcResult, IpcStatusCode};
+use request_utils::file_control::{belong_app_base, check_standardized_path};
+
+use crate::database::suggested_name;
+use crate::error::ErrorCode;
+use crate::manage::database::RequestDb;
+use crate::service::permission::PermissionChecker;
+use crate::service::RequestServiceStub;
+use crate::task::config::Action;
+use crate::task::info::State;
+
+impl RequestServiceStub {
+    /// Sets the directory a download task saves its file to under the
+    /// server-suggested name, an empty directory keeps the file at its
+    /// configured path.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Message parcel containing the task ID and the directory
+    /// * `reply` - Message parcel to write the error code to
+    ///
+    /// # Errors
+    ///
+    /// Returns error codes in the reply parcel:
+    /// * `ErrOk` - Directory set successfully
+    /// * `ParameterCheck` - The directory is not a standardized sandbox path
+    /// * `TaskNotFound` - Invalid task ID, task does not exist, or permission denied
+    /// * `TaskStateErr` - Task is not a single-file download to a sandbox
+    ///   path, or is running
+    /// * `Other` - The directory could not be stored
+    pub(crate) fn set_save_as_suggested_name(
+        &self,
+        data: &mut MsgParcel,
+        reply: &mut MsgParcel,
+    ) -> IpcResult<()> {
+        let task_id: String = data.read()?;
+        let dir: String = data.read()?;
+        info!("Service set_save_as_suggested_name {}", task_id);
+
+        let Ok(task_id) = task_id.parse::<u32>() else {
+            error!(
+                "Service set_save_as_suggested_name, failed: tid not valid: {}",
+                task_id
+            );
+            reply.write(&(ErrorCode::TaskNotFound as i32))?;
+            return Err(IpcStatusCode::Failed);
+        };
+
+        let uid = ipc::Skeleton::calling_uid();
+        if !PermissionChecker::check_down_permission() && !self.check_task_uid(task_id, uid) {
+            error!(
+                "Service set_save_as_suggested_name, failed: check task uid. tid: {}, uid: {}",
+                task_id, uid
+            );
+            reply.write(&(ErrorCode::TaskNotFound as i32))?;
+            return Err(IpcStatusCode::Failed);
+        }
+
+        if !dir.is_empty() && (!check_standardized_path(&dir) || !belong_app_base(&dir)) {
+            error!(
+                "Service set_save_as_suggested_name, tid: {}, invalid dir",
+                task_id
+            );
+            reply.write(&(ErrorCode::ParameterCheck as i32))?;
+            return Err(IpcStatusCode::Failed);
+        }
+
+        let db = RequestDb::get_instance();
+        let code = match (db.get_task_config(task_id), db.query_task_state(task_id)) {
+            (Some(conf), Some(state)) => {
+                if conf.common_data.action != Action::Download
+                    || conf.file_specs.len() != 1
+                    || conf.file_specs[0].is_user_file
+                    || state == State::Running.repr
+                {
+                    ErrorCode::TaskStateErr
+                } else {
+                    match suggested_name::set_save_dir(task_id, &dir)
+                        .and_then(|()| suggested_name::set_saved_path(task_id, ""))
+                    {
+                        Ok(()) => ErrorCode::ErrOk,
+                        Err(e) => {
+                            error!(
+                                "Service set_save_as_suggested_name, tid: {}, failed: {}",
+                                task_id, e
+                            );
+                            ErrorCode::Other
+                        }
+                    }
+                }
+            }
+            _ => ErrorCode::TaskNotFound,
+        };
+        reply.write(&(code as i32))?;
+        Ok(())
+    }
+}
@@ -32,7 +32,8 @@ impl RequestServiceStub {
     ///
     /// # Arguments
     ///
-    /// * `data` - Message parcel containing task IDs and tokens
+    /// * `data` - Message parcel containing task IDs and tokens, optionally
+    ///   followed by the client interface version
     /// * `reply` - Message parcel to write operation results to
     ///
     /// # Returns
@@ -144,6 +145,8 @@ impl RequestServiceStub {
                 }
             };
         }
+        // Clients older than the interface version field do not send it
+        let version: u32 = data.read().unwrap_or(0);
         
         // Write overall operation success
         reply.write(&(ErrorCode::ErrOk as i32))?;
@@ -152,7 +155,7 @@ impl RequestServiceStub {
         for (c, info) in vec {
             reply.write(&(c as i32))?;
             // TODO: Sends info only when ErrOk.
-            serialize_task_info(info, reply)?;
+            serialize_task_info(info, version, reply)?;
         }
         Ok(())
     }
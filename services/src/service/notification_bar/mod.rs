@@ -23,8 +23,10 @@ mod notification_config;
 mod notify_flow;
 mod progress_percentage;
 mod progress_size;
+mod progress_speed;
 mod publish;
 mod task_handle;
+mod throttle;
 mod typology;
 
 // Re-export for internal use within the service
@@ -59,9 +61,14 @@ mod ffi {
         want_agent: String,
         request_id: u32,
         uid: u32,
+        /// Whether the notification is updated live; a notification carrying
+        /// `buttons` is published as a live view as well, the only template
+        /// delivering action buttons.
         live_view: bool,
         progress_circle: ProgressCircle,
         x_mark: bool,
+        /// Names of the action buttons, empty if none.
+        buttons: Vec<String>,
     }
 
     /// Progress circle information for notifications.
@@ -75,6 +82,16 @@ mod ffi {
         total: u64,
     }
 
+    /// File opened by the "open" button of a completed download notification.
+    ///
+    /// An empty `uri` means there is no file to open.
+    #[derive(Eq, PartialEq, Debug)]
+    struct OpenTarget {
+        uri: String,
+        mime_type: String,
+        uid: u64,
+    }
+
     // Rust functions exposed to C++
     extern "Rust" {
         /// Wrapper around task management functionality for notification callbacks.
@@ -115,6 +132,42 @@ mod ffi {
         /// * `true` - If the task was successfully stopped
         /// * `false` - If the task stop failed
         fn stop_task(self: &TaskManagerWrapper, task_id: u32) -> bool;
+
+        /// Re-queues the specified failed task.
+        /// 
+        /// # Arguments
+        /// 
+        /// * `task_id` - The ID of the task to retry
+        /// 
+        /// # Returns
+        /// 
+        /// * `true` - If the task was successfully re-queued
+        /// * `false` - If the task retry failed
+        fn retry_task(self: &TaskManagerWrapper, task_id: u32) -> bool;
+
+        /// Re-queues every failed task of the specified notification group.
+        /// 
+        /// # Arguments
+        /// 
+        /// * `group_id` - The ID of the group whose failed tasks are retried
+        /// 
+        /// # Returns
+        /// 
+        /// * `true` - If at least one task was successfully re-queued
+        /// * `false` - If no task was re-queued
+        fn retry_group(self: &TaskManagerWrapper, group_id: u32) -> bool;
+
+        /// Gets the file to open for the specified completed download task.
+        /// 
+        /// # Arguments
+        /// 
+        /// * `task_id` - The ID of the completed download task
+        /// 
+        /// # Returns
+        /// 
+        /// The file URI and MIME type, with an empty URI if there is no file
+        /// to open
+        fn open_target(self: &TaskManagerWrapper, task_id: u32) -> OpenTarget;
     }
 
     // C++ functions exposed to Rust
@@ -143,6 +196,18 @@ mod ffi {
         /// 
         /// The requested system resource string
         fn GetSystemResourceString(name: &str) -> String;
+
+        /// Retrieves a plural string from system resources by name.
+        /// 
+        /// # Arguments
+        /// 
+        /// * `name` - The name of the plural resource string to retrieve
+        /// * `quantity` - The quantity selecting the plural form
+        /// 
+        /// # Returns
+        /// 
+        /// The requested plural form, or an empty string if it is not found
+        fn GetSystemResourcePluralString(name: &str, quantity: i32) -> String;
         
         /// Gets the current system language setting.
         /// 
@@ -21,15 +21,19 @@
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
+use ylong_runtime::fastrand::fast_random;
 use ylong_runtime::sync::mpsc::{self, UnboundedReceiver};
 
 use super::database::{CustomizedNotification, NotificationDb};
 use super::ffi::{NotifyContent, PublishNotification};
 use super::task_handle::cancel_notification;
+use super::throttle::{NotifyThrottle, FLUSH_INTERVAL};
 use super::NotificationDispatcher;
 use crate::config::Action;
 use crate::info::State;
+use crate::task::reason::Reason;
 use crate::manage::database::RequestDb;
 use crate::utils::{get_current_timestamp, runtime_spawn};
 use crate::task::config::Version;
@@ -40,6 +44,18 @@ use crate::task::config::Version;
 /// Set to 1ms in test mode and 500ms in normal operation.
 const NOTIFY_PROGRESS_INTERVAL: u64 = if cfg!(test) { 1 } else { 500 };
 
+/// Visibility of summary groups, both progress and completion notifications.
+const SUMMARY_GROUP_VISIBILITY: u32 = 0b11;
+
+/// First ID of the range reserved for summary groups.
+///
+/// Task IDs are generated below one billion on the device and app groups are created below this
+/// range, so the notification of a summary group never replaces another one.
+pub(crate) const SUMMARY_GROUP_ID_BASE: u32 = 0xF000_0000;
+
+/// Minimum interval in milliseconds between two transfer speed measurements.
+const SPEED_SAMPLE_INTERVAL: u64 = 1000;
+
 /// Manages the notification flow for download tasks and task groups.
 /// 
 /// Handles notification processing, updates, and publishing based on task events,
@@ -58,6 +74,13 @@ pub(crate) struct NotifyFlow {
     group_customized_notify: HashMap<u32, Option<CustomizedNotification>>,
     // Customized notification content for individual tasks
     task_customized_notify: HashMap<u32, Option<CustomizedNotification>>,
+    // Transfer speed measurements of individual tasks
+    task_speed: HashMap<u32, SpeedSample>,
+    // Update budgets and active individual notifications of each app
+    throttle: NotifyThrottle,
+    // Summary group collecting the tasks of each app beyond the individual limit,
+    // kept in memory only so a restarted service opens a new summary group
+    summary_groups: HashMap<u64, u32>,
     // Cached visibility settings
     group_progress_visibility: HashMap<u32, bool>,
     group_completion_visibility: HashMap<u32, bool>,
@@ -135,22 +158,17 @@ impl GroupProgress {
             return;
         }
         
-        // Update success/failure counters based on state transition
-        if *prev != State::Completed && *prev != State::Failed {
-            // Transition from active to terminal state
-            if state == State::Completed {
-                self.successful += 1;
-            } else if state == State::Failed {
-                self.failed += 1;
-            }
-        } else if state == State::Completed {
-            // Transition from failed to completed
-            self.successful += 1;
+        // Leave the terminal state counted so far, e.g. a retried failed task
+        if *prev == State::Completed {
+            self.successful -= 1;
+        } else if *prev == State::Failed {
             self.failed -= 1;
+        }
+        // Enter the new terminal state, if any
+        if state == State::Completed {
+            self.successful += 1;
         } else if state == State::Failed {
-            // Transition from completed to failed
             self.failed += 1;
-            self.successful -= 1;
         }
         *prev = state;
     }
@@ -186,6 +204,54 @@ impl GroupProgress {
     }
 }
 
+/// Transfer speed measurement of a task from its progress updates.
+pub(crate) struct SpeedSample {
+    // Time of the last measurement, in milliseconds
+    time: u64,
+    // Processed bytes at the last measurement
+    processed: u64,
+    // Speed in bytes per second from the last measurement
+    speed: Option<u64>,
+}
+
+impl SpeedSample {
+    /// Creates a speed measurement starting at the given progress.
+    pub(crate) fn new(time: u64, processed: u64) -> Self {
+        Self {
+            time,
+            processed,
+            speed: None,
+        }
+    }
+
+    /// Updates the measurement with a new progress.
+    /// 
+    /// The speed is measured again once `SPEED_SAMPLE_INTERVAL` has passed
+    /// since the last measurement, and restarted if the progress went back.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `time` - Current time in milliseconds
+    /// * `processed` - The new processed byte count
+    /// 
+    /// # Returns
+    /// 
+    /// The speed in bytes per second, if measured
+    pub(crate) fn update(&mut self, time: u64, processed: u64) -> Option<u64> {
+        if processed < self.processed {
+            *self = Self::new(time, processed);
+            return None;
+        }
+        let elapsed = time.saturating_sub(self.time);
+        if elapsed >= SPEED_SAMPLE_INTERVAL {
+            self.speed = Some((processed - self.processed) * 1000 / elapsed);
+            self.time = time;
+            self.processed = processed;
+        }
+        self.speed
+    }
+}
+
 /// Progress update payload for a single task's notification bar item.
 ///
 /// Carries the task identity and progress snapshot used to render a progress
@@ -209,6 +275,8 @@ pub struct ProgressNotify {
     pub(crate) file_name: String,
     /// API version in use
     pub(crate) version: Version,
+    /// Transfer speed in bytes per second (if measured)
+    pub(crate) speed: Option<u64>,
 }
 
 #[derive(Clone, Debug)]
@@ -225,6 +293,8 @@ pub(crate) struct EventualNotify {
     pub(crate) file_name: String,
     /// Whether the task completed successfully
     pub(crate) is_successful: bool,
+    /// Reason the task failed
+    pub(crate) reason: Reason,
 }
 
 #[derive(Debug)]
@@ -239,6 +309,8 @@ pub(crate) enum NotifyInfo {
     Unregister(u64, u32, u32),
     /// Group completion notification
     GroupEventual(u32, u64),
+    /// Cancel an individual task notification
+    Cancel(u64, u32),
 }
 
 #[derive(Clone, Copy)]
@@ -267,6 +339,9 @@ impl NotifyFlow {
             last_notify_map: HashMap::new(),
             group_notify_progress: HashMap::new(),
             task_customized_notify: HashMap::new(),
+            task_speed: HashMap::new(),
+            throttle: NotifyThrottle::new(),
+            summary_groups: HashMap::new(),
             group_customized_notify: HashMap::new(),
             completion_visibility: HashMap::new(),
             progress_visibility: HashMap::new(),
@@ -283,9 +358,24 @@ impl NotifyFlow {
     pub(crate) fn run(mut self) {
         runtime_spawn(async move {
             loop {
-                let info = match self.rx.recv().await {
-                    Ok(message) => message,
-                    Err(e) => {
+                let message = if self.throttle.has_pending() {
+                    // Wake up when the budget refills to publish the pending updates
+                    ylong_runtime::time::timeout(
+                        Duration::from_millis(FLUSH_INTERVAL),
+                        self.rx.recv(),
+                    )
+                    .await
+                    .ok()
+                } else {
+                    Some(self.rx.recv().await)
+                };
+                for content in self.throttle.flush(get_current_timestamp()) {
+                    PublishNotification(&content);
+                }
+                let info = match message {
+                    None => continue,
+                    Some(Ok(message)) => message,
+                    Some(Err(e)) => {
                         error!("Notification flow channel error: {:?}", e);
                         sys_event!(
                             ExecFault,
@@ -306,6 +396,7 @@ impl NotifyFlow {
                     NotifyInfo::Unregister(uid, task_id, group_id) => {
                         self.unregister_task(uid, task_id, group_id)
                     }
+                    NotifyInfo::Cancel(uid, task_id) => self.cancel_task(uid, task_id),
                 } {
                     PublishNotification(&content);
                 }
@@ -313,6 +404,19 @@ impl NotifyFlow {
         });
     }
 
+    /// Cancels the individual notification of a task.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `uid` - User identifier
+    /// * `task_id` - Task whose notification is cancelled
+    fn cancel_task(&mut self, uid: u64, task_id: u32) -> Option<NotifyContent> {
+        self.throttle.finish(uid, task_id);
+        self.task_speed.remove(&task_id);
+        cancel_notification(task_id);
+        None
+    }
+
     /// Handles task unregistration from notifications.
     /// 
     /// # Arguments
@@ -347,12 +451,16 @@ impl NotifyFlow {
             progress.task_state.remove(&task_id);
         }
         if progress.task_state.is_empty() {
+            self.throttle.finish(uid, group_id);
+            self.summary_groups.retain(|_, summary| *summary != group_id);
             cancel_notification(group_id);
             return None;
         }
         if !Self::group_eventual_check(&self.database, progress, group_id) {
             return None;
         }
+        self.throttle.finish(uid, group_id);
+        self.summary_groups.retain(|_, summary| *summary != group_id);
         if !is_completion_visible {
             cancel_notification(group_id);
             return None;
@@ -563,8 +671,11 @@ impl NotifyFlow {
     /// 
     /// * `Some(NotifyContent)` - If a notification should be published
     /// * `None` - If no notification is needed
-    fn publish_progress_notification(&mut self, info: ProgressNotify) -> Option<NotifyContent> {
-        let content = match self.get_request_id(info.task_id) {
+    fn publish_progress_notification(&mut self, mut info: ProgressNotify) -> Option<NotifyContent> {
+        let request = self.get_request_id(info.task_id);
+        // The text of a group counts its tasks, that of a task shows its speed
+        let text_visible = matches!(request, NotifyType::Group(_));
+        let content = match request {
             NotifyType::Group(group_id) => {
                 if !self.check_progress_visibility_from_group(group_id) {
                     return None;
@@ -580,6 +691,8 @@ impl NotifyFlow {
                     }
                 };
                 progress.update_task_progress(info.task_id, info.processed);
+                // A task reporting progress is running again, e.g. after a retry
+                progress.update_task_state(info.task_id, State::Running);
 
                 if !progress_interval_check {
                     return None;
@@ -593,18 +706,24 @@ impl NotifyFlow {
                 )
             }
             NotifyType::Task => {
+                let current = get_current_timestamp();
+                info.speed = match self.task_speed.entry(info.task_id) {
+                    Entry::Occupied(entry) => entry.into_mut().update(current, info.processed),
+                    Entry::Vacant(entry) => {
+                        entry.insert(SpeedSample::new(current, info.processed));
+                        None
+                    }
+                };
                 if info.version == Version::API9 {
                     // Get gauge value and return notification content only when gauge is true
-                    return NotificationDispatcher::get_instance()
+                    NotificationDispatcher::get_instance()
                         .get_task_gauge(info.task_id)
-                        .filter(|&gauge| gauge)
-                        .map(|_| NotifyContent::task_progress_notify(
-                            self.task_customized_notify(info.task_id),
-                            &info,
-                        ));
-                }
-                if !self.check_progress_visibility(info.task_id) {
+                        .filter(|&gauge| gauge)?;
+                } else if !self.check_progress_visibility(info.task_id) {
                     return None;
+                } else if !self.throttle.admit_task(info.uid, info.task_id) {
+                    // Too many individual notifications, move the task to the app summary
+                    return self.coalesce_task(info);
                 }
                 NotifyContent::task_progress_notify(
                     self.task_customized_notify(info.task_id),
@@ -612,7 +731,65 @@ impl NotifyFlow {
                 )
             }
         };
-        Some(content)
+        self.throttle
+            .throttle_progress(info.uid, content, text_visible, get_current_timestamp())
+    }
+
+    /// Moves a task into the summary group of its app and publishes the
+    /// group progress instead of an individual notification.
+    /// 
+    /// The summary group is created when needed and completes like any other
+    /// group once all its tasks have finished. Its ID is taken from the range
+    /// starting at `SUMMARY_GROUP_ID_BASE`.
+    ///
+    /// Like the groups created by apps, the summary group and the membership
+    /// of its tasks are stored in the notification database, from which the
+    /// group progress and the group of each task are read. They are cleared
+    /// with the group once all its tasks succeed, or with the groups unused
+    /// for a week if some failed and may still be retried together.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `info` - Progress notification information of the task
+    /// 
+    /// # Returns
+    /// 
+    /// * `Some(NotifyContent)` - If a notification should be published
+    /// * `None` - If no notification is needed
+    fn coalesce_task(&mut self, info: ProgressNotify) -> Option<NotifyContent> {
+        let group_id = match self.summary_groups.get(&info.uid) {
+            Some(group_id) => *group_id,
+            None => {
+                let group_id = loop {
+                    let candidate = SUMMARY_GROUP_ID_BASE
+                        + fast_random() as u32 % (u32::MAX - SUMMARY_GROUP_ID_BASE);
+                    if !self.database.contains_group(candidate) {
+                        break candidate;
+                    }
+                };
+                info!(
+                    "Create summary group {} for uid {} at task {}",
+                    group_id, info.uid, info.task_id
+                );
+                self.database.update_group_config(
+                    group_id,
+                    true,
+                    get_current_timestamp(),
+                    true,
+                    SUMMARY_GROUP_VISIBILITY,
+                    info.uid,
+                );
+                // Tasks are added by the flow only, the group completes with them
+                self.database.disable_attach_group(group_id);
+                self.summary_groups.insert(info.uid, group_id);
+                group_id
+            }
+        };
+        self.database.update_task_group(info.task_id, group_id);
+        self.notify_type_map
+            .insert(info.task_id, NotifyType::Group(group_id));
+        self.task_speed.remove(&info.task_id);
+        self.publish_progress_notification(info)
     }
 
     /// Checks if enough time has passed since the last notification.
@@ -655,6 +832,8 @@ impl NotifyFlow {
     /// * `Some(NotifyContent)` - If a notification should be published
     /// * `None` - If no notification is needed
     fn publish_completed_notify(&mut self, info: &EventualNotify) -> Option<NotifyContent> {
+        self.task_speed.remove(&info.task_id);
+        self.throttle.finish(info.uid, info.task_id);
         let content = match self.get_request_id(info.task_id) {
             NotifyType::Group(group_id) => {
                 let is_progress_visible = self.check_progress_visibility_from_group(group_id);
@@ -682,16 +861,25 @@ impl NotifyFlow {
                     Self::group_eventual_check(&self.database, group_progress, group_id);
 
                 match (group_eventual, is_progress_visible) {
-                    (false, true) => NotifyContent::group_progress_notify(
-                        customized,
-                        info.action,
-                        group_id,
-                        info.uid as u32,
-                        group_progress,
-                    ),
+                    (false, true) => {
+                        let content = NotifyContent::group_progress_notify(
+                            customized,
+                            info.action,
+                            group_id,
+                            info.uid as u32,
+                            group_progress,
+                        );
+                        self.throttle.record_progress(&content, true);
+                        content
+                    }
                     (false, false) => return None,
                     (true, _) => {
-                        self.database.clear_group_info(group_id);
+                        // Keep the group of failed tasks so they can be retried together
+                        if group_progress.failed() == 0 {
+                            self.database.clear_group_info(group_id);
+                        }
+                        self.throttle.finish(info.uid, group_id);
+                        self.summary_groups.retain(|_, summary| *summary != group_id);
                         if !is_completion_visible {
                             cancel_notification(group_id);
                             return None;
@@ -720,6 +908,7 @@ impl NotifyFlow {
                     info.uid as u32,
                     info.file_name.clone(),
                     info.is_successful,
+                    info.reason,
                 );
                 if info.is_successful {
                     self.database.clear_task_info(info.task_id);
@@ -757,6 +946,8 @@ impl NotifyFlow {
         if !group_eventual {
             return None;
         }
+        self.throttle.finish(uid, group_id);
+        self.summary_groups.retain(|_, summary| *summary != group_id);
         if !is_completion_visible {
             cancel_notification(group_id);
             return None;
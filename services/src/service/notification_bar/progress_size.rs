@@ -265,7 +265,7 @@ pub fn progress_size(current: u64) -> String {
 /// # Returns
 /// 
 /// Human-readable string representation of the size with locale-specific formatting
-fn progress_size_with_lang(current: u64, lang: &str) -> String {
+pub(super) fn progress_size_with_lang(current: u64, lang: &str) -> String {
     let (size, unit_str) = calculate_size_and_unit(current);
     let formatted = FormattedSize::format_size_with_unit(size, &unit_str, lang);
 
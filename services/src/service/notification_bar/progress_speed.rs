@@ -0,0 +1,99 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Locale-aware transfer speed and remaining time formatting.
+//!
+//! Formats a speed in bytes per second with the locale rules of
+//! `progress_size`, and a remaining time as a clock duration with the
+//! locale-specific time separator determined by the system language.
+
+use super::ffi::GetSystemLanguage;
+use super::progress_size::progress_size_with_lang;
+
+// Language codes that use dot as time separator
+const DOT_TIME_SEPARATOR_LIST: &[&str] = &["fi", "da"];
+
+/// Formats a speed in bytes per second using the specified language.
+///
+/// # Arguments
+///
+/// * `speed` - Speed in bytes per second
+/// * `lang` - Language code to use for formatting
+///
+/// # Returns
+///
+/// Speed string with the locale-specific size formatting (e.g., "1.50 MB/s")
+fn progress_speed_with_lang(speed: u64, lang: &str) -> String {
+    format!("{}/s", progress_size_with_lang(speed, lang))
+}
+
+/// Formats a remaining time in seconds using the specified language.
+///
+/// # Arguments
+///
+/// * `seconds` - Remaining time in seconds
+/// * `lang` - Language code to use for formatting
+///
+/// # Returns
+///
+/// Clock duration, `M:SS` under an hour and `H:MM:SS` otherwise, with the
+/// locale-specific time separator
+fn progress_eta_with_lang(seconds: u64, lang: &str) -> String {
+    let separator = if DOT_TIME_SEPARATOR_LIST.contains(&lang) {
+        "."
+    } else {
+        ":"
+    };
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours == 0 {
+        format!("{}{}{:02}", minutes, separator, seconds)
+    } else {
+        format!(
+            "{}{}{:02}{}{:02}",
+            hours, separator, minutes, separator, seconds
+        )
+    }
+}
+
+/// Formats a speed in bytes per second using the system language.
+///
+/// # Arguments
+///
+/// * `speed` - Speed in bytes per second
+///
+/// # Returns
+///
+/// Human-readable speed string with locale-specific formatting
+pub fn progress_speed(speed: u64) -> String {
+    let lang = GetSystemLanguage();
+    progress_speed_with_lang(speed, &lang)
+}
+
+/// Formats a remaining time in seconds using the system language.
+///
+/// # Arguments
+///
+/// * `seconds` - Remaining time in seconds
+///
+/// # Returns
+///
+/// Clock duration with locale-specific formatting
+pub fn progress_eta(seconds: u64) -> String {
+    let lang = GetSystemLanguage();
+    progress_eta_with_lang(seconds, &lang)
+}
+
+#[cfg(test)]
+mod ut_progress_speed {
+    include!("../../../tests/ut/service/notification_bar/ut_progress_speed.rs");
+}
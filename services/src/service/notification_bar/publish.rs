@@ -26,10 +26,14 @@ use ylong_runtime::fastrand::fast_random;
 use ylong_runtime::sync::mpsc::{self, unbounded_channel};
 
 use super::database::NotificationDb;
-use super::notify_flow::{EventualNotify, NotifyFlow, NotifyInfo, ProgressNotify};
-use super::task_handle::{cancel_notification, NotificationCheck};
-use crate::info::TaskInfo;
+use super::notify_flow::{
+    EventualNotify, NotifyFlow, NotifyInfo, ProgressNotify, SUMMARY_GROUP_ID_BASE,
+};
+use super::task_handle::NotificationCheck;
+use crate::info::{State, TaskInfo};
+use crate::manage::database::RequestDb;
 use crate::service::notification_bar::NotificationConfig;
+use crate::task::reason::Reason;
 use crate::task::request_task::RequestTask;
 use crate::utils::get_current_duration;
 
@@ -147,6 +151,25 @@ impl NotificationDispatcher {
         self.database.check_task_notification_available(&task_id)
     }
 
+    /// Gets the failed tasks of a notification group.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - ID of the group to look up
+    ///
+    /// # Returns
+    ///
+    /// IDs of the group's tasks that are in the failed state
+    pub(crate) fn group_failed_tasks(&self, group_id: u32) -> Vec<u32> {
+        self.database
+            .query_group_tasks(group_id)
+            .into_iter()
+            .filter(|task_id| {
+                RequestDb::get_instance().query_task_state(*task_id) == Some(State::Failed.repr)
+            })
+            .collect()
+    }
+
     /// Gets the notification visibility flag (gauge) for a specific task.
     ///
     /// # Arguments
@@ -226,7 +249,7 @@ impl NotificationDispatcher {
             }
             (Some(gauge), None) => {
                 gauge.store(false, Ordering::Release);
-                cancel_notification(task_id);
+                let _ = self.flow.send(NotifyInfo::Cancel(uid, task_id));
             }
             (None, None) => {}
         }
@@ -276,6 +299,7 @@ impl NotificationDispatcher {
             total,
             multi_upload,
             version: task.conf.version,
+            speed: None,
         };
 
         // Send notification through the channel
@@ -316,6 +340,7 @@ impl NotificationDispatcher {
                 }
             },
             is_successful: true,
+            reason: Reason::Default,
         };
 
         // Send notification through the channel
@@ -356,6 +381,7 @@ impl NotificationDispatcher {
                 }
             },
             is_successful: false,
+            reason: Reason::from(info.common_data.reason),
         };
 
         // Send notification through the channel
@@ -505,9 +531,9 @@ impl NotificationDispatcher {
         visibility: u32,
         uid: u64,
     ) -> u32 {
-        // Generate a unique group ID using random number generation
+        // Generate a unique group ID below the range reserved for summary groups
         let new_group_id = loop {
-            let candidate = fast_random() as u32;
+            let candidate = fast_random() as u32 % SUMMARY_GROUP_ID_BASE;
             if !self.database.contains_group(candidate) {
                 break candidate;
             }
@@ -20,15 +20,17 @@
 use std::sync::atomic::Ordering;
 
 use super::database::NotificationDb;
-use super::ffi::{self, SubscribeNotification};
+use super::ffi::{self, OpenTarget, SubscribeNotification};
 use super::NotificationDispatcher;
-use crate::config::{Mode, Version};
+use crate::config::{Action, Mode, Version};
+use crate::database::suggested_name;
 use crate::error::ErrorCode;
 use crate::info::{State, TaskInfo};
 use crate::manage::database::RequestDb;
 use crate::manage::events::TaskManagerEvent;
 use crate::manage::task_manager::TaskManagerTx;
 use crate::manage::TaskManager;
+use crate::task::files::BundleCache;
 use crate::task::request_task::RequestTask;
 use crate::utils::Recv;
 
@@ -246,6 +248,90 @@ impl TaskManagerWrapper {
         self.event_inner(task_id, TaskManagerEvent::stop)
     }
 
+    /// Re-queues a failed task.
+    /// 
+    /// Downloads are started again, uploads retry their failed files.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `task_id` - ID of the task to retry
+    /// 
+    /// # Returns
+    /// 
+    /// Boolean indicating whether the operation was successful
+    pub(crate) fn retry_task(&self, task_id: u32) -> bool {
+        match RequestDb::get_instance().query_task_action(task_id) {
+            Some(Action::Upload) => {
+                self.event_inner(task_id, TaskManagerEvent::retry_failed_files)
+            }
+            Some(_) => self.event_inner(task_id, TaskManagerEvent::start),
+            None => false,
+        }
+    }
+
+    /// Re-queues every failed task of a notification group.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `group_id` - ID of the group whose failed tasks are retried
+    /// 
+    /// # Returns
+    /// 
+    /// Boolean indicating whether at least one task was re-queued
+    pub(crate) fn retry_group(&self, group_id: u32) -> bool {
+        let mut retried = false;
+        for task_id in NotificationDispatcher::get_instance().group_failed_tasks(group_id) {
+            retried |= self.retry_task(task_id);
+        }
+        retried
+    }
+
+    /// Gets the file to open for a completed download task.
+    /// 
+    /// The file is the one saved under the server-suggested name if any,
+    /// otherwise the configured one.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `task_id` - ID of the completed download task
+    /// 
+    /// # Returns
+    /// 
+    /// The file URI and MIME type, with an empty URI if there is no file to open
+    pub(crate) fn open_target(&self, task_id: u32) -> OpenTarget {
+        let mut target = OpenTarget {
+            uri: String::new(),
+            mime_type: String::new(),
+            uid: 0,
+        };
+        let database = RequestDb::get_instance();
+        let (Some(info), Some(config)) = (
+            database.get_task_info(task_id),
+            database.get_task_config(task_id),
+        ) else {
+            return target;
+        };
+        if info.action() != Action::Download
+            || info.progress.common_data.state != State::Completed.repr
+        {
+            return target;
+        }
+        let saved_path = suggested_name::suggested_name(task_id).saved_path;
+        let path = match config.file_specs.first() {
+            _ if !saved_path.is_empty() => saved_path,
+            Some(spec) => spec.path.clone(),
+            None => return target,
+        };
+        let Ok(bundle_name) = BundleCache::new(&config).get_value() else {
+            error!("notification_bar open {} failed: bundle name not found", task_id);
+            return target;
+        };
+        target.uri = file_uri(&bundle_name, &path);
+        target.mime_type = info.mime_type();
+        target.uid = info.uid();
+        target
+    }
+
     /// Internal function for sending task events and handling responses.
     /// 
     /// # Type Parameters
@@ -285,6 +371,32 @@ impl TaskManagerWrapper {
     }
 }
 
+/// Builds the URI of a file in an app sandbox, `file://<bundle><path>`.
+/// 
+/// Characters of the path other than unreserved ones and `/` are
+/// percent-encoded.
+/// 
+/// # Arguments
+/// 
+/// * `bundle_name` - Bundle name of the app owning the sandbox
+/// * `path` - Sandbox path of the file
+/// 
+/// # Returns
+/// 
+/// The file URI
+fn file_uri(bundle_name: &str, path: &str) -> String {
+    let mut uri = format!("file://{}", bundle_name);
+    for b in path.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                uri.push(b as char)
+            }
+            b => uri.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    uri
+}
+
 /// Subscribes to notification bar events and connects them to task management.
 /// 
 /// Creates a TaskManagerWrapper and registers it with the notification system
@@ -336,3 +448,8 @@ impl RequestDb {
             .map(|mode: &i32| Mode::from(*mode as u8))
     }
 }
+
+#[cfg(test)]
+mod ut_task_handle {
+    include!("../../../tests/ut/service/notification_bar/ut_task_handle.rs");
+}
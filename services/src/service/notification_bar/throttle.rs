@@ -0,0 +1,270 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Adaptive throttling of progress notifications.
+//!
+//! Limits the progress notification updates of each app to a budget per
+//! second, drops updates that would not change what the user sees, and
+//! tracks the individual task notifications of each app so that tasks beyond
+//! `MAX_TASK_NOTIFICATIONS` are coalesced into a summary group.
+//!
+//! An update refused for lack of budget is not lost: the latest one of each
+//! notification is kept and published by `flush` once the budget refills.
+
+use std::collections::{HashMap, HashSet};
+
+use super::ffi::NotifyContent;
+
+/// Progress notification updates an app may publish per second.
+pub(crate) const APP_NOTIFY_BUDGET: u64 = 4;
+
+/// Individual task progress notifications an app may show at once; further
+/// tasks are coalesced into a summary group.
+pub(crate) const MAX_TASK_NOTIFICATIONS: usize = 3;
+
+/// Interval in milliseconds at which pending updates are retried, the time
+/// one token of the budget takes to refill.
+pub(crate) const FLUSH_INTERVAL: u64 = 1000 / APP_NOTIFY_BUDGET;
+
+/// Token bucket refilled with `APP_NOTIFY_BUDGET` tokens per second.
+///
+/// Tokens are counted in thousandths so that the bucket refills every
+/// millisecond.
+pub(crate) struct NotifyBudget {
+    // Available thousandths of tokens
+    tokens: u64,
+    // Time of the last refill, in milliseconds
+    time: u64,
+}
+
+impl NotifyBudget {
+    /// Creates a full bucket.
+    pub(crate) fn new(time: u64) -> Self {
+        Self {
+            tokens: APP_NOTIFY_BUDGET * 1000,
+            time,
+        }
+    }
+
+    /// Takes one token if available.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - Current time in milliseconds
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If a token was taken
+    /// * `false` - If the budget is exhausted
+    pub(crate) fn try_acquire(&mut self, time: u64) -> bool {
+        let elapsed = time.saturating_sub(self.time);
+        self.tokens = (self.tokens + elapsed * APP_NOTIFY_BUDGET).min(APP_NOTIFY_BUDGET * 1000);
+        self.time = self.time.max(time);
+        if self.tokens < 1000 {
+            return false;
+        }
+        self.tokens -= 1000;
+        true
+    }
+}
+
+/// What the user sees of a progress notification.
+#[derive(PartialEq, Eq)]
+struct VisibleProgress {
+    title: String,
+    // Text, if a change of it alone is worth an update
+    text: Option<String>,
+    // Progress circle value in whole percents, if shown
+    percent: Option<u64>,
+}
+
+impl VisibleProgress {
+    /// Gets the visible part of a notification.
+    fn new(content: &NotifyContent, text_visible: bool) -> Self {
+        let circle = &content.progress_circle;
+        Self {
+            title: content.title.clone(),
+            text: text_visible.then(|| content.text.clone()),
+            percent: circle
+                .open
+                .then(|| circle.current * 100 / circle.total.max(1)),
+        }
+    }
+}
+
+/// Throttling state of the progress notifications of all apps.
+pub(crate) struct NotifyThrottle {
+    // Update budget of each app
+    budgets: HashMap<u64, NotifyBudget>,
+    // Last published visible progress of each notification
+    published: HashMap<u32, VisibleProgress>,
+    // Latest update of each notification refused for lack of budget
+    pending: HashMap<u32, (u64, NotifyContent, VisibleProgress)>,
+    // Tasks of each app shown in an individual progress notification
+    active_tasks: HashMap<u64, HashSet<u32>>,
+}
+
+impl NotifyThrottle {
+    /// Creates an empty throttling state.
+    pub(crate) fn new() -> Self {
+        Self {
+            budgets: HashMap::new(),
+            published: HashMap::new(),
+            pending: HashMap::new(),
+            active_tasks: HashMap::new(),
+        }
+    }
+
+    /// Checks whether a progress notification update should be published.
+    ///
+    /// The update is dropped if it would not change what the user sees. If
+    /// the app has used up its budget, the update replaces the pending one of
+    /// the same notification and is published by a later `flush`.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - User identifier of the app
+    /// * `content` - The notification update
+    /// * `text_visible` - Whether a change of the text alone is worth an
+    ///   update; the text of a task carries its transfer speed and remaining
+    ///   time, which change with nearly every update
+    /// * `time` - Current time in milliseconds
+    ///
+    /// # Returns
+    ///
+    /// * `Some(NotifyContent)` - If the update should be published now
+    /// * `None` - If the update is dropped or pending
+    pub(crate) fn throttle_progress(
+        &mut self,
+        uid: u64,
+        content: NotifyContent,
+        text_visible: bool,
+        time: u64,
+    ) -> Option<NotifyContent> {
+        let visible = VisibleProgress::new(&content, text_visible);
+        if self.published.get(&content.request_id) == Some(&visible) {
+            self.pending.remove(&content.request_id);
+            return None;
+        }
+        if !self.budget(uid, time).try_acquire(time) {
+            self.pending
+                .insert(content.request_id, (uid, content, visible));
+            return None;
+        }
+        self.pending.remove(&content.request_id);
+        self.published.insert(content.request_id, visible);
+        Some(content)
+    }
+
+    /// Records a progress notification published without throttling, so
+    /// that it supersedes the pending update of the same notification.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The published notification
+    /// * `text_visible` - Whether a change of the text alone is worth an
+    ///   update
+    pub(crate) fn record_progress(&mut self, content: &NotifyContent, text_visible: bool) {
+        self.pending.remove(&content.request_id);
+        self.published.insert(
+            content.request_id,
+            VisibleProgress::new(content, text_visible),
+        );
+    }
+
+    /// Whether some updates wait for the budget of their app to refill.
+    pub(crate) fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Takes the pending updates whose app has budget again.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - Current time in milliseconds
+    ///
+    /// # Returns
+    ///
+    /// The updates to publish now
+    pub(crate) fn flush(&mut self, time: u64) -> Vec<NotifyContent> {
+        let mut ready = Vec::new();
+        let request_ids: Vec<u32> = self.pending.keys().copied().collect();
+        for request_id in request_ids {
+            let uid = self.pending[&request_id].0;
+            if !self.budget(uid, time).try_acquire(time) {
+                continue;
+            }
+            if let Some((_, content, visible)) = self.pending.remove(&request_id) {
+                self.published.insert(request_id, visible);
+                ready.push(content);
+            }
+        }
+        ready
+    }
+
+    /// Gets the update budget of an app, created full.
+    fn budget(&mut self, uid: u64, time: u64) -> &mut NotifyBudget {
+        self.budgets
+            .entry(uid)
+            .or_insert_with(|| NotifyBudget::new(time))
+    }
+
+    /// Admits a task to an individual progress notification.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - User identifier of the app
+    /// * `task_id` - Task to admit
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the task has or may get an individual notification
+    /// * `false` - If the app already shows `MAX_TASK_NOTIFICATIONS` and the
+    ///   task should be coalesced
+    pub(crate) fn admit_task(&mut self, uid: u64, task_id: u32) -> bool {
+        let active = self.active_tasks.entry(uid).or_default();
+        if active.contains(&task_id) {
+            return true;
+        }
+        if active.len() >= MAX_TASK_NOTIFICATIONS {
+            return false;
+        }
+        active.insert(task_id);
+        true
+    }
+
+    /// Forgets a notification that is no longer showing progress.
+    ///
+    /// Its pending update is dropped, the notification published at the end
+    /// of the task or group supersedes it.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - User identifier of the app
+    /// * `request_id` - Task or group ID of the notification
+    pub(crate) fn finish(&mut self, uid: u64, request_id: u32) {
+        self.published.remove(&request_id);
+        self.pending.remove(&request_id);
+        if let Some(active) = self.active_tasks.get_mut(&uid) {
+            active.remove(&request_id);
+            if active.is_empty() {
+                self.active_tasks.remove(&uid);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod ut_throttle {
+    include!("../../../tests/ut/service/notification_bar/ut_throttle.rs");
+}
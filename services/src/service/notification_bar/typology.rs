@@ -28,13 +28,41 @@ const UPLOAD_SUCCESS: &str = "request_agent_upload_success\0";        // Templat
 const UPLOAD_FAIL: &str = "request_agent_upload_fail\0";            // Template for upload failure notification title
 const TASK_COUNT: &str = "request_agent_task_count\0";            // Template for task count text (successful/failed)
 const DOWNLOAD_COMPLETE: &str = "request_agent_download_complete\0"; // Template for download complete notification title
+const UPLOAD_COMPLETE: &str = "request_agent_upload_complete\0";     // Template for upload complete notification title
+const TASK_SUMMARY: &str = "request_agent_task_summary\0";        // Template joining the successful and failed counts
+const FAIL_TEXT: &str = "request_agent_fail_text\0";              // Template for failure text (file name, reason)
+const PROGRESS_DETAIL: &str = "request_agent_progress_detail\0";  // Template for progress text (file name, speed, remaining time)
+
+// Plural resource keys for notification strings
+const TASK_SUCCESSFUL: &str = "request_agent_task_successful\0";  // Plural template for the successful task count
+const TASK_FAILED: &str = "request_agent_task_failed\0";          // Plural template for the failed task count
+
+// Resource keys for failure reasons
+const REASON_NETWORK: &str = "request_agent_reason_network\0";        // Network offline or not allowed
+const REASON_CONNECTION: &str = "request_agent_reason_connection\0";  // Server could not be reached
+const REASON_TIMEOUT: &str = "request_agent_reason_timeout\0";        // Transfer too slow or too long
+const REASON_SERVER: &str = "request_agent_reason_server\0";          // Server response not usable
+const REASON_STORAGE: &str = "request_agent_reason_storage\0";        // Not enough storage space
+const REASON_FILE: &str = "request_agent_reason_file\0";              // File could not be read or written
+const REASON_INTERRUPTED: &str = "request_agent_reason_interrupted\0"; // App or account stopped
+const REASON_UNKNOWN: &str = "request_agent_reason_unknown\0";        // Any other failure
+
+// Names of the action buttons, matched by the notification subscriber
+const BUTTON_RETRY: &str = "retry";            // Re-queues a failed task
+const BUTTON_RETRY_ALL: &str = "retry_all";    // Re-queues every failed task of a group
+const BUTTON_OPEN: &str = "open";              // Opens the file of a completed download
+const BUTTON_DISMISS: &str = "dismiss";        // Removes the notification
 
 use super::database::CustomizedNotification;
-use super::ffi::{GetSystemResourceString, NotifyContent, ProgressCircle};
+use super::ffi::{
+    GetSystemResourcePluralString, GetSystemResourceString, NotifyContent, ProgressCircle,
+};
 use super::notify_flow::{GroupProgress, ProgressNotify};
 use super::progress_percentage;
 use super::progress_size;
+use super::progress_speed;
 use crate::config::Action;
+use crate::task::reason::Reason;
 
 /// Formats progress size as a human-readable string.
 /// 
@@ -67,6 +95,155 @@ fn progress_percentage(current: u64, total: u64) -> String {
     progress_percentage::progress_percentage(current, total)
 }
 
+/// Fills the placeholders of a resource template with arguments.
+///
+/// Positional placeholders (`%1$s`, `%2$d`, ...) take the argument at their
+/// position, sequential ones (`%s`, `%d`) the next argument.
+///
+/// # Arguments
+///
+/// * `template` - Resource template
+/// * `args` - Arguments in placeholder order
+///
+/// # Returns
+///
+/// The filled template
+fn fill_template(template: &str, args: &[&str]) -> String {
+    let mut out = template.to_string();
+    for (i, arg) in args.iter().enumerate() {
+        out = out
+            .replace(&format!("%{}$s", i + 1), arg)
+            .replace(&format!("%{}$d", i + 1), arg);
+    }
+    for arg in args {
+        match (out.find("%s"), out.find("%d")) {
+            (Some(s), Some(d)) => out.replace_range(s.min(d)..s.min(d) + 2, arg),
+            (Some(pos), None) | (None, Some(pos)) => out.replace_range(pos..pos + 2, arg),
+            (None, None) => break,
+        }
+    }
+    out
+}
+
+/// Gets the resource key of the human-readable text of a failure reason.
+///
+/// # Arguments
+///
+/// * `reason` - Reason the task failed
+///
+/// # Returns
+///
+/// The resource key, or `None` if the reason says nothing to the user
+fn reason_resource(reason: Reason) -> Option<&'static str> {
+    let key = match reason {
+        Reason::Default | Reason::UserOperation => return None,
+        Reason::NetworkOffline
+        | Reason::UnsupportedNetworkType
+        | Reason::NetworkApp
+        | Reason::NetworkAccount
+        | Reason::NetworkAppAccount => REASON_NETWORK,
+        Reason::BuildRequestFailed
+        | Reason::RequestError
+        | Reason::Dns
+        | Reason::Tcp
+        | Reason::Ssl => REASON_CONNECTION,
+        Reason::ContinuousTaskTimeout | Reason::LowSpeed => REASON_TIMEOUT,
+        Reason::GetFileSizeFailed
+        | Reason::RedirectError
+        | Reason::ProtocolError
+        | Reason::UnsupportedRangeRequest => REASON_SERVER,
+        Reason::InsufficientSpace => REASON_STORAGE,
+        Reason::IoError | Reason::UploadFileError => REASON_FILE,
+        Reason::AppBackgroundOrTerminate | Reason::AccountStopped | Reason::AppAccount => {
+            REASON_INTERRUPTED
+        }
+        _ => REASON_UNKNOWN,
+    };
+    Some(key)
+}
+
+/// Formats the text of a failed task, its file name and the failure reason.
+///
+/// Falls back to the file name alone if the reason or the template is not
+/// available.
+///
+/// # Arguments
+///
+/// * `file_name` - Name of the file
+/// * `reason` - Reason the task failed
+///
+/// # Returns
+///
+/// The failure text
+fn failure_text(file_name: String, reason: Reason) -> String {
+    let Some(reason) = reason_resource(reason).map(GetSystemResourceString) else {
+        return file_name;
+    };
+    let template = GetSystemResourceString(FAIL_TEXT);
+    if reason.is_empty() || template.is_empty() {
+        return file_name;
+    }
+    fill_template(&template, &[&file_name, &reason])
+}
+
+/// Formats the successful and failed task counts of a group.
+///
+/// Uses the plural-aware count templates, and falls back to the combined
+/// count template if they are not available.
+///
+/// # Arguments
+///
+/// * `successful` - Number of successfully completed tasks
+/// * `failed` - Number of failed tasks
+///
+/// # Returns
+///
+/// The task count text
+fn task_count_text(successful: usize, failed: usize) -> String {
+    let (successful, failed) = (successful as i32, failed as i32);
+    let summary = GetSystemResourceString(TASK_SUMMARY);
+    let successful_text = GetSystemResourcePluralString(TASK_SUCCESSFUL, successful);
+    let failed_text = GetSystemResourcePluralString(TASK_FAILED, failed);
+    if !summary.is_empty() && !successful_text.is_empty() && !failed_text.is_empty() {
+        let successful_text = fill_template(&successful_text, &[&successful.to_string()]);
+        let failed_text = fill_template(&failed_text, &[&failed.to_string()]);
+        return fill_template(&summary, &[&successful_text, &failed_text]);
+    }
+    fill_template(
+        &GetSystemResourceString(TASK_COUNT),
+        &[&successful.to_string(), &failed.to_string()],
+    )
+}
+
+/// Formats the text of a task in progress, its file name followed by the
+/// transfer speed and the remaining time when they are known.
+///
+/// # Arguments
+///
+/// * `info` - Progress information for the task
+///
+/// # Returns
+///
+/// The progress text
+fn progress_text(info: &ProgressNotify) -> String {
+    let (Some(speed), Some(total)) = (info.speed.filter(|speed| *speed > 0), info.total) else {
+        return info.file_name.clone();
+    };
+    let template = GetSystemResourceString(PROGRESS_DETAIL);
+    if template.is_empty() {
+        return info.file_name.clone();
+    }
+    let remaining = total.saturating_sub(info.processed).div_ceil(speed);
+    fill_template(
+        &template,
+        &[
+            &info.file_name,
+            &progress_speed::progress_speed(speed),
+            &progress_speed::progress_eta(remaining),
+        ],
+    )
+}
+
 impl NotifyContent {
     /// Creates a notification for a task completion event.
     /// 
@@ -81,6 +258,7 @@ impl NotifyContent {
     /// * `uid` - User ID associated with the task
     /// * `file_name` - Name of the file
     /// * `is_successful` - Whether the task completed successfully
+    /// * `reason` - Reason the task failed, shown along the file name
     /// 
     /// # Returns
     /// 
@@ -92,6 +270,7 @@ impl NotifyContent {
         uid: u32,
         file_name: String,
         is_successful: bool,
+        reason: Reason,
     ) -> Self {
         // Use custom title if provided, otherwise get system resource based on action and status
         let title = customized
@@ -115,10 +294,26 @@ impl NotifyContent {
                 _ => unreachable!(),
             });
         
-        // Use custom text if provided, otherwise use file name
-        let text = customized.as_mut().and_then(|c| c.text.take()).unwrap_or(file_name);
+        // Use custom text if provided, otherwise use file name and failure reason
+        let text = customized
+            .as_mut()
+            .and_then(|c| c.text.take())
+            .unwrap_or_else(|| {
+                if is_successful {
+                    file_name
+                } else {
+                    failure_text(file_name, reason)
+                }
+            });
         let want_agent = customized.and_then(|c| c.want_agent).unwrap_or_default();
 
+        // Failed tasks can be retried, completed downloads opened
+        let buttons = match (is_successful, action) {
+            (false, _) => vec![BUTTON_RETRY, BUTTON_DISMISS],
+            (true, Action::Download) => vec![BUTTON_OPEN, BUTTON_DISMISS],
+            (true, _) => vec![],
+        };
+
         Self {
             title,
             text,
@@ -128,6 +323,7 @@ impl NotifyContent {
             live_view: false,       // Not a live updating notification
             progress_circle: ProgressCircle::close(),
             x_mark: false,          // No close button needed for completed task
+            buttons: buttons.into_iter().map(String::from).collect(),
         }
     }
 
@@ -178,10 +374,10 @@ impl NotifyContent {
                 _ => unreachable!(),
             });
 
-        // Use custom text if provided, otherwise use file name
+        // Use custom text if provided, otherwise use file name, speed and remaining time
         let text = customized.as_mut()
             .and_then(|c| c.text.clone())
-            .unwrap_or_else(|| info.file_name.clone());
+            .unwrap_or_else(|| progress_text(info));
         
         let want_agent = customized.and_then(|c| c.want_agent).unwrap_or_default();
         
@@ -200,6 +396,7 @@ impl NotifyContent {
             live_view: true,
             progress_circle,
             x_mark: true,
+            buttons: Vec::new(),
         }
     }
 
@@ -230,36 +427,35 @@ impl NotifyContent {
         successful_count: i32,
         failed_count: i32,
     ) -> Self {
-        // Generate download completion message with formatted size
-        let text_download_complete = GetSystemResourceString(DOWNLOAD_COMPLETE);
-        let text_download = text_download_complete.replace("%s", &progress_size(current_size).to_string());
-        
         // Use custom title if provided, otherwise generate based on action
         let title = customized
             .as_mut()
             .and_then(|c| c.title.take())
-            .unwrap_or_else(|| match action {
-                Action::Download => text_download,
-                Action::Upload => format!("上传完成 {}", progress_size(current_size)),
-                _ => unreachable!(),
+            .unwrap_or_else(|| {
+                let template = match action {
+                    Action::Download => GetSystemResourceString(DOWNLOAD_COMPLETE),
+                    Action::Upload => Some(GetSystemResourceString(UPLOAD_COMPLETE))
+                        .filter(|template| !template.is_empty())
+                        .unwrap_or_else(|| {
+                            format!("{} %s", GetSystemResourceString(UPLOAD_SUCCESS))
+                        }),
+                    _ => unreachable!(),
+                };
+                template.replace("%s", &progress_size(current_size))
             });
 
         // Format task count text with successful and failed task numbers
-        // Handle different format patterns (%d or %1$d/%2$d)
-        let text_task_count = GetSystemResourceString(TASK_COUNT);
-        let text_count = if text_task_count.contains("%d") {
-            text_task_count
-                .replacen("%d", &successful_count.to_string(), 1)
-                .replacen("%d", &failed_count.to_string(), 1)
-        } else {
-            text_task_count
-                .replace("%1$d", &successful_count.to_string())
-                .replace("%2$d", &failed_count.to_string())
-        };
+        let text_count = task_count_text(successful_count as usize, failed_count as usize);
 
         let text = customized.as_mut().and_then(|c| c.text.take()).unwrap_or(text_count);
         let want_agent = customized.and_then(|c| c.want_agent).unwrap_or_default();
 
+        let buttons = if failed_count > 0 {
+            vec![BUTTON_RETRY_ALL, BUTTON_DISMISS]
+        } else {
+            vec![]
+        };
+
         Self {
             title,
             text,
@@ -269,6 +465,7 @@ impl NotifyContent {
             live_view: false,
             progress_circle: ProgressCircle::close(),
             x_mark: false,
+            buttons: buttons.into_iter().map(String::from).collect(),
         }
     }
 
@@ -314,17 +511,7 @@ impl NotifyContent {
         let (successful, failed) = (group_progress.successful(), group_progress.failed());
         
         // Format task count text with successful and failed task numbers
-        // Handle different format patterns (%d or %1$d/%2$d)
-        let text_task_count = GetSystemResourceString(TASK_COUNT);
-        let text_count = if text_task_count.contains("%d") {
-            text_task_count
-                .replacen("%d", &successful.to_string(), 1)
-                .replacen("%d", &failed.to_string(), 1)
-        } else {
-            text_task_count
-                .replace("%1$d", &successful.to_string())
-                .replace("%2$d", &failed.to_string())
-        };
+        let text_count = task_count_text(successful, failed);
 
         let text = customized.as_mut().and_then(|c| c.text.take()).unwrap_or(text_count);
         let want_agent = customized.and_then(|c| c.want_agent).unwrap_or_default();
@@ -340,6 +527,7 @@ impl NotifyContent {
             live_view: true,
             progress_circle,
             x_mark: false,
+            buttons: Vec::new(),
         }
     }
 }
@@ -28,6 +28,7 @@ use super::client::ClientManagerEntry;
 use super::interface;
 use super::permission::PermissionChecker;
 use super::run_count::RunCountManagerEntry;
+use crate::database::suggested_name;
 use crate::manage::database::RequestDb;
 use crate::manage::task_manager::TaskManagerTx;
 use crate::service::active_counter::ActiveCounter;
@@ -185,6 +186,15 @@ impl RemoteStub for RequestServiceStub {
             interface::SET_MAX_SPEED => self.set_max_speed(data, reply),
             interface::SET_MODE => self.set_mode(data, reply),
             interface::DISABLE_TASK_NOTIFICATION => self.disable_task_notifications(data, reply),
+            interface::QUERY_HISTORY => self.query_history(data, reply),
+            interface::SET_HISTORY_RETENTION => self.set_history_retention(data, reply),
+            interface::RETRY_FAILED_FILES => self.retry_failed_files(data, reply),
+            interface::SET_FILE_ERROR_POLICY => self.set_file_error_policy(data, reply),
+            interface::SET_CHUNKED_UPLOAD => self.set_chunked_upload(data, reply),
+            interface::SET_SAVE_AS_SUGGESTED_NAME => self.set_save_as_suggested_name(data, reply),
+            interface::SET_PRIORITY => self.set_priority(data, reply),
+            interface::MOVE_TO_FRONT => self.move_to_front(data, reply),
+            interface::SET_TASK_DNS => self.set_task_dns(data, reply),
             _ => Err(IpcStatusCode::Failed),
         };
 
@@ -224,12 +234,18 @@ impl RemoteStub for RequestServiceStub {
 /// # Arguments
 ///
 /// * `tf` - The task information to serialize
+/// * `version` - Interface version of the client, which gets the suggested
+///   file name and saved path from `SUGGESTED_NAME_VERSION` on
 /// * `reply` - The message parcel to write the serialized data to
 ///
 /// # Returns
 ///
 /// `Ok(())` on successful serialization, or an `IpcResult` error if any field fails to write.
-pub(crate) fn serialize_task_info(tf: TaskInfo, reply: &mut MsgParcel) -> IpcResult<()> {
+pub(crate) fn serialize_task_info(
+    tf: TaskInfo,
+    version: u32,
+    reply: &mut MsgParcel,
+) -> IpcResult<()> {
     // Serialize common data fields
     reply.write(&(tf.common_data.gauge))?;
     reply.write(&(tf.common_data.retry))?;
@@ -297,6 +313,14 @@ pub(crate) fn serialize_task_info(tf: TaskInfo, reply: &mut MsgParcel) -> IpcRes
         reply.write(&(item.reason.repr as u32))?;
         reply.write(&(item.message))?;
     }
+
+    // Serialize the server-suggested file name and the path it was saved at
+    if version < interface::SUGGESTED_NAME_VERSION {
+        return Ok(());
+    }
+    let suggested = suggested_name::suggested_name(tf.common_data.task_id);
+    reply.write(&suggested.name)?;
+    reply.write(&suggested.saved_path)?;
     Ok(())
 }
 
@@ -148,6 +148,8 @@ pub(crate) enum DfxCode {
     INVALID_IPC_MESSAGE_A44 = 0x001F1501,
     INVALID_IPC_MESSAGE_A45 = 0x001F1600,
     INVALID_IPC_MESSAGE_A46 = 0x001F1601,
+    INVALID_IPC_MESSAGE_A47 = 0x001F1700,
+    INVALID_IPC_MESSAGE_A48 = 0x001F1701,
     TASK_STATISTICS = 0x002F0000,
     TASK_FAULT_00 = 0x002F00FF,
     TASK_FAULT_01 = 0x002F01FF,
@@ -0,0 +1,703 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Chunked upload of a single large file.
+//!
+//! The file of a chunked upload task is split into ranges of
+//! `ChunkConfig::chunk_size` bytes, each sent in its own request, up to
+//! `ChunkConfig::parallelism` at the same time. A failed chunk is retried on
+//! its own; completed chunks are persisted so an interrupted task only sends
+//! the missing ones. Once all chunks are uploaded an optional finalize request
+//! is sent, which is how an S3 multipart upload is completed.
+//!
+//! Chunk URLs, chunk header values and the finalize request are templates in
+//! which the following placeholders are replaced:
+//!
+//! * `{index}` - Zero-based chunk index.
+//! * `{number}` - One-based chunk number, the S3 `partNumber`.
+//! * `{offset}` - Offset of the first byte of the chunk.
+//! * `{end}` - Offset of the last byte of the chunk.
+//! * `{size}` - Size of the chunk in bytes.
+//! * `{total}` - Size of the file in bytes.
+//! * `{count}` - Number of chunks.
+//! * `{etag}` - `ETag` returned for the chunk, finalize part only.
+//!
+//! In the finalize body, `{parts}` is replaced by `ChunkConfig::finalize_part`
+//! rendered once per chunk, in chunk order and without separator.
+//!
+//! Chunk and finalize URLs must have the same scheme and host as the task URL.
+//! Chunks are streamed from the file rather than held in memory, and a failed
+//! chunk is sent again after an exponential backoff, or after the delay of
+//! the `Retry-After` header of the response.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::future::Future;
+use std::os::unix::fs::FileExt;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use ylong_http_client::async_impl::{
+    Body, Client, PercentEncoder, Request, RequestBuilder, UploadOperator, Uploader,
+};
+use ylong_http_client::{ErrorKind, HttpClientError, ReusableReader};
+use ylong_runtime::io::{AsyncRead, ReadBuf};
+
+use super::reason::Reason;
+use super::request_task::{RequestTask, TaskError, TaskPhase};
+use crate::database::chunk::{self, ChunkConfig};
+use crate::manage::notifier::Notifier;
+use crate::utils::get_current_timestamp;
+
+/// Delay before the first retry of a chunk, doubled on every retry.
+const RETRY_BASE_DELAY: Duration = Duration::from_secs(1);
+
+/// Longest delay before retrying a chunk, including `Retry-After` delays.
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(120);
+
+/// Interval at which the abort flag is checked while waiting to retry.
+const RETRY_ABORT_CHECK: Duration = Duration::from_millis(500);
+
+/// Byte range of the file sent in one chunk request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ChunkRange {
+    /// Zero-based index of the chunk.
+    pub(crate) index: u32,
+    /// Offset of the first byte of the chunk.
+    pub(crate) offset: u64,
+    /// Size of the chunk in bytes.
+    pub(crate) len: u64,
+}
+
+/// Splits a file of `total` bytes into chunks of `chunk_size` bytes, the last
+/// one holding the remainder. An empty file is sent as one empty chunk.
+pub(crate) fn plan_chunks(total: u64, chunk_size: u64) -> Vec<ChunkRange> {
+    if total == 0 || chunk_size == 0 {
+        return vec![ChunkRange {
+            index: 0,
+            offset: 0,
+            len: total,
+        }];
+    }
+    (0..total.div_ceil(chunk_size))
+        .map(|i| {
+            let offset = i * chunk_size;
+            ChunkRange {
+                index: i as u32,
+                offset,
+                len: chunk_size.min(total - offset),
+            }
+        })
+        .collect()
+}
+
+/// Replaces the chunk placeholders of `template`.
+pub(crate) fn render(
+    template: &str,
+    chunk: &ChunkRange,
+    total: u64,
+    count: usize,
+    etag: &str,
+) -> String {
+    template
+        .replace("{index}", &chunk.index.to_string())
+        .replace("{number}", &(chunk.index + 1).to_string())
+        .replace("{offset}", &chunk.offset.to_string())
+        .replace(
+            "{end}",
+            &(chunk.offset + chunk.len).saturating_sub(1).to_string(),
+        )
+        .replace("{size}", &chunk.len.to_string())
+        .replace("{total}", &total.to_string())
+        .replace("{count}", &count.to_string())
+        .replace("{etag}", etag)
+}
+
+/// Builds the body of the finalize request from the `ETag` of every chunk.
+pub(crate) fn render_finalize_body(
+    config: &ChunkConfig,
+    chunks: &[ChunkRange],
+    etags: &HashMap<u32, String>,
+    total: u64,
+) -> String {
+    let parts: String = chunks
+        .iter()
+        .map(|c| {
+            let etag = etags.get(&c.index).map(String::as_str).unwrap_or("");
+            render(&config.finalize_part, c, total, chunks.len(), etag)
+        })
+        .collect();
+    config.finalize_body.replace("{parts}", &parts)
+}
+
+/// Gets the scheme and host of `url` in lowercase, without credentials or
+/// port, `None` if `url` has no scheme or no host.
+pub(crate) fn url_origin(url: &str) -> Option<(String, String)> {
+    let (scheme, rest) = url.split_once("://")?;
+    let authority = rest
+        .split(|c| c == '/' || c == '?' || c == '#')
+        .next()
+        .unwrap_or_default();
+    let host_port = match authority.rfind('@') {
+        Some(idx) => &authority[idx + 1..],
+        None => authority,
+    };
+    let host = match host_port.strip_prefix('[') {
+        Some(ipv6) => &ipv6[..ipv6.find(']')?],
+        None => host_port.split(':').next().unwrap_or_default(),
+    };
+    if scheme.is_empty() || host.is_empty() {
+        return None;
+    }
+    Some((scheme.to_ascii_lowercase(), host.to_ascii_lowercase()))
+}
+
+/// Whether `url` has the same scheme and host as the task URL `task_url`.
+pub(crate) fn is_same_origin(url: &str, task_url: &str) -> bool {
+    match (url_origin(url), url_origin(task_url)) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Parses a `Retry-After` header value, either a number of seconds or an
+/// HTTP date, into the delay from `now`, in seconds since the epoch.
+pub(crate) fn parse_retry_after(value: &str, now: u64) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    // IMF-fixdate, e.g. "Sun, 06 Nov 1994 08:49:37 GMT"
+    let fields: Vec<&str> = value.split_whitespace().collect();
+    let [_, day, month, year, time, "GMT"] = fields.as_slice() else {
+        return None;
+    };
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let month = MONTHS.iter().position(|m| m == month)? as i64 + 1;
+    let day = i64::from(day.parse::<u8>().ok()?);
+    let year = year.parse::<i64>().ok()?;
+    let mut hms = time.split(':').map(|f| f.parse::<u8>().ok().map(i64::from));
+    let (hour, minute, second) = (hms.next()??, hms.next()??, hms.next()??);
+
+    // Days since the epoch of a proleptic Gregorian date
+    let y = if month <= 2 {
+        year.checked_sub(1)?
+    } else {
+        year
+    };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era.checked_mul(146097)?.checked_add(doe - 719468)?;
+
+    let date = days
+        .checked_mul(86400)?
+        .checked_add(hour * 3600 + minute * 60 + second)?;
+    let delay = date.checked_sub(i64::try_from(now).ok()?)?;
+    Some(Duration::from_secs(delay.max(0) as u64))
+}
+
+/// Gets the delay before sending a chunk again for the `tries`-th time,
+/// preferring the delay asked for by the server.
+pub(crate) fn retry_delay(tries: u32, retry_after: Option<Duration>) -> Duration {
+    let backoff = RETRY_BASE_DELAY.saturating_mul(1 << tries.saturating_sub(1).min(16));
+    retry_after.unwrap_or(backoff).min(RETRY_MAX_DELAY)
+}
+
+/// Waits `delay` before retrying a chunk, returning early if the task is
+/// aborted.
+async fn wait_retry(delay: Duration, abort_flag: &AtomicBool) -> Result<(), TaskError> {
+    let mut remaining = delay;
+    while !remaining.is_zero() {
+        if abort_flag.load(Ordering::Acquire) {
+            return Err(TaskError::Waiting(TaskPhase::UserAbort));
+        }
+        let step = remaining.min(RETRY_ABORT_CHECK);
+        ylong_runtime::time::sleep(step).await;
+        remaining -= step;
+    }
+    Ok(())
+}
+
+/// Reader streaming the bytes of one chunk from the file.
+///
+/// Reads at absolute offsets, so the chunks uploaded at the same time do not
+/// move each other's file position.
+struct ChunkReader {
+    /// The file being uploaded.
+    file: Arc<Mutex<File>>,
+    /// The range of the file sent.
+    chunk: ChunkRange,
+    /// Number of bytes of the chunk already read.
+    read: u64,
+}
+
+impl AsyncRead for ChunkReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let remaining = self.chunk.len - self.read;
+        if remaining == 0 {
+            return Poll::Ready(Ok(()));
+        }
+        let offset = self.chunk.offset + self.read;
+        let unfilled = buf.initialize_unfilled();
+        let len = unfilled.len().min(remaining as usize);
+        let size = match self
+            .file
+            .lock()
+            .unwrap()
+            .read_at(&mut unfilled[..len], offset)
+        {
+            Ok(0) => {
+                return Poll::Ready(Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof)))
+            }
+            Ok(size) => size,
+            Err(e) => return Poll::Ready(Err(e)),
+        };
+        buf.advance(size);
+        self.read += size as u64;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl ReusableReader for ChunkReader {
+    fn reuse<'a>(
+        &'a mut self,
+    ) -> Pin<Box<dyn Future<Output = std::io::Result<()>> + Send + Sync + 'a>>
+    where
+        Self: 'a,
+    {
+        self.read = 0;
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Operator of a chunk request, aborting it when the task is aborted.
+///
+/// Progress is counted once a chunk is accepted by the server.
+struct ChunkOperator {
+    /// Flag set when the task is aborted.
+    abort_flag: Arc<AtomicBool>,
+}
+
+impl UploadOperator for ChunkOperator {
+    fn poll_progress(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        _uploaded: u64,
+        _total: Option<u64>,
+    ) -> Poll<Result<(), HttpClientError>> {
+        if self.abort_flag.load(Ordering::Acquire) {
+            return Poll::Ready(Err(HttpClientError::user_aborted()));
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// A future polling several futures together, completing with their outputs
+/// in order once all of them are done.
+struct JoinAll<'a, T> {
+    futures: Vec<Pin<Box<dyn Future<Output = T> + Send + 'a>>>,
+    outputs: Vec<Option<T>>,
+}
+
+impl<'a, T> JoinAll<'a, T> {
+    fn new(futures: Vec<Pin<Box<dyn Future<Output = T> + Send + 'a>>>) -> Self {
+        let outputs = futures.iter().map(|_| None).collect();
+        Self { futures, outputs }
+    }
+}
+
+impl<T: Unpin> Future for JoinAll<'_, T> {
+    type Output = Vec<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let mut done = true;
+        for (future, output) in this.futures.iter_mut().zip(this.outputs.iter_mut()) {
+            if output.is_some() {
+                continue;
+            }
+            match future.as_mut().poll(cx) {
+                Poll::Ready(value) => *output = Some(value),
+                Poll::Pending => done = false,
+            }
+        }
+        if !done {
+            return Poll::Pending;
+        }
+        Poll::Ready(this.outputs.iter_mut().filter_map(Option::take).collect())
+    }
+}
+
+/// Maps a request error to the reason a task fails with.
+fn client_error_reason(e: &HttpClientError) -> Reason {
+    match e.error_kind() {
+        ErrorKind::Timeout => Reason::ContinuousTaskTimeout,
+        ErrorKind::Request => Reason::RequestError,
+        ErrorKind::Redirect => Reason::RedirectError,
+        ErrorKind::Connect | ErrorKind::ConnectionUpgrade => {
+            if e.is_dns_error() {
+                Reason::Dns
+            } else if e.is_tls_error() {
+                Reason::Ssl
+            } else {
+                Reason::Tcp
+            }
+        }
+        ErrorKind::BodyTransfer if format!("{}", e).contains("Below low speed limit") => {
+            Reason::LowSpeed
+        }
+        _ => Reason::OthersError,
+    }
+}
+
+/// Whether a failed request is worth sending again.
+fn is_transient(e: &HttpClientError) -> bool {
+    matches!(
+        e.error_kind(),
+        ErrorKind::Timeout
+            | ErrorKind::Connect
+            | ErrorKind::ConnectionUpgrade
+            | ErrorKind::BodyTransfer
+    )
+}
+
+/// Creates a request builder for `url` with the task headers and `headers`.
+fn request_builder(
+    task: &RequestTask,
+    method: &str,
+    url: &str,
+    headers: &HashMap<String, String>,
+) -> Result<RequestBuilder, TaskError> {
+    let url = PercentEncoder::encode(url).map_err(|e| {
+        error!(
+            "task {} chunk url percent encoding error {:?}",
+            task.task_id(),
+            e
+        );
+        TaskError::Failed(Reason::BuildRequestFailed)
+    })?;
+    let mut builder = RequestBuilder::new()
+        .method(method.to_uppercase().as_str())
+        .url(url.as_str());
+    for (key, value) in task.conf.headers.iter() {
+        if !headers.contains_key(key) {
+            builder = builder.header(key.as_str(), value.as_str());
+        }
+    }
+    for (key, value) in headers.iter() {
+        builder = builder.header(key.as_str(), value.as_str());
+    }
+    Ok(builder)
+}
+
+/// Builds the request sending one chunk, streaming it from `file`.
+fn build_chunk_request(
+    task: &RequestTask,
+    config: &ChunkConfig,
+    chunk: &ChunkRange,
+    total: u64,
+    count: usize,
+    file: Arc<Mutex<File>>,
+    abort_flag: Arc<AtomicBool>,
+) -> Result<Request, TaskError> {
+    let url = match config.url.is_empty() {
+        true => task.conf.url.as_str(),
+        false => config.url.as_str(),
+    };
+    let url = render(url, chunk, total, count, "");
+    if !is_same_origin(&url, &task.conf.url) {
+        error!(
+            "task {} chunk url not on the task url origin",
+            task.task_id()
+        );
+        return Err(TaskError::Failed(Reason::BuildRequestFailed));
+    }
+    let headers: HashMap<String, String> = config
+        .headers
+        .iter()
+        .map(|(k, v)| (k.clone(), render(v, chunk, total, count, "")))
+        .collect();
+    let mut builder = request_builder(task, &config.method, &url, &headers)?;
+    if !headers.contains_key("Content-Type") && !task.conf.headers.contains_key("Content-Type") {
+        builder = builder.header("Content-Type", "application/octet-stream");
+    }
+    let uploader = Uploader::builder()
+        .reader(ChunkReader {
+            file,
+            chunk: *chunk,
+            read: 0,
+        })
+        .operator(ChunkOperator { abort_flag })
+        .total_bytes(Some(chunk.len))
+        .build();
+    let request = builder
+        .header("Content-Length", chunk.len.to_string().as_str())
+        .body(Body::stream(uploader))?;
+    Ok(request)
+}
+
+/// Uploads one chunk, retrying it up to `ChunkConfig::max_retries` times on
+/// transient failures after a backoff, and records it once the server
+/// accepted it.
+///
+/// # Returns
+///
+/// The `ETag` the server returned for the chunk, empty if none.
+async fn upload_chunk(
+    task: &RequestTask,
+    client: &Client,
+    config: &ChunkConfig,
+    chunk: ChunkRange,
+    total: u64,
+    count: usize,
+    abort_flag: &Arc<AtomicBool>,
+) -> Result<String, TaskError> {
+    let Some(file) = task.files.get(0) else {
+        return Err(TaskError::Failed(Reason::OthersError));
+    };
+
+    let mut tries = 0;
+    let mut retry_after = None;
+    let etag = loop {
+        if tries > 0 {
+            wait_retry(retry_delay(tries, retry_after.take()), abort_flag).await?;
+        }
+        if abort_flag.load(Ordering::Acquire) {
+            return Err(TaskError::Waiting(TaskPhase::UserAbort));
+        }
+        let request = build_chunk_request(
+            task,
+            config,
+            &chunk,
+            total,
+            count,
+            file.clone(),
+            abort_flag.clone(),
+        )?;
+        let retry = tries < config.max_retries;
+        tries += 1;
+
+        match client.request(request).await {
+            Ok(response) => {
+                let status_code = response.status();
+                let code = status_code.as_u16();
+                if (200..300).contains(&code) {
+                    break response
+                        .headers()
+                        .get("etag")
+                        .and_then(|v| v.to_string().ok())
+                        .unwrap_or_default();
+                }
+                if retry && (status_code.is_server_error() || code == 408 || code == 429) {
+                    retry_after = response
+                        .headers()
+                        .get("retry-after")
+                        .and_then(|v| v.to_string().ok())
+                        .and_then(|v| parse_retry_after(&v, get_current_timestamp() / 1000));
+                    info!(
+                        "task {} chunk {} response {}, retry",
+                        task.task_id(),
+                        chunk.index,
+                        code
+                    );
+                    continue;
+                }
+                error!(
+                    "task {} chunk {} response {}",
+                    task.task_id(),
+                    chunk.index,
+                    code
+                );
+                super::http_error_registry::set_http_status_code(task.task_id(), code);
+                return Err(TaskError::Failed(Reason::ProtocolError));
+            }
+            Err(e) if e.error_kind() == ErrorKind::UserAborted => {
+                return Err(TaskError::Waiting(TaskPhase::UserAbort));
+            }
+            Err(e) => {
+                error!("task {} chunk {} {:?}", task.task_id(), chunk.index, e);
+                if retry && is_transient(&e) {
+                    continue;
+                }
+                if matches!(
+                    e.error_kind(),
+                    ErrorKind::Connect | ErrorKind::ConnectionUpgrade | ErrorKind::BodyTransfer
+                ) {
+                    task.network_retry().await?;
+                }
+                return Err(TaskError::Failed(client_error_reason(&e)));
+            }
+        }
+    };
+
+    if let Err(e) = chunk::record_chunk(task.task_id(), chunk.index, &etag) {
+        error!(
+            "task {} record chunk {} failed {}",
+            task.task_id(),
+            chunk.index,
+            e
+        );
+    }
+    {
+        let mut progress = task.progress.lock().unwrap();
+        progress.processed[0] += chunk.len as usize;
+        progress.common_data.total_processed += chunk.len as usize;
+    }
+    Ok(etag)
+}
+
+/// Sends the finalize request of a chunked upload.
+async fn finalize(
+    task: &RequestTask,
+    client: &Client,
+    config: &ChunkConfig,
+    chunks: &[ChunkRange],
+    etags: &HashMap<u32, String>,
+    total: u64,
+) -> Result<(), TaskError> {
+    if !is_same_origin(&config.finalize_url, &task.conf.url) {
+        error!(
+            "task {} finalize url not on the task url origin",
+            task.task_id()
+        );
+        return Err(TaskError::Failed(Reason::BuildRequestFailed));
+    }
+    let body = render_finalize_body(config, chunks, etags, total);
+    let request = request_builder(
+        task,
+        &config.finalize_method,
+        &config.finalize_url,
+        &HashMap::new(),
+    )?
+    .body(Body::slice(body.into_bytes()))?;
+
+    let response = client.request(request).await;
+    match response.as_ref() {
+        Ok(response) => {
+            #[cfg(feature = "oh")]
+            task.notify_response(response);
+            let status_code = response.status();
+            info!("task {} finalize response {}", task.task_id(), status_code);
+            if !(200..300).contains(&status_code.as_u16()) {
+                super::http_error_registry::set_http_status_code(
+                    task.task_id(),
+                    status_code.as_u16(),
+                );
+                return Err(TaskError::Failed(Reason::ProtocolError));
+            }
+        }
+        Err(e) if e.error_kind() == ErrorKind::UserAborted => {
+            return Err(TaskError::Waiting(TaskPhase::UserAbort));
+        }
+        Err(e) => {
+            error!("task {} finalize {:?}", task.task_id(), e);
+            return Err(TaskError::Failed(client_error_reason(e)));
+        }
+    }
+    task.record_upload_response(0, response).await;
+    Ok(())
+}
+
+/// Uploads the file of a chunked upload task.
+///
+/// # Arguments
+///
+/// * `task` - The request task to upload.
+/// * `config` - The chunk configuration of the task.
+/// * `abort_flag` - Atomic flag to signal upload cancellation, checked before
+///   each chunk request.
+///
+/// # Returns
+///
+/// `Ok(())` once all chunks are uploaded and the finalize request, if any,
+/// succeeded, or a `TaskError` if it fails.
+pub(crate) async fn upload_chunked(
+    task: Arc<RequestTask>,
+    config: ChunkConfig,
+    abort_flag: Arc<AtomicBool>,
+) -> Result<(), TaskError> {
+    if task.conf.file_specs.len() != 1 {
+        error!("chunked upload task {} must have one file", task.task_id());
+        return Err(TaskError::Failed(Reason::BuildRequestFailed));
+    }
+    let total = task.progress.lock().unwrap().sizes[0].max(0) as u64;
+    let chunks = plan_chunks(total, config.chunk_size);
+    let mut etags = chunk::uploaded_chunks(task.task_id());
+
+    let pending: Vec<ChunkRange> = chunks
+        .iter()
+        .filter(|c| !etags.contains_key(&c.index))
+        .copied()
+        .collect();
+    {
+        let uploaded = total - pending.iter().map(|c| c.len).sum::<u64>();
+        let mut progress = task.progress.lock().unwrap();
+        progress.processed[0] = uploaded as usize;
+        progress.common_data.total_processed = uploaded as usize;
+    }
+    info!(
+        "chunked upload task {} chunks {} pending {}",
+        task.task_id(),
+        chunks.len(),
+        pending.len()
+    );
+
+    let client = task.client.lock().await;
+    for wave in pending.chunks(config.parallelism.max(1) as usize) {
+        let futures = wave
+            .iter()
+            .map(|c| {
+                let future = upload_chunk(
+                    &task,
+                    &client,
+                    &config,
+                    *c,
+                    total,
+                    chunks.len(),
+                    &abort_flag,
+                );
+                Box::pin(future) as Pin<Box<dyn Future<Output = _> + Send + '_>>
+            })
+            .collect();
+        let results = JoinAll::new(futures).await;
+
+        task.update_progress_in_database();
+        Notifier::progress(&task.client_manager, task.build_notify_data());
+
+        for (c, result) in wave.iter().zip(results) {
+            etags.insert(c.index, result?);
+        }
+    }
+
+    if !config.finalize_url.is_empty() {
+        finalize(&task, &client, &config, &chunks, &etags, total).await?;
+    }
+    info!("chunked upload task {} uploaded", task.task_id());
+    Ok(())
+}
+
+#[cfg(test)]
+mod ut_chunked {
+    include!("../../tests/ut/task/ut_chunked.rs");
+}
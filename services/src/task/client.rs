@@ -19,7 +19,9 @@
 //!
 //! Key features include:
 //! - Secure TLS configuration and certificate management
-//! - Proxy settings support with task-specific and system-wide options
+//! - Proxy settings support with task-specific and system-wide options,
+//!   including SOCKS5 proxies, proxy credentials and per-host proxy rules
+//! - Custom DNS resolution with the DNS options of the application
 //! - Domain policy enforcement for atomic services
 //! - Redirect handling with domain validation
 //! - Public key pinning for enhanced security
@@ -38,7 +40,9 @@ cfg_oh! {
     use crate::utils::url_policy::check_url_domain;
 }
 
+use super::dns::{task_config, TaskResolver};
 use super::files::BundleCache;
+use super::proxy::{route, ProxyRules};
 use crate::task::config::{Action, TaskConfig};
 use crate::task::files::convert_path;
 
@@ -134,6 +138,11 @@ pub(crate) fn build_client(
                                       // proxy
     }
 
+    // Resolve hosts with the DNS options of the application, if any
+    if let Some(resolver) = TaskResolver::new(config.common_data.task_id, &config.bundle) {
+        client = client.dns_resolver(resolver);
+    }
+
     // HTTP url that contains redirects also require a certificate when
     // redirected to HTTPS.
 
@@ -232,6 +241,11 @@ pub(crate) fn build_client(
 
 /// Creates a proxy configuration from task settings.
 ///
+/// A single HTTP proxy is used directly, with its credentials if any. SOCKS5
+/// proxies and proxy rules are registered with the proxy router, which the
+/// client then uses as its HTTP proxy. See `crate::task::proxy` for the
+/// accepted formats.
+///
 /// # Arguments
 ///
 /// * `config` - The task configuration containing proxy settings.
@@ -251,6 +265,10 @@ pub(crate) fn build_client(
 /// config.proxy = "http://proxy.example.com:8080".to_string();
 /// let proxy = build_task_proxy(&config)?; // Returns Some(proxy)
 ///
+/// // With proxy rules
+/// config.proxy = "*.corp.example.com=socks5://10.0.0.1:1080;*=DIRECT".to_string();
+/// let proxy = build_task_proxy(&config)?; // Returns Some(router proxy)
+///
 /// // Without proxy
 /// let config = TaskConfig::default();
 /// let proxy = build_task_proxy(&config)?; // Returns None
@@ -262,9 +280,37 @@ fn build_task_proxy(config: &TaskConfig) -> Result<Option<Proxy>, Box<dyn Error
         return Ok(None);
     }
 
+    let rules = cvt_res_error!(
+        ProxyRules::parse(&config.proxy),
+        "Parse task proxy failed - tid: {}",
+        config.common_data.task_id,
+    );
+
+    // Use a single HTTP proxy directly, otherwise let the router choose the
+    // proxy of each request
+    let (url, credentials) = match rules.single_http() {
+        Some(server) => (
+            format!("http://{}", server.addr()),
+            server.credentials.clone(),
+        ),
+        None => {
+            let dns = task_config(config.common_data.task_id, &config.bundle);
+            let router = cvt_res_error!(
+                route(config.common_data.task_id, rules, dns).map_err(Box::new),
+                "Route task proxy failed - tid: {}",
+                config.common_data.task_id,
+            );
+            (router.url, Some((router.user, router.password)))
+        }
+    };
+
     // Create proxy configuration for all protocols
+    let mut proxy = Proxy::all(&url);
+    if let Some((user, password)) = credentials {
+        proxy = proxy.basic_auth(&user, &password);
+    }
     Ok(Some(cvt_res_error!(
-        Proxy::all(&config.proxy).build().map_err(Box::new),
+        proxy.build().map_err(Box::new),
         "Create task proxy failed",
     )))
 }
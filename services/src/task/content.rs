@@ -0,0 +1,311 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Content type and file name resolution of downloads.
+//!
+//! Provides the parsing of the `Content-Disposition` header, including the
+//! RFC 5987 `filename*` parameter, the sniffing of the content type from the
+//! first bytes of a download when the server sent none or a generic one, and
+//! the sanitization of server-suggested file names before they are used on
+//! disk.
+
+/// Number of leading bytes of a download used to sniff its content type.
+pub(crate) const SNIFF_LEN: u64 = 512;
+
+/// Longest file name accepted by the file system, in bytes.
+const MAX_FILE_NAME_LEN: usize = 255;
+
+/// Longest extension kept when a file name is shortened, in bytes.
+const MAX_EXTENSION_LEN: usize = 16;
+
+/// Content types carrying no information about the content.
+const GENERIC_MIME_TYPES: [&str; 7] = [
+    "application/octet-stream",
+    "binary/octet-stream",
+    "application/unknown",
+    "application/download",
+    "application/x-download",
+    "application/force-download",
+    "unknown/unknown",
+];
+
+/// Content type of content that cannot be identified.
+pub(crate) const UNKNOWN_MIME_TYPE: &str = "application/octet-stream";
+
+/// Byte order marks of Unicode text.
+const BOMS: [&[u8]; 3] = [b"\xef\xbb\xbf", b"\xfe\xff", b"\xff\xfe"];
+
+/// Sizes of the known BMP information headers.
+const BMP_INFO_HEADER_SIZES: [u32; 6] = [12, 40, 52, 56, 108, 124];
+
+/// Size of the BMP file header preceding the information header.
+const BMP_FILE_HEADER_SIZE: u32 = 14;
+
+/// Magic bytes at the start of a file and the content type they identify.
+const SIGNATURES: [(&[u8], &str); 17] = [
+    (b"\x89PNG\r\n\x1a\n", "image/png"),
+    (b"\xff\xd8\xff", "image/jpeg"),
+    (b"GIF87a", "image/gif"),
+    (b"GIF89a", "image/gif"),
+    (b"\x00\x00\x01\x00", "image/x-icon"),
+    (b"%PDF-", "application/pdf"),
+    (b"%!PS", "application/postscript"),
+    (b"PK\x03\x04", "application/zip"),
+    (b"\x1f\x8b", "application/gzip"),
+    (b"7z\xbc\xaf\x27\x1c", "application/x-7z-compressed"),
+    (b"Rar!\x1a\x07", "application/vnd.rar"),
+    (b"ID3", "audio/mpeg"),
+    (b"OggS", "audio/ogg"),
+    (b"fLaC", "audio/flac"),
+    (b"\x1a\x45\xdf\xa3", "video/webm"),
+    (b"wOFF", "font/woff"),
+    (b"wOF2", "font/woff2"),
+];
+
+/// Gets the file name suggested by a `Content-Disposition` header value.
+///
+/// The RFC 5987 `filename*` parameter takes precedence over `filename`. The
+/// returned name is not sanitized, see [`sanitize_file_name`].
+pub(crate) fn content_disposition_file_name(value: &str) -> Option<String> {
+    let mut name = None;
+    let mut ext_name = None;
+    // The first item is the disposition type.
+    for param in split_params(value).into_iter().skip(1) {
+        let Some((key, value)) = param.split_once('=') else {
+            continue;
+        };
+        match key.trim().to_ascii_lowercase().as_str() {
+            "filename*" => ext_name = decode_ext_value(&unquote(value.trim())),
+            "filename" => name = Some(unquote(value.trim())),
+            _ => {}
+        }
+    }
+    ext_name.or(name).filter(|name| !name.is_empty())
+}
+
+/// Splits a header value on the `;` that are not inside a quoted string.
+fn split_params(value: &str) -> Vec<&str> {
+    let mut params = Vec::new();
+    let mut start = 0;
+    let mut quoted = false;
+    let mut escaped = false;
+    for (i, c) in value.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            ';' if !quoted => {
+                params.push(&value[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    params.push(&value[start..]);
+    params
+}
+
+/// Removes the quotes and escapes of a quoted string, or returns a token
+/// unchanged.
+fn unquote(value: &str) -> String {
+    match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        Some(inner) => {
+            let mut out = String::with_capacity(inner.len());
+            let mut chars = inner.chars();
+            while let Some(c) = chars.next() {
+                match c {
+                    '\\' => out.extend(chars.next()),
+                    c => out.push(c),
+                }
+            }
+            out
+        }
+        None => value.to_string(),
+    }
+}
+
+/// Decodes an RFC 5987 extended value, `charset'language'percent-encoded`.
+fn decode_ext_value(value: &str) -> Option<String> {
+    let mut parts = value.splitn(3, '\'');
+    let charset = parts.next()?.to_ascii_lowercase();
+    let _language = parts.next()?;
+    let bytes = percent_decode(parts.next()?)?;
+    match charset.as_str() {
+        "utf-8" => String::from_utf8(bytes).ok(),
+        "iso-8859-1" => Some(bytes.into_iter().map(char::from).collect()),
+        _ => None,
+    }
+}
+
+/// Decodes `%XX` escapes, failing on malformed ones.
+fn percent_decode(value: &str) -> Option<Vec<u8>> {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = bytes.get(i + 1..i + 3)?;
+            // `from_str_radix` also accepts a sign, as in `%+5`
+            if !hex.iter().all(u8::is_ascii_hexdigit) {
+                return None;
+            }
+            out.push(u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok()?);
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    Some(out)
+}
+
+/// Gets the last path segment of a URL, percent-decoded, as a fallback file
+/// name.
+pub(crate) fn url_file_name(url: &str) -> Option<String> {
+    let path = url.split(['?', '#']).next().unwrap_or("");
+    let path = path.split_once("://").map_or(path, |(_, rest)| {
+        rest.split_once('/').map_or("", |(_, path)| path)
+    });
+    let segment = path.rsplit('/').next().unwrap_or("");
+    let bytes = percent_decode(segment).unwrap_or_else(|| segment.as_bytes().to_vec());
+    Some(String::from_utf8_lossy(&bytes).into_owned()).filter(|name| !name.is_empty())
+}
+
+/// Whether a `Content-Type` header value is missing or says nothing about
+/// the content.
+pub(crate) fn is_generic_mime_type(mime_type: &str) -> bool {
+    let essence = mime_type
+        .split(';')
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_ascii_lowercase();
+    essence.is_empty() || GENERIC_MIME_TYPES.contains(&essence.as_str())
+}
+
+/// Sniffs the content type of a file from its first bytes.
+///
+/// Text is only recognized by its byte order mark or its markup, anything
+/// not identified is `UNKNOWN_MIME_TYPE`.
+pub(crate) fn sniff_mime_type(data: &[u8]) -> &'static str {
+    if let Some((_, mime_type)) = SIGNATURES.iter().find(|(magic, _)| data.starts_with(magic)) {
+        return mime_type;
+    }
+    if is_bmp(data) {
+        return "image/bmp";
+    }
+    if data.len() >= 12 {
+        match (&data[..4], &data[8..12]) {
+            (b"RIFF", b"WEBP") => return "image/webp",
+            (b"RIFF", b"WAVE") => return "audio/wav",
+            (b"RIFF", b"AVI ") => return "video/x-msvideo",
+            _ => {}
+        }
+        if &data[4..8] == b"ftyp" {
+            return match &data[8..12] {
+                b"qt  " => "video/quicktime",
+                b"M4A " => "audio/mp4",
+                b"heic" | b"heix" => "image/heic",
+                _ => "video/mp4",
+            };
+        }
+    }
+
+    let text = data
+        .strip_prefix(b"\xef\xbb\xbf")
+        .unwrap_or(data)
+        .trim_ascii_start();
+    let lower = text[..text.len().min(16)].to_ascii_lowercase();
+    if lower.starts_with(b"<?xml") {
+        return "application/xml";
+    }
+    if lower.starts_with(b"<!doctype html") || lower.starts_with(b"<html") {
+        return "text/html";
+    }
+    if BOMS.iter().any(|bom| data.starts_with(bom)) {
+        return "text/plain";
+    }
+    UNKNOWN_MIME_TYPE
+}
+
+/// Whether `data` starts with a BMP file header whose file size, pixel data
+/// offset and information header size are consistent.
+fn is_bmp(data: &[u8]) -> bool {
+    let field = |at: usize| {
+        data.get(at..at + 4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    };
+    let (Some(size), Some(offset), Some(info_size)) = (field(2), field(10), field(14)) else {
+        return false;
+    };
+    data.starts_with(b"BM")
+        && BMP_INFO_HEADER_SIZES.contains(&info_size)
+        && offset >= BMP_FILE_HEADER_SIZE + info_size
+        && size >= offset
+}
+
+/// Makes a server-suggested file name safe to create in a directory.
+///
+/// Drops any directory part, control characters and leading or trailing dots
+/// and spaces, replaces characters reserved on common file systems by `_`
+/// and shortens the name to the file system limit, keeping its extension.
+/// Returns `None` if nothing usable is left.
+pub(crate) fn sanitize_file_name(name: &str) -> Option<String> {
+    let name = name.rsplit(['/', '\\']).next().unwrap_or("");
+    let name: String = name
+        .chars()
+        .filter(|c| !c.is_control())
+        .map(|c| match c {
+            '<' | '>' | ':' | '"' | '|' | '?' | '*' => '_',
+            c => c,
+        })
+        .collect();
+    let name = name.trim_matches(|c: char| c == '.' || c.is_whitespace());
+    if name.is_empty() {
+        return None;
+    }
+    Some(truncate_file_name(name))
+}
+
+/// Gets the `n`th alternative of a file name, `name (n).ext`, used when the
+/// name is already taken.
+pub(crate) fn numbered_file_name(name: &str, n: u32) -> String {
+    let (stem, extension) = split_extension(name);
+    truncate_file_name(&format!("{} ({}){}", stem, n, extension))
+}
+
+/// Splits a file name into its stem and its extension, dot included.
+fn split_extension(name: &str) -> (&str, &str) {
+    match name.rfind('.') {
+        Some(pos) if pos > 0 && name.len() - pos <= MAX_EXTENSION_LEN => name.split_at(pos),
+        _ => (name, ""),
+    }
+}
+
+/// Shortens a file name to `MAX_FILE_NAME_LEN` bytes, keeping its extension.
+fn truncate_file_name(name: &str) -> String {
+    if name.len() <= MAX_FILE_NAME_LEN {
+        return name.to_string();
+    }
+    let (stem, extension) = split_extension(name);
+    let mut end = MAX_FILE_NAME_LEN - extension.len();
+    while !stem.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}{}", &stem[..end], extension)
+}
+
+#[cfg(test)]
+mod ut_content {
+    include!("../../tests/ut/task/ut_content.rs");
+}
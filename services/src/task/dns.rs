@@ -0,0 +1,233 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! DNS options of applications.
+//!
+//! The options are read from `DNS_CONFIG_PATH`, a text file of `key = value`
+//! lines where lines starting with `#` are comments. A key applies to every
+//! application, or with a `.<bundle>` suffix to that application only:
+//!
+//! ```text
+//! server = system
+//! server.com.example.video = https://dns.example.com/dns-query
+//! ip_preference.com.example.video = ipv6_first
+//! first_family_count.com.example.video = 2
+//! resolve.com.example.video = cdn.example.com:443:203.0.113.7,2001:db8::7
+//! ```
+//!
+//! `server` is `system`, `udp://addr[:port]`, `tls://addr[:port][#name]` or
+//! an `https://` URL, `resolve` lines add static overrides in the style of
+//! curl `--resolve`, see `request_utils::dns`. The options of an application
+//! start from the options of every application. Applications with options
+//! other than the system resolver get a `TaskResolver`, which records the
+//! addresses it resolved so that they are reported with the response, under
+//! `RESOLVED_ADDRESS_KEY` in the extras of the progress.
+//!
+//! A task may also pin addresses of its own and choose its IP preference,
+//! see `database::dns`; those apply on top of the options of its
+//! application.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, LazyLock, Mutex};
+
+use request_utils::dns::{DnsConfig, DnsServer, HostOverride, IpPreference};
+use ylong_http_client::async_impl::{Addrs, Resolver, SocketFuture};
+
+use crate::database::dns::task_dns;
+
+/// Path of the DNS configuration file.
+const DNS_CONFIG_PATH: &str = "/system/etc/request/dns.conf";
+
+/// Key of the resolved addresses in the extras of the progress.
+pub(crate) const RESOLVED_ADDRESS_KEY: &str = "resolved_address";
+
+/// Addresses resolved for each task, until reported with its response.
+static RESOLVED: LazyLock<Mutex<HashMap<u32, Vec<SocketAddr>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// DNS options of all applications.
+#[derive(Default)]
+pub(crate) struct DnsSettings {
+    /// Options of applications without specific options.
+    default: DnsConfig,
+    /// Options of specific applications by bundle name.
+    apps: HashMap<String, DnsConfig>,
+}
+
+impl DnsSettings {
+    /// Gets the settings loaded from `DNS_CONFIG_PATH` on first use.
+    pub(crate) fn get_instance() -> &'static Self {
+        static INSTANCE: LazyLock<DnsSettings> =
+            LazyLock::new(|| match std::fs::read_to_string(DNS_CONFIG_PATH) {
+                Ok(text) => DnsSettings::parse(&text),
+                Err(e) => {
+                    info!("DNS config not loaded, use system resolver: {}", e);
+                    DnsSettings::default()
+                }
+            });
+        &INSTANCE
+    }
+
+    /// Parses a configuration file, skipping invalid lines.
+    pub(crate) fn parse(text: &str) -> Self {
+        let mut settings = Self::default();
+        let mut app_lines = Vec::new();
+        for (n, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                error!("DNS config line {} invalid: {}", n + 1, line);
+                continue;
+            };
+            let (key, value) = (key.trim(), value.trim());
+            match key.split_once('.') {
+                Some((option, bundle)) => app_lines.push((n, option, bundle, value)),
+                None => {
+                    if !apply(&mut settings.default, key, value) {
+                        error!("DNS config line {} invalid: {}", n + 1, line);
+                    }
+                }
+            }
+        }
+        // Applications start from the options of every application, wherever
+        // their lines are.
+        for (n, option, bundle, value) in app_lines {
+            let config = settings
+                .apps
+                .entry(bundle.to_string())
+                .or_insert_with(|| settings.default.clone());
+            if !apply(config, option, value) {
+                error!("DNS config line {} invalid: {}", n + 1, option);
+            }
+        }
+        settings
+    }
+
+    /// Gets the options of an application.
+    pub(crate) fn config(&self, bundle: &str) -> &DnsConfig {
+        self.apps.get(bundle).unwrap_or(&self.default)
+    }
+}
+
+/// Gets the DNS options of a task: those of its application with the
+/// options set on the task itself.
+pub(crate) fn task_config(task_id: u32, bundle: &str) -> DnsConfig {
+    let mut config = DnsSettings::get_instance().config(bundle).clone();
+    if let Some(options) = task_dns(task_id) {
+        if options.apply(&mut config).is_none() {
+            error!("task {} dns options invalid, ignored", task_id);
+        }
+    }
+    config
+}
+
+/// Applies one option, returning whether it was valid.
+fn apply(config: &mut DnsConfig, option: &str, value: &str) -> bool {
+    match option {
+        "server" => match DnsServer::parse(value) {
+            Ok(server) => config.server = server,
+            Err(_) => return false,
+        },
+        "ip_preference" => match IpPreference::parse(value) {
+            Ok(preference) => config.preference = preference,
+            Err(_) => return false,
+        },
+        "first_family_count" => match value.parse() {
+            Ok(count) => config.first_family_count = count,
+            Err(_) => return false,
+        },
+        "resolve" => match HostOverride::parse(value) {
+            Ok(host_override) => config.overrides.push(host_override),
+            Err(_) => return false,
+        },
+        _ => return false,
+    }
+    true
+}
+
+/// Resolver of the client of a task following its DNS options.
+pub(crate) struct TaskResolver {
+    task_id: u32,
+    config: Arc<DnsConfig>,
+}
+
+impl TaskResolver {
+    /// Creates the resolver of a task, or `None` if it uses the system
+    /// resolver.
+    pub(crate) fn new(task_id: u32, bundle: &str) -> Option<Self> {
+        let config = task_config(task_id, bundle);
+        if config.is_default() {
+            return None;
+        }
+        Some(Self {
+            task_id,
+            config: Arc::new(config),
+        })
+    }
+}
+
+impl Resolver for TaskResolver {
+    fn resolve(&self, authority: &str) -> SocketFuture {
+        let task_id = self.task_id;
+        let config = self.config.clone();
+        let authority = authority.to_string();
+        Box::pin(async move {
+            let addrs = ylong_runtime::spawn_blocking(move || config.resolve_authority(&authority))
+                .await
+                .map_err(|e| std::io::Error::other(e.to_string()))?
+                .inspect_err(|e| error!("task {} resolve failed: {}", task_id, e))?;
+            debug!("task {} resolved {:?}", task_id, addrs);
+            RESOLVED.lock().unwrap().insert(task_id, addrs.clone());
+            Ok(Box::new(ResolvedAddrs(addrs.into_iter())) as Box<dyn Addrs>)
+        })
+    }
+}
+
+/// Addresses returned by a `TaskResolver`, in the order to try them.
+struct ResolvedAddrs(std::vec::IntoIter<SocketAddr>);
+
+impl Iterator for ResolvedAddrs {
+    type Item = SocketAddr;
+
+    fn next(&mut self) -> Option<SocketAddr> {
+        self.0.next()
+    }
+}
+
+impl Addrs for ResolvedAddrs {}
+
+/// Takes the addresses last resolved for a task by its `TaskResolver`,
+/// formatted as a comma separated list in the order they were tried.
+pub(crate) fn take_resolved(task_id: u32) -> Option<String> {
+    let addrs = RESOLVED.lock().unwrap().remove(&task_id)?;
+    Some(
+        addrs
+            .iter()
+            .map(SocketAddr::to_string)
+            .collect::<Vec<_>>()
+            .join(","),
+    )
+}
+
+/// Forgets the addresses resolved for a task that did not report them.
+pub(crate) fn clear_resolved(task_id: u32) {
+    RESOLVED.lock().unwrap().remove(&task_id);
+}
+
+#[cfg(test)]
+mod ut_dns {
+    include!("../../tests/ut/task/ut_dns.rs");
+}
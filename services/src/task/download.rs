@@ -34,6 +34,8 @@ use ylong_http_client::{ErrorKind, HttpClientError, SpeedLimit, Timeout};
 use super::operator::TaskOperator;
 use super::reason::Reason;
 use super::request_task::{TaskError, TaskPhase};
+use super::{content, dns, files};
+use crate::database::suggested_name;
 use crate::manage::database::RequestDb;
 use crate::task::info::State;
 use crate::task::request_task::RequestTask;
@@ -52,6 +54,10 @@ const LOW_SPEED_TIME: u64 = 60;
 /// stalled.
 const LOW_SPEED_LIMIT: u64 = 1;
 
+/// File name used when saving under the suggested name but neither the
+/// server nor the URL suggests one.
+const DEFAULT_FILE_NAME: &str = "download";
+
 /// Implementation of the `DownloadOperator` trait for `TaskOperator`.
 ///
 /// This implementation enables `TaskOperator` to be used with the HTTP client's
@@ -228,6 +234,74 @@ impl RequestTask {
         }
         Ok(())
     }
+
+    /// Replaces a missing or generic content type by the one sniffed from the
+    /// first bytes of the downloaded file.
+    async fn sniff_mime_type(&self) {
+        if !content::is_generic_mime_type(&self.mime_type()) {
+            return;
+        }
+        let Some(file) = self.files.get(0) else {
+            return;
+        };
+        let downloaded = self
+            .progress
+            .lock()
+            .unwrap()
+            .processed
+            .first()
+            .map_or(0, |x| *x as u64);
+        let len = downloaded.min(content::SNIFF_LEN);
+        match task_control::file_read_at(file, 0, len).await {
+            Ok(data) => {
+                let mime_type = content::sniff_mime_type(&data);
+                info!("task {} sniffed mime type {}", self.task_id(), mime_type);
+                *self.mime_type.lock().unwrap() = mime_type.to_string();
+            }
+            Err(e) => error!("task {} read file to sniff failed {:?}", self.task_id(), e),
+        }
+    }
+
+    /// Moves the downloaded file into the save directory of the task under
+    /// the name suggested by the server, if the app asked for it.
+    ///
+    /// The suggested name is sanitized; the last URL path segment, then
+    /// `DEFAULT_FILE_NAME`, is used when there is none. The file
+    /// specification of the task then points to the new path, in memory and
+    /// in the database.
+    fn save_as_suggested_name(&self) -> Result<(), TaskError> {
+        let suggested = suggested_name::suggested_name(self.task_id());
+        if suggested.save_dir.is_empty() || !suggested.saved_path.is_empty() {
+            return Ok(());
+        }
+        let name = content::sanitize_file_name(&suggested.name)
+            .or_else(|| {
+                content::url_file_name(&self.conf.url)
+                    .and_then(|name| content::sanitize_file_name(&name))
+            })
+            .unwrap_or_else(|| DEFAULT_FILE_NAME.to_string());
+
+        match files::save_as(&self.conf, &suggested.save_dir, &name) {
+            Ok(path) => {
+                info!("task {} saved as {}", self.task_id(), path);
+                if let Err(e) = suggested_name::set_saved_path(self.task_id(), &path) {
+                    error!("task {} record saved path failed {}", self.task_id(), e);
+                }
+                *self.saved_path.lock().unwrap() = Some(path);
+                RequestDb::get_instance()
+                    .update_task_file_specs(self.task_id(), &self.file_specs());
+                Ok(())
+            }
+            Err(e) => {
+                error!(
+                    "task {} save as suggested name failed {:?}",
+                    self.task_id(),
+                    e
+                );
+                Err(TaskError::Failed(Reason::IoError))
+            }
+        }
+    }
 }
 
 /// Performs the core download operation including request handling and file
@@ -450,6 +524,9 @@ pub(crate) async fn download_inner(
                 guard.extras.insert(k.to_string().to_lowercase(), value);
             }
         }
+        if let Some(addrs) = dns::take_resolved(task.task_id()) {
+            guard.extras.insert(dns::RESOLVED_ADDRESS_KEY.to_string(), addrs);
+        }
     }
     task.get_file_info(&response)?;
     task.update_progress_in_database();
@@ -481,6 +558,8 @@ pub(crate) async fn download_inner(
 
     #[cfg(not(test))]
     check_file_exist(&task)?;
+    task.sniff_mime_type().await;
+    task.save_as_suggested_name()?;
     {
         let mut guard = task.progress.lock().unwrap();
         guard.sizes = vec![guard.processed.first().map_or_else(
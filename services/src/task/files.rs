@@ -36,10 +36,14 @@ use std::sync::{Arc, Mutex};
 const O_NOFOLLOW: i32 = 0o400_000;
 const O_CLOEXEC: i32 = 0o2_000_000;
 
+/// Most numbered alternatives tried when a suggested file name is taken.
+const MAX_NAME_ALTERNATIVES: u32 = 1000;
+
 use crate::error::{ErrorCode, ServiceError};
 use crate::manage::account;
 use crate::task::bundle::get_name_and_index;
 use crate::task::config::{Action, TaskConfig};
+use crate::task::content::numbered_file_name;
 use crate::task::ATOMIC_SERVICE;
 
 /// Container for all files associated with a network task.
@@ -303,6 +307,101 @@ fn verify_within_base(base: &str, file: File) -> io::Result<File> {
     }
 }
 
+/// Moves the downloaded file of a task into `dir` under `name`, or under the
+/// first free `name (n)` alternative if `name` is taken.
+///
+/// Both the current and the target directory are opened and verified to lie
+/// under the app base directory, then the file is hard linked and unlinked
+/// relative to the verified descriptors, so swapping a sandbox directory for
+/// a symlink cannot move files outside the caller's sandbox and an existing
+/// file is never overwritten.
+///
+/// # Returns
+/// The sandbox path the file was saved at.
+///
+/// # Errors
+/// Returns an `io::Error` if a path is outside the sandbox, every candidate
+/// name is taken or the file cannot be moved.
+pub(crate) fn save_as(config: &TaskConfig, dir: &str, name: &str) -> io::Result<String> {
+    let uid = config.common_data.uid;
+    let bundle_name = BundleCache::new(config)
+        .get_value()
+        .map_err(|_| io::Error::new(io::ErrorKind::Other, "bundle name not found"))?;
+    let path = config
+        .file_specs
+        .first()
+        .map(|spec| spec.path.as_str())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no file in the task"))?;
+    let Some((from_dir, from_name)) = path.rsplit_once('/') else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "not a file path",
+        ));
+    };
+
+    let (base, full) = app_base_and_path(uid, &bundle_name, from_dir)?;
+    let from_dir = open_dir_verified(&base, &full)?;
+    let (base, full) = app_base_and_path(uid, &bundle_name, dir)?;
+    let to_dir = open_dir_verified(&base, &full)?;
+
+    let file_name = move_into(&from_dir, from_name, &to_dir, name)?;
+    Ok(format!("{}/{}", dir.trim_end_matches('/'), file_name))
+}
+
+/// Opens a directory and verifies that it lies under the app base directory.
+///
+/// # Errors
+/// Returns an `io::Error` if the directory cannot be opened, is not a
+/// directory or fails the sandbox check.
+fn open_dir_verified(base: &str, path: &str) -> io::Result<File> {
+    let dir = OpenOptions::new()
+        .read(true)
+        .custom_flags(O_NOFOLLOW | O_CLOEXEC)
+        .open(path)?;
+    let dir = verify_within_base(base, dir)?;
+    if !dir.metadata()?.is_dir() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "not a directory",
+        ));
+    }
+    Ok(dir)
+}
+
+/// Moves `from_name` of `from_dir` into `to_dir` under `name` or its first
+/// free numbered alternative, returning the name used.
+///
+/// Paths are resolved through `/proc/self/fd`, so the move happens in the
+/// opened directories whatever their paths point to by now.
+fn move_into(from_dir: &File, from_name: &str, to_dir: &File, name: &str) -> io::Result<String> {
+    let from = format!("/proc/self/fd/{}/{}", from_dir.as_raw_fd(), from_name);
+    let same_dir = std::fs::read_link(format!("/proc/self/fd/{}", from_dir.as_raw_fd()))?
+        == std::fs::read_link(format!("/proc/self/fd/{}", to_dir.as_raw_fd()))?;
+
+    for n in 0..=MAX_NAME_ALTERNATIVES {
+        let file_name = match n {
+            0 => name.to_string(),
+            n => numbered_file_name(name, n),
+        };
+        if same_dir && file_name == from_name {
+            return Ok(file_name);
+        }
+        let to = format!("/proc/self/fd/{}/{}", to_dir.as_raw_fd(), file_name);
+        match std::fs::hard_link(&from, &to) {
+            Ok(()) => {
+                std::fs::remove_file(&from)?;
+                return Ok(file_name);
+            }
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Err(io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        "no free file name",
+    ))
+}
+
 /// Converts a relative path to an absolute path based on the user and bundle.
 ///
 /// Transforms paths by replacing "storage" with "app" and "base" with a path
@@ -484,6 +583,7 @@ mod tests {
     use std::fs;
     use std::io::{Read, Write};
     use std::os::unix::fs::symlink;
+    use std::path::Path;
 
     use super::*;
 
@@ -678,4 +778,58 @@ mod tests {
         let err = verify_within_base(&base, file).unwrap_err();
         assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
     }
+
+    // @tc.name: move_into_suggested_name
+    // @tc.desc: Test moving a downloaded file under a suggested name
+    // @tc.precon: NA
+    // @tc.step: 1. Move a file into a directory holding no file of that name
+    //           2. Move another file under the same name
+    // @tc.expect: The first file keeps the name, the second one gets a
+    //             numbered name and no file is overwritten
+    // @tc.type: FUNC
+    #[test]
+    fn move_into_suggested_name() {
+        let dir = TestDir::new("move_into");
+        let base = dir.base();
+        fs::create_dir_all(format!("{}/cache", base)).unwrap();
+        fs::create_dir_all(format!("{}/files", base)).unwrap();
+        fs::write(format!("{}/cache/1.tmp", base), b"one").unwrap();
+        fs::write(format!("{}/cache/2.tmp", base), b"two").unwrap();
+
+        let from = open_dir_verified(&base, &format!("{}/cache", base)).unwrap();
+        let to = open_dir_verified(&base, &format!("{}/files", base)).unwrap();
+        assert_eq!(move_into(&from, "1.tmp", &to, "a.txt").unwrap(), "a.txt");
+        assert_eq!(
+            move_into(&from, "2.tmp", &to, "a.txt").unwrap(),
+            "a (1).txt"
+        );
+
+        assert!(!Path::new(&format!("{}/cache/1.tmp", base)).exists());
+        assert_eq!(fs::read(format!("{}/files/a.txt", base)).unwrap(), b"one");
+        assert_eq!(
+            fs::read(format!("{}/files/a (1).txt", base)).unwrap(),
+            b"two"
+        );
+    }
+
+    // @tc.name: open_dir_outside_base_is_rejected
+    // @tc.desc: Test that a save directory outside the base dir is rejected
+    // @tc.precon: NA
+    // @tc.step: 1. Plant a symlink dir under base pointing outside
+    //           2. Open it as a save directory
+    // @tc.expect: Open fails and no file can be moved there
+    // @tc.type: FUNC
+    #[test]
+    fn open_dir_outside_base_is_rejected() {
+        let dir = TestDir::new("open_dir_outside");
+        let base = dir.base();
+        let outside = format!("{}/outside", dir.0);
+        fs::create_dir_all(format!("{}/files", base)).unwrap();
+        fs::create_dir_all(&outside).unwrap();
+        symlink(&outside, format!("{}/files/link", base)).unwrap();
+
+        assert!(open_dir_verified(&base, &format!("{}/files/link", base)).is_err());
+        assert!(open_dir_verified(&base, &format!("{}/files/link/", base)).is_err());
+        assert!(open_dir_verified(&base, &outside).is_err());
+    }
 }
@@ -23,6 +23,7 @@ use std::collections::HashMap;
 pub use ffi::State;
 
 use super::notify::{EachFileStatus, NotifyData, Progress};
+use crate::database::file_status;
 use crate::task::config::{Action, Version};
 use crate::task::reason::Reason;
 use crate::utils::c_wrapper::{CFileSpec, CFormItem};
@@ -250,14 +251,25 @@ impl TaskInfo {
     }
 
     /// Creates a list of `EachFileStatus` objects representing the status of
-    /// each file. When the task failed due to a protocol error, the message
-    /// is enriched with the specific HTTP status code.
+    /// each file. Files of a multi-file upload whose result has been recorded
+    /// report that result, so succeeded and failed files can be told apart.
+    /// When the task failed due to a protocol error, the message is enriched
+    /// with the specific HTTP status code.
     pub(crate) fn build_each_file_status(&self) -> Vec<EachFileStatus> {
         let mut statuses = EachFileStatus::create_each_file_status(
             &self.file_specs,
             self.progress.common_data.index,
             self.common_data.reason.into(),
         );
+        if Action::from(self.common_data.action) == Action::Upload && statuses.len() > 1 {
+            let results = file_status::file_results(self.common_data.task_id, statuses.len());
+            for (status, result) in statuses.iter_mut().zip(results) {
+                if let Some(reason) = result {
+                    status.reason = reason;
+                    status.message = reason.to_str().into();
+                }
+            }
+        }
         let reason = Reason::from(self.common_data.reason);
         if reason == Reason::ProtocolError {
             let http_code = super::http_error_registry::take_http_status_code(self.common_data.task_id);
@@ -24,11 +24,15 @@ pub mod config;
 pub mod info;
 
 // Internal modules for task implementation
+pub(crate) mod chunked; // Chunked upload of large files
+pub(crate) mod content; // Content type sniffing and suggested file names
+pub(crate) mod dns; // DNS options of applications
 pub(crate) mod download; // Download task handling
 pub(crate) mod files; // File management utilities
 pub(crate) mod http_error_registry; // HTTP error code registry for protocol errors
 pub(crate) mod notify; // Notification and event handling
 mod operator; // Task operation implementations
+pub(crate) mod proxy; // Proxy rules and the proxy router
 pub(crate) mod reason; // Error and state reason codes
 pub(crate) mod request_task; // Core task abstraction
 
@@ -0,0 +1,229 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Proxy selection for tasks.
+//!
+//! The `proxy` of a task configuration is either a single proxy or a list of
+//! rules choosing a proxy per host:
+//!
+//! ```text
+//! http://[user:password@]host:port
+//! socks5://[user:password@]host:port
+//! pattern=target;pattern=target;...
+//! ```
+//!
+//! A rule target is a proxy as above or `DIRECT`. Patterns are matched
+//! against the host of each request, case-insensitively, with `*` matching
+//! any sequence of characters, so `*.example.com` matches the subdomains of
+//! `example.com` and `*` matches every host. The first matching rule wins and
+//! hosts matched by no rule are connected directly.
+//!
+//! A single HTTP proxy is handed to the HTTP client as is. SOCKS5 proxies and
+//! rule lists go through the `router`, a loopback HTTP proxy choosing the
+//! upstream of every request, redirect hops included.
+
+mod router;
+mod socks5;
+
+use std::error::Error;
+
+pub(crate) use router::{route, unroute};
+
+/// Maximum length of the proxy of a task configuration.
+pub(crate) const PROXY_MAX_LEN: usize = 512;
+
+/// A proxy server and its credentials.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ProxyServer {
+    /// Host name or address of the proxy.
+    pub(crate) host: String,
+    /// Port of the proxy.
+    pub(crate) port: u16,
+    /// User name and password, if the proxy requires authentication.
+    pub(crate) credentials: Option<(String, String)>,
+}
+
+impl ProxyServer {
+    /// Gets the `host:port` address of the proxy.
+    pub(crate) fn addr(&self) -> String {
+        format!("{}:{}", self.host, self.port)
+    }
+}
+
+/// Where the requests to a host go.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum ProxyTarget {
+    /// Connects to the host directly.
+    Direct,
+    /// Goes through an HTTP proxy.
+    Http(ProxyServer),
+    /// Goes through a SOCKS5 proxy.
+    Socks5(ProxyServer),
+}
+
+/// Proxy rules of a task, in order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ProxyRules {
+    rules: Vec<(String, ProxyTarget)>,
+}
+
+impl ProxyRules {
+    /// Parses the proxy of a task configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the proxy is too long, a target is neither a
+    /// supported proxy nor `DIRECT`, or a port is invalid.
+    pub(crate) fn parse(proxy: &str) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        if proxy.len() > PROXY_MAX_LEN {
+            return Err("proxy too long".into());
+        }
+        let proxy = proxy.trim();
+        // An `=` in the credentials of a single proxy does not make a rule
+        // list, whose first pattern has neither scheme nor user information.
+        let is_rules = proxy
+            .split_once('=')
+            .is_some_and(|(head, _)| !head.contains("://") && !head.contains('@'));
+        if !is_rules {
+            return match parse_target(proxy)? {
+                ProxyTarget::Direct => Err("DIRECT without pattern".into()),
+                target => Ok(Self {
+                    rules: vec![("*".to_string(), target)],
+                }),
+            };
+        }
+        let mut rules = Vec::new();
+        for rule in proxy
+            .split(';')
+            .map(str::trim)
+            .filter(|rule| !rule.is_empty())
+        {
+            let (pattern, target) = rule
+                .split_once('=')
+                .ok_or_else(|| format!("rule without target: {}", redact(rule)))?;
+            let pattern = pattern.trim();
+            if pattern.is_empty() {
+                return Err(format!("rule without pattern: {}", redact(rule)).into());
+            }
+            rules.push((pattern.to_ascii_lowercase(), parse_target(target.trim())?));
+        }
+        if rules.is_empty() {
+            return Err("empty proxy rules".into());
+        }
+        Ok(Self { rules })
+    }
+
+    /// Selects the target of the requests to `host`.
+    pub(crate) fn select(&self, host: &str) -> &ProxyTarget {
+        let host = host.to_ascii_lowercase();
+        self.rules
+            .iter()
+            .find(|(pattern, _)| matches_pattern(pattern, &host))
+            .map_or(&ProxyTarget::Direct, |(_, target)| target)
+    }
+
+    /// Gets the HTTP proxy used for every host, if these rules are a single
+    /// HTTP proxy that the HTTP client can use without the router.
+    pub(crate) fn single_http(&self) -> Option<&ProxyServer> {
+        match self.rules.as_slice() {
+            [(pattern, ProxyTarget::Http(server))] if pattern == "*" => Some(server),
+            _ => None,
+        }
+    }
+}
+
+fn parse_target(target: &str) -> Result<ProxyTarget, Box<dyn Error + Send + Sync>> {
+    if target.eq_ignore_ascii_case("DIRECT") {
+        return Ok(ProxyTarget::Direct);
+    }
+    let (scheme, rest) = target
+        .split_once("://")
+        .ok_or_else(|| format!("proxy without scheme: {}", redact(target)))?;
+    let (credentials, addr) = match rest.rsplit_once('@') {
+        Some((userinfo, addr)) => {
+            let (user, password) = userinfo.split_once(':').unwrap_or((userinfo, ""));
+            (Some((user.to_string(), password.to_string())), addr)
+        }
+        None => (None, rest),
+    };
+    let addr = addr.strip_suffix('/').unwrap_or(addr);
+    let (host, port) = addr
+        .rsplit_once(':')
+        .ok_or_else(|| format!("proxy without port: {}", redact(target)))?;
+    let port = match port.parse::<u16>() {
+        Ok(value) if value != 0 && port.bytes().all(|b| b.is_ascii_digit()) => value,
+        _ => return Err(format!("invalid proxy port: {}", redact(target)).into()),
+    };
+    if host.is_empty() || host.contains('/') {
+        return Err(format!("invalid proxy host: {}", redact(target)).into());
+    }
+    let server = ProxyServer {
+        host: host.to_string(),
+        port,
+        credentials,
+    };
+    match scheme.to_ascii_lowercase().as_str() {
+        "http" => Ok(ProxyTarget::Http(server)),
+        "socks5" => Ok(ProxyTarget::Socks5(server)),
+        _ => Err(format!("unsupported proxy scheme: {}", redact(target)).into()),
+    }
+}
+
+/// Hides the credentials of the proxies in `text`, which goes to error
+/// messages and logs.
+fn redact(text: &str) -> String {
+    let mut parts = text.split('@').peekable();
+    let mut redacted = String::with_capacity(text.len());
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() {
+            redacted.push_str(part);
+            break;
+        }
+        // The user information starts after the scheme, or the start of the
+        // rule when the scheme is missing.
+        let start = match part.rfind("://") {
+            Some(index) => index + 3,
+            None => part.rfind([';', '=']).map_or(0, |index| index + 1),
+        };
+        redacted.push_str(&part[..start]);
+        redacted.push_str("***@");
+    }
+    redacted
+}
+
+/// Matches a lowercase host against a lowercase pattern where `*` matches
+/// any sequence of characters.
+fn matches_pattern(pattern: &str, host: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = host.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // No `*` in the pattern.
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod ut_mod {
+    include!("../../../tests/ut/task/proxy/ut_mod.rs");
+}
@@ -0,0 +1,626 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Loopback HTTP proxy applying the proxy rules of tasks.
+//!
+//! The HTTP client only knows static HTTP proxies, so tasks with SOCKS5
+//! proxies or proxy rules use the router as their HTTP proxy instead. The
+//! router looks at the host of every request it relays, `CONNECT` tunnels
+//! and plain HTTP requests alike, and connects to it directly, through an
+//! HTTP proxy or through a SOCKS5 proxy as the rules of the task say. Since
+//! the client sends every redirect hop as a new request, each hop is routed
+//! on its own. Hosts and proxies are resolved with the DNS options of the
+//! task.
+//!
+//! The rules of each task are registered under a random token that the client
+//! sends as its proxy credentials, until the task is dropped; connections
+//! without a known token are refused, so that other processes cannot use the
+//! router as an open proxy. Relayed requests are sent with
+//! `Connection: close`, so a connection to the router never carries requests
+//! to different hosts.
+//!
+//! Connections are relayed by tasks of the runtime. At most
+//! `MAX_CONNECTIONS` are relayed at the same time, a client has
+//! `HEAD_TIMEOUT` to send its request head and a connection without traffic
+//! in either direction for `IDLE_TIMEOUT` is closed.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::future::Future;
+use std::io::{self, Read};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use request_utils::dns::DnsConfig;
+use ylong_runtime::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use ylong_runtime::net::{TcpListener, TcpStream};
+use ylong_runtime::time::timeout;
+
+use super::{socks5, ProxyRules, ProxyServer, ProxyTarget};
+
+/// Time allowed to connect to a host or an upstream proxy, handshake
+/// included.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Time allowed to a client to send the head of its request.
+const HEAD_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Time after which a relayed connection without traffic is closed.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Maximum number of connections relayed at the same time.
+const MAX_CONNECTIONS: usize = 64;
+
+/// Maximum size of the head of a relayed request.
+const MAX_HEAD_SIZE: usize = 64 * 1024;
+
+/// Size of the buffer of each relayed direction.
+const RELAY_BUFFER_SIZE: usize = 16 * 1024;
+
+/// User name of the credentials of the router.
+const ROUTER_USER: &str = "request";
+
+static ROUTER: Mutex<Option<Arc<Router>>> = Mutex::new(None);
+
+/// HTTP proxy to give to the client of a task.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct RouterProxy {
+    /// URL of the router.
+    pub(crate) url: String,
+    /// User name to authenticate to the router.
+    pub(crate) user: String,
+    /// Password to authenticate to the router, the token of the task.
+    pub(crate) password: String,
+}
+
+/// Registers the proxy rules of a task with the router, starting it if
+/// needed.
+///
+/// A task registered again keeps its token, every registration is released
+/// with `unroute`.
+///
+/// # Errors
+///
+/// Returns an error if the router cannot be started.
+pub(crate) fn route(task_id: u32, rules: ProxyRules, dns: DnsConfig) -> io::Result<RouterProxy> {
+    let router = router()?;
+    let mut routes = router.routes.lock().unwrap();
+    let token = match routes.tasks.get_mut(&task_id) {
+        Some(route) => {
+            route.users += 1;
+            route.token.clone()
+        }
+        None => {
+            let token = random_token()?;
+            let credentials = basic_credentials(ROUTER_USER, &token);
+            routes
+                .rules
+                .insert(credentials, Arc::new(TaskRules { rules, dns }));
+            routes.tasks.insert(
+                task_id,
+                TaskRoute {
+                    token: token.clone(),
+                    users: 1,
+                },
+            );
+            token
+        }
+    };
+    Ok(RouterProxy {
+        url: format!("http://127.0.0.1:{}", router.port),
+        user: ROUTER_USER.to_string(),
+        password: token,
+    })
+}
+
+/// Releases a registration of the proxy rules of a task, forgetting its token
+/// with the last one. Connections already relayed go on.
+pub(crate) fn unroute(task_id: u32) {
+    let Some(router) = ROUTER.lock().unwrap().clone() else {
+        return;
+    };
+    let mut routes = router.routes.lock().unwrap();
+    let Some(route) = routes.tasks.get_mut(&task_id) else {
+        return;
+    };
+    route.users -= 1;
+    if route.users == 0 {
+        let token = route.token.clone();
+        routes.tasks.remove(&task_id);
+        routes.rules.remove(&basic_credentials(ROUTER_USER, &token));
+    }
+}
+
+#[derive(Default)]
+struct Routes {
+    /// Route of each task.
+    tasks: HashMap<u32, TaskRoute>,
+    /// Rules by the `Proxy-Authorization` value of their token.
+    rules: HashMap<String, Arc<TaskRules>>,
+}
+
+struct TaskRoute {
+    token: String,
+    /// Number of registrations not released yet.
+    users: usize,
+}
+
+struct TaskRules {
+    rules: ProxyRules,
+    dns: DnsConfig,
+}
+
+struct Router {
+    port: u16,
+    routes: Mutex<Routes>,
+    connections: AtomicUsize,
+}
+
+fn router() -> io::Result<Arc<Router>> {
+    let mut router = ROUTER.lock().unwrap();
+    if let Some(router) = router.as_ref() {
+        return Ok(router.clone());
+    }
+    let listener = std::net::TcpListener::bind(("127.0.0.1", 0))?;
+    listener.set_nonblocking(true)?;
+    let started = Arc::new(Router {
+        port: listener.local_addr()?.port(),
+        routes: Mutex::new(Routes::default()),
+        connections: AtomicUsize::new(0),
+    });
+    let listener = TcpListener::from_std(listener)?;
+    ylong_runtime::spawn(started.clone().run(listener));
+    info!("proxy router listens on port {}", started.port);
+    *router = Some(started.clone());
+    Ok(started)
+}
+
+/// Slot of a relayed connection, released when dropped.
+struct ConnectionSlot(Arc<Router>);
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.connections.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+impl Router {
+    async fn run(self: Arc<Self>, listener: TcpListener) {
+        loop {
+            let stream = match listener.accept().await {
+                Ok((stream, _)) => stream,
+                Err(e) => {
+                    error!("proxy router accept failed {:?}", e);
+                    continue;
+                }
+            };
+            if self.connections.fetch_add(1, Ordering::AcqRel) >= MAX_CONNECTIONS {
+                self.connections.fetch_sub(1, Ordering::AcqRel);
+                error!("proxy router refuses a connection, too many connections");
+                continue;
+            }
+            let slot = ConnectionSlot(self.clone());
+            ylong_runtime::spawn(async move {
+                if let Err(e) = slot.0.relay(stream).await {
+                    info!("proxy router relay ends {:?}", e);
+                }
+                drop(slot);
+            });
+        }
+    }
+
+    fn rules(&self, head: &RequestHead) -> Option<Arc<TaskRules>> {
+        let credentials = head.header("Proxy-Authorization")?;
+        self.routes.lock().unwrap().rules.get(credentials).cloned()
+    }
+
+    async fn relay(&self, mut client: TcpStream) -> io::Result<()> {
+        let (head, rest) = match timeout(HEAD_TIMEOUT, RequestHead::read(&mut client)).await {
+            Ok(read) => read?,
+            Err(_) => return reply(&mut client, "408 Request Timeout", "").await,
+        };
+        let Some(task) = self.rules(&head) else {
+            return reply(
+                &mut client,
+                "407 Proxy Authentication Required",
+                "Proxy-Authenticate: Basic realm=\"request\"\r\n",
+            )
+            .await;
+        };
+        let dns = &task.dns;
+
+        if head.method.eq_ignore_ascii_case("CONNECT") {
+            let Some((host, port)) = split_host_port(&head.target, 443) else {
+                return reply(&mut client, "400 Bad Request", "").await;
+            };
+            let upstream = match task.rules.select(&host) {
+                ProxyTarget::Direct => within(CONNECT_TIMEOUT, tcp_connect(&host, port, dns)).await,
+                ProxyTarget::Socks5(server) => {
+                    within(CONNECT_TIMEOUT, socks5::connect(server, &host, port, dns)).await
+                }
+                ProxyTarget::Http(server) => {
+                    within(CONNECT_TIMEOUT, http_tunnel(server, &host, port, dns)).await
+                }
+            };
+            let upstream = match upstream {
+                Ok(upstream) => upstream,
+                Err(e) => {
+                    error!("proxy router connects {} failed {:?}", host, e);
+                    return reply(&mut client, "502 Bad Gateway", "").await;
+                }
+            };
+            client
+                .write_all(b"HTTP/1.1 200 Connection Established\r\n\r\n")
+                .await?;
+            return pump(client, upstream, rest).await;
+        }
+
+        let Some((host, port, path)) = split_absolute_url(&head.target) else {
+            return reply(&mut client, "400 Bad Request", "").await;
+        };
+        let (upstream, request) = match task.rules.select(&host) {
+            ProxyTarget::Direct => (
+                within(CONNECT_TIMEOUT, tcp_connect(&host, port, dns)).await,
+                head.forward(&path, None),
+            ),
+            ProxyTarget::Socks5(server) => (
+                within(CONNECT_TIMEOUT, socks5::connect(server, &host, port, dns)).await,
+                head.forward(&path, None),
+            ),
+            ProxyTarget::Http(server) => (
+                within(CONNECT_TIMEOUT, tcp_connect(&server.host, server.port, dns)).await,
+                head.forward(&head.target, Some(server)),
+            ),
+        };
+        let mut upstream = match upstream {
+            Ok(upstream) => upstream,
+            Err(e) => {
+                error!("proxy router connects {} failed {:?}", host, e);
+                return reply(&mut client, "502 Bad Gateway", "").await;
+            }
+        };
+        upstream.write_all(request.as_bytes()).await?;
+        pump(client, upstream, rest).await
+    }
+}
+
+/// Request line and headers of a request to the router.
+struct RequestHead {
+    method: String,
+    target: String,
+    version: String,
+    headers: Vec<(String, String)>,
+}
+
+impl RequestHead {
+    /// Reads the head of a request, returning it with the bytes read after
+    /// it.
+    async fn read(stream: &mut TcpStream) -> io::Result<(Self, Vec<u8>)> {
+        let mut buf = Vec::new();
+        let mut chunk = [0u8; 4096];
+        loop {
+            let read = stream.read(&mut chunk).await?;
+            if read == 0 {
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+            }
+            // The end of the head may straddle two reads.
+            let from = buf.len().saturating_sub(3);
+            buf.extend_from_slice(&chunk[..read]);
+            if let Some(index) = find(&buf[from..], b"\r\n\r\n") {
+                let end = from + index + 4;
+                let rest = buf.split_off(end);
+                return Ok((Self::parse(&buf)?, rest));
+            }
+            if buf.len() > MAX_HEAD_SIZE {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "request head too large",
+                ));
+            }
+        }
+    }
+
+    fn parse(head: &[u8]) -> io::Result<Self> {
+        let head = String::from_utf8_lossy(head);
+        let mut lines = head.split("\r\n");
+        let mut parts = lines.next().unwrap_or_default().split_whitespace();
+        let (Some(method), Some(target), Some(version)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid request line",
+            ));
+        };
+        let headers = lines
+            .take_while(|line| !line.is_empty())
+            .filter_map(|line| line.split_once(':'))
+            .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+            .collect();
+        Ok(Self {
+            method: method.to_string(),
+            target: target.to_string(),
+            version: version.to_string(),
+            headers,
+        })
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Builds the head sent upstream: `target` as the request target, without
+    /// the headers meant for the router and with the credentials of the
+    /// upstream HTTP proxy, if any.
+    fn forward(&self, target: &str, proxy: Option<&ProxyServer>) -> String {
+        let mut head = format!("{} {} {}\r\n", self.method, target, self.version);
+        for (key, value) in self.headers.iter() {
+            if ["Proxy-Authorization", "Proxy-Connection", "Connection"]
+                .iter()
+                .any(|name| key.eq_ignore_ascii_case(name))
+            {
+                continue;
+            }
+            head.push_str(&format!("{}: {}\r\n", key, value));
+        }
+        if let Some((user, password)) = proxy.and_then(|proxy| proxy.credentials.as_ref()) {
+            head.push_str(&format!(
+                "Proxy-Authorization: {}\r\n",
+                basic_credentials(user, password)
+            ));
+        }
+        head.push_str("Connection: close\r\n\r\n");
+        head
+    }
+}
+
+/// Opens a tunnel to `host:port` through an HTTP proxy.
+async fn http_tunnel(
+    proxy: &ProxyServer,
+    host: &str,
+    port: u16,
+    dns: &DnsConfig,
+) -> io::Result<TcpStream> {
+    let mut upstream = tcp_connect(&proxy.host, proxy.port, dns).await?;
+    let mut request = format!(
+        "CONNECT {host}:{port} HTTP/1.1\r\nHost: {host}:{port}\r\n",
+        host = host,
+        port = port
+    );
+    if let Some((user, password)) = proxy.credentials.as_ref() {
+        request.push_str(&format!(
+            "Proxy-Authorization: {}\r\n",
+            basic_credentials(user, password)
+        ));
+    }
+    request.push_str("\r\n");
+    upstream.write_all(request.as_bytes()).await?;
+
+    // Reads the response byte by byte, so that no byte of the tunnel is lost.
+    let mut response = Vec::new();
+    let mut byte = [0u8; 1];
+    while !response.ends_with(b"\r\n\r\n") {
+        if upstream.read(&mut byte).await? == 0 || response.len() > MAX_HEAD_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid proxy response",
+            ));
+        }
+        response.push(byte[0]);
+    }
+    let status = String::from_utf8_lossy(&response);
+    match status.split_whitespace().nth(1) {
+        Some("200") => Ok(upstream),
+        code => Err(io::Error::other(format!(
+            "proxy refuses the tunnel: {:?}",
+            code
+        ))),
+    }
+}
+
+/// Copies the bytes of both directions, starting with `rest` sent by the
+/// client after its request head, until one side closes or the connection
+/// is idle for `IDLE_TIMEOUT`.
+async fn pump(client: TcpStream, upstream: TcpStream, rest: Vec<u8>) -> io::Result<()> {
+    let (mut client_read, mut client_write) = client.into_split();
+    let (mut upstream_read, mut upstream_write) = upstream.into_split();
+    upstream_write.write_all(&rest).await?;
+
+    let activity = Arc::new(Activity::new());
+    let to_upstream = {
+        let activity = activity.clone();
+        ylong_runtime::spawn(async move {
+            let _ = copy(&mut client_read, &mut upstream_write, &activity).await;
+            let _ = upstream_write.shutdown().await;
+        })
+    };
+    let _ = copy(&mut upstream_read, &mut client_write, &activity).await;
+    let _ = client_write.shutdown().await;
+    // The client may keep its side open, the upstream side is done.
+    to_upstream.cancel();
+    Ok(())
+}
+
+/// Last time bytes went through a relayed connection.
+struct Activity {
+    start: Instant,
+    last: AtomicU64,
+}
+
+impl Activity {
+    fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            last: AtomicU64::new(0),
+        }
+    }
+
+    fn touch(&self) {
+        let now = self.start.elapsed().as_millis() as u64;
+        self.last.store(now, Ordering::Release);
+    }
+
+    fn idle(&self) -> Duration {
+        let last = Duration::from_millis(self.last.load(Ordering::Acquire));
+        self.start.elapsed().saturating_sub(last)
+    }
+}
+
+/// Copies one direction of a relayed connection until its end, or until
+/// neither direction had traffic for `IDLE_TIMEOUT`.
+async fn copy<R, W>(reader: &mut R, writer: &mut W, activity: &Activity) -> io::Result<()>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let mut buf = vec![0u8; RELAY_BUFFER_SIZE];
+    loop {
+        let read = match timeout(IDLE_TIMEOUT, reader.read(&mut buf)).await {
+            Ok(read) => read?,
+            // The other direction may have been busy meanwhile.
+            Err(_) if activity.idle() < IDLE_TIMEOUT => continue,
+            Err(_) => return Err(io::Error::from(io::ErrorKind::TimedOut)),
+        };
+        if read == 0 {
+            return Ok(());
+        }
+        writer.write_all(&buf[..read]).await?;
+        activity.touch();
+    }
+}
+
+async fn reply(stream: &mut TcpStream, status: &str, headers: &str) -> io::Result<()> {
+    let response = format!(
+        "HTTP/1.1 {}\r\n{}Content-Length: 0\r\nConnection: close\r\n\r\n",
+        status, headers
+    );
+    stream.write_all(response.as_bytes()).await
+}
+
+/// Runs `fut` for at most `duration`.
+async fn within<T, F>(duration: Duration, fut: F) -> io::Result<T>
+where
+    F: Future<Output = io::Result<T>>,
+{
+    timeout(duration, fut)
+        .await
+        .map_err(|_| io::Error::from(io::ErrorKind::TimedOut))?
+}
+
+/// Connects to `host:port` resolved with `dns`, trying every address in
+/// order.
+pub(crate) async fn tcp_connect(host: &str, port: u16, dns: &DnsConfig) -> io::Result<TcpStream> {
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    let addrs = resolve(host, port, dns).await?;
+    let mut last_error = io::Error::new(io::ErrorKind::NotFound, "host not resolved");
+    for addr in addrs {
+        match TcpStream::connect(addr).await {
+            Ok(stream) => return Ok(stream),
+            Err(e) => last_error = e,
+        }
+    }
+    Err(last_error)
+}
+
+async fn resolve(host: &str, port: u16, dns: &DnsConfig) -> io::Result<Vec<SocketAddr>> {
+    if let Ok(ip) = host.parse() {
+        return Ok(vec![SocketAddr::new(ip, port)]);
+    }
+    let dns = dns.clone();
+    let host = host.to_string();
+    ylong_runtime::spawn_blocking(move || dns.resolve(&host, port))
+        .await
+        .map_err(|e| io::Error::other(e.to_string()))?
+}
+
+/// Splits `host:port`, or a bare host with the default port.
+fn split_host_port(authority: &str, default_port: u16) -> Option<(String, u16)> {
+    let (host, port) = match authority.rsplit_once(':') {
+        // A bare IPv6 address has colons but no port.
+        Some((host, port)) if !port.contains(']') => (host, port.parse().ok()?),
+        _ => (authority, default_port),
+    };
+    if host.is_empty() {
+        return None;
+    }
+    Some((
+        host.trim_start_matches('[')
+            .trim_end_matches(']')
+            .to_string(),
+        port,
+    ))
+}
+
+/// Splits an absolute `http` URL into its host, port and origin-form target.
+fn split_absolute_url(url: &str) -> Option<(String, u16, String)> {
+    let rest = url
+        .get(..7)
+        .filter(|scheme| scheme.eq_ignore_ascii_case("http://"))
+        .map(|_| &url[7..])?;
+    let (authority, path) = match rest.find(['/', '?']) {
+        Some(index) => (&rest[..index], rest[index..].to_string()),
+        None => (rest, "/".to_string()),
+    };
+    let authority = authority.rsplit('@').next()?;
+    let (host, port) = split_host_port(authority, 80)?;
+    let path = match path.starts_with('?') {
+        true => format!("/{}", path),
+        false => path,
+    };
+    Some((host, port, path))
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn random_token() -> io::Result<String> {
+    let mut bytes = [0u8; 16];
+    File::open("/dev/urandom")?.read_exact(&mut bytes)?;
+    Ok(bytes.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+/// Builds the value of a basic `Proxy-Authorization` header.
+fn basic_credentials(user: &str, password: &str) -> String {
+    const TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let input = format!("{}:{}", user, password).into_bytes();
+    let mut output = String::from("Basic ");
+    for chunk in input.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (bytes[0] as u32) << 16 | (bytes[1] as u32) << 8 | bytes[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                output.push(TABLE[(n >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else {
+                output.push('=');
+            }
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod ut_router {
+    include!("../../../tests/ut/task/proxy/ut_router.rs");
+}
@@ -0,0 +1,147 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SOCKS5 client (RFC 1928), with user name and password authentication
+//! (RFC 1929).
+
+use std::io;
+use std::net::IpAddr;
+
+use request_utils::dns::DnsConfig;
+use ylong_runtime::io::{AsyncReadExt, AsyncWriteExt};
+use ylong_runtime::net::TcpStream;
+
+use super::router::tcp_connect;
+use super::ProxyServer;
+
+const VERSION: u8 = 0x05;
+const NO_AUTH: u8 = 0x00;
+const USER_PASSWORD: u8 = 0x02;
+const NO_ACCEPTABLE_METHOD: u8 = 0xFF;
+const CONNECT: u8 = 0x01;
+const ATYP_IPV4: u8 = 0x01;
+const ATYP_DOMAIN: u8 = 0x03;
+const ATYP_IPV6: u8 = 0x04;
+
+/// Opens a connection to `host:port` through a SOCKS5 proxy.
+///
+/// The host name is resolved by the proxy, so that hosts only known inside
+/// the network of the proxy can be reached. The proxy itself is resolved with
+/// `dns`. The caller bounds the time of the handshake.
+pub(crate) async fn connect(
+    proxy: &ProxyServer,
+    host: &str,
+    port: u16,
+    dns: &DnsConfig,
+) -> io::Result<TcpStream> {
+    let mut stream = tcp_connect(&proxy.host, proxy.port, dns).await?;
+
+    let method = match proxy.credentials {
+        Some(_) => USER_PASSWORD,
+        None => NO_AUTH,
+    };
+    stream.write_all(&[VERSION, 1, method]).await?;
+    let mut reply = [0u8; 2];
+    stream.read_exact(&mut reply).await?;
+    if reply[0] != VERSION || reply[1] == NO_ACCEPTABLE_METHOD || reply[1] != method {
+        return Err(other("socks5 proxy refuses the authentication method"));
+    }
+    if let Some((user, password)) = proxy.credentials.as_ref() {
+        authenticate(&mut stream, user, password).await?;
+    }
+
+    let mut request = vec![VERSION, CONNECT, 0];
+    match host
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<IpAddr>()
+    {
+        Ok(IpAddr::V4(ip)) => {
+            request.push(ATYP_IPV4);
+            request.extend_from_slice(&ip.octets());
+        }
+        Ok(IpAddr::V6(ip)) => {
+            request.push(ATYP_IPV6);
+            request.extend_from_slice(&ip.octets());
+        }
+        Err(_) => {
+            let len = u8::try_from(host.len()).map_err(|_| other("host name too long"))?;
+            request.push(ATYP_DOMAIN);
+            request.push(len);
+            request.extend_from_slice(host.as_bytes());
+        }
+    }
+    request.extend_from_slice(&port.to_be_bytes());
+    stream.write_all(&request).await?;
+
+    let mut head = [0u8; 4];
+    stream.read_exact(&mut head).await?;
+    if head[0] != VERSION {
+        return Err(other("invalid socks5 reply"));
+    }
+    if head[1] != 0 {
+        return Err(other(&format!(
+            "socks5 connect failed: {}",
+            reply_text(head[1])
+        )));
+    }
+    // Skips the bound address and port.
+    let skip = match head[3] {
+        ATYP_IPV4 => 4 + 2,
+        ATYP_IPV6 => 16 + 2,
+        ATYP_DOMAIN => {
+            let mut len = [0u8; 1];
+            stream.read_exact(&mut len).await?;
+            len[0] as usize + 2
+        }
+        _ => return Err(other("invalid socks5 bound address")),
+    };
+    stream.read_exact(&mut vec![0u8; skip]).await?;
+    Ok(stream)
+}
+
+async fn authenticate(stream: &mut TcpStream, user: &str, password: &str) -> io::Result<()> {
+    let user_len = u8::try_from(user.len()).map_err(|_| other("socks5 user too long"))?;
+    let password_len =
+        u8::try_from(password.len()).map_err(|_| other("socks5 password too long"))?;
+    let mut request = vec![0x01, user_len];
+    request.extend_from_slice(user.as_bytes());
+    request.push(password_len);
+    request.extend_from_slice(password.as_bytes());
+    stream.write_all(&request).await?;
+
+    let mut reply = [0u8; 2];
+    stream.read_exact(&mut reply).await?;
+    match reply[1] {
+        0 => Ok(()),
+        _ => Err(other("socks5 authentication failed")),
+    }
+}
+
+fn reply_text(code: u8) -> &'static str {
+    match code {
+        0x01 => "general failure",
+        0x02 => "connection not allowed by ruleset",
+        0x03 => "network unreachable",
+        0x04 => "host unreachable",
+        0x05 => "connection refused",
+        0x06 => "TTL expired",
+        0x07 => "command not supported",
+        0x08 => "address type not supported",
+        _ => "unknown error",
+    }
+}
+
+fn other(msg: &str) -> io::Error {
+    io::Error::other(msg)
+}
@@ -32,9 +32,11 @@ cfg_oh! {
 }
 
 use super::config::Version;
+use super::{content, dns, proxy};
 use super::info::{CommonTaskInfo, State, TaskInfo, UpdateInfo};
 use super::notify::{EachFileStatus, NotifyData, Progress};
 use super::reason::Reason;
+use crate::database::{file_status, suggested_name};
 use crate::error::ErrorCode;
 use crate::manage::database::RequestDb;
 use crate::manage::network_manager::NetworkManager;
@@ -131,6 +133,10 @@ pub(crate) struct RequestTask {
 
     /// Remaining time until task timeout.
     pub(crate) rest_time: AtomicU64,
+
+    /// Path the downloaded file was moved to under its suggested name, if it
+    /// was moved while the task was loaded.
+    pub(crate) saved_path: Mutex<Option<String>>,
 }
 
 impl RequestTask {
@@ -174,6 +180,22 @@ impl RequestTask {
         }
     }
 
+    /// Returns the bytes the task still has to transfer.
+    ///
+    /// # Returns
+    ///
+    /// The remaining bytes of all files, or `None` if the size of a file is
+    /// unknown.
+    pub(crate) fn remaining_bytes(&self) -> Option<u64> {
+        let progress = self.progress.lock().unwrap();
+        let mut remaining = 0;
+        for (size, processed) in progress.sizes.iter().zip(progress.processed.iter()) {
+            let size = u64::try_from(*size).ok()?;
+            remaining += size.saturating_sub(*processed as u64);
+        }
+        Some(remaining)
+    }
+
     /// Attempts to retry the task after a network error.
     ///
     /// # Returns
@@ -301,6 +323,7 @@ impl RequestTask {
             start_time: AtomicU64::new(get_current_duration().as_secs()),
             task_time: AtomicU64::new(0),
             rest_time: AtomicU64::new(rest_time),
+            saved_path: Mutex::new(None),
         }
     }
 
@@ -386,6 +409,7 @@ impl RequestTask {
             start_time: AtomicU64::new(get_current_duration().as_secs()),
             task_time: AtomicU64::new(info.task_time),
             rest_time: AtomicU64::new(rest_time),
+            saved_path: Mutex::new(None),
         };
         let background_notify = NotificationDispatcher::get_instance().register_task(&task);
         task.background_notify = background_notify;
@@ -607,7 +631,7 @@ impl RequestTask {
     /// Extracts file information from an HTTP response.
     ///
     /// This method updates the task's MIME type and file size based on response
-    /// headers.
+    /// headers, and records the file name suggested by `Content-Disposition`.
     ///
     /// # Arguments
     ///
@@ -626,6 +650,15 @@ impl RequestTask {
             }
         }
 
+        let disposition = response.headers().get("content-disposition");
+        if let Some(Ok(value)) = disposition.map(|v| v.to_string()) {
+            if let Some(name) = content::content_disposition_file_name(&value) {
+                if let Err(e) = suggested_name::set_name(self.task_id(), &name) {
+                    error!("task {} record suggested name failed {}", self.task_id(), e);
+                }
+            }
+        }
+
         let content_length = response.headers().get("content-length");
         if let Some(Ok(len)) = content_length.map(|v| v.to_string()) {
             match len.parse::<i64>() {
@@ -791,6 +824,9 @@ impl RequestTask {
                         guard.extras.insert(k.to_string().to_lowercase(), value);
                     }
                 }
+                if let Some(addrs) = dns::take_resolved(self.task_id()) {
+                    guard.extras.insert(dns::RESOLVED_ADDRESS_KEY.to_string(), addrs);
+                }
             }
 
             let file = match self.body_files.get(index) {
@@ -816,6 +852,20 @@ impl RequestTask {
         }
     }
 
+    /// Gets the file specifications of the task, with the path the file was
+    /// saved at if it was moved under its suggested name.
+    pub(crate) fn file_specs(&self) -> Vec<FileSpec> {
+        let mut file_specs = self.conf.file_specs.clone();
+        if let (Some(path), Some(spec)) = (
+            self.saved_path.lock().unwrap().as_ref(),
+            file_specs.first_mut(),
+        ) {
+            spec.file_name = path.rsplit('/').next().unwrap_or(path).to_string();
+            spec.path = path.clone();
+        }
+        file_specs
+    }
+
     /// Gets the status of each file in the task.
     ///
     /// # Returns
@@ -826,7 +876,7 @@ impl RequestTask {
         let mut vec = Vec::new();
         // `unwrap` for propagating panics among threads.
         let codes_guard = self.code.lock().unwrap();
-        for (i, file_spec) in self.conf.file_specs.iter().enumerate() {
+        for (i, file_spec) in self.file_specs().iter().enumerate() {
             let reason = *codes_guard.get(i).unwrap_or(&Reason::Default);
             vec.push(EachFileStatus {
                 path: file_spec.path.clone(),
@@ -837,6 +887,34 @@ impl RequestTask {
         vec
     }
 
+    /// Records the result of the file at `index` of a multi-file upload, in
+    /// memory and in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the file.
+    /// * `reason` - `Reason::Default` if the file succeeded, otherwise the
+    ///   reason it failed with.
+    pub(crate) fn set_file_result(&self, index: usize, reason: Reason) {
+        // `unwrap` for propagating panics among threads.
+        if let Some(code) = self.code.lock().unwrap().get_mut(index) {
+            *code = reason;
+        }
+        if let Err(e) = file_status::record_file_result(
+            self.task_id(),
+            index,
+            self.conf.file_specs.len(),
+            reason,
+        ) {
+            error!(
+                "task {} record result of file {} failed: {}",
+                self.task_id(),
+                index,
+                e
+            );
+        }
+    }
+
     /// Gets the current state of the task as a `TaskInfo` struct.
     ///
     /// # Returns
@@ -852,7 +930,7 @@ impl RequestTask {
             data: self.conf.data.clone(),
             token: self.conf.token.clone(),
             form_items: self.conf.form_items.clone(),
-            file_specs: self.conf.file_specs.clone(),
+            file_specs: self.file_specs(),
             title: self.conf.title.clone(),
             description: self.conf.description.clone(),
             mime_type: {
@@ -900,6 +978,17 @@ impl RequestTask {
     }
 }
 
+impl Drop for RequestTask {
+    /// Releases the proxy route registered for the client of the task and
+    /// the addresses its resolver recorded.
+    fn drop(&mut self) {
+        if !self.conf.proxy.is_empty() {
+            proxy::unroute(self.task_id());
+        }
+        dns::clear_resolved(self.task_id());
+    }
+}
+
 /// Represents the current status of a task.
 #[derive(Clone, Debug)]
 pub(crate) struct TaskStatus {
@@ -1004,10 +1093,17 @@ pub(crate) fn check_config(
     }
     let files = AttachedFiles::open(config).map_err(|_| ErrorCode::FileOperationErr)?;
     #[cfg(feature = "oh")]
-    let client = build_client(config, total_timeout, system).map_err(|_| ErrorCode::Other)?;
+    let client = build_client(config, total_timeout, system);
 
     #[cfg(not(feature = "oh"))]
-    let client = build_client(config, total_timeout).map_err(|_| ErrorCode::Other)?;
+    let client = build_client(config, total_timeout);
+    let client = client.map_err(|_| {
+        // The task is not created, so its proxy route is never released.
+        if !config.proxy.is_empty() {
+            proxy::unroute(config.common_data.task_id);
+        }
+        ErrorCode::Other
+    })?;
     Ok((files, client))
 }
 
@@ -18,7 +18,7 @@
 //! primarily used for HTTP request tasks.
 
 use std::fs::{File, Metadata};
-use std::io::{self, Seek, SeekFrom, Write};
+use std::io::{self, Read, Seek, SeekFrom, Write};
 use std::sync::{Arc, Mutex};
 
 use ylong_runtime::task::JoinHandle;
@@ -197,6 +197,41 @@ pub(crate) async fn file_write_all<'a>(file: Arc<Mutex<File>>, buf: &[u8]) -> io
     .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
 }
 
+/// Reads exactly `len` bytes starting at `offset` from a file asynchronously.
+///
+/// # Arguments
+///
+/// * `file` - A thread-safe reference to the file.
+/// * `offset` - The position of the first byte to read.
+/// * `len` - The number of bytes to read.
+///
+/// # Returns
+///
+/// The bytes read.
+///
+/// # Errors
+///
+/// Returns an error if the file ends before `len` bytes are read, if the read
+/// operation fails or if the blocking task fails.
+pub(crate) async fn file_read_at(
+    file: Arc<Mutex<File>>,
+    offset: u64,
+    len: u64,
+) -> io::Result<Vec<u8>> {
+    runtime_spawn_blocking(move || {
+        let mut file = file.lock().unwrap();
+        file.seek(SeekFrom::Start(offset))?;
+        let mut buf = Vec::with_capacity(len as usize);
+        (&mut *file).take(len).read_to_end(&mut buf)?;
+        if (buf.len() as u64) < len {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+        }
+        Ok(buf)
+    })
+    .await
+    .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
+}
+
 /// Clears a downloaded file and resets its progress tracking.
 ///
 /// This function truncates the first file in the task to zero length and resets
@@ -34,7 +34,8 @@ use super::info::State;
 use super::operator::TaskOperator;
 use super::reason::Reason;
 use super::request_task::{TaskError, TaskPhase};
-use super::task_control;
+use super::{chunked, task_control};
+use crate::database::{chunk, file_status};
 use crate::manage::database::RequestDb;
 use crate::task::request_task::RequestTask;
 #[cfg(feature = "oh")]
@@ -664,7 +665,10 @@ async fn upload_inner(
     task.start_time.store(start_time as u64, Ordering::SeqCst);
 
     // Handle different upload modes
-    if task.conf.common_data.multipart {
+    if let Some(config) = chunk::chunk_config(task.task_id()) {
+        // Chunked upload of a single large file
+        chunked::upload_chunked(task.clone(), config, abort_flag).await?;
+    } else if task.conf.common_data.multipart {
         // Batch multipart upload mode
         #[cfg(feature = "oh")]
         let _trace = Trace::new(&format!("upload file:{} index:{}", task.task_id(), start));
@@ -675,13 +679,26 @@ async fn upload_inner(
         }
 
         // Upload all files in a single multipart request
-        upload_one_file(
+        let res = upload_one_file(
             task.clone(),
             start,
             abort_flag.clone(),
             build_batch_multipart_request,
         )
-        .await?
+        .await;
+
+        // All files of the batch share the result of the request
+        let reason = match &res {
+            Ok(()) => Some(Reason::Default),
+            Err(TaskError::Failed(reason)) => Some(*reason),
+            Err(_) => None,
+        };
+        if let Some(reason) = reason {
+            for index in start..size {
+                task.set_file_result(index, reason);
+            }
+        }
+        res?
     } else {
         // Determine if multipart encoding is needed
         let is_multipart = match task.conf.headers.get("Content-Type") {
@@ -689,23 +706,66 @@ async fn upload_inner(
             None => task.conf.method.to_uppercase().eq("POST"),
         };
 
+        // Files that already failed in an earlier run are uploaded again,
+        // files that already succeeded are skipped.
+        let results = file_status::file_results(task.task_id(), size);
+        let start = match results
+            .iter()
+            .position(|r| matches!(r, Some(reason) if *reason != Reason::Default))
+        {
+            Some(failed) if failed < start => {
+                task.upload_resume.store(false, Ordering::SeqCst);
+                failed
+            }
+            _ => start,
+        };
+        let mut first_error = None;
+
         // Upload files one by one
         for index in start..size {
+            if results[index] == Some(Reason::Default) {
+                continue;
+            }
+
             #[cfg(feature = "oh")]
             let _trace = Trace::new(&format!("upload file:{} index:{}", task.task_id(), index));
 
             // Prepare individual file for upload
-            if !task.prepare_single_upload(index).await {
-                return Err(TaskError::Failed(Reason::OthersError));
+            let res = if task.prepare_single_upload(index).await {
+                // Select appropriate request builder based on content type
+                let func = match is_multipart {
+                    true => build_multipart_request,
+                    false => build_stream_request,
+                };
+                upload_one_file(task.clone(), index, abort_flag.clone(), func).await
+            } else {
+                Err(TaskError::Failed(Reason::OthersError))
+            };
+
+            match res {
+                Ok(()) => {
+                    task.set_file_result(index, Reason::Default);
+                    task.notify_header_receive();
+                }
+                Err(TaskError::Failed(reason)) => {
+                    task.set_file_result(index, reason);
+                    if !file_status::continue_on_file_error(task.task_id()) {
+                        return Err(TaskError::Failed(reason));
+                    }
+                    info!(
+                        "upload task {} file {} failed {:?}, continue with the next file",
+                        task.task_id(),
+                        index,
+                        reason
+                    );
+                    first_error.get_or_insert(reason);
+                }
+                Err(e) => return Err(e),
             }
+        }
 
-            // Select appropriate request builder based on content type
-            let func = match is_multipart {
-                true => build_multipart_request,
-                false => build_stream_request,
-            };
-            upload_one_file(task.clone(), index, abort_flag.clone(), func).await?;
-            task.notify_header_receive();
+        if let Some(reason) = first_error {
+            return Err(TaskError::Failed(reason));
         }
     }
 
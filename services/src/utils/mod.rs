@@ -39,6 +39,8 @@ cfg_oh! {
 
 pub(crate) mod task_event_count;
 pub(crate) mod task_id_generator;
+#[cfg(test)]
+pub(crate) mod virtual_clock;
 use ylong_runtime::sync::oneshot::Receiver;
 use ylong_runtime::task::JoinHandle;
 
@@ -146,6 +148,10 @@ where
 /// assert!(timestamp > 0);
 /// ```
 pub(crate) fn get_current_timestamp() -> u64 {
+    #[cfg(test)]
+    if let Some(now) = virtual_clock::now() {
+        return now;
+    }
     match SystemTime::now().duration_since(UNIX_EPOCH) {
         Ok(n) => n.as_millis() as u64,
         Err(_) => panic!("SystemTime before UNIX EPOCH!"),
@@ -388,6 +394,17 @@ pub(crate) fn is_system_api() -> bool {
     ffi::IsSystemAPI(token_id)
 }
 
+/// Checks if a token ID has system API privileges, for callers outside of an
+/// IPC call such as tasks loaded from the database.
+///
+/// # Availability
+///
+/// This function is only available when the `oh` feature is enabled.
+#[cfg(feature = "oh")]
+pub(crate) fn is_system_token(token_id: u64) -> bool {
+    ffi::IsSystemAPI(token_id)
+}
+
 /// Checks if the calling process has a specific permission.
 ///
 /// This function verifies whether the calling process has been granted a
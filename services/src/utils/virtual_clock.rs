@@ -0,0 +1,38 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Virtual time for tests.
+//!
+//! While a virtual time is set, `get_current_timestamp` returns it instead of
+//! the system time and the state handler leaves its timers to the test. The
+//! virtual time belongs to the thread that sets it: the simulation harness
+//! drives its task manager on the test thread, so tests running in parallel
+//! and the transfers of the simulation keep the system time.
+
+use std::cell::Cell;
+
+thread_local! {
+    /// Virtual time of this thread in milliseconds since UNIX EPOCH.
+    static VIRTUAL_NOW: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// Sets the virtual time of this thread, or goes back to the system time
+/// with `None`.
+pub(crate) fn set(now: Option<u64>) {
+    VIRTUAL_NOW.with(|virtual_now| virtual_now.set(now));
+}
+
+/// Gets the virtual time of this thread if set.
+pub(crate) fn now() -> Option<u64> {
+    VIRTUAL_NOW.with(Cell::get)
+}
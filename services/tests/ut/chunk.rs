@@ -0,0 +1,65 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn config() -> ChunkConfig {
+    ChunkConfig {
+        chunk_size: MIN_CHUNK_SIZE,
+        parallelism: 2,
+        max_retries: 3,
+        url: "https://example.com/file?partNumber={number}".to_string(),
+        method: "put".to_string(),
+        headers: HashMap::new(),
+        finalize_url: String::new(),
+        finalize_method: String::new(),
+        finalize_body: String::new(),
+        finalize_part: String::new(),
+    }
+}
+
+// @tc.name: ut_chunk_config_is_valid
+// @tc.desc: Test validation of chunked upload configurations
+// @tc.precon: NA
+// @tc.step: 1. Validate a configuration within the limits
+//           2. Validate configurations with an invalid chunk size,
+//              parallelism, method or finalize method
+// @tc.expect: Only configurations within the limits are valid
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_chunk_config_is_valid() {
+    assert!(config().is_valid());
+
+    let mut invalid = config();
+    invalid.chunk_size = MIN_CHUNK_SIZE - 1;
+    assert!(!invalid.is_valid());
+    invalid.chunk_size = MAX_CHUNK_SIZE + 1;
+    assert!(!invalid.is_valid());
+
+    let mut invalid = config();
+    invalid.parallelism = 0;
+    assert!(!invalid.is_valid());
+    invalid.parallelism = MAX_CHUNK_PARALLELISM + 1;
+    assert!(!invalid.is_valid());
+
+    let mut invalid = config();
+    invalid.method = "GET".to_string();
+    assert!(!invalid.is_valid());
+
+    let mut finalize = config();
+    finalize.finalize_url = "https://example.com/file".to_string();
+    assert!(!finalize.is_valid());
+    finalize.finalize_method = "POST".to_string();
+    assert!(finalize.is_valid());
+}
@@ -127,5 +127,6 @@ fn ut_db_monitor_result_default() {
     assert!(result.state_distribution.is_empty());
     assert!(result.top_bundles.is_empty());
     assert_eq!(result.max_headers_len, 0);
+    assert_eq!(result.archive_records, 0);
     assert!(!result.size_exceeded);
 }
@@ -0,0 +1,114 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::{IpAddr, Ipv4Addr};
+
+use super::*;
+
+fn options() -> TaskDns {
+    TaskDns {
+        overrides: vec!["cdn.example.com:443:203.0.113.7".to_string()],
+        ip_preference: "ipv4".to_string(),
+    }
+}
+
+// @tc.name: ut_task_dns_is_valid
+// @tc.desc: Test validation of the DNS options of a task
+// @tc.precon: NA
+// @tc.step: 1. Validate options with a pinned address and a preference
+//           2. Validate options with an invalid override, an invalid
+//              preference or too many overrides
+// @tc.expect: Only well-formed options within the limits are valid
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_task_dns_is_valid() {
+    assert!(options().is_valid());
+    assert!(TaskDns::default().is_valid());
+
+    let mut invalid = options();
+    invalid.overrides.push("cdn.example.com:443".to_string());
+    assert!(!invalid.is_valid());
+
+    let mut invalid = options();
+    invalid.ip_preference = "ipv5".to_string();
+    assert!(!invalid.is_valid());
+
+    let mut invalid = options();
+    invalid.overrides = vec![options().overrides[0].clone(); MAX_DNS_OVERRIDES + 1];
+    assert!(!invalid.is_valid());
+}
+
+// @tc.name: ut_task_dns_apply
+// @tc.desc: Test applying the DNS options of a task to those of its app
+// @tc.precon: NA
+// @tc.step: 1. Apply options with a pinned address and a preference to an
+//              app configuration that already pins the same host
+//           2. Apply options without preference
+// @tc.expect: The task override comes first and the preference is only
+//             replaced when the task sets one
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_task_dns_apply() {
+    let mut config = DnsConfig::default();
+    config
+        .overrides
+        .push(HostOverride::parse("cdn.example.com:443:198.51.100.1").unwrap());
+    config.preference = IpPreference::Ipv6First;
+
+    options().apply(&mut config).unwrap();
+    assert_eq!(config.overrides.len(), 2);
+    assert_eq!(
+        config.overrides[0].addrs,
+        vec![IpAddr::V4(Ipv4Addr::new(203, 0, 113, 7))]
+    );
+    assert_eq!(config.preference, IpPreference::Ipv4);
+
+    let mut config = DnsConfig {
+        preference: IpPreference::Ipv6First,
+        ..Default::default()
+    };
+    let mut no_preference = options();
+    no_preference.ip_preference.clear();
+    no_preference.apply(&mut config).unwrap();
+    assert_eq!(config.preference, IpPreference::Ipv6First);
+}
+
+// @tc.name: ut_task_dns_set_and_delete
+// @tc.desc: Test storing the DNS options of a task
+// @tc.precon: NA
+// @tc.step: 1. Set the DNS options of a task and read them back
+//           2. Set empty options
+//           3. Set options again and delete them
+// @tc.expect: The options read back are those set, and none are left after
+//             empty options are set or the options are deleted
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_task_dns_set_and_delete() {
+    let task_id = 0x7fff_0040;
+    let mut dns = options();
+    dns.overrides
+        .push("*:80:[2001:db8::7],203.0.113.8".to_string());
+
+    set_task_dns(task_id, &dns).unwrap();
+    assert_eq!(task_dns(task_id), Some(dns.clone()));
+
+    set_task_dns(task_id, &TaskDns::default()).unwrap();
+    assert_eq!(task_dns(task_id), None);
+
+    set_task_dns(task_id, &dns).unwrap();
+    delete_task_dns(&REQUEST_DB, task_id).unwrap();
+    assert_eq!(task_dns(task_id), None);
+}
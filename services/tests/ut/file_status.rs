@@ -0,0 +1,55 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+// @tc.name: ut_file_results_encode_decode
+// @tc.desc: Test encoding and decoding of per-file upload results
+// @tc.precon: NA
+// @tc.step: 1. Encode results with succeeded, failed and pending files
+//           2. Decode the encoded bytes
+// @tc.expect: The decoded results equal the original ones
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_file_results_encode_decode() {
+    let results = vec![
+        Some(Reason::Default),
+        Some(Reason::ProtocolError),
+        None,
+        Some(Reason::IoError),
+    ];
+    let blob = encode_file_results(&results);
+    assert_eq!(blob.len(), 4);
+    assert_eq!(blob[2], FILE_PENDING);
+    assert_eq!(decode_file_results(&blob, 4), results);
+}
+
+// @tc.name: ut_file_results_decode_length
+// @tc.desc: Test decoding per-file results whose length differs from the
+//           file count
+// @tc.precon: NA
+// @tc.step: 1. Decode an empty blob
+//           2. Decode a blob longer than the file count
+// @tc.expect: Missing files are pending and extra bytes are ignored
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_file_results_decode_length() {
+    assert_eq!(decode_file_results(&[], 2), vec![None, None]);
+    let blob = [Reason::Default.repr, Reason::Default.repr, Reason::IoError.repr];
+    assert_eq!(
+        decode_file_results(&blob, 2),
+        vec![Some(Reason::Default), Some(Reason::Default)]
+    );
+}
@@ -25,6 +25,7 @@ impl Task {
             task_id,
             mode,
             priority,
+            remaining: None,
         }
     }
 }
@@ -120,6 +121,70 @@ fn ut_app_remove() {
     assert_eq!(app.tasks[0].task_id, 2);
 }
 
+// @tc.name: ut_app_set_priority
+// @tc.desc: Test changing the priority of a task in App
+// @tc.precon: NA
+// @tc.step: 1. Create App instance with tasks of increasing priority
+//           2. Change the priority of the last task to the lowest value
+//           3. Change the priority of an unknown task
+// @tc.expect: The task list is re-sorted and unknown tasks are reported
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_app_set_priority() {
+    let mut app = App::new(1);
+    for i in 0..3 {
+        app.insert(Task::new(i, Mode::BackGround, i + 1));
+    }
+    app.insert(Task::new(3, Mode::FrontEnd, 10));
+
+    assert!(app.task_set_priority(2, 0));
+    assert_eq!(app.tasks[0].task_id, 3);
+    assert_eq!(app.tasks[1].task_id, 2);
+    assert_eq!(app.tasks[2].task_id, 0);
+    assert_eq!(app.tasks[3].task_id, 1);
+
+    assert!(!app.task_set_priority(100, 0));
+}
+
+// @tc.name: ut_app_move_to_front
+// @tc.desc: Test moving a task to the front of its mode in App
+// @tc.precon: NA
+// @tc.step: 1. Move a task when a lower priority value is free
+//           2. Move a task when priority 0 is taken
+//           3. Move an unknown task
+// @tc.expect: The task runs first within its mode, shifted tasks keep their
+//             order and every changed priority is reported
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_app_move_to_front() {
+    let mut app = App::new(1);
+    for i in 0..3 {
+        app.insert(Task::new(i, Mode::BackGround, i + 1));
+    }
+    app.insert(Task::new(3, Mode::FrontEnd, 10));
+
+    assert_eq!(app.move_to_front(2), Some(vec![(2, 0)]));
+    assert_eq!(app.tasks[0].task_id, 3);
+    assert_eq!(app.tasks[1].task_id, 2);
+    assert_eq!(app.tasks[2].task_id, 0);
+    assert_eq!(app.tasks[3].task_id, 1);
+
+    let mut changed = app.move_to_front(1).unwrap();
+    changed.sort();
+    assert_eq!(changed, vec![(0, 2), (1, 0), (2, 1)]);
+    assert_eq!(app.tasks[0].task_id, 3);
+    assert_eq!(app.tasks[1].task_id, 1);
+    assert_eq!(app.tasks[2].task_id, 2);
+    assert_eq!(app.tasks[3].task_id, 0);
+    assert_eq!(app.tasks[3].priority, 2);
+
+    assert_eq!(app.move_to_front(3), Some(vec![]));
+    assert_eq!(app.move_to_front(1), Some(vec![]));
+    assert_eq!(app.move_to_front(100), None);
+}
+
 // @tc.name: ut_task_partial_ord
 // @tc.desc: Test task ordering based on priority and mode
 // @tc.precon: NA
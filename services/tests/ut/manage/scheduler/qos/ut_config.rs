@@ -0,0 +1,109 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+// @tc.name: ut_qos_config_default
+// @tc.desc: Test the QoS configuration without a configuration file
+// @tc.precon: NA
+// @tc.step: 1. Parse an empty configuration
+// @tc.expect: The tiered policy and the built-in capacities are used
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_qos_config_default() {
+    let config = QosConfig::parse("");
+    assert_eq!(config.policy, PolicyKind::Tiered);
+    assert_eq!(config.capacity(0), RssCapacity::LEVEL0);
+    assert_eq!(config.capacity(6), RssCapacity::LEVEL6);
+    assert_eq!(config.capacity(7), RssCapacity::LEVEL7);
+    assert_eq!(config.capacity(-1), RssCapacity::LEVEL7);
+    assert_eq!(config.capacity(100), RssCapacity::LEVEL7);
+}
+
+// @tc.name: ut_qos_config_parse
+// @tc.desc: Test parsing a QoS configuration file
+// @tc.precon: NA
+// @tc.step: 1. Parse a configuration with a policy, a capacity, weights,
+//              comments and invalid lines
+// @tc.expect: Valid lines are applied and invalid lines keep the defaults
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_qos_config_parse() {
+    let config = QosConfig::parse(
+        "# QoS policy\n\
+         policy = weighted_fair\n\
+         policy = fastest\n\
+         level3 = 2 4 1 high low low # under pressure\n\
+         level4 = 2 4 high low low\n\
+         level8 = 2 4 1 high low low\n\
+         default_weight = 2\n\
+         weight.20010042 = 5\n\
+         weight.app = 5\n\
+         unknown\n",
+    );
+    assert_eq!(config.policy, PolicyKind::WeightedFair);
+    assert_eq!(
+        config.capacity(3),
+        RssCapacity(2, 4, 1, QosLevel::High, QosLevel::Low, QosLevel::Low)
+    );
+    assert_eq!(config.capacity(4), RssCapacity::LEVEL4);
+    assert_eq!(config.capacity(7), RssCapacity::LEVEL7);
+    assert_eq!(config.default_weight, 2);
+    assert_eq!(config.weights, HashMap::from([(20010042, 5)]));
+}
+
+// @tc.name: ut_qos_config_quota
+// @tc.desc: Test parsing the quotas of a QoS configuration file
+// @tc.precon: NA
+// @tc.step: 1. Parse a configuration with default, system and uid quotas and
+//              invalid quota lines
+//           2. Get the quota of a normal, a system and a specific app
+// @tc.expect: Specific quotas win over system quotas, which win over the
+// default quota
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_qos_config_quota() {
+    let config = QosConfig::parse("");
+    assert_eq!(config.quota(1, false), Quota::UNLIMITED);
+    assert_eq!(config.quota(1, true), Quota::UNLIMITED);
+
+    let config = QosConfig::parse(
+        "quota = 2 100 unlimited\n\
+         system_quota = 8 unlimited 1000\n\
+         quota.20010042 = 1 10 500\n\
+         quota.20010043 = 1 10\n\
+         quota = many 100 1000\n",
+    );
+    let quota = Quota {
+        running: 2,
+        queued: 100,
+        bytes: u64::MAX,
+    };
+    assert_eq!(config.quota(1, false), quota);
+    let quota = Quota {
+        running: 8,
+        queued: usize::MAX,
+        bytes: 1000,
+    };
+    assert_eq!(config.quota(1, true), quota);
+    let quota = Quota {
+        running: 1,
+        queued: 10,
+        bytes: 500,
+    };
+    assert_eq!(config.quota(20010042, true), quota);
+    assert_eq!(config.quota(20010043, false).running, 2);
+}
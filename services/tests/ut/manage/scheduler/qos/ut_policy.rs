@@ -0,0 +1,157 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::super::apps::SortedApps;
+use super::super::QosLevel;
+use super::*;
+use crate::manage::database::TaskQosInfo;
+
+const CAPACITY: RssCapacity = RssCapacity(1, 1, 2, QosLevel::High, QosLevel::Middle, QosLevel::Low);
+
+fn insert(apps: &mut SortedApps, uid: u64, task_id: u32, action: Action, priority: u32) {
+    apps.insert_task(
+        uid,
+        TaskQosInfo {
+            task_id,
+            action: action.repr,
+            mode: 0,
+            state: 0,
+            priority,
+        },
+    );
+}
+
+fn levels(directions: Vec<QosDirection>) -> Vec<(u32, QosLevel)> {
+    directions
+        .iter()
+        .map(|direction| (direction.task_id(), direction.direction()))
+        .collect()
+}
+
+// @tc.name: ut_policy_tiered
+// @tc.desc: Test the historical tiered scheduling policy
+// @tc.precon: NA
+// @tc.step: 1. Add three tasks of one app and one task of two other apps
+//           2. Schedule the downloads with TieredPolicy
+// @tc.expect: M1 and M2 go to the first app in order, M3 round robin to the
+// other apps
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_policy_tiered() {
+    let mut apps = SortedApps::new();
+    for task_id in 1..=3 {
+        insert(&mut apps, 1, task_id, Action::Download, task_id);
+    }
+    insert(&mut apps, 2, 4, Action::Download, 4);
+    insert(&mut apps, 3, 5, Action::Download, 5);
+    insert(&mut apps, 3, 6, Action::Upload, 6);
+
+    let directions = TieredPolicy.schedule(&apps, &CAPACITY, Action::Download);
+    assert_eq!(
+        levels(directions),
+        vec![
+            (1, QosLevel::High),
+            (2, QosLevel::Middle),
+            (4, QosLevel::Low),
+            (5, QosLevel::Low),
+        ]
+    );
+}
+
+// @tc.name: ut_policy_weighted_fair
+// @tc.desc: Test weighted fair queuing by uid
+// @tc.precon: NA
+// @tc.step: 1. Add tasks of three apps, one with weight 3
+//           2. Schedule the downloads with WeightedFairPolicy
+// @tc.expect: Each app takes as many tasks per turn as its weight
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_policy_weighted_fair() {
+    let mut apps = SortedApps::new();
+    for task_id in 1..=3 {
+        insert(&mut apps, 1, task_id, Action::Download, task_id);
+    }
+    for task_id in 4..=7 {
+        insert(&mut apps, 2, task_id, Action::Download, task_id);
+    }
+    insert(&mut apps, 3, 8, Action::Download, 8);
+
+    let policy = WeightedFairPolicy::new(HashMap::from([(2, 3)]), 1);
+    let directions = policy.schedule(&apps, &CAPACITY, Action::Download);
+    assert_eq!(
+        levels(directions),
+        vec![
+            (1, QosLevel::High),
+            (4, QosLevel::Middle),
+            (5, QosLevel::Low),
+            (6, QosLevel::Low),
+        ]
+    );
+}
+
+// @tc.name: ut_policy_strict_priority
+// @tc.desc: Test strict priority across apps
+// @tc.precon: NA
+// @tc.step: 1. Add tasks of different priorities to two apps
+//           2. Schedule the downloads with StrictPriorityPolicy
+// @tc.expect: Tasks run in priority order regardless of their app
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_policy_strict_priority() {
+    let mut apps = SortedApps::new();
+    insert(&mut apps, 1, 1, Action::Download, 5);
+    insert(&mut apps, 1, 2, Action::Download, 1);
+    insert(&mut apps, 2, 3, Action::Download, 0);
+    insert(&mut apps, 2, 4, Action::Upload, 0);
+
+    let directions = StrictPriorityPolicy.schedule(&apps, &CAPACITY, Action::Download);
+    assert_eq!(
+        levels(directions),
+        vec![
+            (3, QosLevel::High),
+            (2, QosLevel::Middle),
+            (1, QosLevel::Low),
+        ]
+    );
+}
+
+// @tc.name: ut_policy_shortest_remaining
+// @tc.desc: Test shortest remaining bytes first across apps
+// @tc.precon: NA
+// @tc.step: 1. Add tasks to two apps and record the remaining size of two
+//           2. Schedule the downloads with ShortestRemainingPolicy
+// @tc.expect: Tasks run by remaining size, tasks of unknown size last
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_policy_shortest_remaining() {
+    let mut apps = SortedApps::new();
+    insert(&mut apps, 1, 1, Action::Download, 0);
+    insert(&mut apps, 1, 2, Action::Download, 0);
+    insert(&mut apps, 2, 3, Action::Download, 0);
+    apps.set_remaining(1, 1, Some(500));
+    apps.set_remaining(2, 3, Some(100));
+
+    let directions = ShortestRemainingPolicy.schedule(&apps, &CAPACITY, Action::Download);
+    assert_eq!(
+        levels(directions),
+        vec![
+            (3, QosLevel::High),
+            (1, QosLevel::Middle),
+            (2, QosLevel::Low),
+        ]
+    );
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::super::apps::SortedApps;
+use super::*;
+use crate::manage::database::TaskQosInfo;
+
+fn insert(apps: &mut SortedApps, uid: u64, task_id: u32, remaining: Option<u64>) {
+    apps.insert_task(
+        uid,
+        TaskQosInfo {
+            task_id,
+            action: 0,
+            mode: 0,
+            state: 0,
+            priority: task_id,
+        },
+    );
+    apps.set_remaining(uid, task_id, remaining);
+}
+
+fn task_ids(apps: &[App]) -> Vec<Vec<u32>> {
+    apps.iter()
+        .map(|app| app.tasks.iter().map(|task| task.task_id()).collect())
+        .collect()
+}
+
+// @tc.name: ut_quota_running
+// @tc.desc: Test the running quota of an application
+// @tc.precon: NA
+// @tc.step: 1. Add four tasks of one app and one task of another app
+//           2. Admit the tasks with a running quota of two for the first app
+// @tc.expect: Only the first two tasks of the first app are admitted, other
+// apps are not affected
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_quota_running() {
+    let mut apps = SortedApps::new();
+    for task_id in 1..=4 {
+        insert(&mut apps, 1, task_id, None);
+    }
+    insert(&mut apps, 2, 5, None);
+
+    let quota_of = |uid| match uid {
+        1 => Quota {
+            running: 2,
+            ..Quota::UNLIMITED
+        },
+        _ => Quota::UNLIMITED,
+    };
+    let mut held = HashSet::new();
+    let (admitted, newly_held) = admit(&apps, quota_of, &mut held);
+    assert_eq!(task_ids(&admitted), vec![vec![1, 2], vec![5]]);
+    assert_eq!(newly_held, vec![(1, 3), (1, 4)]);
+}
+
+// @tc.name: ut_quota_bytes
+// @tc.desc: Test the bytes quota of an application
+// @tc.precon: NA
+// @tc.step: 1. Add tasks of known and unknown remaining sizes
+//           2. Admit the tasks with a bytes quota
+// @tc.expect: The first task is always admitted, tasks exceeding the quota
+// are skipped and unknown sizes count as zero
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_quota_bytes() {
+    let mut apps = SortedApps::new();
+    insert(&mut apps, 1, 1, Some(2000));
+    insert(&mut apps, 1, 2, Some(10));
+    insert(&mut apps, 1, 3, None);
+
+    let quota = Quota {
+        bytes: 1000,
+        ..Quota::UNLIMITED
+    };
+    let (admitted, _) = admit(&apps, |_| quota, &mut HashSet::new());
+    assert_eq!(task_ids(&admitted), vec![vec![1]]);
+
+    let mut apps = SortedApps::new();
+    insert(&mut apps, 1, 1, Some(600));
+    insert(&mut apps, 1, 2, Some(600));
+    insert(&mut apps, 1, 3, Some(400));
+    insert(&mut apps, 1, 4, None);
+    let (admitted, _) = admit(&apps, |_| quota, &mut HashSet::new());
+    assert_eq!(task_ids(&admitted), vec![vec![1, 3, 4]]);
+}
+
+// @tc.name: ut_quota_newly_held
+// @tc.desc: Test that held tasks are reported once
+// @tc.precon: NA
+// @tc.step: 1. Admit tasks over quota twice
+//           2. Add a task over quota and admit again
+// @tc.expect: Only tasks not held at the previous admission are reported
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_quota_newly_held() {
+    let mut apps = SortedApps::new();
+    insert(&mut apps, 1, 1, None);
+    insert(&mut apps, 1, 2, None);
+
+    let quota = Quota {
+        running: 1,
+        ..Quota::UNLIMITED
+    };
+    let mut held = HashSet::new();
+    assert_eq!(admit(&apps, |_| quota, &mut held).1, vec![(1, 2)]);
+    assert!(admit(&apps, |_| quota, &mut held).1.is_empty());
+
+    insert(&mut apps, 1, 3, None);
+    assert_eq!(admit(&apps, |_| quota, &mut held).1, vec![(1, 3)]);
+
+    apps.remove_task(1, 1);
+    assert!(admit(&apps, |_| quota, &mut held).1.is_empty());
+    assert_eq!(held, HashSet::from([(1, 3)]));
+}
@@ -0,0 +1,103 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::super::policy::{StrictPriorityPolicy, TieredPolicy, WeightedFairPolicy};
+use super::super::QosLevel;
+use super::*;
+
+// Two slots at full speed.
+const TWO_SLOTS: RssCapacity = RssCapacity(1, 1, 0, QosLevel::High, QosLevel::High, QosLevel::High);
+// One slot at full speed.
+const ONE_SLOT: RssCapacity = RssCapacity(1, 0, 0, QosLevel::High, QosLevel::High, QosLevel::High);
+
+fn app(bytes: u64, throughput: u64, completed: usize, max_wait: u64, starved: usize) -> AppReport {
+    AppReport {
+        bytes,
+        throughput,
+        completed,
+        max_wait,
+        starved,
+    }
+}
+
+// @tc.name: ut_simulator_tiered_vs_weighted
+// @tc.desc: Test comparing the tiered and weighted fair policies on a trace
+// @tc.precon: NA
+// @tc.step: 1. Replay two tasks of one app and one task of another app with
+//              two slots under TieredPolicy
+//           2. Replay the same trace under WeightedFairPolicy
+// @tc.expect: The tiered policy makes the second app wait for the first one,
+// the weighted fair policy runs one task of each app at once
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_simulator_tiered_vs_weighted() {
+    let trace = [
+        Arrival::download(0, 1, 1, 1000),
+        Arrival::download(0, 1, 2, 1000),
+        Arrival::download(0, 2, 3, 1000),
+    ];
+
+    let report = Simulator::new(Box::new(TieredPolicy), TWO_SLOTS, 1000).run(&trace, 5000);
+    assert_eq!(report[&1], app(2000, 400, 2, 0, 0));
+    assert_eq!(report[&2], app(1000, 200, 1, 2000, 0));
+
+    let policy = WeightedFairPolicy::new(HashMap::new(), 1);
+    let report = Simulator::new(Box::new(policy), TWO_SLOTS, 1000).run(&trace, 5000);
+    assert_eq!(report[&1], app(2000, 400, 2, 2000, 0));
+    assert_eq!(report[&2], app(1000, 200, 1, 0, 0));
+}
+
+// @tc.name: ut_simulator_starvation
+// @tc.desc: Test reporting tasks that never run
+// @tc.precon: NA
+// @tc.step: 1. Replay a large task of one app and a later task of another
+//              app with one slot under TieredPolicy
+// @tc.expect: The later task is reported as starved with its waiting time
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_simulator_starvation() {
+    let trace = [
+        Arrival::download(0, 1, 1, 1_000_000),
+        Arrival::download(500, 2, 2, 1000),
+    ];
+    let simulator = Simulator::new(Box::new(TieredPolicy), ONE_SLOT, 1000);
+    let report = simulator.run(&trace, 3000);
+    assert_eq!(report[&1], app(3000, 1000, 0, 0, 0));
+    assert_eq!(report[&2], app(0, 0, 0, 2500, 1));
+    assert_eq!(simulator.run(&trace, 3000), report);
+}
+
+// @tc.name: ut_simulator_strict_priority
+// @tc.desc: Test replaying downloads and uploads under strict priority
+// @tc.precon: NA
+// @tc.step: 1. Replay a low priority download, a high priority foreground
+//              download and an upload with one slot per action under
+//              StrictPriorityPolicy
+// @tc.expect: The high priority download and the upload share the link first,
+// the low priority download runs after them
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_simulator_strict_priority() {
+    let trace = [
+        Arrival::download(0, 1, 1, 1000).priority(5),
+        Arrival::download(0, 2, 2, 1000).mode(Mode::FrontEnd),
+        Arrival::download(0, 3, 3, 1000).upload(),
+    ];
+    let report = Simulator::new(Box::new(StrictPriorityPolicy), ONE_SLOT, 1000).run(&trace, 3000);
+    assert_eq!(report[&1], app(1000, 333, 1, 2000, 0));
+    assert_eq!(report[&2], app(1000, 333, 1, 0, 0));
+    assert_eq!(report[&3], app(1000, 333, 1, 0, 0));
+}
@@ -36,7 +36,7 @@ fn query_state_and_reason(task_id: u32) -> (u8, u8) {
     )
 }
 
-fn network(sql: &str, change_reason: u8) {
+fn network(sql: &BoundSql, change_reason: u8) {
     let db = RequestDb::get_instance();
     let task_id = TaskIdGenerator::generate();
     let fail_reason = get_current_timestamp() as u8;
@@ -46,7 +46,7 @@ fn network(sql: &str, change_reason: u8) {
         "INSERT OR REPLACE INTO request_task (task_id, state, reason, network, version, mode, retry) VALUES ({task_id}, {RUNNING}, {fail_reason}, {WIFI}, {API10}, {BACKGROUND}, 1)",
     ))
     .unwrap();
-    db.execute(sql).unwrap();
+    db.execute_bound(sql).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, change_reason);
@@ -55,7 +55,7 @@ fn network(sql: &str, change_reason: u8) {
         "INSERT OR REPLACE INTO request_task (task_id, state, reason, network, version, action) VALUES ({task_id}, {RUNNING}, {fail_reason}, {WIFI}, {API9}, {DOWNLOAD})",
     ))
     .unwrap();
-    db.execute(sql).unwrap();
+    db.execute_bound(sql).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, change_reason);
@@ -64,7 +64,7 @@ fn network(sql: &str, change_reason: u8) {
         "INSERT OR REPLACE INTO request_task (task_id, state, reason, network, version, action) VALUES ({task_id}, {RUNNING}, {fail_reason}, {WIFI}, {API9}, {UPLOAD})",
     ))
     .unwrap();
-    db.execute(sql).unwrap();
+    db.execute_bound(sql).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, FAILED);
     assert_eq!(reason, change_reason);
@@ -73,7 +73,7 @@ fn network(sql: &str, change_reason: u8) {
         "INSERT OR REPLACE INTO request_task (task_id, state, reason, network, version, mode, retry) VALUES ({task_id}, {RUNNING}, {fail_reason}, {WIFI}, {API10}, {FRONTEND}, 1)",
     ))
     .unwrap();
-    db.execute(sql).unwrap();
+    db.execute_bound(sql).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, FAILED);
     assert_eq!(reason, change_reason);
@@ -82,7 +82,7 @@ fn network(sql: &str, change_reason: u8) {
         "INSERT OR REPLACE INTO request_task (task_id, state, reason, network, version, mode, retry) VALUES ({task_id}, {RUNNING}, {fail_reason}, {WIFI}, {API10}, {BACKGROUND}, 0)",
     ))
     .unwrap();
-    db.execute(sql).unwrap();
+    db.execute_bound(sql).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, FAILED);
     assert_eq!(reason, change_reason);
@@ -92,7 +92,7 @@ fn network(sql: &str, change_reason: u8) {
         "INSERT OR REPLACE INTO request_task (task_id, state, reason, network) VALUES ({task_id}, {FAILED}, {fail_reason}, {WIFI})",
     ))
     .unwrap();
-    db.execute(sql).unwrap();
+    db.execute_bound(sql).unwrap();
 
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, FAILED);
@@ -103,7 +103,7 @@ fn network(sql: &str, change_reason: u8) {
         "INSERT OR REPLACE INTO request_task (task_id, state, reason, network) VALUES ({task_id}, {WAITING}, {RUNNING_TASK_MEET_LIMITS}, {WIFI})",
     ))
     .unwrap();
-    db.execute(sql).unwrap();
+    db.execute_bound(sql).unwrap();
 
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
@@ -114,7 +114,7 @@ fn network(sql: &str, change_reason: u8) {
         "INSERT OR REPLACE INTO request_task (task_id, state, version, action, network, metered, roaming) VALUES ({task_id}, {RUNNING}, {API9}, {DOWNLOAD}, {CELLULAR}, 1, 0)",
     ))
     .unwrap();
-    db.execute(sql).unwrap();
+    db.execute_bound(sql).unwrap();
 
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
@@ -125,7 +125,7 @@ fn network(sql: &str, change_reason: u8) {
         "INSERT OR REPLACE INTO request_task (task_id, state, version, action, network, metered, roaming) VALUES ({task_id}, {RUNNING}, {API9}, {UPLOAD}, {CELLULAR}, 0, 1)",
     ))
     .unwrap();
-    db.execute(sql).unwrap();
+    db.execute_bound(sql).unwrap();
 
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, FAILED);
@@ -136,7 +136,7 @@ fn network(sql: &str, change_reason: u8) {
         "INSERT OR REPLACE INTO request_task (task_id, state, version, mode, retry, network, metered, roaming) VALUES ({task_id}, {RUNNING}, {API10}, {BACKGROUND}, 1, {CELLULAR}, 0, 0)",
     ))
     .unwrap();
-    db.execute(sql).unwrap();
+    db.execute_bound(sql).unwrap();
 
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
@@ -147,7 +147,7 @@ fn network(sql: &str, change_reason: u8) {
         "INSERT OR REPLACE INTO request_task (task_id, state, version, mode, retry, network) VALUES ({task_id}, {RUNNING}, {API10}, {FRONTEND}, 1, {WIFI})",
     ))
     .unwrap();
-    db.execute(sql).unwrap();
+    db.execute_bound(sql).unwrap();
 
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, FAILED);
@@ -158,7 +158,7 @@ fn network(sql: &str, change_reason: u8) {
         "INSERT OR REPLACE INTO request_task (task_id, state, version, mode, retry, network) VALUES ({task_id}, {RUNNING}, {API10}, {BACKGROUND}, 0, {WIFI})",
     ))
     .unwrap();
-    db.execute(sql).unwrap();
+    db.execute_bound(sql).unwrap();
 
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, FAILED);
@@ -214,7 +214,7 @@ fn ut_network_unsupported() {
         "INSERT OR REPLACE INTO request_task (task_id, state, reason, network, metered, roaming) VALUES ({task_id}, {WAITING}, {RUNNING_TASK_MEET_LIMITS}, {CELLULAR}, 1, 1)",
     ))
     .unwrap();
-    db.execute(&network_unavailable(&info).unwrap()).unwrap();
+    db.execute_bound(&network_unavailable(&info).unwrap()).unwrap();
 
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
@@ -257,7 +257,7 @@ fn ut_network_online() {
             "INSERT OR REPLACE INTO request_task (task_id, state, reason, network, metered, roaming) VALUES ({task_id}, {WAITING}, {NETWORK_OFFLINE}, {}, {}, {})",state.0,state.1,state.2
         )).unwrap();
 
-        db.execute(&network_available(&info)).unwrap();
+        db.execute_bound(&network_available(&info)).unwrap();
 
         let state: u8 = db.query_integer(&format!(
             "SELECT state FROM request_task where task_id = {task_id}"
@@ -273,7 +273,7 @@ fn ut_network_online() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, state, reason, network, metered, roaming) VALUES ({task_id}, {WAITING}, {NETWORK_OFFLINE}, {CELLULAR}, 1, 1)"
     )).unwrap();
-    db.execute(&network_available(&info)).unwrap();
+    db.execute_bound(&network_available(&info)).unwrap();
 
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
@@ -303,7 +303,7 @@ fn ut_app_state_unavailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, mode, state, reason, action) VALUES ({task_id}, {uid}, {FRONTEND}, {RUNNING}, {fail_reason}, {DOWNLOAD})"
     )).unwrap();
-    db.execute(&app_state_unavailable(uid)).unwrap();
+    db.execute_bound(&app_state_unavailable(uid)).unwrap();
 
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
@@ -313,7 +313,7 @@ fn ut_app_state_unavailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, mode, state, reason, action) VALUES ({task_id}, {uid}, {FRONTEND}, {RUNNING}, {fail_reason}, {UPLOAD})"
     )).unwrap();
-    db.execute(&app_state_unavailable(uid)).unwrap();
+    db.execute_bound(&app_state_unavailable(uid)).unwrap();
 
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, FAILED);
@@ -323,7 +323,7 @@ fn ut_app_state_unavailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, mode, state, reason, action) VALUES ({task_id}, {uid}, {FRONTEND}, {RETRYING}, {fail_reason}, {DOWNLOAD})"
     )).unwrap();
-    db.execute(&app_state_unavailable(uid)).unwrap();
+    db.execute_bound(&app_state_unavailable(uid)).unwrap();
 
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
@@ -333,7 +333,7 @@ fn ut_app_state_unavailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, mode, state, reason) VALUES ({task_id}, {uid}, {FRONTEND}, {FAILED}, {fail_reason})"
     )).unwrap();
-    db.execute(&app_state_unavailable(uid)).unwrap();
+    db.execute_bound(&app_state_unavailable(uid)).unwrap();
 
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, FAILED);
@@ -343,7 +343,7 @@ fn ut_app_state_unavailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, mode, state, reason) VALUES ({task_id}, {uid}, {FRONTEND}, {WAITING}, {RUNNING_TASK_MEET_LIMITS})"
     )).unwrap();
-    db.execute(&app_state_unavailable(uid)).unwrap();
+    db.execute_bound(&app_state_unavailable(uid)).unwrap();
 
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
@@ -353,7 +353,7 @@ fn ut_app_state_unavailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, mode, state, action) VALUES ({task_id}, {uid}, {FRONTEND}, {RUNNING}, {DOWNLOAD})"
     )).unwrap();
-    db.execute(&app_state_unavailable(uid)).unwrap();
+    db.execute_bound(&app_state_unavailable(uid)).unwrap();
 
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
@@ -363,7 +363,7 @@ fn ut_app_state_unavailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, mode, state, action) VALUES ({task_id}, {uid}, {FRONTEND}, {RUNNING}, {UPLOAD})"
     )).unwrap();
-    db.execute(&app_state_unavailable(uid)).unwrap();
+    db.execute_bound(&app_state_unavailable(uid)).unwrap();
 
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, FAILED);
@@ -373,7 +373,7 @@ fn ut_app_state_unavailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, mode, state, action) VALUES ({task_id}, {uid}, {BACKGROUND}, {RUNNING}, {UPLOAD})"
     )).unwrap();
-    db.execute(&app_state_unavailable(uid)).unwrap();
+    db.execute_bound(&app_state_unavailable(uid)).unwrap();
 
     let state: u8 = db.query_integer(&format!(
         "SELECT state FROM request_task where task_id = {task_id}"
@@ -402,7 +402,7 @@ fn ut_app_state_available() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason) VALUES ({task_id}, {uid}, {WAITING}, {APP_BACKGROUND_OR_TERMINATE})"
     )).unwrap();
-    db.execute(&app_state_available(uid)).unwrap();
+    db.execute_bound(&app_state_available(uid)).unwrap();
 
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
@@ -434,7 +434,7 @@ fn ut_account_unavailable() {
         db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason) VALUES ({task_id}, {uid}, {state}, {RUNNING_TASK_MEET_LIMITS})"
     )).unwrap();
-        db.execute(&account_unavailable(&hash_set)).unwrap();
+        db.execute_bound(&account_unavailable(&hash_set)).unwrap();
         let state: u8 = db.query_integer(&format!(
             "SELECT state FROM request_task where task_id = {task_id}"
         ))[0];
@@ -450,7 +450,7 @@ fn ut_account_unavailable() {
         db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason) VALUES ({task_id}, {uid}, {state}, {RUNNING_TASK_MEET_LIMITS})"
     )).unwrap();
-        db.execute(&account_unavailable(&hash_set)).unwrap();
+        db.execute_bound(&account_unavailable(&hash_set)).unwrap();
         let change_state: u8 = db.query_integer(&format!(
             "SELECT state FROM request_task where task_id = {task_id}"
         ))[0];
@@ -487,12 +487,12 @@ fn ut_account_available() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason) VALUES ({task_id}, {uid}, {WAITING}, {ACCOUNT_STOPPED})"
     )).unwrap();
-    db.execute(&account_available(&hash_set)).unwrap();
+    db.execute_bound(&account_available(&hash_set)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, ACCOUNT_STOPPED);
     hash_set.insert(user);
-    db.execute(&account_available(&hash_set)).unwrap();
+    db.execute_bound(&account_available(&hash_set)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, RUNNING_TASK_MEET_LIMITS);
@@ -529,12 +529,12 @@ fn ut_multi_reason_available() {
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming) VALUES ({task_id}, {uid}, {WAITING}, {NETWORK_APP_ACCOUNT}, {CELLULAR}, 1, 1)"
     )).unwrap();
 
-    db.execute(&account_available(&hash_set)).unwrap();
+    db.execute_bound(&account_available(&hash_set)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_APP);
 
-    db.execute(&network_available(&info)).unwrap();
+    db.execute_bound(&network_available(&info)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, APP_BACKGROUND_OR_TERMINATE);
@@ -544,8 +544,8 @@ fn ut_multi_reason_available() {
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming) VALUES ({task_id}, {uid}, {WAITING}, {NETWORK_APP_ACCOUNT}, {CELLULAR}, 1, 1)"
     )).unwrap();
 
-    db.execute(&account_available(&hash_set)).unwrap();
-    db.execute(&app_state_available(uid)).unwrap();
+    db.execute_bound(&account_available(&hash_set)).unwrap();
+    db.execute_bound(&app_state_available(uid)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_OFFLINE);
@@ -554,12 +554,12 @@ fn ut_multi_reason_available() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming) VALUES ({task_id}, {uid}, {WAITING}, {NETWORK_APP_ACCOUNT}, {CELLULAR}, 1, 1)"
     )).unwrap();
-    db.execute(&network_available(&info)).unwrap();
+    db.execute_bound(&network_available(&info)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, APP_ACCOUNT);
 
-    db.execute(&app_state_available(uid)).unwrap();
+    db.execute_bound(&app_state_available(uid)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, ACCOUNT_STOPPED);
@@ -568,8 +568,8 @@ fn ut_multi_reason_available() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming) VALUES ({task_id}, {uid}, {WAITING}, {NETWORK_APP_ACCOUNT}, {CELLULAR}, 1, 1)"
     )).unwrap();
-    db.execute(&network_available(&info)).unwrap();
-    db.execute(&account_available(&hash_set)).unwrap();
+    db.execute_bound(&network_available(&info)).unwrap();
+    db.execute_bound(&account_available(&hash_set)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, APP_BACKGROUND_OR_TERMINATE);
@@ -578,12 +578,12 @@ fn ut_multi_reason_available() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming) VALUES ({task_id}, {uid}, {WAITING}, {NETWORK_APP_ACCOUNT}, {CELLULAR}, 1, 1)"
     )).unwrap();
-    db.execute(&app_state_available(uid)).unwrap();
+    db.execute_bound(&app_state_available(uid)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_ACCOUNT);
 
-    db.execute(&network_available(&info)).unwrap();
+    db.execute_bound(&network_available(&info)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, ACCOUNT_STOPPED);
@@ -592,8 +592,8 @@ fn ut_multi_reason_available() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming) VALUES ({task_id}, {uid}, {WAITING}, {NETWORK_APP_ACCOUNT}, {CELLULAR}, 1, 1)"
     )).unwrap();
-    db.execute(&app_state_available(uid)).unwrap();
-    db.execute(&account_available(&hash_set)).unwrap();
+    db.execute_bound(&app_state_available(uid)).unwrap();
+    db.execute_bound(&account_available(&hash_set)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_OFFLINE);
@@ -627,7 +627,7 @@ fn ut_multi_reason_unailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming, mode) VALUES ({task_id}, {uid}, {WAITING}, {ACCOUNT_STOPPED}, {CELLULAR}, 1, 1, {FRONTEND})"
     )).unwrap();
-    db.execute(&network_offline()).unwrap();
+    db.execute_bound(&network_offline()).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_ACCOUNT);
@@ -637,7 +637,7 @@ fn ut_multi_reason_unailable() {
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming, mode) VALUES ({task_id}, {uid}, {WAITING}, {ACCOUNT_STOPPED}, {CELLULAR}, 1, 1, {FRONTEND})"
     )).unwrap();
 
-    db.execute(&network_unavailable(&info).unwrap()).unwrap();
+    db.execute_bound(&network_unavailable(&info).unwrap()).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_ACCOUNT);
@@ -646,7 +646,7 @@ fn ut_multi_reason_unailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming, mode) VALUES ({task_id}, {uid}, {WAITING}, {NETWORK_ACCOUNT}, {CELLULAR}, 1, 1, {FRONTEND})"
     )).unwrap();
-    db.execute(&app_state_unavailable(uid)).unwrap();
+    db.execute_bound(&app_state_unavailable(uid)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_APP_ACCOUNT);
@@ -655,7 +655,7 @@ fn ut_multi_reason_unailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming, mode) VALUES ({task_id}, {uid}, {WAITING}, {ACCOUNT_STOPPED}, {CELLULAR}, 1, 1, {FRONTEND})"
     )).unwrap();
-    db.execute(&app_state_unavailable(uid)).unwrap();
+    db.execute_bound(&app_state_unavailable(uid)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, APP_ACCOUNT);
@@ -664,7 +664,7 @@ fn ut_multi_reason_unailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming, mode) VALUES ({task_id}, {uid}, {WAITING}, {APP_ACCOUNT}, {CELLULAR}, 1, 1, {FRONTEND})"
     )).unwrap();
-    db.execute(&network_offline()).unwrap();
+    db.execute_bound(&network_offline()).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_APP_ACCOUNT);
@@ -673,7 +673,7 @@ fn ut_multi_reason_unailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming, mode) VALUES ({task_id}, {uid}, {WAITING}, {APP_ACCOUNT}, {CELLULAR}, 1, 1, {FRONTEND})"
     )).unwrap();
-    db.execute(&network_unavailable(&info).unwrap()).unwrap();
+    db.execute_bound(&network_unavailable(&info).unwrap()).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_APP_ACCOUNT);
@@ -682,7 +682,7 @@ fn ut_multi_reason_unailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming, mode) VALUES ({task_id}, {uid}, {WAITING}, {NETWORK_OFFLINE}, {CELLULAR}, 1, 1, {FRONTEND})"
     )).unwrap();
-    db.execute(&account_unavailable(&hash_set)).unwrap();
+    db.execute_bound(&account_unavailable(&hash_set)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_ACCOUNT);
@@ -691,7 +691,7 @@ fn ut_multi_reason_unailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming, mode) VALUES ({task_id}, {uid}, {WAITING}, {UNSUPPORTED_NETWORK_TYPE}, {CELLULAR}, 1, 1, {FRONTEND})"
     )).unwrap();
-    db.execute(&account_unavailable(&hash_set)).unwrap();
+    db.execute_bound(&account_unavailable(&hash_set)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_ACCOUNT);
@@ -700,7 +700,7 @@ fn ut_multi_reason_unailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming, mode) VALUES ({task_id}, {uid}, {WAITING}, {NETWORK_ACCOUNT}, {CELLULAR}, 1, 1, {FRONTEND})"
     )).unwrap();
-    db.execute(&app_state_unavailable(uid)).unwrap();
+    db.execute_bound(&app_state_unavailable(uid)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_APP_ACCOUNT);
@@ -709,7 +709,7 @@ fn ut_multi_reason_unailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming, mode) VALUES ({task_id}, {uid}, {WAITING}, {NETWORK_OFFLINE}, {CELLULAR}, 1, 1, {FRONTEND})"
     )).unwrap();
-    db.execute(&app_state_unavailable(uid)).unwrap();
+    db.execute_bound(&app_state_unavailable(uid)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_APP);
@@ -718,7 +718,7 @@ fn ut_multi_reason_unailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming, mode) VALUES ({task_id}, {uid}, {WAITING}, {UNSUPPORTED_NETWORK_TYPE}, {CELLULAR}, 1, 1, {FRONTEND})"
     )).unwrap();
-    db.execute(&app_state_unavailable(uid)).unwrap();
+    db.execute_bound(&app_state_unavailable(uid)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_APP);
@@ -727,7 +727,7 @@ fn ut_multi_reason_unailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming, mode) VALUES ({task_id}, {uid}, {WAITING}, {NETWORK_APP}, {CELLULAR}, 1, 1, {FRONTEND})"
     )).unwrap();
-    db.execute(&account_unavailable(&hash_set)).unwrap();
+    db.execute_bound(&account_unavailable(&hash_set)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_APP_ACCOUNT);
@@ -736,7 +736,7 @@ fn ut_multi_reason_unailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming, mode) VALUES ({task_id}, {uid}, {WAITING}, {APP_BACKGROUND_OR_TERMINATE}, {CELLULAR}, 1, 1, {FRONTEND})"
     )).unwrap();
-    db.execute(&network_offline()).unwrap();
+    db.execute_bound(&network_offline()).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_APP);
@@ -745,7 +745,7 @@ fn ut_multi_reason_unailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming, mode) VALUES ({task_id}, {uid}, {WAITING}, {APP_BACKGROUND_OR_TERMINATE}, {CELLULAR}, 1, 1, {FRONTEND})"
     )).unwrap();
-    db.execute(&network_unavailable(&info).unwrap()).unwrap();
+    db.execute_bound(&network_unavailable(&info).unwrap()).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_APP);
@@ -754,7 +754,7 @@ fn ut_multi_reason_unailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming, mode) VALUES ({task_id}, {uid}, {WAITING}, {NETWORK_APP}, {CELLULAR}, 1, 1, {FRONTEND})"
     )).unwrap();
-    db.execute(&account_unavailable(&hash_set)).unwrap();
+    db.execute_bound(&account_unavailable(&hash_set)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_APP_ACCOUNT);
@@ -763,7 +763,7 @@ fn ut_multi_reason_unailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming, mode) VALUES ({task_id}, {uid}, {WAITING}, {APP_BACKGROUND_OR_TERMINATE}, {CELLULAR}, 1, 1, {FRONTEND})"
     )).unwrap();
-    db.execute(&account_unavailable(&hash_set)).unwrap();
+    db.execute_bound(&account_unavailable(&hash_set)).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, APP_ACCOUNT);
@@ -772,7 +772,7 @@ fn ut_multi_reason_unailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming, mode) VALUES ({task_id}, {uid}, {WAITING}, {APP_ACCOUNT}, {CELLULAR}, 1, 1, {FRONTEND})"
     )).unwrap();
-    db.execute(&network_offline()).unwrap();
+    db.execute_bound(&network_offline()).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_APP_ACCOUNT);
@@ -781,7 +781,7 @@ fn ut_multi_reason_unailable() {
     db.execute(&format!(
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming, mode) VALUES ({task_id}, {uid}, {WAITING}, {APP_ACCOUNT}, {CELLULAR}, 1, 1, {FRONTEND})"
     )).unwrap();
-    db.execute(&network_unavailable(&info).unwrap()).unwrap();
+    db.execute_bound(&network_unavailable(&info).unwrap()).unwrap();
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_APP_ACCOUNT);
@@ -0,0 +1,166 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+
+use ylong_runtime::sync::mpsc::unbounded_channel;
+
+use crate::config::{Action, ConfigBuilder, Mode};
+use crate::database::file_status;
+use crate::error::ErrorCode;
+use crate::info::State;
+use crate::manage::database::RequestDb;
+use crate::manage::network::{NetworkInfo, NetworkType};
+use crate::manage::network_manager::NetworkManager;
+use crate::manage::task_manager::{TaskManagerRx, TaskManagerTx};
+use crate::manage::TaskManager;
+use crate::service::active_counter::ActiveCounter;
+use crate::service::client::ClientManagerEntry;
+use crate::service::run_count::RunCountManagerEntry;
+use crate::task::reason::Reason;
+use crate::tests::{lock_database, test_init};
+
+fn init_manager() -> TaskManager {
+    let (tx, rx) = unbounded_channel();
+    let task_manager_tx = TaskManagerTx::new(tx);
+    let rx = TaskManagerRx::new(rx);
+    {
+        let network_manager = NetworkManager::get_instance().lock().unwrap();
+        let notifier = network_manager.network.inner.clone();
+        notifier.notify_online(NetworkInfo {
+            network_type: NetworkType::Wifi,
+            is_metered: false,
+            is_roaming: false,
+        });
+    }
+    let (tx, _rx) = unbounded_channel();
+    let run_count = RunCountManagerEntry::new(tx);
+    let (tx, _rx) = unbounded_channel();
+    let client = ClientManagerEntry::new(tx);
+    TaskManager::new(task_manager_tx, rx, run_count, client, ActiveCounter::new())
+}
+
+fn task_state(task_id: u32) -> u8 {
+    RequestDb::get_instance()
+        .get_task_info(task_id)
+        .unwrap()
+        .progress
+        .common_data
+        .state
+}
+
+// @tc.name: ut_retry_failed_files
+// @tc.desc: Test retrying the failed files of a failed upload task
+// @tc.precon: NA
+// @tc.step: 1. Create and start an upload task with two files
+//           2. Fail the task with one file succeeded and one failed
+//           3. Start the task, then retry its failed files
+//           4. Retry its failed files again
+// @tc.expect: Starting the failed upload fails, retrying moves it to Waiting,
+//             and retrying a task that is not failed fails
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_retry_failed_files() {
+    test_init();
+    let _lock = lock_database();
+    let mut manager = init_manager();
+
+    let config = ConfigBuilder::new()
+        .action(Action::Upload)
+        .mode(Mode::BackGround)
+        .file_spec(File::create("test_files/ut_retry_failed_files_0").unwrap())
+        .file_spec(File::create("test_files/ut_retry_failed_files_1").unwrap())
+        .url("https://www.example.com/upload")
+        .build();
+    let uid = config.common_data.uid;
+    let task_id = manager.create(config).unwrap();
+    manager.start(uid, task_id);
+    manager
+        .scheduler
+        .task_failed(uid, task_id, Reason::ProtocolError);
+    assert_eq!(task_state(task_id), State::Failed.repr);
+    file_status::record_file_result(task_id, 0, 2, Reason::Default).unwrap();
+    file_status::record_file_result(task_id, 1, 2, Reason::ProtocolError).unwrap();
+
+    assert_eq!(manager.start(uid, task_id), ErrorCode::TaskStateErr);
+    assert_eq!(task_state(task_id), State::Failed.repr);
+
+    assert_eq!(manager.retry_failed_files(uid, task_id), ErrorCode::ErrOk);
+    assert_eq!(task_state(task_id), State::Waiting.repr);
+
+    assert_eq!(
+        manager.retry_failed_files(uid, task_id),
+        ErrorCode::TaskStateErr
+    );
+}
+
+// @tc.name: ut_reorder_queued_tasks
+// @tc.desc: Test changing the priority and queue position of queued tasks
+// @tc.precon: NA
+// @tc.step: 1. Create and start two upload tasks of one app
+//           2. Set the priority of the first task, with its owner and another
+//              uid
+//           3. Move the second task to the front of the queue
+//           4. Fail the first task and move it to the front
+// @tc.expect: The new priorities are persisted and the moved task runs first,
+//             other uids get TaskNotFound and a failed task cannot be moved
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_reorder_queued_tasks() {
+    test_init();
+    let _lock = lock_database();
+    let mut manager = init_manager();
+
+    let mut task_ids = Vec::new();
+    let mut uid = 0;
+    for i in 0..2 {
+        let config = ConfigBuilder::new()
+            .action(Action::Upload)
+            .mode(Mode::BackGround)
+            .file_spec(File::create(format!("test_files/ut_reorder_queued_tasks_{}", i)).unwrap())
+            .url("https://www.example.com/upload")
+            .build();
+        uid = config.common_data.uid;
+        let task_id = manager.create(config).unwrap();
+        manager.start(uid, task_id);
+        task_ids.push(task_id);
+    }
+    let priority = |task_id| {
+        RequestDb::get_instance()
+            .get_task_info(task_id)
+            .unwrap()
+            .common_data
+            .priority
+    };
+
+    assert_eq!(manager.set_priority(uid, task_ids[0], 7), ErrorCode::ErrOk);
+    assert_eq!(priority(task_ids[0]), 7);
+    assert_eq!(
+        manager.set_priority(uid + 1, task_ids[0], 3),
+        ErrorCode::TaskNotFound
+    );
+    assert_eq!(priority(task_ids[0]), 7);
+
+    assert_eq!(manager.move_to_front(uid, task_ids[1]), ErrorCode::ErrOk);
+    assert!(priority(task_ids[1]) < priority(task_ids[0]));
+
+    manager
+        .scheduler
+        .task_failed(uid, task_ids[0], Reason::ProtocolError);
+    assert_eq!(
+        manager.move_to_front(uid, task_ids[0]),
+        ErrorCode::TaskStateErr
+    );
+}
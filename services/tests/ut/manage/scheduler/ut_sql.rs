@@ -14,7 +14,8 @@
 use rusqlite::Connection;
 
 const CREATE: &'static str = "CREATE TABLE IF NOT EXISTS request_task (task_id INTEGER PRIMARY KEY, uid INTEGER, token_id INTEGER, action INTEGER, mode INTEGER, cover INTEGER, network INTEGER, metered INTEGER, roaming INTEGER, ctime INTEGER, mtime INTEGER, reason INTEGER, gauge INTEGER, retry INTEGER, redirect INTEGER, tries INTEGER, version INTEGER, config_idx INTEGER, begins INTEGER, ends INTEGER, precise INTEGER, priority INTEGER, background INTEGER, bundle TEXT, url TEXT, data TEXT, token TEXT, title TEXT, description TEXT, method TEXT, headers TEXT, config_extras TEXT, mime_type TEXT, state INTEGER, idx INTEGER, total_processed INTEGER, sizes TEXT, processed TEXT, extras TEXT, form_items BLOB, file_specs BLOB, each_file_status BLOB, body_file_names BLOB, certs_paths BLOB)";
-use super::{pause_task, start_task, stop_task};
+use super::{pause_task, retry_failed_files, start_task, stop_task, task_set_priority};
+use crate::config::Action;
 use crate::info::State;
 use crate::task::reason::Reason;
 
@@ -196,4 +197,105 @@ fn ut_stop() {
     res.sort();
     tasks.sort();
     assert_eq!(tasks, res);
-}
\ No newline at end of file
+}
+
+// @tc.name: ut_retry_failed_files_sql
+// @tc.desc: Test re-queuing failed upload tasks to retry their failed files
+// @tc.precon: NA
+// @tc.step: 1. Create in-memory database
+//           2. Insert failed and stopped upload and download tasks
+//           3. Start the tasks, then retry their failed files
+// @tc.expect: Starting leaves the failed upload task failed, and retrying only
+//             moves the failed upload task to waiting
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_retry_failed_files_sql() {
+    init();
+
+    let db = Connection::open_in_memory().unwrap();
+    db.execute(&CREATE, ()).unwrap();
+    let tasks = [
+        (Action::Upload, State::Failed),
+        (Action::Upload, State::Stopped),
+        (Action::Download, State::Failed),
+    ];
+    let mut task_ids = vec![];
+    for (action, state) in tasks.iter() {
+        let task_id: u32 = rand::random();
+        task_ids.push(task_id);
+        db.execute(
+            &format!(
+                "INSERT INTO request_task (task_id, action, state) VALUES ({}, {}, {})",
+                task_id, action.repr, state.repr,
+            ),
+            (),
+        )
+        .unwrap();
+    }
+    let state = |task_id: u32| -> u8 {
+        db.query_row(
+            &format!("SELECT state from request_task where task_id = {}", task_id),
+            [],
+            |row| row.get(0),
+        )
+        .unwrap()
+    };
+
+    db.execute(&start_task(task_ids[0]), ()).unwrap();
+    assert_eq!(state(task_ids[0]), State::Failed.repr);
+
+    for task_id in task_ids.iter() {
+        db.execute(&retry_failed_files(*task_id), ()).unwrap();
+    }
+    assert_eq!(state(task_ids[0]), State::Waiting.repr);
+    assert_eq!(state(task_ids[1]), State::Stopped.repr);
+    assert_eq!(state(task_ids[2]), State::Failed.repr);
+}
+
+// @tc.name: ut_task_set_priority_sql
+// @tc.desc: Test changing the priority of a task
+// @tc.precon: NA
+// @tc.step: 1. Create in-memory database
+//           2. Insert two tasks
+//           3. Change the priority of one task with its bound statement
+// @tc.expect: Only the priority of that task changes, its state is kept
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_task_set_priority_sql() {
+    init();
+
+    let db = Connection::open_in_memory().unwrap();
+    db.execute(&CREATE, ()).unwrap();
+    let task_ids: [u32; 2] = [rand::random(), rand::random()];
+    for task_id in task_ids.iter() {
+        db.execute(
+            &format!(
+                "INSERT INTO request_task (task_id, state, priority) VALUES ({}, {}, 0)",
+                task_id,
+                State::Waiting.repr,
+            ),
+            (),
+        )
+        .unwrap();
+    }
+    let query = |column: &str, task_id: u32| -> u32 {
+        db.query_row(
+            &format!(
+                "SELECT {} from request_task where task_id = {}",
+                column, task_id
+            ),
+            [],
+            |row| row.get(0),
+        )
+        .unwrap()
+    };
+
+    let sql = task_set_priority(task_ids[0], 7);
+    db.execute(&sql.sql, rusqlite::params_from_iter(sql.params.iter()))
+        .unwrap();
+    assert_eq!(query("priority", task_ids[0]), 7);
+    assert_eq!(query("state", task_ids[0]), State::Waiting.repr as u32);
+    assert_eq!(query("priority", task_ids[1]), 0);
+}
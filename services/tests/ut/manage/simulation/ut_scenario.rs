@@ -0,0 +1,157 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use super::*;
+use crate::config::Mode;
+use crate::manage::network::NetworkType;
+use crate::utils::get_current_timestamp;
+
+const UID: u64 = 20_020_037;
+
+fn wifi() -> NetworkInfo {
+    NetworkInfo {
+        network_type: NetworkType::Wifi,
+        is_metered: false,
+        is_roaming: false,
+    }
+}
+
+// @tc.name: ut_scenario_download_completes
+// @tc.desc: Test a download served by the mock server in a simulation
+// @tc.precon: NA
+// @tc.step: 1. Serve a file and construct a download task for it
+//           2. Start the task and settle the simulation
+//           3. Check the states, notifications and requests
+// @tc.expect: The task completes after one request and notifies completion
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_scenario_download_completes() {
+    Scenario::new("download completes")
+        .step(Step::Serve(
+            "/file",
+            vec![MockResponse::ok(vec![b'a'; 4096])],
+        ))
+        .step(Step::Download(TaskSpec::new("file", "/file").uid(UID)))
+        .step(Step::Start("file"))
+        .step(Step::Settle)
+        .step(Step::ExpectState("file", State::Completed))
+        .step(Step::ExpectPassed(
+            "file",
+            vec![State::Initialized, State::Completed],
+        ))
+        .step(Step::ExpectNotified("file", SubscribeType::Complete))
+        .step(Step::ExpectRequests(vec!["/file"]))
+        .run();
+}
+
+// @tc.name: ut_scenario_offline_then_online
+// @tc.desc: Test a task started without network in a simulation
+// @tc.precon: NA
+// @tc.step: 1. Take the network offline and start a download task
+//           2. Check the task waits for the network without any request
+//           3. Bring the network online and settle the simulation
+// @tc.expect: The task waits for the network, then completes
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_scenario_offline_then_online() {
+    Scenario::new("offline then online")
+        .step(Step::Offline)
+        .step(Step::Serve("/offline", vec![MockResponse::ok("offline")]))
+        .step(Step::Download(TaskSpec::new("file", "/offline").uid(UID)))
+        .step(Step::Start("file"))
+        .step(Step::Settle)
+        .step(Step::ExpectState("file", State::Waiting))
+        .step(Step::ExpectWaiting("file", WaitingCause::Network))
+        .step(Step::ExpectRequests(vec![]))
+        .step(Step::Online(wifi()))
+        .step(Step::Settle)
+        .step(Step::ExpectPassed(
+            "file",
+            vec![State::Waiting, State::Completed],
+        ))
+        .step(Step::ExpectRequests(vec!["/offline"]))
+        .run();
+}
+
+// @tc.name: ut_scenario_foreground_task
+// @tc.desc: Test a foreground task waiting for its application
+// @tc.precon: NA
+// @tc.step: 1. Start a foreground task of an application in the background
+//           2. Check the task waits for the application
+//           3. Bring the application to the foreground and settle
+// @tc.expect: The task waits for the application state, then completes
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_scenario_foreground_task() {
+    Scenario::new("foreground task")
+        .step(Step::Serve("/front", vec![MockResponse::ok("front")]))
+        .step(Step::Download(
+            TaskSpec::new("file", "/front")
+                .uid(UID)
+                .mode(Mode::FrontEnd),
+        ))
+        .step(Step::Start("file"))
+        .step(Step::Settle)
+        .step(Step::ExpectState("file", State::Waiting))
+        .step(Step::ExpectWaiting("file", WaitingCause::AppState))
+        .step(Step::Foreground(UID))
+        .step(Step::Settle)
+        .step(Step::ExpectState("file", State::Completed))
+        .run();
+}
+
+// @tc.name: ut_scenario_not_found
+// @tc.desc: Test a download answered with 404 in a simulation
+// @tc.precon: NA
+// @tc.step: 1. Construct a download task for a path not served
+//           2. Start the task and settle the simulation
+// @tc.expect: The task fails and notifies the failure
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_scenario_not_found() {
+    Scenario::new("not found")
+        .step(Step::Download(TaskSpec::new("file", "/missing").uid(UID)))
+        .step(Step::Start("file"))
+        .step(Step::Settle)
+        .step(Step::ExpectState("file", State::Failed))
+        .step(Step::ExpectNotified("file", SubscribeType::Fail))
+        .run();
+}
+
+// @tc.name: ut_simulation_virtual_clock
+// @tc.desc: Test the virtual clock of a simulation
+// @tc.precon: NA
+// @tc.step: 1. Create a simulation and read the current timestamp
+//           2. Advance the clock and read the timestamp on another thread
+//           3. Drop the simulation
+// @tc.expect: The timestamp follows the virtual clock on the simulation
+//             thread only, then the system clock
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_simulation_virtual_clock() {
+    let mut simulation = Simulation::new();
+    assert_eq!(get_current_timestamp(), EPOCH);
+    simulation.advance(Duration::from_secs(90));
+    assert_eq!(get_current_timestamp(), EPOCH + 90_000);
+    let other = std::thread::spawn(get_current_timestamp).join().unwrap();
+    assert!(other > EPOCH + 90_000);
+    drop(simulation);
+    assert!(get_current_timestamp() > EPOCH + 90_000);
+}
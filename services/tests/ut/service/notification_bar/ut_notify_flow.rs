@@ -71,6 +71,67 @@ fn ut_notify_flow_group() {
     assert_eq!(group_progress.total(), 100);
 }
 
+// @tc.name: ut_notify_flow_group_retry
+// @tc.desc: Test group state counts when failed tasks are retried
+// @tc.precon: NA
+// @tc.step: 1. Create a GroupProgress instance with completed and failed tasks
+//           2. Move the failed tasks back to Running as a retry does
+//           3. Complete the retried tasks
+// @tc.expect: Retried tasks leave the failed count and are counted once when
+// they complete
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_notify_flow_group_retry() {
+    let mut group_progress = GroupProgress::new();
+    for i in 0..10 {
+        if i % 2 == 0 {
+            group_progress.update_task_state(i, State::Completed);
+        } else {
+            group_progress.update_task_state(i, State::Failed);
+        }
+    }
+    assert_eq!(group_progress.successful(), 5);
+    assert_eq!(group_progress.failed(), 5);
+    assert!(group_progress.is_finish());
+
+    for i in (1..10).step_by(2) {
+        group_progress.update_task_state(i, State::Running);
+    }
+    assert_eq!(group_progress.successful(), 5);
+    assert_eq!(group_progress.failed(), 0);
+    assert!(!group_progress.is_finish());
+
+    for i in (1..10).step_by(2) {
+        group_progress.update_task_state(i, State::Completed);
+    }
+    assert_eq!(group_progress.successful(), 10);
+    assert_eq!(group_progress.failed(), 0);
+    assert_eq!(group_progress.total(), 10);
+    assert!(group_progress.is_finish());
+}
+
+// @tc.name: ut_notify_flow_speed_sample
+// @tc.desc: Test transfer speed measurement from progress updates
+// @tc.precon: NA
+// @tc.step: 1. Create a SpeedSample and update it before the sample interval
+//           2. Update it after the sample interval
+//           3. Update it with a progress going back
+// @tc.expect: The speed is measured once per interval, kept in between, and
+// restarted when the progress goes back
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_notify_flow_speed_sample() {
+    let mut sample = SpeedSample::new(0, 0);
+    assert_eq!(sample.update(500, 1000), None);
+    assert_eq!(sample.update(2000, 4000), Some(2000));
+    assert_eq!(sample.update(2500, 5000), Some(2000));
+    assert_eq!(sample.update(3000, 7000), Some(3000));
+    assert_eq!(sample.update(3500, 100), None);
+    assert_eq!(sample.update(4500, 1100), Some(1000));
+}
+
 // @tc.name: ut_notify_flow_task_progress
 // @tc.desc: Test task progress notification generation
 // @tc.precon: NA
@@ -102,6 +163,7 @@ fn ut_notify_flow_task_progress() {
         multi_upload: None,
         file_name: "test".to_string(),
         version: Version::API10,
+        speed: None,
     };
     let content_default = NotifyContent::task_progress_notify(None, &progress);
     let content = flow
@@ -140,6 +202,7 @@ fn ut_notify_flow_task_eventual() {
         uid,
         file_name: "test".to_string(),
         is_successful: true,
+        reason: Reason::Default,
     };
     let content_default = NotifyContent::task_eventual_notify(
         None,
@@ -148,6 +211,7 @@ fn ut_notify_flow_task_eventual() {
         info.uid as u32,
         info.file_name.clone(),
         info.is_successful,
+        info.reason,
     );
     let content = flow.publish_completed_notify(&info).unwrap();
     assert_eq!(content, content_default);
@@ -184,6 +248,7 @@ fn ut_customized_task_eventual() {
         uid,
         file_name: "test".to_string(),
         is_successful: false,
+        reason: Reason::Default,
     };
     let config = NotificationConfig::new(
         task_id,
@@ -203,6 +268,7 @@ fn ut_customized_task_eventual() {
         info.uid as u32,
         info.file_name.clone(),
         info.is_successful,
+        info.reason,
     );
     let content = flow.publish_completed_notify(&info).unwrap();
     let customized = db.query_task_customized_notification(task_id);
@@ -218,6 +284,7 @@ fn ut_customized_task_eventual() {
         info.uid as u32,
         info.file_name.clone(),
         info.is_successful,
+        info.reason,
     );
     assert!(db.query_task_customized_notification(task_id).is_none());
     assert_eq!(content, content_default);
@@ -260,6 +327,7 @@ fn ut_group_visibility_progress() {
         multi_upload: None,
         file_name: "test".to_string(),
         version: Version::API10,
+        speed: None,
     };
     let content = flow.publish_progress_notification(progress.clone());
     assert!(content.is_some());
@@ -317,6 +385,7 @@ fn ut_group_visibility_completion() {
         uid,
         file_name: "test".to_string(),
         is_successful: true,
+        reason: Reason::Default,
     };
     // First call to update group_progress cache
     flow.publish_completed_notify(&info);
@@ -330,3 +399,61 @@ fn ut_group_visibility_completion() {
     let content = flow.publish_completed_notify(&info);
     assert!(content.is_none());
 }
+
+// @tc.name: ut_notify_flow_coalesce_tasks
+// @tc.desc: Test coalescing of task notifications into a summary group
+// @tc.precon: NA
+// @tc.step: 1. Create a NotifyFlow instance with test channel
+//           2. Publish progress of MAX_TASK_NOTIFICATIONS tasks of one app
+//           3. Publish progress of one more task of the same app
+//           4. Publish progress of one more task of the same app again
+// @tc.expect: Tasks beyond the limit are moved into one summary group of the
+// app, with an ID in the reserved range, and a group progress notification is
+// published
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_notify_flow_coalesce_tasks() {
+    let (_, rx) = mpsc::unbounded_channel();
+    let db = Arc::new(NotificationDb::new());
+    let mut flow = NotifyFlow::new(rx, db.clone());
+    let uid = fast_random();
+    let base = fast_random() as u32 & 0x7fff_ffff;
+
+    let progress = |task_id| ProgressNotify {
+        action: Action::Download,
+        task_id,
+        uid,
+        processed: 50,
+        total: Some(100),
+        multi_upload: None,
+        file_name: "test".to_string(),
+        version: Version::API10,
+        speed: None,
+    };
+
+    let count = super::super::throttle::MAX_TASK_NOTIFICATIONS as u32 + 2;
+    for task_id in base..base + count {
+        let config = NotificationConfig::new(task_id, None, None, None, false, 0b10);
+        db.update_task_customized_notification(&config);
+    }
+
+    for task_id in base..base + count - 2 {
+        let content = flow.publish_progress_notification(progress(task_id)).unwrap();
+        assert_eq!(content.request_id, task_id);
+    }
+
+    let content = flow
+        .publish_progress_notification(progress(base + count - 2))
+        .unwrap();
+    let group_id = content.request_id;
+    assert!(group_id >= SUMMARY_GROUP_ID_BASE);
+    assert_eq!(db.query_task_gid(base + count - 2), Some(group_id));
+
+    flow.publish_progress_notification(progress(base + count - 1));
+    assert_eq!(db.query_task_gid(base + count - 1), Some(group_id));
+    assert!(matches!(
+        flow.notify_type_map.get(&(base + count - 1)),
+        Some(NotifyType::Group(id)) if *id == group_id
+    ));
+}
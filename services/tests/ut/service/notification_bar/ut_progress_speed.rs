@@ -0,0 +1,45 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::service::notification_bar::progress_speed::*;
+
+// @tc.name: ut_progress_speed_with_lang
+// @tc.desc: Test speed formatting with localization.
+// @tc.precon: NA
+// @tc.step: 1. Call progress_speed_with_lang with different languages
+// @tc.expect: Speed uses the localized size format followed by "/s".
+// @tc.type: FUNC
+// @tc.require: issues#ICLN0G
+#[test]
+fn ut_progress_speed_with_lang() {
+    assert_eq!(progress_speed_with_lang(512, "zh-Hans"), "512B/s");
+    assert_eq!(progress_speed_with_lang(1536, "en"), "1.50 KB/s");
+    assert_eq!(progress_speed_with_lang(1536, "fi"), "1,50 KT/s");
+}
+
+// @tc.name: ut_progress_eta_with_lang
+// @tc.desc: Test remaining time formatting with localization.
+// @tc.precon: NA
+// @tc.step: 1. Call progress_eta_with_lang with times under and over an hour
+//           2. Call progress_eta_with_lang with different languages
+// @tc.expect: Times under an hour omit the hours, minutes and seconds are
+// zero-padded, and the time separator follows the language.
+// @tc.type: FUNC
+// @tc.require: issues#ICLN0G
+#[test]
+fn ut_progress_eta_with_lang() {
+    assert_eq!(progress_eta_with_lang(0, "zh-Hans"), "0:00");
+    assert_eq!(progress_eta_with_lang(65, "zh-Hans"), "1:05");
+    assert_eq!(progress_eta_with_lang(3600 + 2 * 60 + 3, "en"), "1:02:03");
+    assert_eq!(progress_eta_with_lang(3600 + 2 * 60 + 3, "fi"), "1.02.03");
+}
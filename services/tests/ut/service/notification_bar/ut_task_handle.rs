@@ -0,0 +1,35 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+// @tc.name: ut_notify_file_uri
+// @tc.desc: Test building the URI of a downloaded file to open
+// @tc.precon: NA
+// @tc.step: 1. Build the URI of a plain sandbox path
+//           2. Build the URI of a path with spaces and non-ASCII characters
+// @tc.expect: The bundle name is the URI authority and reserved characters of
+// the path are percent-encoded
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_notify_file_uri() {
+    assert_eq!(
+        file_uri("com.example.app", "/data/storage/el2/base/files/a.txt"),
+        "file://com.example.app/data/storage/el2/base/files/a.txt"
+    );
+    assert_eq!(
+        file_uri("com.example.app", "/data/storage/el2/base/files/a (1)#é.txt"),
+        "file://com.example.app/data/storage/el2/base/files/a%20%281%29%23%C3%A9.txt"
+    );
+}
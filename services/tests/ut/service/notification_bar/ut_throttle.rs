@@ -0,0 +1,160 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::service::notification_bar::ffi::ProgressCircle;
+
+const TEST_UID: u64 = 1000;
+
+fn progress_content(request_id: u32, title: &str, current: u64) -> NotifyContent {
+    NotifyContent {
+        title: title.to_string(),
+        text: "test_text".to_string(),
+        want_agent: String::new(),
+        request_id,
+        uid: TEST_UID as u32,
+        live_view: true,
+        progress_circle: ProgressCircle::open(current, 1000),
+        x_mark: true,
+        buttons: Vec::new(),
+    }
+}
+
+// @tc.name: ut_throttle_budget
+// @tc.desc: Test the per-app budget of notification updates
+// @tc.precon: NA
+// @tc.step: 1. Acquire APP_NOTIFY_BUDGET tokens at the same time
+//           2. Acquire one more token at the same time
+//           3. Acquire a token after the refill time of one token
+// @tc.expect: The budget allows APP_NOTIFY_BUDGET updates per second
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_throttle_budget() {
+    let mut budget = NotifyBudget::new(0);
+    for _ in 0..APP_NOTIFY_BUDGET {
+        assert!(budget.try_acquire(0));
+    }
+    assert!(!budget.try_acquire(0));
+    assert!(!budget.try_acquire(1000 / APP_NOTIFY_BUDGET - 1));
+    assert!(budget.try_acquire(1000 / APP_NOTIFY_BUDGET));
+    assert!(!budget.try_acquire(1000 / APP_NOTIFY_BUDGET));
+}
+
+// @tc.name: ut_throttle_visible_change
+// @tc.desc: Test that only visible changes of a notification are published
+// @tc.precon: NA
+// @tc.step: 1. Publish a progress notification
+//           2. Publish an update within the same percentage
+//           3. Publish updates with a new percentage and a new title
+//           4. Publish updates changing the text only
+// @tc.expect: Updates that do not change what the user sees are dropped, a
+//             text change counts only if the text is visible
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_throttle_visible_change() {
+    let mut throttle = NotifyThrottle::new();
+    let mut publish = |content, text_visible, time| {
+        throttle
+            .throttle_progress(TEST_UID, content, text_visible, time)
+            .is_some()
+    };
+    assert!(publish(progress_content(1, "a", 100), false, 0));
+    assert!(!publish(progress_content(1, "a", 109), false, 1000));
+    assert!(publish(progress_content(1, "a", 110), false, 2000));
+    assert!(publish(progress_content(1, "b", 110), false, 3000));
+
+    let mut content = progress_content(1, "b", 110);
+    content.text = "1 MB/s".to_string();
+    assert!(!publish(content, false, 4000));
+    let mut content = progress_content(1, "b", 110);
+    content.text = "1 MB/s".to_string();
+    assert!(publish(content, true, 5000));
+
+    throttle.finish(TEST_UID, 1);
+    assert!(throttle
+        .throttle_progress(TEST_UID, progress_content(1, "b", 110), false, 6000)
+        .is_some());
+}
+
+// @tc.name: ut_throttle_pending_flush
+// @tc.desc: Test that an update refused for lack of budget is published later
+// @tc.precon: NA
+// @tc.step: 1. Use up the budget of an app
+//           2. Publish two more updates of one notification
+//           3. Flush before and after the budget refills
+//           4. Publish an update over budget and finish its notification
+// @tc.expect: Only the latest refused update is flushed once the budget
+//             refills, and none after the notification is finished
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_throttle_pending_flush() {
+    let mut throttle = NotifyThrottle::new();
+    for request_id in 0..APP_NOTIFY_BUDGET as u32 {
+        assert!(throttle
+            .throttle_progress(TEST_UID, progress_content(request_id, "a", 0), false, 0)
+            .is_some());
+    }
+    assert!(!throttle.has_pending());
+    assert!(throttle
+        .throttle_progress(TEST_UID, progress_content(0, "a", 100), false, 0)
+        .is_none());
+    assert!(throttle
+        .throttle_progress(TEST_UID, progress_content(0, "a", 200), false, 0)
+        .is_none());
+    assert!(throttle.has_pending());
+    assert!(throttle.flush(FLUSH_INTERVAL - 1).is_empty());
+
+    let flushed = throttle.flush(FLUSH_INTERVAL);
+    assert_eq!(flushed.len(), 1);
+    assert_eq!(flushed[0].progress_circle.current, 200);
+    assert!(!throttle.has_pending());
+
+    assert!(throttle
+        .throttle_progress(
+            TEST_UID,
+            progress_content(1, "a", 100),
+            false,
+            FLUSH_INTERVAL
+        )
+        .is_none());
+    throttle.finish(TEST_UID, 1);
+    assert!(!throttle.has_pending());
+    assert!(throttle.flush(FLUSH_INTERVAL * 2).is_empty());
+}
+
+// @tc.name: ut_throttle_admit_task
+// @tc.desc: Test the limit of individual task notifications of an app
+// @tc.precon: NA
+// @tc.step: 1. Admit MAX_TASK_NOTIFICATIONS tasks of an app
+//           2. Admit another task of the same app and of another app
+//           3. Finish one task and admit the refused task again
+// @tc.expect: Tasks beyond the limit are refused until a slot is freed
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_throttle_admit_task() {
+    let mut throttle = NotifyThrottle::new();
+    for task_id in 0..MAX_TASK_NOTIFICATIONS as u32 {
+        assert!(throttle.admit_task(TEST_UID, task_id));
+    }
+    assert!(throttle.admit_task(TEST_UID, 0));
+    assert!(!throttle.admit_task(TEST_UID, 100));
+    assert!(throttle.admit_task(TEST_UID + 1, 100));
+
+    throttle.finish(TEST_UID, 0);
+    assert!(throttle.admit_task(TEST_UID, 100));
+    assert!(!throttle.admit_task(TEST_UID, 0));
+}
@@ -38,6 +38,7 @@ fn ut_notify_typology_default_task_eventual() {
         UID,
         EXAMPLE_FILE.to_string(),
         false,
+        Reason::Default,
     );
     assert_eq!(content.title, "下载失败");
     assert_eq!(content.text, EXAMPLE_FILE);
@@ -54,6 +55,7 @@ fn ut_notify_typology_default_task_eventual() {
         0,
         EXAMPLE_FILE.to_string(),
         true,
+        Reason::Default,
     );
     assert_eq!(content.title, "下载成功");
     assert_eq!(content.text, EXAMPLE_FILE);
@@ -65,6 +67,7 @@ fn ut_notify_typology_default_task_eventual() {
         0,
         EXAMPLE_FILE.to_string(),
         false,
+        Reason::Default,
     );
     assert_eq!(content.title, "上传失败");
     assert_eq!(content.text, EXAMPLE_FILE);
@@ -76,6 +79,7 @@ fn ut_notify_typology_default_task_eventual() {
         0,
         EXAMPLE_FILE.to_string(),
         true,
+        Reason::Default,
     );
 
     assert_eq!(content.title, "上传成功");
@@ -105,6 +109,7 @@ fn ut_notify_typology_default_progress() {
         total: Some(10),
         multi_upload: None,
         version: Version::API10,
+        speed: None,
     };
     let content = NotifyContent::task_progress_notify(None, &progress_info);
     assert_eq!(content.title, "下载文件 10.00%");
@@ -203,3 +208,115 @@ fn ut_notify_typology_default_group_progress() {
     };
     assert_eq!(content.text, text_count);
 }
+
+// @tc.name: ut_notify_typology_eventual_buttons
+// @tc.desc: Test the action buttons of completion notifications
+// @tc.precon: NA
+// @tc.step: 1. Create task eventual notifications for download/upload
+// success/failure scenarios
+//           2. Create group eventual notifications with and without failed
+//              tasks
+//           3. Verify the action buttons of each notification
+// @tc.expect: Failed tasks offer retry, completed downloads offer open and
+// groups with failed tasks offer retry all, each with dismiss; other
+// notifications have no buttons
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_notify_typology_eventual_buttons() {
+    let content = NotifyContent::task_eventual_notify(
+        None,
+        Action::Download,
+        TASK_ID,
+        UID,
+        EXAMPLE_FILE.to_string(),
+        true,
+        Reason::Default,
+    );
+    assert_eq!(content.buttons, vec![BUTTON_OPEN, BUTTON_DISMISS]);
+
+    let content = NotifyContent::task_eventual_notify(
+        None,
+        Action::Upload,
+        TASK_ID,
+        UID,
+        EXAMPLE_FILE.to_string(),
+        false,
+        Reason::Default,
+    );
+    assert_eq!(content.buttons, vec![BUTTON_RETRY, BUTTON_DISMISS]);
+
+    let content = NotifyContent::task_eventual_notify(
+        None,
+        Action::Upload,
+        TASK_ID,
+        UID,
+        EXAMPLE_FILE.to_string(),
+        true,
+        Reason::Default,
+    );
+    assert!(content.buttons.is_empty());
+
+    let content =
+        NotifyContent::group_eventual_notify(None, Action::Download, GROUP_ID, UID, 100, 2, 1);
+    assert_eq!(content.live_view, false);
+    assert_eq!(content.progress_circle.open, false);
+    assert_eq!(content.buttons, vec![BUTTON_RETRY_ALL, BUTTON_DISMISS]);
+
+    let content =
+        NotifyContent::group_eventual_notify(None, Action::Download, GROUP_ID, UID, 100, 3, 0);
+    assert!(content.buttons.is_empty());
+
+    let progress = GroupProgress::new();
+    let content =
+        NotifyContent::group_progress_notify(None, Action::Download, GROUP_ID, UID, &progress);
+    assert!(content.buttons.is_empty());
+}
+
+// @tc.name: ut_notify_typology_fill_template
+// @tc.desc: Test filling resource templates with arguments
+// @tc.precon: NA
+// @tc.step: 1. Fill templates with sequential placeholders
+//           2. Fill templates with positional placeholders in another order
+//           3. Fill a template with fewer placeholders than arguments
+// @tc.expect: Each placeholder is replaced by the argument it refers to
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_notify_typology_fill_template() {
+    assert_eq!(
+        fill_template("成功 %d 个，失败 %d 个", &["3", "1"]),
+        "成功 3 个，失败 1 个"
+    );
+    assert_eq!(fill_template("%s: %d", &["a.txt", "2"]), "a.txt: 2");
+    assert_eq!(
+        fill_template("%2$s failed, %1$s succeeded", &["3", "1"]),
+        "1 failed, 3 succeeded"
+    );
+    assert_eq!(fill_template("%1$d of %2$d", &["1", "10"]), "1 of 10");
+    assert_eq!(fill_template("Done %s", &["1 MB", "unused"]), "Done 1 MB");
+}
+
+// @tc.name: ut_notify_typology_reason_resource
+// @tc.desc: Test the mapping of failure reasons to localized text resources
+// @tc.precon: NA
+// @tc.step: 1. Map reasons of each category to their resource keys
+// @tc.expect: Reasons map to the resource of their category, and reasons that
+// say nothing to the user map to none
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_notify_typology_reason_resource() {
+    assert_eq!(reason_resource(Reason::Default), None);
+    assert_eq!(reason_resource(Reason::UserOperation), None);
+    assert_eq!(reason_resource(Reason::NetworkOffline), Some(REASON_NETWORK));
+    assert_eq!(reason_resource(Reason::NetworkAppAccount), Some(REASON_NETWORK));
+    assert_eq!(reason_resource(Reason::Dns), Some(REASON_CONNECTION));
+    assert_eq!(reason_resource(Reason::Ssl), Some(REASON_CONNECTION));
+    assert_eq!(reason_resource(Reason::LowSpeed), Some(REASON_TIMEOUT));
+    assert_eq!(reason_resource(Reason::ProtocolError), Some(REASON_SERVER));
+    assert_eq!(reason_resource(Reason::InsufficientSpace), Some(REASON_STORAGE));
+    assert_eq!(reason_resource(Reason::IoError), Some(REASON_FILE));
+    assert_eq!(reason_resource(Reason::AccountStopped), Some(REASON_INTERRUPTED));
+    assert_eq!(reason_resource(Reason::OthersError), Some(REASON_UNKNOWN));
+}
@@ -0,0 +1,189 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+// @tc.name: ut_plan_chunks
+// @tc.desc: Test splitting a file into chunks
+// @tc.precon: NA
+// @tc.step: 1. Plan chunks of a file not a multiple of the chunk size
+//           2. Plan chunks of an exact multiple and of an empty file
+// @tc.expect: Chunks cover the file and only the last one is shorter
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_plan_chunks() {
+    let chunks = plan_chunks(25, 10);
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(
+        chunks[2],
+        ChunkRange {
+            index: 2,
+            offset: 20,
+            len: 5
+        }
+    );
+    assert_eq!(chunks.iter().map(|c| c.len).sum::<u64>(), 25);
+
+    assert_eq!(plan_chunks(20, 10).len(), 2);
+    assert_eq!(
+        plan_chunks(0, 10),
+        vec![ChunkRange {
+            index: 0,
+            offset: 0,
+            len: 0
+        }]
+    );
+}
+
+// @tc.name: ut_render_chunk_template
+// @tc.desc: Test replacing the placeholders of chunk templates
+// @tc.precon: NA
+// @tc.step: 1. Render a template using every placeholder
+// @tc.expect: All placeholders are replaced by the chunk values
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_render_chunk_template() {
+    let chunk = ChunkRange {
+        index: 1,
+        offset: 10,
+        len: 10,
+    };
+    assert_eq!(
+        render(
+            "{index} {number} {offset}-{end}/{total} {size} {count} {etag}",
+            &chunk,
+            25,
+            3,
+            "\"abc\""
+        ),
+        "1 2 10-19/25 10 3 \"abc\""
+    );
+}
+
+// @tc.name: ut_render_finalize_body
+// @tc.desc: Test building the body of the finalize request
+// @tc.precon: NA
+// @tc.step: 1. Render an S3 style finalize body for two chunks
+// @tc.expect: Every chunk is listed in order with its ETag
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_render_finalize_body() {
+    let config = ChunkConfig {
+        chunk_size: 10,
+        parallelism: 1,
+        max_retries: 0,
+        url: String::new(),
+        method: "PUT".to_string(),
+        headers: HashMap::new(),
+        finalize_url: "https://example.com/file".to_string(),
+        finalize_method: "POST".to_string(),
+        finalize_body: "<Parts>{parts}</Parts>".to_string(),
+        finalize_part: "<Part>{number}:{etag}</Part>".to_string(),
+    };
+    let chunks = plan_chunks(15, 10);
+    let etags = HashMap::from([(1, "b".to_string()), (0, "a".to_string())]);
+    assert_eq!(
+        render_finalize_body(&config, &chunks, &etags, 15),
+        "<Parts><Part>1:a</Part><Part>2:b</Part></Parts>"
+    );
+}
+
+// @tc.name: ut_chunk_url_origin
+// @tc.desc: Test checking chunk URLs against the task URL origin
+// @tc.precon: NA
+// @tc.step: 1. Compare URLs differing in path, port, credentials and case
+//           2. Compare URLs differing in scheme or host
+// @tc.expect: Only URLs with the same scheme and host match
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_chunk_url_origin() {
+    let task_url = "https://bucket.example.com/object?uploads";
+    assert!(is_same_origin(
+        "https://bucket.example.com/object?partNumber={number}",
+        task_url
+    ));
+    assert!(is_same_origin(
+        "HTTPS://Bucket.Example.com:443/object",
+        task_url
+    ));
+    assert!(is_same_origin("https://user@bucket.example.com", task_url));
+    assert!(!is_same_origin(
+        "http://bucket.example.com/object",
+        task_url
+    ));
+    assert!(!is_same_origin(
+        "https://other.example.com/object",
+        task_url
+    ));
+    assert!(!is_same_origin(
+        "https://bucket.example.com@other.example.com/object",
+        task_url
+    ));
+    assert!(!is_same_origin("/object", task_url));
+    assert_eq!(
+        url_origin("http://[::1]:8080/object"),
+        Some(("http".to_string(), "::1".to_string()))
+    );
+}
+
+// @tc.name: ut_chunk_retry_delay
+// @tc.desc: Test the delay before retrying a chunk
+// @tc.precon: NA
+// @tc.step: 1. Parse `Retry-After` values in seconds and as HTTP dates,
+//              valid and out of range
+//           2. Get the delay of successive retries with and without
+//              `Retry-After`
+// @tc.expect: The delay doubles on every retry, follows `Retry-After` and is
+//             capped
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_chunk_retry_delay() {
+    // Sun, 06 Nov 1994 08:49:37 GMT
+    let date = 784111777;
+    assert_eq!(parse_retry_after(" 5 ", date), Some(Duration::from_secs(5)));
+    assert_eq!(
+        parse_retry_after("Sun, 06 Nov 1994 08:49:37 GMT", date - 10),
+        Some(Duration::from_secs(10))
+    );
+    assert_eq!(
+        parse_retry_after("Sun, 06 Nov 1994 08:49:37 GMT", date + 10),
+        Some(Duration::ZERO)
+    );
+    assert_eq!(parse_retry_after("soon", date), None);
+    assert_eq!(
+        parse_retry_after("Sun, 06 Nov 9223372036854775807 08:49:37 GMT", date),
+        None
+    );
+    assert_eq!(
+        parse_retry_after("Sun, 06 Jan -9223372036854775808 08:49:37 GMT", date),
+        None
+    );
+
+    assert_eq!(retry_delay(1, None), Duration::from_secs(1));
+    assert_eq!(retry_delay(2, None), Duration::from_secs(2));
+    assert_eq!(retry_delay(4, None), Duration::from_secs(8));
+    assert_eq!(retry_delay(30, None), RETRY_MAX_DELAY);
+    assert_eq!(
+        retry_delay(1, Some(Duration::from_secs(30))),
+        Duration::from_secs(30)
+    );
+    assert_eq!(
+        retry_delay(1, Some(Duration::from_secs(3600))),
+        RETRY_MAX_DELAY
+    );
+}
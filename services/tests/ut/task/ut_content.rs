@@ -0,0 +1,187 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+// @tc.name: ut_content_disposition_file_name
+// @tc.desc: Test parsing the file name of Content-Disposition headers
+// @tc.precon: NA
+// @tc.step: 1. Parse headers with quoted, unquoted and escaped names
+//           2. Parse headers with RFC 5987 `filename*` parameters
+//           3. Parse headers without a file name
+// @tc.expect: `filename*` takes precedence and names are decoded
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_content_disposition_file_name() {
+    assert_eq!(
+        content_disposition_file_name("attachment; filename=\"a; \\\"b\\\".txt\""),
+        Some("a; \"b\".txt".to_string())
+    );
+    assert_eq!(
+        content_disposition_file_name("attachment; FILENAME=report.pdf"),
+        Some("report.pdf".to_string())
+    );
+    assert_eq!(
+        content_disposition_file_name(
+            "attachment; filename=\"fallback.txt\"; filename*=UTF-8''%E6%96%87%E4%BB%B6.txt"
+        ),
+        Some("文件.txt".to_string())
+    );
+    assert_eq!(
+        content_disposition_file_name("attachment; filename*=iso-8859-1'en'%E9t%E9.txt"),
+        Some("été.txt".to_string())
+    );
+    assert_eq!(
+        content_disposition_file_name("attachment; filename*=UTF-8''%G1; filename=a.txt"),
+        Some("a.txt".to_string())
+    );
+    assert_eq!(
+        content_disposition_file_name("attachment; filename*=UTF-8''%+5; filename=a.txt"),
+        Some("a.txt".to_string())
+    );
+    assert_eq!(content_disposition_file_name("inline"), None);
+    assert_eq!(
+        content_disposition_file_name("attachment; filename=\"\""),
+        None
+    );
+}
+
+// @tc.name: ut_sniff_mime_type
+// @tc.desc: Test sniffing the content type from the first bytes of a file
+// @tc.precon: NA
+// @tc.step: 1. Sniff data starting with known magic bytes
+//           2. Sniff BMP headers, valid and not
+//           3. Sniff markup, text with and without byte order mark and binary
+//              data
+// @tc.expect: Known formats, markup and marked text are recognized, anything
+//             else is application/octet-stream
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_sniff_mime_type() {
+    assert_eq!(sniff_mime_type(b"\x89PNG\r\n\x1a\n\x00\x00"), "image/png");
+    assert_eq!(sniff_mime_type(b"%PDF-1.7\n"), "application/pdf");
+    assert_eq!(
+        sniff_mime_type(b"RIFF\x00\x00\x00\x00WEBPVP8 "),
+        "image/webp"
+    );
+    assert_eq!(sniff_mime_type(b"\x00\x00\x00\x18ftypmp42"), "video/mp4");
+
+    let bmp = b"BM\x46\x00\x00\x00\x00\x00\x00\x00\x36\x00\x00\x00\x28\x00\x00\x00";
+    assert_eq!(sniff_mime_type(bmp), "image/bmp");
+    assert_eq!(
+        sniff_mime_type(b"BMW is a car brand, not a bitmap"),
+        UNKNOWN_MIME_TYPE
+    );
+    assert_eq!(sniff_mime_type(b"BM"), UNKNOWN_MIME_TYPE);
+
+    assert_eq!(sniff_mime_type(b"  <!DOCTYPE html><html>"), "text/html");
+    assert_eq!(
+        sniff_mime_type(b"\xef\xbb\xbf<?xml version"),
+        "application/xml"
+    );
+    assert_eq!(sniff_mime_type(b"\xef\xbb\xbfhello world\n"), "text/plain");
+    assert_eq!(sniff_mime_type(b"hello world\n"), UNKNOWN_MIME_TYPE);
+    assert_eq!(sniff_mime_type(b"\x00\x01\x02\x03\x04"), UNKNOWN_MIME_TYPE);
+    assert_eq!(sniff_mime_type(b""), UNKNOWN_MIME_TYPE);
+}
+
+// @tc.name: ut_is_generic_mime_type
+// @tc.desc: Test detecting content types carrying no information
+// @tc.precon: NA
+// @tc.step: 1. Check missing, generic and specific content types
+// @tc.expect: Only missing and generic content types are generic
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_is_generic_mime_type() {
+    assert!(is_generic_mime_type(""));
+    assert!(is_generic_mime_type(
+        "Application/Octet-Stream; charset=binary"
+    ));
+    assert!(is_generic_mime_type("binary/octet-stream"));
+    assert!(!is_generic_mime_type("image/png"));
+    assert!(!is_generic_mime_type("text/plain; charset=utf-8"));
+}
+
+// @tc.name: ut_sanitize_file_name
+// @tc.desc: Test making server-suggested file names safe
+// @tc.precon: NA
+// @tc.step: 1. Sanitize names with directories, reserved and control
+//           characters
+//           2. Sanitize names with nothing usable
+//           3. Sanitize a name longer than the file system limit
+// @tc.expect: Names are safe, unusable names are rejected and long names
+//             keep their extension
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_sanitize_file_name() {
+    assert_eq!(
+        sanitize_file_name("../../etc/passwd"),
+        Some("passwd".to_string())
+    );
+    assert_eq!(
+        sanitize_file_name("C:\\dir\\a<b>|c?.txt"),
+        Some("a_b__c_.txt".to_string())
+    );
+    assert_eq!(
+        sanitize_file_name(" .hidden\u{0}name. "),
+        Some("hiddenname".to_string())
+    );
+    assert_eq!(sanitize_file_name(".."), None);
+    assert_eq!(sanitize_file_name("dir/"), None);
+
+    let long = format!("{}.tar.gz", "文".repeat(100));
+    let name = sanitize_file_name(&long).unwrap();
+    assert!(name.len() <= 255);
+    assert!(name.ends_with(".gz"));
+}
+
+// @tc.name: ut_numbered_file_name
+// @tc.desc: Test building alternative names for taken file names
+// @tc.precon: NA
+// @tc.step: 1. Number names with and without an extension
+// @tc.expect: The number is inserted before the extension
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_numbered_file_name() {
+    assert_eq!(numbered_file_name("a.txt", 1), "a (1).txt");
+    assert_eq!(numbered_file_name("archive", 12), "archive (12)");
+    assert_eq!(numbered_file_name(".profile", 2), ".profile (2)");
+}
+
+// @tc.name: ut_url_file_name
+// @tc.desc: Test deriving a file name from a URL
+// @tc.precon: NA
+// @tc.step: 1. Get the name of URLs with a query, an encoded name, an
+//              invalid escape and no path
+// @tc.expect: The decoded last path segment is returned if any, segments
+//             with invalid escapes are returned as is
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_url_file_name() {
+    assert_eq!(
+        url_file_name("https://example.com/files/a%20b.zip?x=1#top"),
+        Some("a b.zip".to_string())
+    );
+    assert_eq!(
+        url_file_name("https://example.com/a%+5.zip"),
+        Some("a%+5.zip".to_string())
+    );
+    assert_eq!(url_file_name("https://example.com"), None);
+    assert_eq!(url_file_name("https://example.com/dir/"), None);
+}
@@ -0,0 +1,142 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+// @tc.name: ut_dns_settings_parse
+// @tc.desc: Test parsing of the DNS configuration file
+// @tc.precon: NA
+// @tc.step: 1. Parse a file with options of every application, options of
+//              one application and invalid lines
+//           2. Get the options of the configured and of another application
+// @tc.expect: The application options extend the options of every
+//             application, invalid lines are skipped
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_dns_settings_parse() {
+    let settings = DnsSettings::parse(
+        "# DNS options\n\
+         ip_preference.com.example.video = ipv6_first\n\
+         resolve = cdn.example.com:443:203.0.113.7\n\
+         resolve.com.example.video = cdn.example.com:80:203.0.113.8\n\
+         server.com.example.video = tls://1.1.1.1#one.one.one.one\n\
+         first_family_count.com.example.video = 2\n\
+         server = udp://nowhere\n\
+         unknown = 1\n\
+         no value\n",
+    );
+
+    let other = settings.config("com.example.other");
+    assert_eq!(other.server, DnsServer::System);
+    assert_eq!(other.overrides.len(), 1);
+    assert!(!other.is_default());
+
+    let video = settings.config("com.example.video");
+    assert_eq!(video.preference, IpPreference::Ipv6First);
+    assert_eq!(video.first_family_count, 2);
+    assert_eq!(
+        video.server,
+        DnsServer::Tls {
+            addr: "1.1.1.1:853".parse().unwrap(),
+            name: "one.one.one.one".to_string(),
+        }
+    );
+    assert_eq!(
+        video.overrides.iter().map(|o| o.port).collect::<Vec<_>>(),
+        vec![443, 80]
+    );
+
+    assert!(DnsSettings::parse("")
+        .config("com.example.video")
+        .is_default());
+}
+
+// @tc.name: ut_dns_take_resolved
+// @tc.desc: Test reporting of the addresses resolved for a task
+// @tc.precon: NA
+// @tc.step: 1. Record addresses resolved for a task
+//           2. Take them twice
+// @tc.expect: The addresses are reported once, in order
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_dns_take_resolved() {
+    const TASK_ID: u32 = 40_001;
+    RESOLVED.lock().unwrap().insert(
+        TASK_ID,
+        vec![
+            "[2001:db8::7]:443".parse().unwrap(),
+            "203.0.113.7:443".parse().unwrap(),
+        ],
+    );
+    assert_eq!(
+        take_resolved(TASK_ID).as_deref(),
+        Some("[2001:db8::7]:443,203.0.113.7:443")
+    );
+    assert_eq!(take_resolved(TASK_ID), None);
+}
+
+// @tc.name: ut_dns_clear_resolved
+// @tc.desc: Test forgetting the addresses resolved for a finished task
+// @tc.precon: NA
+// @tc.step: 1. Record addresses resolved for a task
+//           2. Clear them and take them
+// @tc.expect: Nothing is left to report
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_dns_clear_resolved() {
+    const TASK_ID: u32 = 40_002;
+    RESOLVED
+        .lock()
+        .unwrap()
+        .insert(TASK_ID, vec!["203.0.113.7:443".parse().unwrap()]);
+    clear_resolved(TASK_ID);
+    assert_eq!(take_resolved(TASK_ID), None);
+}
+
+// @tc.name: ut_dns_task_config
+// @tc.desc: Test the DNS options of a task with options of its own
+// @tc.precon: NA
+// @tc.step: 1. Get the options of a task without options of its own
+//           2. Pin an address and an IP preference to the task and get its
+//              options again
+// @tc.expect: The task uses the options of its application, then its own
+//             address first and its own preference
+// @tc.type: FUNC
+// @tc.require: issues#ICN16H
+#[test]
+fn ut_dns_task_config() {
+    use crate::database::dns::{set_task_dns, TaskDns};
+
+    const TASK_ID: u32 = 40_003;
+    const BUNDLE: &str = "com.example.dns";
+    set_task_dns(TASK_ID, &TaskDns::default()).unwrap();
+    let app = DnsSettings::get_instance().config(BUNDLE).clone();
+    assert_eq!(task_config(TASK_ID, BUNDLE), app);
+
+    let options = TaskDns {
+        overrides: vec!["cdn.example.com:443:203.0.113.7".to_string()],
+        ip_preference: "ipv6_first".to_string(),
+    };
+    set_task_dns(TASK_ID, &options).unwrap();
+    let config = task_config(TASK_ID, BUNDLE);
+    assert_eq!(
+        config.overrides[0],
+        HostOverride::parse("cdn.example.com:443:203.0.113.7").unwrap()
+    );
+    assert_eq!(config.overrides.len(), app.overrides.len() + 1);
+    assert_eq!(config.preference, IpPreference::Ipv6First);
+    set_task_dns(TASK_ID, &TaskDns::default()).unwrap();
+}
@@ -33,4 +33,5 @@ fn ut_enum_error_code() {
     assert_eq!(ErrorCode::TaskEnqueueErr as i32, 21900004);
     assert_eq!(ErrorCode::TaskNotFound as i32, 21900006);
     assert_eq!(ErrorCode::TaskStateErr as i32, 21900007);
+    assert_eq!(ErrorCode::TaskQuotaErr as i32, 21900009);
 }
\ No newline at end of file